@@ -46,7 +46,6 @@ pub fn generate_sbom(package: Package) -> crate::Result {
         spdx_packages.push(netbird_spdx_package(&cargo_metadata));
 
         if package == Package::Edgar {
-            spdx_packages.push(cannelloni_spdx_package(&cargo_metadata));
             spdx_packages.push(rperf_spdx_package(&cargo_metadata));
         }
 
@@ -234,44 +233,6 @@ fn netbird_spdx_package(cargo_metadata: &cargo_metadata::Metadata) -> SpdxItemPa
     }
 }
 
-fn cannelloni_spdx_package(cargo_metadata: &cargo_metadata::Metadata) -> SpdxItemPackages {
-
-    let version = cargo_metadata.workspace_metadata["ci"]["cannelloni"]["version"].as_str()
-        .expect("Cannelloni version not defined.");
-
-    SpdxItemPackages {
-        spdxid: format!("SPDXRef-Package-cannelloni-{version}"),
-        comment: Some(String::from("Used as external software for forwarding CAN communication over HTTP. No direct linking takes place.")),
-        download_location: String::from("https://github.com/mguentner/cannelloni"),
-        homepage: Some(String::from("https://github.com/mguentner/cannelloni")),
-        license_concluded: Some(String::from("GPL-2.0")),
-        license_declared: Some(String::from("GPL-2.0")),
-        name: String::from("Cannelloni"),
-        version_info: Some(String::from(version)),
-
-        annotations: None,
-        attribution_texts: None,
-        built_date: None,
-        checksums: None,
-        copyright_text: None,
-        description: None,
-        external_refs: None,
-        files_analyzed: None,
-        has_files: None,
-        license_comments: None,
-        license_info_from_files: None,
-        originator: None,
-        package_file_name: None,
-        package_verification_code: None,
-        primary_package_purpose: None,
-        release_date: None,
-        source_info: None,
-        summary: None,
-        supplier: None,
-        valid_until_date: None,
-    }
-}
-
 fn rperf_spdx_package(cargo_metadata: &cargo_metadata::Metadata) -> SpdxItemPackages {
 
     let version = cargo_metadata.workspace_metadata["ci"]["rperf"]["version"].as_str()
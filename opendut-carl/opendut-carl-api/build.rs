@@ -23,7 +23,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ".opendut.viper.rt",
             "::opendut_viper_rt::proto"
         )
+        .file_descriptor_set_path(PathBuf::from(std::env::var("OUT_DIR")?).join("opendut_carl_services.bin"))
         .type_attribute(".", "#[allow(clippy::all)]")
+        .field_attribute(".opendut.carl.services.peer_messaging_broker.ExecuteViperRun", r#"#[cfg(feature = "viper")]"#)
+        .field_attribute(".opendut.carl.services.peer_messaging_broker.ViperRunUpdate", r#"#[cfg(feature = "viper")]"#)
+        .field_attribute(".opendut.carl.services.peer_messaging_broker.ViperRunReport", r#"#[cfg(feature = "viper")]"#)
         .compile_protos(&protos, &includes)?;
 
     Ok(())
@@ -0,0 +1,106 @@
+use std::time::SystemTime;
+#[cfg(feature = "client")]
+pub use client::*;
+use opendut_model::audit::{AuditEvent, AuditEventId};
+
+#[derive(Debug, Clone, Default)]
+pub struct ListAuditEventsRequest {
+    /// Only list events recorded at or after this point in time. Lists all events, if not set.
+    pub since: Option<SystemTime>,
+    /// Maximum number of events per page. [`ListAuditEventsRequest::DEFAULT_PAGE_SIZE`] is used, if zero.
+    pub page_size: u32,
+    /// Continue listing after this event, as returned by the previous page.
+    pub page_token: Option<AuditEventId>,
+}
+impl ListAuditEventsRequest {
+    pub const DEFAULT_PAGE_SIZE: u32 = 100;
+    pub const MAX_PAGE_SIZE: u32 = 1000;
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEventsPage {
+    /// Events in chronological order.
+    pub events: Vec<AuditEvent>,
+    /// Set, if there are more events to list.
+    pub next_page_token: Option<AuditEventId>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListAuditEventsError {
+    #[error("Page token <{page_token}> does not designate a known audit event.")]
+    UnknownPageToken {
+        page_token: AuditEventId,
+    },
+    #[error("An internal error occurred while listing the audit events:\n  {cause}")]
+    Internal {
+        cause: String
+    }
+}
+
+#[cfg(feature = "client")]
+mod client {
+    use tonic::codegen::{Body, Bytes, http, InterceptedService, StdError};
+
+    use crate::carl::{ClientError, extract};
+    use crate::carl::audit::{AuditEventsPage, ListAuditEventsError, ListAuditEventsRequest};
+    use crate::proto::services::audit_log;
+    use crate::proto::services::audit_log::audit_log_client::AuditLogClient;
+
+    #[derive(Clone, Debug)]
+    pub struct AuditLog<T> {
+        inner: AuditLogClient<T>,
+    }
+
+    impl<T> AuditLog<T>
+    where T: tonic::client::GrpcService<tonic::body::Body>,
+          T::Error: Into<StdError>,
+          T::ResponseBody: Body<Data=Bytes> + Send + 'static,
+          <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: AuditLogClient<T>) -> AuditLog<T> {
+            AuditLog { inner }
+        }
+
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AuditLog<InterceptedService<T, F>>
+            where
+                F: tonic::service::Interceptor,
+                T::ResponseBody: Default,
+                T: tonic::codegen::Service<
+                    http::Request<tonic::body::Body>,
+                    Response = http::Response<
+                        <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                    >,
+                >,
+                <T as tonic::codegen::Service<
+                    http::Request<tonic::body::Body>,
+                >>::Error: Into<StdError> + Send + Sync,
+        {
+            let inner_client = AuditLogClient::new(InterceptedService::new(inner, interceptor));
+            AuditLog {
+                inner: inner_client
+            }
+        }
+
+        pub async fn list_audit_events(&mut self, request: ListAuditEventsRequest) -> Result<AuditEventsPage, ClientError<ListAuditEventsError>> {
+
+            let request = tonic::Request::new(audit_log::ListAuditEventsRequest::from(request));
+
+            let response = self.inner.list_audit_events(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                audit_log::list_audit_events_response::Reply::Failure(failure) => {
+                    let error = ListAuditEventsError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                audit_log::list_audit_events_response::Reply::Success(success) => {
+                    let page = AuditEventsPage::try_from(success)?;
+                    Ok(page)
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,12 @@ use std::collections::HashMap;
 pub use client::*;
 
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
+use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::executor::state::ExecutorStatus;
+#[cfg(feature = "viper")]
+use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperRunStatus, ViperSourceDescriptor};
+#[cfg(feature = "viper")]
+use opendut_viper_rt::run::TestSuiteReport;
 
 pub mod error {
     #[derive(thiserror::Error, Debug)]
@@ -25,6 +31,34 @@ pub struct UpstreamMessage {
 pub enum UpstreamMessagePayload {
     EdgePeerConfigurationState(EdgePeerConfigurationState),
     Ping,
+    ExecutorStatusUpdate(ExecutorStatusUpdate),
+    #[cfg(feature = "viper")]
+    ViperRunUpdate(ViperRunUpdate),
+    #[cfg(feature = "viper")]
+    ViperRunReport(Box<ViperRunReport>),
+}
+
+/// Lifecycle status of an executor, which was configured via [`DownstreamMessagePayload::ApplyPeerConfiguration`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutorStatusUpdate {
+    pub id: ExecutorId,
+    pub status: ExecutorStatus,
+}
+
+/// Progress of a VIPER run, which was started via [`DownstreamMessagePayload::ExecuteViperRun`].
+#[cfg(feature = "viper")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViperRunUpdate {
+    pub id: ViperRunId,
+    pub status: ViperRunStatus,
+}
+
+/// Report of a finished VIPER run, which was started via [`DownstreamMessagePayload::ExecuteViperRun`].
+#[cfg(feature = "viper")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViperRunReport {
+    pub id: ViperRunId,
+    pub report: TestSuiteReport,
 }
 
 
@@ -39,6 +73,8 @@ pub enum DownstreamMessagePayload {
     Pong,
     ApplyPeerConfiguration(Box<ApplyPeerConfiguration>),
     DisconnectNotice,
+    #[cfg(feature = "viper")]
+    ExecuteViperRun(Box<ExecuteViperRun>),
 }
 
 #[derive(Debug, PartialEq)]
@@ -46,6 +82,13 @@ pub struct ApplyPeerConfiguration {
     pub configuration: PeerConfiguration,
 }
 
+#[cfg(feature = "viper")]
+#[derive(Debug, PartialEq)]
+pub struct ExecuteViperRun {
+    pub run: ViperRunDescriptor,
+    pub source: ViperSourceDescriptor,
+}
+
 #[derive(Debug, Clone)]
 pub struct TracingContext {
     pub values: HashMap<String, String>,
@@ -1,14 +1,48 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 #[cfg(any(feature = "client", feature = "wasm-client"))]
 pub use client::*;
 use opendut_model::cluster::{ClusterDisplay, ClusterId, ClusterName};
 use opendut_model::cluster::state::ClusterState;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::PeerId;
 use opendut_model::peer::state::PeerState;
+use opendut_model::resources::Revision;
+use opendut_model::topology::DeviceId;
 use opendut_model::ShortName;
 
+/// A stored cluster deployment together with the devices, which were resolved for the cluster.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredClusterDeployment {
+    pub cluster_id: ClusterId,
+    pub devices: HashSet<DeviceId>,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum CreateClusterDescriptorError {
+    #[error("ClusterDescriptor '{cluster_name}' <{cluster_id}> could not be stored, because it was changed in the meantime (expected revision {expected_revision}, but found revision {actual_revision}). Reload the cluster descriptor and apply your changes again.")]
+    Conflict {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        expected_revision: Revision,
+        actual_revision: Revision,
+    },
+    #[error(
+        "ClusterDescriptor '{cluster_name}' <{cluster_id}> in namespace '{namespace}' could not be stored, because it uses the peers <{peers}> of other namespaces!",
+        peers = peer_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+    )]
+    ForeignNamespacePeers {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        namespace: NamespaceName,
+        peer_ids: Vec<PeerId>,
+    },
+    #[error("ClusterDescriptor '{cluster_name}' <{cluster_id}> could not be stored, because the CAN rules of device <{device_id}> are invalid: {cause}")]
+    IllegalCanRules {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        device_id: DeviceId,
+        cause: String,
+    },
     #[error("ClusterConfigration '{cluster_name}' <{cluster_id}> could not be created, due to internal errors:\n  {cause}")]
     Internal {
         cluster_id: ClusterId,
@@ -198,9 +232,19 @@ mod client {
         }
 
         pub async fn delete_cluster_descriptor(&mut self, cluster_id: ClusterId) -> Result<ClusterDescriptor, ClientError<DeleteClusterDescriptorError>> {
+            self.delete_cluster_descriptor_scoped(cluster_id, None).await
+        }
+
+        /// Deletes the cluster descriptor, if it belongs to the given namespace.
+        pub async fn delete_cluster_descriptor_in_namespace(&mut self, cluster_id: ClusterId, namespace: NamespaceName) -> Result<ClusterDescriptor, ClientError<DeleteClusterDescriptorError>> {
+            self.delete_cluster_descriptor_scoped(cluster_id, Some(namespace)).await
+        }
+
+        async fn delete_cluster_descriptor_scoped(&mut self, cluster_id: ClusterId, namespace: Option<NamespaceName>) -> Result<ClusterDescriptor, ClientError<DeleteClusterDescriptorError>> {
 
             let request = tonic::Request::new(cluster_manager::DeleteClusterDescriptorRequest {
                 cluster_id: Some(cluster_id.into()),
+                namespace: namespace.map(Into::into),
             });
 
             let response = self.inner.delete_cluster_descriptor(request).await?
@@ -219,8 +263,18 @@ mod client {
         }
 
         pub async fn get_cluster_descriptor(&mut self, cluster_id: ClusterId) -> Result<ClusterDescriptor, GetClusterDescriptorError> {
+            self.get_cluster_descriptor_scoped(cluster_id, None).await
+        }
+
+        /// Gets the cluster descriptor, if it belongs to the given namespace.
+        pub async fn get_cluster_descriptor_in_namespace(&mut self, cluster_id: ClusterId, namespace: NamespaceName) -> Result<ClusterDescriptor, GetClusterDescriptorError> {
+            self.get_cluster_descriptor_scoped(cluster_id, Some(namespace)).await
+        }
+
+        async fn get_cluster_descriptor_scoped(&mut self, cluster_id: ClusterId, namespace: Option<NamespaceName>) -> Result<ClusterDescriptor, GetClusterDescriptorError> {
             let request = tonic::Request::new(cluster_manager::GetClusterDescriptorRequest {
                 id: Some(cluster_id.into()),
+                namespace: namespace.map(Into::into),
             });
 
             match self.inner.get_cluster_descriptor(request).await {
@@ -246,7 +300,18 @@ mod client {
         }
 
         pub async fn list_cluster_descriptors(&mut self) -> Result<Vec<ClusterDescriptor>, ListClusterDescriptorsError> {
-            let request = tonic::Request::new(cluster_manager::ListClusterDescriptorsRequest {});
+            self.list_cluster_descriptors_filtered(None).await
+        }
+
+        /// Lists the cluster descriptors of the given namespace.
+        pub async fn list_cluster_descriptors_in_namespace(&mut self, namespace: NamespaceName) -> Result<Vec<ClusterDescriptor>, ListClusterDescriptorsError> {
+            self.list_cluster_descriptors_filtered(Some(namespace)).await
+        }
+
+        async fn list_cluster_descriptors_filtered(&mut self, namespace: Option<NamespaceName>) -> Result<Vec<ClusterDescriptor>, ListClusterDescriptorsError> {
+            let request = tonic::Request::new(cluster_manager::ListClusterDescriptorsRequest {
+                namespace: namespace.map(Into::into),
+            });
 
             match self.inner.list_cluster_descriptors(request).await {
                 Ok(response) => {
@@ -270,7 +335,7 @@ mod client {
             }
         }
 
-        pub async fn store_cluster_deployment(&mut self, deployment: ClusterDeployment) -> Result<ClusterId, ClientError<StoreClusterDeploymentError>> {
+        pub async fn store_cluster_deployment(&mut self, deployment: ClusterDeployment) -> Result<StoredClusterDeployment, ClientError<StoreClusterDeploymentError>> {
 
             let request = tonic::Request::new(cluster_manager::StoreClusterDeploymentRequest {
                 cluster_deployment: Some(deployment.into()),
@@ -286,7 +351,10 @@ mod client {
                 }
                 cluster_manager::store_cluster_deployment_response::Reply::Success(success) => {
                     let cluster_id = extract!(success.cluster_id)?;
-                    Ok(cluster_id)
+                    let devices = success.devices.into_iter()
+                        .map(DeviceId::try_from)
+                        .collect::<Result<_, _>>()?;
+                    Ok(StoredClusterDeployment { cluster_id, devices })
                 }
             }
         }
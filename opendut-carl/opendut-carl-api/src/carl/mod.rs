@@ -1,5 +1,6 @@
 use cfg_if::cfg_if;
 
+pub mod audit;
 pub mod broker;
 pub mod cluster;
 pub mod metadata;
@@ -135,6 +136,7 @@ cfg_if! {
         use opendut_auth::confidential::client::ConfidentialClient;
         use opendut_auth::confidential::tonic_service::TonicAuthenticationService;
 
+        use crate::carl::audit::AuditLog;
         use crate::carl::cluster::ClusterManager;
         use crate::carl::metadata::MetadataProvider;
         use crate::carl::peer::PeersRegistrar;
@@ -143,6 +145,7 @@ cfg_if! {
         #[cfg(feature="viper")]
         use crate::carl::viper::TestManager;
 
+        use crate::proto::services::audit_log::audit_log_client::AuditLogClient;
         use crate::proto::services::cluster_manager::cluster_manager_client::ClusterManagerClient;
         use crate::proto::services::metadata_provider::metadata_provider_client::MetadataProviderClient;
         use crate::proto::services::peer_manager::peer_manager_client::PeerManagerClient;
@@ -155,6 +158,7 @@ cfg_if! {
 
         #[derive(Clone)]
         pub struct CarlClient {
+            pub audit: AuditLog<TonicAuthenticationService>,
             pub broker: PeerMessagingBroker<TonicAuthenticationService>,
             pub cluster: ClusterManager<TonicAuthenticationService>,
             pub metadata: MetadataProvider<TonicAuthenticationService>,
@@ -232,6 +236,7 @@ cfg_if! {
                     .service(channel);
 
                 Ok(CarlClient {
+                    audit: AuditLog::new(AuditLogClient::new(Clone::clone(&auth_svc))),
                     broker: PeerMessagingBroker::new(PeerMessagingBrokerClient::new(Clone::clone(&auth_svc))),
                     cluster: ClusterManager::new(ClusterManagerClient::new(Clone::clone(&auth_svc))),
                     metadata: MetadataProvider::new(MetadataProviderClient::new(Clone::clone(&auth_svc))),
@@ -1,9 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::time::Duration;
 #[cfg(feature = "client")]
 pub use client::*;
-use opendut_model::peer::PeerId;
+use opendut_model::cluster::{ClusterDeployment, ClusterDescriptor, ClusterId};
+use opendut_model::peer::configuration::EdgePeerConfigurationState;
+use opendut_model::peer::{PeerDescriptor, PeerId};
 use opendut_model::peer::state::PeerConnectionState;
+use uuid::Uuid;
 
 pub mod error {
     #[derive(thiserror::Error, Debug)]
@@ -42,11 +46,129 @@ pub struct WaitForPeersOnlineResponse {
     pub status: WaitForPeersOnlineResponseStatus,
 }
 
+/// Kinds of resources, which can be observed via a [`WatchRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchedResourceKind {
+    PeerDescriptor,
+    PeerConnectionState,
+    PeerConfigurationState,
+    ClusterDescriptor,
+    ClusterDeployment,
+}
+
+/// Position of an event in the sequence of watch events, which is shared by all watches.
+///
+/// The revisions are only kept in memory by CARL, so the numbering starts anew whenever CARL restarts.
+/// The epoch identifies the numbering, which a revision belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WatchRevision {
+    pub epoch: Uuid,
+    pub number: u64,
+}
+
+impl WatchRevision {
+    pub fn initial(epoch: Uuid) -> Self {
+        Self { epoch, number: 0 }
+    }
+
+    pub fn next(self) -> Self {
+        Self { epoch: self.epoch, number: self.number + 1 }
+    }
+}
+
+impl fmt::Display for WatchRevision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.number, self.epoch)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WatchRequest {
+    /// Kinds of resources to watch. All kinds are watched, if empty.
+    pub kinds: HashSet<WatchedResourceKind>,
+    /// Only watch the resources of these peers. Applies to resources identified by a peer ID. No filtering, if empty.
+    pub peer_ids: HashSet<PeerId>,
+    /// Only watch the resources of these clusters. Applies to resources identified by a cluster ID. No filtering, if empty.
+    pub cluster_ids: HashSet<ClusterId>,
+    /// Revision of the last resumable event received by a previous watch, to resume with the events following it.
+    /// If not set, the watch starts with an `Inserted` event for each currently stored resource, see [`WatchEvent::resumable`].
+    pub resume_from: Option<WatchRevision>,
+}
+
+impl WatchRequest {
+    pub fn matches(&self, resource: &WatchedResource) -> bool {
+        let kind_matches = self.kinds.is_empty() || self.kinds.contains(&resource.kind());
+
+        let id_matches = match resource.id() {
+            WatchedResourceId::Peer(peer_id) => self.peer_ids.is_empty() || self.peer_ids.contains(&peer_id),
+            WatchedResourceId::Cluster(cluster_id) => self.cluster_ids.is_empty() || self.cluster_ids.contains(&cluster_id),
+        };
+
+        kind_matches && id_matches
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchEventKind {
+    Inserted,
+    Updated,
+    Removed,
+}
+
+#[derive(Debug, Clone)]
+pub struct WatchEvent {
+    pub revision: WatchRevision,
+    pub kind: WatchEventKind,
+    /// The resource after the change, or the last known state of the resource, if it was removed.
+    pub resource: WatchedResource,
+    /// Whether a watch may be resumed from the revision of this event.
+    /// The `Inserted` events for the current resources at the start of a watch share one revision,
+    /// so only the last of them is resumable. Resuming before it was received would skip the remaining resources.
+    pub resumable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchedResource {
+    PeerDescriptor(PeerDescriptor),
+    PeerConnectionState { peer_id: PeerId, state: PeerConnectionState },
+    PeerConfigurationState { peer_id: PeerId, state: EdgePeerConfigurationState },
+    ClusterDescriptor(ClusterDescriptor),
+    ClusterDeployment(ClusterDeployment),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchedResourceId {
+    Peer(PeerId),
+    Cluster(ClusterId),
+}
+
+impl WatchedResource {
+    pub fn kind(&self) -> WatchedResourceKind {
+        match self {
+            WatchedResource::PeerDescriptor(_) => WatchedResourceKind::PeerDescriptor,
+            WatchedResource::PeerConnectionState { .. } => WatchedResourceKind::PeerConnectionState,
+            WatchedResource::PeerConfigurationState { .. } => WatchedResourceKind::PeerConfigurationState,
+            WatchedResource::ClusterDescriptor(_) => WatchedResourceKind::ClusterDescriptor,
+            WatchedResource::ClusterDeployment(_) => WatchedResourceKind::ClusterDeployment,
+        }
+    }
+
+    pub fn id(&self) -> WatchedResourceId {
+        match self {
+            WatchedResource::PeerDescriptor(descriptor) => WatchedResourceId::Peer(descriptor.id),
+            WatchedResource::PeerConnectionState { peer_id, .. } => WatchedResourceId::Peer(*peer_id),
+            WatchedResource::PeerConfigurationState { peer_id, .. } => WatchedResourceId::Peer(*peer_id),
+            WatchedResource::ClusterDescriptor(descriptor) => WatchedResourceId::Cluster(descriptor.id),
+            WatchedResource::ClusterDeployment(deployment) => WatchedResourceId::Cluster(deployment.id),
+        }
+    }
+}
+
 #[cfg(feature = "client")]
 mod client {
     use std::collections::HashSet;
     use std::time::Duration;
-    use crate::carl::observer::{error, WaitForPeersOnlineResponse};
+    use crate::carl::observer::{error, WaitForPeersOnlineResponse, WatchEvent, WatchRequest};
     use crate::proto::services::observer_messaging_broker::observer_messaging_broker_client::ObserverMessagingBrokerClient;
     use opendut_model::peer::PeerId;
     use tonic::codegen::{Body, Bytes, InterceptedService, StdError};
@@ -128,5 +250,23 @@ mod client {
 
             Ok(GrpcDownstream::from(inbound))
         }
+
+        /// Streams the changes of the requested resources.
+        /// The stream ends with an error, if the client cannot keep up with the changes. Resume the watch from the last received revision in that case.
+        pub async fn watch(&mut self, request: WatchRequest) -> Result<GrpcDownstream<WatchEvent>, error::OpenStream> {
+            let proto_request: crate::proto::services::observer_messaging_broker::WatchRequest = request.into();
+            let response = self.inner
+                .watch(proto_request)
+                .await
+                .map_err(|cause| error::OpenStream { message: format!("Error while opening stream: {cause}") })?;
+
+            let inbound = response.into_inner()
+                .map(|result| result.and_then(|element| {
+                    WatchEvent::try_from(element)
+                        .map_err(|cause| tonic::Status::invalid_argument(format!("Error while converting stream message in watch: {cause}")))
+                }));
+
+            Ok(GrpcDownstream::from(inbound))
+        }
     }
 }
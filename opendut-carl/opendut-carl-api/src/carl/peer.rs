@@ -1,8 +1,10 @@
 #[cfg(any(feature = "client", feature = "wasm-client"))]
 pub use client::*;
 use opendut_model::cluster::ClusterId;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::{PeerId, PeerName};
 use opendut_model::peer::state::PeerState;
+use opendut_model::resources::Revision;
 use opendut_model::ShortName;
 use opendut_model::topology::DeviceId;
 use opendut_model::format::format_id_with_optional_name;
@@ -22,6 +24,23 @@ pub enum StorePeerDescriptorError {
         peer_name: PeerName,
         error: IllegalDevicesError
     },
+    #[error("Peer '{peer_name}' <{peer_id}> could not be stored, because it was changed in the meantime (expected revision {expected_revision}, but found revision {actual_revision}). Reload the peer and apply your changes again.")]
+    Conflict {
+        peer_id: PeerId,
+        peer_name: PeerName,
+        expected_revision: Revision,
+        actual_revision: Revision,
+    },
+    #[error(
+        "Peer '{peer_name}' <{peer_id}> cannot be moved out of namespace '{namespace}', because it is still used by the clusters <{clusters}>. Remove the peer from these clusters first.",
+        clusters = cluster_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+    )]
+    ReferencedByClusters {
+        peer_id: PeerId,
+        peer_name: PeerName,
+        namespace: NamespaceName,
+        cluster_ids: Vec<ClusterId>,
+    },
     #[error("Peer '{peer_name}' <{peer_id}> could not be created, due to internal errors:\n  {cause}")]
     Internal {
         peer_id: PeerId,
@@ -102,6 +121,15 @@ pub enum ListPeerStatesError {
     }
 }
 
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ListExecutorStatesError {
+    #[error("An internal error occurred while listing the executor states of peer <{peer_id}>:\n  {cause}")]
+    Internal {
+        peer_id: PeerId,
+        cause: String
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum ListDevicesError {
     #[error("An internal error occurred computing the list of devices:\n  {cause}")]
@@ -127,7 +155,9 @@ mod client {
     use tracing::error;
     use opendut_model::cleo::CleoSetup;
 
+    use opendut_model::namespace::NamespaceName;
     use opendut_model::peer::{PeerDescriptor, PeerId, PeerSetup};
+    use opendut_model::peer::executor::state::ExecutorState;
     use opendut_model::peer::state::PeerState;
     use opendut_model::topology::DeviceDescriptor;
 
@@ -197,9 +227,19 @@ mod client {
         }
 
         pub async fn delete_peer_descriptor(&mut self, peer_id: PeerId) -> Result<PeerId, ClientError<DeletePeerDescriptorError>> {
+            self.delete_peer_descriptor_scoped(peer_id, None).await
+        }
+
+        /// Deletes the peer descriptor, if it belongs to the given namespace.
+        pub async fn delete_peer_descriptor_in_namespace(&mut self, peer_id: PeerId, namespace: NamespaceName) -> Result<PeerId, ClientError<DeletePeerDescriptorError>> {
+            self.delete_peer_descriptor_scoped(peer_id, Some(namespace)).await
+        }
+
+        async fn delete_peer_descriptor_scoped(&mut self, peer_id: PeerId, namespace: Option<NamespaceName>) -> Result<PeerId, ClientError<DeletePeerDescriptorError>> {
 
             let request = tonic::Request::new(peer_manager::DeletePeerDescriptorRequest {
                 peer_id: Some(peer_id.into()),
+                namespace: namespace.map(Into::into),
             });
 
             let response = self.inner.delete_peer_descriptor(request).await?
@@ -218,9 +258,19 @@ mod client {
         }
 
         pub async fn get_peer_descriptor(&mut self, peer_id: PeerId) -> Result<PeerDescriptor, ClientError<GetPeerDescriptorError>> {
+            self.get_peer_descriptor_scoped(peer_id, None).await
+        }
+
+        /// Gets the peer descriptor, if it belongs to the given namespace.
+        pub async fn get_peer_descriptor_in_namespace(&mut self, peer_id: PeerId, namespace: NamespaceName) -> Result<PeerDescriptor, ClientError<GetPeerDescriptorError>> {
+            self.get_peer_descriptor_scoped(peer_id, Some(namespace)).await
+        }
+
+        async fn get_peer_descriptor_scoped(&mut self, peer_id: PeerId, namespace: Option<NamespaceName>) -> Result<PeerDescriptor, ClientError<GetPeerDescriptorError>> {
 
             let request = tonic::Request::new(peer_manager::GetPeerDescriptorRequest {
                 peer_id: Some(peer_id.into()),
+                namespace: namespace.map(Into::into),
             });
 
             let response = self.inner.get_peer_descriptor(request).await?
@@ -239,8 +289,19 @@ mod client {
         }
 
         pub async fn list_peer_descriptors(&mut self) -> Result<Vec<PeerDescriptor>, ClientError<ListPeerDescriptorsError>> {
+            self.list_peer_descriptors_filtered(None).await
+        }
+
+        /// Lists the peer descriptors of the given namespace.
+        pub async fn list_peer_descriptors_in_namespace(&mut self, namespace: NamespaceName) -> Result<Vec<PeerDescriptor>, ClientError<ListPeerDescriptorsError>> {
+            self.list_peer_descriptors_filtered(Some(namespace)).await
+        }
 
-            let request = tonic::Request::new(peer_manager::ListPeerDescriptorsRequest {});
+        async fn list_peer_descriptors_filtered(&mut self, namespace: Option<NamespaceName>) -> Result<Vec<PeerDescriptor>, ClientError<ListPeerDescriptorsError>> {
+
+            let request = tonic::Request::new(peer_manager::ListPeerDescriptorsRequest {
+                namespace: namespace.map(Into::into),
+            });
 
             let response = self.inner.list_peer_descriptors(request).await?
                 .into_inner();
@@ -312,6 +373,29 @@ mod client {
             }
         }
         
+        pub async fn list_executor_states(&mut self, peer_id: PeerId) -> Result<Vec<ExecutorState>, ClientError<ListExecutorStatesError>> {
+
+            let request = tonic::Request::new(peer_manager::ListExecutorStatesRequest {
+                peer_id: Some(peer_id.into()),
+            });
+
+            let response = self.inner.list_executor_states(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                peer_manager::list_executor_states_response::Reply::Failure(failure) => {
+                    let error = ListExecutorStatesError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                peer_manager::list_executor_states_response::Reply::Success(success) => {
+                    let executor_states = success.executor_states.into_iter()
+                        .map(ExecutorState::try_from)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(executor_states)
+                }
+            }
+        }
+
         pub async fn create_peer_setup(&mut self, peer_id: PeerId, user_id: String) -> Result<PeerSetup, CreateSetupError> {
             let request = tonic::Request::new(
                 peer_manager::GeneratePeerSetupRequest {
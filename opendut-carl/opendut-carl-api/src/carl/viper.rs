@@ -3,6 +3,7 @@ pub use client::*;
 
 use opendut_model::cluster::ClusterId;
 use opendut_model::viper::{ViperRunId, ViperSourceId, ViperSourceName};
+use opendut_viper_rt::common::TestSuiteIdentifier;
 use opendut_model::format::{format_id_with_name, format_id_with_optional_name};
 
 
@@ -60,6 +61,25 @@ pub enum ListViperSourceDescriptorsError {
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum InspectViperTestSuiteError {
+    #[error("Test suite source <{source_id}> could not be inspected, because a source with that ID does not exist!")]
+    SourceNotFound {
+        source_id: ViperSourceId,
+    },
+    #[error("Test suite '{suite}' of source <{source_id}> could not be compiled:\n  {cause}")]
+    CompilationFailed {
+        source_id: ViperSourceId,
+        suite: TestSuiteIdentifier,
+        cause: String,
+    },
+    #[error("An internal error occurred inspecting a test suite of source <{source_id}>:\n  {cause}")]
+    Internal {
+        source_id: ViperSourceId,
+        cause: String,
+    }
+}
+
 
 //
 // ViperRunDescriptor
@@ -162,11 +182,70 @@ pub enum ListViperRunDeploymentsError {
 }
 
 
+//
+// ViperRunState
+//
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetViperRunStateError {
+    #[error("A state of the test suite run with ID <{run_id}> could not be found!")]
+    StateNotFound {
+        run_id: ViperRunId
+    },
+    #[error("An internal error occurred searching for the state of the test suite run with ID <{run_id}>:\n  {cause}")]
+    Internal {
+        run_id: ViperRunId,
+        cause: String
+    }
+}
+
+
+//
+// ViperRunResult
+//
+
+#[derive(thiserror::Error, Debug)]
+pub enum DeleteViperRunResultError {
+    #[error("A test suite run result with ID <{run_id}> could not be found!")]
+    ResultNotFound {
+        run_id: ViperRunId
+    },
+    #[error("Test suite run result <{run_id}> could not be deleted, due to internal errors:\n  {cause}")]
+    Internal {
+        run_id: ViperRunId,
+        cause: String
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetViperRunResultError {
+    #[error("A test suite run result with ID <{run_id}> could not be found!")]
+    ResultNotFound {
+        run_id: ViperRunId
+    },
+    #[error("An internal error occurred searching for a test suite run result with ID <{run_id}>:\n  {cause}")]
+    Internal {
+        run_id: ViperRunId,
+        cause: String
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListViperRunResultsError {
+    #[error("An internal error occurred computing the list of test suite run results:\n  {cause}")]
+    Internal {
+        cause: String
+    }
+}
+
+
 #[cfg(any(feature = "client", feature = "wasm-client"))]
 mod client {
     use super::*;
     use tonic::codegen::{Body, Bytes, http, InterceptedService, StdError};
-    use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperSourceDescriptor, ViperSourceId};
+    use opendut_model::namespace::NamespaceName;
+    use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunResult, ViperRunState, ViperSourceDescriptor, ViperSourceId};
+    use opendut_viper_rt::compile::ParameterDescriptor;
     use crate::carl::{extract, ClientError};
     use crate::proto::services::test_manager;
     use crate::proto::services::test_manager::test_manager_client::TestManagerClient;
@@ -277,8 +356,19 @@ mod client {
         }
 
         pub async fn list_viper_source_descriptors(&mut self) -> Result<Vec<ViperSourceDescriptor>, ClientError<ListViperSourceDescriptorsError>> {
+            self.list_viper_source_descriptors_filtered(None).await
+        }
+
+        /// Lists the test suite source descriptors of the given namespace.
+        pub async fn list_viper_source_descriptors_in_namespace(&mut self, namespace: NamespaceName) -> Result<Vec<ViperSourceDescriptor>, ClientError<ListViperSourceDescriptorsError>> {
+            self.list_viper_source_descriptors_filtered(Some(namespace)).await
+        }
 
-            let request = tonic::Request::new(test_manager::ListViperSourceDescriptorsRequest {});
+        async fn list_viper_source_descriptors_filtered(&mut self, namespace: Option<NamespaceName>) -> Result<Vec<ViperSourceDescriptor>, ClientError<ListViperSourceDescriptorsError>> {
+
+            let request = tonic::Request::new(test_manager::ListViperSourceDescriptorsRequest {
+                namespace: namespace.map(Into::into),
+            });
 
             let response = self.inner.list_viper_source_descriptors(request).await?
                 .into_inner();
@@ -297,6 +387,31 @@ mod client {
             }
         }
 
+        /// Compiles the given test suite of a source in CARL and returns the parameters declared by the suite.
+        pub async fn inspect_viper_test_suite(&mut self, source_id: ViperSourceId, suite: TestSuiteIdentifier) -> Result<Vec<ParameterDescriptor>, ClientError<InspectViperTestSuiteError>> {
+
+            let request = tonic::Request::new(test_manager::InspectViperTestSuiteRequest {
+                source_id: Some(source_id.into()),
+                suite: Some(suite.into()),
+            });
+
+            let response = self.inner.inspect_viper_test_suite(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::inspect_viper_test_suite_response::Reply::Failure(failure) => {
+                    let error = InspectViperTestSuiteError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::inspect_viper_test_suite_response::Reply::Success(success) => {
+                    Ok(success.parameters.into_iter()
+                        .map(ParameterDescriptor::try_from)
+                        .collect::<Result<Vec<_>, _>>()?
+                    )
+                }
+            }
+        }
+
 
         pub async fn store_viper_run_descriptor(&mut self, descriptor: ViperRunDescriptor) -> Result<ViperRunId, ClientError<StoreViperRunDescriptorError>> {
 
@@ -363,8 +478,19 @@ mod client {
         }
 
         pub async fn list_viper_run_descriptors(&mut self) -> Result<Vec<ViperRunDescriptor>, ClientError<ListViperRunDescriptorsError>> {
+            self.list_viper_run_descriptors_filtered(None).await
+        }
+
+        /// Lists the test suite run descriptors of the given namespace.
+        pub async fn list_viper_run_descriptors_in_namespace(&mut self, namespace: NamespaceName) -> Result<Vec<ViperRunDescriptor>, ClientError<ListViperRunDescriptorsError>> {
+            self.list_viper_run_descriptors_filtered(Some(namespace)).await
+        }
+
+        async fn list_viper_run_descriptors_filtered(&mut self, namespace: Option<NamespaceName>) -> Result<Vec<ViperRunDescriptor>, ClientError<ListViperRunDescriptorsError>> {
 
-            let request = tonic::Request::new(test_manager::ListViperRunDescriptorsRequest {});
+            let request = tonic::Request::new(test_manager::ListViperRunDescriptorsRequest {
+                namespace: namespace.map(Into::into),
+            });
 
             let response = self.inner.list_viper_run_descriptors(request).await?
                 .into_inner();
@@ -382,5 +508,177 @@ mod client {
                 }
             }
         }
+
+
+        pub async fn store_viper_run_deployment(&mut self, deployment: ViperRunDeployment) -> Result<ViperRunId, ClientError<StoreViperRunDeploymentError>> {
+
+            let request = tonic::Request::new(test_manager::StoreViperRunDeploymentRequest {
+                run: Some(deployment.into()),
+            });
+
+            let response = self.inner.store_viper_run_deployment(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::store_viper_run_deployment_response::Reply::Failure(failure) => {
+                    let error = StoreViperRunDeploymentError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::store_viper_run_deployment_response::Reply::Success(success) => {
+                    let run_id = extract!(success.run_id)?;
+                    Ok(run_id)
+                }
+            }
+        }
+
+        pub async fn delete_viper_run_deployment(&mut self, run_id: ViperRunId) -> Result<ViperRunId, ClientError<DeleteViperRunDeploymentError>> {
+
+            let request = tonic::Request::new(test_manager::DeleteViperRunDeploymentRequest {
+                run_id: Some(run_id.into()),
+            });
+
+            let response = self.inner.delete_viper_run_deployment(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::delete_viper_run_deployment_response::Reply::Failure(failure) => {
+                    let error = DeleteViperRunDeploymentError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::delete_viper_run_deployment_response::Reply::Success(success) => {
+                    let run_id = extract!(success.run_id)?;
+                    Ok(run_id)
+                }
+            }
+        }
+
+        pub async fn get_viper_run_deployment(&mut self, run_id: ViperRunId) -> Result<ViperRunDeployment, ClientError<GetViperRunDeploymentError>> {
+
+            let request = tonic::Request::new(test_manager::GetViperRunDeploymentRequest {
+                run_id: Some(run_id.into()),
+            });
+
+            let response = self.inner.get_viper_run_deployment(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::get_viper_run_deployment_response::Reply::Failure(failure) => {
+                    let error = GetViperRunDeploymentError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::get_viper_run_deployment_response::Reply::Success(success) => {
+                    let deployment = extract!(success.deployment)?;
+                    Ok(deployment)
+                }
+            }
+        }
+
+        pub async fn list_viper_run_deployments(&mut self) -> Result<Vec<ViperRunDeployment>, ClientError<ListViperRunDeploymentsError>> {
+
+            let request = tonic::Request::new(test_manager::ListViperRunDeploymentsRequest {});
+
+            let response = self.inner.list_viper_run_deployments(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::list_viper_run_deployments_response::Reply::Failure(failure) => {
+                    let error = ListViperRunDeploymentsError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::list_viper_run_deployments_response::Reply::Success(success) => {
+                    Ok(success.runs.into_iter()
+                        .map(ViperRunDeployment::try_from)
+                        .collect::<Result<Vec<_>, _>>()?
+                    )
+                }
+            }
+        }
+
+
+        /// Returns the state of a deployed run, as last reported by the executing peer.
+        pub async fn get_viper_run_state(&mut self, run_id: ViperRunId) -> Result<ViperRunState, ClientError<GetViperRunStateError>> {
+
+            let request = tonic::Request::new(test_manager::GetViperRunStateRequest {
+                run_id: Some(run_id.into()),
+            });
+
+            let response = self.inner.get_viper_run_state(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::get_viper_run_state_response::Reply::Failure(failure) => {
+                    let error = GetViperRunStateError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::get_viper_run_state_response::Reply::Success(success) => {
+                    let state = extract!(success.state)?;
+                    Ok(state)
+                }
+            }
+        }
+
+
+        pub async fn delete_viper_run_result(&mut self, run_id: ViperRunId) -> Result<ViperRunId, ClientError<DeleteViperRunResultError>> {
+
+            let request = tonic::Request::new(test_manager::DeleteViperRunResultRequest {
+                run_id: Some(run_id.into()),
+            });
+
+            let response = self.inner.delete_viper_run_result(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::delete_viper_run_result_response::Reply::Failure(failure) => {
+                    let error = DeleteViperRunResultError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::delete_viper_run_result_response::Reply::Success(success) => {
+                    let run_id = extract!(success.run_id)?;
+                    Ok(run_id)
+                }
+            }
+        }
+
+        pub async fn get_viper_run_result(&mut self, run_id: ViperRunId) -> Result<ViperRunResult, ClientError<GetViperRunResultError>> {
+
+            let request = tonic::Request::new(test_manager::GetViperRunResultRequest {
+                run_id: Some(run_id.into()),
+            });
+
+            let response = self.inner.get_viper_run_result(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::get_viper_run_result_response::Reply::Failure(failure) => {
+                    let error = GetViperRunResultError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::get_viper_run_result_response::Reply::Success(success) => {
+                    let result = extract!(success.result)?;
+                    Ok(result)
+                }
+            }
+        }
+
+        pub async fn list_viper_run_results(&mut self) -> Result<Vec<ViperRunResult>, ClientError<ListViperRunResultsError>> {
+
+            let request = tonic::Request::new(test_manager::ListViperRunResultsRequest {});
+
+            let response = self.inner.list_viper_run_results(request).await?
+                .into_inner();
+
+            match extract!(response.reply)? {
+                test_manager::list_viper_run_results_response::Reply::Failure(failure) => {
+                    let error = ListViperRunResultsError::try_from(failure)?;
+                    Err(ClientError::UsageError(error))
+                }
+                test_manager::list_viper_run_results_response::Reply::Success(success) => {
+                    Ok(success.results.into_iter()
+                        .map(ViperRunResult::try_from)
+                        .collect::<Result<Vec<_>, _>>()?
+                    )
+                }
+            }
+        }
     }
 }
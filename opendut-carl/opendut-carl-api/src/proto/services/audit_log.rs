@@ -0,0 +1,101 @@
+use std::time::SystemTime;
+use crate::carl;
+use crate::carl::audit::ListAuditEventsError;
+use opendut_util::conversion;
+use opendut_util::proto::{ConversionError, ConversionErrorBuilder, ConversionResult};
+
+tonic::include_proto!("opendut.carl.services.audit_log");
+
+conversion! {
+    type Model = carl::audit::ListAuditEventsRequest;
+    type Proto = ListAuditEventsRequest;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            since: value.since.map(Into::into),
+            page_size: value.page_size,
+            page_token: value.page_token.map(Into::into),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let since = value.since
+            .map(SystemTime::try_from)
+            .transpose()
+            .map_err(|error| ErrorBuilder::message(error.to_string()))?;
+
+        Ok(Model {
+            since,
+            page_size: value.page_size,
+            page_token: value.page_token
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+conversion! {
+    type Model = carl::audit::AuditEventsPage;
+    type Proto = ListAuditEventsSuccess;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            events: value.events.into_iter()
+                .map(Into::into)
+                .collect(),
+            next_page_token: value.next_page_token.map(Into::into),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Ok(Model {
+            events: value.events.into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            next_page_token: value.next_page_token
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+impl From<ListAuditEventsError> for ListAuditEventsFailure {
+    fn from(error: ListAuditEventsError) -> Self {
+        let proto_error = match error {
+            ListAuditEventsError::UnknownPageToken { page_token } => {
+                list_audit_events_failure::Error::UnknownPageToken(ListAuditEventsFailureUnknownPageToken {
+                    page_token: Some(page_token.into()),
+                })
+            }
+            ListAuditEventsError::Internal { cause } => {
+                list_audit_events_failure::Error::Internal(ListAuditEventsFailureInternal {
+                    cause
+                })
+            }
+        };
+        ListAuditEventsFailure {
+            error: Some(proto_error)
+        }
+    }
+}
+
+impl TryFrom<ListAuditEventsFailure> for ListAuditEventsError {
+    type Error = ConversionError;
+    fn try_from(failure: ListAuditEventsFailure) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<ListAuditEventsFailure, ListAuditEventsError>;
+        let error = failure.error
+            .ok_or_else(|| ErrorBuilder::field_not_set("error"))?;
+        let error = match error {
+            list_audit_events_failure::Error::UnknownPageToken(ListAuditEventsFailureUnknownPageToken { page_token }) => {
+                let page_token = page_token
+                    .ok_or_else(|| ErrorBuilder::field_not_set("page_token"))?
+                    .try_into()?;
+                ListAuditEventsError::UnknownPageToken { page_token }
+            }
+            list_audit_events_failure::Error::Internal(ListAuditEventsFailureInternal { cause }) => {
+                ListAuditEventsError::Internal { cause }
+            }
+        };
+        Ok(error)
+    }
+}
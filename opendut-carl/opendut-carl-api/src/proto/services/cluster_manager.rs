@@ -2,6 +2,9 @@ use crate::carl;
 use crate::carl::cluster::{CreateClusterDescriptorError, DeleteClusterDeploymentError, DeleteClusterDescriptorError, StoreClusterDeploymentError};
 use opendut_model::cluster::state::ClusterState;
 use opendut_model::cluster::{ClusterId, ClusterName};
+use opendut_model::namespace::NamespaceName;
+use opendut_model::peer::PeerId;
+use opendut_model::topology::DeviceId;
 use opendut_model::proto;
 use std::collections::HashMap;
 use opendut_util::conversion;
@@ -59,6 +62,30 @@ conversion! {
 impl From<CreateClusterDescriptorError> for CreateClusterDescriptorFailure {
     fn from(error: CreateClusterDescriptorError) -> Self {
         let proto_error = match error {
+            CreateClusterDescriptorError::Conflict { cluster_id, cluster_name, expected_revision, actual_revision } => {
+                create_cluster_descriptor_failure::Error::Conflict(CreateClusterDescriptorFailureConflict {
+                    cluster_id: Some(cluster_id.into()),
+                    cluster_name: Some(cluster_name.into()),
+                    expected_revision: expected_revision.into(),
+                    actual_revision: actual_revision.into(),
+                })
+            }
+            CreateClusterDescriptorError::ForeignNamespacePeers { cluster_id, cluster_name, namespace, peer_ids } => {
+                create_cluster_descriptor_failure::Error::ForeignNamespacePeers(CreateClusterDescriptorFailureForeignNamespacePeers {
+                    cluster_id: Some(cluster_id.into()),
+                    cluster_name: Some(cluster_name.into()),
+                    namespace: Some(namespace.into()),
+                    peer_ids: peer_ids.into_iter().map(Into::into).collect(),
+                })
+            }
+            CreateClusterDescriptorError::IllegalCanRules { cluster_id, cluster_name, device_id, cause } => {
+                create_cluster_descriptor_failure::Error::IllegalCanRules(CreateClusterDescriptorFailureIllegalCanRules {
+                    cluster_id: Some(cluster_id.into()),
+                    cluster_name: Some(cluster_name.into()),
+                    device_id: Some(device_id.into()),
+                    cause,
+                })
+            }
             CreateClusterDescriptorError::Internal { cluster_id, cluster_name, cause } => {
                 create_cluster_descriptor_failure::Error::Internal(CreateClusterDescriptorFailureInternal {
                     cluster_id: Some(cluster_id.into()),
@@ -80,6 +107,15 @@ impl TryFrom<CreateClusterDescriptorFailure> for CreateClusterDescriptorError {
         let error = failure.error
             .ok_or_else(|| ErrorBuilder::field_not_set("error"))?;
         let error = match error {
+            create_cluster_descriptor_failure::Error::Conflict(error) => {
+                error.try_into()?
+            }
+            create_cluster_descriptor_failure::Error::ForeignNamespacePeers(error) => {
+                error.try_into()?
+            }
+            create_cluster_descriptor_failure::Error::IllegalCanRules(error) => {
+                error.try_into()?
+            }
             create_cluster_descriptor_failure::Error::Internal(error) => {
                 error.try_into()?
             }
@@ -88,6 +124,67 @@ impl TryFrom<CreateClusterDescriptorFailure> for CreateClusterDescriptorError {
     }
 }
 
+impl TryFrom<CreateClusterDescriptorFailureConflict> for CreateClusterDescriptorError {
+    type Error = ConversionError;
+    fn try_from(failure: CreateClusterDescriptorFailureConflict) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<CreateClusterDescriptorFailureConflict, CreateClusterDescriptorError>;
+        let cluster_id: ClusterId = failure.cluster_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_id"))?
+            .try_into()?;
+        let cluster_name: ClusterName = failure.cluster_name
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_name"))?
+            .try_into()?;
+        Ok(CreateClusterDescriptorError::Conflict {
+            cluster_id,
+            cluster_name,
+            expected_revision: failure.expected_revision.into(),
+            actual_revision: failure.actual_revision.into(),
+        })
+    }
+}
+
+impl TryFrom<CreateClusterDescriptorFailureForeignNamespacePeers> for CreateClusterDescriptorError {
+    type Error = ConversionError;
+    fn try_from(failure: CreateClusterDescriptorFailureForeignNamespacePeers) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<CreateClusterDescriptorFailureForeignNamespacePeers, CreateClusterDescriptorError>;
+        let cluster_id: ClusterId = failure.cluster_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_id"))?
+            .try_into()?;
+        let cluster_name: ClusterName = failure.cluster_name
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_name"))?
+            .try_into()?;
+        let namespace: NamespaceName = failure.namespace
+            .ok_or_else(|| ErrorBuilder::field_not_set("namespace"))?
+            .try_into()?;
+        let peer_ids = failure.peer_ids.into_iter()
+            .map(PeerId::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(CreateClusterDescriptorError::ForeignNamespacePeers {
+            cluster_id,
+            cluster_name,
+            namespace,
+            peer_ids,
+        })
+    }
+}
+
+impl TryFrom<CreateClusterDescriptorFailureIllegalCanRules> for CreateClusterDescriptorError {
+    type Error = ConversionError;
+    fn try_from(failure: CreateClusterDescriptorFailureIllegalCanRules) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<CreateClusterDescriptorFailureIllegalCanRules, CreateClusterDescriptorError>;
+        let cluster_id: ClusterId = failure.cluster_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_id"))?
+            .try_into()?;
+        let cluster_name: ClusterName = failure.cluster_name
+            .ok_or_else(|| ErrorBuilder::field_not_set("cluster_name"))?
+            .try_into()?;
+        let device_id: DeviceId = failure.device_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("device_id"))?
+            .try_into()?;
+        Ok(CreateClusterDescriptorError::IllegalCanRules { cluster_id, cluster_name, device_id, cause: failure.cause })
+    }
+}
+
 impl TryFrom<CreateClusterDescriptorFailureInternal> for CreateClusterDescriptorError {
     type Error = ConversionError;
     fn try_from(failure: CreateClusterDescriptorFailureInternal) -> Result<Self, Self::Error> {
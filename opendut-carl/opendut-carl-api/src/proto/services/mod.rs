@@ -1,3 +1,4 @@
+pub mod audit_log;
 pub mod cluster_manager;
 pub mod metadata_provider;
 pub mod peer_manager;
@@ -5,3 +6,28 @@ pub mod peer_messaging_broker;
 pub mod observer_messaging_broker;
 #[cfg(feature="viper")]
 pub mod test_manager;
+
+/// Encoded `FileDescriptorSet` of the CARL services and the messages they use.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/opendut_carl_services.bin"));
+
+/// Returns the paths of all gRPC methods of the given services, e.g. `/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor`.
+/// The services are identified by their fully qualified name, as in `tonic::server::NamedService::NAME`.
+pub fn method_paths(service_names: &[&str]) -> Vec<String> {
+    use prost::Message;
+
+    let file_descriptor_set = prost_types::FileDescriptorSet::decode(FILE_DESCRIPTOR_SET)
+        .expect("FileDescriptorSet of the CARL services should be valid, as it is generated at build time.");
+
+    file_descriptor_set.file.iter()
+        .flat_map(|file| {
+            file.service.iter().map(move |service| (file.package(), service))
+        })
+        .filter_map(|(package, service)| {
+            let service_name = format!("{package}.{}", service.name());
+            service_names.contains(&service_name.as_str())
+                .then(|| service.method.iter().map(move |method| format!("/{service_name}/{}", method.name())))
+        })
+        .flatten()
+        .collect()
+}
+
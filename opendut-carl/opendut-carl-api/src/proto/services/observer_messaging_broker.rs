@@ -96,3 +96,164 @@ conversion! {
         })
     }
 }
+
+conversion! {
+    type Model = carl::observer::WatchRequest;
+    type Proto = WatchRequest;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            kinds: value.kinds.into_iter().map(WatchedResourceKind::from).collect(),
+            peer_ids: value.peer_ids.into_iter().map(Into::into).collect(),
+            cluster_ids: value.cluster_ids.into_iter().map(Into::into).collect(),
+            resume_from: value.resume_from.map(Into::into),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let kinds = value.kinds.into_iter()
+            .map(carl::observer::WatchedResourceKind::try_from)
+            .collect::<Result<_, _>>()?;
+        let peer_ids = value.peer_ids.into_iter()
+            .map(opendut_model::peer::PeerId::try_from)
+            .collect::<Result<_, _>>()?;
+        let cluster_ids = value.cluster_ids.into_iter()
+            .map(opendut_model::cluster::ClusterId::try_from)
+            .collect::<Result<_, _>>()?;
+
+        let resume_from = value.resume_from
+            .map(carl::observer::WatchRevision::try_from)
+            .transpose()?;
+
+        Ok(Model {
+            kinds,
+            peer_ids,
+            cluster_ids,
+            resume_from,
+        })
+    }
+}
+
+conversion! {
+    type Model = carl::observer::WatchRevision;
+    type Proto = WatchRevision;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            epoch: Some(value.epoch.into()),
+            number: value.number,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let epoch = extract!(value.epoch)?.into();
+
+        Ok(Model {
+            epoch,
+            number: value.number,
+        })
+    }
+}
+
+conversion! {
+    type Model = carl::observer::WatchedResourceKind;
+    type Proto = WatchedResourceKind;
+
+    fn from(value: Model) -> Proto {
+        let inner = match value {
+            Model::PeerDescriptor => watched_resource_kind::Inner::PeerDescriptor(WatchedResourceKindPeerDescriptor {}),
+            Model::PeerConnectionState => watched_resource_kind::Inner::PeerConnectionState(WatchedResourceKindPeerConnectionState {}),
+            Model::PeerConfigurationState => watched_resource_kind::Inner::PeerConfigurationState(WatchedResourceKindPeerConfigurationState {}),
+            Model::ClusterDescriptor => watched_resource_kind::Inner::ClusterDescriptor(WatchedResourceKindClusterDescriptor {}),
+            Model::ClusterDeployment => watched_resource_kind::Inner::ClusterDeployment(WatchedResourceKindClusterDeployment {}),
+        };
+        Proto { inner: Some(inner) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let kind = match extract!(value.inner)? {
+            watched_resource_kind::Inner::PeerDescriptor(_) => Model::PeerDescriptor,
+            watched_resource_kind::Inner::PeerConnectionState(_) => Model::PeerConnectionState,
+            watched_resource_kind::Inner::PeerConfigurationState(_) => Model::PeerConfigurationState,
+            watched_resource_kind::Inner::ClusterDescriptor(_) => Model::ClusterDescriptor,
+            watched_resource_kind::Inner::ClusterDeployment(_) => Model::ClusterDeployment,
+        };
+        Ok(kind)
+    }
+}
+
+conversion! {
+    type Model = carl::observer::WatchEvent;
+    type Proto = WatchResponse;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value.kind {
+            carl::observer::WatchEventKind::Inserted => watch_event_kind::Inner::Inserted(WatchEventKindInserted {}),
+            carl::observer::WatchEventKind::Updated => watch_event_kind::Inner::Updated(WatchEventKindUpdated {}),
+            carl::observer::WatchEventKind::Removed => watch_event_kind::Inner::Removed(WatchEventKindRemoved {}),
+        };
+        Proto {
+            revision: Some(value.revision.into()),
+            kind: Some(WatchEventKind { inner: Some(kind) }),
+            resource: Some(value.resource.into()),
+            resumable: value.resumable,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let kind = extract!(value.kind)?;
+        let kind = match extract!(kind.inner)? {
+            watch_event_kind::Inner::Inserted(_) => carl::observer::WatchEventKind::Inserted,
+            watch_event_kind::Inner::Updated(_) => carl::observer::WatchEventKind::Updated,
+            watch_event_kind::Inner::Removed(_) => carl::observer::WatchEventKind::Removed,
+        };
+        let resource = extract!(value.resource)?.try_into()?;
+        let revision = extract!(value.revision)?.try_into()?;
+
+        Ok(Model {
+            revision,
+            kind,
+            resource,
+            resumable: value.resumable,
+        })
+    }
+}
+
+conversion! {
+    type Model = carl::observer::WatchedResource;
+    type Proto = WatchedResource;
+
+    fn from(value: Model) -> Proto {
+        let inner = match value {
+            Model::PeerDescriptor(descriptor) => watched_resource::Inner::PeerDescriptor(descriptor.into()),
+            Model::PeerConnectionState { peer_id, state } => watched_resource::Inner::PeerConnectionState(WatchedPeerConnectionState {
+                peer_id: Some(peer_id.into()),
+                state: Some(state.into()),
+            }),
+            Model::PeerConfigurationState { peer_id, state } => watched_resource::Inner::PeerConfigurationState(WatchedPeerConfigurationState {
+                peer_id: Some(peer_id.into()),
+                state: Some(state.into()),
+            }),
+            Model::ClusterDescriptor(descriptor) => watched_resource::Inner::ClusterDescriptor(descriptor.into()),
+            Model::ClusterDeployment(deployment) => watched_resource::Inner::ClusterDeployment(deployment.into()),
+        };
+        Proto { inner: Some(inner) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let resource = match extract!(value.inner)? {
+            watched_resource::Inner::PeerDescriptor(descriptor) => Model::PeerDescriptor(descriptor.try_into()?),
+            watched_resource::Inner::PeerConnectionState(WatchedPeerConnectionState { peer_id, state }) => Model::PeerConnectionState {
+                peer_id: extract!(peer_id)?.try_into()?,
+                state: extract!(state)?.try_into()?,
+            },
+            watched_resource::Inner::PeerConfigurationState(WatchedPeerConfigurationState { peer_id, state }) => Model::PeerConfigurationState {
+                peer_id: extract!(peer_id)?.try_into()?,
+                state: extract!(state)?.try_into()?,
+            },
+            watched_resource::Inner::ClusterDescriptor(descriptor) => Model::ClusterDescriptor(descriptor.try_into()?),
+            watched_resource::Inner::ClusterDeployment(deployment) => Model::ClusterDeployment(deployment.try_into()?),
+        };
+        Ok(resource)
+    }
+}
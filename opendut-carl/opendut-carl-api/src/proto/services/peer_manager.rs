@@ -1,9 +1,11 @@
+use opendut_model::cluster::ClusterId;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::state::PeerState;
 use opendut_model::peer::{PeerId, PeerName};
 use opendut_model::topology::DeviceId;
 use opendut_util::conversion;
 use opendut_util::proto::{ConversionError, ConversionErrorBuilder, ConversionResult};
-use crate::carl::peer::{DeletePeerDescriptorError, GetPeerDescriptorError, GetPeerStateError, ListPeerDescriptorsError, ListPeerStatesError, StorePeerDescriptorError};
+use crate::carl::peer::{DeletePeerDescriptorError, GetPeerDescriptorError, GetPeerStateError, ListExecutorStatesError, ListPeerDescriptorsError, ListPeerStatesError, StorePeerDescriptorError};
 
 tonic::include_proto!("opendut.carl.services.peer_manager");
 
@@ -25,6 +27,22 @@ impl From<StorePeerDescriptorError> for StorePeerDescriptorFailure {
                     error: Some(error.into()),
                 })
             }
+            StorePeerDescriptorError::Conflict { peer_id, peer_name, expected_revision, actual_revision } => {
+                store_peer_descriptor_failure::Error::Conflict(StorePeerDescriptorFailureConflict {
+                    peer_id: Some(peer_id.into()),
+                    peer_name: Some(peer_name.into()),
+                    expected_revision: expected_revision.into(),
+                    actual_revision: actual_revision.into(),
+                })
+            }
+            StorePeerDescriptorError::ReferencedByClusters { peer_id, peer_name, namespace, cluster_ids } => {
+                store_peer_descriptor_failure::Error::ReferencedByClusters(StorePeerDescriptorFailureReferencedByClusters {
+                    peer_id: Some(peer_id.into()),
+                    peer_name: Some(peer_name.into()),
+                    namespace: Some(namespace.into()),
+                    cluster_ids: cluster_ids.into_iter().map(Into::into).collect(),
+                })
+            }
             StorePeerDescriptorError::Internal { peer_id, peer_name, cause } => {
                 store_peer_descriptor_failure::Error::Internal(StorePeerDescriptorFailureInternal {
                     peer_id: Some(peer_id.into()),
@@ -52,6 +70,12 @@ impl TryFrom<StorePeerDescriptorFailure> for StorePeerDescriptorError {
             store_peer_descriptor_failure::Error::IllegalDevices(error) => {
                 error.try_into()?
             }
+            store_peer_descriptor_failure::Error::Conflict(error) => {
+                error.try_into()?
+            }
+            store_peer_descriptor_failure::Error::ReferencedByClusters(error) => {
+                error.try_into()?
+            }
             store_peer_descriptor_failure::Error::Internal(error) => {
                 error.try_into()?
             }
@@ -97,6 +121,45 @@ impl TryFrom<StorePeerDescriptorFailureIllegalDevices> for StorePeerDescriptorEr
     }
 }
 
+impl TryFrom<StorePeerDescriptorFailureConflict> for StorePeerDescriptorError {
+    type Error = ConversionError;
+    fn try_from(failure: StorePeerDescriptorFailureConflict) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<StorePeerDescriptorFailureConflict, StorePeerDescriptorError>;
+        let peer_id: PeerId = failure.peer_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("peer_id"))?
+            .try_into()?;
+        let peer_name: PeerName = failure.peer_name
+            .ok_or_else(|| ErrorBuilder::field_not_set("peer_name"))?
+            .try_into()?;
+        Ok(StorePeerDescriptorError::Conflict {
+            peer_id,
+            peer_name,
+            expected_revision: failure.expected_revision.into(),
+            actual_revision: failure.actual_revision.into(),
+        })
+    }
+}
+
+impl TryFrom<StorePeerDescriptorFailureReferencedByClusters> for StorePeerDescriptorError {
+    type Error = ConversionError;
+    fn try_from(failure: StorePeerDescriptorFailureReferencedByClusters) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<StorePeerDescriptorFailureReferencedByClusters, StorePeerDescriptorError>;
+        let peer_id: PeerId = failure.peer_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("peer_id"))?
+            .try_into()?;
+        let peer_name: PeerName = failure.peer_name
+            .ok_or_else(|| ErrorBuilder::field_not_set("peer_name"))?
+            .try_into()?;
+        let namespace: NamespaceName = failure.namespace
+            .ok_or_else(|| ErrorBuilder::field_not_set("namespace"))?
+            .try_into()?;
+        let cluster_ids = failure.cluster_ids.into_iter()
+            .map(ClusterId::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(StorePeerDescriptorError::ReferencedByClusters { peer_id, peer_name, namespace, cluster_ids })
+    }
+}
+
 impl TryFrom<StorePeerDescriptorFailureInternal> for StorePeerDescriptorError {
     type Error = ConversionError;
     fn try_from(failure: StorePeerDescriptorFailureInternal) -> Result<Self, Self::Error> {
@@ -433,3 +496,45 @@ impl TryFrom<ListPeerStatesFailureInternal> for ListPeerStatesError {
         Ok(ListPeerStatesError::Internal{ cause: failure.cause})
     }
 }
+
+impl From<ListExecutorStatesError> for ListExecutorStatesFailure {
+    fn from(error: ListExecutorStatesError) -> Self {
+        let proto_error = match error {
+            ListExecutorStatesError::Internal { peer_id, cause } => {
+                list_executor_states_failure::Error::Internal(ListExecutorStatesFailureInternal {
+                    peer_id: Some(peer_id.into()),
+                    cause
+                })
+            }
+        };
+        ListExecutorStatesFailure {
+            error: Some(proto_error)
+        }
+    }
+}
+
+impl TryFrom<ListExecutorStatesFailure> for ListExecutorStatesError {
+    type Error = ConversionError;
+    fn try_from(failure: ListExecutorStatesFailure) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<ListExecutorStatesFailure, ListExecutorStatesError>;
+        let error = failure.error
+            .ok_or_else(|| ErrorBuilder::field_not_set("error"))?;
+        let error = match error {
+            list_executor_states_failure::Error::Internal(error) => {
+                error.try_into()?
+            }
+        };
+        Ok(error)
+    }
+}
+
+impl TryFrom<ListExecutorStatesFailureInternal> for ListExecutorStatesError {
+    type Error = ConversionError;
+    fn try_from(failure: ListExecutorStatesFailureInternal) -> Result<Self, Self::Error> {
+        type ErrorBuilder = ConversionErrorBuilder<ListExecutorStatesFailureInternal, ListExecutorStatesError>;
+        let peer_id: PeerId = failure.peer_id
+            .ok_or_else(|| ErrorBuilder::field_not_set("peer_id"))?
+            .try_into()?;
+        Ok(ListExecutorStatesError::Internal { peer_id, cause: failure.cause })
+    }
+}
@@ -17,6 +17,17 @@ conversion! {
                 let state = opendut_model::proto::peer::configuration::api::EdgePeerConfigurationState::from(edge_peer_config_state);
                 upstream::Message::EdgePeerConfigurationState(state)
             }
+            crate::carl::broker::UpstreamMessagePayload::ExecutorStatusUpdate(update) => {
+                upstream::Message::ExecutorStatusUpdate(update.into())
+            }
+            #[cfg(feature = "viper")]
+            crate::carl::broker::UpstreamMessagePayload::ViperRunUpdate(update) => {
+                upstream::Message::ViperRunUpdate(update.into())
+            }
+            #[cfg(feature = "viper")]
+            crate::carl::broker::UpstreamMessagePayload::ViperRunReport(report) => {
+                upstream::Message::ViperRunReport(ViperRunReport::from(*report))
+            }
         };
 
         Upstream { context, message: Some(message) }
@@ -36,6 +47,30 @@ conversion! {
                     state.try_into()?
                 )
             },
+            upstream::Message::ExecutorStatusUpdate(update) => {
+                crate::carl::broker::UpstreamMessagePayload::ExecutorStatusUpdate(
+                    update.try_into()?
+                )
+            },
+            #[cfg(feature = "viper")]
+            upstream::Message::ViperRunUpdate(update) => {
+                crate::carl::broker::UpstreamMessagePayload::ViperRunUpdate(
+                    update.try_into()?
+                )
+            },
+            #[cfg(not(feature = "viper"))]
+            upstream::Message::ViperRunUpdate(_) => {
+                return Err(ErrorBuilder::message("Received an update for a VIPER run, but VIPER support is not enabled."));
+            },
+            #[cfg(feature = "viper")]
+            upstream::Message::ViperRunReport(report) => {
+                let viper_run_report: crate::carl::broker::ViperRunReport = report.try_into()?;
+                crate::carl::broker::UpstreamMessagePayload::ViperRunReport(Box::new(viper_run_report))
+            },
+            #[cfg(not(feature = "viper"))]
+            upstream::Message::ViperRunReport(_) => {
+                return Err(ErrorBuilder::message("Received a report for a VIPER run, but VIPER support is not enabled."));
+            },
         };
 
         Ok(Model { context, payload })
@@ -81,6 +116,10 @@ conversion! {
             crate::carl::broker::DownstreamMessagePayload::DisconnectNotice => {
                 downstream::Message::DisconnectNotice(DisconnectNotice { })
             }
+            #[cfg(feature = "viper")]
+            crate::carl::broker::DownstreamMessagePayload::ExecuteViperRun(execute) => {
+                downstream::Message::ExecuteViperRun(ExecuteViperRun::from(*execute))
+            }
         };
 
         Downstream { context, message: Some(message) }
@@ -102,6 +141,15 @@ conversion! {
             downstream::Message::DisconnectNotice(_) => {
                 crate::carl::broker::DownstreamMessagePayload::DisconnectNotice
             }
+            #[cfg(feature = "viper")]
+            downstream::Message::ExecuteViperRun(execute) => {
+                let execute_viper_run: crate::carl::broker::ExecuteViperRun = execute.try_into()?;
+                crate::carl::broker::DownstreamMessagePayload::ExecuteViperRun(Box::new(execute_viper_run))
+            }
+            #[cfg(not(feature = "viper"))]
+            downstream::Message::ExecuteViperRun(_) => {
+                return Err(ErrorBuilder::message("Received instruction to execute a VIPER run, but VIPER support is not enabled."));
+            }
         };
 
         Ok(Model { context, payload })
@@ -126,6 +174,81 @@ conversion! {
     }
 }
 
+#[cfg(feature = "viper")]
+conversion! {
+    type Model = crate::carl::broker::ExecuteViperRun;
+    type Proto = ExecuteViperRun;
+
+    fn from(value: Model) -> Proto {
+        ExecuteViperRun {
+            run: Some(value.run.into()),
+            source: Some(value.source.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let run = extract!(value.run)?.try_into()?;
+        let source = extract!(value.source)?.try_into()?;
+        Ok(Model { run, source })
+    }
+}
+
+conversion! {
+    type Model = crate::carl::broker::ExecutorStatusUpdate;
+    type Proto = ExecutorStatusUpdate;
+
+    fn from(value: Model) -> Proto {
+        ExecutorStatusUpdate {
+            id: Some(value.id.into()),
+            status: Some(value.status.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?.try_into()?;
+        let status = extract!(value.status)?.try_into()?;
+        Ok(Model { id, status })
+    }
+}
+
+#[cfg(feature = "viper")]
+conversion! {
+    type Model = crate::carl::broker::ViperRunUpdate;
+    type Proto = ViperRunUpdate;
+
+    fn from(value: Model) -> Proto {
+        ViperRunUpdate {
+            id: Some(value.id.into()),
+            status: Some(value.status.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?.try_into()?;
+        let status = extract!(value.status)?.try_into()?;
+        Ok(Model { id, status })
+    }
+}
+
+#[cfg(feature = "viper")]
+conversion! {
+    type Model = crate::carl::broker::ViperRunReport;
+    type Proto = ViperRunReport;
+
+    fn from(value: Model) -> Proto {
+        ViperRunReport {
+            id: Some(value.id.into()),
+            report: Some(value.report.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?.try_into()?;
+        let report = extract!(value.report)?.try_into()?;
+        Ok(Model { id, report })
+    }
+}
+
 conversion! {
     type Model = crate::carl::broker::TracingContext;
     type Proto = TracingContext;
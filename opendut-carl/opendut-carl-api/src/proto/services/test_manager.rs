@@ -183,6 +183,66 @@ conversion! {
     }
 }
 
+conversion! {
+    type Model = crate::carl::viper::InspectViperTestSuiteError;
+    type Proto = InspectViperTestSuiteFailure;
+
+    fn from(value: Model) -> Proto {
+        let proto_error = match value {
+            Model::SourceNotFound { source_id } => {
+                inspect_viper_test_suite_failure::Error::SourceNotFound(InspectViperTestSuiteFailureSourceNotFound {
+                    source_id: Some(source_id.into())
+                })
+            }
+            Model::CompilationFailed { source_id, suite, cause } => {
+                inspect_viper_test_suite_failure::Error::CompilationFailed(InspectViperTestSuiteFailureCompilationFailed {
+                    source_id: Some(source_id.into()),
+                    suite: Some(suite.into()),
+                    cause
+                })
+            }
+            Model::Internal { source_id, cause } => {
+                inspect_viper_test_suite_failure::Error::Internal(InspectViperTestSuiteFailureInternal {
+                    source_id: Some(source_id.into()),
+                    cause
+                })
+            }
+        };
+        Proto {
+            error: Some(proto_error)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let error = extract!(value.error)?;
+
+        match error {
+            inspect_viper_test_suite_failure::Error::SourceNotFound(error) => {
+                let source_id = extract!(error.source_id)?.try_into()?;
+                Ok(Model::SourceNotFound { source_id })
+            }
+            inspect_viper_test_suite_failure::Error::CompilationFailed(error) => {
+                let source_id = extract!(error.source_id)?.try_into()?;
+                let suite = extract!(error.suite)?.try_into()?;
+                let cause = error.cause;
+                Ok(Model::CompilationFailed {
+                    source_id,
+                    suite,
+                    cause,
+                })
+            }
+            inspect_viper_test_suite_failure::Error::Internal(error) => {
+                let source_id = extract!(error.source_id)?.try_into()?;
+                let cause = error.cause;
+                Ok(Model::Internal {
+                    source_id,
+                    cause,
+                })
+            }
+        }
+    }
+}
+
 
 //
 // ViperRunDescriptor
@@ -511,3 +571,173 @@ conversion! {
         }
     }
 }
+
+
+//
+// ViperRunState
+//
+
+conversion! {
+    type Model = crate::carl::viper::GetViperRunStateError;
+    type Proto = GetViperRunStateFailure;
+
+    fn from(value: Model) -> Proto {
+        let proto_error = match value {
+            Model::StateNotFound { run_id } => {
+                get_viper_run_state_failure::Error::StateNotFound(GetViperRunStateFailureStateNotFound {
+                    run_id: Some(run_id.into())
+                })
+            }
+            Model::Internal { run_id, cause } => {
+                get_viper_run_state_failure::Error::Internal(GetViperRunStateFailureInternal {
+                    run_id: Some(run_id.into()),
+                    cause
+                })
+            }
+        };
+        Proto {
+            error: Some(proto_error)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let error = extract!(value.error)?;
+
+        match error {
+            get_viper_run_state_failure::Error::StateNotFound(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                Ok(Model::StateNotFound { run_id })
+            }
+            get_viper_run_state_failure::Error::Internal(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                let cause = error.cause;
+                Ok(Model::Internal {
+                    run_id,
+                    cause,
+                })
+            }
+        }
+    }
+}
+
+
+//
+// ViperRunResult
+//
+
+conversion! {
+    type Model = crate::carl::viper::DeleteViperRunResultError;
+    type Proto = DeleteViperRunResultFailure;
+
+    fn from(value: Model) -> Proto {
+        let proto_error = match value {
+            Model::ResultNotFound { run_id } => {
+                delete_viper_run_result_failure::Error::ResultNotFound(DeleteViperRunResultFailureResultNotFound {
+                    run_id: Some(run_id.into())
+                })
+            }
+            Model::Internal { run_id, cause } => {
+                delete_viper_run_result_failure::Error::Internal(DeleteViperRunResultFailureInternal {
+                    run_id: Some(run_id.into()),
+                    cause
+                })
+            }
+        };
+        Proto {
+            error: Some(proto_error)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let error = extract!(value.error)?;
+
+        match error {
+            delete_viper_run_result_failure::Error::ResultNotFound(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                Ok(Model::ResultNotFound { run_id })
+            }
+            delete_viper_run_result_failure::Error::Internal(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                let cause = error.cause;
+                Ok(Model::Internal {
+                    run_id,
+                    cause,
+                })
+            }
+        }
+    }
+}
+
+conversion! {
+    type Model = crate::carl::viper::GetViperRunResultError;
+    type Proto = GetViperRunResultFailure;
+
+    fn from(value: Model) -> Proto {
+        let proto_error = match value {
+            Model::ResultNotFound { run_id } => {
+                get_viper_run_result_failure::Error::ResultNotFound(GetViperRunResultFailureResultNotFound {
+                    run_id: Some(run_id.into())
+                })
+            }
+            Model::Internal { run_id, cause } => {
+                get_viper_run_result_failure::Error::Internal(GetViperRunResultFailureInternal {
+                    run_id: Some(run_id.into()),
+                    cause
+                })
+            }
+        };
+        Proto {
+            error: Some(proto_error)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let error = extract!(value.error)?;
+
+        match error {
+            get_viper_run_result_failure::Error::ResultNotFound(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                Ok(Model::ResultNotFound { run_id })
+            }
+            get_viper_run_result_failure::Error::Internal(error) => {
+                let run_id = extract!(error.run_id)?.try_into()?;
+                let cause = error.cause;
+                Ok(Model::Internal {
+                    run_id,
+                    cause,
+                })
+            }
+        }
+    }
+}
+
+conversion! {
+    type Model = crate::carl::viper::ListViperRunResultsError;
+    type Proto = ListViperRunResultsFailure;
+
+    fn from(value: Model) -> Proto {
+        let proto_error = match value {
+            Model::Internal { cause } => {
+                list_viper_run_results_failure::Error::Internal(ListViperRunResultsFailureInternal {
+                    cause
+                })
+            }
+        };
+        Proto {
+            error: Some(proto_error)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let error = extract!(value.error)?;
+
+        match error {
+            list_viper_run_results_failure::Error::Internal(error) => {
+                let cause = error.cause;
+                Ok(Model::Internal {
+                    cause,
+                })
+            }
+        }
+    }
+}
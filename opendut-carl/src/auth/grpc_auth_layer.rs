@@ -1,4 +1,5 @@
 use crate::auth::grpc_auth_layer::GrpcAuthenticationLayer::GrpcAuthLayerEnabled;
+use crate::auth::authorization::{Authorization, GrpcMethodPath};
 use crate::auth::json_web_key::JwkCacheValue;
 use crate::auth::validation::{authorize_user, Jwk, ValidationError};
 use crate::auth::CurrentUser;
@@ -15,6 +16,7 @@ pub enum GrpcAuthenticationLayer {
         issuer_url: Url,
         issuer_remote_url: Url,
         cache: CustomInMemoryCache<String, JwkCacheValue>,
+        authorization: Authorization,
     },
 }
 
@@ -25,7 +27,7 @@ impl GrpcAuthenticationLayer {
             GrpcAuthenticationLayer::AuthDisabled => {
                 Ok(request)
             }
-            GrpcAuthLayerEnabled { issuer_url, issuer_remote_url, cache } => {
+            GrpcAuthLayerEnabled { issuer_url, issuer_remote_url, cache, authorization } => {
                 let auth_header = match request.metadata().get("authorization") {
                     None => {
                         return Err(Status::unauthenticated("CARL says, you did not provide credentials!"))
@@ -38,6 +40,11 @@ impl GrpcAuthenticationLayer {
 
                 match authorize_current_user(auth_header, issuer_url, issuer_remote_url, cache, reqwest_client).await {
                     Ok(user) => {
+                        let method = request.extensions().get::<GrpcMethodPath>()
+                            .ok_or_else(|| Status::internal("CARL says, the called method could not be determined!"))?;
+                        authorization.authorize(&user, method)
+                            .inspect_err(|_| debug!("Denying user <{}> to call method <{}>.", user.name, method.0))?;
+
                         request.extensions_mut().insert(user);
                         Ok(request)
                     }
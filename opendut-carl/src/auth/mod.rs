@@ -1,4 +1,5 @@
-mod validation;
+pub(crate) mod validation;
+pub(crate) mod authorization;
 pub(crate) mod json_web_key;
 pub(crate) mod grpc_auth_layer;
 pub mod in_memory_cache;
@@ -9,7 +10,6 @@ use opendut_auth::types::MyAdditionalClaims;
 
 pub type Claims<AC> = IdTokenClaims<AC, CoreGenderClaim>;
 
-#[allow(unused)] //TODO implement authorization
 #[derive(Clone, Debug)]
 pub struct CurrentUser {
     pub name: String,
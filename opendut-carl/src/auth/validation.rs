@@ -126,7 +126,7 @@ fn get_key_id(access_token: &str) -> Result<String, ValidationError> {
     }
 }
 
-async fn fetch_jwk_custom(issuer_jwk_url: Url, jwk_requester: impl JwkRequester) -> Result<BTreeMap<String, JsonWebKey>, ValidationError> {
+pub(crate) async fn fetch_jwk_custom(issuer_jwk_url: Url, jwk_requester: impl JwkRequester) -> Result<BTreeMap<String, JsonWebKey>, ValidationError> {
     let result = jwk_requester.fetch_jwk(issuer_jwk_url).await?;
     OidcJsonWebKeySet::parse(result.as_str())
 }
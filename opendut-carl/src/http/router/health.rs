@@ -0,0 +1,219 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Serialize;
+use tracing::debug;
+use url::Url;
+
+use opendut_util::reqwest_client::ReqwestClient;
+
+use crate::auth::validation::{fetch_jwk_custom, Jwk};
+use crate::resource::manager::ResourceManagerRef;
+use crate::settings::vpn::Vpn;
+
+/// Dependencies of CARL, which are checked by the readiness endpoint.
+#[derive(Clone)]
+pub struct ReadinessChecks {
+    pub resource_manager: ResourceManagerRef,
+    pub vpn: Vpn,
+    pub oidc: Option<OidcReadinessCheck>,
+    /// Maximum duration of each individual check.
+    pub timeout: Duration,
+    pub cache: ReadinessCache,
+}
+
+/// Most recent [`ReadinessReport`], which is reused for a while, to not call the VPN management service and the identity provider on each probe.
+#[derive(Clone)]
+pub struct ReadinessCache {
+    max_age: Duration,
+    report: Arc<tokio::sync::Mutex<Option<(Instant, ReadinessReport)>>>,
+}
+
+impl ReadinessCache {
+    pub fn new(max_age: Duration) -> Self {
+        Self { max_age, report: Default::default() }
+    }
+}
+
+#[derive(Clone)]
+pub struct OidcReadinessCheck {
+    pub issuer_jwk_url: Url,
+    pub reqwest_client: ReqwestClient,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReadinessReport {
+    pub ready: bool,
+    pub checks: Vec<CheckReport>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckReport {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    Disabled,
+    Failed,
+}
+
+/// Liveness probe. Answers as long as CARL is able to handle HTTP requests.
+pub async fn healthz() -> &'static str {
+    "OK"
+}
+
+/// Readiness probe. Checks the storage, the VPN management service and the JWKS endpoint of the OIDC identity provider.
+pub async fn readyz(
+    State(readiness_checks): State<ReadinessChecks>,
+) -> (StatusCode, Json<ReadinessReport>) {
+    let report = readiness_checks.run_cached().await;
+
+    let status_code = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status_code, Json(report))
+}
+
+impl ReadinessChecks {
+    /// Runs the checks, unless a report younger than the cache duration exists.
+    /// Concurrent probes wait for the running checks, instead of starting their own.
+    pub async fn run_cached(&self) -> ReadinessReport {
+        let mut cached_report = self.cache.report.lock().await;
+
+        if let Some((created, report)) = cached_report.as_ref()
+            && created.elapsed() < self.cache.max_age {
+            return report.clone();
+        }
+
+        let report = self.run().await;
+        *cached_report = Some((Instant::now(), report.clone()));
+        report
+    }
+
+    pub async fn run(&self) -> ReadinessReport {
+        let (storage, vpn, oidc) = tokio::join!(
+            self.check_storage(),
+            self.check_vpn(),
+            self.check_oidc(),
+        );
+        let checks = vec![storage, vpn, oidc];
+
+        let ready = checks.iter().all(|check| check.status != CheckStatus::Failed);
+        ReadinessReport { ready, checks }
+    }
+
+    async fn check_storage(&self) -> CheckReport {
+        self.check("storage", async {
+            self.resource_manager.check_storage().await
+                .map_err(|cause| cause.to_string())
+        }).await
+    }
+
+    async fn check_vpn(&self) -> CheckReport {
+        match &self.vpn {
+            Vpn::Enabled { vpn_client } => {
+                self.check("vpn", async {
+                    vpn_client.check_reachable().await
+                        .map_err(|cause| cause.to_string())
+                }).await
+            }
+            Vpn::Disabled => CheckReport::disabled("vpn"),
+        }
+    }
+
+    async fn check_oidc(&self) -> CheckReport {
+        match &self.oidc {
+            Some(OidcReadinessCheck { issuer_jwk_url, reqwest_client }) => {
+                self.check("oidc", async {
+                    fetch_jwk_custom(issuer_jwk_url.clone(), Jwk(reqwest_client.clone())).await
+                        .map(|_| ())
+                        .map_err(|cause| cause.to_string())
+                }).await
+            }
+            None => CheckReport::disabled("oidc"),
+        }
+    }
+
+    async fn check(&self, name: &'static str, check: impl Future<Output=Result<(), String>>) -> CheckReport {
+        let result = tokio::time::timeout(self.timeout, check).await
+            .unwrap_or_else(|_| Err(format!("Check did not complete within {} ms.", self.timeout.as_millis())));
+
+        match result {
+            Ok(()) => CheckReport { name, status: CheckStatus::Ok, error: None },
+            Err(cause) => {
+                debug!("Readiness check '{name}' failed:\n  {cause}");
+                CheckReport { name, status: CheckStatus::Failed, error: Some(cause) }
+            }
+        }
+    }
+}
+
+impl CheckReport {
+    fn disabled(name: &'static str) -> Self {
+        Self { name, status: CheckStatus::Disabled, error: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+    use crate::resource::manager::ResourceManager;
+
+    #[tokio::test]
+    async fn should_be_ready_with_in_memory_storage_and_disabled_dependencies() {
+        let readiness_checks = ReadinessChecks {
+            resource_manager: ResourceManager::new_in_memory(),
+            vpn: Vpn::Disabled,
+            oidc: None,
+            timeout: Duration::from_secs(5),
+            cache: ReadinessCache::new(Duration::from_secs(5)),
+        };
+
+        let (status_code, Json(report)) = readyz(State(readiness_checks)).await;
+
+        assert_that!(status_code, eq(StatusCode::OK));
+        assert_that!(report.ready, eq(true));
+        assert_that!(report.checks[0].status, eq(CheckStatus::Ok));
+        assert_that!(report.checks[1].status, eq(CheckStatus::Disabled));
+        assert_that!(report.checks[2].status, eq(CheckStatus::Disabled));
+    }
+
+    #[tokio::test]
+    async fn should_reuse_the_readiness_report_within_the_cache_duration() {
+        let readiness_checks = ReadinessChecks {
+            resource_manager: ResourceManager::new_in_memory(),
+            vpn: Vpn::Disabled,
+            oidc: None,
+            timeout: Duration::from_secs(5),
+            cache: ReadinessCache::new(Duration::from_secs(60)),
+        };
+        let cached_report = ReadinessReport {
+            ready: false,
+            checks: vec![CheckReport { name: "storage", status: CheckStatus::Failed, error: Some(String::from("test")) }],
+        };
+        *readiness_checks.cache.report.lock().await = Some((Instant::now(), cached_report));
+
+        let (status_code, Json(report)) = readyz(State(readiness_checks.clone())).await;
+        assert_that!(status_code, eq(StatusCode::SERVICE_UNAVAILABLE));
+        assert_that!(report.checks.len(), eq(1));
+
+        let readiness_checks = ReadinessChecks { cache: ReadinessCache::new(Duration::ZERO), ..readiness_checks };
+        let (status_code, Json(report)) = readyz(State(readiness_checks)).await;
+        assert_that!(status_code, eq(StatusCode::OK));
+        assert_that!(report.checks.len(), eq(3));
+    }
+}
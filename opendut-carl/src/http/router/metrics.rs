@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use axum::extract::State;
+use axum::response::IntoResponse;
+use http::header;
+use tracing::warn;
+
+use crate::resource::manager::ResourceManagerRef;
+use crate::startup::metrics::{ResourceGauge, RpcMethodMetrics, RpcMetrics, RPC_DURATION_BUCKETS};
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Clone)]
+pub struct PrometheusMetrics {
+    pub resource_manager: ResourceManagerRef,
+    pub rpc_metrics: RpcMetrics,
+}
+
+/// Exposes the metrics in the Prometheus text format.
+pub async fn metrics(
+    State(metrics): State<PrometheusMetrics>,
+) -> impl IntoResponse {
+    let mut gauges = vec![];
+    for gauge in ResourceGauge::ALL {
+        match gauge.measure(&metrics.resource_manager).await {
+            Ok(value) => gauges.push((gauge, value)),
+            Err(cause) => warn!("Error while loading metrics information for '{}' from ResourceManager:\n  {cause}", gauge.name()),
+        }
+    }
+
+    let text = render(&gauges, &metrics.rpc_metrics.snapshot());
+
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], text)
+}
+
+fn render(gauges: &[(ResourceGauge, u64)], rpc_methods: &BTreeMap<String, RpcMethodMetrics>) -> String {
+    let mut text = String::new();
+
+    for (gauge, value) in gauges {
+        text.push_str(&format!("# HELP {} {}\n", gauge.name(), gauge.description()));
+        text.push_str(&format!("# TYPE {} gauge\n", gauge.name()));
+        text.push_str(&format!("{} {value}\n", gauge.name()));
+    }
+
+    if rpc_methods.is_empty() {
+        return text;
+    }
+
+    text.push_str("# HELP rpc_server_requests_total Number of handled gRPC requests.\n");
+    text.push_str("# TYPE rpc_server_requests_total counter\n");
+    for (method, metrics) in rpc_methods {
+        text.push_str(&format!("rpc_server_requests_total{{method=\"{}\"}} {}\n", escape_label_value(method), metrics.requests));
+    }
+
+    text.push_str("# HELP rpc_server_errors_total Number of gRPC requests, which failed with a non-OK status.\n");
+    text.push_str("# TYPE rpc_server_errors_total counter\n");
+    for (method, metrics) in rpc_methods {
+        for (code, count) in &metrics.errors {
+            text.push_str(&format!("rpc_server_errors_total{{method=\"{}\",code=\"{code}\"}} {count}\n", escape_label_value(method)));
+        }
+    }
+
+    text.push_str("# HELP rpc_server_duration_seconds Duration of gRPC requests until the response headers were sent.\n");
+    text.push_str("# TYPE rpc_server_duration_seconds histogram\n");
+    for (method, metrics) in rpc_methods {
+        let method = escape_label_value(method);
+        for (count, upper_bound) in metrics.duration_buckets.iter().zip(RPC_DURATION_BUCKETS) {
+            text.push_str(&format!("rpc_server_duration_seconds_bucket{{method=\"{method}\",le=\"{upper_bound}\"}} {count}\n"));
+        }
+        text.push_str(&format!("rpc_server_duration_seconds_bucket{{method=\"{method}\",le=\"+Inf\"}} {}\n", metrics.requests));
+        text.push_str(&format!("rpc_server_duration_seconds_sum{{method=\"{method}\"}} {}\n", metrics.duration_sum.as_secs_f64()));
+        text.push_str(&format!("rpc_server_duration_seconds_count{{method=\"{method}\"}} {}\n", metrics.requests));
+    }
+
+    text
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_render_gauges_and_rpc_metrics() {
+        let gauges = [(ResourceGauge::ConnectedPeers, 3)];
+
+        let mut duration_buckets = [0; RPC_DURATION_BUCKETS.len()];
+        duration_buckets[4..].fill(1); // one request with 0.1 seconds
+        duration_buckets[10..].fill(2); // one request with 10 seconds
+        let rpc_methods = BTreeMap::from([(
+            String::from("/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor"),
+            RpcMethodMetrics {
+                requests: 2,
+                errors: BTreeMap::from([(5, 1)]),
+                duration_buckets,
+                duration_sum: Duration::from_millis(10_100),
+            },
+        )]);
+
+        let text = render(&gauges, &rpc_methods);
+
+        assert_that!(text, contains_substring("# TYPE connected_peers gauge\nconnected_peers 3\n"));
+        assert_that!(text, contains_substring(r#"rpc_server_requests_total{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor"} 2"#));
+        assert_that!(text, contains_substring(r#"rpc_server_errors_total{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor",code="5"} 1"#));
+        assert_that!(text, contains_substring(r#"rpc_server_duration_seconds_bucket{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor",le="0.05"} 0"#));
+        assert_that!(text, contains_substring(r#"rpc_server_duration_seconds_bucket{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor",le="0.1"} 1"#));
+        assert_that!(text, contains_substring(r#"rpc_server_duration_seconds_bucket{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor",le="+Inf"} 2"#));
+        assert_that!(text, contains_substring(r#"rpc_server_duration_seconds_sum{method="/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor"} 10.1"#));
+    }
+
+    #[test]
+    fn should_escape_label_values() {
+        assert_that!(escape_label_value("a\"b\\c\nd"), eq("a\\\"b\\\\c\\nd"));
+    }
+}
@@ -5,6 +5,8 @@ use opendut_model::lea::LeaConfig;
 pub mod cleo;
 pub mod edgar;
 pub mod arch;
+pub mod health;
+pub mod metrics;
 
 pub async fn lea_config(State(config): State<LeaConfig>) -> Json<LeaConfig> {
     Json(Clone::clone(&config))
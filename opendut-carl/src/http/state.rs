@@ -8,11 +8,15 @@ use shadow_rs::formatcp;
 use url::Url;
 use opendut_auth::confidential::config::OidcConfidentialClientConfig;
 use opendut_model::lea::{LeaConfig, LeaIdentityProviderConfig};
+use crate::http::router::health::ReadinessChecks;
+use crate::http::router::metrics::PrometheusMetrics;
 
 #[derive(Clone)]
 pub struct HttpState {
     pub lea_config: LeaConfig,
     pub carl_installation_directory: CarlInstallDirectory,
+    pub readiness_checks: ReadinessChecks,
+    pub prometheus_metrics: PrometheusMetrics,
 }
 
 pub struct LoadableLeaIdentityProviderConfig(pub(crate) LeaIdentityProviderConfig);
@@ -76,3 +80,15 @@ impl FromRef<HttpState> for CarlInstallDirectory {
         Clone::clone(&app_state.carl_installation_directory)
     }
 }
+
+impl FromRef<HttpState> for ReadinessChecks {
+    fn from_ref(app_state: &HttpState) -> Self {
+        Clone::clone(&app_state.readiness_checks)
+    }
+}
+
+impl FromRef<HttpState> for PrometheusMetrics {
+    fn from_ref(app_state: &HttpState) -> Self {
+        Clone::clone(&app_state.prometheus_metrics)
+    }
+}
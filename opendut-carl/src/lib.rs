@@ -1,6 +1,8 @@
 use std::net::SocketAddr;
 use std::ops::Not;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 use anyhow::Context;
 use tonic::service::Routes;
 use tonic_async_interceptor::async_interceptor;
@@ -20,10 +22,15 @@ use opendut_util::pem::{Pem, PemFromConfig};
 use opendut_util::reqwest_client::OidcReqwestClient;
 use auth::in_memory_cache::CustomInMemoryCache;
 
+use crate::auth::authorization::{Authorization, GrpcMethodPath};
 use crate::auth::grpc_auth_layer::GrpcAuthenticationLayer;
 use crate::auth::json_web_key::JwkCacheValue;
+use crate::auth::validation::GIVEN_ISSUER_JWK_URL;
+use crate::http::router::health::{OidcReadinessCheck, ReadinessCache, ReadinessChecks};
+use crate::http::router::metrics::PrometheusMetrics;
 use crate::http::state::CarlInstallDirectory;
 use crate::resource::manager::{ResourceManager, ResourceManagerRef};
+use crate::startup::metrics::RpcMetrics;
 use crate::startup::tls::TlsConfig;
 
 shadow_rs::shadow!(app_info);
@@ -107,11 +114,36 @@ async fn run(settings: LoadedConfig, get_resource_manager_ref: bool) -> anyhow::
     let oidc_registration_client = RegistrationClient::from_settings(&settings).await
         .expect("Failed to load oidc registration client!");
 
+    let vpn = settings::vpn::create(&settings).await
+        .context("Error while parsing VPN configuration.")?;
+
+    let readiness_checks = ReadinessChecks {
+        resource_manager: Arc::clone(&resource_manager),
+        vpn: Clone::clone(&vpn),
+        oidc: match &oidc_registration_client {
+            None => None,
+            Some(oidc_client_ref) => Some(OidcReadinessCheck {
+                issuer_jwk_url: oidc_client_ref.inner.issuer_url.value().join(GIVEN_ISSUER_JWK_URL)?,
+                reqwest_client: OidcReqwestClient::from_config(&settings)?,
+            }),
+        },
+        timeout: Duration::from_millis(settings.get::<u64>("serve.readiness.timeout.ms")?),
+        cache: ReadinessCache::new(Duration::from_millis(settings.get::<u64>("serve.readiness.cache.ms")?)),
+    };
+    let rpc_metrics = RpcMetrics::new(
+        opendut_carl_api::proto::services::method_paths(&startup::grpc::GrpcFacades::service_names())
+    );
+    let prometheus_metrics = PrometheusMetrics {
+        resource_manager: Arc::clone(&resource_manager),
+        rpc_metrics: Clone::clone(&rpc_metrics),
+    };
+
     let grpc_facades = startup::grpc::GrpcFacades::create(
         resource_manager,
         &carl_url,
         ca_certificate.clone(),
         oidc_registration_client.clone(),
+        vpn,
         &settings
     ).await?;
 
@@ -128,7 +160,7 @@ async fn run(settings: LoadedConfig, get_resource_manager_ref: bool) -> anyhow::
             ).expect("Could not create CLEO install script.");
         }
 
-        let http_state = startup::http::create_http_state(&carl_url, carl_installation_directory, &settings)?;
+        let http_state = startup::http::create_http_state(&carl_url, carl_installation_directory, readiness_checks, prometheus_metrics, &settings)?;
 
         startup::http::create_http_service(&settings)?
             .with_state(http_state)
@@ -144,6 +176,7 @@ async fn run(settings: LoadedConfig, get_resource_manager_ref: bool) -> anyhow::
                     issuer_url: oidc_client_ref.inner.issuer_url.value().clone(),
                     issuer_remote_url: oidc_client_ref.config.issuer_remote_url.value().clone(),
                     cache: jwk_cache,
+                    authorization: Authorization::load(&settings)?,
                 }
             }
         };
@@ -151,6 +184,7 @@ async fn run(settings: LoadedConfig, get_resource_manager_ref: bool) -> anyhow::
         let mut routes_builder = Routes::builder();
 
         routes_builder
+            .add_service(grpc_facades.audit_log_facade.into_grpc_service())
             .add_service(grpc_facades.cluster_manager_facade.into_grpc_service())
             .add_service(grpc_facades.metadata_provider_facade.into_grpc_service())
             .add_service(grpc_facades.peer_manager_facade.into_grpc_service())
@@ -167,6 +201,12 @@ async fn run(settings: LoadedConfig, get_resource_manager_ref: bool) -> anyhow::
             .layer(async_interceptor(move |request| {
                 Clone::clone(&grpc_auth_layer).auth_interceptor(request, reqwest_client.clone())
             }))
+            .layer(tower::util::MapRequestLayer::new(|mut request: axum::extract::Request| {
+                let method = GrpcMethodPath(request.uri().path().to_owned());
+                request.extensions_mut().insert(method);
+                request
+            }))
+            .layer(axum::middleware::from_fn_with_state(rpc_metrics, startup::metrics::record_rpc_metrics))
     };
 
     let http_grpc = tower::steer::Steer::new(vec![http, grpc], |request: &axum::extract::Request, _services: &[_]| {
@@ -0,0 +1,115 @@
+use std::ops::Not;
+use std::time::SystemTime;
+use opendut_carl_api::carl::audit::AuditEventsPage;
+use opendut_model::audit::AuditEventId;
+use tracing::debug;
+use crate::resource::api::resources::Resources;
+use crate::resource::persistence::error::PersistenceError;
+use crate::resource::storage::audit_log::{AuditLogPosition, AuditLogStorageApi};
+
+pub struct ListAuditEventsParams {
+    /// Only considered for the first page, as the page token already designates a later position.
+    pub since: Option<SystemTime>,
+    pub page_size: usize,
+    pub page_token: Option<AuditEventId>,
+}
+
+impl Resources<'_> {
+    #[tracing::instrument(skip_all, level="trace")]
+    pub fn list_audit_events(&self, params: ListAuditEventsParams) -> Result<AuditEventsPage, ListAuditEventsError> {
+        let ListAuditEventsParams { since, page_size, page_token } = params;
+
+        debug!("Querying audit events since {since:?}, continuing after <{page_token:?}>.");
+
+        let position = match (page_token, since) {
+            (Some(page_token), _) => {
+                let known = self.contains_audit_event(page_token)
+                    .map_err(|source| ListAuditEventsError::Persistence { source })?;
+                if known.not() {
+                    return Err(ListAuditEventsError::UnknownPageToken { page_token });
+                }
+                AuditLogPosition::After(page_token)
+            }
+            (None, Some(since)) => AuditLogPosition::Since(since),
+            (None, None) => AuditLogPosition::Start,
+        };
+
+        //read one more event than requested, to determine whether there is another page
+        let mut events = self.read_audit_events(position, page_size.saturating_add(1))
+            .map_err(|source| ListAuditEventsError::Persistence { source })?;
+
+        let next_page_token = if events.len() > page_size {
+            events.truncate(page_size);
+            events.last().map(|event| event.id)
+        } else {
+            None
+        };
+
+        debug!("Successfully queried {} audit events.", events.len());
+
+        Ok(AuditEventsPage { events, next_page_token })
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListAuditEventsError {
+    #[error("Page token <{page_token}> does not designate a known audit event")]
+    UnknownPageToken {
+        page_token: AuditEventId,
+    },
+    #[error("Error when accessing persistence while listing audit events")]
+    Persistence {
+        #[source] source: PersistenceError,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::audit_log::{record_audit_event, AuditRecord};
+    use crate::resource::manager::ResourceManager;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn should_list_the_audit_events_since_a_point_in_time_page_by_page() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let mut events = Vec::new();
+        for _ in 0..5 {
+            let event = AuditRecord {
+                user: None,
+                operation: "StoreClusterDeployment",
+                resource_ids: vec![],
+            }.success();
+            record_audit_event(&resource_manager, Clone::clone(&event)).await;
+            events.push(event);
+
+            tokio::time::sleep(Duration::from_millis(2)).await; //ensure distinct timestamps
+        }
+        let since = events[1].timestamp;
+
+        let list = async |page_token: Option<AuditEventId>| {
+            resource_manager.resources(async |resources|
+                resources.list_audit_events(ListAuditEventsParams {
+                    since: Some(since),
+                    page_size: 2,
+                    page_token,
+                })
+            ).await
+        };
+
+        let first_page = list(None).await??;
+        assert_eq!(first_page.events, events[1..3]);
+        assert_eq!(first_page.next_page_token, Some(events[2].id));
+
+        let second_page = list(first_page.next_page_token).await??;
+        assert_eq!(second_page.events, events[3..5]);
+        assert_eq!(second_page.next_page_token, None);
+
+        let unknown_page_token = AuditEventId::now();
+        let result = list(Some(unknown_page_token)).await?;
+        assert!(matches!(result, Err(ListAuditEventsError::UnknownPageToken { page_token }) if page_token == unknown_page_token));
+
+        Ok(())
+    }
+}
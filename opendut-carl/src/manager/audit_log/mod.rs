@@ -0,0 +1,5 @@
+pub mod list_audit_events;
+pub mod record_audit_event;
+
+pub use list_audit_events::{ListAuditEventsError, ListAuditEventsParams};
+pub use record_audit_event::{audit_user, audited_resources_mut, record_audit_event, AuditRecord};
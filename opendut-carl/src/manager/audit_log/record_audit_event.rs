@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter};
+use std::time::SystemTime;
+use opendut_model::audit::{AuditEvent, AuditEventId, AuditOutcome, AuditResourceId};
+use tonic::Request;
+use tracing::error;
+use crate::auth::CurrentUser;
+use crate::resource::api::resources::Resources;
+use crate::resource::manager::ResourceManagerRef;
+use crate::resource::persistence::error::{PersistenceError, PersistenceResult};
+use crate::resource::storage::audit_log::AuditLogStorageApi;
+
+/// Determines the name of the user, who sent the request, or `None` if authentication is disabled.
+pub fn audit_user<T>(request: &Request<T>) -> Option<String> {
+    request.extensions().get::<CurrentUser>()
+        .map(|user| Clone::clone(&user.name))
+}
+
+/// Describes a mutating operation, which is to be recorded in the audit log.
+#[derive(Clone, Debug)]
+pub struct AuditRecord {
+    pub user: Option<String>,
+    pub operation: &'static str,
+    pub resource_ids: Vec<AuditResourceId>,
+}
+
+impl AuditRecord {
+    pub fn success(self) -> AuditEvent {
+        self.into_event(AuditOutcome::Success)
+    }
+
+    pub fn outcome<T, E: Display>(self, result: &Result<T, E>) -> AuditEvent {
+        let outcome = match result {
+            Ok(_) => AuditOutcome::Success,
+            Err(error) => AuditOutcome::Failure { message: error.to_string() },
+        };
+        self.into_event(outcome)
+    }
+
+    fn into_event(self, outcome: AuditOutcome) -> AuditEvent {
+        let AuditRecord { user, operation, resource_ids } = self;
+
+        let timestamp = SystemTime::now(); //determined before the ID, so the ID does not sort before the timestamp
+        AuditEvent {
+            id: AuditEventId::now(),
+            timestamp,
+            user,
+            operation: String::from(operation),
+            resource_ids,
+            outcome,
+        }
+    }
+}
+
+/// Performs a mutating operation in a transaction, like [`ResourceManager::resources_mut()`](crate::resource::manager::ResourceManager::resources_mut),
+/// and records the operation in the audit log.
+///
+/// If the operation succeeds, its audit event is appended within the same transaction, so the changes are only committed together with the audit event.
+/// If the operation fails, the transaction is rolled back and the failure is recorded in a separate transaction.
+pub async fn audited_resources_mut<F, T, E>(
+    resource_manager: &ResourceManagerRef,
+    audit: AuditRecord,
+    closure: F,
+) -> PersistenceResult<Result<T, E>>
+where
+    F: AsyncFnOnce(&mut Resources) -> Result<T, E>,
+    E: Display,
+{
+    let success = Clone::clone(&audit);
+
+    let result = resource_manager.resources_mut(async move |resources| {
+        let value = closure(resources).await
+            .map_err(AuditedError::Operation)?;
+        resources.append_audit_event(success.success())
+            .map_err(AuditedError::Audit)?;
+        Ok::<_, AuditedError<E>>(value)
+    }).await;
+
+    let result = match result {
+        Ok(Ok(value)) => Ok(Ok(value)),
+        Ok(Err(AuditedError::Operation(error))) => Ok(Err(error)),
+        Ok(Err(AuditedError::Audit(source))) | Err(source) => Err(source),
+    };
+
+    let failure = match &result {
+        Ok(Ok(_)) => None,
+        Ok(Err(error)) => Some(error.to_string()),
+        Err(error) => Some(error.to_string()),
+    };
+    if let Some(message) = failure {
+        record_audit_event(resource_manager, audit.into_event(AuditOutcome::Failure { message })).await;
+    }
+
+    result
+}
+
+/// Appends an [`AuditEvent`] to the audit log in a transaction of its own.
+/// Meant for operations, which did not change any resources, e.g. because they failed.
+///
+/// Failing to record the event does not fail the operation, since it has already been executed at this point.
+pub async fn record_audit_event(resource_manager: &ResourceManagerRef, event: AuditEvent) {
+    let result = resource_manager.resources_mut(async |resources|
+        resources.append_audit_event(Clone::clone(&event))
+    ).await;
+
+    if let Err(cause) | Ok(Err(cause)) = result {
+        error!("Failed to record audit event {event:?}:\n  {cause}");
+    }
+}
+
+enum AuditedError<E> {
+    Operation(E),
+    Audit(PersistenceError),
+}
+impl<E: Display> Display for AuditedError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditedError::Operation(error) => write!(f, "{error}"),
+            AuditedError::Audit(error) => write!(f, "Failed to record audit event: {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::manager::ResourceManager;
+    use crate::resource::storage::audit_log::AuditLogPosition;
+    use crate::resource::storage::ResourcesStorageApi;
+    use crate::manager::peer_manager::tests::create_peer_descriptor;
+    use opendut_model::peer::{PeerDescriptor, PeerId};
+
+    #[tokio::test]
+    async fn should_record_a_successful_operation_together_with_its_changes() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer_id = PeerId::random();
+
+        let result = audited_resources_mut(&resource_manager, audit_record(peer_id), async |resources|
+            resources.insert(peer_id, create_peer_descriptor(peer_id))
+        ).await?;
+        assert!(result.is_ok());
+
+        let events = read_all_audit_events(&resource_manager).await?;
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.user.as_deref(), Some("alice"));
+        assert_eq!(event.operation, "StorePeerDescriptor");
+        assert_eq!(event.resource_ids, vec![AuditResourceId::Peer(peer_id)]);
+        assert_eq!(event.outcome, AuditOutcome::Success);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_roll_back_a_failed_operation_and_record_its_outcome() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer_id = PeerId::random();
+
+        let result = audited_resources_mut(&resource_manager, audit_record(peer_id), async |resources| {
+            resources.insert(peer_id, create_peer_descriptor(peer_id))
+                .map_err(|cause| cause.to_string())?;
+            Err::<(), _>(String::from("Peer name is invalid."))
+        }).await?;
+        assert!(result.is_err());
+
+        assert!(resource_manager.get::<PeerDescriptor>(peer_id).await?.is_none());
+
+        let events = read_all_audit_events(&resource_manager).await?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].outcome, AuditOutcome::Failure { message: String::from("Peer name is invalid.") });
+
+        Ok(())
+    }
+
+    fn audit_record(peer_id: PeerId) -> AuditRecord {
+        AuditRecord {
+            user: Some(String::from("alice")),
+            operation: "StorePeerDescriptor",
+            resource_ids: vec![AuditResourceId::Peer(peer_id)],
+        }
+    }
+
+    async fn read_all_audit_events(resource_manager: &ResourceManagerRef) -> anyhow::Result<Vec<AuditEvent>> {
+        let events = resource_manager.resources(async |resources|
+            resources.read_audit_events(AuditLogPosition::Start, usize::MAX)
+        ).await??;
+        Ok(events)
+    }
+}
@@ -1,4 +1,10 @@
 use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterName};
+use opendut_model::namespace::NamespaceName;
+use opendut_model::peer::{PeerDescriptor, PeerId};
+use opendut_model::resources::Revision;
+use opendut_model::topology::DeviceId;
+use opendut_model::util::can::IllegalCanFrameRule;
+use std::ops::Not;
 use tracing::{debug, info};
 use crate::resource::api::resources::Resources;
 use crate::resource::persistence::error::PersistenceError;
@@ -14,10 +20,52 @@ impl Resources<'_> {
 
         let cluster_id = params.cluster_descriptor.id;
         let cluster_name = Clone::clone(&params.cluster_descriptor.name);
+        let mut cluster_descriptor = params.cluster_descriptor;
 
         debug!("Creating cluster descriptor '{cluster_name}' <{cluster_id}>.");
 
-        self.insert(cluster_id, params.cluster_descriptor)
+        for device_rules in &cluster_descriptor.can_rules {
+            device_rules.rules.validate()
+                .map_err(|source| CreateClusterDescriptorError::IllegalCanRules { cluster_id, cluster_name: cluster_name.clone(), device_id: device_rules.device, source })?;
+        }
+
+        let actual_revision = self.get::<ClusterDescriptor>(cluster_id)
+            .map_err(|source| CreateClusterDescriptorError::Persistence { cluster_id, cluster_name: cluster_name.clone(), source })?
+            .map(|cluster_descriptor| cluster_descriptor.revision)
+            .unwrap_or(Revision::INITIAL);
+
+        if cluster_descriptor.revision != actual_revision {
+            return Err(CreateClusterDescriptorError::Conflict {
+                cluster_id,
+                cluster_name,
+                expected_revision: cluster_descriptor.revision,
+                actual_revision,
+            });
+        }
+        cluster_descriptor.revision = actual_revision.next();
+
+        let peers = self.list::<PeerDescriptor>()
+            .map_err(|source| CreateClusterDescriptorError::Persistence { cluster_id, cluster_name: cluster_name.clone(), source })?;
+        let mut foreign_namespace_peers = peers.into_values()
+            .filter(|peer| peer.namespace != cluster_descriptor.namespace)
+            .filter(|peer|
+                peer.id == cluster_descriptor.leader
+                || peer.topology.devices.iter().any(|device| cluster_descriptor.devices.contains(&device.id))
+            )
+            .map(|peer| peer.id)
+            .collect::<Vec<_>>();
+
+        if foreign_namespace_peers.is_empty().not() {
+            foreign_namespace_peers.sort_by_key(|peer_id| peer_id.uuid);
+            return Err(CreateClusterDescriptorError::ForeignNamespacePeers {
+                cluster_id,
+                cluster_name,
+                namespace: cluster_descriptor.namespace,
+                peer_ids: foreign_namespace_peers,
+            });
+        }
+
+        self.insert(cluster_id, cluster_descriptor)
             .map_err(|source| CreateClusterDescriptorError::Persistence { cluster_id, cluster_name: cluster_name.clone(), source })?;
 
         info!("Successfully created cluster descriptor '{cluster_name}' <{cluster_id}>.");
@@ -27,11 +75,126 @@ impl Resources<'_> {
 }
 
 #[derive(thiserror::Error, Debug)]
-#[error("ClusterConfigration '{cluster_name}' <{cluster_id}> could not be created")]
 pub enum CreateClusterDescriptorError {
+    #[error("ClusterDescriptor '{cluster_name}' <{cluster_id}> could not be stored, because it is based on revision {expected_revision}, but the stored revision is {actual_revision}")]
+    Conflict {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        expected_revision: Revision,
+        actual_revision: Revision,
+    },
+    #[error(
+        "ClusterDescriptor '{cluster_name}' <{cluster_id}> in namespace '{namespace}' could not be stored, because it uses the peers <{peers}> of other namespaces",
+        peers = peer_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+    )]
+    ForeignNamespacePeers {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        namespace: NamespaceName,
+        peer_ids: Vec<PeerId>,
+    },
+    #[error("ClusterDescriptor '{cluster_name}' <{cluster_id}> could not be stored, because the CAN rules of device <{device_id}> are invalid: {source}")]
+    IllegalCanRules {
+        cluster_id: ClusterId,
+        cluster_name: ClusterName,
+        device_id: DeviceId,
+        #[source] source: IllegalCanFrameRule,
+    },
+    #[error("ClusterConfigration '{cluster_name}' <{cluster_id}> could not be created")]
     Persistence {
         cluster_id: ClusterId,
         cluster_name: ClusterName,
         #[source] source: PersistenceError
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::testing::ClusterFixture;
+    use crate::resource::manager::ResourceManager;
+    use opendut_model::cluster::DeviceCanRules;
+    use opendut_model::util::can::{CanFrameRules, CanIdRewrite, MAX_CAN_ROUTES};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn should_reject_a_cluster_descriptor_with_a_stale_revision() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(Arc::clone(&resource_manager)).await?;
+
+        let store = async |cluster_descriptor: ClusterDescriptor| {
+            resource_manager.resources_mut(async |resources|
+                resources.create_cluster_descriptor(CreateClusterDescriptorParams { cluster_descriptor })
+            ).await
+        };
+
+        let renamed_descriptor = ClusterDescriptor {
+            name: ClusterName::try_from("FirstEdit")?,
+            ..Clone::clone(&cluster.configuration)
+        };
+        store(renamed_descriptor).await??;
+
+        let stored_descriptor = resource_manager.get::<ClusterDescriptor>(cluster.id).await?.unwrap();
+        assert_eq!(stored_descriptor.name, ClusterName::try_from("FirstEdit")?);
+        assert_eq!(stored_descriptor.revision, Revision(1));
+
+        let result = store(Clone::clone(&cluster.configuration)).await?;
+        let Err(CreateClusterDescriptorError::Conflict { expected_revision, actual_revision, .. }) = result else {
+            panic!("Storing a cluster descriptor with a stale revision should be rejected, but got: {result:?}");
+        };
+        assert_eq!(expected_revision, Revision(0));
+        assert_eq!(actual_revision, Revision(1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_a_cluster_descriptor_using_peers_of_another_namespace() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(Arc::clone(&resource_manager)).await?;
+
+        let foreign_peer = PeerDescriptor {
+            namespace: NamespaceName::try_from("other")?,
+            ..Clone::clone(&cluster.peer_b.descriptor)
+        };
+        resource_manager.insert(cluster.peer_b.id, foreign_peer).await?;
+
+        let result = resource_manager.resources_mut(async |resources|
+            resources.create_cluster_descriptor(CreateClusterDescriptorParams { cluster_descriptor: Clone::clone(&cluster.configuration) })
+        ).await?;
+        let Err(CreateClusterDescriptorError::ForeignNamespacePeers { namespace, peer_ids, .. }) = result else {
+            panic!("Storing a cluster descriptor using peers of another namespace should be rejected, but got: {result:?}");
+        };
+        assert_eq!(namespace, NamespaceName::default());
+        assert_eq!(peer_ids, vec![cluster.peer_b.id]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_a_cluster_descriptor_with_can_rules_requiring_too_many_routes() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(Arc::clone(&resource_manager)).await?;
+
+        let device_id = DeviceId::random();
+        let rules = CanFrameRules {
+            rewrites: (0..=MAX_CAN_ROUTES as u32).map(|id| CanIdRewrite::new(id, id + 0x100)).collect::<Result<_, _>>()?,
+            ..CanFrameRules::default()
+        };
+        let cluster_descriptor = ClusterDescriptor {
+            can_rules: vec![DeviceCanRules { device: device_id, rules }],
+            ..Clone::clone(&cluster.configuration)
+        };
+
+        let result = resource_manager.resources_mut(async |resources|
+            resources.create_cluster_descriptor(CreateClusterDescriptorParams { cluster_descriptor })
+        ).await?;
+        let Err(CreateClusterDescriptorError::IllegalCanRules { device_id: rejected_device_id, source, .. }) = result else {
+            panic!("Storing a cluster descriptor with CAN rules requiring too many routes should be rejected, but got: {result:?}");
+        };
+        assert_eq!(rejected_device_id, device_id);
+        assert_eq!(source, IllegalCanFrameRule::TooManyRoutes { max: MAX_CAN_ROUTES });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use opendut_model::cluster::ClusterDisplay;
 use opendut_model::ShortName;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterId, ClusterName};
+use opendut_model::namespace::NamespaceName;
 use tracing::{debug, info};
 use opendut_model::cluster::state::ClusterState;
 use crate::resource::api::resources::Resources;
@@ -9,6 +10,8 @@ use crate::resource::storage::ResourcesStorageApi;
 
 pub struct DeleteClusterDescriptorParams {
     pub cluster_id: ClusterId,
+    /// Only delete the cluster descriptor, if it belongs to this namespace. Clusters of other namespaces are treated as not found.
+    pub namespace: Option<NamespaceName>,
 }
 
 impl Resources<'_> {
@@ -17,6 +20,14 @@ impl Resources<'_> {
 
         let cluster_id = params.cluster_id;
 
+        if let Some(namespace) = &params.namespace {
+            let cluster_descriptor = self.get::<ClusterDescriptor>(cluster_id)
+                .map_err(|source| DeleteClusterDescriptorError::Persistence { cluster_id, cluster_name: None, source })?;
+            if cluster_descriptor.is_none_or(|cluster_descriptor| cluster_descriptor.namespace != *namespace) {
+                return Err(DeleteClusterDescriptorError::ClusterDescriptorNotFound { cluster_id });
+            }
+        }
+
         let cluster_deployment = self.get::<ClusterDeployment>(cluster_id)
             .map_err(|source| DeleteClusterDescriptorError::Persistence { cluster_id, cluster_name: None, source })?;
 
@@ -83,7 +94,7 @@ mod tests {
         resource_manager.insert(cluster.id, ClusterDeployment { id: cluster.id }).await?;
 
         let result = resource_manager.resources_mut(async |resources| {
-            resources.delete_cluster_descriptor(DeleteClusterDescriptorParams { cluster_id: cluster.id })
+            resources.delete_cluster_descriptor(DeleteClusterDescriptorParams { cluster_id: cluster.id, namespace: None })
         }).await?;
 
         let Err(DeleteClusterDescriptorError::ClusterDeploymentFound { cluster_id }) = result
@@ -98,10 +109,30 @@ mod tests {
         let resource_manager = ResourceManager::new_in_memory();
         let cluster = ClusterFixture::create(resource_manager.clone()).await?;
         let result = resource_manager.resources_mut(async |resources|
-            resources.delete_cluster_descriptor(DeleteClusterDescriptorParams { cluster_id: cluster.id })
+            resources.delete_cluster_descriptor(DeleteClusterDescriptorParams { cluster_id: cluster.id, namespace: None })
         ).await??;
 
         assert_eq!(result, cluster.configuration);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_not_delete_a_cluster_descriptor_of_another_namespace() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(resource_manager.clone()).await?;
+
+        let result = resource_manager.resources_mut(async |resources|
+            resources.delete_cluster_descriptor(DeleteClusterDescriptorParams {
+                cluster_id: cluster.id,
+                namespace: Some(NamespaceName::try_from("other")?),
+            })
+        ).await?;
+
+        let Err(DeleteClusterDescriptorError::ClusterDescriptorNotFound { cluster_id }) = result
+        else { panic!("Expected ClusterDescriptorNotFound error!") };
+        assert_eq!(cluster_id, cluster.id);
+        assert!(resource_manager.get::<ClusterDescriptor>(cluster.id).await?.is_some());
+
+        Ok(())
+    }
 }
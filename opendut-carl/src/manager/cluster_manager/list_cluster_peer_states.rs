@@ -133,11 +133,11 @@ pub enum ListClusterPeerStatesError {
 mod tests {
     use super::*;
     use crate::resource::manager::{ResourceManager, ResourceManagerRef};
-    use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterName};
+    use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterName, DeviceSelector};
     use opendut_model::peer::executor::ExecutorDescriptors;
     use opendut_model::peer::state::PeerConnectionState;
     use opendut_model::peer::{PeerDescriptor, PeerId, PeerName, PeerNetworkDescriptor};
-    use opendut_model::topology::DeviceName;
+    use opendut_model::topology::{DeviceName, DeviceTag};
     use opendut_model::topology::{DeviceDescriptor, DeviceId, Topology};
     use opendut_model::util::net::{NetworkInterfaceConfiguration, NetworkInterfaceDescriptor, NetworkInterfaceId, NetworkInterfaceName};
     use std::collections::HashSet;
@@ -208,6 +208,12 @@ mod tests {
             name: ClusterName::try_from("BlockingCluster")?,
             leader: cluster.leader,
             devices: cluster.devices.clone(),
+            device_selectors: vec![],
+            topology: Default::default(),
+            can_rules: vec![],
+            restbus_simulations: vec![],
+            revision: Default::default(),
+            namespace: Default::default(),
         };
         // When another cluster is deployed
         {
@@ -284,6 +290,45 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_include_peers_with_devices_matching_the_device_selectors() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let mut matching_peer = generate_peer_descriptor()?;
+        matching_peer.topology.devices[0].tags = vec![DeviceTag::try_from("powertrain")?, DeviceTag::try_from("hil-rig-3")?];
+        resource_manager.insert(matching_peer.id, matching_peer.clone()).await?;
+
+        let mut partially_matching_peer = generate_peer_descriptor()?;
+        partially_matching_peer.topology.devices[0].tags = vec![DeviceTag::try_from("powertrain")?];
+        resource_manager.insert(partially_matching_peer.id, partially_matching_peer.clone()).await?;
+
+        let cluster = ClusterDescriptor {
+            id: ClusterId::random(),
+            name: ClusterName::try_from("TaggedCluster")?,
+            leader: matching_peer.id,
+            devices: HashSet::new(),
+            device_selectors: vec![
+                DeviceSelector { tags: vec![DeviceTag::try_from("powertrain")?, DeviceTag::try_from("hil-rig-3")?] },
+            ],
+            topology: Default::default(),
+            can_rules: vec![],
+            restbus_simulations: vec![],
+            revision: Default::default(),
+            namespace: Default::default(),
+        };
+        resource_manager.insert(cluster.id, cluster.clone()).await?;
+
+        let cluster_peer_states = resource_manager.resources(async |resources| {
+            resources.list_cluster_peer_states(cluster.id).await
+        }).await??;
+
+        assert_eq!(cluster_peer_states.peer_states, HashMap::from_iter([
+            (matching_peer.id, PeerState { connection: PeerConnectionState::Offline, member: PeerMemberState::Available }),
+        ]));
+
+        Ok(())
+    }
+
     struct Fixture {
         resource_manager: ResourceManagerRef,
         peer_a: PeerDescriptor,
@@ -313,6 +358,12 @@ mod tests {
                         .chain(peer_b.topology.devices.iter())
                         .map(|device| device.id)
                 ),
+                device_selectors: vec![],
+                topology: Default::default(),
+                can_rules: vec![],
+                restbus_simulations: vec![],
+                revision: Default::default(),
+                namespace: Default::default(),
             };
             resource_manager.insert(cluster.id, cluster.clone()).await?;
 
@@ -357,6 +408,8 @@ mod tests {
             executors: ExecutorDescriptors {
                 executors: vec![],
             },
+            revision: Default::default(),
+            namespace: Default::default(),
         })
     }
 }
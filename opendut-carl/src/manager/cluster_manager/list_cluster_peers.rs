@@ -1,3 +1,4 @@
+use crate::manager::cluster_manager;
 use crate::resource::api::resources::Resources;
 use crate::resource::persistence::error::PersistenceError;
 use crate::resource::storage::ResourcesStorageApi;
@@ -11,11 +12,14 @@ impl Resources<'_> {
             .map_err(|source| ListClusterPeersError::Persistence { cluster_id, source })?
             .ok_or_else(|| ListClusterPeersError::ClusterNotFound(cluster_id))?;
 
+        let cluster_devices = cluster_manager::resolve_cluster_devices(self, &cluster_descriptor)
+            .map_err(|source| ListClusterPeersError::Persistence { cluster_id, source })?;
+
         let peers = self.list::<PeerDescriptor>()
             .map_err(|source| ListClusterPeersError::Persistence { cluster_id, source })?;
 
         let cluster_peers = peers.into_values()
-            .filter(|peer| peer.topology.devices.iter().any(|device| cluster_descriptor.devices.contains(&device.id)))
+            .filter(|peer| peer.topology.devices.iter().any(|device| cluster_devices.contains(&device.id)))
             .collect();
 
         Ok(cluster_peers)
@@ -1,22 +1,31 @@
 use anyhow::Context;
 use std::collections::{HashMap, HashSet};
+use std::ops::Not;
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use futures::future::join_all;
 use futures::FutureExt;
-use tracing::{debug, error, trace, warn};
+use tracing::{debug, error, info, trace, warn};
 
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterId, ClusterName};
 use opendut_model::peer::state::PeerConnectionState;
 use opendut_model::peer::{PeerDescriptor, PeerId};
 use opendut_model::topology::{DeviceDescriptor, DeviceId};
+use opendut_model::peer::configuration::parameter;
+use opendut_model::peer::configuration::parameter::CanTransport;
+use opendut_model::util::can::CanFrameRules;
 use opendut_model::util::net::{NetworkInterfaceDescriptor, NetworkInterfaceName};
 use opendut_model::util::Port;
 
+use opendut_carl_api::carl::cluster::StoredClusterDeployment;
+
+use crate::manager::audit_log::{record_audit_event, AuditRecord};
 use crate::manager::peer_messaging_broker::PeerMessagingBrokerRef;
 use crate::resource::manager::ResourceManagerRef;
 use crate::resource::persistence::error::{MapErrToInner, PersistenceError, PersistenceResult};
+use crate::resource::storage::audit_log::AuditLogStorageApi;
 use crate::resource::storage::ResourcesStorageApi;
 use crate::settings::vpn::Vpn;
 
@@ -39,6 +48,9 @@ pub use list_cluster_peers::*;
 pub mod list_deployed_clusters;
 mod effects;
 
+pub mod resolve_cluster_devices;
+pub(crate) use resolve_cluster_devices::*;
+
 use crate::manager::peer_manager::{AssignClusterOptions, AssignClusterParams, ClusterAssignment, PeerClusterAssignment};
 pub use list_deployed_clusters::*;
 
@@ -92,7 +104,9 @@ impl ClusterManager {
 
 
     #[tracing::instrument(skip(self), level="trace")]
-    pub async fn store_cluster_deployment(&mut self, deployment: ClusterDeployment) -> Result<ClusterId, StoreClusterDeploymentError> {
+    /// Stores the deployment and records it in the audit log, if successful.
+    /// Failures are left to the caller to record.
+    pub async fn store_cluster_deployment(&mut self, deployment: ClusterDeployment, audit: AuditRecord) -> Result<StoredClusterDeployment, StoreClusterDeploymentError> {
         let cluster_id = deployment.id;
 
         let cluster_peers =
@@ -102,6 +116,16 @@ impl ClusterManager {
             .map_err_to_inner(|source| ListClusterPeerStatesError::Persistence { cluster_id, source })
             .map_err(|source| StoreClusterDeploymentError::ListClusterPeerStates { cluster_id, source })?;
 
+        //resolved before storing anything, so that a failure here is not recorded in addition to a successful deployment
+        let devices = self.resource_manager.resources(async |resources| {
+            match resources.get::<ClusterDescriptor>(cluster_id)? {
+                Some(cluster_descriptor) => resolve_cluster_devices(resources, &cluster_descriptor),
+                None => Ok(HashSet::new()),
+            }
+        }).await
+            .flatten()
+            .map_err(|source| StoreClusterDeploymentError::Persistence { cluster_id, cluster_name: None, source })?;
+
         let cluster_deployable = cluster_peers.check_all_peers_are_available_not_necessarily_online();
         match cluster_deployable {
             ClusterDeployable::AllPeersAvailable => {
@@ -112,7 +136,10 @@ impl ClusterManager {
                         .unwrap_or_else(|| ClusterName::try_from("unknown_cluster").unwrap());
 
                     resources.insert(cluster_id, deployment)
-                        .map_err(|source| StoreClusterDeploymentError::Persistence { cluster_id, cluster_name: Some(cluster_name.clone()), source })
+                        .map_err(|source| StoreClusterDeploymentError::Persistence { cluster_id, cluster_name: Some(cluster_name.clone()), source })?;
+
+                    resources.append_audit_event(audit.success())
+                        .map_err(|source| StoreClusterDeploymentError::Persistence { cluster_id, cluster_name: Some(cluster_name), source })
                 }).await
                     .map_err(|source| StoreClusterDeploymentError::Persistence { cluster_id, cluster_name: None, source })??;
             }
@@ -123,13 +150,15 @@ impl ClusterManager {
             }
             ClusterDeployable::AlreadyDeployed => {
                 trace!("Received instruction to store deployment for cluster <{cluster_id}>, which already exists. Ignoring.");
+                record_audit_event(&self.resource_manager, audit.success()).await;
             }
         }
 
         if let Err(error) = self.rollout_cluster_if_all_peers_available(cluster_id).await {
             error!("Failed to deploy cluster <{cluster_id}> after storing cluster deployment, despite all peers being available, due to:\n  {error}");
         }
-        Ok(cluster_id)
+
+        Ok(StoredClusterDeployment { cluster_id, devices })
     }
 
     #[tracing::instrument(skip(self), level="trace")]
@@ -152,16 +181,16 @@ impl ClusterManager {
             let peer_descriptor = resources.get::<PeerDescriptor>(peer_id)?
                 .context(format!("No peer descriptor found for newly available peer <{peer_id}>."))?;
 
-            let peer_devices = peer_descriptor.topology.devices
-                .into_iter()
+            let peer_devices = peer_descriptor.topology.devices.iter()
                 .map(|device| device.id)
-                .collect::<Vec<_>>();
+                .collect::<HashSet<_>>();
 
             let cluster_descriptors = resources.list::<ClusterDescriptor>()?;
 
             let clusters_containing_devices_of_upped_peer = cluster_descriptors.into_iter()
+                .filter(|(_, cluster_descriptor)| cluster_descriptor.namespace == peer_descriptor.namespace)
                 .filter(|(_, cluster_descriptor)|
-                    cluster_descriptor.devices.iter()
+                    cluster_descriptor.resolve_devices(&peer_descriptor.topology.devices).iter()
                         .any(|device| peer_devices.contains(device))
                 )
                 .filter_map(|(cluster_id, _)| { //filter out clusters without stored deployment
@@ -224,21 +253,40 @@ impl ClusterManager {
             .map_err(|source| RolloutClusterError::Persistence { cluster_id, source })?
             .ok_or(RolloutClusterError::ClusterDescriptorNotFound(cluster_id))?;
 
-        let cluster_name = cluster_config.name;
+        let cluster_name = Clone::clone(&cluster_config.name);
 
         let all_peers = self.resource_manager.list::<PeerDescriptor>().await
             .map_err(|source| RolloutClusterError::Persistence { cluster_id, source })?
             .into_values()
+            .filter(|peer| peer.namespace == cluster_config.namespace) //clusters only ever span peers of their own namespace
             .collect::<Vec<_>>();
 
 
-        let member_interface_mapping = determine_member_interface_mapping(cluster_config.devices, all_peers, cluster_config.leader)
+        let cluster_devices = cluster_config.resolve_devices(all_peers.iter().flat_map(|peer| &peer.topology.devices));
+
+        if cluster_config.device_selectors.is_empty().not() {
+            info!(
+                "Resolved devices of cluster '{cluster_name}' <{cluster_id}> for deployment: {}",
+                cluster_devices.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        let mut member_can_rules = determine_member_can_rules(&cluster_config, &all_peers);
+        let mut member_restbus_simulations = determine_member_restbus_simulations(&cluster_config, &cluster_devices, &all_peers);
+
+        let member_interface_mapping = determine_member_interface_mapping(cluster_devices, all_peers, cluster_config.leader)
             .map_err(|cause| match cause {
-                DetermineMemberInterfaceMappingError::PeerForDeviceNotFound { device_id } => RolloutClusterError::PeerForDeviceNotFound { device_id, cluster_id, cluster_name },
+                DetermineMemberInterfaceMappingError::PeerForDeviceNotFound { device_id } => RolloutClusterError::PeerForDeviceNotFound { device_id, cluster_id, cluster_name: Clone::clone(&cluster_name) },
             })?;
 
         let member_ids = member_interface_mapping.keys().copied().collect::<Vec<_>>();
 
+        let cluster_links = cluster_config.topology.links(cluster_config.leader, &member_ids.iter().copied().collect())
+            .map_err(|source| RolloutClusterError::IllegalTopology { cluster_id, cluster_name, source })?;
+
         if let Vpn::Enabled { vpn_client } = &self.vpn {
             vpn_client.create_cluster(cluster_id, &member_ids).await
                 .map_err(|cause| {
@@ -293,6 +341,7 @@ impl ClusterManager {
 
         let assign_cluster_options = AssignClusterOptions {
             bridge_name_default: self.options.bridge_name_default.clone(),
+            can_transport: self.options.can_transport,
         };
 
         self.resource_manager.resources_mut(async |resources| {
@@ -305,8 +354,12 @@ impl ClusterManager {
                         id: cluster_id,
                         leader: cluster_config.leader,
                         assignments: member_assignments.clone(),
+                        topology: Clone::clone(&cluster_config.topology),
+                        links: Clone::clone(&cluster_links),
                     },
                     device_interfaces,
+                    can_rules: member_can_rules.remove(&member_id).unwrap_or_default(),
+                    restbus_simulations: member_restbus_simulations.remove(&member_id).unwrap_or_default(),
                     options: assign_cluster_options.clone(),
                 }).await
                 .map_err(|cause| {
@@ -363,6 +416,69 @@ impl ClusterManager {
     }
 }
 
+/// Maps the CAN rules configured for the devices of a cluster to the network interfaces of the devices, grouped by peer.
+fn determine_member_can_rules(
+    cluster_config: &ClusterDescriptor,
+    all_peers: &[PeerDescriptor],
+) -> HashMap<PeerId, HashMap<NetworkInterfaceName, CanFrameRules>> {
+
+    let mut result: HashMap<PeerId, HashMap<NetworkInterfaceName, CanFrameRules>> = HashMap::new();
+
+    for device_rules in &cluster_config.can_rules {
+        match device_interface_name(device_rules.device, all_peers) {
+            Some((peer_id, interface_name)) => {
+                result.entry(peer_id)
+                    .or_default()
+                    .insert(interface_name, device_rules.rules.clone());
+            }
+            None => warn!("Ignoring CAN rules for device <{}> of cluster <{}>, because no network interface was found for it.", device_rules.device, cluster_config.id),
+        }
+    }
+    result
+}
+
+/// Maps the restbus simulations configured for the devices of a cluster to the network interfaces of the devices, grouped by peer.
+/// Simulations of devices, which are not part of the cluster, are ignored.
+fn determine_member_restbus_simulations(
+    cluster_config: &ClusterDescriptor,
+    cluster_devices: &HashSet<DeviceId>,
+    all_peers: &[PeerDescriptor],
+) -> HashMap<PeerId, Vec<parameter::RestbusSimulation>> {
+
+    let mut result: HashMap<PeerId, Vec<parameter::RestbusSimulation>> = HashMap::new();
+
+    for simulation in &cluster_config.restbus_simulations {
+        if cluster_devices.contains(&simulation.device).not() {
+            warn!("Ignoring restbus simulation for device <{}> of cluster <{}>, because the device is not part of the cluster.", simulation.device, cluster_config.id);
+            continue;
+        }
+
+        match device_interface_name(simulation.device, all_peers) {
+            Some((peer_id, can_interface_name)) => {
+                result.entry(peer_id)
+                    .or_default()
+                    .push(parameter::RestbusSimulation {
+                        can_interface_name,
+                        arxml_file: simulation.arxml_file.clone(),
+                        ecus: simulation.ecus.clone(),
+                    });
+            }
+            None => warn!("Ignoring restbus simulation for device <{}> of cluster <{}>, because no network interface was found for it.", simulation.device, cluster_config.id),
+        }
+    }
+    result
+}
+
+/// Determines the peer of a device and the name of the network interface, which the device is attached to.
+fn device_interface_name(device_id: DeviceId, all_peers: &[PeerDescriptor]) -> Option<(PeerId, NetworkInterfaceName)> {
+    all_peers.iter().find_map(|peer| {
+        peer.topology.devices.iter()
+            .find(|device| device.id == device_id)
+            .and_then(|device| peer.network.interfaces.iter().find(|interface| interface.id == device.interface))
+            .map(|interface| (peer.id, interface.name.clone()))
+    })
+}
+
 fn determine_member_interface_mapping(
     cluster_devices: HashSet<DeviceId>,
     all_peers: Vec<PeerDescriptor>,
@@ -409,6 +525,7 @@ pub struct ClusterManagerOptions {
     pub can_server_port_range_start: u16,
     pub can_server_port_range_end: u16,
     pub bridge_name_default: NetworkInterfaceName,
+    pub can_transport: CanTransport,
 }
 impl ClusterManagerOptions {
     pub fn load(config: &config::Config) -> Result<Self, opendut_util::settings::LoadError> {
@@ -422,10 +539,18 @@ impl ClusterManagerOptions {
         let bridge_name_default = NetworkInterfaceName::try_from(bridge_name_default.clone())
             .map_err(|cause| opendut_util::settings::LoadError::ParseValue { field, value: bridge_name_default, source: cause.into() })?;
 
+        let field = "peer.can.transport";
+        let can_transport = config.get_string(field)
+            .map_err(|cause| opendut_util::settings::LoadError::ReadField { field, source: cause.into() })?;
+
+        let can_transport = CanTransport::from_str(&can_transport)
+            .map_err(|cause| opendut_util::settings::LoadError::ParseValue { field, value: can_transport, source: cause.into() })?;
+
         Ok(ClusterManagerOptions {
             can_server_port_range_start,
             can_server_port_range_end,
             bridge_name_default,
+            can_transport,
         })
     }
 }
@@ -437,7 +562,7 @@ enum DetermineMemberInterfaceMappingError {
 
 pub mod error {
     use super::*;
-    use opendut_model::cluster::ClusterDisplay;
+    use opendut_model::cluster::{ClusterDisplay, IllegalClusterTopology};
 
     #[derive(thiserror::Error, Debug)]
     #[error("ClusterDescriptor <{cluster_id}> could not be retrieved")]
@@ -490,6 +615,12 @@ pub mod error {
             cluster_id: ClusterId,
             cluster_name: ClusterName,
         },
+        #[error("Topology of cluster '{cluster_name}' <{cluster_id}> is invalid: {source}")]
+        IllegalTopology {
+            cluster_id: ClusterId,
+            cluster_name: ClusterName,
+            #[source] source: IllegalClusterTopology,
+        },
         #[error("Error when listing cluster peer states while rolling out cluster <{cluster_id}>")]
         ListClusterPeerStates {
             cluster_id: ClusterId,
@@ -518,6 +649,7 @@ pub mod error {
 mod test {
     use std::collections::HashSet;
     use std::net::IpAddr;
+    use std::path::PathBuf;
     use std::str::FromStr;
     use std::time::Duration;
 
@@ -525,7 +657,7 @@ mod test {
     use rstest::{fixture, rstest};
     use tokio::sync::mpsc;
 
-    use opendut_model::cluster::ClusterName;
+    use opendut_model::cluster::{ClusterName, DeviceRestbusSimulation};
     use opendut_model::peer::executor::{container::{ContainerCommand, ContainerImage, ContainerName, Engine}, ExecutorDescriptor, ExecutorDescriptors, ExecutorId, ExecutorKind};
     use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
     use opendut_model::topology::{DeviceDescription, DeviceDescriptor, DeviceId, DeviceName, Topology};
@@ -558,6 +690,12 @@ mod test {
                 name: ClusterName::try_from("MyAwesomeCluster")?,
                 leader: leader_id,
                 devices: HashSet::from([peer_a.device, peer_b.device]),
+                device_selectors: vec![],
+                topology: Default::default(),
+                can_rules: vec![],
+                restbus_simulations: vec![],
+                revision: Default::default(),
+                namespace: Default::default(),
             };
 
             fixture.resource_manager.resources_mut::<_, (), anyhow::Error>(async |resources| {
@@ -605,10 +743,12 @@ mod test {
                     &parameter::InterfaceJoinConfig {
                         name: gre,
                         bridge: bridge_name.clone(),
+                        isolated: false,
                     },
                     &parameter::InterfaceJoinConfig {
                         name: eth0,
                         bridge: bridge_name,
+                        isolated: false,
                     }
                 ]);
 
@@ -702,6 +842,8 @@ mod test {
                     devices,
                 },
                 executors: ExecutorDescriptors { executors: vec![] },
+                revision: Default::default(),
+                namespace: Default::default(),
             }
         }
 
@@ -726,6 +868,46 @@ mod test {
         Ok(())
     }
 
+    #[rstest]
+    fn should_determine_member_restbus_simulations_for_devices_of_the_cluster(
+        peer_a: PeerFixture,
+        peer_b: PeerFixture,
+    ) -> anyhow::Result<()> {
+        let simulation = |device: DeviceId| DeviceRestbusSimulation {
+            device,
+            arxml_file: PathBuf::from("/etc/opendut/restbus.arxml"),
+            ecus: vec![String::from("Engine")],
+        };
+        let cluster_descriptor = ClusterDescriptor {
+            id: ClusterId::random(),
+            name: ClusterName::try_from("RestbusCluster")?,
+            leader: peer_a.id,
+            devices: HashSet::from([peer_a.device]),
+            device_selectors: vec![],
+            topology: Default::default(),
+            can_rules: vec![],
+            restbus_simulations: vec![simulation(peer_a.device), simulation(peer_b.device)],
+            revision: Default::default(),
+            namespace: Default::default(),
+        };
+        let all_peers = [Clone::clone(&peer_a.descriptor), Clone::clone(&peer_b.descriptor)];
+
+        let cluster_devices = cluster_descriptor.resolve_devices(all_peers.iter().flat_map(|peer| &peer.topology.devices));
+        let result = determine_member_restbus_simulations(&cluster_descriptor, &cluster_devices, &all_peers);
+
+        assert_that!(
+            result,
+            unordered_elements_are![
+                (eq(&peer_a.id), elements_are![eq(&parameter::RestbusSimulation {
+                    can_interface_name: NetworkInterfaceName::try_from("eth0")?,
+                    arxml_file: PathBuf::from("/etc/opendut/restbus.arxml"),
+                    ecus: vec![String::from("Engine")],
+                })]),
+            ]
+        );
+        Ok(())
+    }
+
     struct Fixture {
         testee: ClusterManagerRef,
         resource_manager: ResourceManagerRef,
@@ -826,6 +1008,8 @@ mod test {
                     }
                 ],
             },
+            revision: Default::default(),
+            namespace: Default::default(),
         };
         PeerFixture {
             id,
@@ -0,0 +1,21 @@
+use crate::resource::persistence::error::PersistenceError;
+use crate::resource::storage::ResourcesStorageApi;
+use opendut_model::cluster::ClusterDescriptor;
+use opendut_model::peer::PeerDescriptor;
+use opendut_model::topology::DeviceId;
+use std::collections::HashSet;
+
+/// Determines the devices of a cluster, i.e. its explicitly listed devices
+/// and the devices of all known peers in its namespace, which currently match its device selectors.
+pub(crate) fn resolve_cluster_devices(resources: &impl ResourcesStorageApi, cluster_descriptor: &ClusterDescriptor) -> Result<HashSet<DeviceId>, PersistenceError> {
+    if cluster_descriptor.device_selectors.is_empty() {
+        return Ok(cluster_descriptor.devices.clone());
+    }
+
+    let peers = resources.list::<PeerDescriptor>()?;
+    let available_devices = peers.values()
+        .filter(|peer| peer.namespace == cluster_descriptor.namespace)
+        .flat_map(|peer| &peer.topology.devices);
+
+    Ok(cluster_descriptor.resolve_devices(available_devices))
+}
@@ -0,0 +1,66 @@
+use tonic::{Request, Response, Status};
+use tracing::trace;
+
+use opendut_carl_api::carl::audit::ListAuditEventsRequest;
+use opendut_carl_api::proto::services::audit_log;
+use opendut_carl_api::proto::services::audit_log::audit_log_server::{AuditLog as AuditLogService, AuditLogServer};
+use opendut_carl_api::proto::services::audit_log::{list_audit_events_response, ListAuditEventsResponse};
+use crate::manager::audit_log::{ListAuditEventsError, ListAuditEventsParams};
+use crate::manager::grpc::error::LogApiErr;
+use crate::resource::manager::ResourceManagerRef;
+
+pub struct AuditLogFacade {
+    resource_manager: ResourceManagerRef,
+}
+
+impl AuditLogFacade {
+
+    pub fn new(resource_manager: ResourceManagerRef) -> Self {
+        Self { resource_manager }
+    }
+
+    pub fn into_grpc_service(self) -> super::web::CorsGrpcWeb<AuditLogServer<Self>> {
+        super::web::enable(AuditLogServer::new(self))
+    }
+}
+
+#[tonic::async_trait]
+impl AuditLogService for AuditLogFacade {
+
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn list_audit_events(&self, request: Request<audit_log::ListAuditEventsRequest>) -> Result<Response<ListAuditEventsResponse>, Status> {
+
+        let request = ListAuditEventsRequest::try_from(request.into_inner())
+            .map_err(|cause| Status::invalid_argument(format!("Request is not valid: {cause}")))?;
+
+        trace!("Received request to list audit events: {request:?}");
+
+        let page_size = match request.page_size {
+            0 => ListAuditEventsRequest::DEFAULT_PAGE_SIZE,
+            page_size => page_size.min(ListAuditEventsRequest::MAX_PAGE_SIZE),
+        };
+        let params = ListAuditEventsParams {
+            since: request.since,
+            page_size: page_size as usize,
+            page_token: request.page_token,
+        };
+
+        let result = self.resource_manager.resources(async |resources|
+            resources.list_audit_events(params)
+        ).await
+            .map_err(|source| ListAuditEventsError::Persistence { source })
+            .and_then(|result| result)
+            .log_api_err();
+
+        let reply = match result {
+            Ok(page) => list_audit_events_response::Reply::Success(page.into()),
+            Err(error) => list_audit_events_response::Reply::Failure(
+                opendut_carl_api::carl::audit::ListAuditEventsError::from(error).into()
+            ),
+        };
+
+        Ok(Response::new(ListAuditEventsResponse {
+            reply: Some(reply),
+        }))
+    }
+}
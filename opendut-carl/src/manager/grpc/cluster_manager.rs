@@ -1,14 +1,16 @@
 use std::collections::HashMap;
 use opendut_carl_api::proto::services::cluster_manager::cluster_manager_server::{ClusterManager as ClusterManagerService, ClusterManagerServer};
 use opendut_carl_api::proto::services::cluster_manager::{CreateClusterDescriptorRequest, CreateClusterDescriptorResponse, create_cluster_descriptor_response, CreateClusterDescriptorSuccess, DeleteClusterDescriptorRequest, DeleteClusterDescriptorResponse, delete_cluster_descriptor_response, DeleteClusterDescriptorSuccess, GetClusterDescriptorRequest, GetClusterDescriptorResponse, get_cluster_descriptor_response, GetClusterDescriptorSuccess, GetClusterDescriptorFailure, ListClusterDescriptorsRequest, ListClusterDescriptorsResponse, list_cluster_descriptors_response, ListClusterDescriptorsSuccess, StoreClusterDeploymentRequest, StoreClusterDeploymentResponse, store_cluster_deployment_response, StoreClusterDeploymentSuccess, DeleteClusterDeploymentRequest, DeleteClusterDeploymentResponse, delete_cluster_deployment_response, DeleteClusterDeploymentSuccess, GetClusterDeploymentRequest, GetClusterDeploymentResponse, get_cluster_deployment_response, GetClusterDeploymentSuccess, GetClusterDeploymentFailure, ListClusterDeploymentsRequest, ListClusterDeploymentsResponse, list_cluster_deployments_response, ListClusterDeploymentsSuccess, ListClusterPeerStatesRequest, ListClusterPeerStatesResponse, list_cluster_peer_states_response, ListClusterPeerStatesSuccess};
+use opendut_model::audit::AuditResourceId;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterId};
 use tonic::{Request, Response, Status};
 use tracing::{error, trace};
 
+use crate::manager::audit_log::{audit_user, audited_resources_mut, record_audit_event, AuditRecord};
 use crate::manager::cluster_manager::delete_cluster_deployment::DeleteClusterDeploymentParams;
 use crate::manager::cluster_manager::{ClusterManagerRef, ClusterPeerStates, CreateClusterDescriptorError, CreateClusterDescriptorParams, DeleteClusterDescriptorError, DeleteClusterDescriptorParams, DeleteClusterDeploymentError};
 use crate::manager::grpc::error::LogApiErr;
-use crate::manager::grpc::extract;
+use crate::manager::grpc::{extract, extract_namespace_filter};
 use crate::resource::manager::ResourceManagerRef;
 use crate::resource::persistence::error::MapErrToInner;
 
@@ -36,13 +38,21 @@ impl ClusterManagerService for ClusterManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn create_cluster_descriptor(&self, request: Request<CreateClusterDescriptorRequest>) -> Result<Response<CreateClusterDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let cluster: ClusterDescriptor = extract!(request.cluster_descriptor)?;
+        let cluster_id = cluster.id;
 
         trace!("Received request to create cluster descriptor: {cluster:?}");
 
+        let audit = AuditRecord {
+            user,
+            operation: "CreateClusterDescriptor",
+            resource_ids: vec![AuditResourceId::Cluster(cluster_id)],
+        };
+
         let result =
-            self.resource_manager.resources_mut(async |resources|
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
                 resources.create_cluster_descriptor(CreateClusterDescriptorParams {
                     cluster_descriptor: cluster.clone(),
                 })
@@ -71,15 +81,24 @@ impl ClusterManagerService for ClusterManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_cluster_descriptor(&self, request: Request<DeleteClusterDescriptorRequest>) -> Result<Response<DeleteClusterDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let cluster_id: ClusterId = extract!(request.cluster_id)?;
+        let namespace = extract_namespace_filter(request.namespace)?;
 
-        trace!("Received request to delete cluster descriptor for cluster <{cluster_id}>.");
+        trace!("Received request to delete cluster descriptor for cluster <{cluster_id}> in namespace <{namespace:?}>.");
+
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteClusterDescriptor",
+            resource_ids: vec![AuditResourceId::Cluster(cluster_id)],
+        };
 
         let result =
-            self.resource_manager.resources_mut(async |resources|
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
                 resources.delete_cluster_descriptor(DeleteClusterDescriptorParams {
                     cluster_id,
+                    namespace,
                 })
             ).await
             .map_err_to_inner(|source| DeleteClusterDescriptorError::Persistence {
@@ -108,12 +127,14 @@ impl ClusterManagerService for ClusterManagerFacade {
 
         let request = request.into_inner();
         let cluster_id: ClusterId = extract!(request.id)?;
+        let namespace = extract_namespace_filter(request.namespace)?;
 
-        trace!("Received request to get cluster descriptor for cluster <{cluster_id}>.");
+        trace!("Received request to get cluster descriptor for cluster <{cluster_id}> in namespace <{namespace:?}>.");
 
         let configuration = self.cluster_manager.lock().await.get_cluster_descriptor(cluster_id).await
             .log_api_err()
-            .map_err(|cause| Status::internal(cause.to_string()))?;
+            .map_err(|cause| Status::internal(cause.to_string()))?
+            .filter(|cluster| namespace.as_ref().is_none_or(|namespace| cluster.namespace == *namespace));
 
         let result = match configuration {
             Some(configuration) => get_cluster_descriptor_response::Result::Success(
@@ -131,8 +152,10 @@ impl ClusterManagerService for ClusterManagerFacade {
         }))
     }
     #[tracing::instrument(skip_all, level="trace")]
-    async fn list_cluster_descriptors(&self, _: Request<ListClusterDescriptorsRequest>) -> Result<Response<ListClusterDescriptorsResponse>, Status> {
-        trace!("Received request to list cluster descriptors.");
+    async fn list_cluster_descriptors(&self, request: Request<ListClusterDescriptorsRequest>) -> Result<Response<ListClusterDescriptorsResponse>, Status> {
+        let namespace = extract_namespace_filter(request.into_inner().namespace)?;
+
+        trace!("Received request to list cluster descriptors in namespace <{namespace:?}>.");
 
         let configurations = self.cluster_manager.lock().await.list_cluster_descriptor().await
             .log_api_err()
@@ -141,7 +164,10 @@ impl ClusterManagerService for ClusterManagerFacade {
         Ok(Response::new(ListClusterDescriptorsResponse {
             result: Some(list_cluster_descriptors_response::Result::Success(
                 ListClusterDescriptorsSuccess {
-                    configurations: configurations.into_iter().map(std::convert::Into::into).collect::<Vec<_>>()
+                    configurations: configurations.into_iter()
+                        .filter(|cluster| namespace.as_ref().is_none_or(|namespace| cluster.namespace == *namespace))
+                        .map(std::convert::Into::into)
+                        .collect::<Vec<_>>()
                 }
             ))
         }))
@@ -150,20 +176,35 @@ impl ClusterManagerService for ClusterManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn store_cluster_deployment(&self, request: Request<StoreClusterDeploymentRequest>) -> Result<Response<StoreClusterDeploymentResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let cluster_deployment: ClusterDeployment = extract!(request.cluster_deployment)?;
+        let cluster_id = cluster_deployment.id;
 
         trace!("Received request to store cluster deployment: {cluster_deployment:?}");
 
-        let result = self.cluster_manager.lock().await.store_cluster_deployment(cluster_deployment).await
+        let audit = AuditRecord {
+            user,
+            operation: "StoreClusterDeployment",
+            resource_ids: vec![AuditResourceId::Cluster(cluster_id)],
+        };
+
+        let result = self.cluster_manager.lock().await.store_cluster_deployment(cluster_deployment, Clone::clone(&audit)).await
             .inspect_err(|cause| error!("{cause}"))
             .map_err(opendut_carl_api::carl::cluster::StoreClusterDeploymentError::from);
 
+        if result.is_err() {
+            record_audit_event(&self.resource_manager, audit.outcome(&result)).await;
+        }
+
         let reply = match result {
-            Ok(cluster_id) => {
+            Ok(stored_deployment) => {
                 store_cluster_deployment_response::Reply::Success(
                     StoreClusterDeploymentSuccess {
-                        cluster_id: Some(cluster_id.into())
+                        cluster_id: Some(stored_deployment.cluster_id.into()),
+                        devices: stored_deployment.devices.into_iter()
+                            .map(Into::into)
+                            .collect(),
                     }
                 )
             }
@@ -176,13 +217,20 @@ impl ClusterManagerService for ClusterManagerFacade {
     }
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_cluster_deployment(&self, request: Request<DeleteClusterDeploymentRequest>) -> Result<Response<DeleteClusterDeploymentResponse>, Status> {
+        let user = audit_user(&request);
         let request = request.into_inner();
         let cluster_id: ClusterId = extract!(request.cluster_id)?;
         let vpn = self.cluster_manager.lock().await.vpn.clone();
 
         trace!("Received request to delete cluster deployment for cluster <{cluster_id}>.");
 
-        let result = self.resource_manager.resources_mut(async |resources|
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteClusterDeployment",
+            resource_ids: vec![AuditResourceId::Cluster(cluster_id)],
+        };
+
+        let result = audited_resources_mut(&self.resource_manager, audit, async |resources|
             resources.delete_cluster_deployment(DeleteClusterDeploymentParams { cluster_id, vpn }).await
         ).await
             .map_err_to_inner(|source| DeleteClusterDeploymentError::Persistence {
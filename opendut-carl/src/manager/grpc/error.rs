@@ -24,6 +24,15 @@ mod cluster_manager {
     impl From<cluster_manager::CreateClusterDescriptorError> for CreateClusterDescriptorError {
         fn from(value: cluster_manager::CreateClusterDescriptorError) -> Self {
             match value {
+                cluster_manager::CreateClusterDescriptorError::Conflict { cluster_id, cluster_name, expected_revision, actual_revision } => {
+                    Self::Conflict { cluster_id, cluster_name, expected_revision, actual_revision }
+                }
+                cluster_manager::CreateClusterDescriptorError::ForeignNamespacePeers { cluster_id, cluster_name, namespace, peer_ids } => {
+                    Self::ForeignNamespacePeers { cluster_id, cluster_name, namespace, peer_ids }
+                }
+                cluster_manager::CreateClusterDescriptorError::IllegalCanRules { cluster_id, cluster_name, device_id, source } => {
+                    Self::IllegalCanRules { cluster_id, cluster_name, device_id, cause: source.to_string() }
+                }
                 cluster_manager::CreateClusterDescriptorError::Persistence { cluster_id, cluster_name, source: _ } => {
                     Self::Internal {
                         cluster_id,
@@ -104,7 +113,7 @@ mod cluster_manager {
 }
 
 mod peer_manager {
-    use opendut_carl_api::carl::peer::{DeletePeerDescriptorError, GetPeerStateError, ListPeerStatesError, StorePeerDescriptorError};
+    use opendut_carl_api::carl::peer::{DeletePeerDescriptorError, GetPeerStateError, ListExecutorStatesError, ListPeerStatesError, StorePeerDescriptorError};
     use crate::manager::peer_manager;
 
     impl From<peer_manager::store_peer_descriptor::StorePeerDescriptorError> for StorePeerDescriptorError {
@@ -112,6 +121,10 @@ mod peer_manager {
             match value {
                 peer_manager::store_peer_descriptor::StorePeerDescriptorError::IllegalPeerState { peer_id, peer_name, actual_state, required_states } =>
                     Self::IllegalPeerState { peer_id, peer_name, actual_state, required_states },
+                peer_manager::store_peer_descriptor::StorePeerDescriptorError::Conflict { peer_id, peer_name, expected_revision, actual_revision } =>
+                    Self::Conflict { peer_id, peer_name, expected_revision, actual_revision },
+                peer_manager::store_peer_descriptor::StorePeerDescriptorError::ReferencedByClusters { peer_id, peer_name, namespace, cluster_ids } =>
+                    Self::ReferencedByClusters { peer_id, peer_name, namespace, cluster_ids },
                 peer_manager::store_peer_descriptor::StorePeerDescriptorError::Persistence { peer_id, peer_name, source: _ } =>
                     Self::Internal {
                         peer_id,
@@ -183,4 +196,56 @@ mod peer_manager {
             }
         }
     }
+
+    impl From<peer_manager::list_executor_states::ListExecutorStatesError> for ListExecutorStatesError {
+        fn from(value: peer_manager::list_executor_states::ListExecutorStatesError) -> Self {
+            match value {
+                peer_manager::list_executor_states::ListExecutorStatesError::Persistence { peer_id, source: _ } =>
+                    Self::Internal {
+                        peer_id,
+                        cause: String::from("Error when accessing persistence while listing executor states"),
+                    }
+            }
+        }
+    }
+}
+
+mod audit_log {
+    use crate::manager::audit_log;
+    use opendut_carl_api::carl::audit::ListAuditEventsError;
+
+    impl From<audit_log::ListAuditEventsError> for ListAuditEventsError {
+        fn from(value: audit_log::ListAuditEventsError) -> Self {
+            match value {
+                audit_log::ListAuditEventsError::UnknownPageToken { page_token } =>
+                    Self::UnknownPageToken { page_token },
+                audit_log::ListAuditEventsError::Persistence { source: _ } =>
+                    Self::Internal {
+                        cause: String::from("Error when accessing persistence while listing audit events"),
+                    },
+            }
+        }
+    }
+}
+
+#[cfg(feature = "viper")]
+mod test_manager {
+    use crate::manager::test_manager;
+    use opendut_carl_api::carl::viper::InspectViperTestSuiteError;
+
+    impl From<test_manager::InspectViperTestSuiteError> for InspectViperTestSuiteError {
+        fn from(value: test_manager::InspectViperTestSuiteError) -> Self {
+            match value {
+                test_manager::InspectViperTestSuiteError::SourceNotFound { source_id } =>
+                    Self::SourceNotFound { source_id },
+                test_manager::InspectViperTestSuiteError::Compilation { source_id, suite, cause } =>
+                    Self::CompilationFailed { source_id, suite, cause },
+                test_manager::InspectViperTestSuiteError::Persistence { source_id, source: _ } =>
+                    Self::Internal {
+                        source_id,
+                        cause: String::from("Error when accessing persistence while inspecting test suite"),
+                    },
+            }
+        }
+    }
 }
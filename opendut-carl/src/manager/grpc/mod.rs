@@ -1,4 +1,5 @@
 use std::fmt::Display;
+pub use audit_log::AuditLogFacade;
 pub use cluster_manager::ClusterManagerFacade;
 pub use metadata_provider::MetadataProviderFacade;
 pub use peer_manager::PeerManagerFacade;
@@ -7,6 +8,7 @@ pub use observer_messaging_broker::ObserverMessagingBrokerFacade;
 #[cfg(feature = "viper")]
 pub use test_manager::TestManagerFacade;
 
+mod audit_log;
 mod cluster_manager;
 mod metadata_provider;
 mod peer_manager;
@@ -48,6 +50,14 @@ macro_rules! extract {
 
 pub(crate) use extract;
 
+/// Converts the optional namespace of a list request. If not set, the resources of all namespaces are listed.
+fn extract_namespace_filter(namespace: Option<opendut_model::proto::namespace::NamespaceName>) -> Result<Option<opendut_model::namespace::NamespaceName>, tonic::Status> {
+    namespace
+        .map(opendut_model::namespace::NamespaceName::try_from)
+        .transpose()
+        .map_err(|cause| tonic::Status::invalid_argument(format!("Field 'namespace' is not valid: {cause}")))
+}
+
 
 mod web {
     use std::task::{Context, Poll};
@@ -8,9 +8,9 @@ use tonic::{Request, Response, Status};
 use tracing::trace;
 use opendut_carl_api::proto::services::observer_messaging_broker::observer_messaging_broker_server::ObserverMessagingBrokerServer;
 use opendut_carl_api::proto::services::observer_messaging_broker::observer_messaging_broker_server::{ObserverMessagingBroker as ObserverMessagingBrokerService};
-use opendut_carl_api::proto::services::observer_messaging_broker::{WaitForPeersOnlineRequest, WaitForPeersOnlineResponse};
+use opendut_carl_api::proto::services::observer_messaging_broker::{WaitForPeersOnlineRequest, WaitForPeersOnlineResponse, WatchRequest, WatchResponse};
 use opendut_model::peer::{PeerDescriptor, PeerId};
-use crate::manager::observer_messaging_broker::ObserverMessagingBrokerRef;
+use crate::manager::observer_messaging_broker::{ObserverMessagingBrokerRef, WatchError};
 use crate::resource::manager::ResourceManagerRef;
 
 pub struct ObserverMessagingBrokerFacade {
@@ -72,4 +72,33 @@ impl ObserverMessagingBrokerService for ObserverMessagingBrokerFacade {
             Box::pin(outbound_stream) as Self::WaitForPeersOnlineStream
         ))
     }
+
+    type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send>>;
+
+    async fn watch(&self, request: Request<WatchRequest>) -> Result<Response<Self::WatchStream>, Status> {
+        let request = request.into_inner();
+        let request = opendut_carl_api::carl::observer::WatchRequest::try_from(request)
+            .map_err(|error| Status::invalid_argument(error.to_string()))?;
+
+        trace!("Received request to watch resources: {request:?}");
+        let rx_outbound = self.observer_messaging_broker.watch(request).await
+            .map_err(watch_error_to_status)?;
+        let outbound_stream = ReceiverStream::new(rx_outbound)
+            .map(|event| event
+                .map(WatchResponse::from)
+                .map_err(watch_error_to_status)
+            );
+
+        Ok(Response::new(
+            Box::pin(outbound_stream) as Self::WatchStream
+        ))
+    }
+}
+
+fn watch_error_to_status(error: WatchError) -> Status {
+    match error {
+        WatchError::UnknownRevision { .. } => Status::out_of_range(error.to_string()),
+        WatchError::RevisionExpired { .. } => Status::out_of_range(error.to_string()),
+        WatchError::Lagged { .. } => Status::data_loss(error.to_string()),
+    }
 }
@@ -3,19 +3,22 @@ use opendut_auth::registration::resources::UserId;
 use opendut_carl_api::proto;
 use opendut_carl_api::proto::services;
 use opendut_carl_api::proto::services::peer_manager::peer_manager_server::{PeerManager as PeerManagerService, PeerManagerServer};
-use opendut_carl_api::proto::services::peer_manager::{StorePeerDescriptorRequest, StorePeerDescriptorResponse, store_peer_descriptor_response, StorePeerDescriptorSuccess, DeletePeerDescriptorRequest, DeletePeerDescriptorResponse, DeletePeerDescriptorSuccess, delete_peer_descriptor_response, GetPeerDescriptorRequest, GetPeerDescriptorResponse, get_peer_descriptor_response, GetPeerDescriptorSuccess, ListPeerDescriptorsRequest, ListPeerDescriptorsResponse, list_peer_descriptors_response, ListPeerDescriptorsSuccess, GetPeerStateRequest, GetPeerStateResponse, get_peer_state_response, GetPeerStateSuccess, ListPeerStatesRequest, ListPeerStatesResponse, list_peer_states_response, ListPeerStatesSuccess, ListPeerStatesEntry, ListDevicesRequest, ListDevicesResponse, GeneratePeerSetupRequest, GeneratePeerSetupResponse, GenerateCleoSetupRequest, GenerateCleoSetupResponse, generate_cleo_setup_response, GenerateCleoSetupSuccess};
+use opendut_carl_api::proto::services::peer_manager::{StorePeerDescriptorRequest, StorePeerDescriptorResponse, store_peer_descriptor_response, StorePeerDescriptorSuccess, DeletePeerDescriptorRequest, DeletePeerDescriptorResponse, DeletePeerDescriptorSuccess, delete_peer_descriptor_response, GetPeerDescriptorRequest, GetPeerDescriptorResponse, get_peer_descriptor_response, GetPeerDescriptorSuccess, ListPeerDescriptorsRequest, ListPeerDescriptorsResponse, list_peer_descriptors_response, ListPeerDescriptorsSuccess, GetPeerStateRequest, GetPeerStateResponse, get_peer_state_response, GetPeerStateSuccess, ListPeerStatesRequest, ListPeerStatesResponse, list_peer_states_response, ListPeerStatesSuccess, ListPeerStatesEntry, ListExecutorStatesRequest, ListExecutorStatesResponse, list_executor_states_response, ListExecutorStatesSuccess, ListDevicesRequest, ListDevicesResponse, GeneratePeerSetupRequest, GeneratePeerSetupResponse, GenerateCleoSetupRequest, GenerateCleoSetupResponse, generate_cleo_setup_response, GenerateCleoSetupSuccess};
+use opendut_model::audit::AuditResourceId;
 use opendut_model::cleo::CleoId;
 use opendut_model::peer::{PeerDescriptor, PeerId};
 use tonic::{Request, Response, Status};
 use tracing::{error, trace};
 use url::Url;
 use opendut_util::pem::Pem;
-use crate::manager::grpc::extract;
+use crate::manager::audit_log::{audit_user, audited_resources_mut, record_audit_event, AuditRecord};
+use crate::manager::grpc::{extract, extract_namespace_filter};
 use crate::manager::peer_manager;
 use crate::manager::peer_manager::{DeletePeerDescriptorError, DeletePeerDescriptorParams, GenerateCleoSetupParams, GeneratePeerSetupError, GeneratePeerSetupParams, StorePeerDescriptorError, StorePeerDescriptorParams};
 use crate::manager::peer_manager::get_peer_state::GetPeerStateError;
 use crate::manager::peer_manager::list_devices::ListDevicesError;
 use crate::manager::peer_manager::list_peer_states::ListPeerStatesError;
+use crate::manager::peer_manager::list_executor_states::ListExecutorStatesError;
 use crate::resource::manager::ResourceManagerRef;
 use crate::resource::persistence::error::{MapErrToInner, PersistenceError};
 use crate::settings::vpn::Vpn;
@@ -58,13 +61,20 @@ impl PeerManagerService for PeerManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn store_peer_descriptor(&self, request: Request<StorePeerDescriptorRequest>) -> Result<Response<StorePeerDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let peer: PeerDescriptor = extract!(request.peer)?;
 
         trace!("Received request to store peer descriptor: {peer:?}");
 
+        let audit = AuditRecord {
+            user,
+            operation: "StorePeerDescriptor",
+            resource_ids: vec![AuditResourceId::Peer(peer.id)],
+        };
+
         let result =
-            self.resource_manager.resources_mut(async |resources| {
+            audited_resources_mut(&self.resource_manager, audit, async |resources| {
                 resources.store_peer_descriptor(StorePeerDescriptorParams {
                     vpn: Clone::clone(&self.vpn),
                     peer_descriptor: Clone::clone(&peer),
@@ -95,17 +105,26 @@ impl PeerManagerService for PeerManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_peer_descriptor(&self, request: Request<DeletePeerDescriptorRequest>) -> Result<Response<DeletePeerDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let peer_id: PeerId = extract!(request.peer_id)?;
+        let namespace = extract_namespace_filter(request.namespace)?;
 
-        trace!("Received request to delete peer descriptor for peer <{peer_id}>.");
+        trace!("Received request to delete peer descriptor for peer <{peer_id}> in namespace <{namespace:?}>.");
+
+        let audit = AuditRecord {
+            user,
+            operation: "DeletePeerDescriptor",
+            resource_ids: vec![AuditResourceId::Peer(peer_id)],
+        };
 
         let result =
-            self.resource_manager.resources_mut(async |resources|
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
                 resources.delete_peer_descriptor(DeletePeerDescriptorParams {
                     vpn: Clone::clone(&self.vpn),
                     peer: peer_id,
                     oidc_registration_client: self.oidc_registration_client.clone(),
+                    namespace,
                 }).await
             ).await
             .map_err_to_inner(|source| DeletePeerDescriptorError::Persistence {
@@ -135,8 +154,9 @@ impl PeerManagerService for PeerManagerFacade {
 
         let request = request.into_inner();
         let peer_id: PeerId = extract!(request.peer_id)?;
+        let namespace = extract_namespace_filter(request.namespace)?;
 
-        trace!("Received request to get peer descriptor for peer <{peer_id}>.");
+        trace!("Received request to get peer descriptor for peer <{peer_id}> in namespace <{namespace:?}>.");
 
         let result =
             self.resource_manager.get::<PeerDescriptor>(peer_id).await
@@ -147,7 +167,7 @@ impl PeerManagerService for PeerManagerFacade {
                 });
 
         let response = match result {
-            Ok(descriptor) => match descriptor {
+            Ok(descriptor) => match descriptor.filter(|peer| namespace.as_ref().is_none_or(|namespace| peer.namespace == *namespace)) {
                 Some(descriptor) => get_peer_descriptor_response::Reply::Success(
                     GetPeerDescriptorSuccess {
                         descriptor: Some(descriptor.into())
@@ -166,9 +186,10 @@ impl PeerManagerService for PeerManagerFacade {
     }
 
     #[tracing::instrument(skip_all, level="trace")]
-    async fn list_peer_descriptors(&self, _: Request<ListPeerDescriptorsRequest>) -> Result<Response<ListPeerDescriptorsResponse>, Status> {
+    async fn list_peer_descriptors(&self, request: Request<ListPeerDescriptorsRequest>) -> Result<Response<ListPeerDescriptorsResponse>, Status> {
+        let namespace = extract_namespace_filter(request.into_inner().namespace)?;
 
-        trace!("Received request to list peer descriptors.");
+        trace!("Received request to list peer descriptors in namespace <{namespace:?}>.");
 
         let result = self.resource_manager.list::<PeerDescriptor>().await
             .inspect_err(|error| error!("Error while listing peer descriptors from gRPC API: {error}"))
@@ -179,6 +200,7 @@ impl PeerManagerService for PeerManagerFacade {
         let response = match result {
             Ok(peers) => {
                 let peers = peers.into_values()
+                    .filter(|peer| namespace.as_ref().is_none_or(|namespace| peer.namespace == *namespace))
                     .map(From::from)
                     .collect::<Vec<_>>();
 
@@ -261,6 +283,41 @@ impl PeerManagerService for PeerManagerFacade {
         }))
     }
 
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn list_executor_states(&self, request: Request<ListExecutorStatesRequest>) -> Result<Response<ListExecutorStatesResponse>, Status> {
+
+        let request = request.into_inner();
+        let peer_id: PeerId = extract!(request.peer_id)?;
+
+        trace!("Received request to list executor states of peer <{peer_id}>.");
+
+        let result =
+            self.resource_manager.resources(async |resources|
+                resources.list_executor_states(peer_id)
+            ).await
+            .map_err_to_inner(|source| ListExecutorStatesError::Persistence {
+                peer_id,
+                source: source.context("Persistence error in transaction for listing executor states"),
+            })
+            .log_api_err()
+            .map_err(opendut_carl_api::carl::peer::ListExecutorStatesError::from);
+
+        let reply = match result {
+            Ok(executor_states) => list_executor_states_response::Reply::Success(
+                ListExecutorStatesSuccess {
+                    executor_states: executor_states.into_iter()
+                        .map(Into::into)
+                        .collect(),
+                }
+            ),
+            Err(error) => list_executor_states_response::Reply::Failure(error.into()),
+        };
+
+        Ok(Response::new(ListExecutorStatesResponse {
+            reply: Some(reply),
+        }))
+    }
+
     #[tracing::instrument(skip_all, level="trace")]
     async fn list_devices(&self, _: Request<ListDevicesRequest>) -> Result<Response<ListDevicesResponse>, Status> {
 
@@ -286,11 +343,12 @@ impl PeerManagerService for PeerManagerFacade {
     async fn generate_peer_setup(&self, request: Request<GeneratePeerSetupRequest>) -> Result<Response<GeneratePeerSetupResponse>, Status> { // TODO: Refactor error types.
         trace!("Received request to generate peer setup.");
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let peer_id: PeerId = extract!(request.peer)?;
         let user_id = UserId { value: request.user_id };
 
-        let setup =
+        let result =
             self.resource_manager.resources(async |resources|
                 resources.generate_peer_setup(GeneratePeerSetupParams {
                     peer: peer_id,
@@ -305,7 +363,16 @@ impl PeerManagerService for PeerManagerFacade {
                 peer_id,
                 source: source.context("Persistence error in transaction for getting peer state"),
             })
-            .log_api_err()
+            .log_api_err();
+
+        let audit = AuditRecord {
+            user,
+            operation: "GeneratePeerSetup",
+            resource_ids: vec![AuditResourceId::Peer(peer_id)],
+        };
+        record_audit_event(&self.resource_manager, audit.outcome(&result)).await;
+
+        let setup = result
             .map_err(|_| Status::internal("Peer setup could not be created"))?;
 
         let response = services::peer_manager::generate_peer_setup_response::Reply::Success(services::peer_manager::GeneratePeerSetupSuccess {
@@ -320,10 +387,11 @@ impl PeerManagerService for PeerManagerFacade {
     async fn generate_cleo_setup(&self, request: Request<GenerateCleoSetupRequest>) -> Result<Response<GenerateCleoSetupResponse>, Status> {
         trace!("Received request to generate CLEO Setup information.");
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         
         let cleo_id = CleoId::random();
-        let setup =
+        let result =
             peer_manager::generate_cleo_setup(GenerateCleoSetupParams {
                 cleo: cleo_id,
                 carl_url: Clone::clone(&self.carl_url),
@@ -331,7 +399,16 @@ impl PeerManagerService for PeerManagerFacade {
                 oidc_registration_client: self.oidc_registration_client.clone(),
                 user_id: UserId { value: request.user_id },
             }).await
-            .log_api_err()
+            .log_api_err();
+
+        let audit = AuditRecord {
+            user,
+            operation: "GenerateCleoSetup",
+            resource_ids: vec![],
+        };
+        record_audit_event(&self.resource_manager, audit.outcome(&result)).await;
+
+        let setup = result
             .map_err(|_| Status::internal("CLEO Setup could not be created"))?;
         
         let response = generate_cleo_setup_response::Reply::Success(GenerateCleoSetupSuccess { 
@@ -355,9 +432,11 @@ mod tests {
     use crate::resource::manager::ResourceManager;
     use crate::settings::vpn::Vpn;
     use opendut_carl_api::proto::services;
+    use opendut_model::namespace::NamespaceName;
     use opendut_model::peer::executor::{container::{ContainerCommand, ContainerImage, ContainerName, Engine}, ExecutorDescriptor, ExecutorDescriptors, ExecutorId, ExecutorKind};
     use opendut_model::peer::{PeerLocation, PeerName, PeerNetworkDescriptor};
     use opendut_model::proto;
+    use opendut_model::resources::Revision;
     use opendut_model::topology::Topology;
     use opendut_model::util::net::{NetworkInterfaceConfiguration, NetworkInterfaceDescriptor, NetworkInterfaceId, NetworkInterfaceName};
 
@@ -419,6 +498,8 @@ mod tests {
                     }
                 ],
             },
+            revision: Default::default(),
+            namespace: Default::default(),
         };
 
         let create_peer_reply = testee.store_peer_descriptor(Request::new(
@@ -437,21 +518,56 @@ mod tests {
             ))
         )?;
 
+        let stored_peer_descriptor = PeerDescriptor {
+            revision: Revision(1),
+            ..peer_descriptor
+        };
         let list_reply = testee.list_peer_descriptors(Request::new(
-            ListPeerDescriptorsRequest {}
+            ListPeerDescriptorsRequest { namespace: None }
         )).await?;
         verify_that!(
             list_reply.get_ref().reply,
             some(matches_pattern!(list_peer_descriptors_response::Reply::Success(
                 matches_pattern!(ListPeerDescriptorsSuccess {
-                    peers: container_eq([peer_descriptor.into()])
+                    peers: container_eq([stored_peer_descriptor.into()])
                 })
             )))
         )?;
 
+        let get_reply = testee.get_peer_descriptor(Request::new(
+            GetPeerDescriptorRequest {
+                peer_id: Some(peer_id.into()),
+                namespace: Some(NamespaceName::default().into()),
+            }
+        )).await?;
+        verify_that!(get_reply.get_ref().reply,
+            some(matches_pattern!(get_peer_descriptor_response::Reply::Success(anything())))
+        )?;
+
+        let get_reply = testee.get_peer_descriptor(Request::new(
+            GetPeerDescriptorRequest {
+                peer_id: Some(peer_id.into()),
+                namespace: Some(NamespaceName::try_from("other")?.into()),
+            }
+        )).await?;
+        verify_that!(get_reply.get_ref().reply,
+            some(matches_pattern!(get_peer_descriptor_response::Reply::Failure(anything())))
+        )?;
+
+        let delete_reply = testee.delete_peer_descriptor(Request::new(
+            services::peer_manager::DeletePeerDescriptorRequest {
+                peer_id: Some(peer_id.into()),
+                namespace: Some(NamespaceName::try_from("other")?.into()),
+            }
+        )).await?;
+        verify_that!(delete_reply.get_ref().reply,
+            some(matches_pattern!(delete_peer_descriptor_response::Reply::Failure(anything())))
+        )?;
+
         let delete_reply = testee.delete_peer_descriptor(Request::new(
             services::peer_manager::DeletePeerDescriptorRequest {
                 peer_id: Some(peer_id.into()),
+                namespace: None,
             }
         )).await?;
         verify_that!(delete_reply.get_ref().reply,
@@ -459,7 +575,7 @@ mod tests {
         )?;
 
         let list_reply = testee.list_peer_descriptors(Request::new(
-            services::peer_manager::ListPeerDescriptorsRequest {}
+            services::peer_manager::ListPeerDescriptorsRequest { namespace: None }
         )).await?;
         verify_that!(list_reply.get_ref().reply,
             some(matches_pattern!(list_peer_descriptors_response::Reply::Success(
@@ -496,7 +612,7 @@ mod tests {
         )?;
 
         let list_reply = testee.list_peer_descriptors(Request::new(
-            services::peer_manager::ListPeerDescriptorsRequest {}
+            services::peer_manager::ListPeerDescriptorsRequest { namespace: None }
         )).await?;
 
         verify_that!(
@@ -526,6 +642,7 @@ mod tests {
         let delete_peer_reply = testee.delete_peer_descriptor(Request::new(
             services::peer_manager::DeletePeerDescriptorRequest {
                 peer_id: None,
+                namespace: None,
             }
         )).await;
 
@@ -535,7 +652,7 @@ mod tests {
         )?;
 
         let list_reply = testee.list_peer_descriptors(Request::new(
-            services::peer_manager::ListPeerDescriptorsRequest {}
+            services::peer_manager::ListPeerDescriptorsRequest { namespace: None }
         )).await?;
 
         verify_that!(
@@ -1,17 +1,28 @@
+use std::sync::Arc;
+
 use tonic::{Request, Response, Status};
 use tracing::{error, trace};
 use opendut_carl_api::proto::services::test_manager::{delete_viper_source_descriptor_response, get_viper_source_descriptor_response, list_viper_source_descriptors_response, store_viper_source_descriptor_response, DeleteViperSourceDescriptorRequest, DeleteViperSourceDescriptorResponse, DeleteViperSourceDescriptorSuccess, GetViperSourceDescriptorRequest, GetViperSourceDescriptorResponse, GetViperSourceDescriptorSuccess, ListViperSourceDescriptorsRequest, ListViperSourceDescriptorsResponse, ListViperSourceDescriptorsSuccess, StoreViperSourceDescriptorRequest, StoreViperSourceDescriptorResponse, StoreViperSourceDescriptorSuccess};
 use opendut_carl_api::proto::services::test_manager::{delete_viper_run_descriptor_response, get_viper_run_descriptor_response, list_viper_run_descriptors_response, store_viper_run_descriptor_response, DeleteViperRunDescriptorRequest, DeleteViperRunDescriptorResponse, DeleteViperRunDescriptorSuccess, GetViperRunDescriptorRequest, GetViperRunDescriptorResponse, GetViperRunDescriptorSuccess, ListViperRunDescriptorsRequest, ListViperRunDescriptorsResponse, ListViperRunDescriptorsSuccess, StoreViperRunDescriptorRequest, StoreViperRunDescriptorResponse, StoreViperRunDescriptorSuccess};
 use opendut_carl_api::proto::services::test_manager::{delete_viper_run_deployment_response, get_viper_run_deployment_response, list_viper_run_deployments_response, store_viper_run_deployment_response, DeleteViperRunDeploymentRequest, DeleteViperRunDeploymentResponse, DeleteViperRunDeploymentSuccess, GetViperRunDeploymentRequest, GetViperRunDeploymentResponse, GetViperRunDeploymentSuccess, ListViperRunDeploymentsRequest, ListViperRunDeploymentsResponse, ListViperRunDeploymentsSuccess, StoreViperRunDeploymentRequest, StoreViperRunDeploymentResponse, StoreViperRunDeploymentSuccess};
+use opendut_carl_api::proto::services::test_manager::{get_viper_run_state_response, GetViperRunStateRequest, GetViperRunStateResponse, GetViperRunStateSuccess};
+use opendut_carl_api::proto::services::test_manager::{delete_viper_run_result_response, get_viper_run_result_response, list_viper_run_results_response, DeleteViperRunResultRequest, DeleteViperRunResultResponse, DeleteViperRunResultSuccess, GetViperRunResultRequest, GetViperRunResultResponse, GetViperRunResultSuccess, ListViperRunResultsRequest, ListViperRunResultsResponse, ListViperRunResultsSuccess};
+use opendut_carl_api::proto::services::test_manager::{inspect_viper_test_suite_response, InspectViperTestSuiteRequest, InspectViperTestSuiteResponse, InspectViperTestSuiteSuccess};
 use opendut_carl_api::proto::services::test_manager::test_manager_server::{TestManager as TestManagerService, TestManagerServer};
-use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperSourceDescriptor, ViperSourceId};
+use opendut_model::audit::AuditResourceId;
+use opendut_viper_rt::common::TestSuiteIdentifier;
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunResult, ViperRunState, ViperSourceDescriptor, ViperSourceId};
+use crate::manager::audit_log::{audit_user, audited_resources_mut, AuditRecord};
 use crate::manager::grpc::error::LogApiErr;
-use crate::manager::grpc::extract;
+use crate::manager::test_manager::{inspect_viper_test_suite, InspectViperTestSuiteParams, ViperTestSuiteInspectionCache};
+use crate::manager::grpc::{extract, extract_namespace_filter};
 use crate::resource::manager::ResourceManagerRef;
-use crate::resource::persistence::error::PersistenceError;
+use crate::resource::persistence::error::{MapErrToInner, PersistenceError};
+use crate::resource::storage::ResourcesStorageApi;
 
 pub struct TestManagerFacade {
     pub resource_manager: ResourceManagerRef,
+    pub inspection_cache: Arc<ViperTestSuiteInspectionCache>,
 }
 
 impl TestManagerFacade {
@@ -30,14 +41,24 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn store_viper_source_descriptor(&self, request: Request<StoreViperSourceDescriptorRequest>) -> Result<Response<StoreViperSourceDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let source: ViperSourceDescriptor = extract!(request.source)?;
 
         trace!("Received request to store test suite source descriptor: {source:?}");
 
 
+        let audit = AuditRecord {
+            user,
+            operation: "StoreViperSourceDescriptor",
+            resource_ids: vec![AuditResourceId::ViperSource(source.id.uuid)],
+        };
+
         let result =
-            self.resource_manager.insert(source.id, source.clone()).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.insert(source.id, source.clone())
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::StoreViperSourceDescriptorError::Internal {
                     source_id: source.id,
@@ -62,13 +83,23 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_viper_source_descriptor(&self, request: Request<DeleteViperSourceDescriptorRequest>) -> Result<Response<DeleteViperSourceDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let source_id: ViperSourceId = extract!(request.source_id)?;
 
         trace!("Received request to delete test suite source descriptor for source <{source_id}>.");
 
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteViperSourceDescriptor",
+            resource_ids: vec![AuditResourceId::ViperSource(source_id.uuid)],
+        };
+
         let result =
-            self.resource_manager.remove::<ViperSourceDescriptor>(source_id).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.remove::<ViperSourceDescriptor>(source_id)
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::DeleteViperSourceDescriptorError::Internal {
                     source_id,
@@ -126,9 +157,10 @@ impl TestManagerService for TestManagerFacade {
     }
 
     #[tracing::instrument(skip_all, level="trace")]
-    async fn list_viper_source_descriptors(&self, _: Request<ListViperSourceDescriptorsRequest>) -> Result<Response<ListViperSourceDescriptorsResponse>, Status> {
+    async fn list_viper_source_descriptors(&self, request: Request<ListViperSourceDescriptorsRequest>) -> Result<Response<ListViperSourceDescriptorsResponse>, Status> {
+        let namespace = extract_namespace_filter(request.into_inner().namespace)?;
 
-        trace!("Received request to list test suite source descriptors.");
+        trace!("Received request to list test suite source descriptors in namespace <{namespace:?}>.");
 
         let result = self.resource_manager.list::<ViperSourceDescriptor>().await
             .inspect_err(|error| error!("Error while listing test suite source descriptors from gRPC API: {error}"))
@@ -139,6 +171,7 @@ impl TestManagerService for TestManagerFacade {
         let response = match result {
             Ok(sources) => {
                 let sources = sources.into_values()
+                    .filter(|source| namespace.as_ref().is_none_or(|namespace| source.namespace == *namespace))
                     .map(From::from)
                     .collect::<Vec<_>>();
 
@@ -154,6 +187,35 @@ impl TestManagerService for TestManagerFacade {
         }))
     }
 
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn inspect_viper_test_suite(&self, request: Request<InspectViperTestSuiteRequest>) -> Result<Response<InspectViperTestSuiteResponse>, Status> {
+
+        let request = request.into_inner();
+        let source_id: ViperSourceId = extract!(request.source_id)?;
+        let suite: TestSuiteIdentifier = extract!(request.suite)?;
+
+        trace!("Received request to inspect test suite '{suite}' of source <{source_id}>.");
+
+        let result = inspect_viper_test_suite(&self.resource_manager, &self.inspection_cache, InspectViperTestSuiteParams { source_id, suite }).await
+            .log_api_err()
+            .map_err(opendut_carl_api::carl::viper::InspectViperTestSuiteError::from);
+
+        let response = match result {
+            Ok(parameters) => inspect_viper_test_suite_response::Reply::Success(
+                InspectViperTestSuiteSuccess {
+                    parameters: parameters.into_iter()
+                        .map(From::from)
+                        .collect(),
+                }
+            ),
+            Err(error) => inspect_viper_test_suite_response::Reply::Failure(error.into()),
+        };
+
+        Ok(Response::new(InspectViperTestSuiteResponse {
+            reply: Some(response)
+        }))
+    }
+
 
 
     //
@@ -163,14 +225,24 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn store_viper_run_descriptor(&self, request: Request<StoreViperRunDescriptorRequest>) -> Result<Response<StoreViperRunDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let run: ViperRunDescriptor = extract!(request.run)?;
 
         trace!("Received request to store test suite run descriptor: {run:?}");
 
 
+        let audit = AuditRecord {
+            user,
+            operation: "StoreViperRunDescriptor",
+            resource_ids: vec![AuditResourceId::ViperRun(run.id.uuid)],
+        };
+
         let result =
-            self.resource_manager.insert(run.id, run.clone()).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.insert(run.id, run.clone())
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::StoreViperRunDescriptorError::Internal {
                     run_id: run.id,
@@ -194,13 +266,23 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_viper_run_descriptor(&self, request: Request<DeleteViperRunDescriptorRequest>) -> Result<Response<DeleteViperRunDescriptorResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let run_id: ViperRunId = extract!(request.run_id)?;
 
         trace!("Received request to delete test suite run descriptor for run <{run_id}>.");
 
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteViperRunDescriptor",
+            resource_ids: vec![AuditResourceId::ViperRun(run_id.uuid)],
+        };
+
         let result =
-            self.resource_manager.remove::<ViperRunDescriptor>(run_id).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.remove::<ViperRunDescriptor>(run_id)
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::DeleteViperRunDescriptorError::Internal {
                     run_id,
@@ -257,9 +339,10 @@ impl TestManagerService for TestManagerFacade {
     }
 
     #[tracing::instrument(skip_all, level="trace")]
-    async fn list_viper_run_descriptors(&self, _: Request<ListViperRunDescriptorsRequest>) -> Result<Response<ListViperRunDescriptorsResponse>, Status> {
+    async fn list_viper_run_descriptors(&self, request: Request<ListViperRunDescriptorsRequest>) -> Result<Response<ListViperRunDescriptorsResponse>, Status> {
+        let namespace = extract_namespace_filter(request.into_inner().namespace)?;
 
-        trace!("Received request to list test suite run descriptors.");
+        trace!("Received request to list test suite run descriptors in namespace <{namespace:?}>.");
 
         let result = self.resource_manager.list::<ViperRunDescriptor>().await
             .inspect_err(|error| error!("Error while listing test suite run descriptors from gRPC API: {error}"))
@@ -270,6 +353,7 @@ impl TestManagerService for TestManagerFacade {
         let response = match result {
             Ok(runs) => {
                 let runs = runs.into_values()
+                    .filter(|run| namespace.as_ref().is_none_or(|namespace| run.namespace == *namespace))
                     .map(From::from)
                     .collect::<Vec<_>>();
 
@@ -293,14 +377,24 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn store_viper_run_deployment(&self, request: Request<StoreViperRunDeploymentRequest>) -> Result<Response<StoreViperRunDeploymentResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let run: ViperRunDeployment = extract!(request.run)?;
 
         trace!("Received request to store test suite run deployment: {run:?}");
 
 
+        let audit = AuditRecord {
+            user,
+            operation: "StoreViperRunDeployment",
+            resource_ids: vec![AuditResourceId::ViperRun(run.id.uuid), AuditResourceId::Cluster(run.cluster)],
+        };
+
         let result =
-            self.resource_manager.insert(run.id, run.clone()).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.insert(run.id, run.clone())
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::StoreViperRunDeploymentError::Internal {
                     run_id: run.id,
@@ -324,13 +418,23 @@ impl TestManagerService for TestManagerFacade {
     #[tracing::instrument(skip_all, level="trace")]
     async fn delete_viper_run_deployment(&self, request: Request<DeleteViperRunDeploymentRequest>) -> Result<Response<DeleteViperRunDeploymentResponse>, Status> {
 
+        let user = audit_user(&request);
         let request = request.into_inner();
         let run_id: ViperRunId = extract!(request.run_id)?;
 
         trace!("Received request to delete test suite run deployment for run <{run_id}>.");
 
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteViperRunDeployment",
+            resource_ids: vec![AuditResourceId::ViperRun(run_id.uuid)],
+        };
+
         let result =
-            self.resource_manager.remove::<ViperRunDeployment>(run_id).await
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.remove::<ViperRunDeployment>(run_id)
+            ).await
+                .map_err_to_inner(std::convert::identity)
                 .log_api_err()
                 .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::DeleteViperRunDeploymentError::Internal {
                     run_id,
@@ -414,4 +518,255 @@ impl TestManagerService for TestManagerFacade {
             reply: Some(response)
         }))
     }
+
+    //
+    // ViperRunState
+    //
+
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn get_viper_run_state(&self, request: Request<GetViperRunStateRequest>) -> Result<Response<GetViperRunStateResponse>, Status> {
+
+        let request = request.into_inner();
+        let run_id: ViperRunId = extract!(request.run_id)?;
+
+        trace!("Received request to get state of test suite run <{run_id}>.");
+
+        let result =
+            self.resource_manager.get::<ViperRunState>(run_id).await
+                .inspect_err(|error| error!("Error while getting test suite run state from gRPC API: {error}"))
+                .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::GetViperRunStateError::Internal {
+                    run_id,
+                    cause: String::from("Error when accessing persistence while getting test suite run state"),
+                });
+
+        let response = match result {
+            Ok(state) => match state {
+                Some(state) => get_viper_run_state_response::Reply::Success(
+                    GetViperRunStateSuccess {
+                        state: Some(state.into())
+                    }
+                ),
+                None => get_viper_run_state_response::Reply::Failure(
+                    opendut_carl_api::carl::viper::GetViperRunStateError::StateNotFound { run_id }.into()
+                ),
+            }
+            Err(error) => get_viper_run_state_response::Reply::Failure(error.into()),
+        };
+
+        Ok(Response::new(GetViperRunStateResponse {
+            reply: Some(response)
+        }))
+    }
+
+    //
+    // ViperRunResult
+    //
+
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn delete_viper_run_result(&self, request: Request<DeleteViperRunResultRequest>) -> Result<Response<DeleteViperRunResultResponse>, Status> {
+
+        let user = audit_user(&request);
+        let request = request.into_inner();
+        let run_id: ViperRunId = extract!(request.run_id)?;
+
+        trace!("Received request to delete test suite run result for run <{run_id}>.");
+
+        let audit = AuditRecord {
+            user,
+            operation: "DeleteViperRunResult",
+            resource_ids: vec![AuditResourceId::ViperRun(run_id.uuid)],
+        };
+
+        let result =
+            audited_resources_mut(&self.resource_manager, audit, async |resources|
+                resources.remove::<ViperRunResult>(run_id)
+            ).await
+                .map_err_to_inner(std::convert::identity)
+                .log_api_err()
+                .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::DeleteViperRunResultError::Internal {
+                    run_id,
+                    cause: String::from("Error when accessing persistence while deleting test suite run result"),
+                });
+
+        let response = match result {
+            Ok(Some(_)) => delete_viper_run_result_response::Reply::Success(
+                DeleteViperRunResultSuccess {
+                    run_id: Some(run_id.into())
+                }
+            ),
+            Ok(None) => delete_viper_run_result_response::Reply::Failure(
+                opendut_carl_api::carl::viper::DeleteViperRunResultError::ResultNotFound { run_id }.into()
+            ),
+            Err(error) => delete_viper_run_result_response::Reply::Failure(error.into()),
+        };
+
+        Ok(Response::new(DeleteViperRunResultResponse {
+            reply: Some(response),
+        }))
+    }
+
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn get_viper_run_result(&self, request: Request<GetViperRunResultRequest>) -> Result<Response<GetViperRunResultResponse>, Status> {
+
+        let request = request.into_inner();
+        let run_id: ViperRunId = extract!(request.run_id)?;
+
+        trace!("Received request to get test suite run result for run <{run_id}>.");
+
+        let result =
+            self.resource_manager.get::<ViperRunResult>(run_id).await
+                .inspect_err(|error| error!("Error while getting test suite run result from gRPC API: {error}"))
+                .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::GetViperRunResultError::Internal {
+                    run_id,
+                    cause: String::from("Error when accessing persistence while getting test suite run result"),
+                });
+
+        let response = match result {
+            Ok(result) => match result {
+                Some(result) => get_viper_run_result_response::Reply::Success(
+                    GetViperRunResultSuccess {
+                        result: Some(result.into())
+                    }
+                ),
+                None => get_viper_run_result_response::Reply::Failure(
+                    opendut_carl_api::carl::viper::GetViperRunResultError::ResultNotFound { run_id }.into()
+                ),
+            }
+            Err(error) => get_viper_run_result_response::Reply::Failure(error.into()),
+        };
+
+        Ok(Response::new(GetViperRunResultResponse {
+            reply: Some(response)
+        }))
+    }
+
+    #[tracing::instrument(skip_all, level="trace")]
+    async fn list_viper_run_results(&self, _: Request<ListViperRunResultsRequest>) -> Result<Response<ListViperRunResultsResponse>, Status> {
+
+        trace!("Received request to list test suite run results.");
+
+        let result = self.resource_manager.list::<ViperRunResult>().await
+            .inspect_err(|error| error!("Error while listing test suite run results from gRPC API: {error}"))
+            .map_err(|_: PersistenceError| opendut_carl_api::carl::viper::ListViperRunResultsError::Internal {
+                cause: String::from("Error when accessing persistence while listing test suite run results"),
+            });
+
+        let response = match result {
+            Ok(results) => {
+                let results = results.into_values()
+                    .map(From::from)
+                    .collect::<Vec<_>>();
+
+                list_viper_run_results_response::Reply::Success(
+                    ListViperRunResultsSuccess { results }
+                )
+            }
+            Err(error) => list_viper_run_results_response::Reply::Failure(error.into())
+        };
+
+        Ok(Response::new(ListViperRunResultsResponse {
+            reply: Some(response)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use opendut_carl_api::proto::services::test_manager::{delete_viper_run_result_failure, get_viper_run_result_failure, DeleteViperRunResultFailure, DeleteViperRunResultFailureResultNotFound, GetViperRunResultFailure, GetViperRunResultFailureResultNotFound};
+    use opendut_model::peer::PeerId;
+    use opendut_viper_rt::run::TestSuiteReport;
+
+    use super::*;
+    use crate::resource::manager::ResourceManager;
+
+    #[tokio::test]
+    async fn should_get_list_and_delete_viper_run_results() -> anyhow::Result<()> {
+        let Fixture { testee, result } = Fixture::create().await?;
+        let run_id = result.id;
+
+        let get_reply = testee.get_viper_run_result(Request::new(
+            GetViperRunResultRequest { run_id: Some(run_id.into()) }
+        )).await?;
+        assert_that!(get_reply.into_inner().reply, some(eq(&get_viper_run_result_response::Reply::Success(
+            GetViperRunResultSuccess { result: Some(Clone::clone(&result).into()) }
+        ))));
+
+        let list_reply = testee.list_viper_run_results(Request::new(ListViperRunResultsRequest {})).await?;
+        assert_that!(list_reply.into_inner().reply, some(eq(&list_viper_run_results_response::Reply::Success(
+            ListViperRunResultsSuccess { results: vec![Clone::clone(&result).into()] }
+        ))));
+
+        let delete_reply = testee.delete_viper_run_result(Request::new(
+            DeleteViperRunResultRequest { run_id: Some(run_id.into()) }
+        )).await?;
+        assert_that!(delete_reply.into_inner().reply, some(eq(&delete_viper_run_result_response::Reply::Success(
+            DeleteViperRunResultSuccess { run_id: Some(run_id.into()) }
+        ))));
+
+        let list_reply = testee.list_viper_run_results(Request::new(ListViperRunResultsRequest {})).await?;
+        assert_that!(list_reply.into_inner().reply, some(eq(&list_viper_run_results_response::Reply::Success(
+            ListViperRunResultsSuccess { results: vec![] }
+        ))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_report_unknown_viper_run_results_as_not_found() -> anyhow::Result<()> {
+        let Fixture { testee, .. } = Fixture::create().await?;
+        let run_id = ViperRunId::random();
+
+        let get_reply = testee.get_viper_run_result(Request::new(
+            GetViperRunResultRequest { run_id: Some(run_id.into()) }
+        )).await?;
+        assert_that!(get_reply.into_inner().reply, some(eq(&get_viper_run_result_response::Reply::Failure(
+            GetViperRunResultFailure {
+                error: Some(get_viper_run_result_failure::Error::ResultNotFound(
+                    GetViperRunResultFailureResultNotFound { run_id: Some(run_id.into()) }
+                )),
+            }
+        ))));
+
+        let delete_reply = testee.delete_viper_run_result(Request::new(
+            DeleteViperRunResultRequest { run_id: Some(run_id.into()) }
+        )).await?;
+        assert_that!(delete_reply.into_inner().reply, some(eq(&delete_viper_run_result_response::Reply::Failure(
+            DeleteViperRunResultFailure {
+                error: Some(delete_viper_run_result_failure::Error::ResultNotFound(
+                    DeleteViperRunResultFailureResultNotFound { run_id: Some(run_id.into()) }
+                )),
+            }
+        ))));
+
+        Ok(())
+    }
+
+
+    struct Fixture {
+        testee: TestManagerFacade,
+        result: ViperRunResult,
+    }
+    impl Fixture {
+        async fn create() -> anyhow::Result<Self> {
+            let resource_manager = ResourceManager::new_in_memory();
+
+            let result = ViperRunResult {
+                id: ViperRunId::random(),
+                peer: PeerId::random(),
+                report: TestSuiteReport {
+                    name: TestSuiteIdentifier::try_from("suite")?,
+                    cases: vec![],
+                },
+            };
+            resource_manager.insert(result.id, Clone::clone(&result)).await?;
+
+            let testee = TestManagerFacade {
+                resource_manager: Arc::clone(&resource_manager),
+                inspection_cache: Default::default(),
+            };
+
+            Ok(Self { testee, result })
+        }
+    }
 }
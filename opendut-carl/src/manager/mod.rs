@@ -1,8 +1,11 @@
+pub mod audit_log;
 pub mod peer_messaging_broker;
 pub mod cluster_manager;
 pub mod grpc;
 pub mod peer_manager;
 pub mod observer_messaging_broker;
+#[cfg(feature = "viper")]
+pub mod test_manager;
 
 #[cfg(test)]
 mod testing {
@@ -67,7 +70,9 @@ mod testing {
                 },
                 executors: ExecutorDescriptors {
                     executors: vec![],
-                }
+                },
+                revision: Default::default(),
+                namespace: Default::default(),
             };
             PeerFixture {
                 id,
@@ -101,6 +106,12 @@ mod testing {
                 name: ClusterName::try_from(format!("Cluster-{cluster_id}"))?,
                 leader: peer_a.id,
                 devices: HashSet::from([peer_a.device_1, peer_a.device_2, peer_b.device_1]),
+                device_selectors: vec![],
+                topology: Default::default(),
+                can_rules: vec![],
+                restbus_simulations: vec![],
+                revision: Default::default(),
+                namespace: Default::default(),
             };
             resource_manager.insert(cluster_id, cluster_descriptor.clone()).await?;
 
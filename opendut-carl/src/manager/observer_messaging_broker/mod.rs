@@ -1,10 +1,13 @@
 mod observe_peers_online;
+mod watch_resources;
 
 use crate::manager::cluster_manager::ClusterManagerRef;
 use crate::manager::observer_messaging_broker::observe_peers_online::ObservePeersOnline;
+use crate::manager::observer_messaging_broker::watch_resources::ResourceWatcher;
+pub use crate::manager::observer_messaging_broker::watch_resources::WatchError;
 use crate::resource::manager::ResourceManagerRef;
 use crate::resource::persistence::error::PersistenceError;
-use opendut_carl_api::carl::observer::{WaitForPeersOnlineResponseStatus};
+use opendut_carl_api::carl::observer::{WaitForPeersOnlineResponseStatus, WatchEvent, WatchRequest};
 use opendut_model::peer::PeerId;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -19,6 +22,7 @@ pub struct ObserverMessagingBroker {
     resource_manager: ResourceManagerRef,
     #[expect(unused)]
     cluster_manager: ClusterManagerRef,
+    resource_watcher: ResourceWatcher,
     // TODO: limit number of observers? maybe use client_id or session_id
 }
 
@@ -29,11 +33,18 @@ pub enum ObserverError {
 }
 
 impl ObserverMessagingBroker {
-    pub fn new(resource_manager: ResourceManagerRef, cluster_manager: ClusterManagerRef) -> ObserverMessagingBrokerRef {
-        Arc::new(Self {
+    pub async fn new(resource_manager: ResourceManagerRef, cluster_manager: ClusterManagerRef) -> Result<ObserverMessagingBrokerRef, ObserverError> {
+        let resource_watcher = ResourceWatcher::create(Arc::clone(&resource_manager)).await?;
+
+        Ok(Arc::new(Self {
             resource_manager,
             cluster_manager,
-        })
+            resource_watcher,
+        }))
+    }
+
+    pub async fn watch(&self, request: WatchRequest) -> Result<mpsc::Receiver<Result<WatchEvent, WatchError>>, WatchError> {
+        self.resource_watcher.watch(request).await
     }
     
     pub async fn wait_for_peers_online(&self, peer_ids: HashSet<PeerId>, max_observation_duration: Duration) -> Result<mpsc::Receiver<opendut_carl_api::proto::services::observer_messaging_broker::WaitForPeersOnlineResponse>, ObserverError> {
@@ -0,0 +1,560 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Not;
+use std::sync::Arc;
+use opendut_carl_api::carl::observer::{WatchEvent, WatchEventKind, WatchRequest, WatchRevision, WatchedResource, WatchedResourceId, WatchedResourceKind};
+use opendut_model::cluster::{ClusterDeployment, ClusterDescriptor};
+use opendut_model::peer::configuration::EdgePeerConfigurationState;
+use opendut_model::peer::state::PeerConnectionState;
+use opendut_model::peer::PeerDescriptor;
+use tokio::sync::{broadcast, mpsc, Mutex};
+use tracing::{debug, trace, warn};
+use uuid::Uuid;
+use crate::resource::api::Resource;
+use crate::resource::manager::{ResourceManagerRef, SubscriptionEvent};
+use crate::resource::persistence::error::PersistenceError;
+use crate::resource::persistence::persistable::Persistable;
+use crate::resource::subscription::{ReceiveError, Subscribable, Subscription};
+
+/// Number of past events kept for resuming a watch.
+const HISTORY_CAPACITY: usize = 1024;
+
+/// Keeps track of the changes of all watchable resources and distributes them to the watches.
+///
+/// Every change is assigned a [`WatchRevision`]. The recent changes are kept in a history,
+/// so a watch can be resumed from the last revision it received.
+/// The history only lives in memory, so each watcher numbers its revisions within a random epoch
+/// and rejects revisions of other epochs, e.g. from before a restart of CARL.
+pub struct ResourceWatcher {
+    state: Arc<Mutex<WatchState>>,
+}
+
+struct WatchState {
+    revision: WatchRevision,
+    history: VecDeque<WatchEvent>,
+    resources: HashMap<(WatchedResourceKind, WatchedResourceId), WatchedResource>,
+    sender: broadcast::Sender<WatchEvent>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WatchError {
+    #[error("Revision <{requested}> is newer than the latest revision <{latest}>.")]
+    UnknownRevision { requested: WatchRevision, latest: WatchRevision },
+    #[error("Revision <{requested}> is no longer available. Restart the watch without a revision to receive the current state.")]
+    RevisionExpired { requested: WatchRevision },
+    #[error("Watch could not keep up with the changes. Resume the watch from revision <{last_sent}>.")]
+    Lagged { last_sent: WatchRevision },
+}
+
+impl ResourceWatcher {
+    pub async fn create(resource_manager: ResourceManagerRef) -> Result<Self, PersistenceError> {
+        let mut subscriptions = WatchSubscriptions {
+            peer_descriptor: resource_manager.subscribe().await,
+            peer_connection_state: resource_manager.subscribe().await,
+            peer_configuration_state: resource_manager.subscribe().await,
+            cluster_descriptor: resource_manager.subscribe().await,
+            cluster_deployment: resource_manager.subscribe().await,
+        };
+
+        let mut state = WatchState::new();
+        state.load::<PeerDescriptor>(&resource_manager).await?;
+        state.load::<PeerConnectionState>(&resource_manager).await?;
+        state.load::<EdgePeerConfigurationState>(&resource_manager).await?;
+        state.load::<ClusterDescriptor>(&resource_manager).await?;
+        state.load::<ClusterDeployment>(&resource_manager).await?;
+
+        state.apply_pending(&mut subscriptions.peer_descriptor, &resource_manager).await?;
+        state.apply_pending(&mut subscriptions.peer_connection_state, &resource_manager).await?;
+        state.apply_pending(&mut subscriptions.peer_configuration_state, &resource_manager).await?;
+        state.apply_pending(&mut subscriptions.cluster_descriptor, &resource_manager).await?;
+        state.apply_pending(&mut subscriptions.cluster_deployment, &resource_manager).await?;
+
+        let state = Arc::new(Mutex::new(state));
+
+        tokio::spawn({
+            let state = Arc::clone(&state);
+            async move {
+                loop {
+                    let result = tokio::select! {
+                        event = subscriptions.peer_descriptor.receive() => handle_event(event, &state, &resource_manager).await,
+                        event = subscriptions.peer_connection_state.receive() => handle_event(event, &state, &resource_manager).await,
+                        event = subscriptions.peer_configuration_state.receive() => handle_event(event, &state, &resource_manager).await,
+                        event = subscriptions.cluster_descriptor.receive() => handle_event(event, &state, &resource_manager).await,
+                        event = subscriptions.cluster_deployment.receive() => handle_event(event, &state, &resource_manager).await,
+                    };
+                    if let Err(HandleEventError::Closed) = result {
+                        debug!("Resource subscriptions were closed. Stopping to watch resources.");
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { state })
+    }
+
+    /// Starts a watch for the given request.
+    /// Without a revision to resume from, the watch starts with an `Inserted` event for each matching resource.
+    /// These events share the current revision, so only the last of them is marked as resumable.
+    pub async fn watch(&self, request: WatchRequest) -> Result<mpsc::Receiver<Result<WatchEvent, WatchError>>, WatchError> {
+        let (initial_events, mut last_sent, mut receiver) = {
+            let state = self.state.lock().await;
+
+            let initial_events = match request.resume_from {
+                Some(requested) => state.events_after(requested)?,
+                None => {
+                    let mut snapshot = state.current_resources().into_iter()
+                        .filter(|event| request.matches(&event.resource))
+                        .collect::<Vec<_>>();
+                    if let Some(last) = snapshot.last_mut() {
+                        last.resumable = true;
+                    }
+                    snapshot
+                }
+            };
+            let last_sent = request.resume_from.unwrap_or(state.revision);
+            (initial_events, last_sent, state.sender.subscribe())
+        };
+
+        let (tx_outbound, rx_outbound) = mpsc::channel(HISTORY_CAPACITY);
+
+        tokio::spawn(async move {
+
+            for event in initial_events {
+                let revision = event.revision;
+                if request.matches(&event.resource) && tx_outbound.send(Ok(event)).await.is_err() {
+                    return;
+                }
+                last_sent = revision;
+            }
+
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if event.revision.number <= last_sent.number {
+                            continue;
+                        }
+                        let revision = event.revision;
+                        if request.matches(&event.resource) && tx_outbound.send(Ok(event)).await.is_err() {
+                            trace!("Watch was closed by the observer.");
+                            break;
+                        }
+                        last_sent = revision;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        warn!("Watch could not keep up with the changes of the resources. Closing the watch.");
+                        let _ignore = tx_outbound.send(Err(WatchError::Lagged { last_sent })).await;
+                        break;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        Ok(rx_outbound)
+    }
+}
+
+struct WatchSubscriptions {
+    peer_descriptor: Subscription<PeerDescriptor>,
+    peer_connection_state: Subscription<PeerConnectionState>,
+    peer_configuration_state: Subscription<EdgePeerConfigurationState>,
+    cluster_descriptor: Subscription<ClusterDescriptor>,
+    cluster_deployment: Subscription<ClusterDeployment>,
+}
+
+enum HandleEventError {
+    Closed,
+}
+
+async fn handle_event<R: Watchable>(
+    event: Result<SubscriptionEvent<R>, ReceiveError>,
+    state: &Mutex<WatchState>,
+    resource_manager: &ResourceManagerRef,
+) -> Result<(), HandleEventError> {
+    match event {
+        Ok(SubscriptionEvent::Inserted { id, value }) => {
+            state.lock().await.insert(R::into_watched(id, value));
+        }
+        Ok(SubscriptionEvent::Removed { id, value }) => {
+            state.lock().await.remove(R::into_watched(id, value));
+        }
+        Err(ReceiveError::Broadcast(broadcast::error::RecvError::Lagged(count))) => {
+            warn!("Missed {count} changes of resource '{}'. Reloading the resources to determine the changes.", std::any::type_name::<R>());
+            let resources = resource_manager.list::<R>().await
+                .inspect_err(|cause| warn!("Failed to reload resources for watches:\n  {cause}"));
+            if let Ok(resources) = resources {
+                state.lock().await.reconcile::<R>(resources);
+            }
+        }
+        Err(ReceiveError::Broadcast(broadcast::error::RecvError::Closed)) => {
+            return Err(HandleEventError::Closed);
+        }
+    }
+    Ok(())
+}
+
+impl WatchState {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(HISTORY_CAPACITY);
+        Self {
+            revision: WatchRevision::initial(Uuid::new_v4()),
+            history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            resources: HashMap::new(),
+            sender,
+        }
+    }
+
+    async fn load<R: Watchable>(&mut self, resource_manager: &ResourceManagerRef) -> Result<(), PersistenceError> {
+        let resources = resource_manager.list::<R>().await?;
+        self.resources.retain(|(kind, _), _| *kind != R::KIND);
+        for (id, value) in resources {
+            let resource = R::into_watched(id, value);
+            self.resources.insert((resource.kind(), resource.id()), resource);
+        }
+        Ok(())
+    }
+
+    /// Applies the changes, which were received while loading the resources, without publishing them.
+    /// The loaded resources already contain (some of) these changes, so publishing them would report
+    /// resources as updated, which did not change. Applying them in order still results in the latest state.
+    async fn apply_pending<R: Watchable>(&mut self, subscription: &mut Subscription<R>, resource_manager: &ResourceManagerRef) -> Result<(), PersistenceError> {
+        loop {
+            match subscription.try_receive() {
+                Ok(SubscriptionEvent::Inserted { id, value }) => {
+                    let resource = R::into_watched(id, value);
+                    self.resources.insert((resource.kind(), resource.id()), resource);
+                }
+                Ok(SubscriptionEvent::Removed { id, value }) => {
+                    let resource = R::into_watched(id, value);
+                    self.resources.remove(&(resource.kind(), resource.id()));
+                }
+                Err(broadcast::error::TryRecvError::Lagged(_)) => {
+                    debug!("Missed changes of resource '{}' while loading. Loading the resources again.", std::any::type_name::<R>());
+                    self.load::<R>(resource_manager).await?;
+                }
+                Err(broadcast::error::TryRecvError::Empty | broadcast::error::TryRecvError::Closed) => return Ok(()),
+            }
+        }
+    }
+
+    fn insert(&mut self, resource: WatchedResource) {
+        let previous = self.resources.insert((resource.kind(), resource.id()), Clone::clone(&resource));
+        let kind = match previous {
+            Some(_) => WatchEventKind::Updated,
+            None => WatchEventKind::Inserted,
+        };
+        self.publish(kind, resource);
+    }
+
+    fn remove(&mut self, resource: WatchedResource) {
+        self.resources.remove(&(resource.kind(), resource.id()));
+        self.publish(WatchEventKind::Removed, resource);
+    }
+
+    /// Publishes the differences to the given stored resources, after changes were missed.
+    /// Resources, which did not change, are not published.
+    fn reconcile<R: Watchable>(&mut self, stored: HashMap<R::Id, R>) {
+        let stored = stored.into_iter()
+            .map(|(id, value)| R::into_watched(id, value))
+            .map(|resource| (resource.id(), resource))
+            .collect::<HashMap<_, _>>();
+
+        let removed = self.resources.iter()
+            .filter(|((kind, id), _)| *kind == R::KIND && stored.contains_key(id).not())
+            .map(|(_, resource)| Clone::clone(resource))
+            .collect::<Vec<_>>();
+
+        for resource in removed {
+            self.remove(resource);
+        }
+        for resource in stored.into_values() {
+            let unchanged = self.resources.get(&(resource.kind(), resource.id())) == Some(&resource);
+            if unchanged.not() {
+                self.insert(resource);
+            }
+        }
+    }
+
+    fn publish(&mut self, kind: WatchEventKind, resource: WatchedResource) {
+        self.revision = self.revision.next();
+        let event = WatchEvent { revision: self.revision, kind, resource, resumable: true };
+
+        if self.history.len() == HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+        self.history.push_back(Clone::clone(&event));
+
+        let _ignore_no_watches = self.sender.send(event);
+    }
+
+    fn events_after(&self, requested: WatchRevision) -> Result<Vec<WatchEvent>, WatchError> {
+        if requested.epoch != self.revision.epoch {
+            return Err(WatchError::RevisionExpired { requested });
+        }
+        if requested.number > self.revision.number {
+            return Err(WatchError::UnknownRevision { requested, latest: self.revision });
+        }
+        let oldest_available = self.history.front()
+            .map(|event| event.revision)
+            .unwrap_or(self.revision.next());
+        if requested.next().number < oldest_available.number {
+            return Err(WatchError::RevisionExpired { requested });
+        }
+
+        let events = self.history.iter()
+            .filter(|event| event.revision.number > requested.number)
+            .cloned()
+            .collect();
+        Ok(events)
+    }
+
+    fn current_resources(&self) -> Vec<WatchEvent> {
+        self.resources.values()
+            .map(|resource| WatchEvent {
+                revision: self.revision,
+                kind: WatchEventKind::Inserted,
+                resource: Clone::clone(resource),
+                resumable: false,
+            })
+            .collect()
+    }
+}
+
+trait Watchable: Resource + Persistable + Subscribable {
+    const KIND: WatchedResourceKind;
+    fn into_watched(id: Self::Id, value: Self) -> WatchedResource;
+}
+
+impl Watchable for PeerDescriptor {
+    const KIND: WatchedResourceKind = WatchedResourceKind::PeerDescriptor;
+    fn into_watched(_: Self::Id, value: Self) -> WatchedResource {
+        WatchedResource::PeerDescriptor(value)
+    }
+}
+impl Watchable for PeerConnectionState {
+    const KIND: WatchedResourceKind = WatchedResourceKind::PeerConnectionState;
+    fn into_watched(peer_id: Self::Id, state: Self) -> WatchedResource {
+        WatchedResource::PeerConnectionState { peer_id, state }
+    }
+}
+impl Watchable for EdgePeerConfigurationState {
+    const KIND: WatchedResourceKind = WatchedResourceKind::PeerConfigurationState;
+    fn into_watched(peer_id: Self::Id, state: Self) -> WatchedResource {
+        WatchedResource::PeerConfigurationState { peer_id, state }
+    }
+}
+impl Watchable for ClusterDescriptor {
+    const KIND: WatchedResourceKind = WatchedResourceKind::ClusterDescriptor;
+    fn into_watched(_: Self::Id, value: Self) -> WatchedResource {
+        WatchedResource::ClusterDescriptor(value)
+    }
+}
+impl Watchable for ClusterDeployment {
+    const KIND: WatchedResourceKind = WatchedResourceKind::ClusterDeployment;
+    fn into_watched(_: Self::Id, value: Self) -> WatchedResource {
+        WatchedResource::ClusterDeployment(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::net::IpAddr;
+    use std::str::FromStr;
+    use std::time::Duration;
+    use googletest::prelude::*;
+    use opendut_model::peer::PeerId;
+    use crate::manager::peer_manager::tests::create_peer_descriptor;
+    use crate::resource::manager::ResourceManager;
+    use super::*;
+
+    #[test_log::test(tokio::test)]
+    async fn should_send_the_current_resources_followed_by_the_changes() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer_id = PeerId::random();
+        let other_peer_id = PeerId::random();
+        resource_manager.insert(peer_id, create_peer_descriptor(peer_id)).await?;
+        resource_manager.insert(other_peer_id, create_peer_descriptor(other_peer_id)).await?;
+
+        let testee = ResourceWatcher::create(Arc::clone(&resource_manager)).await?;
+
+        let mut receiver = testee.watch(WatchRequest {
+            kinds: HashSet::from([WatchedResourceKind::PeerConnectionState, WatchedResourceKind::PeerDescriptor]),
+            peer_ids: HashSet::from([peer_id]),
+            ..Default::default()
+        }).await?;
+
+        let event = receive(&mut receiver).await?;
+        assert_that!(event.kind, eq(WatchEventKind::Inserted));
+        assert!(matches!(event.resource, WatchedResource::PeerDescriptor(ref descriptor) if descriptor.id == peer_id));
+
+        let online = PeerConnectionState::Online { remote_host: IpAddr::from_str("1.2.3.4")? };
+        resource_manager.insert(other_peer_id, PeerConnectionState::Offline).await?;
+        resource_manager.insert(peer_id, PeerConnectionState::Offline).await?;
+        resource_manager.insert(peer_id, online.clone()).await?;
+        resource_manager.remove::<PeerConnectionState>(peer_id).await?;
+
+        let inserted = receive(&mut receiver).await?;
+        assert_that!(inserted.kind, eq(WatchEventKind::Inserted));
+        assert!(matches!(inserted.resource, WatchedResource::PeerConnectionState { peer_id: id, state: PeerConnectionState::Offline } if id == peer_id));
+
+        let updated = receive(&mut receiver).await?;
+        assert_that!(updated.kind, eq(WatchEventKind::Updated));
+        assert!(matches!(updated.resource, WatchedResource::PeerConnectionState { peer_id: id, ref state } if id == peer_id && *state == online));
+        assert_that!(updated.revision.number, gt(inserted.revision.number));
+
+        let removed = receive(&mut receiver).await?;
+        assert_that!(removed.kind, eq(WatchEventKind::Removed));
+        assert!(matches!(removed.resource, WatchedResource::PeerConnectionState { peer_id: id, ref state } if id == peer_id && *state == online));
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_replay_the_changes_after_the_revision_to_resume_from() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer_id = PeerId::random();
+
+        let testee = ResourceWatcher::create(Arc::clone(&resource_manager)).await?;
+        let mut receiver = testee.watch(WatchRequest::default()).await?;
+
+        resource_manager.insert(peer_id, create_peer_descriptor(peer_id)).await?;
+        resource_manager.insert(peer_id, PeerConnectionState::Offline).await?;
+        let first = receive(&mut receiver).await?;
+        let second = receive(&mut receiver).await?;
+        drop(receiver);
+
+        let mut resumed = testee.watch(WatchRequest {
+            resume_from: Some(first.revision),
+            ..Default::default()
+        }).await?;
+
+        let replayed = receive(&mut resumed).await?;
+        assert_that!(replayed.revision, eq(second.revision));
+        assert_that!(replayed.kind, eq(WatchEventKind::Inserted));
+        assert!(matches!(replayed.resource, WatchedResource::PeerConnectionState { peer_id: id, .. } if id == peer_id));
+
+        resource_manager.remove::<PeerDescriptor>(peer_id).await?;
+        let removed = receive(&mut resumed).await?;
+        assert_that!(removed.kind, eq(WatchEventKind::Removed));
+        assert_that!(removed.revision, eq(second.revision.next()));
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_reject_a_revision_which_is_unknown_or_no_longer_available() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let testee = ResourceWatcher::create(Arc::clone(&resource_manager)).await?;
+        let initial = testee.state.lock().await.revision;
+
+        let result = testee.watch(WatchRequest {
+            resume_from: Some(initial.next()),
+            ..Default::default()
+        }).await;
+        assert_that!(result, err(matches_pattern!(WatchError::UnknownRevision { .. })));
+
+        let result = testee.watch(WatchRequest {
+            resume_from: Some(WatchRevision::initial(Uuid::new_v4())),
+            ..Default::default()
+        }).await;
+        assert_that!(result, err(matches_pattern!(WatchError::RevisionExpired { .. })));
+
+        {
+            let mut state = testee.state.lock().await;
+            for _ in 0..=HISTORY_CAPACITY {
+                let peer_id = PeerId::random();
+                state.insert(WatchedResource::PeerConnectionState { peer_id, state: PeerConnectionState::Offline });
+            }
+        }
+
+        let result = testee.watch(WatchRequest {
+            resume_from: Some(initial),
+            ..Default::default()
+        }).await;
+        assert_that!(result, err(matches_pattern!(WatchError::RevisionExpired { .. })));
+
+        let result = testee.watch(WatchRequest {
+            resume_from: Some(initial.next()),
+            ..Default::default()
+        }).await;
+        assert_that!(result, ok(anything()));
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_not_publish_the_changes_received_while_loading_the_resources() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer_id = PeerId::random();
+        let removed_peer_id = PeerId::random();
+
+        let mut subscription = resource_manager.subscribe::<PeerConnectionState>().await;
+        resource_manager.insert(peer_id, PeerConnectionState::Offline).await?;
+        resource_manager.insert(removed_peer_id, PeerConnectionState::Offline).await?;
+
+        let mut state = WatchState::new();
+        state.load::<PeerConnectionState>(&resource_manager).await?;
+        resource_manager.remove::<PeerConnectionState>(removed_peer_id).await?;
+        state.apply_pending(&mut subscription, &resource_manager).await?;
+
+        assert_that!(state.history, is_empty());
+        let current = state.current_resources();
+        assert_that!(current, len(eq(1)));
+        assert!(matches!(current[0].resource, WatchedResource::PeerConnectionState { peer_id: id, .. } if id == peer_id));
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_only_mark_the_last_event_of_the_current_resources_as_resumable() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        for peer_id in [PeerId::random(), PeerId::random()] {
+            resource_manager.insert(peer_id, PeerConnectionState::Offline).await?;
+        }
+
+        let testee = ResourceWatcher::create(Arc::clone(&resource_manager)).await?;
+        let mut receiver = testee.watch(WatchRequest::default()).await?;
+
+        let first = receive(&mut receiver).await?;
+        let second = receive(&mut receiver).await?;
+        assert_that!(first.revision, eq(second.revision));
+        assert_that!(first.resumable, eq(false));
+        assert_that!(second.resumable, eq(true));
+
+        resource_manager.insert(PeerId::random(), PeerConnectionState::Offline).await?;
+        let change = receive(&mut receiver).await?;
+        assert_that!(change.resumable, eq(true));
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_only_publish_changed_resources_when_reconciling() -> anyhow::Result<()> {
+        let unchanged_peer_id = PeerId::random();
+        let changed_peer_id = PeerId::random();
+        let online = PeerConnectionState::Online { remote_host: IpAddr::from_str("1.2.3.4")? };
+
+        let mut state = WatchState::new();
+        state.insert(WatchedResource::PeerConnectionState { peer_id: unchanged_peer_id, state: PeerConnectionState::Offline });
+        state.insert(WatchedResource::PeerConnectionState { peer_id: changed_peer_id, state: PeerConnectionState::Offline });
+        state.history.clear();
+
+        state.reconcile::<PeerConnectionState>(HashMap::from([
+            (unchanged_peer_id, PeerConnectionState::Offline),
+            (changed_peer_id, online.clone()),
+        ]));
+
+        assert_that!(state.history, len(eq(1)));
+        assert_that!(state.history[0].kind, eq(WatchEventKind::Updated));
+        assert!(matches!(state.history[0].resource, WatchedResource::PeerConnectionState { peer_id: id, ref state } if id == changed_peer_id && *state == online));
+
+        Ok(())
+    }
+
+    async fn receive(receiver: &mut mpsc::Receiver<std::result::Result<WatchEvent, WatchError>>) -> anyhow::Result<WatchEvent> {
+        let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await?
+            .ok_or_else(|| anyhow::anyhow!("Watch was closed unexpectedly."))??;
+        Ok(event)
+    }
+}
@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::net::IpAddr;
 use serde::Serialize;
-use opendut_model::cluster::ClusterId;
+use opendut_model::cluster::{ClusterId, ClusterLink, ClusterTopology};
 use opendut_model::peer::PeerId;
 use opendut_model::util::Port;
 
@@ -11,6 +11,9 @@ pub struct ClusterAssignment {
     pub id: ClusterId,
     pub leader: PeerId,
     pub assignments: HashMap<PeerId, PeerClusterAssignment>,
+    pub topology: ClusterTopology,
+    /// Direct links between the peers, as determined from the topology.
+    pub links: Vec<ClusterLink>,
 }
 impl ClusterAssignment {
     pub fn leader_assignment(&self) -> Option<&PeerClusterAssignment> {
@@ -22,12 +25,62 @@ impl ClusterAssignment {
     }
 
     /// Returns the `PeerClusterAssignment` entries for peers which are directly connected to the given peer.
-    /// If the passed peer is the leader, these are all other peers.
-    /// If it's any other peer, the leader is returned.
+    /// In a star topology, these are all other peers for the leader and only the leader for any other peer.
     pub fn assignments_of_peers_directly_connected_to(&self, local_peer_id: PeerId) -> HashMap<PeerId, PeerClusterAssignment> {
-        let mut assignments = self.assignments.clone();
-        assignments.remove(&local_peer_id);
-        assignments
+        self.links.iter()
+            .filter_map(|link| link.other_peer(local_peer_id))
+            .filter_map(|remote_peer_id| {
+                self.assignments.get(&remote_peer_id)
+                    .map(|assignment| (remote_peer_id, assignment.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the `PeerClusterAssignment` entries for peers which the given peer exchanges CAN frames with.
+    /// In a custom topology, these are only the neighbours along the spanning tree of the links, see [`Self::can_links`].
+    pub fn assignments_of_can_peers_of(&self, local_peer_id: PeerId) -> HashMap<PeerId, PeerClusterAssignment> {
+        self.can_links().iter()
+            .filter_map(|link| link.other_peer(local_peer_id))
+            .filter_map(|remote_peer_id| {
+                self.assignments.get(&remote_peer_id)
+                    .map(|assignment| (remote_peer_id, assignment.clone()))
+            })
+            .collect()
+    }
+
+    /// Links which carry CAN frames between the peers.
+    /// Frames are forwarded between the links of a peer via a shared CAN bridge, which does not protect against loops.
+    /// Custom topologies therefore only use the links of a spanning tree, determined breadth-first from the leader.
+    pub fn can_links(&self) -> Vec<ClusterLink> {
+        match self.topology {
+            ClusterTopology::Star | ClusterTopology::FullMesh => self.links.clone(),
+            ClusterTopology::Custom { .. } => {
+                let mut visited = HashSet::from([self.leader]);
+                let mut queue = VecDeque::from([self.leader]);
+                let mut tree_links = Vec::new();
+
+                while let Some(peer_id) = queue.pop_front() {
+                    let mut links = self.links.iter()
+                        .filter_map(|link| link.other_peer(peer_id).map(|other_peer| (other_peer, link)))
+                        .collect::<Vec<_>>();
+                    links.sort_by_key(|(other_peer, _)| other_peer.uuid);
+
+                    for (other_peer, link) in links {
+                        if visited.insert(other_peer) {
+                            tree_links.push(*link);
+                            queue.push_back(other_peer);
+                        }
+                    }
+                }
+                tree_links
+            }
+        }
+    }
+
+    /// Whether the given peer acts as the server for the CAN connection to the remote peer.
+    pub fn is_can_server_towards(&self, local_peer_id: PeerId, remote_peer_id: PeerId) -> bool {
+        self.links.iter()
+            .any(|link| link.peer_a == local_peer_id && link.peer_b == remote_peer_id)
     }
 }
 
@@ -1,11 +1,14 @@
-use std::net::{IpAddr, Ipv4Addr};
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::str::FromStr;
 use tracing::debug;
+use opendut_model::cluster::ClusterTopology;
 use opendut_model::peer::configuration::{parameter, PeerConfiguration};
-use opendut_model::peer::configuration::parameter::{GreInterfaceConfig, InterfaceJoinConfig};
+use opendut_model::peer::configuration::parameter::{CanTransport, GreInterfaceConfig, InterfaceJoinConfig};
 use opendut_model::peer::{PeerDescriptor, PeerId};
+use opendut_model::util::can::{CanFrameRules, CanRateLimitRule};
 use opendut_model::util::net::{NetworkInterfaceConfiguration, NetworkInterfaceDescriptor, NetworkInterfaceName};
-use crate::manager::peer_manager::{AssignClusterError, AssignClusterOptions, ClusterAssignment};
+use crate::manager::peer_manager::{AssignClusterError, AssignClusterOptions, ClusterAssignment, PeerClusterAssignment};
 
 
 pub(super) fn update_peer_configuration(
@@ -13,27 +16,21 @@ pub(super) fn update_peer_configuration(
     peer_descriptor: PeerDescriptor,
     cluster_assignment: &ClusterAssignment,
     expected_device_interfaces: Vec<NetworkInterfaceDescriptor>,
+    can_rules: &HashMap<NetworkInterfaceName, CanFrameRules>,
+    expected_restbus_simulations: Vec<parameter::RestbusSimulation>,
     options: AssignClusterOptions,
 ) -> Result<(), AssignClusterError> {
 
-    let expected_gre_config_parameters = determine_expected_gre_interface_config_parameters(peer_descriptor.id, cluster_assignment)?;
-
-    let expected_joined_interface_names = {
-        let expected_gre_interface_names =
-            expected_gre_config_parameters.iter()
-                .map(GreInterfaceConfig::interface_name).collect::<Result<Vec<_>, _>>()
-                .map_err(|source| AssignClusterError::InterfaceName { source })?;
+    let local_assignment = cluster_assignment.assignments.get(&peer_descriptor.id)
+        .ok_or(AssignClusterError::PeerNotFound(peer_descriptor.id))?;
+    cluster_assignment.leader_assignment()
+        .ok_or(AssignClusterError::PeerNotFound(cluster_assignment.leader))?;
 
-        let expected_joined_ethernet_device_interfaces =
-            expected_device_interfaces.iter()
-                .filter(|interface| matches!(interface.configuration, NetworkInterfaceConfiguration::Ethernet))
-                .map(|device| device.name.clone())
-                .collect::<Vec<_>>();
+    let remote_peers = cluster_assignment.assignments_of_peers_directly_connected_to(peer_descriptor.id);
+    let can_remote_peers = cluster_assignment.assignments_of_can_peers_of(peer_descriptor.id);
+    let loop_protection = LoopProtection::for_topology(&cluster_assignment.topology);
 
-         expected_gre_interface_names.into_iter()
-            .chain(expected_joined_ethernet_device_interfaces)
-            .collect::<Vec<_>>()
-    };
+    let expected_gre_config_parameters = determine_expected_gre_interface_config_parameters(peer_descriptor.id, cluster_assignment)?;
 
     let PeerConfiguration {
         device_interfaces,
@@ -45,6 +42,7 @@ pub(super) fn update_peer_configuration(
         can_connections,
         can_bridges,
         can_local_routes,
+        restbus_simulations,
     } = peer_configuration;
 
     let can_device_names = expected_device_interfaces.iter()
@@ -52,6 +50,12 @@ pub(super) fn update_peer_configuration(
         .map(|device_interfaces| device_interfaces.name.clone())
         .collect::<Vec<_>>();
     let can_enabled = !can_device_names.is_empty();
+
+    let ethernet_device_names = expected_device_interfaces.iter()
+        .filter(|interface| matches!(interface.configuration, NetworkInterfaceConfiguration::Ethernet))
+        .map(|device| device.name.clone())
+        .collect::<Vec<_>>();
+
     let device_dependencies = { // Network device interfaces
         let expected_device_interfaces = expected_device_interfaces.into_iter()
             .map(|descriptor| parameter::DeviceInterface { descriptor });
@@ -63,91 +67,121 @@ pub(super) fn update_peer_configuration(
     };
 
     if can_enabled {
-        // CAN bridge
-        let can_bridge = NetworkInterfaceName::from_str("br-vcan-opendut").unwrap();
-        let expected_can_bridges = vec![parameter::CanBridge {
-            name: can_bridge.clone(),
-        }];
-        let can_dependencies = can_bridges.set_all_present(expected_can_bridges, device_dependencies.clone());
+        // CAN bridges
+        // Frames are forwarded between the CAN connections of a peer via a shared bridge, which must not form loops.
+        // In a full mesh, each remote peer is therefore given its own bridge, which is only routed to the local CAN devices.
+        // Custom topologies only connect the peers along a spanning tree, see `ClusterAssignment::can_links()`.
+        let can_bridge_per_remote_peer = match loop_protection {
+            LoopProtection::None | LoopProtection::SpanningTree => {
+                let shared_can_bridge = NetworkInterfaceName::from_str(SHARED_CAN_BRIDGE_NAME).unwrap();
+                can_remote_peers.keys()
+                    .map(|remote_peer_id| (*remote_peer_id, shared_can_bridge.clone()))
+                    .collect::<HashMap<_, _>>()
+            }
+            LoopProtection::SplitHorizon => can_bridge_names_for_remote_peers(can_remote_peers.keys().copied())?,
+        };
+
+        let expected_can_bridges = match loop_protection {
+            LoopProtection::None | LoopProtection::SpanningTree => vec![NetworkInterfaceName::from_str(SHARED_CAN_BRIDGE_NAME).unwrap()],
+            LoopProtection::SplitHorizon => can_bridge_per_remote_peer.values().cloned().collect(),
+        };
+        let can_dependencies = can_bridges.set_all_present(
+            expected_can_bridges.iter().cloned().map(|name| parameter::CanBridge { name }),
+            device_dependencies.clone()
+        );
 
         // CAN connections
-        if cluster_assignment.leader == peer_descriptor.id {
-            let remote_peers = cluster_assignment.non_leader_assignments();
-            let expected_can_connections = remote_peers.into_iter()
-                .map(|(remote_peer_id, peer_cluster_assignment)| {
-                    parameter::CanConnection {
-                        can_interface_name: can_bridge.clone(),
-                        local_is_server: true,
-                        remote_peer_id,
-                        remote_ip: peer_cluster_assignment.vpn_address,
-                        remote_port: peer_cluster_assignment.can_server_port,
-                        local_port: peer_cluster_assignment.can_server_port,
-                        buffer_timeout_microseconds: 100,
-                    }
-                });
-            can_connections.set_all_present(expected_can_connections, can_dependencies.clone());
-        } else {
-            let leader = cluster_assignment.leader_assignment();
-            match leader {
-                Some(leader_assignment) => {
-                    let can_connection = parameter::CanConnection {
-                        can_interface_name: can_bridge.clone(),
-                        local_is_server: false,
-                        remote_peer_id: cluster_assignment.leader,
-                        remote_ip: leader_assignment.vpn_address,
-                        remote_port: leader_assignment.can_server_port,
-                        local_port: leader_assignment.can_server_port,
-                        buffer_timeout_microseconds: 100,
-                    };
-                    let expected_can_connections = vec![can_connection];
-                    can_connections.set_all_present(expected_can_connections, can_dependencies.clone());
-                }
-                None => {
-                    return Err(AssignClusterError::PeerNotFound(cluster_assignment.leader));
-                }
-            }
-        }
+        // Frames from all local CAN devices share a bridge, so the tunnel applies the rate limit of each device to the identifiers it forwards.
+        let mut rate_limited_devices = can_device_names.iter()
+            .filter_map(|can_device| can_rules.get(can_device).map(|rules| (can_device.name(), rules)))
+            .collect::<Vec<_>>();
+        rate_limited_devices.sort_by(|(device_a, _), (device_b, _)| device_a.cmp(device_b));
+        let rate_limits = rate_limited_devices.into_iter()
+            .filter_map(|(_, rules)| rules.rate_limit_rule())
+            .collect::<Vec<_>>();
+        let expected_can_connections = can_remote_peers.iter()
+            .map(|(remote_peer_id, remote_assignment)| {
+                let local_is_server = cluster_assignment.is_can_server_towards(peer_descriptor.id, *remote_peer_id);
+                can_connection(can_bridge_per_remote_peer[remote_peer_id].clone(), local_is_server, local_assignment, *remote_peer_id, remote_assignment, options.can_transport, rate_limits.clone())
+            });
+        can_connections.set_all_present(expected_can_connections, can_dependencies.clone());
 
         // CAN local routes
+        // The filter and rewrite rules of a device apply to the frames it sends into the cluster.
         let mut expected_can_local_routes = vec![];
         for can_device in &can_device_names {
-            expected_can_local_routes.push(parameter::CanLocalRoute {
-                can_source_device_name: can_device.clone(),
-                can_destination_device_name: can_bridge.clone(),
-            });
-            expected_can_local_routes.push(parameter::CanLocalRoute {
-                can_source_device_name: can_bridge.clone(),
-                can_destination_device_name: can_device.clone(),
-            });
+            let device_rules = can_rules.get(can_device)
+                .map(|rules| CanFrameRules { rate_limit: None, ..rules.clone() })
+                .unwrap_or_default();
+
+            for can_bridge in &expected_can_bridges {
+                expected_can_local_routes.push(parameter::CanLocalRoute {
+                    can_source_device_name: can_device.clone(),
+                    can_destination_device_name: can_bridge.clone(),
+                    rules: device_rules.clone(),
+                });
+                expected_can_local_routes.push(parameter::CanLocalRoute {
+                    can_source_device_name: can_bridge.clone(),
+                    can_destination_device_name: can_device.clone(),
+                    rules: CanFrameRules::default(),
+                });
+            }
         }
         can_local_routes.set_all_present(expected_can_local_routes, can_dependencies.clone());
 
+        // Restbus simulations
+        // Only simulate on the CAN devices of the cluster, which are created as device interfaces above.
+        let expected_restbus_simulations = expected_restbus_simulations.into_iter()
+            .filter(|simulation| can_device_names.contains(&simulation.can_interface_name));
+        restbus_simulations.set_all_present(expected_restbus_simulations, device_dependencies.clone());
+
     } else {
         // Clear CAN-related configurations if no CAN interfaces are expected
         can_connections.set_all_absent();
         can_bridges.set_all_absent();
         can_local_routes.set_all_absent();
+        restbus_simulations.set_all_absent();
     }
 
     let ethernet_bridge = peer_descriptor.network.bridge_name
         .unwrap_or(options.bridge_name_default);
 
     let bridge_dependencies = { // Ethernet bridge
-        let bridge = parameter::EthernetBridge { name: ethernet_bridge.clone() };
+        let bridge = parameter::EthernetBridge {
+            name: ethernet_bridge.clone(),
+            spanning_tree: loop_protection == LoopProtection::SpanningTree,
+        };
 
         ethernet_bridges.set_all_present(vec![bridge], vec![])
     };
 
+    let expected_joined_interfaces = {
+        let gre_interface_joins = expected_gre_config_parameters.iter()
+            .map(|gre_interface| {
+                gre_interface.interface_name()
+                    .map(|name| InterfaceJoinConfig {
+                        name,
+                        bridge: ethernet_bridge.clone(),
+                        isolated: loop_protection == LoopProtection::SplitHorizon,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|source| AssignClusterError::InterfaceName { source })?;
+
+        let ethernet_device_joins = ethernet_device_names.into_iter()
+            .map(|name| InterfaceJoinConfig { name, bridge: ethernet_bridge.clone(), isolated: false });
+
+        gre_interface_joins.into_iter()
+            .chain(ethernet_device_joins)
+            .collect::<Vec<_>>()
+    };
+
     // GRE interfaces
     let gre_upstream_dependencies = device_dependencies.into_iter().chain(bridge_dependencies.clone()).collect::<Vec<_>>();
     let gre_dependencies = gre_interfaces.set_all_present(expected_gre_config_parameters, gre_upstream_dependencies);
 
     
     { // Joined interfaces (all ethernet interfaces + GRE interfaces -> bridge)
-        let expected_joined_interfaces = expected_joined_interface_names.iter()
-            .map(|name| InterfaceJoinConfig { name: name.clone(), bridge: ethernet_bridge.clone() })
-            .collect::<Vec<_>>();
-
         let joined_interfaces_upstream_dependencies = gre_dependencies.into_iter().chain(bridge_dependencies).collect::<Vec<_>>();
         joined_interfaces.set_all_present(expected_joined_interfaces, joined_interfaces_upstream_dependencies);
     }
@@ -160,9 +194,12 @@ pub(super) fn update_peer_configuration(
     }
 
     { //Remote Peer Connection Checks
-        if cluster_assignment.leader == peer_descriptor.id {
-            let remote_peers = cluster_assignment.non_leader_assignments();
+        let is_star_follower = cluster_assignment.topology == ClusterTopology::Star && cluster_assignment.leader != peer_descriptor.id;
 
+        if is_star_follower {
+            // Clear other peers, in case the Leader has changed
+            remote_peer_connection_checks.set_all_absent();
+        } else {
             let expected_remote_peer_connection_checks = remote_peers.into_iter()
                 .map(|(remote_peer_id, peer_cluster_assignment)| {
                     parameter::RemotePeerConnectionCheck {
@@ -173,58 +210,456 @@ pub(super) fn update_peer_configuration(
                 .collect::<Vec<_>>();
 
             remote_peer_connection_checks.set_all_present(expected_remote_peer_connection_checks, vec![]);
-        } else {
-            // Clear other peers, in case the Leader has changed
-            remote_peer_connection_checks.set_all_absent();
         }
     }
 
     Ok(())
 }
 
+const SHARED_CAN_BRIDGE_NAME: &str = "br-vcan-opendut";
+
+/// How traffic is kept from circling between the peers of a cluster.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LoopProtection {
+    /// Not needed, as the leader is the only peer forwarding traffic between the others.
+    None,
+    /// Traffic received from a peer is not forwarded to other peers, as all peers are directly connected.
+    SplitHorizon,
+    /// Loops in the Ethernet network are cut by the Spanning Tree Protocol, allowing traffic to be forwarded via intermediate peers.
+    SpanningTree,
+}
+
+impl LoopProtection {
+    fn for_topology(topology: &ClusterTopology) -> Self {
+        match topology {
+            ClusterTopology::Star => LoopProtection::None,
+            ClusterTopology::FullMesh => LoopProtection::SplitHorizon,
+            ClusterTopology::Custom { .. } => LoopProtection::SpanningTree,
+        }
+    }
+}
+
+/// Derives a name for the CAN bridge of each remote peer from a hash of its ID.
+/// As the name is limited to 15 characters, the hash is shortened, so a colliding name is rehashed until it is unique.
+fn can_bridge_names_for_remote_peers(remote_peer_ids: impl IntoIterator<Item=PeerId>) -> Result<HashMap<PeerId, NetworkInterfaceName>, AssignClusterError> {
+    let mut remote_peer_ids = remote_peer_ids.into_iter().collect::<Vec<_>>();
+    remote_peer_ids.sort_by_key(|peer_id| peer_id.uuid);
+
+    let mut can_bridge_names = HashMap::new();
+    for remote_peer_id in remote_peer_ids {
+        let mut attempt = 0u32;
+        let can_bridge_name = loop {
+            let name = can_bridge_name_for_remote_peer(remote_peer_id, attempt)?;
+            if can_bridge_names.values().all(|existing_name| existing_name != &name) {
+                break name;
+            }
+            debug!("CAN bridge name '{name}' for remote peer <{remote_peer_id}> collides with the bridge of another remote peer. Rehashing.");
+            attempt += 1;
+        };
+        can_bridge_names.insert(remote_peer_id, can_bridge_name);
+    }
+    Ok(can_bridge_names)
+}
+
+fn can_bridge_name_for_remote_peer(remote_peer_id: PeerId, attempt: u32) -> Result<NetworkInterfaceName, AssignClusterError> {
+    // FNV-1a, as its output is stable across Rust versions, unlike `std::hash::DefaultHasher`.
+    let hash = remote_peer_id.uuid.as_bytes().iter()
+        .chain(attempt.to_be_bytes().iter())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3));
+
+    NetworkInterfaceName::try_from(format!("br-vcan-{:07x}", hash & 0x0fff_ffff))
+        .map_err(|source| AssignClusterError::InterfaceName { source })
+}
+
+fn can_connection(
+    can_interface_name: NetworkInterfaceName,
+    local_is_server: bool,
+    local_assignment: &PeerClusterAssignment,
+    remote_peer_id: PeerId,
+    remote_assignment: &PeerClusterAssignment,
+    transport: CanTransport,
+    rate_limits: Vec<CanRateLimitRule>,
+) -> parameter::CanConnection {
+    let (local_port, remote_port) = match transport {
+        // Each end binds the port assigned to the other peer, so a peer binds a different port for each of its connections.
+        CanTransport::Udp => (remote_assignment.can_server_port, local_assignment.can_server_port),
+        // The server listens on the port assigned to the client, so it can serve multiple clients at once.
+        // The client connects from an ephemeral port.
+        CanTransport::Sctp => {
+            let client_port = if local_is_server {
+                remote_assignment.can_server_port
+            } else {
+                local_assignment.can_server_port
+            };
+            (client_port, client_port)
+        }
+    };
+
+    parameter::CanConnection {
+        can_interface_name,
+        local_is_server,
+        remote_peer_id,
+        remote_ip: remote_assignment.vpn_address,
+        remote_port,
+        local_port,
+        buffer_timeout_microseconds: 100,
+        transport,
+        rules: CanFrameRules::default(),
+        rate_limits,
+    }
+}
+
 
 fn determine_expected_gre_interface_config_parameters(peer_id: PeerId, cluster_assignment: &ClusterAssignment) -> Result<Vec<GreInterfaceConfig>, AssignClusterError> {
-    let leader_remote_ip = cluster_assignment.leader_assignment()
+    let local_ip = cluster_assignment.assignments
+        .get(&peer_id)
         .map(|assignment| assignment.vpn_address)
-        .map(require_ipv4_for_gre)
-        .ok_or(AssignClusterError::PeerNotFound(cluster_assignment.leader))??;
-
-    if peer_id == cluster_assignment.leader {
-        // Leader shall create GRE interfaces to bridge traffic to all peers
-        let remote_peer_assignments = cluster_assignment.non_leader_assignments();
-
-        let gre_configs = remote_peer_assignments.values()
-            .map(|assignment| assignment.vpn_address)
-            .map(require_ipv4_for_gre)
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .map(|remote_ip| GreInterfaceConfig {
-                local_ip: leader_remote_ip,
-                remote_ip,
-            })
-            .collect::<Vec<GreInterfaceConfig>>();
+        .ok_or(AssignClusterError::PeerNotFound(peer_id))?;
+
+    // Create GRE interfaces to bridge traffic to all directly connected peers
+    cluster_assignment.assignments_of_peers_directly_connected_to(peer_id).values()
+        .map(|assignment| gre_interface_config(local_ip, assignment.vpn_address))
+        .collect::<Result<Vec<GreInterfaceConfig>, _>>()
+}
 
-        Ok(gre_configs)
+fn gre_interface_config(local_ip: IpAddr, remote_ip: IpAddr) -> Result<GreInterfaceConfig, AssignClusterError> {
+    if local_ip.is_ipv4() == remote_ip.is_ipv4() {
+        Ok(GreInterfaceConfig { local_ip, remote_ip })
     } else {
-        // Other peers shall only send traffic to the leader
-        let peer_vpn_address = cluster_assignment.assignments
-            .get(&peer_id)
-            .map(|assignment| assignment.vpn_address)
-            .map(require_ipv4_for_gre)
-            .ok_or(AssignClusterError::PeerNotFound(peer_id))??;
-
-        Ok(vec![
-            GreInterfaceConfig {
-                local_ip: peer_vpn_address,
-                remote_ip: leader_remote_ip,
-            }
-        ])
+        Err(AssignClusterError::GreAddressFamilyMismatch { local_ip, remote_ip })
     }
 }
 
-fn require_ipv4_for_gre(ip_address: IpAddr) -> Result<Ipv4Addr, AssignClusterError> {
-    match ip_address {
-        IpAddr::V4(ip_address) => Ok(ip_address),
-        IpAddr::V6(_) => Err(AssignClusterError::Ipv6NotSupported),
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::peer_manager::tests::create_peer_descriptor;
+    use opendut_model::cluster::{ClusterId, ClusterLink};
+    use opendut_model::peer::configuration::{ParameterField, ParameterTarget, ParameterValue};
+    use opendut_model::util::can::{CanIdFilter, CanIdRewrite, CanRateLimit};
+    use opendut_model::util::net::NetworkInterfaceId;
+    use opendut_model::util::Port;
+    use std::collections::{HashMap, HashSet};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_determine_gre_interfaces_for_ipv6_vpn_addresses() -> anyhow::Result<()> {
+        let leader = PeerId::random();
+        let follower = PeerId::random();
+        let leader_address = IpAddr::V6(Ipv6Addr::from_str("fd00:1234::1")?);
+        let follower_address = IpAddr::V6(Ipv6Addr::from_str("fd00:1234::2")?);
+
+        let cluster_assignment = cluster_assignment(leader, leader_address, follower, follower_address);
+
+        let leader_gre_interfaces = determine_expected_gre_interface_config_parameters(leader, &cluster_assignment)?;
+        assert_eq!(leader_gre_interfaces, vec![GreInterfaceConfig { local_ip: leader_address, remote_ip: follower_address }]);
+
+        let follower_gre_interfaces = determine_expected_gre_interface_config_parameters(follower, &cluster_assignment)?;
+        assert_eq!(follower_gre_interfaces, vec![GreInterfaceConfig { local_ip: follower_address, remote_ip: leader_address }]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_gre_interfaces_between_different_ip_families() -> anyhow::Result<()> {
+        let leader = PeerId::random();
+        let follower = PeerId::random();
+        let leader_address = IpAddr::V4(Ipv4Addr::from_str("192.168.1.1")?);
+        let follower_address = IpAddr::V6(Ipv6Addr::from_str("fd00:1234::2")?);
+
+        let cluster_assignment = cluster_assignment(leader, leader_address, follower, follower_address);
+
+        let result = determine_expected_gre_interface_config_parameters(follower, &cluster_assignment);
+        assert!(matches!(result, Err(AssignClusterError::GreAddressFamilyMismatch { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_connect_all_peers_directly_with_split_horizon_in_a_full_mesh() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random()];
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, ClusterTopology::FullMesh)?;
+
+        for (index, peer_id) in peers.iter().enumerate() {
+            let peer_configuration = configure_peer_with_can_device(*peer_id, &cluster_assignment)?;
+
+            let gre_remote_ips = present_values(&peer_configuration.gre_interfaces).into_iter()
+                .map(|gre_interface| gre_interface.remote_ip)
+                .collect::<Vec<_>>();
+            assert_eq!(gre_remote_ips.len(), 2);
+            assert!(gre_remote_ips.contains(&peer_address(index + 1)) && gre_remote_ips.contains(&peer_address(index + 2)));
+
+            let bridge = present_values(&peer_configuration.ethernet_bridges);
+            assert!(bridge.iter().all(|bridge| !bridge.spanning_tree));
+
+            let gre_joins = present_values(&peer_configuration.joined_interfaces).into_iter()
+                .filter(|join| join.name.name().starts_with("gre-"))
+                .collect::<Vec<_>>();
+            assert_eq!(gre_joins.len(), 2);
+            assert!(gre_joins.iter().all(|join| join.isolated));
+
+            let can_connections = present_values(&peer_configuration.can_connections);
+            assert_eq!(can_connections.len(), 2);
+            let can_bridges = present_values(&peer_configuration.can_bridges);
+            assert_eq!(can_bridges.len(), 2, "Expected a separate CAN bridge per remote peer.");
+            assert!(can_connections.iter().all(|connection| can_bridges.iter().any(|bridge| bridge.name == connection.can_interface_name)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_use_matching_ports_for_both_ends_of_a_can_connection() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random()];
+
+        for topology in [ClusterTopology::Star, ClusterTopology::FullMesh] {
+            let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, topology)?;
+            let can_connections = peers.iter()
+                .map(|peer_id| Ok((*peer_id, present_values(&configure_peer_with_can_device(*peer_id, &cluster_assignment)?.can_connections))))
+                .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+            for link in &cluster_assignment.links {
+                let server = can_connections[&link.peer_a].iter()
+                    .find(|connection| connection.remote_peer_id == link.peer_b)
+                    .expect("Server side of CAN connection should be configured.");
+                let client = can_connections[&link.peer_b].iter()
+                    .find(|connection| connection.remote_peer_id == link.peer_a)
+                    .expect("Client side of CAN connection should be configured.");
+
+                assert!(server.local_is_server);
+                assert!(!client.local_is_server);
+                assert_eq!(server.local_port, client.remote_port);
+                assert_eq!(client.local_port, server.remote_port);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_bind_a_different_local_port_for_each_can_connection_of_a_peer() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random(), PeerId::random()];
+
+        for topology in [ClusterTopology::Star, ClusterTopology::FullMesh] {
+            let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, topology)?;
+
+            for peer_id in &peers {
+                let can_connections = present_values(&configure_peer_with_can_device(*peer_id, &cluster_assignment)?.can_connections);
+
+                let local_ports = can_connections.iter()
+                    .map(|connection| connection.local_port)
+                    .collect::<HashSet<_>>();
+                assert_eq!(local_ports.len(), can_connections.len(), "Peer <{peer_id}> should not bind the same local port for multiple CAN connections.");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_enable_spanning_tree_for_custom_topologies() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random()];
+        let topology = ClusterTopology::Custom { links: vec![
+            ClusterLink { peer_a: peers[0], peer_b: peers[1] },
+            ClusterLink { peer_a: peers[1], peer_b: peers[2] },
+        ]};
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, topology)?;
+
+        let peer_configuration = configure_peer_with_can_device(peers[2], &cluster_assignment)?;
+
+        let gre_interfaces = present_values(&peer_configuration.gre_interfaces);
+        assert_eq!(gre_interfaces, vec![GreInterfaceConfig { local_ip: peer_address(2), remote_ip: peer_address(1) }]);
+        let bridges = present_values(&peer_configuration.ethernet_bridges);
+        assert!(bridges.iter().all(|bridge| bridge.spanning_tree));
+        let joins = present_values(&peer_configuration.joined_interfaces);
+        assert!(joins.iter().all(|join| !join.isolated));
+        let remote_peer_connection_checks = present_values(&peer_configuration.remote_peer_connection_checks);
+        assert_eq!(remote_peer_connection_checks.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn should_forward_can_frames_via_intermediate_peers_in_custom_topologies() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random()];
+        let topology = ClusterTopology::Custom { links: vec![
+            ClusterLink { peer_a: peers[0], peer_b: peers[1] },
+            ClusterLink { peer_a: peers[1], peer_b: peers[2] },
+        ]};
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, topology)?;
+
+        let peer_configuration = configure_peer_with_can_device(peers[1], &cluster_assignment)?;
+
+        let can_bridges = present_values(&peer_configuration.can_bridges);
+        assert_eq!(can_bridges, vec![parameter::CanBridge { name: NetworkInterfaceName::try_from(SHARED_CAN_BRIDGE_NAME)? }]);
+        let can_connections = present_values(&peer_configuration.can_connections);
+        let remote_peer_ids = can_connections.iter()
+            .map(|connection| connection.remote_peer_id)
+            .collect::<HashSet<_>>();
+        assert_eq!(remote_peer_ids, HashSet::from([peers[0], peers[2]]));
+        assert!(can_connections.iter().all(|connection| connection.can_interface_name.name() == SHARED_CAN_BRIDGE_NAME));
+        Ok(())
+    }
+
+    #[test]
+    fn should_only_connect_can_along_a_spanning_tree_in_custom_topologies_with_loops() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random(), PeerId::random()];
+        let topology = ClusterTopology::Custom { links: vec![
+            ClusterLink { peer_a: peers[0], peer_b: peers[1] },
+            ClusterLink { peer_a: peers[1], peer_b: peers[2] },
+            ClusterLink { peer_a: peers[2], peer_b: peers[0] },
+        ]};
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, topology)?;
+
+        let can_links = cluster_assignment.can_links();
+        assert_eq!(can_links.len(), 2);
+        assert!(can_links.iter().all(|link| link.connects(peers[0])));
+
+        let can_connection_count = peers.iter()
+            .map(|peer_id| Ok(present_values(&configure_peer_with_can_device(*peer_id, &cluster_assignment)?.can_connections).len()))
+            .sum::<anyhow::Result<usize>>()?;
+        assert_eq!(can_connection_count, 4, "Expected both ends of the two spanning tree links to be configured.");
+
+        let gre_interfaces = present_values(&configure_peer_with_can_device(peers[1], &cluster_assignment)?.gre_interfaces);
+        assert_eq!(gre_interfaces.len(), 2, "Ethernet should still use all links.");
+        Ok(())
+    }
+
+    #[test]
+    fn should_derive_distinct_can_bridge_names_for_remote_peers() -> anyhow::Result<()> {
+        let remote_peer_ids = (0..100).map(|_| PeerId::random()).collect::<Vec<_>>();
+
+        let can_bridge_names = can_bridge_names_for_remote_peers(remote_peer_ids.clone())?;
+
+        assert_eq!(can_bridge_names.len(), remote_peer_ids.len());
+        assert_eq!(can_bridge_names.values().collect::<HashSet<_>>().len(), remote_peer_ids.len());
+        assert_eq!(can_bridge_names, can_bridge_names_for_remote_peers(remote_peer_ids.into_iter().rev())?, "Names should not depend on the order of the remote peers.");
+
+        let remote_peer_id = PeerId::random();
+        assert_ne!(can_bridge_name_for_remote_peer(remote_peer_id, 0)?, can_bridge_name_for_remote_peer(remote_peer_id, 1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn should_apply_device_can_rules_to_local_routes_and_the_device_rate_limits_to_connections() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random()];
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, ClusterTopology::Star)?;
+        let device_rules = CanFrameRules {
+            reject: vec![CanIdFilter::exact(0x7df)?],
+            rewrites: vec![CanIdRewrite::new(0x123, 0x523)?],
+            rate_limit: Some(CanRateLimit { frames_per_second: 100 }),
+            ..CanFrameRules::default()
+        };
+        let can_rules = HashMap::from([(NetworkInterfaceName::try_from("vcan0")?, device_rules.clone())]);
+
+        let peer_configuration = configure_peer_with_can_rules(peers[1], &cluster_assignment, &can_rules)?;
+
+        let local_routes = present_values(&peer_configuration.can_local_routes);
+        let outgoing_route = local_routes.iter()
+            .find(|route| route.can_source_device_name.name() == "vcan0")
+            .expect("Route from the CAN device to the bridge should be configured.");
+        assert_eq!(outgoing_route.rules, CanFrameRules { rate_limit: None, ..device_rules.clone() });
+        let incoming_route = local_routes.iter()
+            .find(|route| route.can_destination_device_name.name() == "vcan0")
+            .expect("Route from the bridge to the CAN device should be configured.");
+        assert_eq!(incoming_route.rules, CanFrameRules::default());
+
+        let can_connections = present_values(&peer_configuration.can_connections);
+        assert_eq!(can_connections.len(), 1);
+        assert_eq!(can_connections[0].rules, CanFrameRules::default());
+        assert_eq!(can_connections[0].rate_limits, vec![device_rules.rate_limit_rule().expect("Device should have a rate limit.")]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_configure_restbus_simulations_on_can_devices_of_the_cluster() -> anyhow::Result<()> {
+        let peers = [PeerId::random(), PeerId::random()];
+        let cluster_assignment = cluster_assignment_for_topology(peers[0], &peers, ClusterTopology::Star)?;
+        let simulation = parameter::RestbusSimulation {
+            can_interface_name: NetworkInterfaceName::try_from("vcan0")?,
+            arxml_file: PathBuf::from("/etc/opendut/restbus.arxml"),
+            ecus: vec![String::from("Engine")],
+        };
+        let simulation_on_other_interface = parameter::RestbusSimulation {
+            can_interface_name: NetworkInterfaceName::try_from("vcan1")?,
+            ..simulation.clone()
+        };
+
+        let peer_configuration = configure_peer(peers[1], &cluster_assignment, &HashMap::new(), vec![simulation.clone(), simulation_on_other_interface])?;
+
+        assert_eq!(present_values(&peer_configuration.restbus_simulations), vec![simulation]);
+        let restbus_simulation = peer_configuration.restbus_simulations.values().next().unwrap();
+        let device_interface = peer_configuration.device_interfaces.values().next().unwrap();
+        assert_eq!(restbus_simulation.dependencies, vec![device_interface.id]);
+        Ok(())
+    }
+
+    fn cluster_assignment(leader: PeerId, leader_address: IpAddr, follower: PeerId, follower_address: IpAddr) -> ClusterAssignment {
+        ClusterAssignment {
+            id: ClusterId::random(),
+            leader,
+            assignments: HashMap::from([
+                (leader, PeerClusterAssignment { vpn_address: leader_address, can_server_port: Port(51234) }),
+                (follower, PeerClusterAssignment { vpn_address: follower_address, can_server_port: Port(51235) }),
+            ]),
+            topology: ClusterTopology::Star,
+            links: vec![ClusterLink { peer_a: leader, peer_b: follower }],
+        }
+    }
+
+    fn cluster_assignment_for_topology(leader: PeerId, peers: &[PeerId], topology: ClusterTopology) -> anyhow::Result<ClusterAssignment> {
+        let assignments = peers.iter().enumerate()
+            .map(|(index, peer_id)| {
+                let can_server_port = Port(51234 + u16::try_from(index).unwrap());
+                (*peer_id, PeerClusterAssignment { vpn_address: peer_address(index), can_server_port })
+            })
+            .collect::<HashMap<_, _>>();
+
+        let links = topology.links(leader, &peers.iter().copied().collect())?;
+
+        Ok(ClusterAssignment {
+            id: ClusterId::random(),
+            leader,
+            assignments,
+            topology,
+            links,
+        })
+    }
+
+    fn peer_address(index: usize) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1 + u8::try_from(index % 3).unwrap()))
+    }
+
+    fn configure_peer_with_can_device(peer_id: PeerId, cluster_assignment: &ClusterAssignment) -> anyhow::Result<PeerConfiguration> {
+        configure_peer_with_can_rules(peer_id, cluster_assignment, &HashMap::new())
+    }
+
+    fn configure_peer_with_can_rules(peer_id: PeerId, cluster_assignment: &ClusterAssignment, can_rules: &HashMap<NetworkInterfaceName, CanFrameRules>) -> anyhow::Result<PeerConfiguration> {
+        configure_peer(peer_id, cluster_assignment, can_rules, vec![])
+    }
+
+    fn configure_peer(peer_id: PeerId, cluster_assignment: &ClusterAssignment, can_rules: &HashMap<NetworkInterfaceName, CanFrameRules>, restbus_simulations: Vec<parameter::RestbusSimulation>) -> anyhow::Result<PeerConfiguration> {
+        let mut peer_configuration = PeerConfiguration::default();
+        let can_device = NetworkInterfaceDescriptor {
+            id: NetworkInterfaceId::random(),
+            name: NetworkInterfaceName::try_from("vcan0")?,
+            configuration: NetworkInterfaceConfiguration::Vcan,
+        };
+
+        update_peer_configuration(
+            &mut peer_configuration,
+            create_peer_descriptor(peer_id),
+            cluster_assignment,
+            vec![can_device],
+            can_rules,
+            restbus_simulations,
+            AssignClusterOptions { bridge_name_default: NetworkInterfaceName::try_from("br-opendut")?, can_transport: CanTransport::Udp },
+        )?;
+        Ok(peer_configuration)
+    }
+
+    fn present_values<V: ParameterValue>(field: &ParameterField<V>) -> Vec<V> {
+        field.values()
+            .filter(|parameter| parameter.target == ParameterTarget::Present)
+            .map(|parameter| parameter.value.clone())
+            .collect()
     }
 }
@@ -2,10 +2,15 @@ mod assignment;
 mod configuration;
 
 use crate::manager::peer_messaging_broker::PeerMessagingBrokerRef;
+use std::collections::HashMap;
+use std::net::IpAddr;
 use crate::resource::persistence::error::PersistenceError;
 use crate::resource::storage::ResourcesStorageApi;
 use opendut_model::peer::configuration::PeerConfiguration;
+use opendut_model::peer::configuration::parameter;
+use opendut_model::peer::configuration::parameter::CanTransport;
 use opendut_model::peer::{PeerDescriptor, PeerId};
+use opendut_model::util::can::CanFrameRules;
 use opendut_model::util::net::{NetworkInterfaceDescriptor, NetworkInterfaceName, NetworkInterfaceNameError};
 use tracing::debug;
 use opendut_carl_api::carl::broker::{ApplyPeerConfiguration, DownstreamMessagePayload};
@@ -18,13 +23,18 @@ pub struct AssignClusterParams {
     pub peer_messaging_broker: PeerMessagingBrokerRef,
     pub peer_id: PeerId,
     pub device_interfaces: Vec<NetworkInterfaceDescriptor>,
+    /// Rules for the frames, which the CAN devices of the peer forward to the cluster.
+    pub can_rules: HashMap<NetworkInterfaceName, CanFrameRules>,
+    /// Restbus simulations on the CAN devices of the peer, which are part of the cluster.
+    pub restbus_simulations: Vec<parameter::RestbusSimulation>,
     pub cluster_assignment: ClusterAssignment,
     pub options: AssignClusterOptions,
 }
 
 #[derive(Clone)]
 pub struct AssignClusterOptions {
-    pub bridge_name_default: NetworkInterfaceName
+    pub bridge_name_default: NetworkInterfaceName,
+    pub can_transport: CanTransport,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -37,13 +47,13 @@ pub enum AssignClusterError {
     SendingToPeerFailed { peer_id: PeerId, cause: String },
     #[error("Error while persisting ClusterAssignment for peer <{peer_id}>.")]
     Persistence { peer_id: PeerId, #[source] source: PersistenceError },
-    #[error("IPv6 not supported for GRE interface configuration.")]
-    Ipv6NotSupported,
+    #[error("Cannot create GRE interface between '{local_ip}' and '{remote_ip}', because the addresses belong to different IP families.")]
+    GreAddressFamilyMismatch { local_ip: IpAddr, remote_ip: IpAddr },
 }
 
 impl Resources<'_> {
     pub async fn assign_cluster(&mut self, params: AssignClusterParams) -> Result<(), AssignClusterError> {
-        let AssignClusterParams { peer_messaging_broker, peer_id, cluster_assignment, device_interfaces, options } = params;
+        let AssignClusterParams { peer_messaging_broker, peer_id, cluster_assignment, device_interfaces, can_rules, restbus_simulations, options } = params;
 
         debug!("Assigning cluster to peer <{peer_id}>.");
 
@@ -62,6 +72,8 @@ impl Resources<'_> {
                 peer_descriptor,
                 &cluster_assignment,
                 device_interfaces,
+                &can_rules,
+                restbus_simulations,
                 options
             )?;
 
@@ -94,7 +106,7 @@ mod tests {
     use crate::manager::peer_messaging_broker::{PeerMessagingBroker, PeerMessagingBrokerOptions};
     use crate::resource::manager::ResourceManager;
     use googletest::prelude::*;
-    use opendut_model::cluster::ClusterId;
+    use opendut_model::cluster::{ClusterId, ClusterTopology};
     use std::net::{IpAddr, Ipv4Addr};
     use std::str::FromStr;
     use std::sync::Arc;
@@ -141,6 +153,8 @@ mod tests {
                     can_server_port: opendut_model::util::Port(51234),
                 })
             ]),
+            topology: ClusterTopology::default(),
+            links: vec![],
         };
 
         resource_manager.resources_mut(async |resources|
@@ -149,15 +163,18 @@ mod tests {
                 peer_id,
                 cluster_assignment: Clone::clone(&cluster_assignment),
                 device_interfaces: vec![],
+                can_rules: HashMap::new(),
+                restbus_simulations: vec![],
                 options: AssignClusterOptions {
                     bridge_name_default: NetworkInterfaceName::try_from("br-opendut").unwrap(),
+                    can_transport: CanTransport::default(),
                 }
             }).await
         ).await??;
 
         let mut peer_configuration = PeerConfiguration::default();
         peer_configuration.ethernet_bridges.set(
-            parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut-1")? },
+            parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut-1")?, spanning_tree: false },
             ParameterTarget::Present,
             vec![],
         );
@@ -168,6 +185,8 @@ mod tests {
         match received {
             DownstreamMessagePayload::Pong => panic!("Expected ApplyPeerConfiguration, got Pong"),
             DownstreamMessagePayload::DisconnectNotice => panic!("Expected ApplyPeerConfiguration, got DisconnectNotice"),
+            #[cfg(feature = "viper")]
+            DownstreamMessagePayload::ExecuteViperRun(_) => panic!("Expected ApplyPeerConfiguration, got ExecuteViperRun"),
             DownstreamMessagePayload::ApplyPeerConfiguration(peer_config) => {
                 assert_that!(peer_config.configuration, eq(&peer_configuration));
             }
@@ -6,6 +6,7 @@ use opendut_auth::registration::client::RegistrationClientRef;
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerName};
 use tracing::{debug, info, warn};
 use opendut_model::cluster::ClusterId;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::state::{PeerMemberState, PeerState};
 use crate::manager::peer_manager::list_peer_member_states::ListPeerMemberStatesError;
 use crate::resource::api::resources::Resources;
@@ -15,6 +16,8 @@ pub struct DeletePeerDescriptorParams {
     pub vpn: Vpn,
     pub peer: PeerId,
     pub oidc_registration_client: Option<RegistrationClientRef>,
+    /// Only delete the peer, if it belongs to this namespace. Peers of other namespaces are treated as not found.
+    pub namespace: Option<NamespaceName>,
 }
 
 impl Resources<'_> {
@@ -23,6 +26,14 @@ impl Resources<'_> {
 
         let peer_id = params.peer;
 
+        if let Some(namespace) = &params.namespace {
+            let peer_descriptor = self.get::<PeerDescriptor>(peer_id)
+                .map_err(|source| DeletePeerDescriptorError::Persistence { peer_id, peer_name: None, source })?;
+            if peer_descriptor.is_none_or(|peer_descriptor| peer_descriptor.namespace != *namespace) {
+                return Err(DeletePeerDescriptorError::PeerNotFound { peer_id });
+            }
+        }
+
         let peer_member_states = self.list_peer_member_states()
             .map_err(|source| match source {
                 ListPeerMemberStatesError::Persistence { source } => {
@@ -42,6 +53,9 @@ impl Resources<'_> {
 
             let peer_name = &peer_descriptor.name;
 
+            self.remove_stale_executor_states(peer_id, &[])
+                .map_err(|source| DeletePeerDescriptorError::Persistence { peer_id, peer_name: Some(peer_name.clone()), source })?;
+
             if let Some(registration_client) = params.oidc_registration_client {
                 let resource_id = peer_id.into();
                 debug!("Deleting OIDC client for peer '{peer_name}' <{peer_id}>.");
@@ -131,7 +145,7 @@ mod tests {
         resource_manager.insert(cluster.id, ClusterDeployment { id: cluster.id }).await?;
 
         // Act
-        let delete_peer_descriptor_params = DeletePeerDescriptorParams { vpn: Vpn::Disabled, peer: cluster.peer_a.id, oidc_registration_client: None };
+        let delete_peer_descriptor_params = DeletePeerDescriptorParams { vpn: Vpn::Disabled, peer: cluster.peer_a.id, oidc_registration_client: None, namespace: None };
         let result = resource_manager.resources_mut(async |resources|
             resources.delete_peer_descriptor(delete_peer_descriptor_params).await
         ).await?;
@@ -153,7 +167,7 @@ mod tests {
         let cluster = ClusterFixture::create(resource_manager.clone()).await?;
 
         // Act
-        let delete_peer_descriptor_params = DeletePeerDescriptorParams { vpn: Vpn::Disabled, peer: cluster.peer_a.id, oidc_registration_client: None };
+        let delete_peer_descriptor_params = DeletePeerDescriptorParams { vpn: Vpn::Disabled, peer: cluster.peer_a.id, oidc_registration_client: None, namespace: None };
         let result = resource_manager.resources_mut(async |resources|
             resources.delete_peer_descriptor(delete_peer_descriptor_params).await
         ).await??;
@@ -163,4 +177,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_not_delete_a_peer_descriptor_of_another_namespace() -> anyhow::Result<()> {
+        // Arrange
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(resource_manager.clone()).await?;
+
+        // Act
+        let delete_peer_descriptor_params = DeletePeerDescriptorParams { vpn: Vpn::Disabled, peer: cluster.peer_b.id, oidc_registration_client: None, namespace: Some(NamespaceName::try_from("other")?) };
+        let result = resource_manager.resources_mut(async |resources|
+            resources.delete_peer_descriptor(delete_peer_descriptor_params).await
+        ).await?;
+
+        // Assert
+        let Err(DeletePeerDescriptorError::PeerNotFound { peer_id }) = result
+        else { panic!("Result is not an error of PeerNotFound.") };
+
+        assert_eq!(peer_id, cluster.peer_b.id);
+        assert!(resource_manager.get::<PeerDescriptor>(cluster.peer_b.id).await?.is_some());
+
+        Ok(())
+    }
 }
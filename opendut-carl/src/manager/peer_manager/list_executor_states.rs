@@ -0,0 +1,59 @@
+use crate::resource::api::resources::Resources;
+use crate::resource::persistence::error::PersistenceError;
+use crate::resource::storage::ResourcesStorageApi;
+use opendut_model::peer::executor::state::ExecutorState;
+use opendut_model::peer::PeerId;
+use tracing::debug;
+
+impl Resources<'_> {
+    #[tracing::instrument(skip_all, level="trace")]
+    pub fn list_executor_states(&self, peer_id: PeerId) -> Result<Vec<ExecutorState>, ListExecutorStatesError> {
+
+        debug!("Querying executor states of peer <{peer_id}>.");
+        let executor_states = self.list::<ExecutorState>()
+            .map_err(|source| ListExecutorStatesError::Persistence { peer_id, source })?
+            .into_values()
+            .filter(|state| state.peer == peer_id)
+            .collect::<Vec<_>>();
+
+        debug!("Successfully queried executor states of peer <{peer_id}>.");
+
+        Ok(executor_states)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListExecutorStatesError {
+    #[error("Error when accessing persistence while listing executor states of peer <{peer_id}>")]
+    Persistence {
+        peer_id: PeerId,
+        #[source] source: PersistenceError,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::manager::ResourceManager;
+    use opendut_model::peer::executor::state::ExecutorStatus;
+    use opendut_model::peer::executor::ExecutorId;
+
+    #[tokio::test]
+    async fn should_list_only_the_executor_states_of_the_given_peer() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let peer_id = PeerId::random();
+        let own_state = ExecutorState { id: ExecutorId::random(), peer: peer_id, status: ExecutorStatus::Exited { code: Some(1) } };
+        let other_state = ExecutorState { id: ExecutorId::random(), peer: PeerId::random(), status: ExecutorStatus::Running };
+
+        resource_manager.insert(own_state.id, own_state.clone()).await?;
+        resource_manager.insert(other_state.id, other_state).await?;
+
+        let result = resource_manager.resources(async |resources|
+            resources.list_executor_states(peer_id)
+        ).await??;
+
+        assert_eq!(result, vec![own_state]);
+        Ok(())
+    }
+}
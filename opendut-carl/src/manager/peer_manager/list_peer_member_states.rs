@@ -11,12 +11,13 @@ use std::collections::HashMap;
 impl Resources<'_> {
     pub fn list_peer_member_states(&self) -> Result<HashMap<PeerId, PeerMemberState>, ListPeerMemberStatesError> {
         let deployed_clusters = cluster_manager::internal::list_deployed_clusters(self)?;
-        let deployed_devices = deployed_clusters.into_iter()
-            .flat_map(|deployed_cluster| {
-                let cluster_id = deployed_cluster.id;
-                deployed_cluster.devices.into_iter().map(move |device_id| (device_id, cluster_id))
-            })
-            .collect::<HashMap<_, _>>();
+        let mut deployed_devices = HashMap::new();
+        for deployed_cluster in deployed_clusters {
+            let cluster_devices = cluster_manager::resolve_cluster_devices(self, &deployed_cluster)?;
+            deployed_devices.extend(
+                cluster_devices.into_iter().map(|device_id| (device_id, deployed_cluster.id))
+            );
+        }
 
 
         let all_peers = self.list::<PeerDescriptor>()?;
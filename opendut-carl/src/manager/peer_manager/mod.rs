@@ -8,6 +8,10 @@ pub mod get_peer_state;
 
 pub mod list_peer_states;
 
+pub mod list_executor_states;
+
+pub mod update_executor_state;
+
 pub mod list_devices;
 
 pub mod generate_peer_setup;
@@ -2,9 +2,13 @@ use crate::resource::api::resources::Resources;
 use crate::resource::persistence::error::PersistenceError;
 use crate::resource::storage::ResourcesStorageApi;
 use crate::settings::vpn::Vpn;
+use opendut_model::cluster::{ClusterDescriptor, ClusterId};
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::state::PeerState;
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerName};
+use opendut_model::resources::Revision;
 use opendut_model::ShortName;
+use std::ops::Not;
 use tracing::{debug, error, info, warn};
 
 pub struct StorePeerDescriptorParams {
@@ -18,11 +22,50 @@ impl Resources<'_> {
 
         let peer_id = params.peer_descriptor.id;
         let peer_name = Clone::clone(&params.peer_descriptor.name);
-        let peer_descriptor = params.peer_descriptor;
+        let mut peer_descriptor = params.peer_descriptor;
 
-        let is_new_peer = self.get::<PeerDescriptor>(peer_id)
-            .map_err(|source| StorePeerDescriptorError::Persistence { peer_id, peer_name: peer_name.clone(), source })?
-            .is_none();
+        let stored_peer_descriptor = self.get::<PeerDescriptor>(peer_id)
+            .map_err(|source| StorePeerDescriptorError::Persistence { peer_id, peer_name: peer_name.clone(), source })?;
+
+        let actual_revision = stored_peer_descriptor.as_ref()
+            .map(|peer_descriptor| peer_descriptor.revision)
+            .unwrap_or(Revision::INITIAL);
+
+        if peer_descriptor.revision != actual_revision {
+            return Err(StorePeerDescriptorError::Conflict {
+                peer_id,
+                peer_name,
+                expected_revision: peer_descriptor.revision,
+                actual_revision,
+            });
+        }
+        peer_descriptor.revision = actual_revision.next();
+
+        if let Some(stored_peer_descriptor) = &stored_peer_descriptor
+            && stored_peer_descriptor.namespace != peer_descriptor.namespace {
+            let clusters = self.list::<ClusterDescriptor>()
+                .map_err(|source| StorePeerDescriptorError::Persistence { peer_id, peer_name: peer_name.clone(), source })?;
+            let mut referencing_clusters = clusters.into_values()
+                .filter(|cluster| cluster.namespace == stored_peer_descriptor.namespace)
+                .filter(|cluster|
+                    cluster.leader == peer_id
+                    || stored_peer_descriptor.topology.devices.iter().any(|device| cluster.devices.contains(&device.id))
+                )
+                .map(|cluster| cluster.id)
+                .collect::<Vec<_>>();
+
+            if referencing_clusters.is_empty().not() {
+                referencing_clusters.sort_by_key(|cluster_id| cluster_id.uuid);
+                return Err(StorePeerDescriptorError::ReferencedByClusters {
+                    peer_id,
+                    peer_name,
+                    namespace: Clone::clone(&stored_peer_descriptor.namespace),
+                    cluster_ids: referencing_clusters,
+                });
+            }
+        }
+
+        let is_new_peer = stored_peer_descriptor.is_none();
 
         if is_new_peer {
             if let Vpn::Enabled { vpn_client } = &params.vpn {
@@ -38,7 +81,12 @@ impl Resources<'_> {
             }
         }
 
+        let executor_ids = peer_descriptor.executors.executors.iter()
+            .map(|executor| executor.id)
+            .collect::<Vec<_>>();
+
         let persistence_result = self.insert(peer_id, peer_descriptor)
+            .and_then(|()| self.remove_stale_executor_states(peer_id, &executor_ids))
             .map(|()| peer_id)
             .map_err(|source| StorePeerDescriptorError::Persistence { peer_id, peer_name: peer_name.clone(), source });
 
@@ -72,6 +120,23 @@ pub enum StorePeerDescriptorError {
         actual_state: PeerState,
         required_states: Vec<PeerState>,
     },
+    #[error("Peer '{peer_name}' <{peer_id}> could not be stored, because it is based on revision {expected_revision}, but the stored revision is {actual_revision}. Reload the peer and try again.")]
+    Conflict {
+        peer_id: PeerId,
+        peer_name: PeerName,
+        expected_revision: Revision,
+        actual_revision: Revision,
+    },
+    #[error(
+        "Peer '{peer_name}' <{peer_id}> cannot be moved out of namespace '{namespace}', because it is used by the clusters <{clusters}>",
+        clusters = cluster_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+    )]
+    ReferencedByClusters {
+        peer_id: PeerId,
+        peer_name: PeerName,
+        namespace: NamespaceName,
+        cluster_ids: Vec<ClusterId>,
+    },
     #[error("Error when accessing persistence while storing peer '{peer_name}' <{peer_id}>")]
     Persistence {
         peer_id: PeerId,
@@ -89,9 +154,10 @@ pub enum StorePeerDescriptorError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::manager::testing::PeerFixture;
+    use crate::manager::testing::{ClusterFixture, PeerFixture};
     use crate::resource::manager::ResourceManager;
     use googletest::prelude::*;
+    use std::sync::Arc;
     use opendut_model::peer::PeerNetworkDescriptor;
     use opendut_model::topology::DeviceDescriptor;
     use opendut_model::topology::{DeviceDescription, DeviceId, DeviceName, Topology};
@@ -109,7 +175,11 @@ mod tests {
             }).await
         ).await??;
 
-        assert_that!(resource_manager.get::<PeerDescriptor>(peer.id).await?.as_ref(), some(eq(&peer.descriptor)));
+        let stored_descriptor = PeerDescriptor {
+            revision: Revision(1),
+            ..Clone::clone(&peer.descriptor)
+        };
+        assert_that!(resource_manager.get::<PeerDescriptor>(peer.id).await?.as_ref(), some(eq(&stored_descriptor)));
         // TODO: what about PeerState?
 
         let additional_network_interface = NetworkInterfaceDescriptor {
@@ -140,7 +210,7 @@ mod tests {
                     Clone::clone(&additional_device),
                 ]
             },
-            ..Clone::clone(&peer.descriptor)
+            ..Clone::clone(&stored_descriptor)
         };
 
         resource_manager.resources_mut(async |resources|
@@ -150,9 +220,89 @@ mod tests {
             }).await
         ).await??;
 
+        let changed_descriptor = PeerDescriptor {
+            revision: Revision(2),
+            ..changed_descriptor
+        };
         assert_that!(resource_manager.get::<PeerDescriptor>(peer.id).await?.as_ref(), some(eq(&changed_descriptor)));
         // TODO: what about PeerState?
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_reject_a_peer_descriptor_with_a_stale_revision() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let peer = PeerFixture::new();
+
+        let store = async |peer_descriptor: PeerDescriptor| {
+            resource_manager.resources_mut(async |resources|
+                resources.store_peer_descriptor(StorePeerDescriptorParams {
+                    vpn: Vpn::Disabled,
+                    peer_descriptor,
+                }).await
+            ).await
+        };
+
+        store(Clone::clone(&peer.descriptor)).await??;
+        let stored_descriptor = resource_manager.get::<PeerDescriptor>(peer.id).await?.unwrap();
+
+        store(PeerDescriptor { name: PeerName::try_from("FirstEdit")?, ..Clone::clone(&stored_descriptor) }).await??;
+
+        let result = store(PeerDescriptor { name: PeerName::try_from("SecondEdit")?, ..Clone::clone(&stored_descriptor) }).await?;
+        assert_that!(result, err(matches_pattern!(StorePeerDescriptorError::Conflict {
+            expected_revision: eq(&Revision(1)),
+            actual_revision: eq(&Revision(2)),
+            ..
+        })));
+
+        let result = store(Clone::clone(&peer.descriptor)).await?;
+        assert!(matches!(result, Err(StorePeerDescriptorError::Conflict { .. })), "Creating a peer, which already exists, should be rejected.");
+
+        let stored_descriptor = resource_manager.get::<PeerDescriptor>(peer.id).await?.unwrap();
+        assert_eq!(stored_descriptor.name, PeerName::try_from("FirstEdit")?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_reject_moving_a_peer_to_another_namespace_while_clusters_use_it() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let cluster = ClusterFixture::create(Arc::clone(&resource_manager)).await?;
+        let other_namespace = NamespaceName::try_from("other")?;
+
+        let store = async |peer_descriptor: PeerDescriptor| {
+            resource_manager.resources_mut(async |resources|
+                resources.store_peer_descriptor(StorePeerDescriptorParams {
+                    vpn: Vpn::Disabled,
+                    peer_descriptor,
+                }).await
+            ).await
+        };
+
+        let result = store(PeerDescriptor {
+            namespace: Clone::clone(&other_namespace),
+            ..Clone::clone(&cluster.peer_b.descriptor)
+        }).await?;
+        assert_that!(result, err(matches_pattern!(StorePeerDescriptorError::ReferencedByClusters {
+            peer_id: eq(&cluster.peer_b.id),
+            namespace: eq(&NamespaceName::default()),
+            cluster_ids: elements_are![eq(&cluster.id)],
+            ..
+        })));
+        let stored_descriptor = resource_manager.get::<PeerDescriptor>(cluster.peer_b.id).await?.unwrap();
+        assert_eq!(stored_descriptor.namespace, NamespaceName::default());
+
+        let unused_peer = PeerFixture::new();
+        store(Clone::clone(&unused_peer.descriptor)).await??;
+        let stored_descriptor = resource_manager.get::<PeerDescriptor>(unused_peer.id).await?.unwrap();
+        store(PeerDescriptor {
+            namespace: Clone::clone(&other_namespace),
+            ..stored_descriptor
+        }).await??;
+        let stored_descriptor = resource_manager.get::<PeerDescriptor>(unused_peer.id).await?.unwrap();
+        assert_eq!(stored_descriptor.namespace, other_namespace);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
-use opendut_model::peer::executor::ExecutorDescriptors;
+use opendut_model::peer::executor::container::{ContainerCommand, ContainerImage, ContainerName, Engine};
+use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorDescriptors, ExecutorId, ExecutorKind};
 use opendut_model::topology::Topology;
 use opendut_model::util::net::NetworkInterfaceName;
 
@@ -17,6 +18,26 @@ pub fn create_peer_descriptor(peer_id: PeerId) -> PeerDescriptor {
         },
         executors: ExecutorDescriptors {
             executors: vec![],
-        }
+        },
+        revision: Default::default(),
+        namespace: Default::default(),
+    }
+}
+
+pub fn create_executor_descriptor(executor_id: ExecutorId) -> ExecutorDescriptor {
+    ExecutorDescriptor {
+        id: executor_id,
+        kind: ExecutorKind::Container {
+            engine: Engine::Docker,
+            name: ContainerName::Empty,
+            image: ContainerImage::try_from("testUrl").unwrap(),
+            volumes: vec![],
+            devices: vec![],
+            envs: vec![],
+            ports: vec![],
+            command: ContainerCommand::Default,
+            args: vec![],
+        },
+        results_url: None,
     }
 }
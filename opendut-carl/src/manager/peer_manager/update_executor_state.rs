@@ -0,0 +1,121 @@
+use crate::resource::api::resources::Resources;
+use crate::resource::persistence::error::{PersistenceError, PersistenceResult};
+use crate::resource::storage::ResourcesStorageApi;
+use opendut_model::peer::executor::state::{ExecutorState, ExecutorStatus};
+use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::{PeerDescriptor, PeerId};
+use tracing::debug;
+
+impl Resources<'_> {
+    /// Stores the status of an executor, as reported by the peer running it.
+    /// The executor has to be configured in the descriptor of that peer, so a peer cannot overwrite the status of another peer's executors.
+    #[tracing::instrument(skip_all, level="trace")]
+    pub fn update_executor_state(&mut self, peer_id: PeerId, executor_id: ExecutorId, status: ExecutorStatus) -> Result<(), UpdateExecutorStateError> {
+
+        let peer_descriptor = self.get::<PeerDescriptor>(peer_id)
+            .map_err(|source| UpdateExecutorStateError::Persistence { peer_id, executor_id, source })?
+            .ok_or(UpdateExecutorStateError::PeerNotFound { peer_id, executor_id })?;
+
+        let is_executor_of_peer = peer_descriptor.executors.executors.iter()
+            .any(|executor| executor.id == executor_id);
+        if !is_executor_of_peer {
+            return Err(UpdateExecutorStateError::ExecutorNotFound { peer_id, executor_id });
+        }
+
+        self.insert(executor_id, ExecutorState { id: executor_id, peer: peer_id, status })
+            .map_err(|source| UpdateExecutorStateError::Persistence { peer_id, executor_id, source })
+    }
+
+    /// Removes the states of the executors of the given peer, which are not among the given executors anymore.
+    pub(super) fn remove_stale_executor_states(&mut self, peer_id: PeerId, executors: &[ExecutorId]) -> PersistenceResult<()> {
+        let stale_executors = self.list::<ExecutorState>()?
+            .into_values()
+            .filter(|state| state.peer == peer_id && !executors.contains(&state.id))
+            .map(|state| state.id)
+            .collect::<Vec<_>>();
+
+        for executor_id in stale_executors {
+            debug!("Removing state of executor <{executor_id}>, which is not configured for peer <{peer_id}> anymore.");
+            self.remove::<ExecutorState>(executor_id)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum UpdateExecutorStateError {
+    #[error("State of executor <{executor_id}> could not be updated, because peer <{peer_id}> does not exist!")]
+    PeerNotFound {
+        peer_id: PeerId,
+        executor_id: ExecutorId,
+    },
+    #[error("State of executor <{executor_id}> could not be updated, because it is not an executor of peer <{peer_id}>!")]
+    ExecutorNotFound {
+        peer_id: PeerId,
+        executor_id: ExecutorId,
+    },
+    #[error("Error when accessing persistence while updating state of executor <{executor_id}> of peer <{peer_id}>")]
+    Persistence {
+        peer_id: PeerId,
+        executor_id: ExecutorId,
+        #[source] source: PersistenceError,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::peer_manager::tests::{create_executor_descriptor, create_peer_descriptor};
+    use crate::resource::manager::ResourceManager;
+
+    #[tokio::test]
+    async fn should_only_store_the_states_of_executors_of_the_reporting_peer() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let peer_id = PeerId::random();
+        let other_peer_id = PeerId::random();
+        let executor_id = ExecutorId::random();
+        let mut peer_descriptor = create_peer_descriptor(peer_id);
+        peer_descriptor.executors.executors.push(create_executor_descriptor(executor_id));
+        resource_manager.insert(peer_id, peer_descriptor).await?;
+        resource_manager.insert(other_peer_id, create_peer_descriptor(other_peer_id)).await?;
+
+        let result = resource_manager.resources_mut(async |resources|
+            resources.update_executor_state(other_peer_id, executor_id, ExecutorStatus::Running)
+        ).await?;
+        assert!(matches!(result, Err(UpdateExecutorStateError::ExecutorNotFound { .. })));
+        assert_eq!(resource_manager.get::<ExecutorState>(executor_id).await?, None);
+
+        resource_manager.resources_mut(async |resources|
+            resources.update_executor_state(peer_id, executor_id, ExecutorStatus::Running)
+        ).await??;
+        assert_eq!(
+            resource_manager.get::<ExecutorState>(executor_id).await?,
+            Some(ExecutorState { id: executor_id, peer: peer_id, status: ExecutorStatus::Running })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_remove_the_states_of_executors_which_are_not_configured_anymore() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let peer_id = PeerId::random();
+        let kept_state = ExecutorState { id: ExecutorId::random(), peer: peer_id, status: ExecutorStatus::Running };
+        let stale_state = ExecutorState { id: ExecutorId::random(), peer: peer_id, status: ExecutorStatus::Running };
+        let other_peer_state = ExecutorState { id: ExecutorId::random(), peer: PeerId::random(), status: ExecutorStatus::Running };
+        for state in [&kept_state, &stale_state, &other_peer_state] {
+            resource_manager.insert(state.id, Clone::clone(state)).await?;
+        }
+
+        resource_manager.resources_mut(async |resources|
+            resources.remove_stale_executor_states(peer_id, &[kept_state.id])
+        ).await??;
+
+        let remaining_states = resource_manager.list::<ExecutorState>().await?;
+        assert_eq!(remaining_states.len(), 2);
+        assert!(remaining_states.contains_key(&kept_state.id));
+        assert!(remaining_states.contains_key(&other_peer_state.id));
+        Ok(())
+    }
+}
@@ -38,7 +38,12 @@ async fn remove_absent_peer_configuration_parameters_that_are_absent(resource_ma
             let peer_subscription = peer_config_state_subscription.receive().await;
 
             if let Ok(SubscriptionEvent::Inserted { id, value }) = peer_subscription {
-                
+
+                if value.cached.is_some() {
+                    // The state refers to a configuration cached by the peer, which may be outdated. Wait for the peer to apply the current configuration.
+                    continue;
+                }
+
                 let parameter_ids_with_target_absent = value.parameter_states
                     .into_iter()
                     .filter(|p| matches!(p.detected_state, ParameterEdgeDetectedStateKind::Absent))
@@ -4,7 +4,15 @@ use std::ops::Not;
 use std::sync::Arc;
 use std::time::Duration;
 
-use opendut_carl_api::carl::broker::{stream_header, ApplyPeerConfiguration, DownstreamMessage, DownstreamMessagePayload, TracingContext, UpstreamMessage, UpstreamMessagePayload};
+use opendut_carl_api::carl::broker::{stream_header, ApplyPeerConfiguration, DownstreamMessage, DownstreamMessagePayload, ExecutorStatusUpdate, TracingContext, UpstreamMessage, UpstreamMessagePayload};
+#[cfg(feature = "viper")]
+use opendut_carl_api::carl::broker::{ViperRunReport, ViperRunUpdate};
+#[cfg(feature = "viper")]
+use opendut_model::viper::{ViperRunId, ViperRunResult, ViperRunState, ViperRunStatus};
+#[cfg(feature = "viper")]
+use crate::manager::test_manager::ExecuteViperRunDeploymentError;
+use crate::resource::persistence::error::MapErrToInner;
+use crate::manager::peer_manager::update_executor_state::UpdateExecutorStateError;
 use opendut_model::peer::configuration::PeerConfiguration;
 use opendut_model::peer::state::{PeerConnectionState};
 use opendut_model::peer::{PeerDescriptor, PeerId};
@@ -145,6 +153,8 @@ impl PeerMessagingBroker {
         self.update_peer_connection_state(peer_id, remote_host).await?;
         self.peers.write().await.insert(peer_id, peer_messaging_ref);
         self.send_initial_peer_configuration(peer_id).await?;
+        #[cfg(feature = "viper")]
+        self.send_pending_viper_runs(peer_id).await?;
 
         let timeout_duration = self.options.peer_disconnect_timeout;
 
@@ -214,6 +224,27 @@ impl PeerMessagingBroker {
         Ok(())
     }
 
+    #[cfg(feature = "viper")]
+    async fn send_pending_viper_runs(&self, peer_id: PeerId) -> Result<(), OpenError> {
+        let pending_runs = self.resource_manager.resources(async |resources| {
+            resources.list_pending_viper_runs(peer_id)
+        }).await
+            .map_err(|source| OpenError::Persistence { peer_id, source })?
+            .map_err(|source| OpenError::Persistence { peer_id, source })?;
+
+        for execute in pending_runs {
+            let run_id = execute.run.id;
+            debug!("Found pending test suite run <{run_id}> for newly connected peer <{peer_id}>. Re-sending this run.");
+
+            self.resource_manager.insert(run_id, ViperRunState { id: run_id, peer: peer_id, status: ViperRunStatus::Scheduled }).await
+                .map_err(|source| OpenError::Persistence { peer_id, source })?;
+
+            self.send_to_peer(peer_id, DownstreamMessagePayload::ExecuteViperRun(Box::new(execute))).await
+                .map_err(|cause| OpenError::SendViperRun { peer_id, run_id, cause: cause.to_string() })?;
+        }
+        Ok(())
+    }
+
     async fn expect_known_peer_descriptor(&self, peer_id: PeerId) -> Result<Option<PeerDescriptor>, OpenError> {
         self.resource_manager.resources_mut(async |resources| {
             resources.get::<PeerDescriptor>(peer_id)
@@ -295,6 +326,9 @@ async fn handle_stream_message(
     match payload {
         UpstreamMessagePayload::EdgePeerConfigurationState(state) => {
             info!("Received PeerConfigurationState from peer <{peer_id}>:\n  {state:#?}");
+            if let Some(cached) = &state.cached {
+                info!("Peer <{peer_id}> is running a cached peer configuration with checksum '{}'.", cached.checksum);
+            }
             let _ignore_result = resource_manager.insert(peer_id, state).await
                 .inspect_err(|cause| {
                     warn!("Failed to insert PeerConfigurationState for peer <{peer_id}>:\n  {cause}");
@@ -305,6 +339,53 @@ async fn handle_stream_message(
                 tx_outbound.send(DownstreamMessage { payload: DownstreamMessagePayload::Pong, context }).await
                     .inspect_err(|cause| warn!("Failed to send ping to peer <{peer_id}>:\n  {cause}"));
         }
+        UpstreamMessagePayload::ExecutorStatusUpdate(update) => {
+            let ExecutorStatusUpdate { id: executor_id, status } = update;
+            debug!("Received status update for executor <{executor_id}> from peer <{peer_id}>: {status:?}");
+            let _ignore_result = resource_manager.resources_mut(async |resources| {
+                resources.update_executor_state(peer_id, executor_id, status)
+            }).await
+                .map_err_to_inner(|source| UpdateExecutorStateError::Persistence { peer_id, executor_id, source })
+                .inspect_err(|cause| {
+                    warn!("Failed to store state of executor <{executor_id}> reported by peer <{peer_id}>:\n  {cause}");
+                });
+        }
+        #[cfg(feature = "viper")]
+        UpstreamMessagePayload::ViperRunUpdate(update) => {
+            let ViperRunUpdate { id: run_id, status } = update;
+            debug!("Received status update for test suite run <{run_id}> from peer <{peer_id}>: {status:?}");
+            let _ignore_result = resource_manager.resources_mut(async |resources| {
+                if resources.is_viper_run_deployed_to(run_id, peer_id)? {
+                    resources.insert(run_id, ViperRunState { id: run_id, peer: peer_id, status })
+                        .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?;
+                } else {
+                    warn!("Ignoring status update for test suite run <{run_id}> from peer <{peer_id}>, since the run is not deployed to this peer.");
+                }
+                Ok::<_, ExecuteViperRunDeploymentError>(())
+            }).await
+                .map_err_to_inner(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })
+                .inspect_err(|cause| {
+                    warn!("Failed to insert state of test suite run <{run_id}> reported by peer <{peer_id}>:\n  {cause}");
+                });
+        }
+        #[cfg(feature = "viper")]
+        UpstreamMessagePayload::ViperRunReport(report) => {
+            let ViperRunReport { id: run_id, report } = *report;
+            debug!("Received report of test suite run <{run_id}> from peer <{peer_id}>.");
+            let _ignore_result = resource_manager.resources_mut(async |resources| {
+                if resources.is_viper_run_deployed_to(run_id, peer_id)? {
+                    resources.insert(run_id, ViperRunResult { id: run_id, peer: peer_id, report })
+                        .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?;
+                } else {
+                    warn!("Ignoring report of test suite run <{run_id}> from peer <{peer_id}>, since the run is not deployed to this peer.");
+                }
+                Ok::<_, ExecuteViperRunDeploymentError>(())
+            }).await
+                .map_err_to_inner(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })
+                .inspect_err(|cause| {
+                    warn!("Failed to insert result of test suite run <{run_id}> reported by peer <{peer_id}>:\n  {cause}");
+                });
+        }
     }
 }
 
@@ -330,6 +411,10 @@ pub enum OpenError {
     #[error("Error while sending peer configuration to peer:\n  {cause}")]
     SendApplyPeerConfiguration { peer_id: PeerId, cause: String },
 
+    #[cfg(feature = "viper")]
+    #[error("Error while sending pending test suite run <{run_id}> to peer:\n  {cause}")]
+    SendViperRun { peer_id: PeerId, run_id: ViperRunId, cause: String },
+
     #[error("Error while accessing persistence after Peer <{peer_id}> opened stream.")]
     Persistence { peer_id: PeerId, #[source] source: PersistenceError },
 }
@@ -385,7 +470,7 @@ mod tests {
     use tokio::sync::mpsc;
     use tokio::sync::mpsc::Receiver;
 
-    use crate::manager::peer_manager::tests::create_peer_descriptor;
+    use crate::manager::peer_manager::tests::{create_executor_descriptor, create_peer_descriptor};
     use super::*;
     use crate::resource::manager::ResourceManager;
     use crate::resource::storage::ResourcesStorageApi;
@@ -538,6 +623,112 @@ mod tests {
         Ok(())
     }
 
+    #[test_log::test(tokio::test)]
+    async fn should_store_executor_state_reported_by_peer() -> anyhow::Result<()> {
+        use opendut_model::peer::executor::ExecutorId;
+        use opendut_model::peer::executor::state::{ExecutorState, ExecutorStatus};
+
+        let Fixture { resource_manager, peer_id } = fixture().await?;
+        let executor_id = ExecutorId::random();
+        let mut peer_descriptor = create_peer_descriptor(peer_id);
+        peer_descriptor.executors.executors.push(create_executor_descriptor(executor_id));
+        resource_manager.insert(peer_id, peer_descriptor).await?;
+
+        let options = PeerMessagingBrokerOptions {
+            peer_disconnect_timeout: Duration::from_secs(30),
+        };
+        let testee = PeerMessagingBroker::new(Arc::clone(&resource_manager), options).await;
+        let remote_host = IpAddr::from_str("1.2.3.4")?;
+
+        let (sender, mut receiver) = testee.open(peer_id, remote_host, stream_header::ExtraHeaders::default()).await?;
+        let _initial_peer_configuration = receiver.recv().await;
+
+        let status = ExecutorStatus::Exited { code: Some(1) };
+
+        sender.send(UpstreamMessage {
+            context: None,
+            payload: UpstreamMessagePayload::ExecutorStatusUpdate(ExecutorStatusUpdate { id: executor_id, status: status.clone() }),
+        }).await?;
+        do_ping(&sender, &mut receiver).await; //wait for the update to be processed
+
+        let state = resource_manager.get::<ExecutorState>(executor_id).await?;
+        assert_that!(state, some(eq(&ExecutorState { id: executor_id, peer: peer_id, status })));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "viper")]
+    #[test_log::test(tokio::test)]
+    async fn should_store_viper_run_state_reported_by_peer() -> anyhow::Result<()> {
+        use opendut_model::viper::{ViperRunId, ViperRunProgress, ViperRunStatus};
+
+        let Fixture { resource_manager, peer_id } = fixture().await?;
+        let options = PeerMessagingBrokerOptions {
+            peer_disconnect_timeout: Duration::from_secs(30),
+        };
+        let testee = PeerMessagingBroker::new(Arc::clone(&resource_manager), options).await;
+        let remote_host = IpAddr::from_str("1.2.3.4")?;
+
+        let (sender, mut receiver) = testee.open(peer_id, remote_host, stream_header::ExtraHeaders::default()).await?;
+        let _initial_peer_configuration = receiver.recv().await;
+
+        let run_id = ViperRunId::random();
+        let status = ViperRunStatus::Running(ViperRunProgress { total_tests: 3, passed_tests: 1, failed_tests: 0 });
+
+        sender.send(UpstreamMessage {
+            context: None,
+            payload: UpstreamMessagePayload::ViperRunUpdate(ViperRunUpdate { id: run_id, status: status.clone() }),
+        }).await?;
+        do_ping(&sender, &mut receiver).await; //wait for the update to be processed
+
+        let state = resource_manager.get::<ViperRunState>(run_id).await?;
+        assert_that!(state, some(eq(&ViperRunState { id: run_id, peer: peer_id, status })));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "viper")]
+    #[test_log::test(tokio::test)]
+    async fn should_store_viper_run_result_reported_by_peer() -> anyhow::Result<()> {
+        use opendut_model::viper::ViperRunId;
+        use opendut_viper_rt::run::{Outcome, ReportProperty, ReportPropertyValue, TestCaseReport, TestReport, TestSuiteReport};
+
+        let Fixture { resource_manager, peer_id } = fixture().await?;
+        let options = PeerMessagingBrokerOptions {
+            peer_disconnect_timeout: Duration::from_secs(30),
+        };
+        let testee = PeerMessagingBroker::new(Arc::clone(&resource_manager), options).await;
+        let remote_host = IpAddr::from_str("1.2.3.4")?;
+
+        let (sender, mut receiver) = testee.open(peer_id, remote_host, stream_header::ExtraHeaders::default()).await?;
+        let _initial_peer_configuration = receiver.recv().await;
+
+        let run_id = ViperRunId::random();
+        let report = TestSuiteReport {
+            name: "suite".try_into()?,
+            cases: vec![TestCaseReport {
+                name: "suite::case".try_into()?,
+                tests: vec![TestReport {
+                    identifier: "suite::case::test".try_into()?,
+                    outcome: Outcome::Failure,
+                    properties: vec![ReportProperty { name: String::from("speed"), value: ReportPropertyValue::Number(42) }],
+                    output: vec![String::from("Hello, World!")],
+                }],
+            }],
+        };
+
+        sender.send(UpstreamMessage {
+            context: None,
+            payload: UpstreamMessagePayload::ViperRunReport(Box::new(ViperRunReport { id: run_id, report: report.clone() })),
+        }).await?;
+        do_ping(&sender, &mut receiver).await; //wait for the report to be processed
+
+        let result = resource_manager.get::<ViperRunResult>(run_id).await?;
+        assert_that!(result, some(eq(&ViperRunResult { id: run_id, peer: peer_id, report })));
+
+        Ok(())
+    }
+
     async fn do_ping(sender: &mpsc::Sender<UpstreamMessage>, receiver: &mut Receiver<DownstreamMessage>) {
         sender.send(UpstreamMessage { context: None, payload: UpstreamMessagePayload::Ping }).await
             .unwrap();
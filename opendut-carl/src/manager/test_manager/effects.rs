@@ -0,0 +1,42 @@
+use opendut_model::viper::{ViperRunDeployment, ViperRunState};
+use tracing::{error, trace};
+
+use crate::manager::test_manager::TestManagerRef;
+use crate::resource::manager::{ResourceManagerRef, SubscriptionEvent};
+
+pub(crate) async fn register(resource_manager: ResourceManagerRef, test_manager: TestManagerRef) {
+    execute_viper_run_when_deployment_stored(resource_manager.clone(), test_manager).await;
+    remove_viper_run_state_when_deployment_removed(resource_manager).await;
+}
+
+async fn execute_viper_run_when_deployment_stored(resource_manager: ResourceManagerRef, test_manager: TestManagerRef) {
+    let mut deployment_subscription = resource_manager.subscribe::<ViperRunDeployment>().await;
+
+    tokio::spawn(async move {
+        loop {
+            let deployment = deployment_subscription.receive().await;
+
+            if let Ok(SubscriptionEvent::Inserted { id: run_id, value: deployment }) = deployment {
+                trace!("Test suite run deployment <{run_id}> was stored. Sending run to peer for execution.");
+                let _ = test_manager.execute_viper_run_deployment(deployment).await
+                    .inspect_err(|cause| error!("Failed to execute test suite run deployment <{run_id}>: {cause}"));
+            }
+        }
+    });
+}
+
+async fn remove_viper_run_state_when_deployment_removed(resource_manager: ResourceManagerRef) {
+    let mut deployment_subscription = resource_manager.subscribe::<ViperRunDeployment>().await;
+
+    tokio::spawn(async move {
+        loop {
+            let deployment = deployment_subscription.receive().await;
+
+            if let Ok(SubscriptionEvent::Removed { id: run_id, .. }) = deployment {
+                trace!("Test suite run deployment <{run_id}> was removed. Removing its run state.");
+                let _ = resource_manager.remove::<ViperRunState>(run_id).await
+                    .inspect_err(|cause| error!("Failed to remove state of test suite run <{run_id}>: {cause}"));
+            }
+        }
+    });
+}
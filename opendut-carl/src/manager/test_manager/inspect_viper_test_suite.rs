@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tracing::debug;
+
+use opendut_model::viper::{ViperSourceDescriptor, ViperSourceId};
+use opendut_viper_rt::common::TestSuiteIdentifier;
+use opendut_viper_rt::compile::{IdentifierFilter, ParameterDescriptor};
+use opendut_viper_rt::events::emitter;
+use opendut_viper_rt::source::loaders::{EmbeddedSourceLoader, GitSourceLoader, HttpSourceLoader};
+use opendut_viper_rt::source::Source;
+use opendut_viper_rt::ViperRuntime;
+
+use crate::resource::manager::ResourceManagerRef;
+use crate::resource::persistence::error::PersistenceError;
+
+/// Maximum time the top-level code of a test suite may run in CARL while it is compiled for inspection.
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum time an inspection may take, including loading the source.
+/// Also bounds the inspection, if the top-level code of the suite blocks outside the interpreter, e.g. in `time.sleep()`,
+/// which the compile timeout cannot interrupt. The thread of such an inspection is only released once the blocking call returns.
+const INSPECTION_TIMEOUT: Duration = Duration::from_secs(30);
+/// Maximum number of inspection results kept in the [`ViperTestSuiteInspectionCache`].
+const INSPECTION_CACHE_CAPACITY: usize = 64;
+
+pub struct InspectViperTestSuiteParams {
+    pub source_id: ViperSourceId,
+    pub suite: TestSuiteIdentifier,
+}
+
+/// Caches the parameters of inspected test suites by the code of the suite,
+/// so a test suite is only compiled again, when its code changed.
+#[derive(Default)]
+pub struct ViperTestSuiteInspectionCache {
+    parameters: Mutex<HashMap<(String, String), Vec<ParameterDescriptor>>>,
+}
+
+impl ViperTestSuiteInspectionCache {
+    fn get(&self, suite: &TestSuiteIdentifier, code: &str) -> Option<Vec<ParameterDescriptor>> {
+        let parameters = self.parameters.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        parameters.get(&(suite.to_string(), code.to_owned())).cloned()
+    }
+
+    fn insert(&self, suite: &TestSuiteIdentifier, code: String, descriptors: Vec<ParameterDescriptor>) {
+        let mut parameters = self.parameters.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if parameters.len() >= INSPECTION_CACHE_CAPACITY {
+            parameters.clear();
+        }
+        parameters.insert((suite.to_string(), code), descriptors);
+    }
+}
+
+/// Compiles the given test suite of a source and returns the parameters declared by the suite,
+/// e.g. to let a user fill in the parameters of a new run.
+///
+/// Compiling runs the top-level code of the test suite within CARL, so its execution time is limited.
+/// The result is cached for the loaded code of the suite.
+#[tracing::instrument(skip_all, level="trace")]
+pub async fn inspect_viper_test_suite(
+    resource_manager: &ResourceManagerRef,
+    cache: &Arc<ViperTestSuiteInspectionCache>,
+    params: InspectViperTestSuiteParams,
+) -> Result<Vec<ParameterDescriptor>, InspectViperTestSuiteError> {
+    let InspectViperTestSuiteParams { source_id, suite } = params;
+
+    let source = resource_manager.get::<ViperSourceDescriptor>(source_id).await
+        .map_err(|source| InspectViperTestSuiteError::Persistence { source_id, source })?
+        .ok_or(InspectViperTestSuiteError::SourceNotFound { source_id })?;
+
+    let handle = tokio::runtime::Handle::current();
+    let compile_suite = Clone::clone(&suite);
+    let cache = Arc::clone(cache);
+
+    // The Python interpreter used by the VIPER runtime is not `Send`,
+    // so the compilation is driven to completion on a dedicated blocking thread.
+    let inspection = tokio::task::spawn_blocking(move || {
+        handle.block_on(async move {
+            let runtime = ViperRuntime::builder()
+                .with_source_loader(HttpSourceLoader)
+                .with_source_loader(GitSourceLoader::default())
+                .with_source_loader(EmbeddedSourceLoader)
+                .with_compile_timeout(COMPILE_TIMEOUT)
+                .build()
+                .map_err(|cause| cause.message)?;
+
+            let suite_source = Source::try_from_url(Clone::clone(&compile_suite), Clone::clone(&source.url))
+                .map_err(|cause| cause.to_string())?;
+
+            let code = runtime.load(&suite_source).await
+                .map_err(|cause| cause.to_string())?;
+
+            if let Some(descriptors) = cache.get(&compile_suite, &code) {
+                debug!("Using cached parameters of test suite '{compile_suite}' from '{}'.", source.url);
+                return Ok(descriptors);
+            }
+
+            debug!("Compiling test suite '{compile_suite}' from '{}' for inspection.", source.url);
+            let loaded_source = Source::embedded_with_identifier(Clone::clone(&compile_suite), Clone::clone(&code));
+
+            let (_, descriptors, _) = runtime.compile(&loaded_source, &mut emitter::drain(), &IdentifierFilter::default()).await
+                .map_err(|cause| cause.to_string())?
+                .split();
+            let descriptors = descriptors.into_iter().collect::<Vec<_>>();
+
+            cache.insert(&compile_suite, code, Clone::clone(&descriptors));
+
+            Ok::<_, String>(descriptors)
+        })
+    });
+
+    tokio::time::timeout(INSPECTION_TIMEOUT, inspection).await
+        .map_err(|_| format!("Inspection did not finish within {} seconds.", INSPECTION_TIMEOUT.as_secs()))
+        .and_then(|result| result.map_err(|cause| cause.to_string()))
+        .flatten()
+        .map_err(|cause| InspectViperTestSuiteError::Compilation { source_id, suite, cause })
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum InspectViperTestSuiteError {
+    #[error("Test suite source <{source_id}> could not be inspected, because it does not exist.")]
+    SourceNotFound { source_id: ViperSourceId },
+    #[error("Test suite '{suite}' of source <{source_id}> could not be compiled:\n  {cause}")]
+    Compilation { source_id: ViperSourceId, suite: TestSuiteIdentifier, cause: String },
+    #[error("Error when accessing persistence while inspecting a test suite of source <{source_id}>.")]
+    Persistence { source_id: ViperSourceId, #[source] source: PersistenceError },
+}
+
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use opendut_model::viper::ViperSourceName;
+
+    use super::*;
+    use crate::resource::manager::ResourceManager;
+
+    #[test_log::test(tokio::test)]
+    async fn should_fail_for_unknown_source() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+
+        let result = inspect_viper_test_suite(&resource_manager, &Default::default(), InspectViperTestSuiteParams {
+            source_id: ViperSourceId::random(),
+            suite: "suite".try_into()?,
+        }).await;
+
+        assert_that!(result, err(matches_pattern!(InspectViperTestSuiteError::SourceNotFound { .. })));
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_fail_for_source_which_cannot_be_loaded() -> anyhow::Result<()> {
+        let resource_manager = ResourceManager::new_in_memory();
+        let source = ViperSourceDescriptor {
+            id: ViperSourceId::random(),
+            name: ViperSourceName::try_from("local-source")?,
+            url: url::Url::parse("file:///tests/suite.py")?,
+            namespace: Default::default(),
+        };
+        resource_manager.insert(source.id, source.clone()).await?;
+
+        let result = inspect_viper_test_suite(&resource_manager, &Default::default(), InspectViperTestSuiteParams {
+            source_id: source.id,
+            suite: "suite".try_into()?,
+        }).await;
+
+        assert_that!(result, err(matches_pattern!(InspectViperTestSuiteError::Compilation { .. })));
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+use std::ops::Not;
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+
+use opendut_carl_api::carl::broker::{DownstreamMessagePayload, ExecuteViperRun};
+use opendut_model::cluster::{ClusterDescriptor, ClusterId};
+use opendut_model::peer::PeerId;
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunResult, ViperRunState, ViperRunStatus, ViperSourceDescriptor, ViperSourceId};
+
+use crate::manager::cluster_manager::ListClusterPeersError;
+use crate::manager::peer_messaging_broker::PeerMessagingBrokerRef;
+use crate::resource::api::resources::Resources;
+use crate::resource::manager::ResourceManagerRef;
+use crate::resource::persistence::error::{PersistenceError, PersistenceResult};
+use crate::resource::storage::ResourcesStorageApi;
+
+mod effects;
+pub mod inspect_viper_test_suite;
+pub use inspect_viper_test_suite::*;
+
+pub type TestManagerRef = Arc<TestManager>;
+
+pub struct TestManager {
+    resource_manager: ResourceManagerRef,
+    peer_messaging_broker: PeerMessagingBrokerRef,
+}
+
+impl TestManager {
+    pub async fn create(resource_manager: ResourceManagerRef, peer_messaging_broker: PeerMessagingBrokerRef) -> TestManagerRef {
+        let self_ref = Arc::new(Self {
+            resource_manager: Arc::clone(&resource_manager),
+            peer_messaging_broker,
+        });
+
+        effects::register(resource_manager, Arc::clone(&self_ref)).await;
+
+        self_ref
+    }
+
+    /// Sends the run of the given deployment to a peer of the deployed cluster for execution.
+    /// The run is executed by the peer specified in the deployment or by the cluster leader otherwise.
+    #[tracing::instrument(skip_all, level="trace")]
+    pub async fn execute_viper_run_deployment(&self, deployment: ViperRunDeployment) -> Result<PeerId, ExecuteViperRunDeploymentError> {
+        let run_id = deployment.id;
+        let cluster_id = deployment.cluster;
+
+        let (peer_id, execute) = self.resource_manager.resources(async |resources| {
+            resources.resolve_viper_run_execution(&deployment)
+        }).await
+        .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })??;
+
+        debug!("Sending test suite run <{run_id}> to peer <{peer_id}> of cluster <{cluster_id}> for execution.");
+
+        // Stored before sending, so it cannot overwrite the first status update of the peer.
+        // If the peer cannot be reached, the run remains scheduled and is sent once the peer connects.
+        self.resource_manager.insert(run_id, ViperRunState {
+            id: run_id,
+            peer: peer_id,
+            status: ViperRunStatus::Scheduled,
+        }).await
+        .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?;
+
+        self.peer_messaging_broker.send_to_peer(
+            peer_id,
+            DownstreamMessagePayload::ExecuteViperRun(Box::new(execute))
+        ).await
+        .map_err(|cause| ExecuteViperRunDeploymentError::SendToPeer { run_id, peer_id, cause: cause.to_string() })?;
+
+        info!("Scheduled test suite run <{run_id}> on peer <{peer_id}>.");
+
+        Ok(peer_id)
+    }
+}
+
+impl Resources<'_> {
+    /// Determines the peer, which executes the run of the given deployment.
+    /// This is the peer specified in the deployment or the cluster leader otherwise.
+    pub fn resolve_viper_run_peer(&self, deployment: &ViperRunDeployment) -> Result<PeerId, ExecuteViperRunDeploymentError> {
+        let ViperRunDeployment { id: run_id, cluster: cluster_id, peer } = *deployment;
+
+        match peer {
+            Some(peer_id) => {
+                let cluster_peers = self.list_cluster_peers(cluster_id)
+                    .map_err(|source| ExecuteViperRunDeploymentError::ListClusterPeers { run_id, source })?;
+
+                if cluster_peers.iter().any(|cluster_peer| cluster_peer.id == peer_id) {
+                    Ok(peer_id)
+                } else {
+                    Err(ExecuteViperRunDeploymentError::PeerNotInCluster { run_id, peer_id, cluster_id })
+                }
+            }
+            None => {
+                let cluster = self.get::<ClusterDescriptor>(cluster_id)
+                    .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?
+                    .ok_or(ExecuteViperRunDeploymentError::ClusterNotFound { run_id, cluster_id })?;
+                Ok(cluster.leader)
+            }
+        }
+    }
+
+    /// Determines the peer, which executes the run of the given deployment, and the message instructing it to do so.
+    pub fn resolve_viper_run_execution(&self, deployment: &ViperRunDeployment) -> Result<(PeerId, ExecuteViperRun), ExecuteViperRunDeploymentError> {
+        let run_id = deployment.id;
+
+        let run = self.get::<ViperRunDescriptor>(run_id)
+            .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?
+            .ok_or(ExecuteViperRunDeploymentError::RunDescriptorNotFound { run_id })?;
+
+        let source = self.get::<ViperSourceDescriptor>(run.source)
+            .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?
+            .ok_or(ExecuteViperRunDeploymentError::SourceDescriptorNotFound { run_id, source_id: run.source })?;
+
+        let peer_id = self.resolve_viper_run_peer(deployment)?;
+
+        Ok((peer_id, ExecuteViperRun { run, source }))
+    }
+
+    /// Checks whether a deployment of the given run exists and is executed by the given peer.
+    pub fn is_viper_run_deployed_to(&self, run_id: ViperRunId, peer_id: PeerId) -> Result<bool, ExecuteViperRunDeploymentError> {
+        let deployment = self.get::<ViperRunDeployment>(run_id)
+            .map_err(|source| ExecuteViperRunDeploymentError::Persistence { run_id, source })?;
+
+        match deployment {
+            Some(deployment) => Ok(self.resolve_viper_run_peer(&deployment)? == peer_id),
+            None => Ok(false),
+        }
+    }
+
+    /// Lists the runs, which are to be executed by the given peer, but which the peer did not yet start.
+    /// These are re-sent when the peer connects, since the peer may have been offline when the run was deployed.
+    ///
+    /// Runs the peer already reported progress for are not included, since they may still be executing.
+    /// Neither are runs with a stored result, as their state is not persisted across CARL restarts, unlike their result.
+    pub fn list_pending_viper_runs(&self, peer_id: PeerId) -> PersistenceResult<Vec<ExecuteViperRun>> {
+        let deployments = self.list::<ViperRunDeployment>()?;
+        let states = self.list::<ViperRunState>()?;
+        let results = self.list::<ViperRunResult>()?;
+
+        let pending_runs = deployments.into_values()
+            .filter(|deployment| results.contains_key(&deployment.id).not())
+            .filter(|deployment| match states.get(&deployment.id) {
+                None => true,
+                Some(state) => state.status == ViperRunStatus::Scheduled,
+            })
+            .filter_map(|deployment| {
+                let run_id = deployment.id;
+                self.resolve_viper_run_execution(&deployment)
+                    .inspect_err(|cause| warn!("Could not determine whether test suite run <{run_id}> is pending for peer <{peer_id}>:\n  {cause}"))
+                    .ok()
+            })
+            .filter(|(target_peer_id, _)| *target_peer_id == peer_id)
+            .map(|(_, execute)| execute)
+            .collect();
+
+        Ok(pending_runs)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExecuteViperRunDeploymentError {
+    #[error("Test suite run <{run_id}> could not be executed, because no run descriptor with that ID exists.")]
+    RunDescriptorNotFound { run_id: ViperRunId },
+    #[error("Test suite run <{run_id}> could not be executed, because its source <{source_id}> does not exist.")]
+    SourceDescriptorNotFound { run_id: ViperRunId, source_id: ViperSourceId },
+    #[error("Test suite run <{run_id}> could not be executed, because cluster <{cluster_id}> does not exist.")]
+    ClusterNotFound { run_id: ViperRunId, cluster_id: ClusterId },
+    #[error("Test suite run <{run_id}> could not be executed, because peer <{peer_id}> is not part of cluster <{cluster_id}>.")]
+    PeerNotInCluster { run_id: ViperRunId, peer_id: PeerId, cluster_id: ClusterId },
+    #[error("Test suite run <{run_id}> could not be executed, because the peers of its cluster could not be determined.")]
+    ListClusterPeers { run_id: ViperRunId, #[source] source: ListClusterPeersError },
+    #[error("Test suite run <{run_id}> could not be sent to peer <{peer_id}>:\n  {cause}")]
+    SendToPeer { run_id: ViperRunId, peer_id: PeerId, cause: String },
+    #[error("Error when accessing persistence while executing test suite run <{run_id}>.")]
+    Persistence { run_id: ViperRunId, #[source] source: PersistenceError },
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::net::IpAddr;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use googletest::prelude::*;
+    use opendut_carl_api::carl::broker::{stream_header, DownstreamMessage, UpstreamMessage, UpstreamMessagePayload, ViperRunUpdate};
+    use opendut_model::viper::ViperSourceName;
+    use opendut_viper_rt::run::TestSuiteReport;
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::manager::peer_messaging_broker::{PeerMessagingBroker, PeerMessagingBrokerOptions};
+    use crate::manager::testing::ClusterFixture;
+    use crate::resource::manager::ResourceManager;
+
+    #[test_log::test(tokio::test)]
+    async fn should_execute_run_on_cluster_leader_by_default() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+        let (_leader_sender, mut leader_receiver) = fixture.connect_peer(fixture.cluster.peer_a.id).await?;
+
+        let peer_id = fixture.testee.execute_viper_run_deployment(ViperRunDeployment {
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: None,
+        }).await?;
+
+        assert_that!(peer_id, eq(fixture.cluster.peer_a.id));
+        fixture.assert_execution_received(&mut leader_receiver).await;
+        fixture.assert_scheduled_on(fixture.cluster.peer_a.id).await?;
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_execute_run_on_specified_peer() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+        let (_peer_sender, mut peer_receiver) = fixture.connect_peer(fixture.cluster.peer_b.id).await?;
+
+        let peer_id = fixture.testee.execute_viper_run_deployment(ViperRunDeployment {
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: Some(fixture.cluster.peer_b.id),
+        }).await?;
+
+        assert_that!(peer_id, eq(fixture.cluster.peer_b.id));
+        fixture.assert_execution_received(&mut peer_receiver).await;
+        fixture.assert_scheduled_on(fixture.cluster.peer_b.id).await?;
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_reject_peer_outside_of_cluster() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+        let foreign_peer = PeerId::random();
+
+        let result = fixture.testee.execute_viper_run_deployment(ViperRunDeployment {
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: Some(foreign_peer),
+        }).await;
+
+        assert_that!(result, err(matches_pattern!(ExecuteViperRunDeploymentError::PeerNotInCluster { .. })));
+        assert_that!(fixture.resource_manager.get::<ViperRunState>(fixture.run.id).await?, none());
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_fail_for_unknown_run_descriptor() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+
+        let result = fixture.testee.execute_viper_run_deployment(ViperRunDeployment {
+            id: ViperRunId::random(),
+            cluster: fixture.cluster.id,
+            peer: None,
+        }).await;
+
+        assert_that!(result, err(matches_pattern!(ExecuteViperRunDeploymentError::RunDescriptorNotFound { .. })));
+
+        Ok(())
+    }
+
+
+    #[test_log::test(tokio::test)]
+    async fn should_resend_pending_run_when_peer_connects() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+
+        fixture.resource_manager.insert(fixture.run.id, ViperRunDeployment { //peer is offline, so the run cannot be sent yet
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: Some(fixture.cluster.peer_b.id),
+        }).await?;
+
+        let (_peer_sender, mut peer_receiver) = fixture.connect_peer(fixture.cluster.peer_b.id).await?;
+
+        fixture.assert_execution_received(&mut peer_receiver).await;
+        fixture.assert_scheduled_on(fixture.cluster.peer_b.id).await?;
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_not_resend_runs_with_a_stored_result() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+        let leader = fixture.cluster.peer_a.id;
+
+        fixture.resource_manager.insert(fixture.run.id, ViperRunDeployment {
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: None,
+        }).await?;
+
+        let pending_runs = fixture.resource_manager.resources(async |resources| resources.list_pending_viper_runs(leader)).await??;
+        assert_that!(pending_runs.len(), eq(1));
+
+        fixture.resource_manager.insert(fixture.run.id, ViperRunResult {
+            id: fixture.run.id,
+            peer: leader,
+            report: TestSuiteReport { name: Clone::clone(&fixture.run.suite), cases: vec![] },
+        }).await?;
+
+        let pending_runs = fixture.resource_manager.resources(async |resources| resources.list_pending_viper_runs(leader)).await??;
+        assert_that!(pending_runs, empty());
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_only_accept_run_updates_from_the_executing_peer() -> anyhow::Result<()> {
+        let fixture = Fixture::create().await?;
+        let (leader_sender, mut leader_receiver) = fixture.connect_peer(fixture.cluster.peer_a.id).await?;
+        let (other_sender, mut other_receiver) = fixture.connect_peer(fixture.cluster.peer_b.id).await?;
+
+        fixture.resource_manager.insert(fixture.run.id, ViperRunDeployment {
+            id: fixture.run.id,
+            cluster: fixture.cluster.id,
+            peer: None,
+        }).await?;
+        fixture.assert_execution_received(&mut leader_receiver).await;
+        fixture.wait_until_scheduled().await?;
+
+        Fixture::send_run_update(&other_sender, &mut other_receiver, fixture.run.id, ViperRunStatus::Failed).await?;
+        fixture.assert_scheduled_on(fixture.cluster.peer_a.id).await?;
+
+        Fixture::send_run_update(&leader_sender, &mut leader_receiver, fixture.run.id, ViperRunStatus::Compiling).await?;
+        let state = fixture.resource_manager.get::<ViperRunState>(fixture.run.id).await?;
+        assert_that!(state, some(eq(&ViperRunState {
+            id: fixture.run.id,
+            peer: fixture.cluster.peer_a.id,
+            status: ViperRunStatus::Compiling,
+        })));
+
+        Ok(())
+    }
+
+
+    struct Fixture {
+        resource_manager: ResourceManagerRef,
+        peer_messaging_broker: PeerMessagingBrokerRef,
+        testee: TestManagerRef,
+        cluster: ClusterFixture,
+        run: ViperRunDescriptor,
+        source: ViperSourceDescriptor,
+    }
+    impl Fixture {
+        async fn create() -> anyhow::Result<Self> {
+            let resource_manager = ResourceManager::new_in_memory();
+            let peer_messaging_broker = PeerMessagingBroker::new(
+                Arc::clone(&resource_manager),
+                PeerMessagingBrokerOptions {
+                    peer_disconnect_timeout: Duration::from_secs(30),
+                },
+            ).await;
+            let testee = TestManager::create(Arc::clone(&resource_manager), Arc::clone(&peer_messaging_broker)).await;
+
+            let cluster = ClusterFixture::create(Arc::clone(&resource_manager)).await?;
+
+            let source = ViperSourceDescriptor {
+                id: ViperSourceId::random(),
+                name: ViperSourceName::try_from("test-source")?,
+                url: url::Url::parse("file:///tests/suite.py")?,
+                namespace: Default::default(),
+            };
+            resource_manager.insert(source.id, source.clone()).await?;
+
+            let run = ViperRunDescriptor {
+                id: ViperRunId::random(),
+                source: source.id,
+                suite: "suite".try_into()?,
+                parameters: HashMap::new(),
+                timeout: None,
+                namespace: Default::default(),
+            };
+            resource_manager.insert(run.id, run.clone()).await?;
+
+            Ok(Self { resource_manager, peer_messaging_broker, testee, cluster, run, source })
+        }
+
+        async fn connect_peer(&self, peer_id: PeerId) -> anyhow::Result<(mpsc::Sender<UpstreamMessage>, mpsc::Receiver<DownstreamMessage>)> {
+            let remote_host = IpAddr::from_str("1.2.3.4")?;
+            let (sender, mut receiver) = self.peer_messaging_broker.open(peer_id, remote_host, stream_header::ExtraHeaders::default()).await?;
+
+            let _initial_peer_configuration = receiver.recv().await;
+
+            Ok((sender, receiver))
+        }
+
+        async fn assert_execution_received(&self, receiver: &mut mpsc::Receiver<DownstreamMessage>) {
+            let received = receiver.recv().await.unwrap().payload;
+
+            assert_that!(received, eq(&DownstreamMessagePayload::ExecuteViperRun(Box::new(ExecuteViperRun {
+                run: self.run.clone(),
+                source: self.source.clone(),
+            }))));
+        }
+
+        /// Sends a status update for the given run and waits until CARL processed it, by sending a ping afterward.
+        async fn send_run_update(sender: &mpsc::Sender<UpstreamMessage>, receiver: &mut mpsc::Receiver<DownstreamMessage>, run_id: ViperRunId, status: ViperRunStatus) -> anyhow::Result<()> {
+            sender.send(UpstreamMessage {
+                payload: UpstreamMessagePayload::ViperRunUpdate(ViperRunUpdate { id: run_id, status }),
+                context: None,
+            }).await?;
+            sender.send(UpstreamMessage { payload: UpstreamMessagePayload::Ping, context: None }).await?;
+
+            loop {
+                let received = receiver.recv().await.unwrap().payload;
+                if received == DownstreamMessagePayload::Pong {
+                    return Ok(());
+                }
+            }
+        }
+
+        async fn wait_until_scheduled(&self) -> anyhow::Result<()> {
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while self.resource_manager.get::<ViperRunState>(self.run.id).await?.is_none() {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+                anyhow::Ok(())
+            }).await?
+        }
+
+        async fn assert_scheduled_on(&self, peer_id: PeerId) -> anyhow::Result<()> {
+            let state = self.resource_manager.get::<ViperRunState>(self.run.id).await?;
+
+            assert_that!(state, some(eq(&ViperRunState {
+                id: self.run.id,
+                peer: peer_id,
+                status: ViperRunStatus::Scheduled,
+            })));
+            Ok(())
+        }
+    }
+}
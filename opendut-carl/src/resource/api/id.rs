@@ -1,12 +1,14 @@
 use crate::resource::api::Resource;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterId};
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
+use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::executor::state::ExecutorState;
 use opendut_model::peer::state::PeerConnectionState;
 use opendut_model::peer::{PeerDescriptor, PeerId};
 use opendut_model::resources::Id;
 
 #[cfg(feature = "viper")]
-use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperSourceDescriptor, ViperSourceId};
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunResult, ViperRunState, ViperSourceDescriptor, ViperSourceId};
 
 pub trait ResourceId<R: Resource> {
     fn into_id(self) -> Id;
@@ -62,6 +64,15 @@ impl ResourceId<EdgePeerConfigurationState> for PeerId {
     }
 }
 
+impl ResourceId<ExecutorState> for ExecutorId {
+    fn into_id(self) -> Id {
+        Id::from(self.uuid)
+    }
+    fn from_id(id: Id) -> Self {
+        ExecutorId::from(id.value())
+    }
+}
+
 #[cfg(feature = "viper")]
 impl ResourceId<ViperSourceDescriptor> for ViperSourceId {
     fn into_id(self) -> Id {
@@ -91,3 +102,23 @@ impl ResourceId<ViperRunDeployment> for ViperRunId {
         ViperRunId::from(id.value())
     }
 }
+
+#[cfg(feature = "viper")]
+impl ResourceId<ViperRunState> for ViperRunId {
+    fn into_id(self) -> Id {
+        Id::from(self.uuid)
+    }
+    fn from_id(id: Id) -> Self {
+        ViperRunId::from(id.value())
+    }
+}
+
+#[cfg(feature = "viper")]
+impl ResourceId<ViperRunResult> for ViperRunId {
+    fn into_id(self) -> Id {
+        Id::from(self.uuid)
+    }
+    fn from_id(id: Id) -> Self {
+        ViperRunId::from(id.value())
+    }
+}
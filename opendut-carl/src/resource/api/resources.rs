@@ -1,7 +1,9 @@
 use crate::resource::api::Resource;
 use crate::resource::persistence::error::PersistenceResult;
 use crate::resource::persistence::persistable::Persistable;
+use crate::resource::storage::audit_log::{AuditLogPosition, AuditLogStorageApi};
 use crate::resource::storage::{ResourceTransaction, ResourcesStorageApi};
+use opendut_model::audit::{AuditEvent, AuditEventId};
 use crate::resource::subscription::{ResourceSubscriptionChannels, Subscribable, SubscriptionEvent};
 use std::collections::HashMap;
 
@@ -63,3 +65,17 @@ impl ResourcesStorageApi for Resources<'_> {
         self.transaction.list()
     }
 }
+
+impl AuditLogStorageApi for Resources<'_> {
+    fn append_audit_event(&mut self, event: AuditEvent) -> PersistenceResult<()> {
+        self.transaction.append_audit_event(event)
+    }
+
+    fn contains_audit_event(&self, id: AuditEventId) -> PersistenceResult<bool> {
+        self.transaction.contains_audit_event(id)
+    }
+
+    fn read_audit_events(&self, position: AuditLogPosition, limit: usize) -> PersistenceResult<Vec<AuditEvent>> {
+        self.transaction.read_audit_events(position, limit)
+    }
+}
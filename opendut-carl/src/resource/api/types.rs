@@ -1,6 +1,8 @@
 use crate::resource::api::id::ResourceId;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment, ClusterId};
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
+use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::executor::state::ExecutorState;
 use opendut_model::peer::state::PeerConnectionState;
 use opendut_model::peer::{PeerDescriptor, PeerId};
 use std::any::Any;
@@ -8,7 +10,7 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 #[cfg(feature = "viper")]
-use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperSourceDescriptor, ViperSourceId};
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunResult, ViperRunState, ViperSourceDescriptor, ViperSourceId};
 
 pub trait Resource: Any + Send + Sync + Debug + Clone {
     type Id: ResourceId<Self> + Clone + Hash + PartialEq + Eq + Debug;
@@ -34,6 +36,10 @@ impl Resource for EdgePeerConfigurationState {
     type Id = PeerId;
 }
 
+impl Resource for ExecutorState {
+    type Id = ExecutorId;
+}
+
 #[cfg(feature = "viper")]
 impl Resource for ViperSourceDescriptor {
     type Id = ViperSourceId;
@@ -48,3 +54,11 @@ impl Resource for ViperRunDescriptor {
 impl Resource for ViperRunDeployment {
     type Id = ViperRunId;
 }
+#[cfg(feature = "viper")]
+impl Resource for ViperRunState {
+    type Id = ViperRunId;
+}
+#[cfg(feature = "viper")]
+impl Resource for ViperRunResult {
+    type Id = ViperRunId;
+}
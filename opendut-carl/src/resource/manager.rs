@@ -75,6 +75,12 @@ impl ResourceManager {
         }).await
     }
 
+    /// Checks whether the storage can be accessed, e.g. for readiness probes.
+    pub async fn check_storage(&self) -> PersistenceResult<()> {
+        let state = self.state.read().await;
+        state.storage.check()
+    }
+
     /// Allows grouping modifications to the database. This does multiple things:
     /// - Opens a database transaction and then either commits it, or rolls it back when you return an `Err` out of the closure.
     /// - Acquires the lock for the database mutex and keeps it until the end of the closure.
@@ -112,9 +118,12 @@ impl ResourceManager {
             mut peer_descriptor,
             mut peer_connection_state,
             mut peer_configuration_state,
+            mut executor_state,
             #[cfg(feature = "viper")] mut test_suite_source_descriptor,
             #[cfg(feature = "viper")] mut test_suite_run_descriptor,
             #[cfg(feature = "viper")] mut test_suite_run_deployment,
+            #[cfg(feature = "viper")] mut test_suite_run_state,
+            #[cfg(feature = "viper")] mut test_suite_run_result,
         } = relayed_subscription_events;
 
 
@@ -154,6 +163,12 @@ impl ResourceManager {
                 .expect("should successfully send notification about event during resource transaction");
         }
 
+        while let Ok(event) = executor_state.1.try_recv() {
+            state.subscribers
+                .notify(event)
+                .expect("should successfully send notification about event during resource transaction");
+        }
+
         #[cfg(feature = "viper")]
         while let Ok(event) = test_suite_source_descriptor.1.try_recv() {
             state.subscribers
@@ -174,6 +189,20 @@ impl ResourceManager {
                 .notify(event)
                 .expect("should successfully send notification about event during resource transaction");
         }
+
+        #[cfg(feature = "viper")]
+        while let Ok(event) = test_suite_run_state.1.try_recv() {
+            state.subscribers
+                .notify(event)
+                .expect("should successfully send notification about event during resource transaction");
+        }
+
+        #[cfg(feature = "viper")]
+        while let Ok(event) = test_suite_run_result.1.try_recv() {
+            state.subscribers
+                .notify(event)
+                .expect("should successfully send notification about event during resource transaction");
+        }
     }
 
     #[deprecated(since="0.8.0")]
@@ -254,7 +283,9 @@ mod test {
                         results_url: None,
                     }
                 ],
-            }
+            },
+            revision: Default::default(),
+            namespace: Default::default(),
         };
 
         let cluster_resource_id = ClusterId::random();
@@ -263,6 +294,12 @@ mod test {
             name: ClusterName::try_from("ClusterX032")?,
             leader: peer.id,
             devices: HashSet::new(),
+            device_selectors: vec![],
+            topology: Default::default(),
+            can_rules: vec![],
+            restbus_simulations: vec![],
+            revision: Default::default(),
+            namespace: Default::default(),
         };
 
 
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::ops::Bound;
 
 use crate::resource::persistence::error::PersistenceResult;
 use opendut_model::resources::Id;
@@ -55,6 +56,12 @@ impl ReadTable<'_> {
             ReadTable::ReadWrite(table) => table.iter(),
         }
     }
+    pub(crate) fn range(&self, range: (Bound<Key>, Bound<Key>)) -> redb::Result<redb::Range<'_, Key, Value>> {
+        match self {
+            ReadTable::Read(table) => table.range(range),
+            ReadTable::ReadWrite(table) => table.range(range),
+        }
+    }
 }
 pub(super) type ReadWriteTable<'a> = redb::Table<'a, Key, Value>;
 
@@ -3,6 +3,7 @@ use crate::resource::persistence::error::PersistenceResult;
 use crate::resource::persistence::TableDefinition;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment};
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
+use opendut_model::peer::executor::state::ExecutorState;
 use opendut_model::peer::state::PeerConnectionState;
 use opendut_model::peer::PeerDescriptor;
 use opendut_util::proto::ConversionError;
@@ -10,7 +11,7 @@ use prost::Message;
 use std::fmt::Debug;
 
 #[cfg(feature = "viper")]
-use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperSourceDescriptor};
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunResult, ViperRunState, ViperSourceDescriptor};
 
 impl Persistable for ClusterDeployment {
     type Proto = opendut_model::proto::cluster::ClusterDeployment;
@@ -56,6 +57,12 @@ impl Persistable for EdgePeerConfigurationState {
     const STORAGE: StorageKind = StorageKind::Volatile;
 }
 
+impl Persistable for ExecutorState {
+    type Proto = opendut_model::proto::peer::executor::ExecutorState;
+    const TABLE: &'static str = "executor_state";
+    const STORAGE: StorageKind = StorageKind::Volatile;
+}
+
 #[cfg(feature = "viper")]
 impl Persistable for ViperSourceDescriptor {
     type Proto = opendut_model::proto::viper::ViperSourceDescriptor;
@@ -77,6 +84,20 @@ impl Persistable for ViperRunDeployment {
     const STORAGE: StorageKind = StorageKind::Persistent;
 }
 
+#[cfg(feature = "viper")]
+impl Persistable for ViperRunState {
+    type Proto = opendut_model::proto::viper::ViperRunState;
+    const TABLE: &'static str = "test_suite_run_state";
+    const STORAGE: StorageKind = StorageKind::Volatile;
+}
+
+#[cfg(feature = "viper")]
+impl Persistable for ViperRunResult {
+    type Proto = opendut_model::proto::viper::ViperRunResult;
+    const TABLE: &'static str = "test_suite_run_result";
+    const STORAGE: StorageKind = StorageKind::Persistent;
+}
+
 
 pub trait Persistable: Send + Sync + Sized + Debug + Resource {
     type Proto: Message + Default + From<Self> + TryInto<Self, Error=ConversionError>;
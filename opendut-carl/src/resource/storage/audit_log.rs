@@ -0,0 +1,102 @@
+use crate::resource::persistence;
+use crate::resource::persistence::error::{PersistenceError, PersistenceResult};
+use crate::resource::persistence::TableDefinition;
+use crate::resource::storage::ResourceTransaction;
+use opendut_model::audit::{AuditEvent, AuditEventId};
+use opendut_model::resources::Id;
+use prost::Message;
+use redb::ReadableTable;
+use std::ops::Bound;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Append-only table of the audit log, separate from the resources.
+/// The keys are time-ordered UUIDs (v7), so the events are stored in the order, in which they were recorded.
+const AUDIT_LOG_TABLE: TableDefinition = TableDefinition::new("audit_log");
+
+/// Position in the audit log, from which to read the events.
+#[derive(Clone, Copy, Debug)]
+pub enum AuditLogPosition {
+    Start,
+    /// First event, which was recorded at or after the given point in time.
+    Since(SystemTime),
+    /// Event following the given event.
+    After(AuditEventId),
+}
+
+pub trait AuditLogStorageApi {
+    /// Appends an event to the audit log. Events cannot be modified or removed afterwards.
+    fn append_audit_event(&mut self, event: AuditEvent) -> PersistenceResult<()>;
+
+    fn contains_audit_event(&self, id: AuditEventId) -> PersistenceResult<bool>;
+
+    /// Reads up to `limit` events in the order they were recorded, beginning at the given position.
+    fn read_audit_events(&self, position: AuditLogPosition, limit: usize) -> PersistenceResult<Vec<AuditEvent>>;
+}
+
+impl AuditLogStorageApi for ResourceTransaction<'_> {
+    fn append_audit_event(&mut self, event: AuditEvent) -> PersistenceResult<()> {
+        let id = event.id;
+        let key = key(id);
+        let value = opendut_model::proto::audit::AuditEvent::from(event).encode_to_vec();
+
+        let mut table = self.db.read_write_table(AUDIT_LOG_TABLE)?;
+        if table.get(&key)?.is_some() {
+            return Err(PersistenceError::insert::<AuditEvent>(id, "Audit events cannot be overwritten."));
+        }
+        table.insert(key, value)?;
+
+        Ok(())
+    }
+
+    fn contains_audit_event(&self, id: AuditEventId) -> PersistenceResult<bool> {
+        let key = key(id);
+
+        match self.db.read_table(AUDIT_LOG_TABLE)? {
+            Some(table) => Ok(table.get(&key)?.is_some()),
+            None => Ok(false),
+        }
+    }
+
+    fn read_audit_events(&self, position: AuditLogPosition, limit: usize) -> PersistenceResult<Vec<AuditEvent>> {
+        let Some(table) = self.db.read_table(AUDIT_LOG_TABLE)? else {
+            return Ok(Vec::new());
+        };
+
+        let (start, since) = match position {
+            AuditLogPosition::Start => (Bound::Unbounded, None),
+            AuditLogPosition::Since(since) => (Bound::Included(first_key_at(since)), Some(since)),
+            AuditLogPosition::After(id) => (Bound::Excluded(key(id)), None),
+        };
+
+        let mut events = Vec::new();
+        for entry in table.range((start, Bound::Unbounded))? {
+            if events.len() == limit {
+                break;
+            }
+            let (_, value) = entry?;
+            let event: AuditEvent = opendut_model::proto::audit::AuditEvent::decode(value.value().as_slice())?.try_into()?;
+
+            //the key only has a precision of milliseconds
+            if since.is_some_and(|since| event.timestamp < since) {
+                continue;
+            }
+            events.push(event);
+        }
+        Ok(events)
+    }
+}
+
+fn key(id: AuditEventId) -> persistence::Key {
+    persistence::Key::from(Id::from(id.uuid))
+}
+
+/// Determines the lowest key of an event recorded in the millisecond of the given point in time.
+fn first_key_at(timestamp: SystemTime) -> persistence::Key {
+    let millis = timestamp.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+    let uuid = Uuid::from_u128((millis & 0xFFFF_FFFF_FFFF) << 80);
+
+    persistence::Key::from(Id::from(uuid))
+}
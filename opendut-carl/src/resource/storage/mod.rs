@@ -16,6 +16,8 @@ use std::ops::Not;
 use std::path::PathBuf;
 use tracing::{debug, info};
 
+pub(crate) mod audit_log;
+
 #[cfg(test)]
 mod tests;
 
@@ -62,6 +64,13 @@ impl ResourceStorage {
         Ok(Self { db, memory })
     }
 
+    /// Checks that read transactions can be opened on the persistent and the in-memory database.
+    pub fn check(&self) -> PersistenceResult<()> {
+        let _ = self.db.begin_read()?;
+        let _ = self.memory.begin_read()?;
+        Ok(())
+    }
+
     pub async fn resources<T, F>(&self, code: F) -> PersistenceResult<T>
     where
         F: AsyncFnOnce(ResourceTransaction) -> T,
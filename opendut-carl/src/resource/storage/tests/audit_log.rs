@@ -0,0 +1,46 @@
+use crate::resource::manager::ResourceManager;
+use crate::resource::storage::audit_log::{AuditLogPosition, AuditLogStorageApi};
+use googletest::prelude::*;
+use opendut_model::audit::{AuditEvent, AuditEventId, AuditOutcome};
+use std::time::SystemTime;
+
+#[tokio::test]
+async fn should_append_audit_events_without_overwriting_them() -> anyhow::Result<()> {
+    let resource_manager = ResourceManager::new_in_memory();
+
+    let first = audit_event("StorePeerDescriptor");
+    let second = audit_event("DeletePeerDescriptor");
+
+    resource_manager.resources_mut(async |resources| {
+        resources.append_audit_event(Clone::clone(&first))?;
+        resources.append_audit_event(Clone::clone(&second))
+    }).await??;
+
+    let result = resource_manager.resources_mut(async |resources|
+        resources.append_audit_event(AuditEvent { operation: String::from("Overwritten"), ..Clone::clone(&first) })
+    ).await?;
+    assert_that!(result, err(anything()));
+
+    let events = resource_manager.resources(async |resources|
+        resources.read_audit_events(AuditLogPosition::Start, usize::MAX)
+    ).await??;
+    assert_eq!(events, vec![Clone::clone(&first), Clone::clone(&second)]);
+
+    let events = resource_manager.resources(async |resources|
+        resources.read_audit_events(AuditLogPosition::After(first.id), usize::MAX)
+    ).await??;
+    assert_eq!(events, vec![second]);
+
+    Ok(())
+}
+
+fn audit_event(operation: &str) -> AuditEvent {
+    AuditEvent {
+        id: AuditEventId::now(),
+        timestamp: SystemTime::now(),
+        user: None,
+        operation: String::from(operation),
+        resource_ids: vec![],
+        outcome: AuditOutcome::Success,
+    }
+}
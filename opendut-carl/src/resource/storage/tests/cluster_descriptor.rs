@@ -60,5 +60,11 @@ pub fn cluster_descriptor(leader_id: PeerId, devices: Vec<DeviceId>) -> anyhow::
         name: ClusterName::try_from("cluster-name")?,
         leader: leader_id,
         devices: HashSet::from_iter(devices),
+        device_selectors: vec![],
+        topology: Default::default(),
+        can_rules: vec![],
+        restbus_simulations: vec![],
+        revision: Default::default(),
+        namespace: Default::default(),
     })
 }
@@ -4,3 +4,4 @@ mod peer_descriptor;
 mod cluster_descriptor;
 mod cluster_deployment;
 mod transaction;
+mod audit_log;
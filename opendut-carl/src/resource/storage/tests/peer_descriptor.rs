@@ -1,4 +1,5 @@
 use opendut_model::peer::executor::container::{ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine};
+use opendut_model::peer::executor::executable::{ExecutableArgument, ExecutablePath, ExecutableRestartPolicy, ExecutableWorkingDirectory};
 use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorDescriptors, ExecutorId, ExecutorKind, ResultsUrl};
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
 use opendut_model::topology::{DeviceDescription, DeviceDescriptor, DeviceId, DeviceName, DeviceTag, Topology};
@@ -147,10 +148,22 @@ pub fn peer_descriptor() -> anyhow::Result<PeerDescriptor> {
                 },
                 ExecutorDescriptor {
                     id: ExecutorId::random(),
-                    kind: ExecutorKind::Executable,
+                    kind: ExecutorKind::Executable {
+                        path: ExecutablePath::try_from("/usr/bin/executable")?,
+                        args: vec![
+                            ExecutableArgument::try_from("--verbose")?,
+                        ],
+                        envs: vec![
+                            ContainerEnvironmentVariable::new("env-name", "env-value")?,
+                        ],
+                        working_dir: Some(ExecutableWorkingDirectory::try_from("/tmp")?),
+                        restart_policy: ExecutableRestartPolicy::OnFailure,
+                    },
                     results_url: Some(ResultsUrl::try_from("https://example.com/")?),
                 },
             ]
         },
+        revision: Default::default(),
+        namespace: Default::default(),
     })
 }
@@ -1,12 +1,13 @@
 use crate::resource::api::Resource;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment};
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
+use opendut_model::peer::executor::state::ExecutorState;
 use opendut_model::peer::state::PeerConnectionState;
 use opendut_model::peer::PeerDescriptor;
 use tokio::sync::broadcast;
 
 #[cfg(feature = "viper")]
-use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperSourceDescriptor};
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunResult, ViperRunState, ViperSourceDescriptor};
 
 pub struct Subscription<R: Resource> {
     receiver: broadcast::Receiver<SubscriptionEvent<R>>,
@@ -16,6 +17,11 @@ impl<R: Resource> Subscription<R> {
         self.receiver.recv().await
             .map_err(ReceiveError::Broadcast)
     }
+
+    /// Receives an event, which was already sent, without waiting for further events.
+    pub fn try_receive(&mut self) -> Result<SubscriptionEvent<R>, broadcast::error::TryRecvError> {
+        self.receiver.try_recv()
+    }
 }
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SubscriptionEvent<R: Resource> {
@@ -61,12 +67,17 @@ impl_subscribable!(PeerConfiguration, peer_configuration);
 impl_subscribable!(PeerDescriptor, peer_descriptor);
 impl_subscribable!(PeerConnectionState, peer_connection_state);
 impl_subscribable!(EdgePeerConfigurationState, peer_configuration_state);
+impl_subscribable!(ExecutorState, executor_state);
 #[cfg(feature = "viper")]
 impl_subscribable!(ViperSourceDescriptor, test_suite_source_descriptor);
 #[cfg(feature = "viper")]
 impl_subscribable!(ViperRunDescriptor, test_suite_run_descriptor);
 #[cfg(feature = "viper")]
 impl_subscribable!(ViperRunDeployment, test_suite_run_deployment);
+#[cfg(feature = "viper")]
+impl_subscribable!(ViperRunState, test_suite_run_state);
+#[cfg(feature = "viper")]
+impl_subscribable!(ViperRunResult, test_suite_run_result);
 
 pub type ResourceSubscriptionChannel<R> = (broadcast::Sender<SubscriptionEvent<R>>, broadcast::Receiver<SubscriptionEvent<R>>); //store both the sender and initial receiver, to prevent channel from closing
 
@@ -77,9 +88,12 @@ pub struct ResourceSubscriptionChannels {
     pub peer_descriptor: ResourceSubscriptionChannel<PeerDescriptor>,
     pub peer_connection_state: ResourceSubscriptionChannel<PeerConnectionState>,
     pub peer_configuration_state: ResourceSubscriptionChannel<EdgePeerConfigurationState>,
+    pub executor_state: ResourceSubscriptionChannel<ExecutorState>,
     #[cfg(feature = "viper")] pub test_suite_source_descriptor: ResourceSubscriptionChannel<ViperSourceDescriptor>,
     #[cfg(feature = "viper")] pub test_suite_run_descriptor: ResourceSubscriptionChannel<ViperRunDescriptor>,
     #[cfg(feature = "viper")] pub test_suite_run_deployment: ResourceSubscriptionChannel<ViperRunDeployment>,
+    #[cfg(feature = "viper")] pub test_suite_run_state: ResourceSubscriptionChannel<ViperRunState>,
+    #[cfg(feature = "viper")] pub test_suite_run_result: ResourceSubscriptionChannel<ViperRunResult>,
 }
 impl ResourceSubscriptionChannels {
     pub fn subscribe<R: Resource + Subscribable>(&mut self) -> Subscription<R> {
@@ -102,9 +116,12 @@ impl ResourceSubscriptionChannels {
             peer_descriptor,
             peer_connection_state,
             peer_configuration_state,
+            executor_state,
             #[cfg(feature = "viper")] test_suite_source_descriptor,
             #[cfg(feature = "viper")] test_suite_run_descriptor,
             #[cfg(feature = "viper")] test_suite_run_deployment,
+            #[cfg(feature = "viper")] test_suite_run_state,
+            #[cfg(feature = "viper")] test_suite_run_result,
         } = self;
 
         let result =
@@ -113,13 +130,16 @@ impl ResourceSubscriptionChannels {
             && peer_configuration.0.is_empty()
             && peer_descriptor.0.is_empty()
             && peer_connection_state.0.is_empty()
-            && peer_configuration_state.0.is_empty();
+            && peer_configuration_state.0.is_empty()
+            && executor_state.0.is_empty();
 
         #[cfg(feature = "viper")]
         let result = result
             && test_suite_source_descriptor.0.is_empty()
             && test_suite_run_descriptor.0.is_empty()
-            && test_suite_run_deployment.0.is_empty();
+            && test_suite_run_deployment.0.is_empty()
+            && test_suite_run_state.0.is_empty()
+            && test_suite_run_result.0.is_empty();
 
         result
     }
@@ -135,9 +155,12 @@ impl Default for ResourceSubscriptionChannels {
             peer_descriptor: broadcast::channel(capacity),
             peer_connection_state: broadcast::channel(capacity),
             peer_configuration_state: broadcast::channel(capacity),
+            executor_state: broadcast::channel(capacity),
             #[cfg(feature = "viper")] test_suite_source_descriptor: broadcast::channel(capacity),
             #[cfg(feature = "viper")] test_suite_run_descriptor: broadcast::channel(capacity),
             #[cfg(feature = "viper")] test_suite_run_deployment: broadcast::channel(capacity),
+            #[cfg(feature = "viper")] test_suite_run_state: broadcast::channel(capacity),
+            #[cfg(feature = "viper")] test_suite_run_result: broadcast::channel(capacity),
         }
     }
 }
@@ -1,10 +1,9 @@
 use std::sync::Arc;
-use anyhow::Context;
 use config::Config;
 use opendut_auth::registration::client::RegistrationClientRef;
 use opendut_auth::registration::resources::ResourceHomeUrl;
 use opendut_util::pem::Pem;
-use crate::manager::grpc::{ClusterManagerFacade, MetadataProviderFacade, ObserverMessagingBrokerFacade, PeerManagerFacade, PeerMessagingBrokerFacade};
+use crate::manager::grpc::{AuditLogFacade, ClusterManagerFacade, MetadataProviderFacade, ObserverMessagingBrokerFacade, PeerManagerFacade, PeerMessagingBrokerFacade};
 #[cfg(feature = "viper")]
 use crate::manager::grpc::TestManagerFacade;
 use crate::resource::manager::ResourceManagerRef;
@@ -12,9 +11,12 @@ use crate::startup;
 use crate::manager::cluster_manager::{ClusterManager, ClusterManagerOptions};
 use crate::manager::observer_messaging_broker::ObserverMessagingBroker;
 use crate::manager::peer_messaging_broker::{PeerMessagingBroker, PeerMessagingBrokerOptions};
-use crate::settings::vpn;
+#[cfg(feature = "viper")]
+use crate::manager::test_manager::TestManager;
+use crate::settings::vpn::Vpn;
 
 pub struct GrpcFacades {
+    pub audit_log_facade: AuditLogFacade,
     pub cluster_manager_facade: ClusterManagerFacade,
     pub metadata_provider_facade: MetadataProviderFacade,
     pub peer_manager_facade: PeerManagerFacade,
@@ -25,17 +27,31 @@ pub struct GrpcFacades {
 }
 
 impl GrpcFacades {
+    /// Fully qualified names of the gRPC services, which are served by CARL.
+    pub fn service_names() -> Vec<&'static str> {
+        use opendut_carl_api::proto::services::*;
+
+        vec![
+            audit_log::audit_log_server::SERVICE_NAME,
+            cluster_manager::cluster_manager_server::SERVICE_NAME,
+            metadata_provider::metadata_provider_server::SERVICE_NAME,
+            peer_manager::peer_manager_server::SERVICE_NAME,
+            peer_messaging_broker::peer_messaging_broker_server::SERVICE_NAME,
+            observer_messaging_broker::observer_messaging_broker_server::SERVICE_NAME,
+            #[cfg(feature = "viper")]
+            test_manager::test_manager_server::SERVICE_NAME,
+        ]
+    }
+
     pub async fn create(
         resource_manager: ResourceManagerRef,
         carl_url: &ResourceHomeUrl,
         ca_certificate: Pem,
         oidc_registration_client: Option<RegistrationClientRef>,
+        vpn: Vpn,
         settings: &Config,
     ) -> anyhow::Result<Self> {
 
-        let vpn = vpn::create(settings).await
-            .context("Error while parsing VPN configuration.")?;
-
         startup::metrics::initialize_metrics_collection(Arc::clone(&resource_manager));
 
         let peer_messaging_broker = PeerMessagingBroker::new(
@@ -48,7 +64,14 @@ impl GrpcFacades {
             Clone::clone(&vpn),
             ClusterManagerOptions::load(settings)?,
         ).await;
+        #[cfg(feature = "viper")]
+        TestManager::create(
+            Arc::clone(&resource_manager),
+            Arc::clone(&peer_messaging_broker),
+        ).await;
+
 
+        let audit_log_facade = AuditLogFacade::new(Arc::clone(&resource_manager));
 
         let cluster_manager_facade = ClusterManagerFacade::new(Arc::clone(&cluster_manager), Arc::clone(&resource_manager));
 
@@ -63,15 +86,17 @@ impl GrpcFacades {
         );
         let peer_messaging_broker_facade = PeerMessagingBrokerFacade::new(Arc::clone(&peer_messaging_broker));
         
-        let observer_messaging_broker = ObserverMessagingBroker::new(Arc::clone(&resource_manager), Arc::clone(&cluster_manager));
+        let observer_messaging_broker = ObserverMessagingBroker::new(Arc::clone(&resource_manager), Arc::clone(&cluster_manager)).await?;
         let observer_messaging_broker_facade = ObserverMessagingBrokerFacade::new(Arc::clone(&resource_manager), Arc::clone(&observer_messaging_broker));
 
         #[cfg(feature = "viper")]
         let test_manager_facade = TestManagerFacade {
             resource_manager: Arc::clone(&resource_manager),
+            inspection_cache: Default::default(),
         };
 
         Ok(GrpcFacades {
+            audit_log_facade,
             cluster_manager_facade,
             metadata_provider_facade,
             peer_manager_facade,
@@ -7,6 +7,8 @@ use opendut_auth::registration::resources::ResourceHomeUrl;
 use opendut_model::lea::LeaConfig;
 use opendut_util::project;
 use crate::http::router;
+use crate::http::router::health::ReadinessChecks;
+use crate::http::router::metrics::PrometheusMetrics;
 use crate::http::state::{CarlInstallDirectory, HttpState, LoadableLeaIdentityProviderConfig};
 
 
@@ -34,7 +36,9 @@ pub fn create_http_service(settings: &Config) -> anyhow::Result<axum::Router<Htt
     let licenses_dir = project::make_path_absolute("./licenses")
         .expect("licenses directory should be absolute");
 
-    let router = axum::Router::new()
+    let mut router = axum::Router::new()
+        .route("/healthz", get(router::health::healthz))
+        .route("/readyz", get(router::health::readyz))
         .nest_service(
             "/api/licenses",
             ServeDir::new(&licenses_dir)
@@ -48,12 +52,20 @@ pub fn create_http_service(settings: &Config) -> anyhow::Result<axum::Router<Htt
                 .fallback(ServeFile::new(lea_index_html))
         );
 
+    let prometheus_enabled = settings.get_bool("serve.metrics.prometheus.enabled")?;
+    if prometheus_enabled {
+        info!("Serving metrics in Prometheus format at `/metrics`.");
+        router = router.route("/metrics", get(router::metrics::metrics));
+    }
+
     Ok(router)
 }
 
 pub fn create_http_state(
     carl_url: &ResourceHomeUrl,
     carl_installation_directory: CarlInstallDirectory,
+    readiness_checks: ReadinessChecks,
+    prometheus_metrics: PrometheusMetrics,
     settings: &Config,
 ) -> anyhow::Result<HttpState> {
 
@@ -73,7 +85,9 @@ pub fn create_http_state(
             carl_url: carl_url.value(),
             idp_config: lea_idp_config.map(|LoadableLeaIdentityProviderConfig(config)| config),
         },
-        carl_installation_directory
+        carl_installation_directory,
+        readiness_checks,
+        prometheus_metrics,
     };
 
     Ok(http_state)
@@ -1,82 +1,229 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
 use opentelemetry::global;
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::KeyValue;
 use tracing::trace;
 use opendut_model::cluster::{ClusterDescriptor, ClusterDeployment};
 use opendut_model::peer::PeerDescriptor;
 use opendut_model::peer::state::PeerConnectionState;
-use crate::resource::manager::ResourceManagerRef;
+use crate::resource::manager::{ResourceManager, ResourceManagerRef};
+use crate::resource::persistence::error::PersistenceResult;
 
 pub fn initialize_metrics_collection(
     resource_manager: ResourceManagerRef,
 ) {
     let meter = global::meter(opendut_telemetry::DEFAULT_METER_NAME);
 
-    {
+    for gauge in ResourceGauge::ALL {
         let resource_manager = resource_manager.clone();
-        meter.u64_observable_gauge("deployed_clusters")
+        meter.u64_observable_gauge(gauge.name())
+            .with_description(gauge.description())
             .with_callback(move |observer| {
                 let result = futures::executor::block_on(
-                    resource_manager.list::<ClusterDeployment>()
+                    gauge.measure(&resource_manager)
                 );
 
                 match result {
-                    Ok(deployed_clusters) => observer.observe(deployed_clusters.len() as u64, &[]),
+                    Ok(value) => observer.observe(value, &[]),
                     Err(cause) => trace!("Error while loading metrics information from ResourceManager:\n  {cause}")
                 }
             })
             .build();
     }
+}
 
-    {
-        let resource_manager = resource_manager.clone();
-        meter.u64_observable_gauge("configured_clusters")
-            .with_callback(move |observer| {
-                let result = futures::executor::block_on(
-                    resource_manager.list::<ClusterDescriptor>()
-                );
+/// Gauges, which are calculated from the resources in the [`ResourceManager`].
+#[derive(Clone, Copy, Debug)]
+pub enum ResourceGauge {
+    DeployedClusters,
+    ConfiguredClusters,
+    RegisteredPeers,
+    ConnectedPeers,
+}
 
-                match result {
-                    Ok(configured_clusters) => observer.observe(configured_clusters.len() as u64, &[]),
-                    Err(cause) => trace!("Error while loading metrics information from ResourceManager:\n  {cause}")
-                }
-            })
-            .build();
+impl ResourceGauge {
+    pub const ALL: [ResourceGauge; 4] = [
+        ResourceGauge::DeployedClusters,
+        ResourceGauge::ConfiguredClusters,
+        ResourceGauge::RegisteredPeers,
+        ResourceGauge::ConnectedPeers,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ResourceGauge::DeployedClusters => "deployed_clusters",
+            ResourceGauge::ConfiguredClusters => "configured_clusters",
+            ResourceGauge::RegisteredPeers => "registered_peers",
+            ResourceGauge::ConnectedPeers => "connected_peers",
+        }
     }
 
-    {
-        let resource_manager = resource_manager.clone();
-        meter.u64_observable_gauge("registered_peers")
-            .with_callback(move |observer| {
-                let result = futures::executor::block_on(
-                    resource_manager.list::<PeerDescriptor>()
-                );
+    pub fn description(self) -> &'static str {
+        match self {
+            ResourceGauge::DeployedClusters => "Number of deployed clusters.",
+            ResourceGauge::ConfiguredClusters => "Number of configured clusters.",
+            ResourceGauge::RegisteredPeers => "Number of registered peers.",
+            ResourceGauge::ConnectedPeers => "Number of peers, which are currently connected to CARL.",
+        }
+    }
 
-                match result {
-                    Ok(registered_peers) => observer.observe(registered_peers.len() as u64, &[]),
-                    Err(cause) => trace!("Error while loading metrics information from ResourceManager:\n  {cause}")
-                }
-            })
-            .build();
+    pub async fn measure(self, resource_manager: &ResourceManager) -> PersistenceResult<u64> {
+        let value = match self {
+            ResourceGauge::DeployedClusters => resource_manager.list::<ClusterDeployment>().await?.len(),
+            ResourceGauge::ConfiguredClusters => resource_manager.list::<ClusterDescriptor>().await?.len(),
+            ResourceGauge::RegisteredPeers => resource_manager.list::<PeerDescriptor>().await?.len(),
+            ResourceGauge::ConnectedPeers => {
+                resource_manager.list::<PeerConnectionState>().await?
+                    .into_values()
+                    .filter(|connection| matches!(connection, PeerConnectionState::Online { .. }))
+                    .count()
+            }
+        };
+        Ok(value as u64)
     }
+}
 
-    {
-        let resource_manager = resource_manager.clone();
-        meter.u64_observable_gauge("connected_peers")
-            .with_callback(move |observer| {
-                let result = futures::executor::block_on(
-                    resource_manager.list::<PeerConnectionState>()
-                );
+/// Upper bounds of the buckets of the RPC latency histogram, in seconds.
+pub const RPC_DURATION_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
 
-                match result {
-                    Ok(peer_connection_states) => {
-                        let online_peers = peer_connection_states.into_iter()
-                            .filter(|(_, connection)| matches!(connection, PeerConnectionState::Online { .. }))
-                            .collect::<Vec<_>>();
+/// Label for calls to paths, which are not a method of the served gRPC services.
+const UNKNOWN_METHOD: &str = "unknown";
 
-                        observer.observe(online_peers.len() as u64, &[]);
-                    }
-                    Err(cause) => trace!("Error while loading metrics information from ResourceManager:\n  {cause}")
-                }
-            })
-            .build();
+/// Records the latency and errors of each gRPC method.
+/// The values are kept in memory for the Prometheus endpoint and are additionally recorded via OpenTelemetry.
+#[derive(Clone)]
+pub struct RpcMetrics {
+    /// Paths of the served gRPC methods. Other paths are recorded as [`UNKNOWN_METHOD`], to not create a time series per requested path.
+    known_methods: Arc<HashSet<String>>,
+    methods: Arc<Mutex<BTreeMap<String, RpcMethodMetrics>>>,
+    duration_histogram: Histogram<f64>,
+    error_counter: Counter<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RpcMethodMetrics {
+    pub requests: u64,
+    /// Number of failed requests per gRPC status code.
+    pub errors: BTreeMap<i32, u64>,
+    /// Cumulative number of requests per bucket of [`RPC_DURATION_BUCKETS`].
+    pub duration_buckets: [u64; RPC_DURATION_BUCKETS.len()],
+    pub duration_sum: Duration,
+}
+
+impl RpcMethodMetrics {
+    fn record(&mut self, duration: Duration, grpc_status: i32) {
+        self.requests += 1;
+        if grpc_status != 0 {
+            *self.errors.entry(grpc_status).or_default() += 1;
+        }
+
+        let seconds = duration.as_secs_f64();
+        for (bucket, upper_bound) in self.duration_buckets.iter_mut().zip(RPC_DURATION_BUCKETS) {
+            if seconds <= upper_bound {
+                *bucket += 1;
+            }
+        }
+        self.duration_sum += duration;
+    }
+}
+
+impl RpcMetrics {
+    pub fn new(known_methods: impl IntoIterator<Item=String>) -> Self {
+        let meter = global::meter(opendut_telemetry::DEFAULT_METER_NAME);
+
+        Self {
+            known_methods: Arc::new(known_methods.into_iter().collect()),
+            methods: Default::default(),
+            duration_histogram: meter.f64_histogram("rpc_server_duration")
+                .with_unit("s")
+                .with_description("Duration of gRPC requests until the response headers were sent.")
+                .with_boundaries(RPC_DURATION_BUCKETS.to_vec())
+                .build(),
+            error_counter: meter.u64_counter("rpc_server_errors")
+                .with_description("Number of gRPC requests, which failed with a non-OK status.")
+                .build(),
+        }
+    }
+
+    pub fn record(&self, method: &str, duration: Duration, grpc_status: i32) {
+        let method = if self.known_methods.contains(method) {
+            method
+        } else {
+            UNKNOWN_METHOD
+        };
+
+        let attributes = [KeyValue::new("rpc.method", method.to_owned())];
+        self.duration_histogram.record(duration.as_secs_f64(), &attributes);
+        if grpc_status != 0 {
+            self.error_counter.add(1, &[
+                KeyValue::new("rpc.method", method.to_owned()),
+                KeyValue::new("rpc.grpc.status_code", i64::from(grpc_status)),
+            ]);
+        }
+
+        let mut methods = self.methods.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        methods.entry(method.to_owned()).or_default()
+            .record(duration, grpc_status);
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, RpcMethodMetrics> {
+        self.methods.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// Middleware for the gRPC router, which records each call in the [`RpcMetrics`].
+///
+/// The duration is measured until the response headers are sent, which for streaming methods is when the stream is opened.
+/// Failed calls are recognized by the `grpc-status` header, which tonic sends for calls returning an error.
+pub async fn record_rpc_metrics(
+    State(rpc_metrics): State<RpcMetrics>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.uri().path().to_owned();
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    let grpc_status = response.headers().get("grpc-status")
+        .and_then(|status| status.to_str().ok())
+        .and_then(|status| status.parse::<i32>().ok())
+        .unwrap_or(0);
+
+    rpc_metrics.record(&method, start.elapsed(), grpc_status);
+
+    response
+}
+
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    const STORE_PEER_DESCRIPTOR: &str = "/opendut.carl.services.peer_manager.PeerManager/StorePeerDescriptor";
+
+    #[test]
+    fn should_record_unknown_paths_as_a_single_method() {
+        let rpc_metrics = RpcMetrics::new([String::from(STORE_PEER_DESCRIPTOR)]);
+
+        rpc_metrics.record(STORE_PEER_DESCRIPTOR, Duration::from_millis(10), 0);
+        rpc_metrics.record("/opendut.carl.services.peer_manager.PeerManager/SomethingNew", Duration::from_millis(10), 12);
+        rpc_metrics.record("/random/path", Duration::from_millis(10), 16);
+
+        let snapshot = rpc_metrics.snapshot();
+        assert_that!(snapshot.keys().map(String::as_str).collect::<Vec<_>>(), elements_are![eq(STORE_PEER_DESCRIPTOR), eq(UNKNOWN_METHOD)]);
+        assert_that!(snapshot[UNKNOWN_METHOD].requests, eq(2));
+        assert_eq!(snapshot[UNKNOWN_METHOD].errors, BTreeMap::from([(12, 1), (16, 1)]));
     }
 }
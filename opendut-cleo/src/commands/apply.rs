@@ -2,16 +2,33 @@ use crate::commands::cluster_descriptor::apply::convert_document_to_cluster_desc
 use crate::commands::cluster_descriptor::create::create_cluster_descriptor;
 use crate::commands::peer::apply::convert_document_to_peer_descriptor;
 use crate::commands::peer::create::create_peer;
+#[cfg(feature = "viper")]
+use crate::commands::viper_run::apply::convert_document_to_viper_run;
+#[cfg(feature = "viper")]
+use crate::commands::viper_run::create::{create_viper_run, UnboundViperRunDescriptor};
+#[cfg(feature = "viper")]
+use crate::commands::viper_run_deployment::apply::convert_document_to_viper_run_deployment;
+#[cfg(feature = "viper")]
+use crate::commands::viper_run_deployment::create::create_viper_run_deployment;
+#[cfg(feature = "viper")]
+use crate::commands::viper_source::apply::convert_document_to_viper_source;
+#[cfg(feature = "viper")]
+use crate::commands::viper_source::create::create_viper_source;
 use crate::CreateOutputFormat;
 use opendut_carl_api::carl::CarlClient;
 use opendut_model::cluster::ClusterDescriptor;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::PeerDescriptor;
+use opendut_model::resources::Revision;
 use opendut_model::specs::cluster::ClusterDescriptorSpecification;
 use opendut_model::specs::parse::json::JsonSpecificationDocument;
 use opendut_model::specs::parse::yaml::YamlSpecificationFile;
 use opendut_model::specs::peer::PeerDescriptorSpecification;
 use opendut_model::specs::{Specification, SpecificationDocument};
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "viper")]
+use opendut_model::specs::viper::{ViperRunDeploymentSpecification, ViperRunDescriptorSpecification, ViperSourceDescriptorSpecification};
+#[cfg(feature = "viper")]
+use opendut_model::viper::{ViperRunDeployment, ViperSourceDescriptor};
 use std::fs;
 use std::path::PathBuf;
 
@@ -23,10 +40,13 @@ pub struct ApplyCli {
     ///Text, JSON or prettified JSON as output format
     #[arg(global=true, value_enum, short, long, default_value_t=CreateOutputFormat::Text)]
     output: CreateOutputFormat,
+    ///Move existing peers and clusters into the selected namespace. Without this flag, they keep their stored namespace.
+    #[arg(long)]
+    move_to_namespace: bool,
 }
 
 impl ApplyCli {
-    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName) -> crate::Result<()> {
         match &self.from {
             Source::File(path) => {
                 let content = fs::read_to_string(path).unwrap();
@@ -45,7 +65,7 @@ impl ApplyCli {
                             .collect::<Result<Vec<_>, _>>()?;
                         
                         for model in models {
-                            self.create_resource_in_carl_from_model(model, carl).await?;
+                            self.create_resource_in_carl_from_model(model, &namespace, carl).await?;
                         }
                         
                         Ok(())
@@ -64,26 +84,68 @@ impl ApplyCli {
 
                 let model = convert_document_to_model(document)?;
 
-                self.create_resource_in_carl_from_model(model, carl).await?;
+                self.create_resource_in_carl_from_model(model, &namespace, carl).await?;
 
                 Ok(())
             }
         }
     }
 
-    async fn create_resource_in_carl_from_model(&self, model: ResourceModel, carl: &mut CarlClient) -> crate::Result<()> {
+    async fn create_resource_in_carl_from_model(&self, model: ResourceModel, namespace: &NamespaceName, carl: &mut CarlClient) -> crate::Result<()> {
         match model {
-            ResourceModel::PeerDescriptor(model) => {
+            ResourceModel::PeerDescriptor(mut model) => {
+                let stored_peers = carl.peers.list_peer_descriptors().await
+                    .map_err(|error| format!("Error while listing peers.\n  {error}"))?;
+                let stored_peer = stored_resource(model.id, stored_peers, |peer| peer.id);
+                model.revision = stored_peer.as_ref().map(|peer| peer.revision).unwrap_or(Revision::INITIAL);
+                model.namespace = target_namespace(stored_peer.map(|peer| peer.namespace), namespace, self.move_to_namespace);
+
                 create_peer(model, carl, &self.output).await?;
             }
-            ResourceModel::ClusterDescriptor(model) => {
+            ResourceModel::ClusterDescriptor(mut model) => {
+                let stored_clusters = carl.cluster.list_cluster_descriptors().await
+                    .map_err(|error| format!("Error while listing cluster descriptors.\n  {error}"))?;
+                let stored_cluster = stored_resource(model.id, stored_clusters, |cluster| cluster.id);
+                model.revision = stored_cluster.as_ref().map(|cluster| cluster.revision).unwrap_or(Revision::INITIAL);
+                model.namespace = target_namespace(stored_cluster.map(|cluster| cluster.namespace), namespace, self.move_to_namespace);
+
                 create_cluster_descriptor(model, carl, &self.output).await?;
             }
+            #[cfg(feature = "viper")]
+            ResourceModel::ViperSourceDescriptor(mut model) => {
+                model.namespace = Clone::clone(namespace);
+
+                create_viper_source(model, carl, &self.output).await?;
+            }
+            #[cfg(feature = "viper")]
+            ResourceModel::ViperRunDescriptor(mut model) => {
+                model.namespace = Clone::clone(namespace);
+
+                create_viper_run(model, carl, &self.output).await?;
+            }
+            #[cfg(feature = "viper")]
+            ResourceModel::ViperRunDeployment(model) => {
+                create_viper_run_deployment(model, carl, &self.output).await?;
+            }
         }
         Ok(())
     }
 }
 
+/// Applying a specification replaces the stored resource, so it is based on the latest stored revision.
+fn stored_resource<Id: PartialEq, R>(id: Id, stored: Vec<R>, id_of: impl Fn(&R) -> Id) -> Option<R> {
+    stored.into_iter()
+        .find(|resource| id_of(resource) == id)
+}
+
+/// An existing resource keeps its namespace, unless it is explicitly moved into the selected namespace.
+fn target_namespace(stored_namespace: Option<NamespaceName>, namespace: &NamespaceName, move_to_namespace: bool) -> NamespaceName {
+    match stored_namespace {
+        Some(stored_namespace) if !move_to_namespace => stored_namespace,
+        _ => Clone::clone(namespace),
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub enum Source {
@@ -122,10 +184,16 @@ fn parse_source(arg: &str) -> Result<Source, SourceParsingError> {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
 enum ResourceModel {
     PeerDescriptor(PeerDescriptor),
     ClusterDescriptor(ClusterDescriptor),
+    #[cfg(feature = "viper")]
+    ViperSourceDescriptor(ViperSourceDescriptor),
+    #[cfg(feature = "viper")]
+    ViperRunDescriptor(UnboundViperRunDescriptor),
+    #[cfg(feature = "viper")]
+    ViperRunDeployment(ViperRunDeployment),
 }
 
 fn convert_document_to_model(specification_document: SpecificationDocument) -> crate::Result<ResourceModel> {
@@ -141,7 +209,34 @@ fn convert_document_to_model(specification_document: SpecificationDocument) -> c
             let cluster_descriptor = convert_document_to_cluster_descriptor(specification_document.metadata, cluster_descriptor)
                 .map_err(|error| format!("Could not parse the provided specification for cluster descriptor <{cluster_descriptor_id}>.\n {error}"))?;
             ResourceModel::ClusterDescriptor(cluster_descriptor)
-        }        
+        }
+        #[cfg(feature = "viper")]
+        Specification::ViperSourceDescriptorSpecification(ViperSourceDescriptorSpecification::V1(source)) => {
+            let source_id = specification_document.metadata.id;
+            let source = convert_document_to_viper_source(specification_document.metadata, source)
+                .map_err(|error| format!("Could not parse the provided specification for test suite source <{source_id}>.\n  {error}"))?;
+            ResourceModel::ViperSourceDescriptor(source)
+        }
+        #[cfg(feature = "viper")]
+        Specification::ViperRunDescriptorSpecification(ViperRunDescriptorSpecification::V1(run)) => {
+            let run_id = specification_document.metadata.id;
+            let run = convert_document_to_viper_run(specification_document.metadata, run)
+                .map_err(|error| format!("Could not parse the provided specification for test suite run <{run_id}>.\n  {error}"))?;
+            ResourceModel::ViperRunDescriptor(run)
+        }
+        #[cfg(feature = "viper")]
+        Specification::ViperRunDeploymentSpecification(ViperRunDeploymentSpecification::V1(deployment)) => {
+            let run_id = specification_document.metadata.id;
+            let deployment = convert_document_to_viper_run_deployment(specification_document.metadata, deployment)
+                .map_err(|error| format!("Could not parse the provided specification for test suite run deployment <{run_id}>.\n  {error}"))?;
+            ResourceModel::ViperRunDeployment(deployment)
+        }
+        #[cfg(not(feature = "viper"))]
+        Specification::ViperSourceDescriptorSpecification(_)
+        | Specification::ViperRunDescriptorSpecification(_)
+        | Specification::ViperRunDeploymentSpecification(_) => {
+            Err(format!("Could not apply the provided specification <{}>, because CLEO was built without support for test suites.", specification_document.metadata.id))?
+        }
     };
     Ok(result)
 }
@@ -197,6 +292,18 @@ mod tests {
         Ok(())
     }
     
+    #[test]
+    fn should_keep_the_stored_namespace_unless_moved() -> anyhow::Result<()> {
+        let stored_namespace = NamespaceName::try_from("stored")?;
+        let namespace = NamespaceName::try_from("selected")?;
+
+        assert_eq!(target_namespace(None, &namespace, false), namespace);
+        assert_eq!(target_namespace(Some(Clone::clone(&stored_namespace)), &namespace, false), stored_namespace);
+        assert_eq!(target_namespace(Some(Clone::clone(&stored_namespace)), &namespace, true), namespace);
+
+        Ok(())
+    }
+
     fn generate_peer_descriptor() -> anyhow::Result<PeerDescriptor> {
         Ok(PeerDescriptor {
             id: PeerId::random(),
@@ -214,6 +321,8 @@ mod tests {
             },
             topology: Topology { devices: vec![] },
             executors: ExecutorDescriptors { executors: vec![] },
+            revision: Default::default(),
+            namespace: Default::default(),
         })
     }
     
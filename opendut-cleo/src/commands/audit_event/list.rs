@@ -0,0 +1,116 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use cli_table::{print_stdout, Table, WithTitle};
+use serde::Serialize;
+
+use opendut_carl_api::carl::audit::ListAuditEventsRequest;
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::audit::AuditEvent;
+use crate::ListOutputFormat;
+
+/// List the audit events of mutating operations performed on CARL
+#[derive(clap::Parser)]
+pub struct ListAuditEventsCli {
+    /// Only list events recorded at or after this point in time, e.g. 2025-01-31T12:00:00Z
+    #[arg(long)]
+    since: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug)]
+struct SerializableAuditEvent {
+    timestamp: String,
+    user: Option<String>,
+    operation: String,
+    resources: Vec<String>,
+    outcome: String,
+}
+
+#[derive(Table)]
+struct AuditEventTable {
+    #[table(title = "Timestamp")]
+    timestamp: String,
+    #[table(title = "User")]
+    user: String,
+    #[table(title = "Operation")]
+    operation: String,
+    #[table(title = "Resources")]
+    resources: String,
+    #[table(title = "Outcome")]
+    outcome: String,
+}
+impl From<SerializableAuditEvent> for AuditEventTable {
+    fn from(event: SerializableAuditEvent) -> Self {
+        let SerializableAuditEvent { timestamp, user, operation, resources, outcome } = event;
+
+        AuditEventTable {
+            timestamp,
+            user: user.unwrap_or_else(|| String::from("-")),
+            operation,
+            resources: resources.join(", "),
+            outcome,
+        }
+    }
+}
+
+impl From<AuditEvent> for SerializableAuditEvent {
+    fn from(event: AuditEvent) -> Self {
+        SerializableAuditEvent {
+            timestamp: DateTime::<Utc>::from(event.timestamp).to_rfc3339_opts(SecondsFormat::Millis, true),
+            user: event.user,
+            operation: event.operation,
+            resources: event.resource_ids.iter()
+                .map(ToString::to_string)
+                .collect(),
+            outcome: event.outcome.to_string(),
+        }
+    }
+}
+
+impl ListAuditEventsCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: ListOutputFormat) -> crate::Result<()> {
+        let since = self.since.map(SystemTime::from);
+
+        let mut events = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = carl.audit.list_audit_events(ListAuditEventsRequest {
+                since,
+                page_size: ListAuditEventsRequest::MAX_PAGE_SIZE,
+                page_token,
+            }).await
+                .map_err(|error| format!("Could not list audit events.\n  {error}"))?;
+
+            events.extend(page.events);
+
+            match page.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => break,
+            }
+        }
+
+        let serializable_events = events.into_iter()
+            .map(SerializableAuditEvent::from)
+            .collect::<Vec<_>>();
+
+        match output {
+            ListOutputFormat::Table => {
+                let audit_event_table = serializable_events.into_iter()
+                    .map(AuditEventTable::from)
+                    .collect::<Vec<_>>();
+
+                print_stdout(audit_event_table.with_title())
+                    .expect("List of audit events should be printable as table.");
+            }
+            ListOutputFormat::Json => {
+                let json = serde_json::to_string(&serializable_events).unwrap();
+                println!("{json}");
+            }
+            ListOutputFormat::PrettyJson => {
+                let json = serde_json::to_string_pretty(&serializable_events).unwrap();
+                println!("{json}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -15,11 +15,19 @@ impl CreateClusterDeploymentCli {
         let id = self.id;
 
         let deployment = ClusterDeployment { id };
-        carl.cluster.store_cluster_deployment(deployment).await
+        let stored_deployment = carl.cluster.store_cluster_deployment(deployment).await
             .map_err(|error| format!("Could not create cluster deployment for ClusterID: '{id}'.\n  {error}"))?;
         match output {
             CreateOutputFormat::Text => {
                 println!("Successfully created cluster deployment for cluster <{id}>.");
+
+                let devices = carl.peers.list_devices().await
+                    .map_err(|error| format!("Error while trying to list devices.\n  {error}"))?;
+
+                println!("The following devices were resolved for the cluster:");
+                for device in devices.into_iter().filter(|device| stored_deployment.devices.contains(&device.id)) {
+                    println!("\x09{} <{}>", device.name, device.id);
+                }
             }
             CreateOutputFormat::Json => {
                 let json = serde_json::to_string(&id).unwrap();
@@ -1,9 +1,12 @@
 use std::collections::HashSet;
-use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterName};
+use std::path::PathBuf;
+use std::str::FromStr;
+use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterLink, ClusterName, ClusterTopology, DeviceCanRules, DeviceRestbusSimulation, DeviceSelector};
 use opendut_model::peer::PeerId;
-use opendut_model::specs::cluster::ClusterDescriptorSpecificationV1;
+use opendut_model::specs::cluster::{ClusterDescriptorSpecificationV1, ClusterTopologySpecification};
 use opendut_model::specs::SpecificationMetadata;
-use opendut_model::topology::{DeviceId};
+use opendut_model::topology::{DeviceId, DeviceTag};
+use opendut_model::util::can::{CanFrameRules, CanRateLimit, IllegalCanFrameRule};
 
 pub fn convert_document_to_cluster_descriptor(specification_metadata: SpecificationMetadata, cluster: ClusterDescriptorSpecificationV1) -> crate::Result<ClusterDescriptor>  {
     let SpecificationMetadata { id, name } = specification_metadata;
@@ -18,28 +21,113 @@ pub fn convert_document_to_cluster_descriptor(specification_metadata: Specificat
         .map(DeviceId::from).
         collect::<HashSet<_>>();
 
+    let device_selectors = cluster.device_selectors.into_iter()
+        .map(|selector| {
+            let tags = selector.tags.into_iter()
+                .map(DeviceTag::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| error.to_string())?;
+            Ok(DeviceSelector { tags })
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let topology = match cluster.topology {
+        ClusterTopologySpecification::Star => ClusterTopology::Star,
+        ClusterTopologySpecification::FullMesh => ClusterTopology::FullMesh,
+        ClusterTopologySpecification::Custom { links } => ClusterTopology::Custom {
+            links: links.into_iter()
+                .map(|link| ClusterLink { peer_a: PeerId::from(link.peer_a), peer_b: PeerId::from(link.peer_b) })
+                .collect(),
+        },
+    };
+
+    let can_rules = cluster.can_rules.into_iter()
+        .map(|device_rules| {
+            let rules = CanFrameRules {
+                accept: parse_can_rules(device_rules.accept)?,
+                reject: parse_can_rules(device_rules.reject)?,
+                rewrites: parse_can_rules(device_rules.rewrite)?,
+                rate_limit: device_rules.rate_limit.map(|frames_per_second| CanRateLimit { frames_per_second }),
+            };
+            rules.validate()
+                .map_err(|error| format!("The CAN rules of device <{}> are invalid: {error}", device_rules.device))?;
+            Ok(DeviceCanRules { device: DeviceId::from(device_rules.device), rules })
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let restbus_simulations = cluster.restbus_simulations.into_iter()
+        .map(|simulation| {
+            if simulation.arxml_file.is_empty() {
+                return Err(format!("The ARXML file of the restbus simulation for device <{}> must not be empty.", simulation.device));
+            }
+            Ok(DeviceRestbusSimulation {
+                device: DeviceId::from(simulation.device),
+                arxml_file: PathBuf::from(simulation.arxml_file),
+                ecus: simulation.ecus,
+            })
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
     let configuration = ClusterDescriptor {
         id,
         name,
         leader: PeerId::from(leader),
         devices,
+        device_selectors,
+        topology,
+        can_rules,
+        restbus_simulations,
+        revision: Default::default(),
+        namespace: Default::default(),
     };
     
     Ok(configuration)
 }
 
+fn parse_can_rules<T: FromStr<Err=IllegalCanFrameRule>>(values: Vec<String>) -> crate::Result<Vec<T>> {
+    values.iter()
+        .map(|value| T::from_str(value))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| error.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use googletest::prelude::*;
+    use opendut_model::specs::cluster::{ClusterLinkSpecification, DeviceCanRulesSpecification, DeviceRestbusSimulationSpecification, DeviceSelectorSpecification};
+    use opendut_model::util::can::{CanIdFilter, CanIdRewrite};
     
     #[test]
     fn should_convert_document_to_cluster_descriptor() -> anyhow::Result<()> {
+        let leader = PeerId::random();
+        let other_peer = PeerId::random();
+        let device = DeviceId::random();
         let cluster_descriptor = ClusterDescriptor {
             id: ClusterId::random(),
             name: ClusterName::try_from("FirstCluster")?,
-            leader: PeerId::random(),
+            leader,
             devices: Default::default(),
+            device_selectors: vec![
+                DeviceSelector { tags: vec![DeviceTag::try_from("powertrain")?, DeviceTag::try_from("hil-rig-3")?] },
+            ],
+            topology: ClusterTopology::Custom { links: vec![ClusterLink { peer_a: leader, peer_b: other_peer }] },
+            can_rules: vec![DeviceCanRules {
+                device,
+                rules: CanFrameRules {
+                    accept: vec![CanIdFilter::new(0x100, 0x700)?],
+                    reject: vec![CanIdFilter::exact(0x7df)?],
+                    rewrites: vec![CanIdRewrite::new(0x123, 0x523)?],
+                    rate_limit: Some(CanRateLimit { frames_per_second: 100 }),
+                },
+            }],
+            restbus_simulations: vec![DeviceRestbusSimulation {
+                device,
+                arxml_file: PathBuf::from("/etc/opendut/restbus.arxml"),
+                ecus: vec![String::from("Engine")],
+            }],
+            revision: Default::default(),
+            namespace: Default::default(),
         };
         
         let specification_meta_data = SpecificationMetadata {
@@ -50,6 +138,24 @@ mod tests {
         let document = ClusterDescriptorSpecificationV1 {
             leader_id: cluster_descriptor.leader.uuid,
             devices: vec![],
+            device_selectors: vec![
+                DeviceSelectorSpecification { tags: vec![String::from("powertrain"), String::from("hil-rig-3")] },
+            ],
+            topology: ClusterTopologySpecification::Custom {
+                links: vec![ClusterLinkSpecification { peer_a: leader.uuid, peer_b: other_peer.uuid }],
+            },
+            can_rules: vec![DeviceCanRulesSpecification {
+                device: device.uuid,
+                accept: vec![String::from("100:700")],
+                reject: vec![String::from("7DF")],
+                rewrite: vec![String::from("123->523")],
+                rate_limit: Some(100),
+            }],
+            restbus_simulations: vec![DeviceRestbusSimulationSpecification {
+                device: device.uuid,
+                arxml_file: String::from("/etc/opendut/restbus.arxml"),
+                ecus: vec![String::from("Engine")],
+            }],
         };
         
         let result = convert_document_to_cluster_descriptor(specification_meta_data, document).unwrap();
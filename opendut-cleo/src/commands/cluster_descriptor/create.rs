@@ -2,11 +2,12 @@ use std::collections::HashSet;
 use std::ops::Not;
 
 use opendut_carl_api::carl::CarlClient;
-use opendut_model::cluster::{ClusterDescriptor, ClusterId};
+use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterTopology};
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::PeerId;
 use opendut_model::topology::{DeviceDescriptor, DeviceId, DeviceName};
 
-use crate::parse::cluster::{ParseableClusterId, ParseableClusterName};
+use crate::parse::cluster::{ParseableClusterId, ParseableClusterLink, ParseableClusterName, ParseableDeviceSelector};
 use crate::{ClusterDescriptorDevices, CreateOutputFormat};
 
 /// Create a cluster descriptor
@@ -24,12 +25,26 @@ pub struct CreateClusterDescriptorCli {
     ///List of devices in cluster
     #[clap(flatten)]
     devices: ClusterDescriptorDevices,
+    ///Topology of the connections between the peers of the cluster
+    #[arg(long, value_enum, default_value_t = ClusterTopologyType::Star)]
+    topology: ClusterTopologyType,
+    ///Direct connection between two peers as `<PEER_ID>:<PEER_ID>`, required for a custom topology
+    #[arg(long = "link", value_name = "PEER_IDS", required_if_eq("topology", "custom"))]
+    links: Vec<ParseableClusterLink>,
+}
+
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum ClusterTopologyType {
+    Star,
+    FullMesh,
+    Custom,
 }
 
 impl CreateClusterDescriptorCli {
     pub async fn execute(
         self,
         carl: &mut CarlClient,
+        namespace: NamespaceName,
         output: CreateOutputFormat,
     ) -> crate::Result<()> {
         let ParseableClusterName(cluster_name) = self.name;
@@ -87,10 +102,27 @@ impl CreateClusterDescriptorCli {
             }
         }
 
-        if devices.len() < 2 {
-            return Err("Specify at least 2 devices per cluster descriptor.".to_string());
+        let device_selectors = self.devices.device_selectors.into_iter()
+            .map(|ParseableDeviceSelector(selector)| selector)
+            .collect::<Vec<_>>();
+
+        if device_selectors.is_empty() && devices.len() < 2 {
+            return Err("Specify at least 2 devices or a device selector per cluster descriptor.".to_string());
         }
 
+        let links = self.links.into_iter()
+            .map(|ParseableClusterLink(link)| link)
+            .collect::<Vec<_>>();
+
+        let topology = match self.topology {
+            ClusterTopologyType::Custom => ClusterTopology::Custom { links },
+            ClusterTopologyType::Star | ClusterTopologyType::FullMesh if links.is_empty().not() => {
+                return Err("Links between peers can only be specified for a custom topology.".to_string());
+            }
+            ClusterTopologyType::Star => ClusterTopology::Star,
+            ClusterTopologyType::FullMesh => ClusterTopology::FullMesh,
+        };
+
         let device_ids = devices.clone().into_iter()
             .map(|device| device.id)
             .collect::<HashSet<_>>();
@@ -98,8 +130,14 @@ impl CreateClusterDescriptorCli {
         let descriptor = ClusterDescriptor {
             id: cluster_id, 
             name: Clone::clone(&cluster_name), 
-            leader, 
-            devices: device_ids 
+            leader,
+            devices: device_ids,
+            device_selectors,
+            topology,
+            can_rules: Vec::new(),
+            restbus_simulations: Vec::new(),
+            revision: Default::default(),
+            namespace,
         };
         
         create_cluster_descriptor(descriptor, carl, &output).await?;
@@ -115,9 +153,11 @@ pub async fn create_cluster_descriptor(cluster: ClusterDescriptor, carl: &mut Ca
     let devices = carl.peers.list_devices().await
         .map_err(|error| format!("Error while trying to list devices.\n  {error}"))?;
 
+    let cluster_devices = cluster.resolve_devices(&devices);
+
     let device_names = devices.into_iter()
         .filter(|device| {
-            cluster.devices.contains(&device.id)
+            cluster_devices.contains(&device.id)
         })
         .map(|device| device.name)
         .collect::<Vec<_>>();
@@ -128,6 +168,13 @@ pub async fn create_cluster_descriptor(cluster: ClusterDescriptor, carl: &mut Ca
         
             println!("ClusterID: {}", cluster.id);
             println!("Name of the Cluster: {}", cluster.name);
+            println!("Topology: {}", cluster.topology);
+            if cluster.device_selectors.is_empty().not() {
+                println!("Devices are additionally selected by:");
+                for device_selector in cluster.device_selectors.iter() {
+                    println!("\x09{device_selector}");
+                };
+            }
             println!("The following devices are part of the cluster descriptor:");
             for device_name in device_names.iter() {
                 println!("\x09{device_name}");
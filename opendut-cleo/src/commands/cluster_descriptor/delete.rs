@@ -1,6 +1,7 @@
 use opendut_carl_api::carl::cluster::DeleteClusterDescriptorError;
 use opendut_carl_api::carl::{CarlClient, ClientError};
 use opendut_model::cluster::ClusterId;
+use opendut_model::namespace::NamespaceName;
 
 /// Delete a cluster descriptor
 #[derive(clap::Parser)]
@@ -14,7 +15,7 @@ pub struct DeleteClusterDescriptorCli {
 }
 
 impl DeleteClusterDescriptorCli {
-    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName) -> crate::Result<()> {
         let id = self.id;
 
         let cluster_deployments = carl.cluster.list_cluster_deployments().await
@@ -24,14 +25,14 @@ impl DeleteClusterDescriptorCli {
             Err(format!("Cluster <{id}> can not be deleted while it is deployed."))?
         };
         
-        match carl.cluster.delete_cluster_descriptor(id).await {
+        match carl.cluster.delete_cluster_descriptor_in_namespace(id, Clone::clone(&namespace)).await {
             Ok(cluster_descriptor) => {
                 eprintln!("Deleted ClusterDescriptor {} <{}> successfully.", cluster_descriptor.name, cluster_descriptor.id);
                 Ok(())
             }
             Err(error) => match error {
                 ClientError::UsageError(DeleteClusterDescriptorError::ClusterDescriptorNotFound { .. }) => {
-                    eprintln!("No cluster descriptor found with ID <{id}>.");
+                    eprintln!("No cluster descriptor found with ID <{id}> in namespace '{namespace}'.");
                     if self.error_when_missing {
                         Err(error)
                     } else {
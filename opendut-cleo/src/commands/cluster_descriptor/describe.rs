@@ -1,7 +1,8 @@
 use indoc::indoc;
 
 use opendut_carl_api::carl::CarlClient;
-use opendut_model::cluster::{ClusterId, ClusterName};
+use opendut_model::cluster::{ClusterId, ClusterName, ClusterTopology, DeviceCanRules, DeviceRestbusSimulation, DeviceSelector};
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::{PeerId, PeerName};
 use opendut_model::topology::DeviceName;
 use serde::Serialize;
@@ -24,30 +25,31 @@ struct ClusterTable {
     leader: PeerId,
     peers: Vec<PeerName>,
     devices: Vec<DeviceName>,
+    device_selectors: Vec<DeviceSelector>,
+    topology: ClusterTopology,
+    can_rules: Vec<DeviceCanRules>,
+    restbus_simulations: Vec<DeviceRestbusSimulation>,
 }
 
 impl DescribeClusterDescriptorCli {
-    pub async fn execute(self, carl: &mut CarlClient, output: DescribeOutputFormat) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: DescribeOutputFormat) -> crate::Result<()> {
         let cluster_id = self.id;
 
-        let clusters_configuration = carl.cluster.list_cluster_descriptors().await
-            .map_err(|_| String::from("Failed to get list of cluster descriptors!"))?;
-
-        let cluster_descriptor = clusters_configuration.into_iter()
-            .find(|cluster_descriptor| cluster_descriptor.id == cluster_id)
-            .ok_or(format!("Failed to find cluster descriptor for ClusterID <{cluster_id}>"))?;
+        let cluster_descriptor = carl.cluster.get_cluster_descriptor_in_namespace(cluster_id, Clone::clone(&namespace)).await
+            .map_err(|_| format!("Failed to find cluster descriptor for ClusterID <{cluster_id}> in namespace '{namespace}'"))?;
 
         let cluster_devices = {
             let devices = carl.peers.list_devices().await
                 .map_err(|_| String::from("Failed to get list of devices!"))?;
+            let cluster_device_ids = cluster_descriptor.resolve_devices(&devices);
             devices.into_iter()
-                .filter(|device| cluster_descriptor.devices.contains(&device.id))
+                .filter(|device| cluster_device_ids.contains(&device.id))
                 .map(|devices| devices.name)
                 .collect::<Vec<_>>()
         };
 
         let cluster_peers = {
-            let peers = carl.peers.list_peer_descriptors().await
+            let peers = carl.peers.list_peer_descriptors_in_namespace(namespace).await
                 .map_err(|_| String::from("Failed to get list of peers!"))?;
             peers.into_iter()
                 .filter(|peer| {
@@ -63,6 +65,10 @@ impl DescribeClusterDescriptorCli {
             leader: cluster_descriptor.leader,
             peers: cluster_peers,
             devices: cluster_devices,
+            device_selectors: cluster_descriptor.device_selectors,
+            topology: cluster_descriptor.topology,
+            can_rules: cluster_descriptor.can_rules,
+            restbus_simulations: cluster_descriptor.restbus_simulations,
         };
 
         let text = match output {
@@ -73,7 +79,15 @@ impl DescribeClusterDescriptorCli {
                   Leader: {}
                   Peers: [{:?}]
                   Devices: [{:?}]
-            "), table.name, table.id, table.leader, table.peers, table.devices)
+                  Device Selectors: [{}]
+                  Topology: {}
+                  CAN Rules: [{}]
+                  Restbus Simulations: [{}]
+            "), table.name, table.id, table.leader, table.peers, table.devices,
+                table.device_selectors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+                table.topology,
+                table.can_rules.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+                table.restbus_simulations.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))
             }
             DescribeOutputFormat::Json => {
                 serde_json::to_string(&table).unwrap()
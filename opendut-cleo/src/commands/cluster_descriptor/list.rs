@@ -2,10 +2,11 @@ use cli_table::{print_stdout, Table, WithTitle};
 
 use opendut_carl_api::carl::CarlClient;
 use opendut_model::cluster::{ClusterId, ClusterName};
+use opendut_model::namespace::NamespaceName;
 
 use crate::ListOutputFormat;
 
-/// List all cluster descriptors
+/// List all cluster descriptors of the namespace
 #[derive(clap::Parser)]
 pub struct ListClusterDescriptorsCli;
 
@@ -18,8 +19,8 @@ struct ClusterTable {
 }
 
 impl ListClusterDescriptorsCli {
-    pub async fn execute(self, carl: &mut CarlClient, output: ListOutputFormat) -> crate::Result<()> {
-        let clusters = carl.cluster.list_cluster_descriptors().await
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: ListOutputFormat) -> crate::Result<()> {
+        let clusters = carl.cluster.list_cluster_descriptors_in_namespace(namespace).await
             .map_err(|error| format!("Could not list any cluster descriptors.\n  {error}"))?;
 
         match output {
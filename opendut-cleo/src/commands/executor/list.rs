@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
 use cli_table::{print_stdout, Table, WithTitle};
-use opendut_model::peer::executor::ExecutorDescriptor;
+use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorId};
+use opendut_model::peer::executor::state::ExecutorStatus;
 use serde::Serialize;
 use uuid::Uuid;
 
@@ -24,7 +27,14 @@ impl ListContainerExecutorCli {
             .peers.get_peer_descriptor(peer_id)
             .await
             .map_err(|error| format!("Could not find peer.\n  {error}"))?;
-        let executor_table = filter_connected_peers(&peer);
+        let executor_states = carl
+            .peers.list_executor_states(peer_id)
+            .await
+            .map_err(|error| format!("Could not list executor states of peer.\n  {error}"))?
+            .into_iter()
+            .map(|state| (state.id, state.status))
+            .collect::<HashMap<_, _>>();
+        let executor_table = filter_connected_peers(&peer, &executor_states);
 
         match output {
             ListOutputFormat::Table => {
@@ -46,10 +56,11 @@ impl ListContainerExecutorCli {
 
 fn filter_connected_peers(
     peer: &PeerDescriptor,
+    executor_states: &HashMap<ExecutorId, ExecutorStatus>,
 ) -> Vec<ContainerExecutorTable> {
     let mut executor_table = vec![];
     for executor in &peer.executors.executors {
-        let ExecutorDescriptor { id: _, kind, results_url } = executor;
+        let ExecutorDescriptor { id, kind, results_url } = executor;
         
         if let ExecutorKind::Container {
             engine,
@@ -79,6 +90,7 @@ fn filter_connected_peers(
                 command: command.into(),
                 args: args.join(", "),
                 results_url: results_url.clone().map_or("None".to_string(), |results_url| results_url.into()),
+                status: executor_states.get(id).map_or("Unknown".to_string(), |status| status.to_string()),
             });
         }
     };
@@ -107,4 +119,6 @@ struct ContainerExecutorTable {
     args: String,
     #[table(title = "Results URL")]
     results_url: String,
+    #[table(title = "Status")]
+    status: String,
 }
@@ -1,4 +1,5 @@
 pub mod apply;
+pub mod audit_event;
 pub mod cluster_deployment;
 pub mod cluster_descriptor;
 pub mod device;
@@ -10,3 +11,9 @@ pub mod generate_setup_string;
 pub mod completions;
 pub mod setup;
 pub mod wait;
+#[cfg(feature = "viper")]
+pub mod viper_run;
+#[cfg(feature = "viper")]
+pub mod viper_run_deployment;
+#[cfg(feature = "viper")]
+pub mod viper_source;
@@ -1,7 +1,8 @@
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
 use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorDescriptors, ExecutorId, ExecutorKind, ResultsUrl};
 use opendut_model::peer::executor::container::{ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine};
-use opendut_model::specs::peer::{DeviceSpecificationV1, NetworkInterfaceDescriptorSpecificationV1, NetworkInterfaceKind, PeerDescriptorSpecificationV1, ExecutorSpecificationV1, SpecificationEngineKind, SpecificationExecutorKind};
+use opendut_model::peer::executor::executable::{ExecutableArgument, ExecutablePath, ExecutableRestartPolicy, ExecutableWorkingDirectory};
+use opendut_model::specs::peer::{DeviceSpecificationV1, NetworkInterfaceDescriptorSpecificationV1, NetworkInterfaceKind, PeerDescriptorSpecificationV1, ExecutorSpecificationV1, ExecutorParametersSpecification, SpecificationEngineKind, SpecificationRestartPolicy};
 use opendut_model::specs::SpecificationMetadata;
 use opendut_model::topology::{DeviceDescription, DeviceDescriptor, DeviceId, DeviceName, DeviceTag, Topology};
 use opendut_model::util::net::{CanSamplePoint, NetworkInterfaceConfiguration, NetworkInterfaceDescriptor, NetworkInterfaceId, NetworkInterfaceName};
@@ -49,6 +50,8 @@ pub fn convert_document_to_peer_descriptor(specification_metadata: Specification
         executors: ExecutorDescriptors {
             executors,
         },
+        revision: Default::default(),
+        namespace: Default::default(),
     };
     Ok(descriptor)
 }
@@ -117,10 +120,43 @@ fn convert_executor_specification_to_descriptor(specification: ExecutorSpecifica
         .transpose()
         .map_err(| error | format!("Could not apply the provided results url for the executor <{}>: {}", specification.id, error))?;
 
-    let kind = match specification.kind {
-        SpecificationExecutorKind::Executable => ExecutorKind::Executable,
-        SpecificationExecutorKind::Container => {
-            match specification.parameters {
+    let kind = match specification.parameters {
+        ExecutorParametersSpecification::Executable(parameters) => {
+            match parameters {
+                Some(parameters) => {
+                    let path = ExecutablePath::try_from(parameters.path)
+                        .map_err(|error| format!("Could not use the provided path for executable executor <{}>: {}", specification.id, error))?;
+                    let args = parameters.args.into_iter().map(|arg|
+                        ExecutableArgument::try_from(arg)
+                            .map_err(|error| format!("Could not use the provided arguments for executable executor <{}>: {}", specification.id, error))
+                    ).collect::<Result<Vec<_>, _>>()?;
+                    let envs = parameters.envs.into_iter().map(|envs|
+                        ContainerEnvironmentVariable::new(envs.name, envs.value)
+                            .map_err(|error| format!("Could not apply the provided environment variables for executable executor <{}>: {}", specification.id, error))
+                    ).collect::<Result<Vec<_>, _>>()?;
+                    let working_dir = parameters.working_dir
+                        .map(ExecutableWorkingDirectory::try_from)
+                        .transpose()
+                        .map_err(|error| format!("Could not use the provided working directory for executable executor <{}>: {}", specification.id, error))?;
+                    let restart_policy = match parameters.restart_policy {
+                        SpecificationRestartPolicy::Never => ExecutableRestartPolicy::Never,
+                        SpecificationRestartPolicy::OnFailure => ExecutableRestartPolicy::OnFailure,
+                        SpecificationRestartPolicy::Always => ExecutableRestartPolicy::Always,
+                    };
+
+                    ExecutorKind::Executable {
+                        path,
+                        args,
+                        envs,
+                        working_dir,
+                        restart_policy,
+                    }
+                }
+                None => Err(String::from("Parameters for the executable executor were not provided."))?,
+            }
+        }
+        ExecutorParametersSpecification::Container(parameters) => {
+            match parameters {
                 Some(parameters) => {
                     let engine = match parameters.engine {
                         SpecificationEngineKind::Docker => Engine::Docker,
@@ -192,7 +228,7 @@ mod tests {
     use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorId, ExecutorKind, ResultsUrl};
     use opendut_model::peer::executor::container::{ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine};
     use opendut_model::peer::executor::container::ContainerName::Empty;
-    use opendut_model::specs::peer::{DeviceSpecificationV1, ExecutorConfigurationSpecification, NetworkInterfaceConfigurationSpecification, NetworkInterfaceDescriptorSpecificationV1, NetworkInterfaceKind, TopologySpecificationV1, ExecutorSpecificationV1, NetworkDescriptorSpecificationV1, SpecificationEngineKind, SpecificationExecutorKind, SpecificationEnvVariable};
+    use opendut_model::specs::peer::{DeviceSpecificationV1, ExecutableConfigurationSpecification, ExecutorConfigurationSpecification, NetworkInterfaceConfigurationSpecification, NetworkInterfaceDescriptorSpecificationV1, NetworkInterfaceKind, TopologySpecificationV1, ExecutorSpecificationV1, NetworkDescriptorSpecificationV1, SpecificationEngineKind, SpecificationEnvVariable};
     use opendut_model::topology::{DeviceDescription, DeviceDescriptor, DeviceId, DeviceName, DeviceTag};
     use opendut_model::util::net::{NetworkInterfaceConfiguration, NetworkInterfaceDescriptor, NetworkInterfaceId, NetworkInterfaceName};
 
@@ -387,8 +423,25 @@ mod tests {
                         ],
                     },
                     results_url: Some(ResultsUrl::try_from("https://example.com/webdav/results/")?),
-                }
+                },
+                ExecutorDescriptor {
+                    id: ExecutorId::random(),
+                    kind: ExecutorKind::Executable {
+                        path: ExecutablePath::try_from("/opt/tests/run-tests")?,
+                        args: vec![
+                            ExecutableArgument::try_from("--verbose")?,
+                        ],
+                        envs: vec![
+                            ContainerEnvironmentVariable::new(String::from("ENV_NAME"), String::from("EnvValue"))?
+                        ],
+                        working_dir: Some(ExecutableWorkingDirectory::try_from("/opt/tests/")?),
+                        restart_policy: ExecutableRestartPolicy::OnFailure,
+                    },
+                    results_url: Some(ResultsUrl::try_from("https://example.com/webdav/results/")?),
+                },
             ] },
+            revision: Default::default(),
+            namespace: Default::default(),
         })
     }
     
@@ -429,20 +482,32 @@ mod tests {
 
     fn convert_executor_descriptor_to_specification(executor: ExecutorDescriptor) -> anyhow::Result<ExecutorSpecificationV1> {
 
-        let executor_kind = match executor.kind {
-            ExecutorKind::Executable => {
-                SpecificationExecutorKind::Executable
-            }
-            ExecutorKind::Container { .. } => {
-                SpecificationExecutorKind::Container
-            }
-        };
-
         let executor_result_url = executor.results_url.map(String::from
         );
 
         let executor_parameters = match executor.kind {
-            ExecutorKind::Executable => unimplemented!("executable not implemented"),
+            ExecutorKind::Executable { path, args, envs, working_dir, restart_policy } => {
+                let spec_restart_policy = match restart_policy {
+                    ExecutableRestartPolicy::Never => SpecificationRestartPolicy::Never,
+                    ExecutableRestartPolicy::OnFailure => SpecificationRestartPolicy::OnFailure,
+                    ExecutableRestartPolicy::Always => SpecificationRestartPolicy::Always,
+                };
+
+                ExecutorParametersSpecification::Executable(Some(ExecutableConfigurationSpecification {
+                    path: String::from(path),
+                    args: args.into_iter().map(String::from).collect(),
+                    envs: envs.into_iter()
+                        .map(|env_variable|
+                            SpecificationEnvVariable {
+                                name: String::from(env_variable.name()),
+                                value: String::from(env_variable.value()),
+                            }
+                        )
+                        .collect(),
+                    working_dir: working_dir.map(String::from),
+                    restart_policy: spec_restart_policy,
+                }))
+            }
             ExecutorKind::Container { engine, name, image, volumes, devices, envs, ports, command, args } => {
                 let spec_engine_kind = match engine {
                     Engine::Docker => SpecificationEngineKind::Docker,
@@ -481,7 +546,7 @@ mod tests {
                     .map(String::from)
                     .collect::<Vec<_>>();
                 
-                ExecutorConfigurationSpecification {
+                ExecutorParametersSpecification::Container(Some(ExecutorConfigurationSpecification {
                     engine: spec_engine_kind,
                     name: spec_executor_name,
                     image: spec_executor_image,
@@ -491,15 +556,14 @@ mod tests {
                     ports: spec_ports,
                     command: spec_command,
                     command_args: spec_args,
-                }
+                }))
             }
         };
 
         Ok(ExecutorSpecificationV1 {
             id: executor.id.uuid,
             results_url: executor_result_url,
-            kind: executor_kind,
-            parameters: Some(executor_parameters)
+            parameters: executor_parameters,
         })
     }
 }
@@ -2,6 +2,7 @@ use console::Style;
 
 use crate::{CreateOutputFormat};
 use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
 use opendut_model::peer::executor::{ExecutorDescriptors};
 use opendut_model::util::net::NetworkInterfaceName;
@@ -26,7 +27,7 @@ pub struct CreatePeerCli {
 }
 
 impl CreatePeerCli {
-    pub async fn execute(self, carl: &mut CarlClient, output: CreateOutputFormat) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: CreateOutputFormat) -> crate::Result<()> {
         let id = self.id.unwrap_or_else(PeerId::random);
 
         let name = self.name;
@@ -44,7 +45,9 @@ impl CreatePeerCli {
             topology: Default::default(),
             executors: ExecutorDescriptors {
                 executors: vec![],
-            }
+            },
+            revision: Default::default(),
+            namespace,
         };
 
         create_peer(descriptor, carl, &output).await?;
@@ -4,6 +4,7 @@ use std::ops::Not;
 use opendut_carl_api::carl::peer::GetPeerDescriptorError;
 use opendut_carl_api::carl::{CarlClient, ClientError};
 use opendut_model::cluster::ClusterId;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::PeerId;
 
 /// Delete a peer
@@ -18,14 +19,14 @@ pub struct DeletePeerCli {
 }
 
 impl DeletePeerCli {
-    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName) -> crate::Result<()> {
         let id = self.id;
 
-        let peer_descriptor = match carl.peers.get_peer_descriptor(id).await {
+        let peer_descriptor = match carl.peers.get_peer_descriptor_in_namespace(id, Clone::clone(&namespace)).await {
             Ok(peer_descriptor) => Ok(peer_descriptor),
             Err(error) => match error {
                 ClientError::UsageError(GetPeerDescriptorError::PeerNotFound { .. }) => {
-                    eprintln!("No peer descriptor found with ID <{id}>.");
+                    eprintln!("No peer descriptor found with ID <{id}> in namespace '{namespace}'.");
                     if self.error_when_missing {
                         Err(error)
                     } else {
@@ -43,7 +44,7 @@ impl DeletePeerCli {
                 .collect::<Vec<_>>();
 
             let clusters = carl.cluster
-                .list_cluster_descriptors()
+                .list_cluster_descriptors_in_namespace(Clone::clone(&namespace))
                 .await
                 .map_err(|error| format!("Failed to list cluster descriptors.\n  {error}"))?;
 
@@ -61,7 +62,7 @@ impl DeletePeerCli {
             }
         }
         
-        carl.peers.delete_peer_descriptor(id).await
+        carl.peers.delete_peer_descriptor_in_namespace(id, namespace).await
             .map_err(|error| format!("Failed to delete peer with the id '{id}'.\n  {error}"))?;
 
         println!("Deleted peer with the PeerID: {id}");
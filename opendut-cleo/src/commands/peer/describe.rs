@@ -1,6 +1,7 @@
 use indoc::indoc;
 
 use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::{PeerDescriptor, PeerId};
 use crate::DescribeOutputFormat;
 
@@ -13,12 +14,12 @@ pub struct DescribePeerCli {
 }
 
 impl DescribePeerCli {
-    pub async fn execute(self, carl: &mut CarlClient, output: DescribeOutputFormat) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: DescribeOutputFormat) -> crate::Result<()> {
         let peer_id = self.id;
         
         let peer_descriptor =
-            carl.peers.get_peer_descriptor(peer_id).await.map_err(|_| {
-                format!("Failed to retrieve peer descriptor for peer <{peer_id}>")
+            carl.peers.get_peer_descriptor_in_namespace(peer_id, Clone::clone(&namespace)).await.map_err(|_| {
+                format!("Failed to retrieve peer descriptor for peer <{peer_id}> in namespace '{namespace}'")
             })?;
 
         render_peer_descriptor(peer_descriptor, output);
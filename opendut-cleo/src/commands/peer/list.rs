@@ -4,11 +4,12 @@ use cli_table::{print_stdout, Table, WithTitle};
 use serde::Serialize;
 
 use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName};
 use opendut_model::peer::state::{PeerConnectionState, PeerState};
 use crate::ListOutputFormat;
 
-/// List all peers
+/// List all peers of the namespace
 #[derive(clap::Parser)]
 pub struct ListPeersCli;
 
@@ -74,10 +75,10 @@ impl Display for PeerStatus {
 }
 
 impl ListPeersCli {
-    pub async fn execute(self, carl: &mut CarlClient, output: ListOutputFormat) -> crate::Result<()> {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: ListOutputFormat) -> crate::Result<()> {
         let all_peer_descriptors = carl
             .peers
-            .list_peer_descriptors()
+            .list_peer_descriptors_in_namespace(namespace)
             .await
             .map_err(|error| format!("Could not list peers.\n  {error}"))?;
         let all_peer_states = carl.peers.list_peer_states().await
@@ -157,7 +158,9 @@ mod test {
             topology: Default::default(),
             executors: ExecutorDescriptors {
                 executors: vec![]
-            }
+            },
+            revision: Default::default(),
+            namespace: Default::default(),
         };
         assert_that!(
             add_peer_status(peer.clone(), PeerState::default()),
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use opendut_model::specs::viper::ViperRunDescriptorSpecificationV1;
+use opendut_model::specs::SpecificationMetadata;
+use opendut_model::viper::{ViperRunId, ViperSourceId};
+use opendut_viper_rt::common::TestSuiteIdentifier;
+
+use crate::commands::viper_run::create::UnboundViperRunDescriptor;
+
+pub fn convert_document_to_viper_run(specification_metadata: SpecificationMetadata, run: ViperRunDescriptorSpecificationV1) -> crate::Result<UnboundViperRunDescriptor> {
+    let SpecificationMetadata { id, name: _ } = specification_metadata;
+
+    let suite = TestSuiteIdentifier::try_from(run.suite)
+        .map_err(|error| error.to_string())?;
+
+    let mut parameters = run.parameters.into_iter()
+        .map(|(name, value)| (name, value.to_string()))
+        .collect::<Vec<_>>();
+    parameters.sort();
+
+    Ok(UnboundViperRunDescriptor {
+        id: ViperRunId::from(id),
+        source: ViperSourceId::from(run.source_id),
+        suite,
+        parameters,
+        timeout: run.timeout.map(Duration::from_secs),
+        namespace: Default::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use googletest::prelude::*;
+    use opendut_model::specs::viper::ViperRunParameterValueSpecification;
+
+    use super::*;
+
+    #[test]
+    fn should_convert_document_to_viper_run() -> anyhow::Result<()> {
+        let run_id = ViperRunId::random();
+        let source_id = ViperSourceId::random();
+
+        let specification_metadata = SpecificationMetadata {
+            id: run_id.uuid,
+            name: String::from("nightly-regression"),
+        };
+
+        let document = ViperRunDescriptorSpecificationV1 {
+            source_id: source_id.uuid,
+            suite: String::from("regression"),
+            parameters: HashMap::from([
+                (String::from("verbose"), ViperRunParameterValueSpecification::Boolean(true)),
+                (String::from("retries"), ViperRunParameterValueSpecification::Number(3)),
+                (String::from("target"), ViperRunParameterValueSpecification::Text(String::from("hil-rig"))),
+            ]),
+            timeout: Some(300),
+        };
+
+        let result = convert_document_to_viper_run(specification_metadata, document).unwrap();
+
+        assert_that!(result.id, eq(run_id));
+        assert_that!(result.source, eq(source_id));
+        assert_that!(result.suite.to_string(), eq("regression"));
+        assert_that!(result.parameters, eq(&vec![
+            (String::from("retries"), String::from("3")),
+            (String::from("target"), String::from("hil-rig")),
+            (String::from("verbose"), String::from("true")),
+        ]));
+        assert_that!(result.timeout, some(eq(Duration::from_secs(300))));
+
+        Ok(())
+    }
+}
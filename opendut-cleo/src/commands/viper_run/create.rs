@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use console::Style;
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperSourceId};
+use opendut_viper_rt::common::TestSuiteIdentifier;
+
+use crate::commands::viper_run::parameters::bind_parameters;
+use crate::commands::viper_run::SerializableViperRun;
+use crate::parse::viper::{ParseableTestSuiteIdentifier, ParseableViperRunParameter};
+use crate::CreateOutputFormat;
+
+/// Create a test suite run
+#[derive(clap::Parser)]
+pub struct CreateViperRunCli {
+    ///ID of the test suite source
+    #[arg(short, long)]
+    source_id: ViperSourceId,
+    ///Name of the test suite in the source
+    #[arg(long)]
+    suite: ParseableTestSuiteIdentifier,
+    ///Value for a parameter of the test suite as `<NAME>=<VALUE>`
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    parameters: Vec<ParseableViperRunParameter>,
+    ///Time limit for each test in seconds, overriding the time limits declared in the test suite
+    #[arg(long)]
+    timeout: Option<u64>,
+    ///ID of the test suite run
+    #[arg(short, long)]
+    id: Option<ViperRunId>,
+}
+
+/// A test suite run, whose parameter values have not yet been validated against the parameters of the test suite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnboundViperRunDescriptor {
+    pub id: ViperRunId,
+    pub source: ViperSourceId,
+    pub suite: TestSuiteIdentifier,
+    pub parameters: Vec<(String, String)>,
+    pub timeout: Option<Duration>,
+    pub namespace: NamespaceName,
+}
+
+impl CreateViperRunCli {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: CreateOutputFormat) -> crate::Result<()> {
+        let ParseableTestSuiteIdentifier(suite) = self.suite;
+
+        let run = UnboundViperRunDescriptor {
+            id: self.id.unwrap_or_else(ViperRunId::random),
+            source: self.source_id,
+            suite,
+            parameters: self.parameters.into_iter()
+                .map(|parameter| (parameter.name, parameter.value))
+                .collect(),
+            timeout: self.timeout.map(Duration::from_secs),
+            namespace,
+        };
+
+        create_viper_run(run, carl, &output).await
+    }
+}
+
+pub async fn create_viper_run(run: UnboundViperRunDescriptor, carl: &mut CarlClient, output: &CreateOutputFormat) -> crate::Result<()> {
+    let UnboundViperRunDescriptor { id, source, suite, parameters, timeout, namespace } = run;
+
+    let deployments = carl.test_suite.list_viper_run_deployments().await
+        .map_err(|_| String::from("Failed to get list of test suite run deployments!"))?;
+    if deployments.into_iter().any(|deployment| deployment.id == id) {
+        Err(format!("Test suite run <{id}> can not be updated while it is deployed."))?
+    };
+
+    let parameter_descriptors = carl.test_suite.inspect_viper_test_suite(source, Clone::clone(&suite)).await
+        .map_err(|error| format!("Failed to determine the parameters of test suite '{suite}' in source <{source}>.\n  {error}"))?;
+
+    let parameters = bind_parameters(parameter_descriptors, parameters)
+        .map_err(|error| format!("Invalid parameters for test suite '{suite}'.\n  {error}"))?;
+
+    let descriptor = ViperRunDescriptor {
+        id,
+        source,
+        suite,
+        parameters,
+        timeout,
+        namespace,
+    };
+
+    carl.test_suite.store_viper_run_descriptor(Clone::clone(&descriptor)).await
+        .map_err(|error| format!("Failed to create test suite run.\n  {error}"))?;
+
+    match output {
+        CreateOutputFormat::Text => {
+            let bold = Style::new().bold();
+            println!(
+                "Created the test suite run of '{}' with the ID: <{}>",
+                descriptor.suite,
+                bold.apply_to(descriptor.id)
+            );
+        }
+        CreateOutputFormat::Json => {
+            let json = serde_json::to_string(&SerializableViperRun::from(descriptor)).unwrap();
+            println!("{json}");
+        }
+        CreateOutputFormat::PrettyJson => {
+            let json = serde_json::to_string_pretty(&SerializableViperRun::from(descriptor)).unwrap();
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
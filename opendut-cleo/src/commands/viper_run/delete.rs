@@ -0,0 +1,45 @@
+use opendut_carl_api::carl::viper::DeleteViperRunDescriptorError;
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::viper::ViperRunId;
+
+/// Delete a test suite run
+#[derive(clap::Parser)]
+pub struct DeleteViperRunCli {
+    /// ID of the test suite run
+    #[arg()]
+    id: ViperRunId,
+    /// Report an error status code when the resource to delete does not exist
+    #[arg(long)]
+    error_when_missing: bool,
+}
+
+impl DeleteViperRunCli {
+    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+        let id = self.id;
+
+        let deployments = carl.test_suite.list_viper_run_deployments().await
+            .map_err(|_| String::from("Failed to get list of test suite run deployments!"))?;
+
+        if deployments.iter().any(|deployment| deployment.id == id) {
+            Err(format!("Test suite run <{id}> can not be deleted while it is deployed."))?
+        };
+
+        match carl.test_suite.delete_viper_run_descriptor(id).await {
+            Ok(_) => {
+                eprintln!("Deleted test suite run <{id}> successfully.");
+                Ok(())
+            }
+            Err(error) => match error {
+                ClientError::UsageError(DeleteViperRunDescriptorError::RunNotFound { .. }) => {
+                    eprintln!("No test suite run found with ID <{id}>.");
+                    if self.error_when_missing {
+                        Err(error)
+                    } else {
+                        Ok(())
+                    }
+                }
+                other => Err(other),
+            }
+        }.map_err(|error| format!("Failed to delete test suite run with ID <{id}>.\n  {error}"))
+    }
+}
@@ -0,0 +1,92 @@
+use indoc::indoc;
+use serde::Serialize;
+
+use opendut_carl_api::carl::viper::{GetViperRunDeploymentError, GetViperRunStateError};
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::cluster::ClusterId;
+use opendut_model::viper::ViperRunId;
+
+use crate::commands::viper_run::{format_parameter_value, format_status, SerializableViperRun};
+use crate::DescribeOutputFormat;
+
+/// Describe a test suite run
+#[derive(clap::Parser)]
+pub struct DescribeViperRunCli {
+    /// ID of the test suite run
+    #[arg()]
+    id: ViperRunId,
+}
+
+#[derive(Serialize)]
+struct RunTable {
+    #[serde(flatten)]
+    run: SerializableViperRun,
+    cluster: Option<ClusterId>,
+    status: Option<String>,
+}
+
+impl DescribeViperRunCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: DescribeOutputFormat) -> crate::Result<()> {
+        let run_id = self.id;
+
+        let run = carl.test_suite.get_viper_run_descriptor(run_id).await
+            .map_err(|error| format!("Failed to retrieve test suite run <{run_id}>.\n  {error}"))?;
+
+        let cluster = match carl.test_suite.get_viper_run_deployment(run_id).await {
+            Ok(deployment) => Some(deployment.cluster),
+            Err(ClientError::UsageError(GetViperRunDeploymentError::RunDeploymentNotFound { .. })) => None,
+            Err(error) => Err(format!("Failed to retrieve deployment of test suite run <{run_id}>.\n  {error}"))?,
+        };
+
+        let status = match carl.test_suite.get_viper_run_state(run_id).await {
+            Ok(state) => Some(format_status(&state.status)),
+            Err(ClientError::UsageError(GetViperRunStateError::StateNotFound { .. })) => None,
+            Err(error) => Err(format!("Failed to retrieve state of test suite run <{run_id}>.\n  {error}"))?,
+        };
+
+        let mut parameters = run.parameters.iter()
+            .map(|(key, value)| format!("{}={}", key.inner, format_parameter_value(value)))
+            .collect::<Vec<_>>();
+        parameters.sort();
+
+        let table = RunTable {
+            run: SerializableViperRun::from(run),
+            cluster,
+            status,
+        };
+
+        let text = match output {
+            DescribeOutputFormat::Text => {
+                format!(indoc!("
+                    Test Suite Run: {}
+                      Id: {}
+                      Source: {}
+                      Parameters: [{}]
+                      Timeout: {}
+                      Namespace: {}
+                      Cluster: {}
+                      Status: {}\
+                "),
+                    table.run.suite,
+                    table.run.id,
+                    table.run.source,
+                    parameters.join(", "),
+                    table.run.timeout_seconds.map(|timeout| format!("{timeout}s")).unwrap_or_else(|| String::from("-")),
+                    table.run.namespace,
+                    table.cluster.map(|cluster| cluster.to_string()).unwrap_or_else(|| String::from("-")),
+                    Clone::clone(&table.status).unwrap_or_else(|| String::from("-")),
+                )
+            }
+            DescribeOutputFormat::Json => {
+                serde_json::to_string(&table).unwrap()
+            }
+            DescribeOutputFormat::PrettyJson => {
+                serde_json::to_string_pretty(&table).unwrap()
+            }
+        };
+
+        println!("{text}");
+
+        Ok(())
+    }
+}
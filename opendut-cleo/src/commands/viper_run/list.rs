@@ -0,0 +1,56 @@
+use cli_table::{print_stdout, Table, WithTitle};
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperRunId, ViperSourceId};
+
+use crate::commands::viper_run::SerializableViperRun;
+use crate::ListOutputFormat;
+
+/// List all test suite runs of the namespace
+#[derive(clap::Parser)]
+pub struct ListViperRunsCli;
+
+#[derive(Table)]
+struct RunTable {
+    #[table(title = "Suite")]
+    suite: String,
+    #[table(title = "RunID")]
+    id: ViperRunId,
+    #[table(title = "SourceID")]
+    source: ViperSourceId,
+}
+
+impl ListViperRunsCli {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: ListOutputFormat) -> crate::Result<()> {
+        let runs = carl.test_suite.list_viper_run_descriptors_in_namespace(namespace).await
+            .map_err(|error| format!("Could not list any test suite runs.\n  {error}"))?;
+
+        match output {
+            ListOutputFormat::Table => {
+                let run_table = runs.into_iter()
+                    .map(|run| {
+                        RunTable {
+                            suite: run.suite.to_string(),
+                            id: run.id,
+                            source: run.source,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                print_stdout(run_table.with_title())
+                    .expect("List of test suite runs should be printable as table.");
+            }
+            ListOutputFormat::Json => {
+                let runs = runs.into_iter().map(SerializableViperRun::from).collect::<Vec<_>>();
+                let json = serde_json::to_string(&runs).unwrap();
+                println!("{json}");
+            }
+            ListOutputFormat::PrettyJson => {
+                let runs = runs.into_iter().map(SerializableViperRun::from).collect::<Vec<_>>();
+                let json = serde_json::to_string_pretty(&runs).unwrap();
+                println!("{json}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+pub mod apply;
+pub mod create;
+pub mod delete;
+pub mod describe;
+pub mod list;
+pub mod parameters;
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperRunParameterValue, ViperRunStatus, ViperSourceId};
+
+#[derive(Serialize)]
+struct SerializableViperRun {
+    id: ViperRunId,
+    source: ViperSourceId,
+    suite: String,
+    parameters: BTreeMap<String, serde_json::Value>,
+    timeout_seconds: Option<u64>,
+    namespace: NamespaceName,
+}
+
+impl From<ViperRunDescriptor> for SerializableViperRun {
+    fn from(run: ViperRunDescriptor) -> Self {
+        let ViperRunDescriptor { id, source, suite, parameters, timeout, namespace } = run;
+
+        SerializableViperRun {
+            id,
+            source,
+            suite: suite.to_string(),
+            parameters: parameters.into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        ViperRunParameterValue::Boolean(value) => serde_json::Value::from(value),
+                        ViperRunParameterValue::Number(value) => serde_json::Value::from(value),
+                        ViperRunParameterValue::Text(value) => serde_json::Value::from(value),
+                    };
+                    (key.inner, value)
+                })
+                .collect(),
+            timeout_seconds: timeout.map(|timeout| timeout.as_secs()),
+            namespace,
+        }
+    }
+}
+
+fn format_parameter_value(value: &ViperRunParameterValue) -> String {
+    match value {
+        ViperRunParameterValue::Boolean(value) => value.to_string(),
+        ViperRunParameterValue::Number(value) => value.to_string(),
+        ViperRunParameterValue::Text(value) => value.to_owned(),
+    }
+}
+
+pub fn format_status(status: &ViperRunStatus) -> String {
+    match status {
+        ViperRunStatus::Scheduled => String::from("Scheduled"),
+        ViperRunStatus::Compiling => String::from("Compiling"),
+        ViperRunStatus::Running(progress) => format!(
+            "Running ({} of {} tests passed, {} failed)",
+            progress.passed_tests, progress.total_tests, progress.failed_tests
+        ),
+        ViperRunStatus::Passed => String::from("Passed"),
+        ViperRunStatus::Failed => String::from("Failed"),
+        ViperRunStatus::Error { cause } => format!("Error: {cause}"),
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use opendut_model::viper::{ViperRunParameterKey, ViperRunParameterValue};
+use opendut_viper_rt::compile::{ParameterDescriptor, ParameterDescriptors};
+use opendut_viper_rt::run::{BindingValue, ParameterBindings};
+
+/// Validates the given parameter values against the parameters declared by a test suite.
+///
+/// The values are given as text and converted according to the type of their parameter.
+/// Parameters without a value are not included in the result, as their default value is applied when the run is executed.
+pub fn bind_parameters(descriptors: Vec<ParameterDescriptor>, values: Vec<(String, String)>) -> crate::Result<HashMap<ViperRunParameterKey, ViperRunParameterValue>> {
+    let declared_parameters = descriptors.iter()
+        .map(|descriptor| descriptor.name().to_string())
+        .collect::<Vec<_>>();
+
+    let mut parameter_descriptors = ParameterDescriptors::new();
+    for descriptor in Clone::clone(&descriptors) {
+        parameter_descriptors.push(descriptor);
+    }
+    let mut bindings = ParameterBindings::from(parameter_descriptors);
+
+    for (name, value) in values {
+        let descriptor = descriptors.iter()
+            .find(|descriptor| descriptor.name().as_str() == name)
+            .ok_or_else(|| {
+                if declared_parameters.is_empty() {
+                    format!("The test suite does not declare any parameters, but a value was given for '{name}'.")
+                } else {
+                    format!("The test suite does not declare a parameter '{name}'. Declared parameters are: {}", declared_parameters.join(", "))
+                }
+            })?;
+
+        let value = parse_binding_value(descriptor, &value)?;
+
+        bindings.bind(descriptor.name(), value)
+            .map_err(|cause| cause.to_string())?;
+    }
+
+    let bindings = bindings.complete()
+        .map_err(|cause| cause.to_string())?;
+
+    let parameters = bindings.into_iter()
+        .filter_map(|binding| {
+            let key = ViperRunParameterKey { inner: binding.name().to_string() };
+            let value = match binding.value? {
+                BindingValue::BooleanValue(value) => ViperRunParameterValue::Boolean(value),
+                BindingValue::NumberValue(value) => ViperRunParameterValue::Number(value),
+                BindingValue::TextValue(value) => ViperRunParameterValue::Text(value),
+            };
+            Some((key, value))
+        })
+        .collect();
+
+    Ok(parameters)
+}
+
+fn parse_binding_value(descriptor: &ParameterDescriptor, value: &str) -> crate::Result<BindingValue> {
+    match descriptor {
+        ParameterDescriptor::BooleanParameter { name, .. } => {
+            value.parse::<bool>()
+                .map(BindingValue::BooleanValue)
+                .map_err(|_| format!("Expected 'true' or 'false' as value for boolean parameter '{name}', but got '{value}'."))
+        }
+        ParameterDescriptor::NumberParameter { name, .. } => {
+            value.parse::<i64>()
+                .map(BindingValue::NumberValue)
+                .map_err(|_| format!("Expected a whole number as value for number parameter '{name}', but got '{value}'."))
+        }
+        ParameterDescriptor::TextParameter { .. } => {
+            Ok(BindingValue::TextValue(value.to_owned()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use opendut_viper_rt::compile::{ParameterInfo, ParameterName};
+
+    use super::*;
+
+    fn descriptors() -> anyhow::Result<Vec<ParameterDescriptor>> {
+        Ok(vec![
+            ParameterDescriptor::BooleanParameter {
+                name: ParameterName::try_from("verbose")?,
+                info: ParameterInfo::default(),
+                default: Some(false),
+            },
+            ParameterDescriptor::NumberParameter {
+                name: ParameterName::try_from("retries")?,
+                info: ParameterInfo::default(),
+                default: None,
+                min: 0,
+                max: 10,
+            },
+            ParameterDescriptor::TextParameter {
+                name: ParameterName::try_from("target")?,
+                info: ParameterInfo::default(),
+                default: Some(String::from("hil-rig")),
+                max: 16,
+            },
+        ])
+    }
+
+    fn values(values: &[(&str, &str)]) -> Vec<(String, String)> {
+        values.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_bind_parameter_values_according_to_their_type() -> anyhow::Result<()> {
+        let result = bind_parameters(descriptors()?, values(&[("verbose", "true"), ("retries", "3")])).unwrap();
+
+        assert_that!(result, len(eq(2)));
+        assert_that!(result.get(&ViperRunParameterKey { inner: String::from("verbose") }), some(eq(&ViperRunParameterValue::Boolean(true))));
+        assert_that!(result.get(&ViperRunParameterKey { inner: String::from("retries") }), some(eq(&ViperRunParameterValue::Number(3))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_invalid_parameter_values() -> anyhow::Result<()> {
+        assert_that!(bind_parameters(descriptors()?, values(&[("retries", "3"), ("verbose", "yes")])), err(anything()));
+        assert_that!(bind_parameters(descriptors()?, values(&[("retries", "three")])), err(anything()));
+        assert_that!(bind_parameters(descriptors()?, values(&[("retries", "11")])), err(anything()));
+        assert_that!(bind_parameters(descriptors()?, values(&[("retries", "3"), ("target", "a-very-long-target-name")])), err(anything()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_unknown_and_missing_parameters() -> anyhow::Result<()> {
+        assert_that!(bind_parameters(descriptors()?, values(&[("retries", "3"), ("timeout", "10")])), err(contains_substring("verbose, retries, target")));
+        assert_that!(bind_parameters(descriptors()?, values(&[("verbose", "true")])), err(contains_substring("retries")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use opendut_model::cluster::ClusterId;
+use opendut_model::peer::PeerId;
+use opendut_model::specs::viper::ViperRunDeploymentSpecificationV1;
+use opendut_model::specs::SpecificationMetadata;
+use opendut_model::viper::{ViperRunDeployment, ViperRunId};
+
+pub fn convert_document_to_viper_run_deployment(specification_metadata: SpecificationMetadata, deployment: ViperRunDeploymentSpecificationV1) -> crate::Result<ViperRunDeployment> {
+    let SpecificationMetadata { id, name: _ } = specification_metadata;
+
+    Ok(ViperRunDeployment {
+        id: ViperRunId::from(id),
+        cluster: ClusterId::from(deployment.cluster_id),
+        peer: deployment.peer_id.map(PeerId::from),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_convert_document_to_viper_run_deployment() -> anyhow::Result<()> {
+        let run_id = ViperRunId::random();
+        let cluster_id = ClusterId::random();
+
+        let specification_metadata = SpecificationMetadata {
+            id: run_id.uuid,
+            name: String::from("nightly-regression"),
+        };
+
+        let document = ViperRunDeploymentSpecificationV1 {
+            cluster_id: cluster_id.uuid,
+            peer_id: None,
+        };
+
+        let result = convert_document_to_viper_run_deployment(specification_metadata, document).unwrap();
+
+        assert_that!(result.id, eq(run_id));
+        assert_that!(result.cluster, eq(cluster_id));
+        assert_that!(result.peer, none());
+
+        Ok(())
+    }
+}
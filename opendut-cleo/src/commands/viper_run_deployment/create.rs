@@ -0,0 +1,60 @@
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::cluster::ClusterId;
+use opendut_model::peer::PeerId;
+use opendut_model::viper::{ViperRunDeployment, ViperRunId};
+
+use crate::commands::viper_run_deployment::SerializableViperRunDeployment;
+use crate::CreateOutputFormat;
+
+/// Create a test suite run deployment, which executes the run on a peer of a cluster
+#[derive(clap::Parser)]
+pub struct CreateViperRunDeploymentCli {
+    /// ID of the test suite run
+    #[arg()]
+    id: ViperRunId,
+    /// ID of the cluster to execute the run in
+    #[arg(short, long)]
+    cluster_id: ClusterId,
+    /// ID of the peer in the cluster, which should execute the run [default: leader of the cluster]
+    #[arg(short, long)]
+    peer_id: Option<PeerId>,
+}
+
+impl CreateViperRunDeploymentCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: CreateOutputFormat) -> crate::Result<()> {
+        let deployment = ViperRunDeployment {
+            id: self.id,
+            cluster: self.cluster_id,
+            peer: self.peer_id,
+        };
+
+        create_viper_run_deployment(deployment, carl, &output).await
+    }
+}
+
+pub async fn create_viper_run_deployment(deployment: ViperRunDeployment, carl: &mut CarlClient, output: &CreateOutputFormat) -> crate::Result<()> {
+    let id = deployment.id;
+    let cluster_id = deployment.cluster;
+
+    carl.test_suite.get_viper_run_descriptor(id).await
+        .map_err(|error| format!("Could not find test suite run <{id}> to deploy.\n  {error}"))?;
+
+    carl.test_suite.store_viper_run_deployment(Clone::clone(&deployment)).await
+        .map_err(|error| format!("Could not create test suite run deployment for run <{id}>.\n  {error}"))?;
+
+    match output {
+        CreateOutputFormat::Text => {
+            println!("Successfully created test suite run deployment for run <{id}> in cluster <{cluster_id}>.");
+        }
+        CreateOutputFormat::Json => {
+            let json = serde_json::to_string(&SerializableViperRunDeployment::from(deployment)).unwrap();
+            println!("{json}");
+        }
+        CreateOutputFormat::PrettyJson => {
+            let json = serde_json::to_string_pretty(&SerializableViperRunDeployment::from(deployment)).unwrap();
+            println!("{json}");
+        }
+    }
+
+    Ok(())
+}
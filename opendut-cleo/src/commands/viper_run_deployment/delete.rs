@@ -0,0 +1,40 @@
+use opendut_carl_api::carl::viper::DeleteViperRunDeploymentError;
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::viper::ViperRunId;
+
+/// Delete a test suite run deployment
+#[derive(clap::Parser)]
+pub struct DeleteViperRunDeploymentCli {
+    /// ID of the test suite run
+    #[arg()]
+    id: ViperRunId,
+    /// Report an error status code when the resource to delete does not exist
+    #[arg(long)]
+    error_when_missing: bool,
+}
+
+impl DeleteViperRunDeploymentCli {
+    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+        let id = self.id;
+
+        match carl.test_suite.delete_viper_run_deployment(id).await {
+            Ok(_) => Ok(()),
+            Err(error) => match error {
+                ClientError::UsageError(DeleteViperRunDeploymentError::RunDeploymentNotFound { .. }) => {
+                    eprintln!("No test suite run deployment found with ID <{id}>.");
+                    if self.error_when_missing {
+                        Err(error)
+                    } else {
+                        Ok(())
+                    }
+                }
+                other => Err(other)
+            }
+            .map_err(|error| format!("Could not delete test suite run deployment for run <{id}>.\n  {error}"))
+        }?;
+
+        println!("Deleted test suite run deployment for run <{id}>.");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use indoc::indoc;
+use serde::Serialize;
+
+use opendut_carl_api::carl::viper::GetViperRunStateError;
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::viper::ViperRunId;
+
+use crate::commands::viper_run::format_status;
+use crate::commands::viper_run_deployment::SerializableViperRunDeployment;
+use crate::DescribeOutputFormat;
+
+/// Describe a test suite run deployment
+#[derive(clap::Parser)]
+pub struct DescribeViperRunDeploymentCli {
+    /// ID of the test suite run
+    #[arg()]
+    id: ViperRunId,
+}
+
+#[derive(Serialize)]
+struct RunDeploymentTable {
+    #[serde(flatten)]
+    deployment: SerializableViperRunDeployment,
+    status: Option<String>,
+}
+
+impl DescribeViperRunDeploymentCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: DescribeOutputFormat) -> crate::Result<()> {
+        let run_id = self.id;
+
+        let deployment = carl.test_suite.get_viper_run_deployment(run_id).await
+            .map_err(|error| format!("Failed to retrieve test suite run deployment <{run_id}>.\n  {error}"))?;
+
+        let status = match carl.test_suite.get_viper_run_state(run_id).await {
+            Ok(state) => Some(format_status(&state.status)),
+            Err(ClientError::UsageError(GetViperRunStateError::StateNotFound { .. })) => None,
+            Err(error) => Err(format!("Failed to retrieve state of test suite run <{run_id}>.\n  {error}"))?,
+        };
+
+        let table = RunDeploymentTable {
+            deployment: SerializableViperRunDeployment::from(deployment),
+            status,
+        };
+
+        let text = match output {
+            DescribeOutputFormat::Text => {
+                format!(indoc!("
+                    Test Suite Run Deployment: {}
+                      Cluster: {}
+                      Peer: {}
+                      Status: {}\
+                "),
+                    table.deployment.id,
+                    table.deployment.cluster,
+                    table.deployment.peer.map(|peer| peer.to_string()).unwrap_or_else(|| String::from("leader")),
+                    Clone::clone(&table.status).unwrap_or_else(|| String::from("-")),
+                )
+            }
+            DescribeOutputFormat::Json => {
+                serde_json::to_string(&table).unwrap()
+            }
+            DescribeOutputFormat::PrettyJson => {
+                serde_json::to_string_pretty(&table).unwrap()
+            }
+        };
+
+        println!("{text}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use cli_table::{print_stdout, Table, WithTitle};
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::cluster::ClusterId;
+use opendut_model::viper::ViperRunId;
+
+use crate::commands::viper_run_deployment::SerializableViperRunDeployment;
+use crate::ListOutputFormat;
+
+/// List all test suite run deployments
+#[derive(clap::Parser)]
+pub struct ListViperRunDeploymentsCli;
+
+#[derive(Table)]
+struct RunDeploymentTable {
+    #[table(title = "RunID")]
+    id: ViperRunId,
+    #[table(title = "ClusterID")]
+    cluster: ClusterId,
+    #[table(title = "PeerID")]
+    peer: String,
+}
+
+impl ListViperRunDeploymentsCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: ListOutputFormat) -> crate::Result<()> {
+        let deployments = carl.test_suite.list_viper_run_deployments().await
+            .map_err(|error| format!("Error while listing test suite run deployments: {error}"))?;
+
+        match output {
+            ListOutputFormat::Table => {
+                let deployment_table = deployments.into_iter()
+                    .map(|deployment| {
+                        RunDeploymentTable {
+                            id: deployment.id,
+                            cluster: deployment.cluster,
+                            peer: deployment.peer
+                                .map(|peer| peer.to_string())
+                                .unwrap_or_else(|| String::from("leader")),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                print_stdout(deployment_table.with_title())
+                    .expect("List of test suite run deployments should be printable as table.");
+            }
+            ListOutputFormat::Json => {
+                let deployments = deployments.into_iter().map(SerializableViperRunDeployment::from).collect::<Vec<_>>();
+                let json = serde_json::to_string(&deployments).unwrap();
+                println!("{json}");
+            }
+            ListOutputFormat::PrettyJson => {
+                let deployments = deployments.into_iter().map(SerializableViperRunDeployment::from).collect::<Vec<_>>();
+                let json = serde_json::to_string_pretty(&deployments).unwrap();
+                println!("{json}");
+            }
+        }
+
+        Ok(())
+    }
+}
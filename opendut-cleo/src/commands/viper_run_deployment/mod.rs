@@ -0,0 +1,26 @@
+pub mod apply;
+pub mod create;
+pub mod delete;
+pub mod describe;
+pub mod list;
+
+use serde::Serialize;
+
+use opendut_model::cluster::ClusterId;
+use opendut_model::peer::PeerId;
+use opendut_model::viper::{ViperRunDeployment, ViperRunId};
+
+#[derive(Serialize)]
+struct SerializableViperRunDeployment {
+    id: ViperRunId,
+    cluster: ClusterId,
+    peer: Option<PeerId>,
+}
+
+impl From<ViperRunDeployment> for SerializableViperRunDeployment {
+    fn from(deployment: ViperRunDeployment) -> Self {
+        let ViperRunDeployment { id, cluster, peer } = deployment;
+
+        SerializableViperRunDeployment { id, cluster, peer }
+    }
+}
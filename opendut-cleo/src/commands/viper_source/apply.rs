@@ -0,0 +1,49 @@
+use opendut_model::specs::viper::ViperSourceDescriptorSpecificationV1;
+use opendut_model::specs::SpecificationMetadata;
+use opendut_model::viper::{ViperSourceDescriptor, ViperSourceId, ViperSourceName};
+
+pub fn convert_document_to_viper_source(specification_metadata: SpecificationMetadata, source: ViperSourceDescriptorSpecificationV1) -> crate::Result<ViperSourceDescriptor> {
+    let SpecificationMetadata { id, name } = specification_metadata;
+
+    let name = ViperSourceName::try_from(name)
+        .map_err(|error| error.to_string())?;
+
+    Ok(ViperSourceDescriptor {
+        id: ViperSourceId::from(id),
+        name,
+        url: source.url,
+        namespace: Default::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use googletest::prelude::*;
+    use url::Url;
+
+    #[test]
+    fn should_convert_document_to_viper_source() -> anyhow::Result<()> {
+        let source = ViperSourceDescriptor {
+            id: ViperSourceId::random(),
+            name: ViperSourceName::try_from("regression-tests")?,
+            url: Url::parse("git+https://example.com/tests.git#ref=main")?,
+            namespace: Default::default(),
+        };
+
+        let specification_metadata = SpecificationMetadata {
+            id: source.id.uuid,
+            name: source.name.value().to_owned(),
+        };
+
+        let document = ViperSourceDescriptorSpecificationV1 {
+            url: source.url.clone(),
+        };
+
+        let result = convert_document_to_viper_source(specification_metadata, document).unwrap();
+
+        assert_that!(result, eq(&source));
+
+        Ok(())
+    }
+}
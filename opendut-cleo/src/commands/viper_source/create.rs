@@ -0,0 +1,60 @@
+use console::Style;
+use url::Url;
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperSourceDescriptor, ViperSourceId, ViperSourceName};
+
+use crate::CreateOutputFormat;
+
+/// Create a test suite source
+#[derive(clap::Parser)]
+pub struct CreateViperSourceCli {
+    ///Name of the test suite source
+    #[arg(short, long)]
+    name: ViperSourceName,
+    ///URL to load the test suites from, e.g. `git+https://example.com/tests.git#ref=main`
+    #[arg(short, long)]
+    url: Url,
+    /// ID of the test suite source
+    #[arg(short, long)]
+    id: Option<ViperSourceId>,
+}
+
+impl CreateViperSourceCli {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: CreateOutputFormat) -> crate::Result<()> {
+        let descriptor = ViperSourceDescriptor {
+            id: self.id.unwrap_or_else(ViperSourceId::random),
+            name: self.name,
+            url: self.url,
+            namespace,
+        };
+
+        create_viper_source(descriptor, carl, &output).await
+    }
+}
+
+pub async fn create_viper_source(descriptor: ViperSourceDescriptor, carl: &mut CarlClient, output: &CreateOutputFormat) -> crate::Result<()> {
+    carl.test_suite.store_viper_source_descriptor(descriptor.clone()).await
+        .map_err(|error| format!("Failed to create test suite source.\n  {error}"))?;
+
+    match output {
+        CreateOutputFormat::Text => {
+            let bold = Style::new().bold();
+            println!(
+                "Created the test suite source '{}' with the ID: <{}>",
+                descriptor.name,
+                bold.apply_to(descriptor.id)
+            );
+        }
+        CreateOutputFormat::Json => {
+            let json = serde_json::to_string(&descriptor).unwrap();
+            println!("{json}");
+        }
+        CreateOutputFormat::PrettyJson => {
+            let json = serde_json::to_string_pretty(&descriptor).unwrap();
+            println!("{json}");
+        }
+    }
+    Ok(())
+}
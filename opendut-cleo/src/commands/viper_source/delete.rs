@@ -0,0 +1,45 @@
+use opendut_carl_api::carl::viper::DeleteViperSourceDescriptorError;
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::viper::ViperSourceId;
+
+/// Delete a test suite source
+#[derive(clap::Parser)]
+pub struct DeleteViperSourceCli {
+    /// ID of the test suite source
+    #[arg()]
+    id: ViperSourceId,
+    /// Report an error status code when the resource to delete does not exist
+    #[arg(long)]
+    error_when_missing: bool,
+}
+
+impl DeleteViperSourceCli {
+    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+        let id = self.id;
+
+        let runs = carl.test_suite.list_viper_run_descriptors().await
+            .map_err(|_| String::from("Failed to get list of test suite runs!"))?;
+
+        if runs.iter().any(|run| run.source == id) {
+            Err(format!("Test suite source <{id}> can not be deleted while it is used by a test suite run."))?
+        };
+
+        match carl.test_suite.delete_viper_source_descriptor(id).await {
+            Ok(_) => {
+                eprintln!("Deleted test suite source <{id}> successfully.");
+                Ok(())
+            }
+            Err(error) => match error {
+                ClientError::UsageError(DeleteViperSourceDescriptorError::SourceNotFound { .. }) => {
+                    eprintln!("No test suite source found with ID <{id}>.");
+                    if self.error_when_missing {
+                        Err(error)
+                    } else {
+                        Ok(())
+                    }
+                }
+                other => Err(other),
+            }
+        }.map_err(|error| format!("Failed to delete test suite source with ID <{id}>.\n  {error}"))
+    }
+}
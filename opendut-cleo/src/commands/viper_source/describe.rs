@@ -0,0 +1,44 @@
+use indoc::indoc;
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::viper::ViperSourceId;
+
+use crate::DescribeOutputFormat;
+
+/// Describe a test suite source
+#[derive(clap::Parser)]
+pub struct DescribeViperSourceCli {
+    /// ID of the test suite source
+    #[arg()]
+    id: ViperSourceId,
+}
+
+impl DescribeViperSourceCli {
+    pub async fn execute(self, carl: &mut CarlClient, output: DescribeOutputFormat) -> crate::Result<()> {
+        let source_id = self.id;
+
+        let source = carl.test_suite.get_viper_source_descriptor(source_id).await
+            .map_err(|error| format!("Failed to retrieve test suite source <{source_id}>.\n  {error}"))?;
+
+        let text = match output {
+            DescribeOutputFormat::Text => {
+                format!(indoc!("
+                    Test Suite Source: {}
+                      Id: {}
+                      URL: {}
+                      Namespace: {}\
+                "), source.name, source.id, source.url, source.namespace)
+            }
+            DescribeOutputFormat::Json => {
+                serde_json::to_string(&source).unwrap()
+            }
+            DescribeOutputFormat::PrettyJson => {
+                serde_json::to_string_pretty(&source).unwrap()
+            }
+        };
+
+        println!("{text}");
+
+        Ok(())
+    }
+}
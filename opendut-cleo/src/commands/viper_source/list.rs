@@ -0,0 +1,54 @@
+use cli_table::{print_stdout, Table, WithTitle};
+use url::Url;
+
+use opendut_carl_api::carl::CarlClient;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperSourceId, ViperSourceName};
+
+use crate::ListOutputFormat;
+
+/// List all test suite sources of the namespace
+#[derive(clap::Parser)]
+pub struct ListViperSourcesCli;
+
+#[derive(Table)]
+struct SourceTable {
+    #[table(title = "Name")]
+    name: ViperSourceName,
+    #[table(title = "SourceID")]
+    id: ViperSourceId,
+    #[table(title = "URL")]
+    url: Url,
+}
+
+impl ListViperSourcesCli {
+    pub async fn execute(self, carl: &mut CarlClient, namespace: NamespaceName, output: ListOutputFormat) -> crate::Result<()> {
+        let sources = carl.test_suite.list_viper_source_descriptors_in_namespace(namespace).await
+            .map_err(|error| format!("Could not list any test suite sources.\n  {error}"))?;
+
+        match output {
+            ListOutputFormat::Table => {
+                let source_table = sources.into_iter()
+                    .map(|source| {
+                        SourceTable {
+                            name: source.name,
+                            id: source.id,
+                            url: source.url,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                print_stdout(source_table.with_title())
+                    .expect("List of test suite sources should be printable as table.");
+            }
+            ListOutputFormat::Json => {
+                let json = serde_json::to_string(&sources).unwrap();
+                println!("{json}");
+            }
+            ListOutputFormat::PrettyJson => {
+                let json = serde_json::to_string_pretty(&sources).unwrap();
+                println!("{json}");
+            }
+        }
+        Ok(())
+    }
+}
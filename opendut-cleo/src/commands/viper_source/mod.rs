@@ -0,0 +1,5 @@
+pub mod apply;
+pub mod create;
+pub mod delete;
+pub mod describe;
+pub mod list;
@@ -6,6 +6,8 @@ use opendut_model::peer::PeerId;
 
 pub mod peer_online;
 pub mod cluster_peers_online;
+#[cfg(feature = "viper")]
+pub mod viper_run;
 
 async fn await_peers_online(carl: &mut CarlClient, peer_ids: HashSet<PeerId>, max_observation_duration: Duration, peers_may_not_yet_exist: bool) -> crate::Result<()> {
     let mut response_stream = carl.observer.wait_peers_online(peer_ids.clone(), max_observation_duration, peers_may_not_yet_exist).await
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use opendut_carl_api::carl::viper::{GetViperRunResultError, GetViperRunStateError};
+use opendut_carl_api::carl::{CarlClient, ClientError};
+use opendut_model::viper::{ViperRunId, ViperRunStatus};
+use opendut_viper_rt::run::Report;
+
+use crate::commands::viper_run::format_status;
+
+/// Wait for a deployed test suite run to finish, failing if the run does not pass
+#[derive(clap::Parser)]
+pub struct WaitViperRunCli {
+    /// ID of the test suite run
+    #[arg()]
+    pub id: ViperRunId,
+    /// Maximum observation duration in seconds
+    #[arg(long, default_value_t = 3600)]
+    pub timeout: u64,
+}
+
+impl WaitViperRunCli {
+    pub async fn execute(self, carl: &mut CarlClient) -> crate::Result<()> {
+        let run_id = self.id;
+
+        carl.test_suite.get_viper_run_deployment(run_id).await
+            .map_err(|error| format!("Test suite run <{run_id}> is not deployed.\n  {error}"))?;
+
+        let max_observation_duration = Duration::from_secs(self.timeout);
+        let poll_interval = Duration::from_secs(5);
+        let start = tokio::time::Instant::now();
+
+        let mut last_status = None;
+
+        loop {
+            match carl.test_suite.get_viper_run_state(run_id).await {
+                Ok(state) => {
+                    if last_status.as_ref() != Some(&state.status) {
+                        println!("Test suite run <{run_id}> on peer <{}>: {}", state.peer, format_status(&state.status));
+                    }
+
+                    match &state.status {
+                        ViperRunStatus::Passed => return Ok(()),
+                        ViperRunStatus::Failed => return Err(format!("Test suite run <{run_id}> failed.")),
+                        ViperRunStatus::Error { cause } => return Err(format!("Test suite run <{run_id}> could not be executed: {cause}")),
+                        ViperRunStatus::Scheduled
+                        | ViperRunStatus::Compiling
+                        | ViperRunStatus::Running(_) => {}
+                    }
+                    last_status = Some(state.status);
+                }
+                Err(ClientError::UsageError(GetViperRunStateError::StateNotFound { .. })) => {
+                    // Either the deployment was not yet picked up by a peer, or the run already finished
+                    // and only its result is stored, e.g. after a restart of CARL.
+                    match carl.test_suite.get_viper_run_result(run_id).await {
+                        Ok(result) => {
+                            return if result.report.is_success() {
+                                println!("Test suite run <{run_id}> on peer <{}>: Passed", result.peer);
+                                Ok(())
+                            } else {
+                                Err(format!("Test suite run <{run_id}> failed."))
+                            };
+                        }
+                        Err(ClientError::UsageError(GetViperRunResultError::ResultNotFound { .. })) => {}
+                        Err(error) => {
+                            println!("Failed to retrieve result of test suite run <{run_id}>: {error}");
+                        }
+                    }
+                }
+                Err(error) => {
+                    println!("Failed to retrieve state of test suite run <{run_id}>: {error}");
+                }
+            }
+
+            if start.elapsed() >= max_observation_duration {
+                return Err(format!("Test suite run <{run_id}> did not finish within {} seconds.", max_observation_duration.as_secs()));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
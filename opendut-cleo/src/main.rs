@@ -8,6 +8,7 @@ use tracing::metadata::LevelFilter;
 use uuid::Uuid;
 use opendut_carl_api::carl::{CaCertInfo, CarlClient};
 use opendut_carl_api::carl::metadata::version_compatibility::VersionCompatibilityInfo;
+use opendut_model::namespace::NamespaceName;
 use opendut_model::topology::{DeviceId, DeviceName};
 use opendut_util::settings::{load_config, FileFormat, LoadedConfig};
 
@@ -29,6 +30,9 @@ struct Args {
     command: Commands,
     #[command(flatten)]
     verbosity: clap_verbosity_flag::Verbosity,
+    ///Namespace of the peers and cluster descriptors to work with [default: from configuration]
+    #[arg(global = true, long)]
+    namespace: Option<NamespaceName>,
 }
 
 #[derive(Subcommand)]
@@ -106,6 +110,17 @@ enum ListResource {
     Devices(commands::device::list::ListDevicesCli),
     #[command(alias="container-executor")]
     ContainerExecutors(commands::executor::list::ListContainerExecutorCli),
+    #[command(alias="audit-event")]
+    AuditEvents(commands::audit_event::list::ListAuditEventsCli),
+    #[cfg(feature = "viper")]
+    #[command(alias="viper-source")]
+    ViperSources(commands::viper_source::list::ListViperSourcesCli),
+    #[cfg(feature = "viper")]
+    #[command(alias="viper-run")]
+    ViperRuns(commands::viper_run::list::ListViperRunsCli),
+    #[cfg(feature = "viper")]
+    #[command(alias="viper-run-deployment")]
+    ViperRunDeployments(commands::viper_run_deployment::list::ListViperRunDeploymentsCli),
 }
 
 #[derive(clap::Args)]
@@ -115,6 +130,10 @@ struct ClusterDescriptorDevices {
     device_names: Vec<DeviceName>,
     #[arg(long, num_args = 0..)]
     device_ids: Vec<DeviceId>,
+    /// Select devices by comma-separated tags, which a device must all carry (AND).
+    /// Repeat the option to select devices matching any of the given selectors (OR).
+    #[arg(long = "device-tags", value_name = "TAGS")]
+    device_selectors: Vec<parse::cluster::ParseableDeviceSelector>,
 }
 
 #[derive(ValueEnum, Clone)]
@@ -139,6 +158,12 @@ enum CreateResource {
     ContainerExecutor(commands::executor::create::CreateContainerExecutorCli),
     NetworkInterface(commands::network_interface::create::CreateNetworkInterfaceCli),
     Device(commands::device::create::CreateDeviceCli),
+    #[cfg(feature = "viper")]
+    ViperSource(commands::viper_source::create::CreateViperSourceCli),
+    #[cfg(feature = "viper")]
+    ViperRun(commands::viper_run::create::CreateViperRunCli),
+    #[cfg(feature = "viper")]
+    ViperRunDeployment(commands::viper_run_deployment::create::CreateViperRunDeploymentCli),
     /// Generate a random UUID, which can be used for assigning a new ID to a resource
     Uuid,
 }
@@ -149,6 +174,12 @@ enum DescribeResource {
     ClusterDescriptor(commands::cluster_descriptor::describe::DescribeClusterDescriptorCli),
     Peer(commands::peer::describe::DescribePeerCli),
     Device(commands::device::describe::DescribeDeviceCli),
+    #[cfg(feature = "viper")]
+    ViperSource(commands::viper_source::describe::DescribeViperSourceCli),
+    #[cfg(feature = "viper")]
+    ViperRun(commands::viper_run::describe::DescribeViperRunCli),
+    #[cfg(feature = "viper")]
+    ViperRunDeployment(commands::viper_run_deployment::describe::DescribeViperRunDeploymentCli),
 }
 
 #[derive(Subcommand)]
@@ -165,12 +196,20 @@ enum DeleteResource {
     ContainerExecutor(commands::executor::delete::DeleteContainerExecutorCli),
     NetworkInterface(commands::network_interface::delete::DeleteNetworkInterfaceCli),
     Device(commands::device::delete::DeleteDeviceCli),
+    #[cfg(feature = "viper")]
+    ViperSource(commands::viper_source::delete::DeleteViperSourceCli),
+    #[cfg(feature = "viper")]
+    ViperRun(commands::viper_run::delete::DeleteViperRunCli),
+    #[cfg(feature = "viper")]
+    ViperRunDeployment(commands::viper_run_deployment::delete::DeleteViperRunDeploymentCli),
 }
 
 #[derive(Subcommand)]
 enum AwaitResource {
     PeerOnline(commands::wait::peer_online::WaitPeerOnlineCli),
     ClusterPeersOnline(commands::wait::cluster_peers_online::WaitPeersInClusterOnline),
+    #[cfg(feature = "viper")]
+    ViperRun(commands::wait::viper_run::WaitViperRunCli),
 }
 
 
@@ -255,14 +294,29 @@ async fn execute() -> Result<()> {
             .map_err(|_| "Error while initializing logging.")?
     };
 
-    execute_command(args.command, &settings).await?;
+    let namespace = determine_namespace(args.namespace, &settings.config)?;
+
+    execute_command(args.command, namespace, &settings).await?;
 
     telemetry_shutdown_handle.shutdown();
 
     Ok(())
 }
 
-async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<()>{
+/// The namespace passed via command line takes precedence over the configured namespace.
+fn determine_namespace(namespace: Option<NamespaceName>, config: &config::Config) -> Result<NamespaceName> {
+    match namespace {
+        Some(namespace) => Ok(namespace),
+        None => {
+            let namespace = config.get_string("namespace")
+                .map_err(|cause| format!("Failed to determine namespace from configuration: {cause}"))?;
+            NamespaceName::try_from(namespace)
+                .map_err(|cause| format!("Configured namespace is not valid: {cause}"))
+        }
+    }
+}
+
+async fn execute_command(commands: Commands, namespace: NamespaceName, settings: &LoadedConfig) -> Result<()>{
     match commands {
         Commands::Setup(implementation) => {
             implementation.execute().await?;
@@ -271,13 +325,13 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
             let mut carl = create_carl_client(&settings.config).await;
             match resource {
                 ListResource::ClusterDescriptors(implementation) => {
-                    implementation.execute(&mut carl, output).await?;
+                    implementation.execute(&mut carl, namespace, output).await?;
                 }
                 ListResource::ClusterDeployments(implementation) => {
                     implementation.execute(&mut carl, output).await?;
                 }
                 ListResource::Peers(implementation) => {
-                    implementation.execute(&mut carl, output).await?;
+                    implementation.execute(&mut carl, namespace, output).await?;
                 }
                 ListResource::ContainerExecutors(implementation) => {
                     implementation.execute(&mut carl, output).await?;
@@ -285,17 +339,32 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
                 ListResource::Devices(implementation) => {
                     implementation.execute(&mut carl, output).await?;
                 }
+                ListResource::AuditEvents(implementation) => {
+                    implementation.execute(&mut carl, output).await?;
+                }
+                #[cfg(feature = "viper")]
+                ListResource::ViperSources(implementation) => {
+                    implementation.execute(&mut carl, namespace, output).await?;
+                }
+                #[cfg(feature = "viper")]
+                ListResource::ViperRuns(implementation) => {
+                    implementation.execute(&mut carl, namespace, output).await?;
+                }
+                #[cfg(feature = "viper")]
+                ListResource::ViperRunDeployments(implementation) => {
+                    implementation.execute(&mut carl, output).await?;
+                }
             }
         }
         Commands::Apply(implementation) => {
             let mut carl = create_carl_client(&settings.config).await;
-            implementation.execute(&mut carl).await?;
+            implementation.execute(&mut carl, namespace).await?;
         }
         Commands::Create { resource, output } => {
             match *resource {
                 CreateResource::ClusterDescriptor(implementation) => {
                     let mut carl = create_carl_client(&settings.config).await;
-                    implementation.execute(&mut carl, output).await?;
+                    implementation.execute(&mut carl, namespace, output).await?;
                 }
                 CreateResource::ClusterDeployment(implementation) => {
                     let mut carl = create_carl_client(&settings.config).await;
@@ -303,7 +372,7 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
                 }
                 CreateResource::Peer(implementation) => {
                     let mut carl = create_carl_client(&settings.config).await;
-                    implementation.execute(&mut carl, output).await?;
+                    implementation.execute(&mut carl, namespace, output).await?;
                 }
                 CreateResource::ContainerExecutor(implementation) => {
                     let mut carl = create_carl_client(&settings.config).await;
@@ -317,6 +386,21 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
                     let mut carl = create_carl_client(&settings.config).await;
                     implementation.execute(&mut carl, output).await?;
                 }
+                #[cfg(feature = "viper")]
+                CreateResource::ViperSource(implementation) => {
+                    let mut carl = create_carl_client(&settings.config).await;
+                    implementation.execute(&mut carl, namespace, output).await?;
+                }
+                #[cfg(feature = "viper")]
+                CreateResource::ViperRun(implementation) => {
+                    let mut carl = create_carl_client(&settings.config).await;
+                    implementation.execute(&mut carl, namespace, output).await?;
+                }
+                #[cfg(feature = "viper")]
+                CreateResource::ViperRunDeployment(implementation) => {
+                    let mut carl = create_carl_client(&settings.config).await;
+                    implementation.execute(&mut carl, output).await?;
+                }
                 CreateResource::Uuid => {
                     println!("{}", Uuid::new_v4());
                 }
@@ -334,27 +418,39 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
             let mut carl = create_carl_client(&settings.config).await;
             match resource {
                 DescribeResource::ClusterDescriptor(implementation)=> {
-                    implementation.execute(&mut carl, output).await?
+                    implementation.execute(&mut carl, namespace, output).await?
                 }
                 DescribeResource::Peer(implementation)=> {
-                    implementation.execute(&mut carl, output).await?
+                    implementation.execute(&mut carl, namespace, output).await?
                 }
                 DescribeResource::Device (implementation)=> {
                     implementation.execute(&mut carl, output).await?
                 }
+                #[cfg(feature = "viper")]
+                DescribeResource::ViperSource(implementation)=> {
+                    implementation.execute(&mut carl, output).await?
+                }
+                #[cfg(feature = "viper")]
+                DescribeResource::ViperRun(implementation)=> {
+                    implementation.execute(&mut carl, output).await?
+                }
+                #[cfg(feature = "viper")]
+                DescribeResource::ViperRunDeployment(implementation)=> {
+                    implementation.execute(&mut carl, output).await?
+                }
             }
         }
         Commands::Delete { resource} => {
             let mut carl = create_carl_client(&settings.config).await;
             match resource {
                 DeleteResource::ClusterDescriptor(implementation) => {
-                    implementation.execute(&mut carl).await?;
+                    implementation.execute(&mut carl, namespace).await?;
                 }
                 DeleteResource::ClusterDeployment(implementation) => {
                     implementation.execute(&mut carl).await?;
                 }
                 DeleteResource::Peer(implementation) => {
-                    implementation.execute(&mut carl).await?;
+                    implementation.execute(&mut carl, namespace).await?;
                 }
                 DeleteResource::ContainerExecutor(implementation) => {
                     implementation.execute(&mut carl).await?;
@@ -365,6 +461,18 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
                 DeleteResource::Device(implementation) => {
                     implementation.execute(&mut carl).await?;
                 }
+                #[cfg(feature = "viper")]
+                DeleteResource::ViperSource(implementation) => {
+                    implementation.execute(&mut carl).await?;
+                }
+                #[cfg(feature = "viper")]
+                DeleteResource::ViperRun(implementation) => {
+                    implementation.execute(&mut carl).await?;
+                }
+                #[cfg(feature = "viper")]
+                DeleteResource::ViperRunDeployment(implementation) => {
+                    implementation.execute(&mut carl).await?;
+                }
             }
         }
         Commands::Find { resource, output } => {
@@ -391,6 +499,10 @@ async fn execute_command(commands: Commands, settings: &LoadedConfig) -> Result<
                 AwaitResource::ClusterPeersOnline(cli) => {
                     cli.execute(&mut carl).await?;
                 }
+                #[cfg(feature = "viper")]
+                AwaitResource::ViperRun(cli) => {
+                    cli.execute(&mut carl).await?;
+                }
             };
         }
     }
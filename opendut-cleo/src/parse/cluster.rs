@@ -1,4 +1,7 @@
 use opendut_model::cluster::*;
+use opendut_model::peer::PeerId;
+use opendut_model::topology::DeviceTag;
+use std::ops::Not;
 
 use super::*;
 
@@ -25,3 +28,44 @@ impl FromStr for ParseableClusterName {
         Ok(Self(inner))
     }
 }
+
+/// A device selector given as comma-separated tags, e.g. `powertrain,hil-rig-3`.
+#[derive(Clone)]
+pub struct ParseableDeviceSelector(pub DeviceSelector);
+impl FromStr for ParseableDeviceSelector {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let tags = value.split(',')
+            .map(str::trim)
+            .filter(|tag| tag.is_empty().not())
+            .map(DeviceTag::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|cause| ParseError::new::<Self>(value, cause.to_string()))?;
+
+        if tags.is_empty() {
+            return Err(ParseError::new::<Self>(value, "At least one tag is required."));
+        }
+        Ok(Self(DeviceSelector { tags }))
+    }
+}
+
+/// A link between two peers given as `<PEER_ID>:<PEER_ID>`.
+#[derive(Clone)]
+pub struct ParseableClusterLink(pub ClusterLink);
+impl FromStr for ParseableClusterLink {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (peer_a, peer_b) = value.split_once(':')
+            .ok_or_else(|| ParseError::new::<Self>(value, "Expected two peer IDs separated by ':'."))?;
+
+        let parse_peer_id = |peer_id: &str| PeerId::try_from(peer_id.trim())
+            .map_err(|cause| ParseError::new::<Self>(value, cause.to_string()));
+
+        Ok(Self(ClusterLink {
+            peer_a: parse_peer_id(peer_a)?,
+            peer_b: parse_peer_id(peer_b)?,
+        }))
+    }
+}
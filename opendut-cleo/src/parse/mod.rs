@@ -1,4 +1,6 @@
 pub mod cluster;
+#[cfg(feature = "viper")]
+pub mod viper;
 
 use std::str::FromStr;
 
@@ -0,0 +1,63 @@
+use opendut_viper_rt::common::TestSuiteIdentifier;
+
+use super::*;
+
+#[derive(Clone)]
+pub struct ParseableTestSuiteIdentifier(pub TestSuiteIdentifier);
+impl FromStr for ParseableTestSuiteIdentifier {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let inner = TestSuiteIdentifier::try_from(value)
+            .map_err(|cause| ParseError::new::<Self>(value, cause.to_string()))?;
+        Ok(Self(inner))
+    }
+}
+
+/// A value for a parameter of a test suite given as `<NAME>=<VALUE>`.
+/// The value is validated against the parameters declared by the test suite, once these are known.
+#[derive(Clone)]
+pub struct ParseableViperRunParameter {
+    pub name: String,
+    pub value: String,
+}
+impl FromStr for ParseableViperRunParameter {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, parameter_value) = value.split_once('=')
+            .ok_or_else(|| ParseError::new::<Self>(value, "Expected a parameter name and a value separated by '='."))?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ParseError::new::<Self>(value, "The parameter name must not be empty."));
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            value: parameter_value.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_parse_run_parameters() -> anyhow::Result<()> {
+        let parameter = ParseableViperRunParameter::from_str("target=hil-rig=3")?;
+        assert_that!(parameter.name, eq("target"));
+        assert_that!(parameter.value, eq("hil-rig=3"));
+
+        let parameter = ParseableViperRunParameter::from_str("comment=")?;
+        assert_that!(parameter.value, eq(""));
+
+        assert_that!(ParseableViperRunParameter::from_str("verbose"), err(anything()));
+        assert_that!(ParseableViperRunParameter::from_str("=true"), err(anything()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+pub use opendut_can_database::{arxml_parser, arxml_structs, arxml_utils};
+pub mod restbus_simulation;
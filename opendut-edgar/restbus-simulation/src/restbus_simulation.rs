@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use autosar_data::EnumItem;
+use socketcan::tokio::CanFdSocket;
+use socketcan::{CanAnyFrame, CanDataFrame, CanFdFrame, EmbeddedFrame, ExtendedId, Id, StandardId};
+use tokio::task::JoinSet;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::{debug, warn};
+
+use crate::arxml_parser::{ArxmlParser, ArxmlParserError};
+use crate::arxml_structs::{CanCluster, CanFrameTriggering, Pdu};
+use crate::arxml_utils::extract_init_values;
+
+const CAN_MAX_DATA_LENGTH: usize = 8;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestbusSimulationError {
+    #[error("Failed to parse ARXML file for restbus simulation:\n  {source}")]
+    Parse { #[from] source: ArxmlParserError },
+    #[error("None of the ECUs {ecus:?} sends any CAN frames according to ARXML file <{arxml_file}>.")]
+    NoFrames { arxml_file: PathBuf, ecus: Vec<String> },
+    #[error("Cannot build CAN frame <{frame}>: {details}")]
+    InvalidFrame { frame: String, details: String },
+    #[error("Failed to open CAN socket on interface <{interface}>: {source}")]
+    Socket { interface: String, source: std::io::Error },
+}
+
+/// A CAN frame, which is transmitted by the restbus simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestbusFrame {
+    pub name: String,
+    pub can_id: u32,
+    pub extended_id: bool,
+    pub fd: bool,
+    pub data: Vec<u8>,
+    pub timing: RestbusFrameTiming,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RestbusFrameTiming {
+    /// Transmitted every `period`, starting after `offset`.
+    Cyclic { offset: Duration, period: Duration },
+    /// Transmitted once when the simulation starts, followed by the given number of repetitions.
+    Once { repetitions: u32, repetition_period: Duration },
+}
+
+/// Simulates the rest of a CAN bus, by transmitting the frames sent by a set of ECUs with their init values.
+#[derive(Clone, Debug)]
+pub struct RestbusSimulation {
+    frames: Vec<RestbusFrame>,
+}
+
+impl RestbusSimulation {
+    pub fn from_arxml(arxml_file: impl AsRef<Path>, ecus: &[String]) -> Result<Self, RestbusSimulationError> {
+        let arxml_file = arxml_file.as_ref();
+        let can_clusters = ArxmlParser::default().parse_file(arxml_file)?;
+
+        let frames = restbus_frames(&can_clusters, ecus)?;
+        if frames.is_empty() {
+            return Err(RestbusSimulationError::NoFrames { arxml_file: arxml_file.to_owned(), ecus: ecus.to_vec() });
+        }
+        Ok(Self { frames })
+    }
+
+    pub fn frames(&self) -> &[RestbusFrame] {
+        &self.frames
+    }
+
+    /// Starts transmitting the frames on the given CAN interface.
+    /// The transmission continues until the returned handle is stopped or dropped.
+    pub fn start(&self, interface: &str) -> Result<RestbusSimulationHandle, RestbusSimulationError> {
+        let can_frames = self.frames.iter()
+            .map(|frame| Ok((frame.clone(), to_can_frame(frame)?)))
+            .collect::<Result<Vec<_>, RestbusSimulationError>>()?;
+
+        let socket = CanFdSocket::open(interface)
+            .map_err(|source| RestbusSimulationError::Socket { interface: interface.to_owned(), source })?;
+        let socket = Arc::new(socket);
+
+        let mut transmissions = JoinSet::new();
+        for (frame, can_frame) in can_frames {
+            transmissions.spawn(transmit(Arc::clone(&socket), frame, can_frame));
+        }
+        debug!("Started restbus simulation with {} frames on interface <{interface}>.", self.frames.len());
+
+        Ok(RestbusSimulationHandle { transmissions })
+    }
+}
+
+/// Handle to a running restbus simulation. Dropping it stops the simulation.
+#[derive(Debug)]
+pub struct RestbusSimulationHandle {
+    transmissions: JoinSet<()>,
+}
+
+impl RestbusSimulationHandle {
+    pub async fn stop(mut self) {
+        self.transmissions.shutdown().await;
+    }
+}
+
+/// Determines the frames, which are sent by any of the given ECUs, and their init values and timing.
+pub fn restbus_frames(can_clusters: &HashMap<String, CanCluster>, ecus: &[String]) -> Result<Vec<RestbusFrame>, RestbusSimulationError> {
+    let mut frames = can_clusters.values()
+        .flat_map(|can_cluster| can_cluster.can_frame_triggerings.values())
+        .filter(|triggering| triggering.sender_ecus.iter().any(|sender| ecus.contains(sender)))
+        .map(restbus_frame)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    frames.sort_by_key(|frame| frame.can_id);
+    Ok(frames)
+}
+
+fn restbus_frame(triggering: &CanFrameTriggering) -> Result<RestbusFrame, RestbusSimulationError> {
+    let invalid_frame = |details: String| RestbusSimulationError::InvalidFrame { frame: triggering.frame_name.clone(), details };
+
+    let can_id = u32::try_from(triggering.can_id)
+        .map_err(|_| invalid_frame(format!("Invalid CAN ID {}", triggering.can_id)))?;
+    let frame_length = usize::try_from(triggering.frame_length)
+        .map_err(|_| invalid_frame(format!("Invalid frame length {}", triggering.frame_length)))?;
+
+    let mut data = vec![0u8; frame_length];
+    let mut timing = None;
+
+    for pdu_mapping in &triggering.pdu_mappings {
+        let (unused_bit_pattern, ungrouped_signals, grouped_signals) = match &pdu_mapping.pdu {
+            Pdu::ISignalIPdu(pdu) => {
+                if timing.is_none() {
+                    timing = pdu_timing(pdu.cyclic_timing_period_value, pdu.cyclic_timing_offset_value, pdu.number_of_repetitions, pdu.repetition_period_value);
+                }
+                (pdu.unused_bit_pattern, &pdu.ungrouped_signals, &pdu.grouped_signals)
+            }
+            Pdu::NmPdu(pdu) => (pdu.unused_bit_pattern, &pdu.ungrouped_signals, &pdu.grouped_signals),
+        };
+
+        let pdu_data = extract_init_values(unused_bit_pattern, ungrouped_signals, grouped_signals, pdu_mapping.length, pdu_mapping.byte_order)
+            .map_err(invalid_frame)?;
+
+        let start_byte = usize::try_from(pdu_mapping.start_position / 8)
+            .map_err(|_| invalid_frame(format!("Invalid start position {} of PDU {}", pdu_mapping.start_position, pdu_mapping.name)))?;
+        let end_byte = start_byte + pdu_data.len();
+        if end_byte > data.len() {
+            return Err(invalid_frame(format!("PDU {} exceeds the frame length of {frame_length} bytes", pdu_mapping.name)));
+        }
+        data[start_byte..end_byte].copy_from_slice(&pdu_data);
+    }
+
+    Ok(RestbusFrame {
+        name: triggering.frame_name.clone(),
+        can_id,
+        extended_id: triggering.addressing_mode == EnumItem::Extended.to_string(),
+        fd: frame_length > CAN_MAX_DATA_LENGTH || triggering.frame_tx_behavior == EnumItem::CanFd.to_string(),
+        data,
+        timing: timing.unwrap_or(RestbusFrameTiming::Once { repetitions: 0, repetition_period: Duration::ZERO }),
+    })
+}
+
+fn pdu_timing(period: f64, offset: f64, number_of_repetitions: i64, repetition_period: f64) -> Option<RestbusFrameTiming> {
+    if period > 0.0 {
+        Some(RestbusFrameTiming::Cyclic {
+            offset: Duration::from_secs_f64(offset.max(0.0)),
+            period: Duration::from_secs_f64(period),
+        })
+    } else if number_of_repetitions > 0 {
+        Some(RestbusFrameTiming::Once {
+            repetitions: u32::try_from(number_of_repetitions).unwrap_or(u32::MAX),
+            repetition_period: Duration::from_secs_f64(repetition_period.max(0.0)),
+        })
+    } else {
+        None
+    }
+}
+
+fn to_can_frame(frame: &RestbusFrame) -> Result<CanAnyFrame, RestbusSimulationError> {
+    let invalid_frame = |details: String| RestbusSimulationError::InvalidFrame { frame: frame.name.clone(), details };
+
+    let id: Id = if frame.extended_id {
+        ExtendedId::new(frame.can_id)
+            .ok_or_else(|| invalid_frame(format!("Invalid extended CAN ID {:#x}", frame.can_id)))?
+            .into()
+    } else {
+        u16::try_from(frame.can_id).ok()
+            .and_then(StandardId::new)
+            .ok_or_else(|| invalid_frame(format!("Invalid standard CAN ID {:#x}", frame.can_id)))?
+            .into()
+    };
+
+    let can_frame = if frame.fd {
+        CanFdFrame::new(id, &frame.data).map(CanAnyFrame::Fd)
+    } else {
+        CanDataFrame::new(id, &frame.data).map(CanAnyFrame::Normal)
+    };
+    can_frame.ok_or_else(|| invalid_frame(format!("Invalid data length of {} bytes", frame.data.len())))
+}
+
+async fn transmit(socket: Arc<CanFdSocket>, frame: RestbusFrame, can_frame: CanAnyFrame) {
+    match frame.timing {
+        RestbusFrameTiming::Cyclic { offset, period } => {
+            let mut interval = tokio::time::interval_at(Instant::now() + offset, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                send(&socket, &frame, &can_frame).await;
+            }
+        }
+        RestbusFrameTiming::Once { repetitions, repetition_period } => {
+            send(&socket, &frame, &can_frame).await;
+            for _ in 0..repetitions {
+                tokio::time::sleep(repetition_period).await;
+                send(&socket, &frame, &can_frame).await;
+            }
+        }
+    }
+}
+
+async fn send(socket: &CanFdSocket, frame: &RestbusFrame, can_frame: &CanAnyFrame) {
+    if let Err(cause) = socket.write_frame(can_frame).await {
+        warn!("Failed to transmit CAN frame <{}> of restbus simulation: {cause}", frame.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use googletest::prelude::*;
+
+    fn arxml_file() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../opendut-util/opendut-can-database/tests/resources/restbus.arxml") //shared with the ARXML parser tests
+    }
+
+    #[test]
+    fn should_determine_frames_sent_by_the_simulated_ecus() -> anyhow::Result<()> {
+        let simulation = RestbusSimulation::from_arxml(arxml_file(), &[String::from("Engine"), String::from("Gateway")])?;
+
+        assert_that!(simulation.frames(), elements_are![
+            eq(&RestbusFrame {
+                name: String::from("EngineStatus"),
+                can_id: 0x100,
+                extended_id: false,
+                fd: false,
+                data: vec![0x34, 0x12, 90, 0, 0, 0, 0, 0],
+                timing: RestbusFrameTiming::Cyclic { offset: Duration::ZERO, period: Duration::from_millis(100) },
+            }),
+            eq(&RestbusFrame {
+                name: String::from("GatewayWakeup"),
+                can_id: 0x300,
+                extended_id: false,
+                fd: false,
+                data: vec![1],
+                timing: RestbusFrameTiming::Once { repetitions: 2, repetition_period: Duration::from_millis(50) },
+            }),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_if_the_simulated_ecus_do_not_send_any_frames() {
+        let result = RestbusSimulation::from_arxml(arxml_file(), &[String::from("UnknownEcu")]);
+
+        assert!(matches!(result, Err(RestbusSimulationError::NoFrames { .. })));
+    }
+
+    #[test]
+    fn should_fail_for_a_missing_arxml_file() {
+        let result = RestbusSimulation::from_arxml("/does/not/exist.arxml", &[String::from("Engine")]);
+
+        assert!(matches!(result, Err(RestbusSimulationError::Parse { source: ArxmlParserError::Load { .. } })));
+    }
+}
@@ -22,6 +22,8 @@ enum Commands {
         id: Option<Uuid>,
     },
     Setup(crate::setup::cli::SetupCli),
+    Status(crate::diagnostics::status::StatusCli),
+    Doctor(crate::diagnostics::doctor::DoctorCli),
 }
 
 
@@ -37,5 +39,7 @@ pub async fn cli() -> anyhow::Result<()> {
             ).await
         },
         Commands::Setup(cli) => cli.run().await,
+        Commands::Status(cli) => cli.run().await,
+        Commands::Doctor(cli) => cli.run().await,
     }
 }
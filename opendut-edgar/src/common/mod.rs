@@ -25,4 +25,13 @@ pub mod constants {
             install_dir.join("rperf")
         }
     }
+
+    pub mod viper {
+        use std::path::PathBuf;
+
+        pub fn git_source_cache_directory() -> PathBuf {
+            let install_dir = crate::common::constants::edgar_install_directory();
+            install_dir.join("viper").join("git-sources")
+        }
+    }
 }
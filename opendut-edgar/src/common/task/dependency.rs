@@ -42,6 +42,7 @@ impl Hash for ParameterVariantWithDependencies {
             ParameterVariant::CanConnections(parameter) => parameter.id.hash(state),
             ParameterVariant::CanBridges(parameter) => parameter.id.hash(state),
             ParameterVariant::CanLocalRoutes(parameter) => parameter.id.hash(state),
+            ParameterVariant::RestbusSimulations(parameter) => parameter.id.hash(state),
         }
     }
 }
@@ -156,8 +157,8 @@ mod tests {
         fn new() -> Self {
             let bridge_old_name = NetworkInterfaceName::try_from("br-old").unwrap();
             let bridge_name = NetworkInterfaceName::try_from("br-opendut").unwrap();
-            let parameter_bridge_old = parameter::EthernetBridge { name: bridge_old_name.clone() };
-            let parameter_bridge_new = parameter::EthernetBridge { name: bridge_name.clone() };
+            let parameter_bridge_old = parameter::EthernetBridge { name: bridge_old_name.clone(), spanning_tree: false };
+            let parameter_bridge_new = parameter::EthernetBridge { name: bridge_name.clone(), spanning_tree: false };
 
             let dut_name = NetworkInterfaceName::try_from("dut0").unwrap();
             let dut_descriptor = NetworkInterfaceDescriptor {
@@ -166,7 +167,7 @@ mod tests {
                 configuration: NetworkInterfaceConfiguration::Ethernet,
             };
             let parameter_eth_device = parameter::DeviceInterface { descriptor: dut_descriptor };
-            let parameter_join = parameter::InterfaceJoinConfig { name: dut_name, bridge: bridge_name.clone() };
+            let parameter_join = parameter::InterfaceJoinConfig { name: dut_name, bridge: bridge_name.clone(), isolated: false };
 
             let mut config = PeerConfiguration::default();
             // add old bridge as present and check if set_all_present adds a dependency to remove the old bridge before adding the new
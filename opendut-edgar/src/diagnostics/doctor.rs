@@ -0,0 +1,198 @@
+use std::ops::Not;
+
+use anyhow::bail;
+use config::Config;
+use tokio::sync::mpsc;
+
+use opendut_model::peer::configuration::{ParameterTarget, ParameterVariant, PeerConfiguration};
+use opendut_util::settings::LoadedConfig;
+
+use crate::common::task::task_resolver::TaskResolver;
+use crate::common::task::{Task, TaskStateFulfilled};
+use crate::common::{carl, settings};
+use crate::service::network_metrics::manager::NetworkMetricsManager;
+use crate::service::peer_configuration::NetworkInterfaceManagement;
+use crate::service::peer_configuration_cache::PeerConfigurationCache;
+use crate::service::service_runner;
+use crate::service::status::{format_target, parameter_kind};
+use crate::service::tasks::task_resolver::ServiceTaskResolver;
+use crate::service::test_execution::executor_manager::ExecutorManager;
+use crate::setup::tasks;
+
+/// Check the host system and the applied peer configuration for problems, without changing anything
+#[derive(clap::Parser)]
+pub struct DoctorCli {
+    /// Skip the checks for CAN, if CAN support was not set up on this host
+    #[arg(long)]
+    skip_can_setup: bool,
+}
+
+struct Finding {
+    check: String,
+    result: CheckResult,
+}
+
+enum CheckResult {
+    Passed,
+    Skipped { reason: String },
+    Failed { cause: String, hint: String },
+}
+
+impl Finding {
+    fn passed(check: impl Into<String>) -> Self {
+        Self { check: check.into(), result: CheckResult::Passed }
+    }
+    fn skipped(check: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self { check: check.into(), result: CheckResult::Skipped { reason: reason.into() } }
+    }
+    fn failed(check: impl Into<String>, cause: impl ToString, hint: impl Into<String>) -> Self {
+        Self { check: check.into(), result: CheckResult::Failed { cause: cause.to_string(), hint: hint.into() } }
+    }
+
+    fn print(&self) {
+        match &self.result {
+            CheckResult::Passed => println!("[ OK ] {}", self.check),
+            CheckResult::Skipped { reason } => {
+                println!("[SKIP] {}", self.check);
+                println!("         {reason}");
+            }
+            CheckResult::Failed { cause, hint } => {
+                println!("[FAIL] {}", self.check);
+                for line in cause.lines() {
+                    println!("         {line}");
+                }
+                println!("         Hint: {hint}");
+            }
+        }
+    }
+}
+
+impl DoctorCli {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let settings = settings::load_with_overrides(Config::default())?;
+
+        println!("Checking setup of the host system:");
+        let setup_findings = check_setup(self.skip_can_setup).await?;
+        setup_findings.iter().for_each(Finding::print);
+
+        println!("\nChecking applied peer configuration:");
+        let configuration_findings = check_peer_configuration(&settings).await?;
+        configuration_findings.iter().for_each(Finding::print);
+
+        let problems = setup_findings.iter().chain(configuration_findings.iter())
+            .filter(|finding| matches!(finding.result, CheckResult::Failed { .. }))
+            .count();
+
+        if problems > 0 {
+            bail!("Found {problems} problem(s).");
+        }
+        println!("\nNo problems found.");
+        Ok(())
+    }
+}
+
+async fn check_setup(skip_can_setup: bool) -> anyhow::Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    let command_line_programs = tasks::CheckCommandLinePrograms { skip_can_setup };
+    findings.push(match command_line_programs.make_present().await {
+        Ok(_) => Finding::passed(command_line_programs.description()),
+        Err(cause) => Finding::failed(command_line_programs.description(), format!("{cause:#}"), "Install the missing program via the package manager of your distribution."),
+    });
+
+    if skip_can_setup.not() {
+        let kernel_modules = tasks::LoadCanKernelModules::default();
+        findings.push(match kernel_modules.check_present().await {
+            Ok(TaskStateFulfilled::Yes | TaskStateFulfilled::Unchecked) => Finding::passed(kernel_modules.description()),
+            Ok(TaskStateFulfilled::No) => Finding::failed(kernel_modules.description(), "Not all required kernel modules are loaded.", "Load the kernel modules via `modprobe` or run `opendut-edgar setup` again."),
+            Err(cause) => Finding::failed(kernel_modules.description(), format!("{cause:#}"), "Check that the kernel modules for CAN are available for your kernel."),
+        });
+    }
+
+    let settings = settings::load_with_overrides(
+        Config::builder()
+            .set_override("network.connect.retries", 1)?
+            .build()?
+    )?;
+    let check = format!(
+        "Check CARL reachable at {}:{}",
+        settings.config.get_string("network.carl.host")?,
+        settings.config.get_int("network.carl.port")?,
+    );
+    findings.push(match carl::connect(&settings.config).await {
+        Ok(_) => Finding::passed(check),
+        Err(cause) => Finding::failed(check, format!("{cause:#}"), "Check the network connection, the CARL address and the CA certificate configured in /etc/opendut/edgar.toml."),
+    });
+
+    Ok(findings)
+}
+
+async fn check_peer_configuration(settings: &LoadedConfig) -> anyhow::Result<Vec<Finding>> {
+    const CHECK: &str = "Load applied peer configuration";
+
+    let Some(cache) = PeerConfigurationCache::load_from_settings(&settings.config)? else {
+        return Ok(vec![Finding::skipped(CHECK, "The peer configuration cache is disabled, so the applied configuration is not known. It can be enabled in the section `[peer.configuration.cache]` of the configuration.")]);
+    };
+    let peer_configuration = match cache.load() {
+        Ok(Some((peer_configuration, _))) => peer_configuration,
+        Ok(None) => return Ok(vec![Finding::skipped(CHECK, "No peer configuration was applied successfully yet.")]),
+        Err(cause) => return Ok(vec![Finding::failed(CHECK, format!("{cause:#}"), "The cached peer configuration is discarded on the next start of the EDGAR Service.")]),
+    };
+
+    let mut findings = vec![Finding::passed(CHECK)];
+    findings.extend(check_service_tasks(peer_configuration, settings).await?);
+    Ok(findings)
+}
+
+/// Runs the checks of the tasks, which the service executes for the parameters of the configuration.
+async fn check_service_tasks(peer_configuration: PeerConfiguration, settings: &LoadedConfig) -> anyhow::Result<Vec<Finding>> {
+    let (tx_executor_status, _rx_executor_status) = mpsc::channel(1);
+
+    let resolver = ServiceTaskResolver::new(
+        peer_configuration.clone(),
+        NetworkInterfaceManagement::load(settings)?,
+        ExecutorManager::create(tx_executor_status),
+        NetworkMetricsManager::load(settings)?,
+    );
+
+    let mut parameters = peer_configuration.all_parameters().into_values().collect::<Vec<_>>();
+    parameters.sort_by_key(|parameter| (parameter_kind(parameter), parameter.id().0));
+
+    let mut findings = vec![];
+    for parameter in parameters {
+        let target = parameter.target();
+        let name = format!("{} <{}> ({})", parameter_kind(&parameter), parameter.id().0, format_target(target));
+
+        if is_managed_by_running_service(&parameter) {
+            findings.push(Finding::skipped(name, "Managed by the running EDGAR Service. Its state is shown by `opendut-edgar status`."));
+            continue;
+        }
+
+        for task in resolver.resolve_tasks(&parameter) {
+            let check = format!("{name}: {}", task.description());
+            findings.push(match service_runner::check_task(task.as_ref(), target).await {
+                Ok(TaskStateFulfilled::Yes | TaskStateFulfilled::Unchecked) => Finding::passed(check),
+                Ok(TaskStateFulfilled::No) => Finding::failed(check, "The host system does not match the peer configuration.", unfulfilled_hint(target)),
+                Err(cause) => Finding::failed(check, format!("{cause:#}"), "Check the logs of the EDGAR Service via `journalctl -u opendut-edgar`."),
+            });
+        }
+    }
+    Ok(findings)
+}
+
+/// Parameters, whose tasks track their state within the service process, so they cannot be checked from outside.
+fn is_managed_by_running_service(parameter: &ParameterVariant) -> bool {
+    matches!(parameter,
+        ParameterVariant::Executor(_)
+        | ParameterVariant::CanConnections(_)
+        | ParameterVariant::RestbusSimulations(_)
+        | ParameterVariant::RemotePeerConnectionCheck(_)
+    )
+}
+
+fn unfulfilled_hint(target: ParameterTarget) -> &'static str {
+    match target {
+        ParameterTarget::Present => "The configuration was changed or removed outside of EDGAR. Restart the EDGAR Service to apply it again.",
+        ParameterTarget::Absent => "The configuration was not cleaned up. Restart the EDGAR Service to remove it again.",
+    }
+}
@@ -0,0 +1,12 @@
+//! Local diagnostics, which inspect the host system and the running EDGAR Service without changing anything.
+
+use std::time::SystemTime;
+
+pub mod doctor;
+pub mod status;
+
+fn format_time(time: SystemTime) -> String {
+    chrono::DateTime::<chrono::Local>::from(time)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
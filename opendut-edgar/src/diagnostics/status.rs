@@ -0,0 +1,139 @@
+use std::ops::Not;
+use std::time::SystemTime;
+
+use anyhow::bail;
+use config::Config;
+
+use crate::common::settings;
+use crate::diagnostics::format_time;
+use crate::service::status;
+use crate::service::status::{CarlConnectionState, ServiceStatus};
+
+/// Show the state of the running EDGAR Service
+#[derive(clap::Parser)]
+pub struct StatusCli {
+    /// Print the status as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+impl StatusCli {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let settings = settings::load_with_overrides(Config::default())?;
+        let status_file = status::status_file(&settings.config)?;
+
+        if status_file.exists().not() {
+            bail!(
+                "No status found at '{}'.\n\
+                Is the EDGAR Service running? Check with `systemctl status opendut-edgar`.",
+                status_file.display()
+            );
+        }
+        let status = status::read(&status_file)?;
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&status)?);
+        } else {
+            print!("{}", format_status(&status, SystemTime::now()));
+        }
+        Ok(())
+    }
+}
+
+fn format_status(status: &ServiceStatus, now: SystemTime) -> String {
+    let mut text = String::new();
+
+    let age = now.duration_since(status.updated_at).unwrap_or_default();
+    text.push_str("EDGAR Service\n");
+    text.push_str(&format!("  Peer ID: {}\n", status.peer_id));
+    text.push_str(&format!("  Last update: {} ({}s ago)\n", format_time(status.updated_at), age.as_secs()));
+    if age > status::REFRESH_INTERVAL * 3 {
+        text.push_str("  Warning: The status was not updated recently. The EDGAR Service might not be running.\n");
+    }
+
+    let connection = match status.carl.state {
+        CarlConnectionState::Connecting => "Connecting",
+        CarlConnectionState::Connected => "Connected",
+        CarlConnectionState::Disconnected => "Disconnected",
+    };
+    text.push_str("\nCARL\n");
+    text.push_str(&format!("  Address: {}\n", status.carl.address));
+    text.push_str(&format!("  Connection: {connection} (since {})\n", format_time(status.carl.since)));
+
+    text.push_str("\nPeer Configuration\n");
+    match &status.configuration {
+        Some(configuration) => {
+            let source = match &configuration.cached {
+                Some(checksum) => format!("from local cache, checksum {checksum}"),
+                None => String::from("from CARL"),
+            };
+            text.push_str(&format!("  Applied: {} ({source})\n", format_time(configuration.applied_at)));
+
+            if configuration.parameters.is_empty() {
+                text.push_str("  Parameters: none\n");
+            } else {
+                text.push_str("  Parameters:\n");
+                for parameter in &configuration.parameters {
+                    text.push_str(&format!("    {} <{}>: target {}, detected {}\n", parameter.kind, parameter.id, parameter.target, parameter.detected_state));
+                }
+            }
+        }
+        None => text.push_str("  No configuration applied yet.\n"),
+    }
+
+    text.push_str("\nCAN Tunnels\n");
+    if status.can_tunnels.is_empty() {
+        text.push_str("  none\n");
+    }
+    for tunnel in &status.can_tunnels {
+        text.push_str(&format!("  {} to peer <{}> via {}: {}\n", tunnel.can_interface, tunnel.remote_peer_id, tunnel.transport, tunnel.state));
+    }
+
+    text.push_str("\nExecutors\n");
+    if status.executors.is_empty() {
+        text.push_str("  none\n");
+    }
+    for executor in &status.executors {
+        text.push_str(&format!("  <{}>: {}\n", executor.id, executor.status));
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use googletest::prelude::*;
+    use opendut_model::peer::PeerId;
+
+    use super::*;
+    use crate::service::status::CarlConnectionStatus;
+
+    fn status(updated_at: SystemTime) -> ServiceStatus {
+        ServiceStatus {
+            peer_id: PeerId::random(),
+            carl: CarlConnectionStatus {
+                address: String::from("carl.opendut.local:443"),
+                state: CarlConnectionState::Connected,
+                since: updated_at,
+            },
+            configuration: None,
+            can_tunnels: vec![],
+            executors: vec![],
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn should_warn_about_outdated_status() {
+        let now = SystemTime::now();
+
+        let text = format_status(&status(now), now);
+        assert_that!(text, contains_substring("Connection: Connected"));
+        assert_that!(text, not(contains_substring("Warning")));
+
+        let text = format_status(&status(now - Duration::from_secs(300)), now);
+        assert_that!(text, contains_substring("Warning"));
+    }
+}
@@ -5,6 +5,7 @@ mod cli;
 pub use cli::cli;
 
 mod common;
+mod diagnostics;
 mod setup;
 mod service;
 pub(crate) use fs_err as fs;
@@ -17,6 +18,7 @@ pub mod testing {
         pub use crate::service::start;
         pub use crate::service::peer_messaging_client;
         pub use crate::service::peer_configuration;
+        pub use crate::service::status;
     }
     pub mod carl {
         pub use crate::common::carl::connect;
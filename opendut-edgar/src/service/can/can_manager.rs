@@ -1,12 +1,10 @@
-use crate::service::process_manager::{AsyncProcessId, AsyncProcessManager, AsyncProcessManagerExt, AsyncProcessManagerRef, OutputConfig, ProcessConfig, RestartPolicy};
-use opendut_model::peer::configuration::parameter::CanConnection;
+use crate::service::can::tunnel::{CanTunnelHandle, CanTunnelState};
+use opendut_model::peer::configuration::parameter::{CanConnection, RestbusSimulation};
 use opendut_model::peer::configuration::{ParameterId, ParameterValue};
 use std::collections::HashMap;
-use std::process::Stdio;
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::sync::Mutex;
-use tokio::process::Command;
+use opendut_restbus_simulation::restbus_simulation::RestbusSimulationHandle;
 
 pub type CanManagerRef = Arc<Mutex<CanManager>>;
 
@@ -16,109 +14,87 @@ pub trait CanManagerExt {
 
 impl CanManagerExt for CanManagerRef {
     fn new_shared() -> CanManagerRef {
-        let process_manager = AsyncProcessManagerRef::new_shared();
-        Arc::new(Mutex::new(CanManager::create(process_manager)))
+        Arc::new(Mutex::new(CanManager::create()))
     }
 }
 
 pub struct CanManager {
-    process_manager: AsyncProcessManagerRef,
-    process_map: Mutex<HashMap<ParameterId, AsyncProcessId>>,
+    tunnels: Mutex<HashMap<ParameterId, CanTunnelHandle>>,
+    restbus_simulations: Mutex<HashMap<ParameterId, RestbusSimulationHandle>>,
 }
 
 impl CanManager {
-    fn create(process_manager: AsyncProcessManagerRef) -> Self {
+    fn create() -> Self {
         Self {
-            process_manager,
-            process_map: Default::default(),
+            tunnels: Default::default(),
+            restbus_simulations: Default::default(),
         }
     }
 
-    pub async fn spawn_process(&self, parameter: &CanConnection) -> anyhow::Result<()> {
-        let name = if parameter.local_is_server {
-            format!("cannelloni-server-on-port-{}", parameter.local_port)
-        } else {
-            format!("cannelloni-to-leader-peer-{}", parameter.remote_peer_id)
-        };
-        // Create process with restart policy
-        let command_parameter = parameter.clone();
-        let config = ProcessConfig::new(
-            name,
-            move || {
-                let mut cmd = Command::new("cannelloni");
-                Self::fill_cannelloni_cmd(&command_parameter, &mut cmd);
-                cmd
-            }
-        )
-            .with_restart_policy(RestartPolicy::Always)
-            .with_restart_delay(Duration::from_secs(5))
-            .with_output_config(OutputConfig::Capture);
-
-        let process_id = AsyncProcessManager::spawn(self.process_manager.clone(), config).await?;
-        let parameter_id = parameter.parameter_identifier();
-        let mut processes = self.process_map.lock().await;
-        processes.insert(parameter_id, process_id);
+    pub async fn start_tunnel(&self, parameter: &CanConnection) {
+        let handle = CanTunnelHandle::start(parameter.clone());
 
-        Ok(())
+        let mut tunnels = self.tunnels.lock().await;
+        if let Some(outdated) = tunnels.insert(parameter.parameter_identifier(), handle) {
+            outdated.stop().await;
+        }
+    }
+
+    pub async fn tunnel_is_running(&self, parameter: &CanConnection) -> bool {
+        let tunnels = self.tunnels.lock().await;
+        tunnels.get(&parameter.parameter_identifier())
+            .is_some_and(CanTunnelHandle::is_running)
     }
 
-    pub async fn process_is_running(&self, parameter: &CanConnection) -> bool {
-        let id = parameter.parameter_identifier();
-        let processes = self.process_map.lock().await;
-        if let Some(process_id) = processes.get(&id) {
-            let mut process_manager = self.process_manager.lock().await;
-            process_manager.process_is_running(process_id)
-        } else {
-            false
+    /// States of all running tunnels, ordered by their remote peer.
+    pub async fn tunnel_states(&self) -> Vec<(CanConnection, CanTunnelState)> {
+        let tunnels = self.tunnels.lock().await;
+        let mut states = tunnels.values()
+            .map(|handle| (handle.parameter().clone(), handle.state()))
+            .collect::<Vec<_>>();
+        states.sort_by_key(|(parameter, _)| (parameter.remote_peer_id.uuid, parameter.can_interface_name.name()));
+        states
+    }
+
+    pub async fn stop_tunnel(&self, parameter: &CanConnection) {
+        let handle = self.tunnels.lock().await.remove(&parameter.parameter_identifier());
+        if let Some(handle) = handle {
+            handle.stop().await;
         }
     }
 
-    pub async fn terminate_process(&self, parameter: &CanConnection) -> anyhow::Result<()> {
-        let id = parameter.parameter_identifier();
-        let mut processes = self.process_map.lock().await;
-        if let Some(process_id) = processes.remove(&id) {
-            let mut process_manager = self.process_manager.lock().await;
-            process_manager.terminate(process_id).await?;
+    pub async fn start_restbus_simulation(&self, parameter: &RestbusSimulation) -> anyhow::Result<()> {
+        let simulation = opendut_restbus_simulation::restbus_simulation::RestbusSimulation::from_arxml(&parameter.arxml_file, &parameter.ecus)?;
+        let handle = simulation.start(&parameter.can_interface_name.name())?;
+
+        let mut restbus_simulations = self.restbus_simulations.lock().await;
+        if let Some(outdated) = restbus_simulations.insert(parameter.parameter_identifier(), handle) {
+            outdated.stop().await;
         }
         Ok(())
     }
 
-    pub async fn shutdown(&self) {
-        let mut process_manager = self.process_manager.lock().await;
-        if !process_manager.is_empty() {
-            process_manager.shutdown().await;
-        }
+    pub async fn restbus_simulation_is_running(&self, parameter: &RestbusSimulation) -> bool {
+        let restbus_simulations = self.restbus_simulations.lock().await;
+        restbus_simulations.contains_key(&parameter.parameter_identifier())
     }
 
-    /*
-     cannelloni with SCTP transport for CAN bus tunneling
-
-     With SCTP it is possible to use cannelloni over lossy connections where packet loss and re-ordering is very likely.
-     The SCTP implementation uses the server-client model (for now). One instance binds on a fixed port and the other instance (client) connects to it.
-
-     https://github.com/mguentner/cannelloni?tab=readme-ov-file#sctp
-     */
-    fn fill_cannelloni_cmd(parameter: &CanConnection, cmd: &mut Command) {
-        let instance_type = if parameter.local_is_server {"s"} else {"c"}; // act as server or client
-        let port_arg = if parameter.local_is_server {"-l"} else {"-r"};  // listening port or remote port
-        let port = if parameter.local_is_server {
-            parameter.local_port
-        } else {
-            parameter.remote_port
-        };
-
-        cmd.arg("-I")
-            .arg(parameter.can_interface_name.name())
-            .arg("-S")  // enable SCTP transport
-            .arg(instance_type)
-            .arg("-t")  // buffer timeout
-            .arg(parameter.buffer_timeout_microseconds.to_string())
-            .arg("-R")  // remote IP address
-            .arg(parameter.remote_ip.to_string())
-            .arg(port_arg)
-            .arg(port.to_string())
-            .stderr(Stdio::piped())
-            .stdout(Stdio::piped());
+    pub async fn stop_restbus_simulation(&self, parameter: &RestbusSimulation) {
+        let handle = self.restbus_simulations.lock().await.remove(&parameter.parameter_identifier());
+        if let Some(handle) = handle {
+            handle.stop().await;
+        }
     }
 
+    pub async fn shutdown(&self) {
+        let restbus_simulations = std::mem::take(&mut *self.restbus_simulations.lock().await);
+        for (_, handle) in restbus_simulations {
+            handle.stop().await;
+        }
+
+        let tunnels = std::mem::take(&mut *self.tunnels.lock().await);
+        for (_, handle) in tunnels {
+            handle.stop().await;
+        }
+    }
 }
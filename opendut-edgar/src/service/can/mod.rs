@@ -1 +1,2 @@
-pub mod can_manager;
\ No newline at end of file
+pub mod can_manager;
+mod tunnel;
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use opendut_model::peer::configuration::parameter::CanConnection;
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::{global, KeyValue};
+
+/// Counters of a CAN tunnel, labeled with the remote peer and the local CAN interface.
+pub(super) struct CanTunnelMetrics {
+    frames_sent: Counter<u64>,
+    frames_received: Counter<u64>,
+    frames_dropped: Counter<u64>,
+    frames_filtered: Counter<u64>,
+    packets_lost: Counter<u64>,
+    packets_reordered: Counter<u64>,
+    packets_invalid: Counter<u64>,
+    send_latency: Histogram<f64>,
+    attributes: Vec<KeyValue>,
+}
+
+impl CanTunnelMetrics {
+    pub fn new(parameter: &CanConnection) -> Self {
+        let meter = global::meter(opendut_telemetry::DEFAULT_METER_NAME);
+
+        Self {
+            frames_sent: meter.u64_counter("can_tunnel_frames_sent")
+                .with_description("CAN frames sent to the remote peer")
+                .build(),
+            frames_received: meter.u64_counter("can_tunnel_frames_received")
+                .with_description("CAN frames received from the remote peer")
+                .build(),
+            frames_dropped: meter.u64_counter("can_tunnel_frames_dropped")
+                .with_description("CAN frames which could not be sent to the remote peer or written to the CAN interface")
+                .build(),
+            frames_filtered: meter.u64_counter("can_tunnel_frames_filtered")
+                .with_description("CAN frames which were not sent to the remote peer due to the filter rules or the rate limit")
+                .build(),
+            packets_lost: meter.u64_counter("can_tunnel_packets_lost")
+                .with_description("Packets from the remote peer, which were skipped according to their sequence number")
+                .build(),
+            packets_reordered: meter.u64_counter("can_tunnel_packets_reordered")
+                .with_description("Packets from the remote peer, which arrived after a later packet")
+                .build(),
+            packets_invalid: meter.u64_counter("can_tunnel_packets_invalid")
+                .with_description("Packets from the remote peer, which could not be decoded")
+                .build(),
+            send_latency: meter.f64_histogram("can_tunnel_send_latency")
+                .with_description("Time from receiving the first CAN frame of a packet until the packet is sent to the remote peer")
+                .with_unit("ms")
+                .build(),
+            attributes: vec![
+                KeyValue::new("peer_id", parameter.remote_peer_id.to_string()),
+                KeyValue::new("can_interface", parameter.can_interface_name.name()),
+                KeyValue::new("transport", parameter.transport.to_string()),
+            ],
+        }
+    }
+
+    pub fn frames_sent(&self, count: usize, latency: Duration) {
+        self.frames_sent.add(count as u64, &self.attributes);
+        self.send_latency.record(latency.as_secs_f64() * 1000.0, &self.attributes);
+    }
+
+    pub fn frames_received(&self, count: usize) {
+        self.frames_received.add(count as u64, &self.attributes);
+    }
+
+    pub fn frames_dropped(&self, count: usize) {
+        self.frames_dropped.add(count as u64, &self.attributes);
+    }
+
+    pub fn frames_filtered(&self, count: usize) {
+        self.frames_filtered.add(count as u64, &self.attributes);
+    }
+
+    pub fn packets_lost(&self, count: u8) {
+        self.packets_lost.add(u64::from(count), &self.attributes);
+    }
+
+    pub fn packet_reordered(&self) {
+        self.packets_reordered.add(1, &self.attributes);
+    }
+
+    pub fn packet_invalid(&self) {
+        self.packets_invalid.add(1, &self.attributes);
+    }
+}
@@ -0,0 +1,316 @@
+//! Tunnels CAN frames between a local CAN interface and a remote peer, compatible with cannelloni.
+//! The rules and rate limits of the connection are applied to the frames sent to the remote peer.
+
+mod metrics;
+mod packet;
+mod rules;
+mod transport;
+
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::time::Duration;
+
+use opendut_model::peer::configuration::parameter::{CanConnection, CanTransport};
+use opendut_model::util::net::NetworkInterfaceName;
+use socketcan::tokio::CanFdSocket;
+use socketcan::CanAnyFrame;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+use metrics::CanTunnelMetrics;
+use packet::{CannelloniPacket, SequenceNumberCheck, SequenceNumberTracker};
+use rules::FrameRules;
+use transport::TunnelSocket;
+
+const RESTART_DELAY: Duration = Duration::from_secs(5);
+const RECEIVE_BUFFER_LENGTH: usize = 65535;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CanTunnelError {
+    #[error("Failed to access CAN interface <{interface}>: {source}")]
+    CanInterface { interface: NetworkInterfaceName, source: io::Error },
+    #[error("Failed to establish {transport} connection to peer at <{remote_ip}>: {source}")]
+    Connect { transport: CanTransport, remote_ip: std::net::IpAddr, source: io::Error },
+    #[error("{transport} connection to peer at <{remote_ip}> failed: {source}")]
+    Connection { transport: CanTransport, remote_ip: std::net::IpAddr, source: io::Error },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CanTunnelState {
+    Connecting,
+    Established,
+    /// The tunnel failed and is restarted after a delay.
+    Failed { cause: String },
+}
+
+impl Display for CanTunnelState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanTunnelState::Connecting => write!(f, "Connecting"),
+            CanTunnelState::Established => write!(f, "Established"),
+            CanTunnelState::Failed { cause } => write!(f, "Failed: {cause}"),
+        }
+    }
+}
+
+/// Handle to a running CAN tunnel. Dropping it stops the tunnel.
+#[derive(Debug)]
+pub struct CanTunnelHandle {
+    parameter: CanConnection,
+    task: JoinHandle<()>,
+    rx_state: watch::Receiver<CanTunnelState>,
+}
+
+impl CanTunnelHandle {
+    /// Starts tunneling CAN frames between the CAN interface and the remote peer.
+    /// When the tunnel fails, e.g. because the remote peer is not reachable yet, it is restarted after a delay.
+    pub fn start(parameter: CanConnection) -> Self {
+        let (tx_state, rx_state) = watch::channel(CanTunnelState::Connecting);
+
+        let task = {
+            let parameter = parameter.clone();
+            tokio::spawn(async move {
+                let metrics = CanTunnelMetrics::new(&parameter);
+                loop {
+                    let Err(cause) = run(&parameter, &metrics, &tx_state).await;
+                    warn!("CAN tunnel to peer <{}> failed. Restarting in {} seconds. Cause: {cause}", parameter.remote_peer_id, RESTART_DELAY.as_secs());
+                    tx_state.send_replace(CanTunnelState::Failed { cause: cause.to_string() });
+                    tokio::time::sleep(RESTART_DELAY).await;
+                    tx_state.send_replace(CanTunnelState::Connecting);
+                }
+            })
+        };
+        Self { parameter, task, rx_state }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    pub fn parameter(&self) -> &CanConnection {
+        &self.parameter
+    }
+
+    pub fn state(&self) -> CanTunnelState {
+        self.rx_state.borrow().clone()
+    }
+
+    pub async fn stop(mut self) {
+        self.task.abort();
+        let _ = (&mut self.task).await;
+    }
+}
+
+impl Drop for CanTunnelHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn run(parameter: &CanConnection, metrics: &CanTunnelMetrics, tx_state: &watch::Sender<CanTunnelState>) -> Result<Infallible, CanTunnelError> {
+    let can_interface_error = |source| CanTunnelError::CanInterface { interface: parameter.can_interface_name.clone(), source };
+    let can_socket = CanFdSocket::open(&parameter.can_interface_name.name())
+        .map_err(can_interface_error)?;
+
+    let tunnel_socket = TunnelSocket::open(parameter).await
+        .map_err(|source| CanTunnelError::Connect { transport: parameter.transport, remote_ip: parameter.remote_ip, source })?;
+    info!("Established CAN tunnel via {} to peer <{}> for interface <{}>.", parameter.transport, parameter.remote_peer_id, parameter.can_interface_name);
+    tx_state.send_replace(CanTunnelState::Established);
+
+    tokio::select! {
+        result = forward_to_peer(parameter, &can_socket, &tunnel_socket, metrics) => result,
+        result = forward_to_can(parameter, &can_socket, &tunnel_socket, metrics) => result,
+    }
+}
+
+/// Reads frames from the CAN interface and sends them to the remote peer.
+/// Frames are buffered for the configured timeout, so multiple frames can be sent in one packet.
+async fn forward_to_peer(
+    parameter: &CanConnection,
+    can_socket: &CanFdSocket,
+    tunnel_socket: &TunnelSocket,
+    metrics: &CanTunnelMetrics,
+) -> Result<Infallible, CanTunnelError> {
+    let can_interface_error = |source| CanTunnelError::CanInterface { interface: parameter.can_interface_name.clone(), source };
+    let buffer_timeout = Duration::from_micros(parameter.buffer_timeout_microseconds);
+    let mut rules = FrameRules::new(parameter.rules.clone(), parameter.rate_limits.clone());
+
+    let mut sequence_number: u8 = 0;
+    let mut pending_frame: Option<CanAnyFrame> = None;
+
+    loop {
+        let first_frame = match pending_frame.take() {
+            Some(frame) => frame,
+            None => read_forwarded_frame(can_socket, &mut rules, metrics).await.map_err(can_interface_error)?,
+        };
+        let buffering_start = Instant::now();
+        let mut frames = vec![first_frame];
+
+        while let Ok(frame) = tokio::time::timeout_at(buffering_start + buffer_timeout, read_forwarded_frame(can_socket, &mut rules, metrics)).await {
+            let frame = frame.map_err(can_interface_error)?;
+            if packet::fits_into_packet(&frames, &frame) {
+                frames.push(frame);
+            } else {
+                pending_frame = Some(frame);
+                break;
+            }
+        }
+
+        let frame_count = frames.len();
+        let packet = CannelloniPacket { sequence_number, frames };
+        sequence_number = sequence_number.wrapping_add(1);
+
+        match tunnel_socket.send(&packet.encode()).await {
+            Ok(()) => metrics.frames_sent(frame_count, buffering_start.elapsed()),
+            Err(source) => match parameter.transport {
+                CanTransport::Udp => {
+                    debug!("Dropped {frame_count} CAN frame(s) for peer <{}>, because they could not be sent: {source}", parameter.remote_peer_id);
+                    metrics.frames_dropped(frame_count);
+                }
+                CanTransport::Sctp => {
+                    metrics.frames_dropped(frame_count);
+                    return Err(CanTunnelError::Connection { transport: parameter.transport, remote_ip: parameter.remote_ip, source });
+                }
+            },
+        }
+    }
+}
+
+/// Reads frames from the CAN interface until one is forwarded according to the rules.
+async fn read_forwarded_frame(can_socket: &CanFdSocket, rules: &mut FrameRules, metrics: &CanTunnelMetrics) -> io::Result<CanAnyFrame> {
+    loop {
+        let frame = can_socket.read_frame().await?;
+        match rules.apply(frame, Instant::now()) {
+            Some(frame) => return Ok(frame),
+            None => metrics.frames_filtered(1),
+        }
+    }
+}
+
+/// Receives packets from the remote peer and writes their frames to the CAN interface.
+async fn forward_to_can(
+    parameter: &CanConnection,
+    can_socket: &CanFdSocket,
+    tunnel_socket: &TunnelSocket,
+    metrics: &CanTunnelMetrics,
+) -> Result<Infallible, CanTunnelError> {
+    let mut buffer = vec![0; RECEIVE_BUFFER_LENGTH];
+    let mut sequence_numbers = SequenceNumberTracker::default();
+
+    loop {
+        let length = tunnel_socket.receive(&mut buffer).await
+            .map_err(|source| CanTunnelError::Connection { transport: parameter.transport, remote_ip: parameter.remote_ip, source })?;
+
+        let packet = match CannelloniPacket::decode(&buffer[..length]) {
+            Ok(packet) => packet,
+            Err(cause) => {
+                debug!("Ignoring invalid CAN tunnel packet from peer <{}>: {cause}", parameter.remote_peer_id);
+                metrics.packet_invalid();
+                continue;
+            }
+        };
+
+        match sequence_numbers.check(packet.sequence_number) {
+            SequenceNumberCheck::InOrder => {}
+            SequenceNumberCheck::Gap { skipped } => metrics.packets_lost(skipped),
+            SequenceNumberCheck::Reordered => metrics.packet_reordered(),
+        }
+        metrics.frames_received(packet.frames.len());
+
+        for frame in packet.frames {
+            if let Err(cause) = can_socket.write_frame(&frame).await {
+                // e.g. when the transmit queue is full or a CAN FD frame is received for a classic CAN interface
+                debug!("Dropped CAN frame from peer <{}>, because it could not be written to interface <{}>: {cause}", parameter.remote_peer_id, parameter.can_interface_name);
+                metrics.frames_dropped(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::network_interface::manager::testing::run_in_network_namespace;
+    use crate::service::network_interface::manager::NetworkInterfaceManagerRef;
+    use opendut_model::peer::PeerId;
+    use opendut_model::util::Port;
+    use socketcan::{CanDataFrame, EmbeddedFrame, Frame, StandardId};
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::str::FromStr;
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn should_tunnel_frames_between_vcan_interfaces() -> anyhow::Result<()> {
+        run_in_network_namespace(|network_interface_manager| async move {
+            let vcan_a = create_vcan_interface(&network_interface_manager, "vcan-a").await?;
+            let vcan_b = create_vcan_interface(&network_interface_manager, "vcan-b").await?;
+
+            let _tunnel_a = CanTunnelHandle::start(can_connection(vcan_a.clone(), Port(10001), Port(10002)));
+            let _tunnel_b = CanTunnelHandle::start(can_connection(vcan_b.clone(), Port(10002), Port(10001)));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            let sender = CanFdSocket::open(&vcan_a.name())?;
+            let receiver = CanFdSocket::open(&vcan_b.name())?;
+
+            let frame = CanAnyFrame::from(CanDataFrame::new(StandardId::new(0x123).unwrap(), &[1, 2, 3]).unwrap());
+            sender.write_frame(&frame).await?;
+
+            let received = tokio::time::timeout(Duration::from_secs(1), receiver.read_frame()).await??;
+            assert_eq!(received.raw_id(), frame.raw_id());
+            assert_eq!(received.data(), frame.data());
+            Ok(())
+        })
+    }
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn should_write_frames_from_a_cannelloni_peer_to_the_can_interface() -> anyhow::Result<()> {
+        run_in_network_namespace(|network_interface_manager| async move {
+            let vcan = create_vcan_interface(&network_interface_manager, "vcan").await?;
+            let _tunnel = CanTunnelHandle::start(can_connection(vcan.clone(), Port(10001), Port(10002)));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            let receiver = CanFdSocket::open(&vcan.name())?;
+            let cannelloni_peer = tokio::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 10002)).await?;
+
+            let packet = [
+                2, 0, 0, 0, 1, // version, op code, sequence number, frame count
+                0x00, 0x00, 0x07, 0xff, 2, 0xca, 0xfe, // frame with ID 0x7ff and two bytes of data
+            ];
+            cannelloni_peer.send_to(&packet, (Ipv4Addr::LOCALHOST, 10001)).await?;
+
+            let received = tokio::time::timeout(Duration::from_secs(1), receiver.read_frame()).await??;
+            assert_eq!(received.raw_id(), 0x7ff);
+            assert_eq!(received.data(), &[0xca, 0xfe]);
+            Ok(())
+        })
+    }
+
+    async fn create_vcan_interface(network_interface_manager: &NetworkInterfaceManagerRef, name: &str) -> anyhow::Result<NetworkInterfaceName> {
+        let name = NetworkInterfaceName::from_str(name)?;
+        let interface = network_interface_manager.create_vcan_interface(&name).await?;
+        network_interface_manager.set_interface_up(&interface).await?;
+
+        let loopback = network_interface_manager.try_find_interface(&NetworkInterfaceName::from_str("lo")?).await?;
+        network_interface_manager.set_interface_up(&loopback).await?;
+        Ok(name)
+    }
+
+    fn can_connection(can_interface_name: NetworkInterfaceName, local_port: Port, remote_port: Port) -> CanConnection {
+        CanConnection {
+            remote_peer_id: PeerId::random(),
+            remote_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            remote_port,
+            local_port,
+            can_interface_name,
+            local_is_server: false,
+            buffer_timeout_microseconds: 100,
+            transport: CanTransport::Udp,
+            rules: Default::default(),
+            rate_limits: vec![],
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Wire format of [cannelloni](https://github.com/mguentner/cannelloni), so tunnels can be established with peers running cannelloni.
+//!
+//! A packet consists of a header (version, op code, sequence number, frame count), followed by the frames.
+//! Each frame is encoded as its SocketCAN ID word (including the EFF/RTR/ERR flags), its length and its data.
+//! CAN FD frames are marked in the length byte and carry an additional byte with their flags.
+//! Remote frames carry no data, only their requested length.
+
+use socketcan::frame::{can_frame_default, canfd_frame_default, CANFD_MAX_DLEN, CAN_MAX_DLEN, CAN_RTR_FLAG};
+use socketcan::{CanAnyFrame, EmbeddedFrame, Frame};
+
+const VERSION: u8 = 2;
+const OP_CODE_DATA: u8 = 0;
+const HEADER_LENGTH: usize = 5;
+const FRAME_HEADER_LENGTH: usize = 5;
+const CANFD_FRAME: u8 = 0x80;
+
+/// Upper bound for the size of an encoded packet, which keeps packets below the MTU of the VPN.
+pub const MAX_PACKET_LENGTH: usize = 1200;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CannelloniPacket {
+    pub sequence_number: u8,
+    pub frames: Vec<CanAnyFrame>,
+}
+
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum PacketDecodeError {
+    #[error("Packet is too short with {length} bytes.")]
+    TooShort { length: usize },
+    #[error("Unsupported protocol version {version}. Expected version {VERSION}.")]
+    UnsupportedVersion { version: u8 },
+    #[error("Unsupported op code {op_code}. Only data packets are supported.")]
+    UnsupportedOpCode { op_code: u8 },
+    #[error("Packet ends within frame {index} of {count} frames.")]
+    Truncated { index: u16, count: u16 },
+    #[error("Frame {index} has an invalid length of {length} bytes.")]
+    InvalidFrameLength { index: u16, length: u8 },
+}
+
+impl CannelloniPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let count = u16::try_from(self.frames.len())
+            .expect("number of frames in a packet should be limited by MAX_PACKET_LENGTH");
+
+        let mut buffer = Vec::with_capacity(HEADER_LENGTH + self.frames.iter().map(encoded_length).sum::<usize>());
+        buffer.push(VERSION);
+        buffer.push(OP_CODE_DATA);
+        buffer.push(self.sequence_number);
+        buffer.extend_from_slice(&count.to_be_bytes());
+
+        for frame in &self.frames {
+            encode_frame(frame, &mut buffer);
+        }
+        buffer
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, PacketDecodeError> {
+        if bytes.len() < HEADER_LENGTH {
+            return Err(PacketDecodeError::TooShort { length: bytes.len() });
+        }
+        let version = bytes[0];
+        if version != VERSION {
+            return Err(PacketDecodeError::UnsupportedVersion { version });
+        }
+        let op_code = bytes[1];
+        if op_code != OP_CODE_DATA {
+            return Err(PacketDecodeError::UnsupportedOpCode { op_code });
+        }
+        let sequence_number = bytes[2];
+        let count = u16::from_be_bytes([bytes[3], bytes[4]]);
+
+        let mut remaining = &bytes[HEADER_LENGTH..];
+        let mut frames = Vec::with_capacity(usize::from(count));
+
+        for index in 0..count {
+            let truncated = PacketDecodeError::Truncated { index, count };
+
+            let (frame_header, rest) = remaining.split_at_checked(FRAME_HEADER_LENGTH).ok_or(truncated.clone())?;
+            let can_id = u32::from_be_bytes([frame_header[0], frame_header[1], frame_header[2], frame_header[3]]);
+            let length_byte = frame_header[4];
+            remaining = rest;
+
+            let is_fd = length_byte & CANFD_FRAME != 0;
+            let length = length_byte & !CANFD_FRAME;
+            let max_length = if is_fd { CANFD_MAX_DLEN } else { CAN_MAX_DLEN };
+            if usize::from(length) > max_length {
+                return Err(PacketDecodeError::InvalidFrameLength { index, length });
+            }
+
+            let frame = if is_fd {
+                let (flags, rest) = remaining.split_first().ok_or(truncated.clone())?;
+                let (data, rest) = rest.split_at_checked(usize::from(length)).ok_or(truncated)?;
+                remaining = rest;
+
+                let mut raw = canfd_frame_default();
+                raw.can_id = can_id;
+                raw.len = length;
+                raw.flags = *flags;
+                raw.data[..data.len()].copy_from_slice(data);
+                CanAnyFrame::from(raw)
+            } else {
+                let mut raw = can_frame_default();
+                raw.can_id = can_id;
+                raw.can_dlc = length;
+                if can_id & CAN_RTR_FLAG == 0 {
+                    let (data, rest) = remaining.split_at_checked(usize::from(length)).ok_or(truncated)?;
+                    remaining = rest;
+                    raw.data[..data.len()].copy_from_slice(data);
+                }
+                CanAnyFrame::from(raw)
+            };
+            frames.push(frame);
+        }
+
+        Ok(Self { sequence_number, frames })
+    }
+}
+
+/// Size of the given frame within an encoded packet.
+pub fn encoded_length(frame: &CanAnyFrame) -> usize {
+    match frame {
+        CanAnyFrame::Normal(frame) => FRAME_HEADER_LENGTH + frame.data().len(),
+        CanAnyFrame::Remote(_) => FRAME_HEADER_LENGTH,
+        CanAnyFrame::Error(frame) => FRAME_HEADER_LENGTH + frame.data().len(),
+        CanAnyFrame::Fd(frame) => FRAME_HEADER_LENGTH + 1 + frame.data().len(),
+    }
+}
+
+/// Whether a packet with the given frames has room for another frame.
+pub fn fits_into_packet(frames: &[CanAnyFrame], frame: &CanAnyFrame) -> bool {
+    let length = HEADER_LENGTH + frames.iter().map(encoded_length).sum::<usize>() + encoded_length(frame);
+    length <= MAX_PACKET_LENGTH
+}
+
+fn encode_frame(frame: &CanAnyFrame, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&frame.id_word().to_be_bytes());
+
+    match frame {
+        CanAnyFrame::Normal(frame) => {
+            buffer.push(data_length(frame.data()));
+            buffer.extend_from_slice(frame.data());
+        }
+        CanAnyFrame::Remote(frame) => {
+            buffer.push(u8::try_from(frame.dlc()).unwrap_or(u8::MAX));
+        }
+        CanAnyFrame::Error(frame) => {
+            buffer.push(data_length(frame.data()));
+            buffer.extend_from_slice(frame.data());
+        }
+        CanAnyFrame::Fd(frame) => {
+            buffer.push(data_length(frame.data()) | CANFD_FRAME);
+            buffer.push(frame.flags().bits());
+            buffer.extend_from_slice(frame.data());
+        }
+    }
+}
+
+fn data_length(data: &[u8]) -> u8 {
+    u8::try_from(data.len())
+        .expect("data of a CAN frame should not exceed 64 bytes")
+}
+
+/// Tracks the sequence numbers of received packets, to detect lost and reordered packets.
+#[derive(Debug, Default)]
+pub struct SequenceNumberTracker {
+    expected: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SequenceNumberCheck {
+    InOrder,
+    /// The given number of packets were skipped. They were either lost or arrive later.
+    Gap { skipped: u8 },
+    /// The packet arrived after a packet with a higher sequence number.
+    Reordered,
+}
+
+impl SequenceNumberTracker {
+    pub fn check(&mut self, sequence_number: u8) -> SequenceNumberCheck {
+        let Some(expected) = self.expected else {
+            self.expected = Some(sequence_number.wrapping_add(1));
+            return SequenceNumberCheck::InOrder;
+        };
+
+        let distance = sequence_number.wrapping_sub(expected);
+        if distance == 0 {
+            self.expected = Some(sequence_number.wrapping_add(1));
+            SequenceNumberCheck::InOrder
+        } else if distance < 128 {
+            self.expected = Some(sequence_number.wrapping_add(1));
+            SequenceNumberCheck::Gap { skipped: distance }
+        } else {
+            SequenceNumberCheck::Reordered
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use socketcan::frame::FdFlags;
+    use socketcan::{CanDataFrame, CanFdFrame, CanRemoteFrame, ExtendedId, StandardId};
+
+    #[test]
+    fn should_encode_frames_like_cannelloni() {
+        let packet = CannelloniPacket {
+            sequence_number: 7,
+            frames: vec![
+                CanDataFrame::new(StandardId::new(0x123).unwrap(), &[0xde, 0xad]).unwrap().into(),
+                CanRemoteFrame::new_remote(ExtendedId::new(0x1abcdef).unwrap(), 4).unwrap().into(),
+                CanFdFrame::with_flags(StandardId::new(0x42).unwrap(), &[1; 12], FdFlags::BRS).unwrap().into(),
+            ],
+        };
+
+        let mut expected = vec![
+            2, 0, 7, 0, 3, // header
+            0x00, 0x00, 0x01, 0x23, 2, 0xde, 0xad, // data frame
+            0xc1, 0xab, 0xcd, 0xef, 4, // remote frame with EFF and RTR flag, without data
+            0x00, 0x00, 0x00, 0x42, 12 | 0x80, (FdFlags::BRS | FdFlags::FDF).bits(), // CAN FD frame
+        ];
+        expected.extend_from_slice(&[1; 12]);
+
+        assert_eq!(packet.encode(), expected);
+    }
+
+    #[test]
+    fn should_decode_encoded_packets() -> anyhow::Result<()> {
+        let packet = CannelloniPacket {
+            sequence_number: 255,
+            frames: vec![
+                CanDataFrame::new(ExtendedId::new(0x1fff_ffff).unwrap(), &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap().into(),
+                CanDataFrame::new(StandardId::new(0x7ff).unwrap(), &[]).unwrap().into(),
+                CanRemoteFrame::new_remote(StandardId::new(0x100).unwrap(), 8).unwrap().into(),
+                CanFdFrame::with_flags(ExtendedId::new(0x1234).unwrap(), &[0xaa; 64], FdFlags::BRS | FdFlags::ESI).unwrap().into(),
+            ],
+        };
+
+        let decoded = CannelloniPacket::decode(&packet.encode())?;
+
+        assert_eq!(decoded, packet);
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_invalid_packets() {
+        assert_eq!(CannelloniPacket::decode(&[2, 0, 1]), Err(PacketDecodeError::TooShort { length: 3 }));
+        assert_eq!(CannelloniPacket::decode(&[1, 0, 1, 0, 0]), Err(PacketDecodeError::UnsupportedVersion { version: 1 }));
+        assert_eq!(CannelloniPacket::decode(&[2, 1, 1, 0, 0]), Err(PacketDecodeError::UnsupportedOpCode { op_code: 1 }));
+        assert_eq!(
+            CannelloniPacket::decode(&[2, 0, 1, 0, 1, 0, 0, 0, 0x10, 4, 1, 2]),
+            Err(PacketDecodeError::Truncated { index: 0, count: 1 })
+        );
+        assert_eq!(
+            CannelloniPacket::decode(&[2, 0, 1, 0, 1, 0, 0, 0, 0x10, 9]),
+            Err(PacketDecodeError::InvalidFrameLength { index: 0, length: 9 })
+        );
+    }
+
+    #[test]
+    fn should_limit_the_size_of_packets() {
+        let frame: CanAnyFrame = CanFdFrame::new(StandardId::new(0x1).unwrap(), &[0; 64]).unwrap().into();
+
+        let mut frames = vec![];
+        while fits_into_packet(&frames, &frame) {
+            frames.push(frame);
+        }
+
+        assert_eq!(frames.len(), 17);
+        assert!(CannelloniPacket { sequence_number: 0, frames }.encode().len() <= MAX_PACKET_LENGTH);
+    }
+
+    #[test]
+    fn should_detect_lost_and_reordered_packets() {
+        let mut testee = SequenceNumberTracker::default();
+
+        assert_eq!(testee.check(254), SequenceNumberCheck::InOrder);
+        assert_eq!(testee.check(255), SequenceNumberCheck::InOrder);
+        assert_eq!(testee.check(0), SequenceNumberCheck::InOrder);
+        assert_eq!(testee.check(3), SequenceNumberCheck::Gap { skipped: 2 });
+        assert_eq!(testee.check(2), SequenceNumberCheck::Reordered);
+        assert_eq!(testee.check(4), SequenceNumberCheck::InOrder);
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use opendut_model::util::can::{CanFrameRules, CanIdFilter, CanRateLimit, CanRateLimitRule};
+use socketcan::id::id_from_raw;
+use socketcan::{CanAnyFrame, Frame};
+use tokio::time::Instant;
+
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Applies the filter, rewrite and rate limit rules of a CAN connection to the frames sent to the remote peer.
+/// Each rate limit only counts the frames matching its filters, so the rate limit of one local device does not throttle the others.
+pub(super) struct FrameRules {
+    rules: CanFrameRules,
+    rate_limiters: Vec<(CanRateLimitRule, RateLimiter)>,
+}
+
+impl FrameRules {
+    pub fn new(rules: CanFrameRules, rate_limits: Vec<CanRateLimitRule>) -> Self {
+        let connection_rate_limit = rules.rate_limit
+            .map(|rate_limit| CanRateLimitRule { filters: vec![CanIdFilter::ANY], rate_limit });
+        let rate_limiters = connection_rate_limit.into_iter()
+            .chain(rate_limits)
+            .map(|rule| {
+                let rate_limiter = RateLimiter::new(rule.rate_limit);
+                (rule, rate_limiter)
+            })
+            .collect();
+        Self { rules, rate_limiters }
+    }
+
+    /// Returns the frame to be forwarded, or `None` if the frame is dropped according to the rules.
+    /// Error frames are always forwarded unchanged.
+    pub fn apply(&mut self, mut frame: CanAnyFrame, now: Instant) -> Option<CanAnyFrame> {
+        if matches!(frame, CanAnyFrame::Error(_)) {
+            return Some(frame);
+        }
+
+        let id = frame.raw_id();
+        let forwarded_id = self.rules.apply(id)?;
+
+        let mut allowed = true;
+        for (rule, rate_limiter) in &mut self.rate_limiters {
+            if rule.matches(id) {
+                allowed &= rate_limiter.allows(id, now);
+            }
+        }
+        if !allowed {
+            return None;
+        }
+
+        if forwarded_id != id {
+            frame.set_id(id_from_raw(forwarded_id)?);
+        }
+        Some(frame)
+    }
+}
+
+/// Counts the frames per CAN identifier in fixed windows of one second.
+struct RateLimiter {
+    frames_per_second: u32,
+    windows: HashMap<u32, (Instant, u32)>,
+}
+
+impl RateLimiter {
+    fn new(rate_limit: CanRateLimit) -> Self {
+        Self {
+            frames_per_second: rate_limit.frames_per_second,
+            windows: HashMap::new(),
+        }
+    }
+
+    fn allows(&mut self, id: u32, now: Instant) -> bool {
+        let (window_start, count) = self.windows.entry(id).or_insert((now, 0));
+
+        if now.duration_since(*window_start) >= RATE_LIMIT_WINDOW {
+            *window_start = now;
+            *count = 0;
+        }
+
+        if *count < self.frames_per_second {
+            *count += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendut_model::util::can::CanIdRewrite;
+    use socketcan::{CanDataFrame, CanFdFrame, EmbeddedFrame, ExtendedId, StandardId};
+
+    #[test]
+    fn should_filter_and_rewrite_frames() -> anyhow::Result<()> {
+        let mut rules = FrameRules::new(CanFrameRules {
+            accept: vec![CanIdFilter::new(0x100, 0x700)?],
+            reject: vec![CanIdFilter::exact(0x1ff)?],
+            rewrites: vec![CanIdRewrite::new(0x123, 0x18ff_0001)?],
+            rate_limit: None,
+        }, vec![]);
+        let now = Instant::now();
+
+        assert!(rules.apply(data_frame(0x100), now).is_some());
+        assert!(rules.apply(data_frame(0x1ff), now).is_none());
+        assert!(rules.apply(data_frame(0x200), now).is_none());
+
+        let rewritten = rules.apply(data_frame(0x123), now).expect("Frame should be forwarded.");
+        assert!(rewritten.is_extended());
+        assert_eq!(rewritten.raw_id(), 0x18ff_0001);
+        assert_eq!(rewritten.data(), &[1, 2, 3]);
+
+        let fd_frame = CanAnyFrame::from(CanFdFrame::new(ExtendedId::new(0x123).unwrap(), &[0; 12]).unwrap());
+        let rewritten = rules.apply(fd_frame, now).expect("Frame should be forwarded.");
+        assert_eq!(rewritten.raw_id(), 0x18ff_0001);
+        assert_eq!(rewritten.data().len(), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn should_limit_the_rate_per_can_identifier() {
+        let mut rules = FrameRules::new(CanFrameRules {
+            rate_limit: Some(CanRateLimit { frames_per_second: 2 }),
+            ..CanFrameRules::default()
+        }, vec![]);
+        let start = Instant::now();
+
+        assert!(rules.apply(data_frame(0x100), start).is_some());
+        assert!(rules.apply(data_frame(0x100), start + Duration::from_millis(100)).is_some());
+        assert!(rules.apply(data_frame(0x100), start + Duration::from_millis(200)).is_none());
+        assert!(rules.apply(data_frame(0x200), start + Duration::from_millis(200)).is_some(), "Other identifiers should be limited separately.");
+
+        assert!(rules.apply(data_frame(0x100), start + Duration::from_millis(1000)).is_some(), "Limit should be reset after one second.");
+    }
+
+    #[test]
+    fn should_limit_the_rate_only_for_the_frames_matching_the_filters_of_a_rate_limit() -> anyhow::Result<()> {
+        let mut rules = FrameRules::new(CanFrameRules::default(), vec![
+            CanRateLimitRule { filters: vec![CanIdFilter::new(0x100, 0x700)?], rate_limit: CanRateLimit { frames_per_second: 1 } },
+        ]);
+        let start = Instant::now();
+
+        assert!(rules.apply(data_frame(0x100), start).is_some());
+        assert!(rules.apply(data_frame(0x100), start + Duration::from_millis(100)).is_none());
+        for millis in 0..10 {
+            assert!(rules.apply(data_frame(0x200), start + Duration::from_millis(millis)).is_some(), "Frames of other devices should not be limited.");
+        }
+        Ok(())
+    }
+
+    fn data_frame(id: u16) -> CanAnyFrame {
+        CanAnyFrame::from(CanDataFrame::new(StandardId::new(id).unwrap(), &[1, 2, 3]).unwrap())
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::{self, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use opendut_model::peer::configuration::parameter::{CanConnection, CanTransport};
+use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+use tokio::io::unix::AsyncFd;
+use tokio::net::UdpSocket;
+use tracing::{debug, warn};
+
+const IPPROTO_SCTP: i32 = 132;
+
+/// Socket for exchanging packets with the remote peer.
+pub(super) enum TunnelSocket {
+    /// Each peer binds its local port and sends to the port of the other peer.
+    Udp { socket: UdpSocket, remote: SocketAddr },
+    /// The server accepts a single connection from the remote peer, which acts as the client.
+    /// SCTP preserves the boundaries of the packets, so they are exchanged without additional framing.
+    Sctp { socket: AsyncFd<Socket> },
+}
+
+impl TunnelSocket {
+    pub async fn open(parameter: &CanConnection) -> io::Result<Self> {
+        let local = SocketAddr::new(unspecified_address(parameter.remote_ip), parameter.local_port.0);
+        let remote = SocketAddr::new(parameter.remote_ip, parameter.remote_port.0);
+
+        match parameter.transport {
+            CanTransport::Udp => {
+                let socket = UdpSocket::bind(local).await?;
+                Ok(TunnelSocket::Udp { socket, remote })
+            }
+            CanTransport::Sctp => {
+                let socket = if parameter.local_is_server {
+                    sctp_accept(local, parameter.remote_ip).await?
+                } else {
+                    sctp_connect(remote).await?
+                };
+                Ok(TunnelSocket::Sctp { socket })
+            }
+        }
+    }
+
+    pub async fn send(&self, packet: &[u8]) -> io::Result<()> {
+        match self {
+            TunnelSocket::Udp { socket, remote } => {
+                socket.send_to(packet, remote).await?;
+            }
+            TunnelSocket::Sctp { socket } => {
+                socket.async_io(tokio::io::Interest::WRITABLE, |socket| (&*socket).write(packet)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Receives the next packet from the remote peer into the buffer and returns its length.
+    pub async fn receive(&self, buffer: &mut [u8]) -> io::Result<usize> {
+        match self {
+            TunnelSocket::Udp { socket, remote } => loop {
+                let (length, sender) = socket.recv_from(buffer).await?;
+                if sender.ip() == remote.ip() {
+                    return Ok(length);
+                }
+                debug!("Ignoring CAN tunnel packet from unexpected sender <{sender}>. Expected packets from <{remote}>.");
+            },
+            TunnelSocket::Sctp { socket } => {
+                let length = socket.async_io(tokio::io::Interest::READABLE, |socket| (&*socket).read(buffer)).await?;
+                if length == 0 {
+                    return Err(io::Error::new(io::ErrorKind::ConnectionAborted, "SCTP connection was closed by the remote peer"));
+                }
+                Ok(length)
+            }
+        }
+    }
+}
+
+async fn sctp_accept(local: SocketAddr, remote_ip: IpAddr) -> io::Result<AsyncFd<Socket>> {
+    let listener = sctp_socket(local)?;
+    listener.set_reuse_address(true)?;
+    listener.bind(&SockAddr::from(local))?;
+    listener.listen(1)?;
+    let listener = AsyncFd::new(listener)?;
+
+    loop {
+        let (socket, sender) = listener.async_io(tokio::io::Interest::READABLE, |listener| listener.accept()).await?;
+
+        if sender.as_socket().map(|sender| sender.ip()) == Some(remote_ip) {
+            socket.set_nonblocking(true)?;
+            return AsyncFd::new(socket);
+        }
+        warn!("Rejected SCTP connection for CAN tunnel from unexpected peer <{sender:?}>. Expected connection from <{remote_ip}>.");
+    }
+}
+
+async fn sctp_connect(remote: SocketAddr) -> io::Result<AsyncFd<Socket>> {
+    let socket = sctp_socket(remote)?;
+
+    match socket.connect(&SockAddr::from(remote)) {
+        Ok(()) => {}
+        Err(cause) if cause.raw_os_error() == Some(nix::errno::Errno::EINPROGRESS as i32) => {}
+        Err(cause) => return Err(cause),
+    }
+    let socket = AsyncFd::new(socket)?;
+
+    let _ = socket.writable().await?;
+    if let Some(cause) = socket.get_ref().take_error()? {
+        return Err(cause);
+    }
+    Ok(socket)
+}
+
+fn sctp_socket(address: SocketAddr) -> io::Result<Socket> {
+    let socket = Socket::new(Domain::for_address(address), Type::STREAM, Some(Protocol::from(IPPROTO_SCTP)))?;
+    socket.set_nonblocking(true)?;
+    Ok(socket)
+}
+
+fn unspecified_address(remote_ip: IpAddr) -> IpAddr {
+    match remote_ip {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    }
+}
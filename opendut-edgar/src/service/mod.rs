@@ -1,12 +1,14 @@
 pub mod start;
 pub mod network_interface;
 pub mod peer_configuration;
+pub mod peer_configuration_cache;
+pub mod status;
 
 pub mod peer_messaging_client;
 mod vpn;
-mod test_execution;
-mod network_metrics;
-mod tasks;
+pub(crate) mod test_execution;
+pub(crate) mod network_metrics;
+pub(crate) mod tasks;
 mod can;
 pub(crate) mod service_runner;
 pub mod process_manager;
@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 use std::sync::Arc;
 use tracing::debug;
 
@@ -18,8 +18,8 @@ pub enum Error {
 }
 
 pub async fn setup_interfaces(
-    local_ip: &Ipv4Addr,
-    remote_ips: &[Ipv4Addr],
+    local_ip: &IpAddr,
+    remote_ips: &[IpAddr],
     bridge_name: &NetworkInterfaceName,
     network_interface_manager: NetworkInterfaceManagerRef,
 ) -> Result<(), Error> {
@@ -49,8 +49,8 @@ async fn remove_existing_interfaces(network_interface_manager: NetworkInterfaceM
 }
 
 async fn create_interface(
-    local_ip: &Ipv4Addr,
-    remote_ip: &Ipv4Addr,
+    local_ip: &IpAddr,
+    remote_ip: &IpAddr,
     interface_index: usize,
     bridge_name: &NetworkInterfaceName,
     network_interface_manager: NetworkInterfaceManagerRef,
@@ -59,7 +59,7 @@ async fn create_interface(
     let interface_name = NetworkInterfaceName::try_from(format!("{GRE_INTERFACE_NAME_PREFIX}{interface_index}"))
         .map_err(|cause| Error::Other { message: format!("Error while constructing GRE interface name: {cause}") })?;
 
-    let gre_interface = network_interface_manager.create_gretap_v4_interface(&interface_name, local_ip, remote_ip)
+    let gre_interface = network_interface_manager.create_gretap_interface(&interface_name, local_ip, remote_ip)
         .await
         .map_err(|error| Error::NetworkInterfaceError(Box::new(error)))?;
     debug!("Created GRE interface '{gre_interface}'.");
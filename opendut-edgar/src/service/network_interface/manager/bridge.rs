@@ -1,5 +1,6 @@
 use futures::TryStreamExt;
-use rtnetlink::{LinkBridge, LinkMessageBuilder};
+use rtnetlink::packet_route::link::{InfoBridge, InfoBridgePort, InfoData, InfoKind, InfoPortData, InfoPortKind, LinkAttribute, LinkInfo, LinkMessage};
+use rtnetlink::{LinkBridge, LinkMessageBuilder, LinkUnspec};
 use tracing::warn;
 use opendut_model::util::net::NetworkInterfaceName;
 use crate::service::network_interface::manager::interface::Interface;
@@ -64,6 +65,80 @@ impl NetworkInterfaceManager {
             .map_err(|cause| Error::ModificationFailure { name: interface.name.clone(), cause: format!("Failed to remove controller from interface. {cause}") })?;
         Ok(())
     }
+
+    /// Enables or disables the Spanning Tree Protocol of a bridge (equivalent to `ip link set dev <BRIDGE> type bridge stp_state <0|1>`).
+    pub async fn set_bridge_spanning_tree(&self, bridge: &Interface, enabled: bool) -> Result<(), Error> {
+        self.handle
+            .link()
+            .set(
+                LinkMessageBuilder::<LinkBridge>::new_with_info_kind(InfoKind::Bridge)
+                    .index(bridge.index)
+                    .set_info_data(InfoData::Bridge(vec![InfoBridge::StpState(u32::from(enabled))]))
+                    .build()
+            )
+            .execute().await
+            .map_err(|cause| Error::ModificationFailure { name: bridge.name.clone(), cause: format!("Failed to set spanning tree state of bridge. {cause}") })?;
+        Ok(())
+    }
+
+    pub async fn is_bridge_spanning_tree_enabled(&self, bridge: &Interface) -> Result<bool, Error> {
+        let link_info = self.get_link_info(bridge).await?;
+        let stp_state = link_info.iter().find_map(|info| match info {
+            LinkInfo::Data(InfoData::Bridge(bridge_infos)) => bridge_infos.iter().find_map(|bridge_info| match bridge_info {
+                InfoBridge::StpState(state) => Some(*state),
+                _ => None,
+            }),
+            _ => None,
+        });
+        Ok(stp_state.is_some_and(|state| state != 0))
+    }
+
+    /// Sets whether a bridge port may forward traffic to other isolated ports of the same bridge
+    /// (equivalent to `ip link set dev <INTERFACE> type bridge_slave isolated <on|off>`).
+    pub async fn set_bridge_port_isolated(&self, interface: &Interface, isolated: bool) -> Result<(), Error> {
+        self.handle
+            .link()
+            .set(
+                LinkUnspec::new_with_index(interface.index)
+                    .set_port_kind(InfoPortKind::Bridge)
+                    .set_port_data(InfoPortData::BridgePort(vec![InfoBridgePort::Isolated(isolated)]))
+                    .build()
+            )
+            .execute().await
+            .map_err(|cause| Error::ModificationFailure { name: interface.name.clone(), cause: format!("Failed to set isolation of bridge port. {cause}") })?;
+        Ok(())
+    }
+
+    pub async fn is_bridge_port_isolated(&self, interface: &Interface) -> Result<bool, Error> {
+        let link_info = self.get_link_info(interface).await?;
+        let isolated = link_info.iter().find_map(|info| match info {
+            LinkInfo::PortData(InfoPortData::BridgePort(port_infos)) => port_infos.iter().find_map(|port_info| match port_info {
+                InfoBridgePort::Isolated(isolated) => Some(*isolated),
+                _ => None,
+            }),
+            _ => None,
+        });
+        Ok(isolated.unwrap_or(false))
+    }
+
+    async fn get_link_info(&self, interface: &Interface) -> Result<Vec<LinkInfo>, Error> {
+        let link_message: Option<LinkMessage> = self.handle
+            .link()
+            .get()
+            .match_index(interface.index)
+            .execute()
+            .try_next().await
+            .map_err(|cause| Error::ListInterfaces { cause: cause.into() })?;
+
+        let link_info = link_message.into_iter()
+            .flat_map(|link_message| link_message.attributes)
+            .find_map(|attribute| match attribute {
+                LinkAttribute::LinkInfo(link_info) => Some(link_info),
+                _ => None,
+            })
+            .unwrap_or_default();
+        Ok(link_info)
+    }
 }
 
 #[cfg(test)]
@@ -71,6 +146,7 @@ mod tests {
     use tracing::debug;
     use opendut_model::util::net::NetworkInterfaceName;
     use crate::service::network_interface::manager::NetworkInterfaceManager;
+    use crate::service::network_interface::manager::testing::run_in_network_namespace;
 
     #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
     #[test_log::test(tokio::test)]
@@ -106,4 +182,23 @@ mod tests {
         debug!("{:?}", child_interfaces);
         Ok(())
     }
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn test_bridge_loop_protection() -> anyhow::Result<()> {
+        run_in_network_namespace(|manager| async move {
+            let bridge = manager.create_empty_bridge(&NetworkInterfaceName::try_from("bridge-stp")?).await?;
+            let port = manager.create_dummy_ipv4_interface(&NetworkInterfaceName::try_from("dummy-port")?).await?;
+            manager.join_interface_to_bridge(&port, &bridge).await?;
+
+            assert!(!manager.is_bridge_spanning_tree_enabled(&bridge).await?);
+            manager.set_bridge_spanning_tree(&bridge, true).await?;
+            assert!(manager.is_bridge_spanning_tree_enabled(&bridge).await?);
+
+            assert!(!manager.is_bridge_port_isolated(&port).await?);
+            manager.set_bridge_port_isolated(&port, true).await?;
+            assert!(manager.is_bridge_port_isolated(&port).await?);
+            Ok(())
+        })
+    }
 }
@@ -1,5 +1,5 @@
 use std::mem::size_of;
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use byteorder::{ByteOrder, NativeEndian, WriteBytesExt};
 use rtnetlink::packet_route::link::{InfoData, InfoKind, LinkAttribute, LinkInfo};
 use rtnetlink::packet_core::{Emitable, NlaBuffer, Parseable};
@@ -7,6 +7,7 @@ use rtnetlink::LinkMessageBuilder;
 
 pub trait Gretap {
     fn gretap_v4(self, local_ip: &Ipv4Addr, remote_ip: &Ipv4Addr) -> Self;
+    fn gretap_v6(self, local_ip: &Ipv6Addr, remote_ip: &Ipv6Addr) -> Self;
 }
 impl<T> Gretap for LinkMessageBuilder<T> {
     fn gretap_v4(self, local_ip: &Ipv4Addr, remote_ip: &Ipv4Addr) -> Self {
@@ -46,8 +47,49 @@ impl<T> Gretap for LinkMessageBuilder<T> {
             ]),
         )
     }
+
+    fn gretap_v6(self, local_ip: &Ipv6Addr, remote_ip: &Ipv6Addr) -> Self {
+
+        // Mirrors the defaults of the ip-command for `ip link add name <NAME> type ip6gretap local <LOCAL_IP> remote <REMOTE_IP>`.
+        // Compare with implementation of ip-command: https://github.com/shemminger/iproute2/blob/040325f543a1f7e6bb336355c136984e9bbe00d6/ip/link_gre6.c
+        let attributes = [
+            InfoGreTap::IKey(0),
+            InfoGreTap::OKey(0),
+            InfoGreTap::IFlags(0),
+            InfoGreTap::OFlags(0),
+            InfoGreTap::Local6(*local_ip),
+            InfoGreTap::Remote6(*remote_ip),
+            InfoGreTap::Ttl(DEFAULT_IPV6_HOP_LIMIT),
+            InfoGreTap::EncapLimit(DEFAULT_IPV6_TUNNEL_ENCAP_LIMIT),
+            InfoGreTap::FlowInfo(0),
+            InfoGreTap::Flags(0),
+            InfoGreTap::FwMark(0),
+            InfoGreTap::EncapType(0),
+            InfoGreTap::EncapFlags(0),
+            InfoGreTap::EncapSPort(0),
+            InfoGreTap::EncapDPort(0),
+        ];
+
+        let attributes = attributes.map(|attribute| {
+            let mut buffer = vec![0u8; attribute.buffer_len()];
+            attribute.emit(&mut buffer);
+            let buffer = NlaBuffer::new(&buffer);
+            rtnetlink::packet_route::link::InfoGreTap6::parse(&buffer)
+                .expect("GRE attribute should be parseable from constant") //if not, this is a bug in how we specify the attribute
+        });
+
+        self.append_extra_attribute(
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::GreTap6),
+                LinkInfo::Data(InfoData::GreTap6(attributes.to_vec())),
+            ]),
+        )
+    }
 }
 
+const DEFAULT_IPV6_HOP_LIMIT: u8 = 64;
+const DEFAULT_IPV6_TUNNEL_ENCAP_LIMIT: u8 = 4;
+
 #[allow(dead_code)]
 pub(crate) enum InfoGreTap { // https://elixir.bootlin.com/linux/v6.5.3/source/include/uapi/linux/if_tunnel.h#L117
     Unspec,
@@ -58,12 +100,14 @@ pub(crate) enum InfoGreTap { // https://elixir.bootlin.com/linux/v6.5.3/source/i
     OKey(u32),
     Local(u32),
     Remote(u32),
+    Local6(Ipv6Addr),
+    Remote6(Ipv6Addr),
     Ttl(u8),
     Tos(u8),
     Pmtudisc(u8),
-    EncapLimit,
-    FlowInfo,
-	Flags,
+    EncapLimit(u8),
+    FlowInfo(u32),
+	Flags(u32),
 	EncapType(u16),
 	EncapFlags(u16),
 	EncapSPort(u16),
@@ -88,12 +132,14 @@ impl rtnetlink::packet_core::Nla for InfoGreTap {
             Self::OKey(_) => size_of::<u32>(),
             Self::Local(_) => size_of::<u32>(),
             Self::Remote(_) => size_of::<u32>(),
+            Self::Local6(_) => size_of::<Ipv6Addr>(),
+            Self::Remote6(_) => size_of::<Ipv6Addr>(),
             Self::Ttl(_) => size_of::<u8>(),
             Self::Tos(_) => size_of::<u8>(),
             Self::Pmtudisc(_) => size_of::<u8>(),
-            Self::EncapLimit => unimplemented!(),
-            Self::FlowInfo => unimplemented!(),
-            Self::Flags => unimplemented!(),
+            Self::EncapLimit(_) => size_of::<u8>(),
+            Self::FlowInfo(_) => size_of::<u32>(),
+            Self::Flags(_) => size_of::<u32>(),
             Self::EncapType(_) => size_of::<u16>(),
             Self::EncapFlags(_) => size_of::<u16>(),
             Self::EncapSPort(_) => size_of::<u16>(),
@@ -118,12 +164,14 @@ impl rtnetlink::packet_core::Nla for InfoGreTap {
             Self::OKey(_)         => 0x05,
             Self::Local(_)        => 0x06,
             Self::Remote(_)       => 0x07,
+            Self::Local6(_)       => 0x06,
+            Self::Remote6(_)      => 0x07,
             Self::Ttl(_)          => 0x08,
             Self::Tos(_)          => 0x09,
             Self::Pmtudisc(_)     => 0x0a,
-            Self::EncapLimit      => 0x0b,
-            Self::FlowInfo        => 0x0c,
-            Self::Flags           => 0x0d,
+            Self::EncapLimit(_)   => 0x0b,
+            Self::FlowInfo(_)     => 0x0c,
+            Self::Flags(_)        => 0x0d,
             Self::EncapType(_)    => 0x0e,
             Self::EncapFlags(_)   => 0x0f,
             Self::EncapSPort(_)   => 0x10,
@@ -148,12 +196,14 @@ impl rtnetlink::packet_core::Nla for InfoGreTap {
             Self::OKey(bytes) => NativeEndian::write_u32(buffer, *bytes),
             Self::Local(bytes) => NativeEndian::write_u32(buffer, *bytes),
             Self::Remote(bytes) => NativeEndian::write_u32(buffer, *bytes),
+            Self::Local6(address) => buffer[..size_of::<Ipv6Addr>()].copy_from_slice(&address.octets()),
+            Self::Remote6(address) => buffer[..size_of::<Ipv6Addr>()].copy_from_slice(&address.octets()),
             Self::Ttl(byte) => WriteBytesExt::write_u8(&mut buffer, *byte).unwrap(),
             Self::Tos(byte) => WriteBytesExt::write_u8(&mut buffer, *byte).unwrap(),
             Self::Pmtudisc(byte) => WriteBytesExt::write_u8(&mut buffer, *byte).unwrap(),
-            Self::EncapLimit => unimplemented!(),
-            Self::FlowInfo => unimplemented!(),
-            Self::Flags => unimplemented!(),
+            Self::EncapLimit(byte) => WriteBytesExt::write_u8(&mut buffer, *byte).unwrap(),
+            Self::FlowInfo(bytes) => NativeEndian::write_u32(buffer, *bytes),
+            Self::Flags(bytes) => NativeEndian::write_u32(buffer, *bytes),
             Self::EncapType(bytes) => NativeEndian::write_u16(buffer, *bytes),
             Self::EncapFlags(bytes) => NativeEndian::write_u16(buffer, *bytes),
             Self::EncapSPort(bytes) => NativeEndian::write_u16(buffer, *bytes),
@@ -173,8 +223,9 @@ impl rtnetlink::packet_core::Nla for InfoGreTap {
 
 #[cfg(test)]
 mod tests {
-    use std::net::Ipv4Addr;
+    use std::net::{Ipv4Addr, Ipv6Addr};
     use rtnetlink::packet_core::{DefaultNla, Nla};
+    use super::InfoGreTap;
 
     #[test]
     fn test_nla_conversion() {
@@ -187,4 +238,17 @@ mod tests {
 
         assert_eq!(result, expected_address);
     }
+
+    #[test]
+    fn test_ipv6_address_nla() {
+        let address = Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1);
+        let local_address_nla = InfoGreTap::Local6(address);
+        assert_eq!(local_address_nla.kind(), 0x06);
+        assert_eq!(local_address_nla.value_len(), 16);
+
+        let buffer: &mut [u8; 16] = &mut [0; 16];
+        local_address_nla.emit_value(buffer);
+
+        assert_eq!(Ipv6Addr::from(*buffer), address);
+    }
 }
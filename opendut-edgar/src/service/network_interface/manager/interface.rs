@@ -1,9 +1,9 @@
 use crate::service::network_interface::manager::vcan::VIRTUAL_CAN_INTERFACE_TYPE;
-use rtnetlink::packet_route::link::{InfoData, InfoGreTap, InfoKind, LinkAttribute, LinkFlags, LinkInfo, LinkMessage, Prop};
+use rtnetlink::packet_route::link::{InfoData, InfoKind, LinkAttribute, LinkFlags, LinkInfo, LinkMessage, Prop};
 use rtnetlink::packet_core::Nla;
 use opendut_model::util::net::{NetworkInterfaceName, NetworkInterfaceNameError};
 use std::fmt::Formatter;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 
 pub const CAN_INTERFACE_TYPE: &str = "can";
@@ -25,8 +25,8 @@ pub struct Interface {
 pub enum NetlinkInterfaceKind {
     Bridge,
     GreTap {
-        local: Ipv4Addr,
-        remote: Ipv4Addr,
+        local: IpAddr,
+        remote: IpAddr,
     },
     Vcan,
     Can(Option<InfoData>),
@@ -103,7 +103,7 @@ impl TryFrom<LinkMessage> for Interface {
 
         let kind = match interface_kind {
             InfoKind::Bridge => { NetlinkInterfaceKind::Bridge }
-            InfoKind::GreTap => { determine_gre_interface(&link_message)? }
+            InfoKind::GreTap | InfoKind::GreTap6 => { determine_gre_interface(&link_message)? }
             InfoKind::Other(ref name) => {
                 if name.eq(VIRTUAL_CAN_INTERFACE_TYPE) {
                     NetlinkInterfaceKind::Vcan
@@ -188,35 +188,44 @@ fn find_interface_alias(attributes: &[LinkAttribute]) -> Option<String> {
 
 
 fn determine_gre_interface(link_message: &LinkMessage) -> Result<NetlinkInterfaceKind, NetlinkConversionError> {
-    fn extract_address(info_gre_tap: &[InfoGreTap], kind: u16) -> Option<Ipv4Addr> {
-        info_gre_tap.iter().find_map(|info_gre_tap|{
-            if let InfoGreTap::Other(nla) = info_gre_tap
-            && nla.kind() == kind {
-                let buffer: &mut [u8; 4] = &mut [0; 4];
-                nla.emit_value(buffer);
-                let octets: [u8; 4] = buffer[0..4].try_into().ok()?;
-                return Some(Ipv4Addr::from(octets))
+    fn extract_address<'a>(mut attributes: impl Iterator<Item=&'a dyn Nla>, kind: u16) -> Option<IpAddr> {
+        attributes.find_map(|nla| {
+            if nla.kind() != kind {
+                return None;
+            }
+            match nla.value_len() {
+                4 => {
+                    let mut octets = [0u8; 4];
+                    nla.emit_value(&mut octets);
+                    Some(IpAddr::V4(Ipv4Addr::from(octets)))
+                }
+                16 => {
+                    let mut octets = [0u8; 16];
+                    nla.emit_value(&mut octets);
+                    Some(IpAddr::V6(Ipv6Addr::from(octets)))
+                }
+                _ => None,
             }
-            None
         })
     }
-    
+
     struct GreAddresses {
-        local: Ipv4Addr,
-        remote: Ipv4Addr,
+        local: IpAddr,
+        remote: IpAddr,
     }
 
     let gre_addresses = link_message.attributes.iter().find_map(|nla| {
         if let LinkAttribute::LinkInfo(link_info) = nla {
             link_info.iter().find_map(|link_info| {
-                if let LinkInfo::Data(InfoData::GreTap(gretap)) = link_info {
-                    let local_address = extract_address(gretap, 0x06);
-                    let remote_address = extract_address(gretap, 0x07);
-                    if let (Some(local), Some(remote)) = (local_address, remote_address) {
-                        Some(GreAddresses { local, remote })
-                    } else {
-                        None
-                    }
+                let attributes: Vec<&dyn Nla> = match link_info {
+                    LinkInfo::Data(InfoData::GreTap(gretap)) => gretap.iter().map(|attribute| attribute as &dyn Nla).collect(),
+                    LinkInfo::Data(InfoData::GreTap6(gretap)) => gretap.iter().map(|attribute| attribute as &dyn Nla).collect(),
+                    _ => return None,
+                };
+                let local_address = extract_address(attributes.iter().copied(), 0x06);
+                let remote_address = extract_address(attributes.iter().copied(), 0x07);
+                if let (Some(local), Some(remote)) = (local_address, remote_address) {
+                    Some(GreAddresses { local, remote })
                 } else {
                     None
                 }
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 use std::io;
-use std::net::{Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 
 use futures::TryStreamExt;
@@ -78,8 +78,16 @@ impl NetworkInterfaceManager {
         Ok(interface)
     }
 
-    // We only support IPv4 for now, as NetBird only assigns IPv4 addresses to peers.
-    // This does not prevent IPv6 traffic from being routed between peers.
+    /// Creates a gretap interface for IPv4 or an ip6gretap interface for IPv6 endpoints.
+    /// Both endpoints need to be of the same address family.
+    pub async fn create_gretap_interface(&self, name: &NetworkInterfaceName, local_ip: &IpAddr, remote_ip: &IpAddr) -> Result<Interface, Error> {
+        match (local_ip, remote_ip) {
+            (IpAddr::V4(local_ip), IpAddr::V4(remote_ip)) => self.create_gretap_v4_interface(name, local_ip, remote_ip).await,
+            (IpAddr::V6(local_ip), IpAddr::V6(remote_ip)) => self.create_gretap_v6_interface(name, local_ip, remote_ip).await,
+            _ => Err(Error::GretapAddressFamilyMismatch { name: name.clone(), local_ip: *local_ip, remote_ip: *remote_ip }),
+        }
+    }
+
     pub async fn create_gretap_v4_interface(&self, name: &NetworkInterfaceName, local_ip: &Ipv4Addr, remote_ip: &Ipv4Addr) -> Result<Interface, Error> {
         self.handle
             .link()
@@ -94,6 +102,20 @@ impl NetworkInterfaceManager {
         Ok(interface)
     }
 
+    pub async fn create_gretap_v6_interface(&self, name: &NetworkInterfaceName, local_ip: &Ipv6Addr, remote_ip: &Ipv6Addr) -> Result<Interface, Error> {
+        self.handle
+            .link()
+            .add(
+                rtnetlink::LinkUnspec::new_with_name(&name.name())
+                    .gretap_v6(local_ip, remote_ip)
+                    .build()
+            )
+            .execute().await
+            .map_err(|cause| Error::GretapCreation { name: name.clone(), cause: cause.into() })?;
+        let interface = self.try_find_interface(name).await?;
+        Ok(interface)
+    }
+
     pub async fn set_interface_up(&self, interface: &Interface) -> Result<(), Error> {
         debug!("Set interface {} up.", interface.name);
         self.handle
@@ -218,6 +240,8 @@ pub enum Error {
     DeleteInterface { interface: Box<Interface>, cause: Box<rtnetlink::Error> },
     #[error("Failure while creating gretap interface '{name}': {cause}")]
     GretapCreation { name: NetworkInterfaceName, cause: Box<rtnetlink::Error> },
+    #[error("Cannot create gretap interface '{name}' between addresses of different IP families: local '{local_ip}', remote '{remote_ip}'")]
+    GretapAddressFamilyMismatch { name: NetworkInterfaceName, local_ip: IpAddr, remote_ip: IpAddr },
     #[error("Interface with name '{name}' not found.")]
     InterfaceNotFound { name: NetworkInterfaceName },
     #[error("Failure while listing interfaces: {cause}")]
@@ -239,9 +263,46 @@ pub enum Error {
 }
 
 
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::future::Future;
+    use nix::sched::CloneFlags;
+    use crate::service::network_interface::manager::{NetworkInterfaceManager, NetworkInterfaceManagerRef};
+
+    /// Runs the given test in a fresh network namespace, so it does not touch the interfaces of the host.
+    /// The namespace is entered on a dedicated thread and lives as long as that thread.
+    /// Requires the capability CAP_SYS_ADMIN, e.g. when running as root.
+    pub fn run_in_network_namespace<F, Fut>(test: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(NetworkInterfaceManagerRef) -> Fut + Send + 'static,
+        Fut: Future<Output=anyhow::Result<()>>,
+    {
+        std::thread::spawn(move || {
+            nix::sched::unshare(CloneFlags::CLONE_NEWNET)?;
+
+            // A single-threaded runtime ensures that the netlink socket is opened and polled within the new namespace.
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?;
+
+            runtime.block_on(async move {
+                let network_interface_manager = NetworkInterfaceManager::create()?;
+                test(network_interface_manager).await
+            })
+        })
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::service::network_interface::manager::NetworkInterfaceManager;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::str::FromStr;
+    use crate::service::network_interface::manager::interface::NetlinkInterfaceKind;
+    use crate::service::network_interface::manager::testing::run_in_network_namespace;
+    use crate::service::network_interface::manager::{Error, NetworkInterfaceManager};
+    use opendut_model::util::net::NetworkInterfaceName;
     use tracing::debug;
 
     /// How to run integration tests in dev environment: 
@@ -260,4 +321,36 @@ mod tests {
         debug!("Network interfaces: {:?}", result);
         Ok(())
     }
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn test_create_gretap_v6_interface() -> anyhow::Result<()> {
+        run_in_network_namespace(|manager| async move {
+            let name = NetworkInterfaceName::try_from("gre6-test")?;
+            let local_ip = Ipv6Addr::from_str("fd00::1")?;
+            let remote_ip = Ipv6Addr::from_str("fd00::2")?;
+
+            let interface = manager.create_gretap_interface(&name, &IpAddr::V6(local_ip), &IpAddr::V6(remote_ip)).await?;
+
+            assert_eq!(interface.name, name);
+            assert_eq!(interface.kind, NetlinkInterfaceKind::GreTap { local: IpAddr::V6(local_ip), remote: IpAddr::V6(remote_ip) });
+            Ok(())
+        })
+    }
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn test_reject_gretap_interface_between_different_ip_families() -> anyhow::Result<()> {
+        run_in_network_namespace(|manager| async move {
+            let name = NetworkInterfaceName::try_from("gre-mixed")?;
+            let local_ip = IpAddr::V4(Ipv4Addr::from_str("192.168.0.1")?);
+            let remote_ip = IpAddr::V6(Ipv6Addr::from_str("fd00::2")?);
+
+            let result = manager.create_gretap_interface(&name, &local_ip, &remote_ip).await;
+
+            assert!(matches!(result, Err(Error::GretapAddressFamilyMismatch { .. })));
+            assert!(manager.find_interface(&name).await?.is_none());
+            Ok(())
+        })
+    }
 }
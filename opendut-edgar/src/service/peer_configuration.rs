@@ -1,20 +1,25 @@
-use crate::service::can::can_manager::CanManagerRef;
-use crate::service::network_interface::manager::NetworkInterfaceManagerRef;
+use crate::service::can::can_manager::{CanManagerExt, CanManagerRef};
+use crate::service::network_interface::manager::{NetworkInterfaceManager, NetworkInterfaceManagerRef};
 use crate::service::tasks;
 use crate::service::test_execution::executor_manager::ExecutorManagerRef;
-use opendut_model::peer::configuration::{EdgePeerConfigurationState, PeerConfiguration};
+use opendut_model::peer::configuration::{CachedPeerConfiguration, EdgePeerConfigurationState, PeerConfiguration};
 
 use std::fmt::Formatter;
 use std::sync::Arc;
+use opendut_util::settings::LoadedConfig;
 use tokio::sync::mpsc;
-use tracing::{debug, error};
+use tracing::{debug, error, info, warn};
 use crate::service;
+use crate::service::peer_configuration_cache::PeerConfigurationCache;
+use crate::service::status::{ConfigurationStatus, ServiceStatusWriter};
 use crate::service::service_runner::CollectedResult;
 use super::network_metrics::manager::NetworkMetricsManagerRef;
 
 #[derive(Debug)]
 pub struct ApplyPeerConfigurationParams {
     pub peer_configuration: PeerConfiguration,
+    /// Set, if the configuration was loaded from the local cache rather than received from CARL.
+    pub cached: Option<CachedPeerConfiguration>,
     pub network_interface_management: NetworkInterfaceManagement,
     pub executor_manager: ExecutorManagerRef,
     pub metrics_manager: NetworkMetricsManagerRef,
@@ -24,6 +29,20 @@ pub enum NetworkInterfaceManagement {
     Enabled { network_interface_manager: NetworkInterfaceManagerRef, can_manager: CanManagerRef },
     Disabled,
 }
+
+impl NetworkInterfaceManagement {
+    pub fn load(settings: &LoadedConfig) -> anyhow::Result<Self> {
+        let network_interface_management_enabled = settings.config.get::<bool>("network.interface.management.enabled")?;
+        if network_interface_management_enabled {
+            let network_interface_manager: NetworkInterfaceManagerRef = NetworkInterfaceManager::create()?;
+            let can_manager = CanManagerRef::new_shared();
+
+            Ok(NetworkInterfaceManagement::Enabled { network_interface_manager, can_manager })
+        } else {
+            Ok(NetworkInterfaceManagement::Disabled)
+        }
+    }
+}
 impl std::fmt::Debug for NetworkInterfaceManagement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -35,12 +54,30 @@ impl std::fmt::Debug for NetworkInterfaceManagement {
 
 pub async fn spawn_peer_configurations_handler(
     mut rx_peer_configuration: mpsc::Receiver<ApplyPeerConfigurationParams>,
-    tx_peer_configuration_state: mpsc::Sender<EdgePeerConfigurationState>
+    tx_peer_configuration_state: mpsc::Sender<EdgePeerConfigurationState>,
+    peer_configuration_cache: Option<PeerConfigurationCache>,
+    status_writer: ServiceStatusWriter,
 ) -> anyhow::Result<()> {
     tokio::spawn(async move {
         while let Some(apply_peer_configuration_params) = rx_peer_configuration.recv().await {
+            let peer_configuration = apply_peer_configuration_params.peer_configuration.clone();
+            let cached = apply_peer_configuration_params.cached.clone();
+
             let result = apply_peer_configuration(apply_peer_configuration_params).await;
-            let state = EdgePeerConfigurationState::from(result);
+            let success = result.success;
+
+            let mut state = EdgePeerConfigurationState::from(result);
+            state.cached = cached;
+
+            status_writer.update(|status| status.configuration = Some(ConfigurationStatus::new(&peer_configuration, &state)));
+
+            if let Some(cache) = &peer_configuration_cache
+                && state.cached.is_none()
+                && success {
+                let _ = cache.store(peer_configuration)
+                    .inspect_err(|cause| warn!("Failed to store peer configuration in cache: {cause}"));
+            }
+
             let _ = tx_peer_configuration_state.send(state).await
                 .inspect_err(|err| error!("Failed to send peer configuration state to CARL. {err}"));
         }
@@ -52,12 +89,18 @@ pub async fn spawn_peer_configurations_handler(
 async fn apply_peer_configuration(params: ApplyPeerConfigurationParams) -> CollectedResult {
     let ApplyPeerConfigurationParams { 
         peer_configuration,
+        cached,
         network_interface_management, 
         executor_manager, metrics_manager } = params;
 
+    if let Some(cached) = cached {
+        info!("Applying cached peer configuration with checksum '{}', as CARL is not reachable.", cached.checksum);
+    }
+
     let resolver = tasks::task_resolver::ServiceTaskResolver::new(
         peer_configuration.clone(),
         network_interface_management.clone(),
+        Arc::clone(&executor_manager),
         Arc::clone(&metrics_manager),
     );
     let result = service::service_runner::run_tasks(peer_configuration.clone(), resolver).await;
@@ -68,12 +111,6 @@ async fn apply_peer_configuration(params: ApplyPeerConfigurationParams) -> Colle
         return result;
     }
 
-    {
-        let mut executor_manager = executor_manager.lock().await;
-        executor_manager.terminate_executors();
-        executor_manager.create_new_executors(peer_configuration.executors);
-    }
-
     debug!("Peer configuration has been successfully applied.");
     result
 }
@@ -0,0 +1,198 @@
+use std::io::Write;
+use std::ops::Not;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use anyhow::{anyhow, bail, Context};
+use config::Config;
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+
+use opendut_model::peer::configuration::{CachedPeerConfiguration, PeerConfiguration};
+use opendut_model::proto;
+use opendut_util::project;
+
+use crate::fs;
+use crate::fs::os::unix::fs::OpenOptionsExt;
+
+/// Version of the format of the cache file. Caches of other versions are ignored.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// The cache file may only be read or modified by its owner.
+const CACHE_FILE_MODE: u32 = 0o600;
+
+/// Stores the last successfully applied [`PeerConfiguration`] on disk,
+/// so that it can be reapplied after a restart, while CARL is not reachable.
+#[derive(Clone, Debug)]
+pub struct PeerConfigurationCache {
+    file: PathBuf,
+}
+
+impl PeerConfigurationCache {
+    /// Returns `None`, if the cache is disabled in the configuration.
+    pub fn load_from_settings(settings: &Config) -> anyhow::Result<Option<Self>> {
+        let enabled = settings.get_bool("peer.configuration.cache.enabled")?;
+        if enabled {
+            let file = project::make_path_absolute(settings.get_string("peer.configuration.cache.file")?)?;
+            Ok(Some(Self { file }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Loads the cached configuration. Returns `None`, if no configuration was cached yet.
+    pub fn load(&self) -> anyhow::Result<Option<(PeerConfiguration, CachedPeerConfiguration)>> {
+        if self.file.exists().not() {
+            debug!("No cached peer configuration found at '{}'.", self.file.display());
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&self.file)?;
+        let cache = proto::peer::configuration::api::PeerConfigurationCache::decode(bytes.as_slice())
+            .context("Failed to decode cached peer configuration")?;
+
+        if cache.version != CACHE_FORMAT_VERSION {
+            bail!("Cached peer configuration has version {}, but only version {CACHE_FORMAT_VERSION} is supported.", cache.version);
+        }
+
+        let checksum = checksum(&cache.configuration);
+        if checksum != cache.checksum {
+            bail!("Checksum of cached peer configuration does not match. Expected '{}', but calculated '{checksum}'.", cache.checksum);
+        }
+
+        let configuration = proto::peer::configuration::api::PeerConfiguration::decode(cache.configuration.as_slice())
+            .context("Failed to decode cached peer configuration")?;
+        let configuration = PeerConfiguration::try_from(configuration)
+            .map_err(|cause| anyhow!("Failed to convert cached peer configuration: {cause}"))?;
+
+        let stored_at = cache.stored_at
+            .ok_or_else(|| anyhow!("Cached peer configuration has no timestamp."))?;
+        let stored_at = SystemTime::try_from(stored_at)?;
+
+        Ok(Some((configuration, CachedPeerConfiguration { checksum, stored_at })))
+    }
+
+    /// Replaces the cached configuration. The file is replaced atomically, so a crash does not leave a partially written cache behind.
+    /// The cache is only readable by the owner, as the configuration may contain sensitive data, like network addresses and keys.
+    pub fn store(&self, configuration: PeerConfiguration) -> anyhow::Result<()> {
+        let configuration = proto::peer::configuration::api::PeerConfiguration::from(configuration).encode_to_vec();
+
+        let cache = proto::peer::configuration::api::PeerConfigurationCache {
+            version: CACHE_FORMAT_VERSION,
+            checksum: checksum(&configuration),
+            stored_at: Some(SystemTime::now().into()),
+            configuration,
+        };
+
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary_file = self.file.with_extension("tmp");
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(CACHE_FILE_MODE)
+            .open(&temporary_file)?;
+        file.set_permissions(std::fs::Permissions::from_mode(CACHE_FILE_MODE))?; //in case the temporary file was left behind with other permissions
+        file.write_all(&cache.encode_to_vec())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temporary_file, &self.file)?;
+
+        debug!("Stored peer configuration in cache at '{}'.", self.file.display());
+        Ok(())
+    }
+
+    /// Removes a cache, which cannot be loaded, so that it is not reapplied on every start.
+    pub fn discard(&self) {
+        let _ = fs::remove_file(&self.file)
+            .inspect_err(|cause| warn!("Failed to remove cached peer configuration at '{}': {cause}", self.file.display()));
+    }
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    fn cache_in(dir: &tempfile::TempDir) -> PeerConfigurationCache {
+        PeerConfigurationCache { file: dir.path().join("peer-configuration.cache") }
+    }
+
+    #[test]
+    fn should_load_stored_peer_configuration() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = cache_in(&dir);
+
+        assert_that!(cache.load()?, none());
+
+        let configuration = PeerConfiguration::default();
+        cache.store(Clone::clone(&configuration))?;
+
+        let (loaded, cached) = cache.load()?.expect("Cached peer configuration should be loadable.");
+        assert_that!(loaded, eq(&configuration));
+        assert_that!(cached.checksum.len(), eq(64));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_store_cache_only_accessible_by_owner() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = cache_in(&dir);
+
+        cache.store(PeerConfiguration::default())?;
+
+        let mode = fs::metadata(&cache.file)?.permissions().mode();
+        assert_that!(mode & 0o777, eq(CACHE_FILE_MODE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_cache_with_mismatching_checksum() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = cache_in(&dir);
+
+        let tampered = proto::peer::configuration::api::PeerConfigurationCache {
+            version: CACHE_FORMAT_VERSION,
+            checksum: checksum(b"something else"),
+            stored_at: Some(SystemTime::now().into()),
+            configuration: proto::peer::configuration::api::PeerConfiguration::from(PeerConfiguration::default()).encode_to_vec(),
+        };
+        fs::write(&cache.file, tampered.encode_to_vec())?;
+
+        assert_that!(cache.load(), err(anything()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_cache_with_unknown_version() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = cache_in(&dir);
+
+        let configuration = proto::peer::configuration::api::PeerConfiguration::from(PeerConfiguration::default()).encode_to_vec();
+        let unknown_version = proto::peer::configuration::api::PeerConfigurationCache {
+            version: CACHE_FORMAT_VERSION + 1,
+            checksum: checksum(&configuration),
+            stored_at: Some(SystemTime::now().into()),
+            configuration,
+        };
+        fs::write(&cache.file, unknown_version.encode_to_vec())?;
+
+        assert_that!(cache.load(), err(anything()));
+
+        Ok(())
+    }
+}
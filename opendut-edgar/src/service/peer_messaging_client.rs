@@ -11,16 +11,15 @@ use tonic::Code;
 use tracing::{debug, error, info, trace, warn, Span};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 use opendut_carl_api::carl::{broker, GrpcUpstream};
-use opendut_carl_api::carl::broker::Upstream;
+use opendut_carl_api::carl::broker::{ExecutorStatusUpdate, Upstream};
 use opendut_carl_api::carl::CarlClient;
-use opendut_model::peer::configuration::EdgePeerConfigurationState;
+use opendut_model::peer::configuration::{CachedPeerConfiguration, EdgePeerConfigurationState, PeerConfiguration};
 use opendut_model::peer::PeerId;
 use opendut_util::settings::LoadedConfig;
 use crate::common::carl;
-use crate::service::can::can_manager::{CanManagerRef, CanManagerExt};
-use crate::service::network_interface::manager::{NetworkInterfaceManager, NetworkInterfaceManagerRef};
 use crate::service::network_metrics::manager::{NetworkMetricsManager, NetworkMetricsManagerRef};
 use crate::service::peer_configuration::{ApplyPeerConfigurationParams, NetworkInterfaceManagement};
+use crate::service::status::{CarlConnectionState, ServiceStatusWriter};
 use crate::service::test_execution::executor_manager::{ExecutorManager, ExecutorManagerRef};
 use crate::service::vpn;
 
@@ -29,6 +28,7 @@ pub struct PeerMessagingClient {
     handle_stream_info: HandleStreamInfo,
     settings: LoadedConfig,
     tx_peer_configuration: mpsc::Sender<ApplyPeerConfigurationParams>,
+    rx_executor_status: Option<Receiver<ExecutorStatusUpdate>>,
 }
 
 pub struct HandleStreamInfo {
@@ -36,48 +36,71 @@ pub struct HandleStreamInfo {
     pub network_interface_management: NetworkInterfaceManagement,
     pub executor_manager: ExecutorManagerRef,
     pub metrics_manager: NetworkMetricsManagerRef,
+    pub status_writer: ServiceStatusWriter,
 }
 
-impl PeerMessagingClient {
-    pub async fn create(
-       self_id: PeerId,
-       carl: CarlClient,
-       settings: LoadedConfig,
-       tx_peer_configuration: mpsc::Sender<ApplyPeerConfigurationParams>,
-    ) -> anyhow::Result<Self> {
-        info!("Started with ID <{self_id}> and configuration: {settings:?}");
+impl HandleStreamInfo {
+    pub fn create(self_id: PeerId, settings: &LoadedConfig, status_writer: ServiceStatusWriter) -> anyhow::Result<(Self, Receiver<ExecutorStatusUpdate>)> {
+        let (tx_executor_status, rx_executor_status) = {
+            let (tx_executor_status, mut rx_executor_status_local) = mpsc::channel::<ExecutorStatusUpdate>(100);
+            let (tx_executor_status_forward, rx_executor_status) = mpsc::channel::<ExecutorStatusUpdate>(100);
+
+            // record the status locally, before forwarding it to CARL
+            let status_writer = status_writer.clone();
+            tokio::spawn(async move {
+                while let Some(update) = rx_executor_status_local.recv().await {
+                    status_writer.set_executor_status(update.id, &update.status);
+                    if tx_executor_status_forward.send(update).await.is_err() {
+                        break;
+                    }
+                }
+            });
+            (tx_executor_status, rx_executor_status)
+        };
 
-        let handle_stream_info = {
-            let executor_manager: ExecutorManagerRef = ExecutorManager::create();
+        let executor_manager: ExecutorManagerRef = ExecutorManager::create(tx_executor_status);
 
-            let network_interface_management = {
-                let network_interface_management_enabled = settings.config.get::<bool>("network.interface.management.enabled")?;
-                if network_interface_management_enabled {
-                    let network_interface_manager: NetworkInterfaceManagerRef = NetworkInterfaceManager::create()?;
-                    let can_manager = CanManagerRef::new_shared();
+        let network_interface_management = NetworkInterfaceManagement::load(settings)?;
 
-                    NetworkInterfaceManagement::Enabled { network_interface_manager, can_manager }
-                } else {
-                    NetworkInterfaceManagement::Disabled
-                }
-            };
+        let metrics_manager: NetworkMetricsManagerRef = NetworkMetricsManager::load(settings)?;
 
-            let metrics_manager: NetworkMetricsManagerRef = NetworkMetricsManager::load(&settings)?;
+        let handle_stream_info = HandleStreamInfo {
+            self_id,
+            network_interface_management,
+            executor_manager,
+            metrics_manager,
+            status_writer,
+        };
+        Ok((handle_stream_info, rx_executor_status))
+    }
 
+    pub fn apply_peer_configuration_params(&self, peer_configuration: PeerConfiguration, cached: Option<CachedPeerConfiguration>) -> ApplyPeerConfigurationParams {
+        ApplyPeerConfigurationParams {
+            peer_configuration,
+            cached,
+            network_interface_management: self.network_interface_management.clone(),
+            executor_manager: Arc::clone(&self.executor_manager),
+            metrics_manager: Arc::clone(&self.metrics_manager),
+        }
+    }
+}
 
-            HandleStreamInfo {
-                self_id,
-                network_interface_management,
-                executor_manager,
-                metrics_manager,
-            }
-        };
+impl PeerMessagingClient {
+    pub async fn create(
+       carl: CarlClient,
+       handle_stream_info: HandleStreamInfo,
+       rx_executor_status: Receiver<ExecutorStatusUpdate>,
+       settings: LoadedConfig,
+       tx_peer_configuration: mpsc::Sender<ApplyPeerConfigurationParams>,
+    ) -> anyhow::Result<Self> {
+        info!("Started with ID <{self_id}> and configuration: {settings:?}", self_id=handle_stream_info.self_id);
 
         Ok(PeerMessagingClient {
             carl,
             handle_stream_info,
             settings,
             tx_peer_configuration,
+            rx_executor_status: Some(rx_executor_status),
         })
     }
     
@@ -102,14 +125,34 @@ impl PeerMessagingClient {
 
     }
 
+    async fn spawn_executor_status_sender(&self, mut rx_executor_status: Receiver<ExecutorStatusUpdate>, tx_outbound: Upstream) {
+        tokio::spawn(async move {
+            while let Some(update) = rx_executor_status.recv().await {
+                let message = broker::UpstreamMessage {
+                    payload: broker::UpstreamMessagePayload::ExecutorStatusUpdate(update),
+                    context: None,
+                };
+                let _send_result = tx_outbound.send(message).await
+                    .inspect_err(|error| {
+                        error!("Failed to send executor status to CARL. Encountered error was: {error}");
+                    });
+            }
+            info!("Executor status channel closed");
+        });
+    }
+
     pub async fn process_messages_loop(&mut self, rx_peer_configuration_state: Receiver<EdgePeerConfigurationState>) -> anyhow::Result<()> {
         let remote_address = vpn::retrieve_remote_host(&self.settings).await?;
 
         let timeout_duration = Duration::from_millis(self.settings.config.get::<u64>("carl.disconnect.timeout.ms")?);
 
         let (mut rx_inbound, tx_outbound) = carl::open_stream(self.handle_stream_info.self_id, &remote_address, &mut self.carl).await?;
+        self.handle_stream_info.status_writer.set_carl_connection_state(CarlConnectionState::Connected);
 
         self.spawn_peer_configuration_state_sender(rx_peer_configuration_state, tx_outbound.clone()).await;
+        if let Some(rx_executor_status) = self.rx_executor_status.take() {
+            self.spawn_executor_status_sender(rx_executor_status, tx_outbound.clone()).await;
+        }
 
         loop {
             let received = tokio::time::timeout(timeout_duration, rx_inbound.receive()).await;
@@ -162,6 +205,8 @@ impl PeerMessagingClient {
             }
         }
 
+        self.handle_stream_info.status_writer.set_carl_connection_state(CarlConnectionState::Disconnected);
+
         // Shutdown processes of the can manager if enabled
         match self.handle_stream_info.network_interface_management.clone() {
             NetworkInterfaceManagement::Enabled { can_manager, .. } => {
@@ -200,6 +245,11 @@ impl PeerMessagingClient {
             broker::DownstreamMessagePayload::DisconnectNotice => {
                 return Err(anyhow!("CARL sent a disconnect notice. Shutting down now."))
             }
+            #[cfg(feature = "viper")]
+            broker::DownstreamMessagePayload::ExecuteViperRun(message) => {
+                info!("Received instruction to execute test suite run <{}>.", message.run.id);
+                crate::service::test_execution::viper_runner::spawn_viper_run(*message, Clone::clone(tx_outbound));
+            }
         }
 
         Ok(())
@@ -221,12 +271,7 @@ async fn apply_peer_configuration_raw(
 
     info!("Received PeerConfiguration: {configuration:?}");
 
-    let apply_config_params = ApplyPeerConfigurationParams {
-        peer_configuration: configuration,
-        network_interface_management: handle_stream_info.network_interface_management.clone(),
-        executor_manager: Arc::clone(&handle_stream_info.executor_manager),
-        metrics_manager: Arc::clone(&handle_stream_info.metrics_manager),
-    };
+    let apply_config_params = handle_stream_info.apply_peer_configuration_params(configuration, None);
     peer_configuration_sender.send(apply_config_params).await?;
 
     Ok(())
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use tokio::process::{Child, Command};
 use tokio::sync::{mpsc, Mutex};
 use tokio::time::Duration;
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
 use tracing::{debug, trace, error, info, warn};
 use tokio::io::BufReader;
 use tokio::io::AsyncBufReadExt;
@@ -28,10 +28,20 @@ pub enum RestartPolicy {
     /// Always restart the process when it terminates
     Always,
     /// Restart only if the process exits with an error (non-zero exit code)
-    #[allow(unused)]
     OnFailure,
 }
 
+impl RestartPolicy {
+    /// Whether a process, which terminated with the given status, should be restarted
+    pub fn should_restart(&self, status: ExitStatus) -> bool {
+        match self {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => !status.success(),
+            RestartPolicy::Never => false,
+        }
+    }
+}
+
 /// Configuration for process output handling
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OutputConfig {
@@ -319,11 +329,7 @@ impl AsyncProcessManager {
                                 info!("Process '{}' exited with status: {}", name, status);
 
                                 // Decide if we should restart
-                                match restart_policy {
-                                    RestartPolicy::Always => true,
-                                    RestartPolicy::OnFailure => !status.success(),
-                                    RestartPolicy::Never => false,
-                                }
+                                restart_policy.should_restart(status)
                             }
                             Ok(None) => false, // Still running
                             Err(e) => {
@@ -353,12 +359,14 @@ impl AsyncProcessManager {
 
                                 let (new_shutdown_tx, new_shutdown_rx) = mpsc::channel(1);
 
-                                manager.processes.insert(id, ManagedAsyncProcess {
+                                let mut managed_process = ManagedAsyncProcess {
                                     name: name.clone(),
                                     child,
                                     config: Some(config.clone()),
                                     shutdown_tx: Some(new_shutdown_tx),
-                                });
+                                };
+                                managed_process.spawn_output_drainers();
+                                manager.processes.insert(id, managed_process);
 
                                 // Continue monitoring with new shutdown channel
                                 drop(manager); // Release lock
@@ -392,21 +400,6 @@ impl AsyncProcessManager {
         }
     }
 
-    /// Terminate all managed processes
-    pub async fn shutdown(&mut self) {
-        info!("Shutting down all {} managed async processes", self.processes.len());
-
-        let process_ids: Vec<_> = self.processes.keys().copied().collect();
-
-        for id in process_ids {
-            if let Err(e) = self.terminate(id).await {
-                error!("Failed to terminate async process {:?}: {}", id, e);
-            }
-        }
-
-        info!("All async processes terminated");
-    }
-
     /// Synchronous shutdown for Drop implementation
     fn shutdown_blocking(&mut self) {
         info!("Shutting down all {} managed async processes (blocking)", self.processes.len());
@@ -433,9 +426,10 @@ impl AsyncProcessManager {
         }
     }
 
-    /// Check if there are no active processes
-    pub fn is_empty(&self) -> bool {
-        self.processes.is_empty()
+    /// Get the exit status of a process, if it has exited
+    pub fn exit_status(&mut self, id: &AsyncProcessId) -> Option<ExitStatus> {
+        self.processes.get_mut(id)
+            .and_then(|process| process.child.try_wait().ok().flatten())
     }
 }
 
@@ -110,6 +110,7 @@ impl From<CollectedResult> for EdgePeerConfigurationState {
 
         EdgePeerConfigurationState {
             parameter_states,
+            cached: None,
         }
     }
 }
@@ -151,7 +152,7 @@ pub async fn run_tasks(
     results
 }
 
-async fn check_task(task: &dyn TaskAbsent, target: ParameterTarget) -> anyhow::Result<TaskStateFulfilled> {
+pub(crate) async fn check_task(task: &dyn TaskAbsent, target: ParameterTarget) -> anyhow::Result<TaskStateFulfilled> {
     match target {
         ParameterTarget::Present => task.check_present().await,
         ParameterTarget::Absent => task.check_absent().await,
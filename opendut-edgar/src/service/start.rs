@@ -1,13 +1,21 @@
 use crate::app_info;
 use crate::common::{carl, settings};
 use anyhow::Context;
+use std::time::Duration;
 use opendut_model::peer::configuration::EdgePeerConfigurationState;
 use opendut_model::peer::PeerId;
 use opendut_telemetry::logging::LoggingConfig;
 use opendut_telemetry::opentelemetry_types;
 use opendut_telemetry::opentelemetry_types::Opentelemetry;
 use tokio::sync::mpsc;
-use crate::service::peer_messaging_client::PeerMessagingClient;
+use tracing::{info, warn};
+use crate::service::peer_configuration_cache::PeerConfigurationCache;
+use crate::service::peer_messaging_client::{HandleStreamInfo, PeerMessagingClient};
+use crate::service::status::ServiceStatusWriter;
+
+/// Delays between attempts to connect to CARL, while running with a cached peer configuration.
+const CARL_RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const CARL_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
 
 const BANNER: &str = r"
                          _____     _______
@@ -52,11 +60,45 @@ pub async fn create_with_telemetry(settings_override: config::Config) -> anyhow:
 
     let (tx_peer_configuration, rx_peer_configuration) = mpsc::channel(100);
     let (tx_peer_configuration_state, rx_peer_configuration_state) = mpsc::channel::<EdgePeerConfigurationState>(100);
-    crate::service::peer_configuration::spawn_peer_configurations_handler(rx_peer_configuration, tx_peer_configuration_state).await?;
+    let status_writer = ServiceStatusWriter::load(&settings.config, self_id)?;
+
+    let peer_configuration_cache = PeerConfigurationCache::load_from_settings(&settings.config)?;
+    crate::service::peer_configuration::spawn_peer_configurations_handler(rx_peer_configuration, tx_peer_configuration_state, Clone::clone(&peer_configuration_cache), status_writer.clone()).await?;
+
+    let (handle_stream_info, rx_executor_status) = HandleStreamInfo::create(self_id, &settings, status_writer.clone())?;
+    status_writer.spawn_refresh(handle_stream_info.network_interface_management.clone());
 
-    let mut carl = carl::connect(&settings.config).await?;
+    let running_cached_configuration = match peer_configuration_cache {
+        Some(cache) => match cache.load() {
+            Ok(Some((peer_configuration, cached))) => {
+                info!("Reapplying cached peer configuration from {:?}, until CARL is reachable.", cached.stored_at);
+                tx_peer_configuration.send(handle_stream_info.apply_peer_configuration_params(peer_configuration, Some(cached))).await?;
+                true
+            }
+            Ok(None) => false,
+            Err(cause) => {
+                warn!("Failed to load cached peer configuration. Discarding it.\n  {cause:#}");
+                cache.discard();
+                false
+            }
+        }
+        None => false,
+    };
+
+    let mut retry_delay = CARL_RECONNECT_INITIAL_DELAY;
+    let mut carl = loop {
+        match carl::connect(&settings.config).await {
+            Ok(carl) => break carl,
+            Err(cause) if running_cached_configuration => {
+                warn!("{cause} Continuing with cached peer configuration and retrying in {} seconds.", retry_delay.as_secs());
+                tokio::time::sleep(retry_delay).await;
+                retry_delay = (retry_delay * 2).min(CARL_RECONNECT_MAX_DELAY);
+            }
+            Err(cause) => return Err(cause),
+        }
+    };
     carl::log_version_compatibility(&mut carl).await?;
-    let mut peer_messaging_client = PeerMessagingClient::create(self_id, carl, settings, tx_peer_configuration).await?;
+    let mut peer_messaging_client = PeerMessagingClient::create(carl, handle_stream_info, rx_executor_status, settings, tx_peer_configuration).await?;
     peer_messaging_client.process_messages_loop(rx_peer_configuration_state).await?;
 
     metrics_shutdown_handle.shutdown();
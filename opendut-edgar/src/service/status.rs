@@ -0,0 +1,290 @@
+//! Snapshot of the state of the running EDGAR service.
+//! It is written to disk whenever it changes, so that it can be inspected via `opendut-edgar status`, without connecting to the service.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use config::Config;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use opendut_model::peer::configuration::{EdgePeerConfigurationState, ParameterDetectedStateErrorCause, ParameterEdgeDetectedStateKind, ParameterTarget, ParameterVariant, PeerConfiguration};
+use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::executor::state::ExecutorStatus;
+use opendut_model::peer::PeerId;
+use opendut_util::project;
+
+use crate::fs;
+use crate::service::peer_configuration::NetworkInterfaceManagement;
+
+/// Interval in which the status is refreshed, even if nothing else changed.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub peer_id: PeerId,
+    pub carl: CarlConnectionStatus,
+    pub configuration: Option<ConfigurationStatus>,
+    pub can_tunnels: Vec<CanTunnelStatus>,
+    pub executors: Vec<ExecutorStatusEntry>,
+    pub updated_at: SystemTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CarlConnectionStatus {
+    pub address: String,
+    pub state: CarlConnectionState,
+    pub since: SystemTime,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CarlConnectionState {
+    Connecting,
+    Connected,
+    Disconnected,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfigurationStatus {
+    pub applied_at: SystemTime,
+    /// Checksum of the cached configuration, if the configuration was applied from the local cache.
+    pub cached: Option<String>,
+    pub parameters: Vec<ParameterStatus>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ParameterStatus {
+    pub id: Uuid,
+    pub kind: String,
+    pub target: String,
+    pub detected_state: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CanTunnelStatus {
+    pub remote_peer_id: PeerId,
+    pub can_interface: String,
+    pub transport: String,
+    pub state: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExecutorStatusEntry {
+    pub id: ExecutorId,
+    pub status: String,
+}
+
+impl ConfigurationStatus {
+    pub fn new(peer_configuration: &PeerConfiguration, state: &EdgePeerConfigurationState) -> Self {
+        let all_parameters = peer_configuration.all_parameters();
+
+        let mut parameters = state.parameter_states.iter()
+            .map(|parameter_state| {
+                let (kind, target) = match all_parameters.get(&parameter_state.id) {
+                    Some(parameter) => (parameter_kind(parameter), format_target(parameter.target())),
+                    None => ("Unknown", String::from("-")),
+                };
+                ParameterStatus {
+                    id: parameter_state.id.0,
+                    kind: kind.to_owned(),
+                    target,
+                    detected_state: format_detected_state(&parameter_state.detected_state),
+                }
+            })
+            .collect::<Vec<_>>();
+        parameters.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.id.cmp(&b.id)));
+
+        Self {
+            applied_at: SystemTime::now(),
+            cached: state.cached.as_ref().map(|cached| cached.checksum.clone()),
+            parameters,
+        }
+    }
+}
+
+pub fn parameter_kind(parameter: &ParameterVariant) -> &'static str {
+    match parameter {
+        ParameterVariant::DeviceInterface(_) => "DeviceInterface",
+        ParameterVariant::EthernetBridge(_) => "EthernetBridge",
+        ParameterVariant::Executor(_) => "Executor",
+        ParameterVariant::GreInterface(_) => "GreInterface",
+        ParameterVariant::JoinedInterface(_) => "JoinedInterface",
+        ParameterVariant::RemotePeerConnectionCheck(_) => "RemotePeerConnectionCheck",
+        ParameterVariant::CanConnections(_) => "CanConnection",
+        ParameterVariant::CanBridges(_) => "CanBridge",
+        ParameterVariant::CanLocalRoutes(_) => "CanLocalRoute",
+        ParameterVariant::RestbusSimulations(_) => "RestbusSimulation",
+    }
+}
+
+pub fn format_target(target: ParameterTarget) -> String {
+    match target {
+        ParameterTarget::Present => String::from("Present"),
+        ParameterTarget::Absent => String::from("Absent"),
+    }
+}
+
+fn format_detected_state(state: &ParameterEdgeDetectedStateKind) -> String {
+    match state {
+        ParameterEdgeDetectedStateKind::Present => String::from("Present"),
+        ParameterEdgeDetectedStateKind::Absent => String::from("Absent"),
+        ParameterEdgeDetectedStateKind::Error(error) => {
+            let cause = match &error.cause {
+                ParameterDetectedStateErrorCause::Unclassified(cause) => cause.clone(),
+                ParameterDetectedStateErrorCause::MissingDependencies(dependencies) => {
+                    let dependencies = dependencies.iter()
+                        .map(|dependency| dependency.0.to_string())
+                        .collect::<Vec<_>>();
+                    format!("Missing dependencies: {}", dependencies.join(", "))
+                }
+            };
+            format!("Error ({:?}): {cause}", error.kind)
+        }
+    }
+}
+
+pub fn status_file(settings: &Config) -> anyhow::Result<PathBuf> {
+    project::make_path_absolute(settings.get_string("service.status.file")?)
+}
+
+pub fn read(file: &Path) -> anyhow::Result<ServiceStatus> {
+    let json = fs::read_to_string(file)?;
+    let status = serde_json::from_str(&json)?;
+    Ok(status)
+}
+
+/// Keeps the [ServiceStatus] of the running service and writes it to disk on every update.
+#[derive(Clone, Debug)]
+pub struct ServiceStatusWriter {
+    file: PathBuf,
+    status: Arc<Mutex<ServiceStatus>>,
+}
+
+impl ServiceStatusWriter {
+    pub fn load(settings: &Config, peer_id: PeerId) -> anyhow::Result<Self> {
+        let file = status_file(settings)?;
+        let address = format!("{}:{}", settings.get_string("network.carl.host")?, settings.get_int("network.carl.port")?);
+
+        let now = SystemTime::now();
+        let status = ServiceStatus {
+            peer_id,
+            carl: CarlConnectionStatus {
+                address,
+                state: CarlConnectionState::Connecting,
+                since: now,
+            },
+            configuration: None,
+            can_tunnels: vec![],
+            executors: vec![],
+            updated_at: now,
+        };
+
+        let writer = Self { file, status: Arc::new(Mutex::new(status)) };
+        writer.update(|_| {});
+        Ok(writer)
+    }
+
+    pub fn update(&self, update: impl FnOnce(&mut ServiceStatus)) {
+        let mut status = self.status.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        update(&mut status);
+        status.updated_at = SystemTime::now();
+
+        let _ = self.write(&status)
+            .inspect_err(|cause| warn!("Failed to write service status to '{}': {cause}", self.file.display()));
+    }
+
+    pub fn set_carl_connection_state(&self, state: CarlConnectionState) {
+        self.update(|status| {
+            if status.carl.state != state {
+                status.carl.state = state;
+                status.carl.since = SystemTime::now();
+            }
+        });
+    }
+
+    pub fn set_executor_status(&self, id: ExecutorId, executor_status: &ExecutorStatus) {
+        self.update(|status| {
+            let executor_status = executor_status.to_string();
+            match status.executors.iter_mut().find(|executor| executor.id == id) {
+                Some(executor) => executor.status = executor_status,
+                None => status.executors.push(ExecutorStatusEntry { id, status: executor_status }),
+            }
+        });
+    }
+
+    /// Periodically refreshes the state of the CAN tunnels, which also marks the service as alive.
+    pub fn spawn_refresh(&self, network_interface_management: NetworkInterfaceManagement) {
+        let writer = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let can_tunnels = match &network_interface_management {
+                    NetworkInterfaceManagement::Enabled { can_manager, .. } => {
+                        let can_manager = can_manager.lock().await;
+                        can_manager.tunnel_states().await.into_iter()
+                            .map(|(parameter, state)| CanTunnelStatus {
+                                remote_peer_id: parameter.remote_peer_id,
+                                can_interface: parameter.can_interface_name.name(),
+                                transport: parameter.transport.to_string(),
+                                state: state.to_string(),
+                            })
+                            .collect()
+                    }
+                    NetworkInterfaceManagement::Disabled => vec![],
+                };
+                writer.update(|status| status.can_tunnels = can_tunnels);
+
+                tokio::time::sleep(REFRESH_INTERVAL).await;
+            }
+        });
+    }
+
+    fn write(&self, status: &ServiceStatus) -> anyhow::Result<()> {
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary_file = self.file.with_extension("tmp");
+        fs::write(&temporary_file, serde_json::to_string_pretty(status)?)?;
+        fs::rename(&temporary_file, &self.file)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_read_written_status() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("status.json");
+
+        let settings = Config::builder()
+            .set_override("service.status.file", file.display().to_string())?
+            .set_override("network.carl.host", "carl.opendut.local")?
+            .set_override("network.carl.port", 443)?
+            .build()?;
+
+        let peer_id = PeerId::random();
+        let executor_id = ExecutorId::random();
+
+        let writer = ServiceStatusWriter::load(&settings, peer_id)?;
+        writer.set_carl_connection_state(CarlConnectionState::Connected);
+        writer.set_executor_status(executor_id, &ExecutorStatus::Pulling);
+        writer.set_executor_status(executor_id, &ExecutorStatus::Running);
+
+        let status = read(&file)?;
+        assert_that!(status.peer_id, eq(peer_id));
+        assert_that!(status.carl.address, eq("carl.opendut.local:443"));
+        assert_that!(status.carl.state, eq(CarlConnectionState::Connected));
+        assert_that!(status.executors.len(), eq(1));
+        assert_that!(status.executors[0].id, eq(executor_id));
+        assert_that!(status.executors[0].status, eq("Running"));
+
+        Ok(())
+    }
+}
@@ -24,7 +24,7 @@ impl Task for CanConnection {
     async fn make_present(&self) -> anyhow::Result<Success> {
         if !self.is_running().await {
             let can_manager = self.can_manager.lock().await;
-            can_manager.spawn_process(&self.parameter).await?;
+            can_manager.start_tunnel(&self.parameter).await;
             Ok(Success::message(format!("CAN connection to {} established", self.parameter.remote_peer_id)))
         } else {
             Ok(Success::message(format!("CAN connection to {} was already running", self.parameter.remote_peer_id)))
@@ -45,7 +45,7 @@ impl TaskAbsent for CanConnection {
     async fn make_absent(&self) -> anyhow::Result<Success> {
         if self.is_running().await {
             let can_manager = self.can_manager.lock().await;
-            can_manager.terminate_process(&self.parameter).await?;
+            can_manager.stop_tunnel(&self.parameter).await;
             Ok(Success::message(format!("CAN connection to {} terminated", self.parameter.remote_peer_id)))
         } else {
             Ok(Success::message(format!("CAN connection to {} was not running", self.parameter.remote_peer_id)))
@@ -56,6 +56,6 @@ impl TaskAbsent for CanConnection {
 impl CanConnection {
     async fn is_running(&self) -> bool {
         let can_manager = self.can_manager.lock().await;
-        can_manager.process_is_running(&self.parameter).await
+        can_manager.tunnel_is_running(&self.parameter).await
     }
 }
\ No newline at end of file
@@ -3,8 +3,8 @@ use crate::service::network_interface::manager::NetworkInterfaceManagerRef;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use opendut_model::peer::configuration::parameter;
+use opendut_model::util::can::{CanIdFilter, CAN_ID_MASK, CAN_MAX_STANDARD_ID};
 use opendut_model::util::net::NetworkInterfaceName;
-use regex::Regex;
 use std::fmt::Display;
 use tokio::process::Command;
 use tracing::{debug, trace};
@@ -17,6 +17,8 @@ pub struct CanLocalRoute {
 
 /// Maximum number of hops for local CAN message.
 const CAN_MAX_HOPS: u8 = 2;
+/// Marks a CAN identifier as extended identifier in the kernel's representation of a frame.
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
 
 #[derive(Debug)]
 pub enum CanRouteOperation {
@@ -53,12 +55,10 @@ impl Task for CanLocalRoute {
 
         match (source, destination) {
             (Some(_), Some(_)) => {
-                let can_route_present = check_can_route_exists(
-                    &self.parameter.can_source_device_name,
-                    &self.parameter.can_destination_device_name,
-                    self.can_fd,
-                    CAN_MAX_HOPS,
-                ).await?;
+                let existing_rules = list_can_gateway_rules().await?;
+
+                let can_route_present = self.expected_rules().iter()
+                    .all(|rule| existing_rules.contains(rule));
 
                 if can_route_present {
                     Ok(TaskStateFulfilled::Yes)
@@ -76,13 +76,13 @@ impl Task for CanLocalRoute {
 
         match (source, destination) {
             (Some(source), Some(destination)) => {
-                modify_can_route(
-                    &source.name,
-                    &destination.name,
-                    self.can_fd,
-                    CAN_MAX_HOPS,
-                    CanRouteOperation::Create,
-                ).await?;
+                let existing_rules = list_can_gateway_rules().await?;
+
+                for rule in self.expected_rules() {
+                    if !existing_rules.contains(&rule) {
+                        modify_can_route(&rule, CanRouteOperation::Create).await?;
+                    }
+                }
 
                 debug!("Created CAN local route from '{}' to '{}'.", source.name, destination.name);
                 Ok(Success::default())
@@ -95,12 +95,10 @@ impl Task for CanLocalRoute {
 #[async_trait]
 impl TaskAbsent for CanLocalRoute {
     async fn check_absent(&self) -> anyhow::Result<TaskStateFulfilled> {
-        let can_route_present = check_can_route_exists(
-            &self.parameter.can_source_device_name,
-            &self.parameter.can_destination_device_name,
-            self.can_fd,
-            CAN_MAX_HOPS,
-        ).await?;
+        let existing_rules = list_can_gateway_rules().await?;
+
+        let can_route_present = self.expected_rules().iter()
+            .any(|rule| existing_rules.contains(rule));
 
         if can_route_present {
             Ok(TaskStateFulfilled::No)
@@ -110,19 +108,144 @@ impl TaskAbsent for CanLocalRoute {
     }
 
     async fn make_absent(&self) -> anyhow::Result<Success> {
-        modify_can_route(
-            &self.parameter.can_source_device_name,
-            &self.parameter.can_destination_device_name,
-            self.can_fd,
-            CAN_MAX_HOPS,
-            CanRouteOperation::Delete,
-        ).await?;
+        let existing_rules = list_can_gateway_rules().await?;
+
+        for rule in self.expected_rules() {
+            if existing_rules.contains(&rule) {
+                modify_can_route(&rule, CanRouteOperation::Delete).await?;
+            }
+        }
 
         Ok(Success::default())
     }
 }
 
-async fn check_can_route_exists(src: &NetworkInterfaceName, dst: &NetworkInterfaceName, can_fd: bool, max_hops: u8) -> anyhow::Result<bool> {
+impl CanLocalRoute {
+    fn expected_rules(&self) -> Vec<CanGatewayRule> {
+        CanGatewayRule::for_route(&self.parameter, self.can_fd, CAN_MAX_HOPS)
+    }
+}
+
+/// A single routing rule of the kernel's CAN gateway, as managed with `cangw`.
+/// As `cangw` supports only one filter per rule, a route with filter or rewrite rules is expressed with multiple rules.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct CanGatewayRule {
+    src: NetworkInterfaceName,
+    dst: NetworkInterfaceName,
+    can_fd: bool,
+    max_hops: u8,
+    filter: CanIdFilter,
+    set_id: Option<u32>,
+}
+
+impl CanGatewayRule {
+    fn for_route(parameter: &parameter::CanLocalRoute, can_fd: bool, max_hops: u8) -> Vec<Self> {
+        let rule = |filter: CanIdFilter, set_id: Option<u32>| CanGatewayRule {
+            src: parameter.can_source_device_name.clone(),
+            dst: parameter.can_destination_device_name.clone(),
+            can_fd,
+            max_hops,
+            filter,
+            set_id,
+        };
+
+        let unmodified = parameter.rules.unmodified_filters().into_iter()
+            .map(|filter| rule(filter, None));
+
+        let rewritten = parameter.rules.effective_rewrites().into_iter()
+            .map(|rewrite| rule(CanIdFilter { id: rewrite.from, mask: CAN_ID_MASK }, Some(rewrite.to)));
+
+        unmodified.chain(rewritten).collect()
+    }
+
+    fn arguments(&self) -> Vec<String> {
+        let mut arguments = vec![
+            String::from("-s"), self.src.name(),
+            String::from("-d"), self.dst.name(),
+            String::from("-e"),
+            String::from("-l"), self.max_hops.to_string(),
+        ];
+
+        if self.can_fd {
+            arguments.push(String::from("-X"));
+        }
+
+        if self.filter != CanIdFilter::ANY {
+            arguments.push(String::from("-f"));
+            arguments.push(format!("{:X}:{:X}", self.filter.id, self.filter.mask));
+        }
+
+        if let Some(id) = self.set_id {
+            let can_id = if id > CAN_MAX_STANDARD_ID { id | CAN_EFF_FLAG } else { id };
+            let modification = if self.can_fd {
+                format!("SET:I:{can_id:X}.0.0.{}", "00".repeat(64))
+            } else {
+                format!("SET:I:{can_id:X}.0.{}", "00".repeat(8))
+            };
+            arguments.push(String::from("-m"));
+            arguments.push(modification);
+        }
+        arguments
+    }
+
+    /// Parses a line of the output of `cangw -L`, e.g. `cangw -A -s vcan0 -d vcan1 -X -e -f 100:700 -l 2 # 0 handled 0 dropped 0 deleted`.
+    /// Returns `None` for rules with options, which are not used by EDGAR.
+    fn parse(line: &str) -> Option<Self> {
+        let rule = line.split('#').next()?;
+        let mut tokens = rule.split_whitespace();
+
+        if tokens.next()? != "cangw" || tokens.next()? != "-A" {
+            return None;
+        }
+
+        let mut src = None;
+        let mut dst = None;
+        let mut can_fd = false;
+        let mut echo = false;
+        let mut max_hops = None;
+        let mut filter = CanIdFilter::ANY;
+        let mut set_id = None;
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "-s" => src = Some(NetworkInterfaceName::try_from(tokens.next()?).ok()?),
+                "-d" => dst = Some(NetworkInterfaceName::try_from(tokens.next()?).ok()?),
+                "-X" => can_fd = true,
+                "-e" => echo = true,
+                "-l" => max_hops = Some(tokens.next()?.parse().ok()?),
+                "-f" => {
+                    let (id, mask) = tokens.next()?.split_once(':')?;
+                    filter = CanIdFilter::new(u32::from_str_radix(id, 16).ok()?, u32::from_str_radix(mask, 16).ok()?).ok()?;
+                }
+                "-m" => {
+                    let mut modification = tokens.next()?.split(':');
+                    let (kind, elements, frame) = (modification.next()?, modification.next()?, modification.next()?);
+                    if kind != "SET" || elements != "I" || set_id.is_some() {
+                        return None;
+                    }
+                    let can_id = u32::from_str_radix(frame.split('.').next()?, 16).ok()?;
+                    set_id = Some(can_id & CAN_ID_MASK);
+                }
+                _ => return None,
+            }
+        }
+
+        if !echo {
+            return None;
+        }
+
+        Some(CanGatewayRule {
+            src: src?,
+            dst: dst?,
+            can_fd,
+            max_hops: max_hops?,
+            filter,
+            set_id,
+        })
+    }
+}
+
+async fn list_can_gateway_rules() -> anyhow::Result<Vec<CanGatewayRule>> {
     let output = Command::new("cangw")
         .arg("-L")
         .output()
@@ -133,25 +256,14 @@ async fn check_can_route_exists(src: &NetworkInterfaceName, dst: &NetworkInterfa
 
     let output_str = String::from_utf8_lossy(&output.stdout);
 
-    let regex = Regex::new(r"(?m)^cangw -A -s ([^\n ]+) -d ([^\n ]+) ((?:-X )?)-e -l ([0-9[^\n ]]+) #.*$").unwrap();
+    let rules = output_str.lines()
+        .filter_map(CanGatewayRule::parse)
+        .collect();
 
-    let captures = regex.captures_iter(&output_str).map(|captures| captures.extract().1);
-
-    for [exist_src, exist_dst, can_fd_flag, exist_max_hops] in captures {
-        let exist_can_fd = can_fd_flag.trim() == "-X";
-
-        if exist_src == src.name()
-            && exist_dst == dst.name()
-            && exist_can_fd == can_fd
-            && exist_max_hops == max_hops.to_string() {
-            return Ok(true)
-        }
-    }
-
-    Ok(false)
+    Ok(rules)
 }
 
-async fn modify_can_route(src: &NetworkInterfaceName, dst: &NetworkInterfaceName, can_fd: bool, max_hops: u8, operation: CanRouteOperation) -> anyhow::Result<()> {
+async fn modify_can_route(rule: &CanGatewayRule, operation: CanRouteOperation) -> anyhow::Result<()> {
     let operation_arg = match operation {
         CanRouteOperation::Create => "-A",
         CanRouteOperation::Delete => "-D",
@@ -159,17 +271,7 @@ async fn modify_can_route(src: &NetworkInterfaceName, dst: &NetworkInterfaceName
 
     let mut cmd = Command::new("cangw");
     cmd.arg(operation_arg)
-        .arg("-s")
-        .arg(src.name())
-        .arg("-d")
-        .arg(dst.name())
-        .arg("-e")
-        .arg("-l")
-        .arg(max_hops.to_string());
-
-    if can_fd {
-        cmd.arg("-X");
-    }
+        .args(rule.arguments());
 
     trace!("{operation:?} CAN route, executing command: {:?}", cmd);
     let output = cmd.output().await
@@ -179,8 +281,8 @@ async fn modify_can_route(src: &NetworkInterfaceName, dst: &NetworkInterfaceName
         Ok(())
     } else {
         Err(anyhow!(Error::CanRouteCreation {
-            src: src.clone(),
-            dst: dst.clone(),
+            src: rule.src.clone(),
+            dst: rule.dst.clone(),
             operation,
             cause: format!("{:?}", String::from_utf8_lossy(&output.stderr).trim())
         }))
@@ -196,6 +298,8 @@ pub(crate) mod tests {
     use anyhow::anyhow;
     use crate::common::task::{Task, TaskAbsent, TaskStateFulfilled};
     use crate::service::tasks::testing::NetworkInterfaceNameExt;
+    use super::CanGatewayRule;
+    use opendut_model::util::can::{CanFrameRules, CanIdFilter, CanIdRewrite};
 
     pub struct FixtureVirtualCan {
         pub(crate) network_interface_manager: NetworkInterfaceManagerRef,
@@ -215,6 +319,7 @@ pub(crate) mod tests {
             let parameter = parameter::CanLocalRoute {
                 can_source_device_name: vcan1_name.clone(),
                 can_destination_device_name: vcan2_name.clone(),
+                rules: Default::default(),
             };
 
             Ok(Self {
@@ -305,4 +410,67 @@ pub(crate) mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_create_a_single_unfiltered_rule_without_can_rules() -> anyhow::Result<()> {
+        let parameter = can_local_route(CanFrameRules::default())?;
+
+        let rules = CanGatewayRule::for_route(&parameter, false, 2);
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].arguments().join(" "), "-s vcan0 -d br-vcan-opendut -e -l 2");
+        Ok(())
+    }
+
+    #[test]
+    fn should_create_filter_and_modification_rules() -> anyhow::Result<()> {
+        let parameter = can_local_route(CanFrameRules {
+            accept: vec![CanIdFilter::new(0x100, 0x700)?],
+            reject: vec![CanIdFilter::new(0x1f0, 0x7f0)?],
+            rewrites: vec![CanIdRewrite::new(0x123, 0x18ff_0001)?],
+            rate_limit: None,
+        })?;
+
+        let rules = CanGatewayRule::for_route(&parameter, false, 2)
+            .into_iter()
+            .map(|rule| rule.arguments().join(" "))
+            .collect::<Vec<_>>();
+
+        assert!(rules.contains(&String::from("-s vcan0 -d br-vcan-opendut -e -l 2 -f 123:1FFFFFFF -m SET:I:98FF0001.0.0000000000000000")));
+        assert!(rules.iter().all(|rule| rule.contains("-f ")));
+        assert!(rules.iter().all(|rule| !rule.contains("-f 100:700 ")), "The accept filter should be split to exclude rejected and rewritten identifiers.");
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_rules_listed_by_cangw() -> anyhow::Result<()> {
+        let parameter = can_local_route(CanFrameRules {
+            rewrites: vec![CanIdRewrite::new(0x123, 0x456)?],
+            ..CanFrameRules::default()
+        })?;
+        let expected = CanGatewayRule::for_route(&parameter, true, 2);
+
+        for rule in &expected {
+            let listed = format!("cangw -A {} # 0 handled 0 dropped 0 deleted", rule.arguments().join(" "));
+            assert_eq!(CanGatewayRule::parse(&listed).as_ref(), Some(rule));
+        }
+
+        let rewrite = CanGatewayRule::parse("cangw -A -s vcan0 -d br-vcan-opendut -X -e -f 123:1FFFFFFF -m SET:I:456.0.0.0000 -l 2 # 5 handled 0 dropped 0 deleted");
+        assert!(rewrite.is_some_and(|rule| expected.contains(&rule)));
+
+        let unfiltered = CanGatewayRule::parse("cangw -A -s vcan0 -d br-vcan-opendut -X -e -l 2 # 0 handled 0 dropped 0 deleted");
+        assert!(unfiltered.is_some_and(|rule| !expected.contains(&rule)));
+
+        let foreign = CanGatewayRule::parse("cangw -A -s vcan0 -d br-vcan-opendut -e -i -l 2 # 0 handled 0 dropped 0 deleted");
+        assert_eq!(foreign, None);
+        Ok(())
+    }
+
+    fn can_local_route(rules: CanFrameRules) -> anyhow::Result<parameter::CanLocalRoute> {
+        Ok(parameter::CanLocalRoute {
+            can_source_device_name: NetworkInterfaceName::try_from("vcan0")?,
+            can_destination_device_name: NetworkInterfaceName::try_from("br-vcan-opendut")?,
+            rules,
+        })
+    }
+}
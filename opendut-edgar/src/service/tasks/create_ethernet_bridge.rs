@@ -23,7 +23,8 @@ impl Task for CreateEthernetBridge {
         match interface {
             Some(bridge) => {
                 let interface_is_up = bridge.link_flags.contains(LinkFlags::Up);
-                if NetlinkInterfaceKind::Bridge == bridge.kind && interface_is_up {
+                if NetlinkInterfaceKind::Bridge == bridge.kind && interface_is_up
+                && self.network_interface_manager.is_bridge_spanning_tree_enabled(&bridge).await? == self.parameter.spanning_tree {
                     Ok(TaskStateFulfilled::Yes)
                 } else {
                     Ok(TaskStateFulfilled::No)
@@ -40,12 +41,16 @@ impl Task for CreateEthernetBridge {
             None => {
                 let bridge = self.network_interface_manager.create_empty_bridge(&self.parameter.name).await?;
                 self.network_interface_manager.set_opendut_alternative_name(&bridge).await?;
+                if self.parameter.spanning_tree {
+                    self.network_interface_manager.set_bridge_spanning_tree(&bridge, true).await?;
+                }
                 self.network_interface_manager.set_interface_up(&bridge).await?;
 
                 Ok(Success::default())
             }
             Some(bridge) => {
                 if NetlinkInterfaceKind::Bridge == bridge.kind {
+                    self.network_interface_manager.set_bridge_spanning_tree(&bridge, self.parameter.spanning_tree).await?;
                     self.network_interface_manager.set_interface_up(&bridge).await?;
                     Ok(Success::default())
                 } else {
@@ -109,6 +114,8 @@ mod tests {
     use crate::service::tasks;
     use crate::service::tasks::task_resolver::ServiceTaskResolver;
     use crate::service::tasks::testing::NetworkInterfaceNameExt;
+    use crate::service::test_execution::executor_manager::ExecutorManager;
+    use tokio::sync::mpsc;
 
     #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
     #[test_log::test(tokio::test)]
@@ -186,6 +193,7 @@ mod tests {
 
             let parameter = parameter::EthernetBridge {
                 name: bridge_name.clone(),
+                spanning_tree: false,
             };
 
             let mut peer_configuration = PeerConfiguration::default();
@@ -199,10 +207,13 @@ mod tests {
                 network_interface_manager: network_interface_manager.clone(),
                 can_manager
             };
+            let (tx_executor_status, _) = mpsc::channel(1);
+            let executor_manager = ExecutorManager::create(tx_executor_status);
             let metrics_manager = NetworkMetricsManager::new(NetworkMetricsOptions::default());
             let service_task_resolver = tasks::task_resolver::ServiceTaskResolver::new(
                 peer_configuration.clone(),
                 network_interface_management.clone(),
+                executor_manager,
                 Arc::clone(&metrics_manager),
             );
 
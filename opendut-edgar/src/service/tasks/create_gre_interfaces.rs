@@ -46,7 +46,7 @@ impl Task for ManageGreInterface {
         match name {
             None => {
                 let name = self.parameter.interface_name()?;
-                let interface = self.network_interface_manager.create_gretap_v4_interface(&name, &self.parameter.local_ip, &self.parameter.remote_ip).await?;
+                let interface = self.network_interface_manager.create_gretap_interface(&name, &self.parameter.local_ip, &self.parameter.remote_ip).await?;
                 self.network_interface_manager.set_interface_up(&interface).await?;
                 self.network_interface_manager.set_opendut_alternative_name(&interface).await?;
             }
@@ -88,7 +88,8 @@ mod tests {
     use crate::service::network_interface::manager::NetworkInterfaceManager;
     use crate::service::service_runner;
     use opendut_model::peer::configuration::ParameterTarget;
-    use std::net::Ipv4Addr;
+    use crate::service::network_interface::manager::testing::run_in_network_namespace;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     use std::str::FromStr;
     use std::sync::Arc;
 
@@ -98,8 +99,8 @@ mod tests {
         // ARRANGE
         let fixture = Fixture::create();
         let parameter = parameter::GreInterfaceConfig {
-            local_ip: Ipv4Addr::from_str("192.168.0.1")?,
-            remote_ip: Ipv4Addr::from_str("192.168.0.2")?,
+            local_ip: IpAddr::V4(Ipv4Addr::from_str("192.168.0.1")?),
+            remote_ip: IpAddr::V4(Ipv4Addr::from_str("192.168.0.2")?),
         };
         let expected_name = parameter.interface_name()?;
         let gre_interface = fixture.network_interface_manager.find_interface(&expected_name).await?;
@@ -120,6 +121,39 @@ mod tests {
         Ok(())
     }
 
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test]
+    fn test_create_gre_interface_for_ipv6() -> anyhow::Result<()> {
+        run_in_network_namespace(|network_interface_manager| async move {
+            // ARRANGE
+            let parameter = parameter::GreInterfaceConfig {
+                local_ip: IpAddr::V6(Ipv6Addr::from_str("fd00::1")?),
+                remote_ip: IpAddr::V6(Ipv6Addr::from_str("fd00::2")?),
+            };
+            let expected_name = parameter.interface_name()?;
+
+            // ACT
+            let task: Box<dyn TaskAbsent> = Box::new(ManageGreInterface {
+                parameter,
+                network_interface_manager: Arc::clone(&network_interface_manager),
+            });
+            let result = service_runner::run_individual_task(task.as_ref(), ParameterTarget::Present).await;
+
+            // ASSERT
+            assert!(result.is_ok());
+            let gre_interface = network_interface_manager.find_interface(&expected_name).await?
+                .expect("GRE interface not found!");
+            assert!(gre_interface.link_flags.contains(LinkFlags::Up), "GRE interface is not up!");
+
+            let result = service_runner::run_individual_task(task.as_ref(), ParameterTarget::Absent).await;
+            assert!(result.is_ok());
+            let gre_interface = network_interface_manager.find_interface(&expected_name).await?;
+            assert!(gre_interface.is_none(), "GRE interface unexpectedly present!");
+
+            Ok(())
+        })
+    }
+
     pub struct Fixture {
         network_interface_manager: NetworkInterfaceManagerRef,
     }
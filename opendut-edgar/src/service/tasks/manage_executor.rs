@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use tracing::debug;
+use opendut_model::peer::configuration::parameter;
+use crate::common::task::{Success, Task, TaskAbsent, TaskStateFulfilled};
+use crate::service::test_execution::executor_manager::ExecutorManagerRef;
+
+pub struct ManageExecutor {
+    pub parameter: parameter::Executor,
+    pub executor_manager: ExecutorManagerRef,
+}
+
+#[async_trait]
+impl Task for ManageExecutor {
+    fn description(&self) -> String {
+        format!("Executor <{}>", self.parameter.descriptor.id)
+    }
+
+    async fn check_present(&self) -> anyhow::Result<TaskStateFulfilled> {
+        let executor_manager = self.executor_manager.lock().await;
+
+        if executor_manager.contains(&self.parameter.descriptor) {
+            Ok(TaskStateFulfilled::Yes)
+        } else {
+            Ok(TaskStateFulfilled::No)
+        }
+    }
+
+    async fn make_present(&self) -> anyhow::Result<Success> {
+        let descriptor = &self.parameter.descriptor;
+
+        let outdated_executor = self.executor_manager.lock().await.remove(descriptor.id);
+        if let Some(outdated_executor) = outdated_executor {
+            debug!("Configuration of executor <{}> changed. Replacing it.", descriptor.id);
+            outdated_executor.terminate().await;
+        }
+
+        self.executor_manager.lock().await.spawn(descriptor.clone());
+
+        Ok(Success::default())
+    }
+}
+
+#[async_trait]
+impl TaskAbsent for ManageExecutor {
+    async fn check_absent(&self) -> anyhow::Result<TaskStateFulfilled> {
+        let executor_manager = self.executor_manager.lock().await;
+
+        if executor_manager.contains(&self.parameter.descriptor) {
+            Ok(TaskStateFulfilled::No)
+        } else {
+            Ok(TaskStateFulfilled::Yes)
+        }
+    }
+
+    async fn make_absent(&self) -> anyhow::Result<Success> {
+        let descriptor = &self.parameter.descriptor;
+
+        let executor = {
+            let mut executor_manager = self.executor_manager.lock().await;
+            if executor_manager.contains(descriptor) {
+                executor_manager.remove(descriptor.id)
+            } else {
+                None
+            }
+        };
+        if let Some(executor) = executor {
+            executor.terminate().await;
+        }
+
+        Ok(Success::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::test_execution::executor_manager::ExecutorManager;
+    use opendut_carl_api::carl::broker::ExecutorStatusUpdate;
+    use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorId, ExecutorKind};
+    use opendut_model::peer::executor::executable::{ExecutableArgument, ExecutablePath, ExecutableRestartPolicy};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn should_only_replace_executors_whose_configuration_changed() -> anyhow::Result<()> {
+        let (tx_executor_status, _rx_executor_status) = mpsc::channel::<ExecutorStatusUpdate>(100);
+        let executor_manager = ExecutorManager::create(tx_executor_status);
+
+        let unchanged = task(executable_descriptor(ExecutorId::random(), "60"), &executor_manager);
+        let original = task(executable_descriptor(ExecutorId::random(), "60"), &executor_manager);
+        let changed = task(executable_descriptor(original.parameter.descriptor.id, "120"), &executor_manager);
+
+        unchanged.make_present().await?;
+        original.make_present().await?;
+        assert_eq!(unchanged.check_present().await?, TaskStateFulfilled::Yes);
+        assert_eq!(original.check_present().await?, TaskStateFulfilled::Yes);
+        assert_eq!(changed.check_present().await?, TaskStateFulfilled::No);
+
+        changed.make_present().await?;
+        assert_eq!(changed.check_present().await?, TaskStateFulfilled::Yes);
+        assert_eq!(original.check_absent().await?, TaskStateFulfilled::Yes);
+        assert_eq!(unchanged.check_present().await?, TaskStateFulfilled::Yes);
+
+        original.make_absent().await?;
+        assert_eq!(changed.check_present().await?, TaskStateFulfilled::Yes, "Removing the outdated configuration should not stop the replacement.");
+
+        changed.make_absent().await?;
+        unchanged.make_absent().await?;
+        assert_eq!(changed.check_absent().await?, TaskStateFulfilled::Yes);
+        assert_eq!(unchanged.check_absent().await?, TaskStateFulfilled::Yes);
+
+        Ok(())
+    }
+
+    fn task(descriptor: ExecutorDescriptor, executor_manager: &ExecutorManagerRef) -> ManageExecutor {
+        ManageExecutor {
+            parameter: parameter::Executor { descriptor },
+            executor_manager: executor_manager.clone(),
+        }
+    }
+
+    fn executable_descriptor(id: ExecutorId, sleep_seconds: &str) -> ExecutorDescriptor {
+        ExecutorDescriptor {
+            id,
+            kind: ExecutorKind::Executable {
+                path: ExecutablePath::try_from("sleep").unwrap(),
+                args: vec![ExecutableArgument::try_from(sleep_seconds).unwrap()],
+                envs: vec![],
+                working_dir: None,
+                restart_policy: ExecutableRestartPolicy::Never,
+            },
+            results_url: None,
+        }
+    }
+}
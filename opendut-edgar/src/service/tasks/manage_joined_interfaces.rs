@@ -27,25 +27,33 @@ impl Task for ManageJoinedInterface {
     }
 
     async fn check_present(&self) -> anyhow::Result<TaskStateFulfilled> {
-        if self.find_joined_interface().await?.is_some() {
-            Ok(TaskStateFulfilled::Yes)
-        } else {
-            Ok(TaskStateFulfilled::No)
+        match self.find_joined_interface().await? {
+            Some(interface) if self.network_interface_manager.is_bridge_port_isolated(&interface).await? == self.parameter.isolated => {
+                Ok(TaskStateFulfilled::Yes)
+            }
+            _ => Ok(TaskStateFulfilled::No),
         }
     }
 
     async fn make_present(&self) -> anyhow::Result<Success> {
-        if self.find_joined_interface().await?.is_none() {
-            let interface = self.network_interface_manager.find_interface(&self.parameter.name).await?;
-            let bridge = self.network_interface_manager.find_interface(&self.parameter.bridge).await?;
-            if let (Some(interface), Some(bridge)) = (interface, bridge) {
-                self.network_interface_manager.join_interface_to_bridge(&interface, &bridge).await?;
-            } else {
-                return Err(anyhow::Error::msg(format!(
-                    "Cannot join interface '{}' to bridge '{}': one of them does not exist.",
-                    self.parameter.name, self.parameter.bridge
-                )));
+        let joined_interface = match self.find_joined_interface().await? {
+            Some(interface) => interface,
+            None => {
+                let interface = self.network_interface_manager.find_interface(&self.parameter.name).await?;
+                let bridge = self.network_interface_manager.find_interface(&self.parameter.bridge).await?;
+                if let (Some(interface), Some(bridge)) = (interface, bridge) {
+                    self.network_interface_manager.join_interface_to_bridge(&interface, &bridge).await?;
+                    interface
+                } else {
+                    return Err(anyhow::Error::msg(format!(
+                        "Cannot join interface '{}' to bridge '{}': one of them does not exist.",
+                        self.parameter.name, self.parameter.bridge
+                    )));
+                }
             }
+        };
+        if self.network_interface_manager.is_bridge_port_isolated(&joined_interface).await? != self.parameter.isolated {
+            self.network_interface_manager.set_bridge_port_isolated(&joined_interface, self.parameter.isolated).await?;
         }
         Ok(Success::default())
     }
@@ -0,0 +1,107 @@
+use crate::common::task::{Success, Task, TaskAbsent, TaskStateFulfilled};
+use crate::service::can::can_manager::CanManagerRef;
+use opendut_model::peer::configuration::parameter;
+
+pub struct ManageRestbusSimulation {
+    pub parameter: parameter::RestbusSimulation,
+    pub can_manager: CanManagerRef,
+}
+
+#[async_trait::async_trait]
+impl Task for ManageRestbusSimulation {
+    fn description(&self) -> String {
+        format!("Restbus simulation of ECUs {:?} on interface <{}>", self.parameter.ecus, self.parameter.can_interface_name)
+    }
+
+    async fn check_present(&self) -> anyhow::Result<TaskStateFulfilled> {
+        if self.is_running().await {
+            Ok(TaskStateFulfilled::Yes)
+        } else {
+            Ok(TaskStateFulfilled::No)
+        }
+    }
+
+    async fn make_present(&self) -> anyhow::Result<Success> {
+        if !self.is_running().await {
+            let can_manager = self.can_manager.lock().await;
+            can_manager.start_restbus_simulation(&self.parameter).await?;
+            Ok(Success::message(format!("Restbus simulation on {} started", self.parameter.can_interface_name)))
+        } else {
+            Ok(Success::message(format!("Restbus simulation on {} was already running", self.parameter.can_interface_name)))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskAbsent for ManageRestbusSimulation {
+    async fn check_absent(&self) -> anyhow::Result<TaskStateFulfilled> {
+        if self.is_running().await {
+            Ok(TaskStateFulfilled::No)
+        } else {
+            Ok(TaskStateFulfilled::Yes)
+        }
+    }
+
+    async fn make_absent(&self) -> anyhow::Result<Success> {
+        if self.is_running().await {
+            let can_manager = self.can_manager.lock().await;
+            can_manager.stop_restbus_simulation(&self.parameter).await;
+            Ok(Success::message(format!("Restbus simulation on {} stopped", self.parameter.can_interface_name)))
+        } else {
+            Ok(Success::message(format!("Restbus simulation on {} was not running", self.parameter.can_interface_name)))
+        }
+    }
+}
+
+impl ManageRestbusSimulation {
+    async fn is_running(&self) -> bool {
+        let can_manager = self.can_manager.lock().await;
+        can_manager.restbus_simulation_is_running(&self.parameter).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::can::can_manager::CanManagerExt;
+    use crate::service::network_interface::manager::NetworkInterfaceManager;
+    use crate::service::tasks::testing::NetworkInterfaceNameExt;
+    use opendut_model::util::net::NetworkInterfaceName;
+    use socketcan::{EmbeddedFrame, Frame};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test_with::env(RUN_EDGAR_NETLINK_INTEGRATION_TESTS)]
+    #[test_log::test(tokio::test)]
+    async fn should_transmit_frames_of_simulated_ecus_on_vcan() -> anyhow::Result<()> {
+        let network_interface_manager = NetworkInterfaceManager::create()?;
+        let interface_name = NetworkInterfaceName::with_random_suffix("rbsim");
+        let interface = network_interface_manager.create_vcan_interface(&interface_name).await?;
+        network_interface_manager.set_interface_up(&interface).await?;
+
+        let receiver = socketcan::tokio::CanFdSocket::open(&interface_name.name())?;
+
+        let testee = ManageRestbusSimulation {
+            parameter: parameter::RestbusSimulation {
+                can_interface_name: interface_name.clone(),
+                arxml_file: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../opendut-util/opendut-can-database/tests/resources/restbus.arxml"),
+                ecus: vec![String::from("Engine")],
+            },
+            can_manager: CanManagerRef::new_shared(),
+        };
+        assert_eq!(testee.check_present().await?, TaskStateFulfilled::No);
+
+        testee.make_present().await?;
+        assert_eq!(testee.check_present().await?, TaskStateFulfilled::Yes);
+
+        let frame = tokio::time::timeout(Duration::from_secs(1), receiver.read_frame()).await??;
+        assert_eq!(frame.raw_id(), 0x100);
+        assert_eq!(frame.data(), &[0x34, 0x12, 90, 0, 0, 0, 0, 0]);
+
+        testee.make_absent().await?;
+        assert_eq!(testee.check_absent().await?, TaskStateFulfilled::Yes);
+
+        network_interface_manager.delete_interface(&interface).await?;
+        Ok(())
+    }
+}
@@ -9,6 +9,8 @@ mod can_local_route;
 mod can_virtual_device;
 mod create_ethernet_bridge;
 mod create_gre_interfaces;
+mod manage_executor;
 mod manage_joined_interfaces;
+mod manage_restbus_simulation;
 mod require_interface_up;
 mod setup_cluster_metrics;
@@ -3,6 +3,7 @@ use crate::common::task::TaskAbsent;
 use crate::service::network_metrics::manager::NetworkMetricsManagerRef;
 use crate::service::peer_configuration::NetworkInterfaceManagement;
 use crate::service::tasks;
+use crate::service::test_execution::executor_manager::ExecutorManagerRef;
 use opendut_model::peer::configuration::{ParameterVariant, PeerConfiguration};
 use std::collections::HashMap;
 use opendut_model::peer::configuration::parameter::DeviceInterface;
@@ -13,6 +14,7 @@ use crate::service::network_interface::manager::NetworkInterfaceManagerRef;
 pub struct ServiceTaskResolver {
     peer_configuration: PeerConfiguration,
     network_interface_management: NetworkInterfaceManagement,
+    executor_manager: ExecutorManagerRef,
     metrics_manager: NetworkMetricsManagerRef,
 }
 
@@ -20,11 +22,13 @@ impl ServiceTaskResolver {
     pub fn new(
         peer_configuration: PeerConfiguration,
         network_interface_management: NetworkInterfaceManagement,
+        executor_manager: ExecutorManagerRef,
         metrics_manager: NetworkMetricsManagerRef,
     ) -> Self {
         Self {
             peer_configuration,
             network_interface_management,
+            executor_manager,
             metrics_manager,
         }
     }
@@ -34,6 +38,10 @@ impl TaskResolver for ServiceTaskResolver {
     fn resolve_tasks(&self, parameter: &ParameterVariant) -> Vec<Box<dyn TaskAbsent>> {
         let mut tasks: Vec<Box<dyn TaskAbsent>> = vec![];
 
+        if let ParameterVariant::Executor(executor) = parameter {
+            tasks.push(Box::new(tasks::manage_executor::ManageExecutor { parameter: executor.value.clone(), executor_manager: self.executor_manager.clone() }));
+        }
+
         if let NetworkInterfaceManagement::Enabled { network_interface_manager, can_manager } = &self.network_interface_management {
             let network_interface_manager = network_interface_manager.clone();
             match parameter {
@@ -43,8 +51,8 @@ impl TaskResolver for ServiceTaskResolver {
                 ParameterVariant::EthernetBridge(ethernet_bridge) => {
                     tasks.push(Box::new(tasks::create_ethernet_bridge::CreateEthernetBridge { parameter: ethernet_bridge.value.clone(), network_interface_manager }));
                 }
-                ParameterVariant::Executor(_executor) => {
-                    // TODO: Migrate to a task that can handle the executor
+                ParameterVariant::Executor(_) => {
+                    // executors are managed independently of network interface management, see above
                 }
                 ParameterVariant::GreInterface(gre_interface) => {
                     tasks.push(Box::new(tasks::create_gre_interfaces::ManageGreInterface { parameter: gre_interface.value.clone(), network_interface_manager }));
@@ -65,6 +73,9 @@ impl TaskResolver for ServiceTaskResolver {
                     tasks.push(Box::new(tasks::can_local_route::CanLocalRoute { parameter: parameter.value.clone(), network_interface_manager: network_interface_manager.clone(), can_fd: false }));
                     tasks.push(Box::new(tasks::can_local_route::CanLocalRoute { parameter: parameter.value.clone(), network_interface_manager, can_fd: true }));
                 }
+                ParameterVariant::RestbusSimulations(parameter) => {
+                    tasks.push(Box::new(tasks::manage_restbus_simulation::ManageRestbusSimulation { parameter: parameter.value.clone(), can_manager: can_manager.clone() }));
+                }
             };
         }
 
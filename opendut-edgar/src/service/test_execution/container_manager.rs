@@ -1,16 +1,12 @@
-use std::{env, io::{Cursor, ErrorKind, Write}, path::PathBuf, process::Stdio};
+use std::process::Stdio;
 
-use anyhow::Result;
-use tokio::{fs::{self, File}, io::{AsyncBufReadExt, AsyncReadExt, BufReader}, process::{Child, Command}, sync::{mpsc, watch}};
+use tokio::{io::{AsyncBufReadExt, BufReader}, process::{Child, Command}, sync::{mpsc, watch}};
 use tracing::{error, info, warn};
-use url::Url;
-use uuid::Uuid;
-use walkdir::WalkDir;
-use zip::{CompressionMethod, write::{FileOptionExtension, FileOptions, SimpleFileOptions}, ZipWriter};
 
-use opendut_model::peer::executor::{container::{CommandName, ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine}, ResultsUrl};
+use opendut_model::peer::executor::{container::{CommandName, ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine}, state::ExecutorStatus, ResultsUrl};
 
-use crate::service::test_execution::webdav_client::{self, WebdavClient};
+use crate::service::test_execution::executor_manager::ExecutorStatusReporter;
+use crate::service::test_execution::results_uploader::{self, ResultsUploader};
 
 #[derive(Debug)]
 enum ContainerState {
@@ -37,38 +33,44 @@ pub struct ContainerConfiguration {
 
 pub struct ContainerManager{
     config: ContainerConfiguration,
-    results_dir: PathBuf,
-    webdav_client: WebdavClient,
+    results_uploader: ResultsUploader,
+    status_reporter: ExecutorStatusReporter,
     termination_channel_rx: watch::Receiver<bool>,
 }
 
 const MONITOR_INTERVAL_MS: u64 = 1000;
-const RESULTS_READY_FILE: &str = ".results_ready";
 const CONTAINER_RESULTS_DIRECTORY: &str = "/results";
 
 impl ContainerManager {
 
-    pub fn new(container_configuration: ContainerConfiguration, termination_channel_rx: watch::Receiver<bool>) -> Self {
+    pub fn new(container_configuration: ContainerConfiguration, status_reporter: ExecutorStatusReporter, termination_channel_rx: watch::Receiver<bool>) -> Self {
+        let results_uploader = ResultsUploader::new(String::from(&container_configuration.name), container_configuration.results_url.clone());
         Self { 
             config: container_configuration,
-            results_dir: env::temp_dir().join(format!("opendut-edgar-results_{}", Uuid::new_v4())),
-            webdav_client: WebdavClient::new("some_dummy_token".to_string()), // TODO: Authenticate with actual token
+            results_uploader,
+            status_reporter,
             termination_channel_rx
         }
     }
 
     pub async fn start(&mut self) {
+        self.status_reporter.report(ExecutorStatus::Pending).await;
         match self.run().await {
             Ok(_) => (),
-            Err(cause) => error!("{}", cause.to_string()),
+            Err(cause) => {
+                error!("{}", cause.to_string());
+                self.status_reporter.report(ExecutorStatus::Failed { reason: cause.to_string() }).await;
+            }
         }
     }
 
     async fn run(&mut self) -> Result<(), Error> {
         let mut results_uploaded = false;
 
-        self.create_results_dir().await?;
+        self.results_uploader.create_results_dir().await?;
+        self.pull_image_if_missing().await?;
         let container_name = self.start_container().await?;
+        self.status_reporter.report(ExecutorStatus::Running).await;
         let mut log_reader = 
             ContainerLogReader::create(
                 self.config.engine.command_name(), 
@@ -83,18 +85,21 @@ impl ContainerManager {
                 self.stop_container(&container_name).await?;
             }
 
-            if self.are_results_ready().await? {
-                self.remove_result_ready_indicator().await?;
-                self.upload_results().await?;
+            if self.results_uploader.are_results_ready().await? {
+                self.results_uploader.remove_result_ready_indicator().await?;
+                self.results_uploader.upload_results().await?;
                 results_uploaded = true;
             }
 
             match self.get_container_state(&container_name).await? {
                 ContainerState::Running => (),
                 ContainerState::Exited => {
+                    let code = self.get_container_exit_code(&container_name).await?;
+                    self.status_reporter.report(ExecutorStatus::Exited { code }).await;
+
                     if ! results_uploaded {
-                        self.remove_result_ready_indicator().await?;
-                        self.upload_results().await?;
+                        self.results_uploader.remove_result_ready_indicator().await?;
+                        self.results_uploader.upload_results().await?;
                     }
                     break
                 },
@@ -106,7 +111,11 @@ impl ContainerManager {
             tokio::time::sleep(std::time::Duration::from_millis(MONITOR_INTERVAL_MS)).await;
         }
 
-        self.cleanup_results_dir().await?;
+        self.results_uploader.cleanup_results_dir().await?;
+
+        if self.results_uploader.has_results_url() {
+            self.status_reporter.report(ExecutorStatus::ResultsUploaded).await;
+        }
 
         Ok(())
     }
@@ -130,6 +139,47 @@ impl ContainerManager {
         
     }
 
+    async fn get_container_exit_code(&self, container_name: &str) -> Result<Option<i32>, Error> {
+        let output = Command::new(self.config.engine.command_name())
+            .args(["inspect", "-f", "{{.State.ExitCode}}", container_name])
+            .output()
+            .await
+            .map_err(|cause| Error::CommandLineProgramExecution { command: format!("{} inspect", &self.config.engine.command_name()), cause })?;
+
+        let exit_code = String::from_utf8_lossy(&output.stdout);
+        Ok(exit_code.trim().parse::<i32>().ok())
+    }
+
+    async fn pull_image_if_missing(&self) -> Result<(), Error> {
+        let image = self.config.image.to_string();
+
+        let image_exists = Command::new(self.config.engine.command_name())
+            .args(["image", "inspect", image.as_str()])
+            .output()
+            .await
+            .map_err(|cause| Error::CommandLineProgramExecution { command: format!("{} image inspect", &self.config.engine.command_name()), cause })?
+            .status.success();
+
+        if image_exists {
+            return Ok(());
+        }
+
+        self.status_reporter.report(ExecutorStatus::Pulling).await;
+
+        let output = Command::new(self.config.engine.command_name())
+            .args(["pull", image.as_str()])
+            .output()
+            .await
+            .map_err(|cause| Error::CommandLineProgramExecution { command: format!("{} pull", &self.config.engine.command_name()), cause })?;
+
+        if output.status.success() {
+            info!("Pulled image {} for container {}", image, self.config.name);
+            Ok(())
+        } else {
+            Err(Error::Other { message: format!("Pulling image '{}' failed: {}", image, String::from_utf8_lossy(&output.stderr)) })
+        }
+    }
+
     async fn start_container(&mut self) -> Result<String, Error>{
 
         let mut cmd = Command::new(self.config.engine.command_name());
@@ -147,7 +197,7 @@ impl ContainerManager {
         }
         cmd.args(["--name", container_name.as_str()]);
 
-        cmd.args(["--mount", format!("type=bind,source={},target={}", self.results_dir.to_string_lossy(), CONTAINER_RESULTS_DIRECTORY).as_str()]);
+        cmd.args(["--mount", format!("type=bind,source={},target={}", self.results_uploader.results_dir().to_string_lossy(), CONTAINER_RESULTS_DIRECTORY).as_str()]);
         
         for env in &self.config.envs {
             cmd.args(["--env", &format!("{}={}", env.name(), env.value())]);
@@ -208,115 +258,14 @@ impl ContainerManager {
 
     }
 
-    async fn remove_result_ready_indicator(&self) -> Result<(), Error>{ 
-        let indicator_file = self.results_dir.join(RESULTS_READY_FILE);
-        match fs::remove_file(&indicator_file).await {
-            Ok(_) => Ok(()),
-            Err(err) => match err.kind() {
-                ErrorKind::NotFound => Ok(()),
-                _ => Err(Error::Other { message: format!("Failed to remove result indicator file '{}': {}", indicator_file.to_string_lossy(), err) }),
-            },
-        }
-
-    }
-
-    async fn upload_results(&self) -> Result<(), Error>{
-        info!("Starting upload for results of {}", self.config.name);
-        let results_url = match &self.config.results_url {
-            Some(results_url) => results_url.value(),
-            None => {
-                info!("Container {} has no results URL, won't upload results.", self.config.name);
-                return Ok(());
-            },
-        };
-        
-        let mut zipped_data = Vec::new();
-        // https://github.com/zip-rs/zip2/issues/195 large_file(true) produces invalid zip file with crate version 2.1.3
-        let zip_options = SimpleFileOptions::default().compression_method(CompressionMethod::BZIP2).large_file(false);
-        create_zip_from_directory(&mut zipped_data, &self.results_dir, zip_options).await.map_err(|cause| Error::ResultZipping { path: self.results_dir.clone(), cause })?;
-
-        self.webdav_client.create_collection_path(results_url.clone())
-            .await
-            .map_err(|cause| Error::ResultUploadingInternal { url: results_url.clone(), cause })?;
-
-        let results_file_url = results_url.join(
-            format!("{}_{}.zip", chrono::offset::Local::now().format("%Y-%m-%d_%H-%M-%S"), self.config.name).as_str()
-        ).map_err(|cause| Error::Other { message: format!("Failed to construct URL for results directory: {cause}") })?;
-
-        let response = self.webdav_client.put(zipped_data, results_file_url.clone())
-            .await
-            .map_err(|cause| Error::ResultUploadingInternal { url: results_file_url.clone(), cause })?;
-
-        match response.status().is_success() {
-            true => {
-                info!("Successfully uploaded results of {}", self.config.name);
-                Ok(())
-            },
-            false => Err(Error::ResultUploadingServer { container_name: self.config.name.clone(), url: results_file_url.clone(), status: response.status() }),
-        }
-
-    }
-
-    async fn create_results_dir(&mut self) -> Result<(), Error>{
-        fs::create_dir_all(&self.results_dir)
-            .await
-            .map_err(|cause| Error::Other { message: format!("Failed to create results directory '{}': {}", self.results_dir.to_string_lossy(), cause) })?;
-        Ok(())
-    }
-
-    async fn cleanup_results_dir(&self) -> Result<(), Error> {
-        fs::remove_dir_all(&self.results_dir)
-            .await
-            .map_err(|cause| Error::Other { message: format!("Failed to remove results directory '{}': {}", self.results_dir.to_string_lossy(), cause) })?;
-        Ok(())
-    }
-
-    async fn are_results_ready(&self) -> Result<bool, Error> {
-        let indicator_file = self.results_dir.join(RESULTS_READY_FILE);
-        Ok(indicator_file.is_file())
-    }
-
 }
 
-async fn create_zip_from_directory<T>(data: &mut Vec<u8>, directory: &PathBuf, file_options: FileOptions<'_, T>) -> Result<()> 
-    where
-        T: FileOptionExtension + std::marker::Copy,
-    {
-        let mut file_buffer = Vec::new();
-        let zip_buffer = Cursor::new(data);
-        let mut zip = ZipWriter::new(zip_buffer);
-
-        for entry_res in WalkDir::new(directory) {
-            let entry = entry_res?;
-            let entry_path = entry.path();
-            let entry_metadata = entry.metadata()?;
-
-            if entry_metadata.is_file() {
-                let mut f = File::open(&entry_path).await?;
-                f.read_to_end(&mut file_buffer).await?;
-                let relative_path = entry_path.strip_prefix(directory)?;
-                zip.start_file(relative_path.to_string_lossy(), file_options)?;
-                zip.write_all(file_buffer.as_ref())?;
-                file_buffer.clear();
-            } else if entry_metadata.is_dir() {
-                let relative_path = entry_path.strip_prefix(directory)?;
-                zip.add_directory(relative_path.to_string_lossy(), file_options)?;
-            }
-        }
-
-        Ok(())
-    }
-
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Failure while invoking command line program '{command}': {cause}")]
     CommandLineProgramExecution { command: String, cause: std::io::Error },
-    #[error("Failure while creating a ZIP archive of the test results at '{path}' : {cause}")]
-    ResultZipping { path: PathBuf, cause: anyhow::Error },
-    #[error("Failure while uploading test results to '{url}': {cause}")]
-    ResultUploadingInternal { url: Url, cause: webdav_client::Error },
-    #[error("Failure while uploading test results for '{container_name}' to '{url}' (HTTP status {status})")]
-    ResultUploadingServer { container_name: ContainerName, url: Url, status: reqwest::StatusCode },
+    #[error(transparent)]
+    Results(#[from] results_uploader::Error),
     #[error("{message}")]
     Other { message: String },
 }
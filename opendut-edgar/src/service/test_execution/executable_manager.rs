@@ -0,0 +1,266 @@
+use std::time::Duration;
+
+use tokio::{process::Command, sync::watch};
+use tracing::{error, info};
+
+use opendut_model::peer::executor::{container::ContainerEnvironmentVariable, executable::{ExecutableArgument, ExecutablePath, ExecutableRestartPolicy, ExecutableWorkingDirectory}, state::ExecutorStatus, ResultsUrl};
+
+use crate::service::process_manager::{AsyncProcessId, AsyncProcessManager, AsyncProcessManagerExt, AsyncProcessManagerRef, OutputConfig, ProcessConfig, RestartPolicy};
+use crate::service::test_execution::executor_manager::ExecutorStatusReporter;
+use crate::service::test_execution::results_uploader::{self, ResultsUploader};
+
+/// Environment variable, which tells the executable the directory to write its results to.
+pub const RESULTS_DIRECTORY_ENVIRONMENT_VARIABLE: &str = "OPENDUT_RESULTS_DIR";
+
+const MONITOR_INTERVAL_MS: u64 = 1000;
+const RESTART_DELAY: Duration = Duration::from_secs(5);
+
+pub struct ExecutableConfiguration {
+    pub name: String,
+    pub path: ExecutablePath,
+    pub args: Vec<ExecutableArgument>,
+    pub envs: Vec<ContainerEnvironmentVariable>,
+    pub working_dir: Option<ExecutableWorkingDirectory>,
+    pub restart_policy: ExecutableRestartPolicy,
+    pub results_url: Option<ResultsUrl>,
+}
+
+/// Runs an executable natively on the peer, as a child process of EDGAR.
+pub struct ExecutableManager {
+    config: ExecutableConfiguration,
+    process_manager: AsyncProcessManagerRef,
+    results_uploader: ResultsUploader,
+    status_reporter: ExecutorStatusReporter,
+    termination_channel_rx: watch::Receiver<bool>,
+}
+
+impl ExecutableManager {
+
+    pub fn new(executable_configuration: ExecutableConfiguration, status_reporter: ExecutorStatusReporter, termination_channel_rx: watch::Receiver<bool>) -> Self {
+        let results_uploader = ResultsUploader::new(executable_configuration.name.clone(), executable_configuration.results_url.clone());
+        Self {
+            config: executable_configuration,
+            process_manager: AsyncProcessManagerRef::new_shared(),
+            results_uploader,
+            status_reporter,
+            termination_channel_rx,
+        }
+    }
+
+    pub async fn start(&mut self) {
+        self.status_reporter.report(ExecutorStatus::Pending).await;
+        match self.run().await {
+            Ok(_) => (),
+            Err(cause) => {
+                error!("{}", cause.to_string());
+                self.status_reporter.report(ExecutorStatus::Failed { reason: cause.to_string() }).await;
+            }
+        }
+    }
+
+    async fn run(&mut self) -> Result<(), Error> {
+        let mut results_uploaded = false;
+
+        self.results_uploader.create_results_dir().await?;
+        let process_id = self.start_executable().await?;
+        self.status_reporter.report(ExecutorStatus::Running).await;
+
+        loop {
+            // If the value in the channel has changed or the channel has been closed, we terminate
+            if self.termination_channel_rx.has_changed().unwrap_or(true) {
+                self.stop_executable(process_id).await?;
+            }
+
+            if self.results_uploader.are_results_ready().await? {
+                self.results_uploader.remove_result_ready_indicator().await?;
+                self.results_uploader.upload_results().await?;
+                results_uploaded = true;
+            }
+
+            if let Some(code) = self.finished(&process_id).await {
+                self.status_reporter.report(ExecutorStatus::Exited { code }).await;
+
+                if ! results_uploaded {
+                    self.results_uploader.remove_result_ready_indicator().await?;
+                    self.results_uploader.upload_results().await?;
+                }
+                break
+            }
+
+            tokio::time::sleep(Duration::from_millis(MONITOR_INTERVAL_MS)).await;
+        }
+
+        self.results_uploader.cleanup_results_dir().await?;
+
+        if self.results_uploader.has_results_url() {
+            self.status_reporter.report(ExecutorStatus::ResultsUploaded).await;
+        }
+
+        Ok(())
+    }
+
+    async fn start_executable(&self) -> Result<AsyncProcessId, Error> {
+        let ExecutableConfiguration { name, path, args, envs, working_dir, restart_policy, results_url: _ } = &self.config;
+
+        let command_builder = {
+            let path = path.clone();
+            let args = args.clone();
+            let envs = envs.clone();
+            let working_dir = working_dir.clone();
+            let results_dir = self.results_uploader.results_dir().to_owned();
+
+            move || {
+                let mut command = Command::new(path.value());
+                command.args(args.iter().map(ExecutableArgument::value));
+                command.envs(envs.iter().map(|env| (env.name(), env.value())));
+                command.env(RESULTS_DIRECTORY_ENVIRONMENT_VARIABLE, &results_dir);
+                if let Some(working_dir) = &working_dir {
+                    command.current_dir(working_dir.value());
+                }
+                command
+            }
+        };
+
+        let config = ProcessConfig::new(name.clone(), command_builder)
+            .with_restart_policy(restart_policy_of(*restart_policy))
+            .with_restart_delay(RESTART_DELAY)
+            .with_output_config(OutputConfig::Capture);
+
+        let process_id = AsyncProcessManager::spawn(self.process_manager.clone(), config)
+            .await
+            .map_err(|cause| Error::Start { executable: path.clone(), cause })?;
+
+        info!("Started executable {}", name);
+        Ok(process_id)
+    }
+
+    async fn stop_executable(&self, process_id: AsyncProcessId) -> Result<(), Error> {
+        let mut process_manager = self.process_manager.lock().await;
+        process_manager.terminate(process_id)
+            .await
+            .map_err(|cause| Error::Stop { executable: self.config.path.clone(), cause })
+    }
+
+    /// An executable has finished, when it was stopped or exited and is not going to be restarted.
+    /// Returns the exit code of a finished executable, which is missing if it was terminated by a signal.
+    async fn finished(&self, process_id: &AsyncProcessId) -> Option<Option<i32>> {
+        let mut process_manager = self.process_manager.lock().await;
+        if process_manager.process_is_running(process_id) {
+            return None;
+        }
+        match process_manager.exit_status(process_id) {
+            Some(status) if restart_policy_of(self.config.restart_policy).should_restart(status) => None,
+            Some(status) => Some(status.code()),
+            None => Some(None),
+        }
+    }
+}
+
+fn restart_policy_of(policy: ExecutableRestartPolicy) -> RestartPolicy {
+    match policy {
+        ExecutableRestartPolicy::Never => RestartPolicy::Never,
+        ExecutableRestartPolicy::OnFailure => RestartPolicy::OnFailure,
+        ExecutableRestartPolicy::Always => RestartPolicy::Always,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failure while starting executable '{executable}': {cause}")]
+    Start { executable: ExecutablePath, cause: anyhow::Error },
+    #[error("Failure while stopping executable '{executable}': {cause}")]
+    Stop { executable: ExecutablePath, cause: anyhow::Error },
+    #[error(transparent)]
+    Results(#[from] results_uploader::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use googletest::prelude::*;
+    use opendut_carl_api::carl::broker::ExecutorStatusUpdate;
+    use opendut_model::peer::executor::ExecutorId;
+    use tokio::sync::mpsc;
+
+    fn status_reporter() -> (ExecutorStatusReporter, mpsc::Receiver<ExecutorStatusUpdate>) {
+        let (tx, rx) = mpsc::channel(100);
+        (ExecutorStatusReporter::new(ExecutorId::random(), tx), rx)
+    }
+
+    fn received_statuses(rx: &mut mpsc::Receiver<ExecutorStatusUpdate>) -> Vec<ExecutorStatus> {
+        let mut statuses = vec![];
+        while let Ok(update) = rx.try_recv() {
+            statuses.push(update.status);
+        }
+        statuses
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_run_executable_with_configured_arguments_environment_and_working_directory() -> anyhow::Result<()> {
+        let working_dir = tempfile::tempdir()?;
+
+        let config = ExecutableConfiguration {
+            name: String::from("greeter"),
+            path: ExecutablePath::try_from("sh")?,
+            args: vec![
+                ExecutableArgument::try_from("-c")?,
+                ExecutableArgument::try_from(r#"echo "$GREETING $1" > output.txt && touch "$OPENDUT_RESULTS_DIR/.results_ready""#)?,
+                ExecutableArgument::try_from("sh")?,
+                ExecutableArgument::try_from("World")?,
+            ],
+            envs: vec![
+                ContainerEnvironmentVariable::new("GREETING", "Hello")?,
+            ],
+            working_dir: Some(ExecutableWorkingDirectory::try_from(working_dir.path().to_path_buf())?),
+            restart_policy: ExecutableRestartPolicy::Never,
+            results_url: None,
+        };
+        let (_termination_tx, termination_rx) = watch::channel(false);
+        let (status_reporter, mut status_rx) = status_reporter();
+
+        let mut testee = ExecutableManager::new(config, status_reporter, termination_rx);
+        let results_dir = testee.results_uploader.results_dir().to_owned();
+
+        tokio::time::timeout(Duration::from_secs(10), testee.run()).await??;
+
+        let output = std::fs::read_to_string(working_dir.path().join("output.txt"))?;
+        assert_that!(output.trim(), eq("Hello World"));
+        assert_that!(results_dir.exists(), eq(false));
+        assert_that!(received_statuses(&mut status_rx), elements_are![
+            eq(&ExecutorStatus::Running),
+            eq(&ExecutorStatus::Exited { code: Some(0) }),
+        ]);
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn should_stop_executable_when_terminated() -> anyhow::Result<()> {
+        let config = ExecutableConfiguration {
+            name: String::from("sleeper"),
+            path: ExecutablePath::try_from("sleep")?,
+            args: vec![
+                ExecutableArgument::try_from("600")?,
+            ],
+            envs: vec![],
+            working_dir: None,
+            restart_policy: ExecutableRestartPolicy::Always,
+            results_url: None,
+        };
+        let (termination_tx, termination_rx) = watch::channel(false);
+        let (status_reporter, mut status_rx) = status_reporter();
+
+        let mut testee = ExecutableManager::new(config, status_reporter, termination_rx);
+        termination_tx.send(true)?;
+
+        tokio::time::timeout(Duration::from_secs(10), testee.start()).await?;
+
+        assert_that!(received_statuses(&mut status_rx), elements_are![
+            eq(&ExecutorStatus::Pending),
+            eq(&ExecutorStatus::Running),
+            eq(&ExecutorStatus::Exited { code: None }),
+        ]);
+
+        Ok(())
+    }
+}
@@ -1,85 +1,149 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use opendut_model::peer::{self, executor::{ExecutorDescriptor, ExecutorKind}};
-use tokio::sync::{watch::{self, Sender}, Mutex};
+use opendut_carl_api::carl::broker::ExecutorStatusUpdate;
+use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorId, ExecutorKind, state::ExecutorStatus};
+use tokio::sync::{mpsc, watch::{self, Sender}, Mutex};
+use tokio::task::JoinHandle;
 use tracing::{debug, warn};
-use opendut_model::peer::configuration::{parameter, ParameterField};
 use crate::service::test_execution::container_manager::{ContainerManager, ContainerConfiguration};
+use crate::service::test_execution::executable_manager::{ExecutableConfiguration, ExecutableManager};
 
 pub type ExecutorManagerRef = Arc<Mutex<ExecutorManager>>;
 
 #[derive(Debug)]
 pub struct ExecutorManager {
-    tx_termination_channels: Vec<Sender<bool>>,
+    executors: HashMap<ExecutorId, RunningExecutor>,
+    tx_executor_status: mpsc::Sender<ExecutorStatusUpdate>,
 }
 
 impl ExecutorManager {
-    pub fn create() -> ExecutorManagerRef {
+    pub fn create(tx_executor_status: mpsc::Sender<ExecutorStatusUpdate>) -> ExecutorManagerRef {
         Arc::new(Mutex::new(Self {
-            tx_termination_channels: Vec::new(),
+            executors: HashMap::new(),
+            tx_executor_status,
         }))
     }
 
-    pub fn create_new_executors(&mut self, executors: ParameterField<parameter::Executor>) {
-        debug!("Creating executors.");
+    /// Whether an executor with exactly this descriptor was spawned and not removed since.
+    pub fn contains(&self, descriptor: &ExecutorDescriptor) -> bool {
+        self.executors.get(&descriptor.id)
+            .is_some_and(|executor| executor.descriptor == *descriptor)
+    }
 
-        let executors = executors.into_iter()
-            .filter_map(|executor| { //TODO properly handle Present vs. Absent
-                if matches!(executor.target, peer::configuration::ParameterTarget::Present) {
-                    Some(executor.value)
-                } else {
-                    None
-                }
-            });
+    /// Removes the executor with the given ID, handing it out to be terminated.
+    pub fn remove(&mut self, id: ExecutorId) -> Option<RunningExecutor> {
+        self.executors.remove(&id)
+    }
 
-        for executor in executors {
+    pub fn spawn(&mut self, descriptor: ExecutorDescriptor) {
+        debug!("Spawning executor <{}>.", descriptor.id);
 
-            let (tx, rx) = watch::channel(false);
+        let (tx_termination, rx_termination) = watch::channel(false);
 
-            let parameter::Executor { descriptor } = executor;
-            let ExecutorDescriptor { id: _, kind, results_url } = descriptor;
+        let ExecutorDescriptor { id, kind, results_url } = descriptor.clone();
+        let status_reporter = ExecutorStatusReporter::new(id, self.tx_executor_status.clone());
 
-            match kind {
-                ExecutorKind::Executable => warn!("Executing Executable not yet implemented."),
-                ExecutorKind::Container {
-                    engine,
+        let handle = match kind {
+            ExecutorKind::Executable {
+                path,
+                args,
+                envs,
+                working_dir,
+                restart_policy,
+            } => {
+                let executable_config = ExecutableConfiguration {
+                    name: format!("executor-{id}"),
+                    path,
+                    args,
+                    envs,
+                    working_dir,
+                    restart_policy,
+                    results_url,
+                };
+                tokio::spawn(async move {
+                    ExecutableManager::new(executable_config, status_reporter, rx_termination).start().await;
+                })
+            }
+            ExecutorKind::Container {
+                engine,
+                name,
+                image,
+                volumes,
+                devices,
+                envs,
+                ports,
+                command,
+                args,
+            } => {
+                let container_config = ContainerConfiguration{
                     name,
+                    engine,
                     image,
-                    volumes,
-                    devices,
-                    envs,
-                    ports,
                     command,
                     args,
-                } => {
-                    let container_config = ContainerConfiguration{
-                        name,
-                        engine,
-                        image,
-                        command,
-                        args,
-                        envs,
-                        results_url,
-                        ports,
-                        devices,
-                        volumes,
-                    };
-                    tokio::spawn(async move {
-                        ContainerManager::new(container_config, rx).start().await;
-                    });
-                }
+                    envs,
+                    results_url,
+                    ports,
+                    devices,
+                    volumes,
+                };
+                tokio::spawn(async move {
+                    ContainerManager::new(container_config, status_reporter, rx_termination).start().await;
+                })
             }
-            self.tx_termination_channels.push(tx);
+        };
+
+        let previous = self.executors.insert(id, RunningExecutor { descriptor, tx_termination, handle });
+        if let Some(previous) = previous {
+            warn!("Replaced executor <{id}> without terminating it first. Sending termination signal now.");
+            previous.signal_termination();
         }
     }
+}
 
-    pub fn terminate_executors(&mut self) {
-        debug!("Terminating executors.");
-        for tx_termination_channel in &self.tx_termination_channels {
-            if let Err(cause) = tx_termination_channel.send(true) {
-                warn!("Failed to send termination signal to executor, perhaps it already terminated? Cause: {cause}");
-            }
+/// Handle to an executor which was spawned by the [ExecutorManager].
+#[derive(Debug)]
+pub struct RunningExecutor {
+    descriptor: ExecutorDescriptor,
+    tx_termination: Sender<bool>,
+    handle: JoinHandle<()>,
+}
+
+impl RunningExecutor {
+    /// Signals the executor to terminate and waits for it to stop.
+    pub async fn terminate(self) {
+        let id = self.descriptor.id;
+        debug!("Terminating executor <{id}>.");
+        self.signal_termination();
+
+        if let Err(cause) = self.handle.await {
+            warn!("Executor <{id}> did not terminate cleanly: {cause}");
+        }
+    }
+
+    fn signal_termination(&self) {
+        if let Err(cause) = self.tx_termination.send(true) {
+            warn!("Failed to send termination signal to executor <{}>, perhaps it already terminated? Cause: {cause}", self.descriptor.id);
         }
-        self.tx_termination_channels.clear();
+    }
+}
+
+/// Reports the lifecycle of a single executor, to be forwarded to CARL.
+#[derive(Clone, Debug)]
+pub struct ExecutorStatusReporter {
+    id: ExecutorId,
+    tx_executor_status: mpsc::Sender<ExecutorStatusUpdate>,
+}
+
+impl ExecutorStatusReporter {
+    pub fn new(id: ExecutorId, tx_executor_status: mpsc::Sender<ExecutorStatusUpdate>) -> Self {
+        Self { id, tx_executor_status }
+    }
+
+    pub async fn report(&self, status: ExecutorStatus) {
+        debug!("Executor <{}> changed its status to: {status}", self.id);
+        let _ignore_result = self.tx_executor_status.send(ExecutorStatusUpdate { id: self.id, status }).await
+            .inspect_err(|cause| warn!("Failed to report status of executor <{}>: {cause}", self.id));
     }
 }
@@ -1,3 +1,7 @@
 pub mod container_manager;
+pub mod executable_manager;
+mod results_uploader;
 mod webdav_client;
-pub mod executor_manager;
\ No newline at end of file
+pub mod executor_manager;
+#[cfg(feature = "viper")]
+pub mod viper_runner;
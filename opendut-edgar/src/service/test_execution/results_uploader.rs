@@ -0,0 +1,151 @@
+use std::{env, io::{Cursor, ErrorKind, Write}, path::{Path, PathBuf}};
+
+use anyhow::Result;
+use tokio::{fs::{self, File}, io::AsyncReadExt};
+use tracing::info;
+use url::Url;
+use uuid::Uuid;
+use walkdir::WalkDir;
+use zip::{CompressionMethod, write::{FileOptionExtension, FileOptions, SimpleFileOptions}, ZipWriter};
+
+use opendut_model::peer::executor::ResultsUrl;
+
+use crate::service::test_execution::webdav_client::{self, WebdavClient};
+
+const RESULTS_READY_FILE: &str = ".results_ready";
+
+/// Manages the local directory into which an executor writes its results,
+/// and uploads the contents as ZIP archive to the WebDAV server of the executor's results URL.
+pub struct ResultsUploader {
+    name: String,
+    results_dir: PathBuf,
+    results_url: Option<ResultsUrl>,
+    webdav_client: WebdavClient,
+}
+
+impl ResultsUploader {
+
+    pub fn new(name: impl Into<String>, results_url: Option<ResultsUrl>) -> Self {
+        Self {
+            name: name.into(),
+            results_dir: env::temp_dir().join(format!("opendut-edgar-results_{}", Uuid::new_v4())),
+            results_url,
+            webdav_client: WebdavClient::new("some_dummy_token".to_string()), // TODO: Authenticate with actual token
+        }
+    }
+
+    pub fn results_dir(&self) -> &Path {
+        &self.results_dir
+    }
+
+    pub fn has_results_url(&self) -> bool {
+        self.results_url.is_some()
+    }
+
+    pub async fn create_results_dir(&self) -> Result<(), Error>{
+        fs::create_dir_all(&self.results_dir)
+            .await
+            .map_err(|cause| Error::Other { message: format!("Failed to create results directory '{}': {}", self.results_dir.to_string_lossy(), cause) })?;
+        Ok(())
+    }
+
+    pub async fn cleanup_results_dir(&self) -> Result<(), Error> {
+        fs::remove_dir_all(&self.results_dir)
+            .await
+            .map_err(|cause| Error::Other { message: format!("Failed to remove results directory '{}': {}", self.results_dir.to_string_lossy(), cause) })?;
+        Ok(())
+    }
+
+    pub async fn are_results_ready(&self) -> Result<bool, Error> {
+        let indicator_file = self.results_dir.join(RESULTS_READY_FILE);
+        Ok(indicator_file.is_file())
+    }
+
+    pub async fn remove_result_ready_indicator(&self) -> Result<(), Error>{
+        let indicator_file = self.results_dir.join(RESULTS_READY_FILE);
+        match fs::remove_file(&indicator_file).await {
+            Ok(_) => Ok(()),
+            Err(err) => match err.kind() {
+                ErrorKind::NotFound => Ok(()),
+                _ => Err(Error::Other { message: format!("Failed to remove result indicator file '{}': {}", indicator_file.to_string_lossy(), err) }),
+            },
+        }
+    }
+
+    pub async fn upload_results(&self) -> Result<(), Error>{
+        info!("Starting upload for results of {}", self.name);
+        let results_url = match &self.results_url {
+            Some(results_url) => results_url.value(),
+            None => {
+                info!("Executor {} has no results URL, won't upload results.", self.name);
+                return Ok(());
+            },
+        };
+
+        let mut zipped_data = Vec::new();
+        // https://github.com/zip-rs/zip2/issues/195 large_file(true) produces invalid zip file with crate version 2.1.3
+        let zip_options = SimpleFileOptions::default().compression_method(CompressionMethod::BZIP2).large_file(false);
+        create_zip_from_directory(&mut zipped_data, &self.results_dir, zip_options).await.map_err(|cause| Error::ResultZipping { path: self.results_dir.clone(), cause })?;
+
+        self.webdav_client.create_collection_path(results_url.clone())
+            .await
+            .map_err(|cause| Error::ResultUploadingInternal { url: results_url.clone(), cause })?;
+
+        let results_file_url = results_url.join(
+            format!("{}_{}.zip", chrono::offset::Local::now().format("%Y-%m-%d_%H-%M-%S"), self.name).as_str()
+        ).map_err(|cause| Error::Other { message: format!("Failed to construct URL for results directory: {cause}") })?;
+
+        let response = self.webdav_client.put(zipped_data, results_file_url.clone())
+            .await
+            .map_err(|cause| Error::ResultUploadingInternal { url: results_file_url.clone(), cause })?;
+
+        match response.status().is_success() {
+            true => {
+                info!("Successfully uploaded results of {}", self.name);
+                Ok(())
+            },
+            false => Err(Error::ResultUploadingServer { url: results_file_url.clone(), status: response.status() }),
+        }
+    }
+}
+
+async fn create_zip_from_directory<T>(data: &mut Vec<u8>, directory: &PathBuf, file_options: FileOptions<'_, T>) -> Result<()>
+    where
+        T: FileOptionExtension + std::marker::Copy,
+    {
+        let mut file_buffer = Vec::new();
+        let zip_buffer = Cursor::new(data);
+        let mut zip = ZipWriter::new(zip_buffer);
+
+        for entry_res in WalkDir::new(directory) {
+            let entry = entry_res?;
+            let entry_path = entry.path();
+            let entry_metadata = entry.metadata()?;
+
+            if entry_metadata.is_file() {
+                let mut f = File::open(&entry_path).await?;
+                f.read_to_end(&mut file_buffer).await?;
+                let relative_path = entry_path.strip_prefix(directory)?;
+                zip.start_file(relative_path.to_string_lossy(), file_options)?;
+                zip.write_all(file_buffer.as_ref())?;
+                file_buffer.clear();
+            } else if entry_metadata.is_dir() {
+                let relative_path = entry_path.strip_prefix(directory)?;
+                zip.add_directory(relative_path.to_string_lossy(), file_options)?;
+            }
+        }
+
+        Ok(())
+    }
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failure while creating a ZIP archive of the test results at '{path}' : {cause}")]
+    ResultZipping { path: PathBuf, cause: anyhow::Error },
+    #[error("Failure while uploading test results to '{url}': {cause}")]
+    ResultUploadingInternal { url: Url, cause: webdav_client::Error },
+    #[error("Failure while uploading test results to '{url}' (HTTP status {status})")]
+    ResultUploadingServer { url: Url, status: reqwest::StatusCode },
+    #[error("{message}")]
+    Other { message: String },
+}
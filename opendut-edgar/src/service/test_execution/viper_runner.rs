@@ -0,0 +1,195 @@
+use anyhow::anyhow;
+use tracing::{debug, error, info, warn};
+
+use opendut_carl_api::carl::broker::{ExecuteViperRun, UpstreamMessage, UpstreamMessagePayload, ViperRunReport, ViperRunUpdate};
+use opendut_carl_api::carl::GrpcUpstream;
+use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperRunParameterValue, ViperRunProgress, ViperRunStatus, ViperSourceDescriptor};
+use opendut_viper_rt::compile::{IdentifierFilter, ParameterName};
+use opendut_viper_rt::events::{emitter, EventEmissionError, EventEmitter};
+use opendut_viper_rt::run::{BindingValue, ParameterBindings, Report, RunEvent, TestSuiteReport};
+use opendut_viper_rt::source::loaders::{GitSourceLoader, GitSourceLoaderOptions, HttpSourceLoader, SimpleFileSourceLoader};
+use opendut_viper_rt::source::Source;
+use opendut_viper_rt::ViperRuntime;
+
+/// Executes the given test suite run in the background and reports its progress and result to CARL.
+pub fn spawn_viper_run(execute: ExecuteViperRun, upstream: GrpcUpstream) {
+    let ExecuteViperRun { run, source } = execute;
+    let handle = tokio::runtime::Handle::current();
+
+    tokio::task::spawn_blocking(move || {
+        // The Python interpreter used by the VIPER runtime is not `Send`,
+        // so the run is driven to completion on this dedicated blocking thread.
+        handle.block_on(async move {
+            let run_id = run.id;
+            let mut reporter = ProgressReporter::new(run_id, upstream);
+
+            let status = match execute_viper_run(run, source, &mut reporter).await {
+                Ok(report) => {
+                    let status = if report.is_success() { ViperRunStatus::Passed } else { ViperRunStatus::Failed };
+                    reporter.send_report(report).await;
+                    status
+                }
+                Err(cause) => {
+                    error!("Failed to execute test suite run <{run_id}>:\n  {cause:#}");
+                    ViperRunStatus::Error { cause: format!("{cause:#}") }
+                }
+            };
+
+            info!("Finished test suite run <{run_id}> with status: {status:?}");
+            reporter.report(status).await;
+        });
+    });
+}
+
+async fn execute_viper_run(run: ViperRunDescriptor, source: ViperSourceDescriptor, reporter: &mut ProgressReporter) -> anyhow::Result<TestSuiteReport> {
+    let run_id = run.id;
+    reporter.report(ViperRunStatus::Compiling).await;
+
+    let runtime = ViperRuntime::builder()
+        .with_source_loader(SimpleFileSourceLoader)
+        .with_source_loader(HttpSourceLoader)
+        .with_source_loader(GitSourceLoader::new(GitSourceLoaderOptions {
+            cache_dir: crate::common::constants::viper::git_source_cache_directory(),
+        }))
+        .build()
+        .map_err(|cause| anyhow!("Failed to instantiate VIPER runtime: {}", cause.message))?;
+
+    let suite_source = Source::try_from_url(Clone::clone(&run.suite), Clone::clone(&source.url))
+        .map_err(|cause| anyhow!("Invalid location '{}' of test suite source <{}>: {cause}", source.url, source.id))?;
+
+    debug!("Compiling test suite '{}' of run <{run_id}> from '{}'.", run.suite, source.url);
+    let (_, descriptors, mut suite) = runtime.compile(&suite_source, &mut emitter::drain(), &IdentifierFilter::default()).await
+        .map_err(|cause| anyhow!("Failed to compile test suite '{}': {cause}", run.suite))?
+        .split();
+
+    if let Some(timeout) = run.timeout {
+        suite.override_timeout(timeout);
+    }
+
+    let mut bindings = ParameterBindings::from(descriptors);
+    for (key, value) in run.parameters {
+        let name = ParameterName::try_from(key.inner)
+            .map_err(|cause| anyhow!("Invalid parameter name: {cause}"))?;
+        let value = match value {
+            ViperRunParameterValue::Boolean(value) => BindingValue::BooleanValue(value),
+            ViperRunParameterValue::Number(value) => BindingValue::NumberValue(value),
+            ViperRunParameterValue::Text(value) => BindingValue::TextValue(value),
+        };
+        bindings.bind(&name, value)
+            .map_err(|cause| anyhow!("Failed to bind parameter '{}': {cause}", name.as_str()))?;
+    }
+    let bindings = bindings.complete()
+        .map_err(|cause| anyhow!("Incomplete parameters: {cause}"))?;
+
+    debug!("Running test suite '{}' of run <{run_id}>.", run.suite);
+    let report = runtime.run(suite, bindings, reporter).await
+        .map_err(|cause| anyhow!("Failed to run test suite '{}': {cause}", run.suite))?;
+
+    Ok(report)
+}
+
+/// Translates the events of a test suite run into [`ViperRunUpdate`]s sent to CARL and forwards the final [`ViperRunReport`].
+struct ProgressReporter {
+    run_id: ViperRunId,
+    upstream: GrpcUpstream,
+    progress: ViperRunProgress,
+}
+
+impl ProgressReporter {
+    fn new(run_id: ViperRunId, upstream: GrpcUpstream) -> Self {
+        Self { run_id, upstream, progress: ViperRunProgress::default() }
+    }
+
+    async fn report(&self, status: ViperRunStatus) {
+        let message = UpstreamMessage {
+            context: None,
+            payload: UpstreamMessagePayload::ViperRunUpdate(ViperRunUpdate { id: self.run_id, status }),
+        };
+        let _ignore_error = self.upstream.send(message).await
+            .inspect_err(|cause| warn!("Failed to report status of test suite run <{}> to CARL: {cause}", self.run_id));
+    }
+
+    async fn send_report(&self, report: TestSuiteReport) {
+        let message = UpstreamMessage {
+            context: None,
+            payload: UpstreamMessagePayload::ViperRunReport(Box::new(ViperRunReport { id: self.run_id, report })),
+        };
+        let _ignore_error = self.upstream.send(message).await
+            .inspect_err(|cause| warn!("Failed to send report of test suite run <{}> to CARL: {cause}", self.run_id));
+    }
+}
+
+#[async_trait::async_trait]
+impl EventEmitter<RunEvent> for ProgressReporter {
+    async fn emit(&mut self, event: RunEvent) -> Result<(), EventEmissionError> {
+        match event {
+            RunEvent::Initialized(suite) => {
+                self.progress.total_tests = suite.cases.iter()
+                    .map(|case| case.tests.len() as u32)
+                    .sum();
+            }
+            RunEvent::TestPassed(_) => self.progress.passed_tests += 1,
+            RunEvent::TestFailed(_) => self.progress.failed_tests += 1,
+            _ => return Ok(()),
+        }
+        self.report(ViperRunStatus::Running(self.progress)).await;
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use googletest::prelude::*;
+    use opendut_carl_api::proto::services::peer_messaging_broker::Upstream;
+    use opendut_viper_rt::run::{RunState, TestCaseRunState, TestRunState, TestSuiteRunState};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn should_report_progress_of_run_events() -> anyhow::Result<()> {
+        let (sender, mut receiver) = mpsc::channel::<Upstream>(10);
+        let run_id = ViperRunId::random();
+        let mut testee = ProgressReporter::new(run_id, GrpcUpstream::from(sender));
+
+        let events = vec![
+            RunEvent::Initialized(TestSuiteRunState {
+                identifier: "suite".try_into()?,
+                state: RunState::Initialized,
+                cases: vec![TestCaseRunState {
+                    identifier: "suite::case".try_into()?,
+                    state: RunState::Initialized,
+                    tests: vec![
+                        TestRunState { identifier: "suite::case::test_a".try_into()?, state: RunState::Initialized },
+                        TestRunState { identifier: "suite::case::test_b".try_into()?, state: RunState::Initialized },
+                    ],
+                }],
+            }),
+            RunEvent::TestSuiteStarted("suite".try_into()?),
+            RunEvent::TestPassed("suite::case::test_a".try_into()?),
+            RunEvent::TestFailed("suite::case::test_b".try_into()?),
+        ];
+        for event in events {
+            testee.emit(event).await
+                .map_err(|cause| anyhow!(cause.cause))?;
+        }
+
+        let mut statuses = vec![];
+        while let Ok(message) = receiver.try_recv() {
+            let message = UpstreamMessage::try_from(message)?;
+            let UpstreamMessagePayload::ViperRunUpdate(update) = message.payload else {
+                panic!("Expected a ViperRunUpdate, got: {:?}", message.payload);
+            };
+            assert_that!(update.id, eq(run_id));
+            statuses.push(update.status);
+        }
+
+        assert_that!(statuses, elements_are![
+            eq(&ViperRunStatus::Running(ViperRunProgress { total_tests: 2, passed_tests: 0, failed_tests: 0 })),
+            eq(&ViperRunStatus::Running(ViperRunProgress { total_tests: 2, passed_tests: 1, failed_tests: 0 })),
+            eq(&ViperRunStatus::Running(ViperRunProgress { total_tests: 2, passed_tests: 1, failed_tests: 1 })),
+        ]);
+
+        Ok(())
+    }
+}
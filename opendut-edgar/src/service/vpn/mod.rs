@@ -28,11 +28,9 @@ pub async fn retrieve_remote_host(settings: &LoadedConfig) -> anyhow::Result<IpA
         debug!("Netbird management state {:?}", status.management_state);
         debug!("Netbird signal state {:?}", status.signal_state);
 
-        let host = status.local_peer_state
+        status.local_peer_state
             .ok_or(anyhow!("NetBird Client did not return a local peer state. May not be logged in. Re-run `edgar setup` to fix this."))?
-            .local_ip()?;
-
-        IpAddr::from(host)
+            .local_ip()?
     } else {
         settings.config.get::<IpAddr>(settings::key::vpn::disabled::remote::host)
             .map_err(|cause| anyhow!("Configuration value '{field}' must be a valid IP address: {cause}", field=settings::key::vpn::disabled::remote::host))?
@@ -5,7 +5,7 @@ use opendut_model::peer::PeerSetup;
 use opendut_model::util::net::NetworkInterfaceName;
 use opendut_model::vpn::netbird::SetupKey;
 use std::collections::HashSet;
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 use std::ops::Not;
 use std::str::FromStr;
 use std::{env, fs};
@@ -178,8 +178,8 @@ impl FromStr for ParseableLeader {
         if string.to_lowercase() == local_string {
             Ok(ParseableLeader(setup::Leader::Local))
         } else {
-            let ip = Ipv4Addr::from_str(string)
-                .map_err(|cause| format!("Specify either '{local_string}' or a valid IPv4 address ({cause})."))?;
+            let ip = IpAddr::from_str(string)
+                .map_err(|cause| format!("Specify either '{local_string}' or a valid IP address ({cause})."))?;
             Ok(ParseableLeader(setup::Leader::Remote(ip)))
         }
     }
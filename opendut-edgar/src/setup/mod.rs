@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 
 pub use crate::common::task::runner::RunMode;
 
@@ -10,7 +10,7 @@ pub mod start;
 mod plugin;
 
 #[allow(non_camel_case_types)]
-mod tasks;
+pub(crate) mod tasks;
 
 mod util;
 pub mod write_configuration;
@@ -18,7 +18,7 @@ pub mod write_configuration;
 pub use util::user_confirmation_prompt;
 
 #[derive(Clone, Debug)]
-pub enum Leader { Local, Remote(Ipv4Addr) }
+pub enum Leader { Local, Remote(IpAddr) }
 
 #[derive(Clone, Debug)]
 struct User { pub name: String }
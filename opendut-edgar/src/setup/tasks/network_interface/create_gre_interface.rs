@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -57,7 +57,7 @@ impl Task for CreateGreInterfaces {
             let remote_ips = full_status.peers.into_iter()
                 .filter_map(|peer| {
                     let remote_ip = peer.ip;
-                    let address = Ipv4Addr::from_str(&remote_ip)
+                    let address = IpAddr::from_str(&remote_ip)
                         .context(format!("Failed to parse remote IP returned by NetBird '{remote_ip}'."));
                     match address {
                         Ok(address) => Some(address),
@@ -63,8 +63,10 @@ pub enum FontAwesomeIcon {
     Downloads,
     EllipsisVertical,
     Email,
+    Flask,
     OpenPage,
     Peers,
+    Play,
     Plus,
     Save,
     SignOut,
@@ -90,8 +92,10 @@ impl FontAwesomeIcon {
             FontAwesomeIcon::Downloads => "fa-solid fa-download",
             FontAwesomeIcon::EllipsisVertical => "fa-solid fa-ellipsis-vertical",
             FontAwesomeIcon::Email => "fa-regular fa-envelope",
+            FontAwesomeIcon::Flask => "fa-solid fa-flask",
             FontAwesomeIcon::OpenPage => "fa-solid fa-arrow-up-right-from-square",
             FontAwesomeIcon::Peers => "fa-solid fa-microchip",
+            FontAwesomeIcon::Play => "fa-solid fa-play",
             FontAwesomeIcon::Plus => "fa-solid fa-plus",
             FontAwesomeIcon::Save => "fa-solid fa-save",
             FontAwesomeIcon::SignOut => "fas fa-sign-out-alt",
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 use tracing::{debug, error};
+use opendut_carl_api::carl::ClientError;
+use opendut_carl_api::carl::cluster::CreateClusterDescriptorError;
 use opendut_lea_components::tooltip::{Tooltip, TooltipDirection};
 use opendut_model::cluster::ClusterDescriptor;
 
@@ -15,7 +17,7 @@ use crate::routing::{navigate_to, WellKnownRoutes};
 
 #[component]
 pub fn Controls(
-    cluster_descriptor: ReadSignal<UserClusterDescriptor>,
+    cluster_descriptor: RwSignal<UserClusterDescriptor>,
     deployed_signal: Signal<IsDeployed>
 ) -> impl IntoView {
 
@@ -48,7 +50,7 @@ pub fn Controls(
 
 #[component]
 fn SaveClusterButton(
-    cluster_descriptor: ReadSignal<UserClusterDescriptor>,
+    cluster_descriptor: RwSignal<UserClusterDescriptor>,
     deployed_signal: Signal<IsDeployed>
 ) -> impl IntoView {
 
@@ -85,6 +87,7 @@ fn SaveClusterButton(
 
             match configuration {
                 Ok(configuration) => {
+                    let revision = configuration.revision;
                     let result = carl.cluster.store_cluster_descriptor(configuration).await;
                     match result {
                         Ok(cluster_id) => {
@@ -93,6 +96,14 @@ fn SaveClusterButton(
                                 .simple("Successfully stored cluster descriptor.")
                                 .success()
                             );
+                            cluster_descriptor.update(|cluster_descriptor| cluster_descriptor.revision = revision.next());
+                        }
+                        Err(ClientError::UsageError(CreateClusterDescriptorError::Conflict { cluster_id, .. })) => {
+                            error!("Failed to store cluster <{}>, because it was changed in the meantime.", cluster_id);
+                            toaster.toast(Toast::builder()
+                                .simple("Failed to store cluster descriptor! It was changed in the meantime. Reload the page and apply your changes again.")
+                                .error()
+                            );
                         }
                         Err(cause) => {
                             error!("Failed to store cluster <{}>, due to error: {:?}", "id", cause);
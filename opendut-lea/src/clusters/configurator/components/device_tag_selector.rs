@@ -0,0 +1,140 @@
+use std::ops::Not;
+use leptos::prelude::*;
+use opendut_model::cluster::DeviceSelector;
+use opendut_model::topology::DeviceTag;
+
+use crate::clusters::configurator::types::UserClusterDescriptor;
+use crate::components::{ButtonColor, ButtonSize, ButtonState, FontAwesomeIcon, IconButton, UserInput, UserInputValue};
+
+#[component]
+pub fn DeviceTagSelectors(
+    cluster_descriptor: RwSignal<UserClusterDescriptor>,
+) -> impl IntoView {
+
+    let (getter, setter) = create_slice(
+        cluster_descriptor,
+        |config| Clone::clone(&config.device_selectors),
+        |config, input| {
+            config.device_selectors = input;
+        },
+    );
+
+    let empty_tags = || UserInputValue::Left(String::from("Enter one or more comma-separated tags."));
+    let (tags_getter, tags_setter) = RwSignal::new(empty_tags()).split();
+
+    let tags_validator = |input: String| {
+        match parse_device_selector(&input) {
+            Ok(_) => UserInputValue::Right(input),
+            Err(cause) => UserInputValue::Both(cause, input),
+        }
+    };
+
+    let button_state = Signal::derive(move || {
+        match tags_getter.get() {
+            UserInputValue::Right(_) => ButtonState::Enabled,
+            _ => ButtonState::Disabled,
+        }
+    });
+
+    let on_add = move || {
+        let UserInputValue::Right(input) = tags_getter.get_untracked() else { return };
+        let Ok(selector) = parse_device_selector(&input) else { return };
+
+        let mut selectors = getter.get_untracked();
+        if selectors.contains(&selector).not() {
+            selectors.push(selector);
+            setter.set(selectors);
+        }
+        tags_setter.set(empty_tags());
+    };
+
+    let rows = move || {
+        getter.get().into_iter()
+            .map(|selector| {
+                let label = selector.tags.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let on_remove = {
+                    let selector = Clone::clone(&selector);
+                    move || {
+                        let mut selectors = getter.get_untracked();
+                        selectors.retain(|other| other != &selector);
+                        setter.set(selectors);
+                    }
+                };
+
+                view! {
+                    <tr>
+                        <td>{label}</td>
+                        <td class="is-narrow">
+                            <IconButton
+                                icon=FontAwesomeIcon::TrashCan
+                                color=ButtonColor::Danger
+                                size=ButtonSize::Small
+                                state=ButtonState::Enabled
+                                label="Remove device selector"
+                                on_action=on_remove
+                            />
+                        </td>
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <h2 class="subtitle mt-5">Device Selectors</h2>
+        <p class="help">"Devices tagged with all tags of a selector are added to the cluster, when it is deployed."</p>
+        <div class="table-container mt-2">
+            <table class="table is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>Tags</th>
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { rows }
+                </tbody>
+            </table>
+        </div>
+        <div class="columns">
+            <div class="column">
+                <UserInput
+                    getter=tags_getter.into()
+                    setter=tags_setter.into()
+                    validator=tags_validator
+                    label="Tags"
+                    placeholder="powertrain, hil-rig-3"
+                />
+            </div>
+            <div class="column is-narrow pt-5 mt-3">
+                <IconButton
+                    icon=FontAwesomeIcon::Plus
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=button_state
+                    label="Add Selector"
+                    show_label=true
+                    on_action=on_add
+                />
+            </div>
+        </div>
+    }
+}
+
+fn parse_device_selector(input: &str) -> Result<DeviceSelector, String> {
+    let tags = input.split(',')
+        .map(str::trim)
+        .filter(|tag| tag.is_empty().not())
+        .map(|tag| DeviceTag::try_from(tag).map_err(|cause| cause.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if tags.is_empty() {
+        Err(String::from("Enter at least one tag."))
+    } else {
+        Ok(DeviceSelector { tags })
+    }
+}
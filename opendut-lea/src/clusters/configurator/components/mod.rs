@@ -1,14 +1,18 @@
 mod controls;
 mod device_selector;
+mod device_tag_selector;
 mod cluster_name_input;
 mod leader_selector;
+mod restbus_simulation;
 
 use std::collections::HashSet;
 use leptos::prelude::*;
 pub use controls::Controls;
 pub use device_selector::{DeviceSelector, DeviceSelection};
+pub use device_tag_selector::DeviceTagSelectors;
 pub use cluster_name_input::ClusterNameInput;
 pub use leader_selector::{LeaderSelector, LeaderSelection};
+pub use restbus_simulation::RestbusSimulations;
 use opendut_model::topology::DeviceId;
 
 
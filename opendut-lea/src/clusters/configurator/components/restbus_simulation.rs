@@ -0,0 +1,203 @@
+use std::ops::Not;
+use std::path::PathBuf;
+use leptos::prelude::*;
+use opendut_model::cluster::DeviceRestbusSimulation;
+use opendut_model::peer::PeerDescriptor;
+use opendut_model::topology::DeviceId;
+
+use crate::clusters::configurator::types::UserClusterDescriptor;
+use crate::components::{ButtonColor, ButtonSize, ButtonState, FontAwesomeIcon, IconButton, UserInput, UserInputValue};
+
+#[component]
+pub fn RestbusSimulations(
+    cluster_descriptor: RwSignal<UserClusterDescriptor>,
+    peers: ReadSignal<Vec<PeerDescriptor>>,
+) -> impl IntoView {
+
+    let (getter, setter) = create_slice(
+        cluster_descriptor,
+        |config| Clone::clone(&config.restbus_simulations),
+        |config, input| {
+            config.restbus_simulations = input;
+        },
+    );
+
+    let device_name = move |device: DeviceId| {
+        peers.with(|peers| {
+            peers.iter()
+                .flat_map(|peer| &peer.topology.devices)
+                .find(|descriptor| descriptor.id == device)
+                .map(|descriptor| descriptor.name.to_string())
+                .unwrap_or_else(|| device.to_string())
+        })
+    };
+
+    let device = RwSignal::new(None::<DeviceId>);
+
+    let empty_arxml_file = || UserInputValue::Left(String::from("Enter the path of the ARXML file on the peer."));
+    let (arxml_file_getter, arxml_file_setter) = RwSignal::new(empty_arxml_file()).split();
+    let arxml_file_validator = |input: String| {
+        if input.trim().is_empty() {
+            UserInputValue::Both(String::from("Enter the path of the ARXML file on the peer."), input)
+        } else {
+            UserInputValue::Right(input)
+        }
+    };
+
+    let empty_ecus = || UserInputValue::Left(String::from("Enter one or more comma-separated ECU names."));
+    let (ecus_getter, ecus_setter) = RwSignal::new(empty_ecus()).split();
+    let ecus_validator = |input: String| {
+        if parse_ecus(&input).is_empty() {
+            UserInputValue::Both(String::from("Enter at least one ECU name."), input)
+        } else {
+            UserInputValue::Right(input)
+        }
+    };
+
+    let button_state = Signal::derive(move || {
+        match (device.get(), arxml_file_getter.get(), ecus_getter.get()) {
+            (Some(_), UserInputValue::Right(_), UserInputValue::Right(_)) => ButtonState::Enabled,
+            _ => ButtonState::Disabled,
+        }
+    });
+
+    let on_add = move || {
+        let Some(device) = device.get_untracked() else { return };
+        let UserInputValue::Right(arxml_file) = arxml_file_getter.get_untracked() else { return };
+        let UserInputValue::Right(ecus) = ecus_getter.get_untracked() else { return };
+
+        let simulation = DeviceRestbusSimulation {
+            device,
+            arxml_file: PathBuf::from(arxml_file.trim()),
+            ecus: parse_ecus(&ecus),
+        };
+
+        let mut simulations = getter.get_untracked();
+        if simulations.contains(&simulation).not() {
+            simulations.push(simulation);
+            setter.set(simulations);
+        }
+        arxml_file_setter.set(empty_arxml_file());
+        ecus_setter.set(empty_ecus());
+    };
+
+    let device_options = move || {
+        peers.with(|peers| {
+            peers.iter()
+                .flat_map(|peer| &peer.topology.devices)
+                .map(|descriptor| view! {
+                    <option value=descriptor.id.to_string()>{ descriptor.name.to_string() }</option>
+                })
+                .collect_view()
+        })
+    };
+
+    let rows = move || {
+        getter.get().into_iter()
+            .map(|simulation| {
+                let on_remove = {
+                    let simulation = Clone::clone(&simulation);
+                    move || {
+                        let mut simulations = getter.get_untracked();
+                        simulations.retain(|other| other != &simulation);
+                        setter.set(simulations);
+                    }
+                };
+
+                view! {
+                    <tr>
+                        <td>{ device_name(simulation.device) }</td>
+                        <td>{ simulation.arxml_file.display().to_string() }</td>
+                        <td>{ simulation.ecus.join(", ") }</td>
+                        <td class="is-narrow">
+                            <IconButton
+                                icon=FontAwesomeIcon::TrashCan
+                                color=ButtonColor::Danger
+                                size=ButtonSize::Small
+                                state=ButtonState::Enabled
+                                label="Remove restbus simulation"
+                                on_action=on_remove
+                            />
+                        </td>
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <h2 class="subtitle mt-5">Restbus Simulations</h2>
+        <p class="help">"EDGAR transmits the CAN frames of the given ECUs on the interface of the device, while the cluster is deployed."</p>
+        <div class="table-container mt-2">
+            <table class="table is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>Device</th>
+                        <th>ARXML File</th>
+                        <th>ECUs</th>
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { rows }
+                </tbody>
+            </table>
+        </div>
+        <div class="columns">
+            <div class="column is-narrow">
+                <div class="field">
+                    <label class="label">Device</label>
+                    <div class="control">
+                        <div class="select"
+                            on:change=move |ev| {
+                                let device_id = DeviceId::try_from(event_target_value(&ev).as_str()).ok();
+                                device.set(device_id);
+                            }>
+                            <select aria-label="Device">
+                                <option>Select device</option>
+                                { device_options }
+                            </select>
+                        </div>
+                    </div>
+                </div>
+            </div>
+            <div class="column">
+                <UserInput
+                    getter=arxml_file_getter.into()
+                    setter=arxml_file_setter.into()
+                    validator=arxml_file_validator
+                    label="ARXML File"
+                    placeholder="/etc/opendut/restbus.arxml"
+                />
+            </div>
+            <div class="column">
+                <UserInput
+                    getter=ecus_getter.into()
+                    setter=ecus_setter.into()
+                    validator=ecus_validator
+                    label="ECUs"
+                    placeholder="Engine, Gateway"
+                />
+            </div>
+            <div class="column is-narrow pt-5 mt-3">
+                <IconButton
+                    icon=FontAwesomeIcon::Plus
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=button_state
+                    label="Add Simulation"
+                    show_label=true
+                    on_action=on_add
+                />
+            </div>
+        </div>
+    }
+}
+
+fn parse_ecus(input: &str) -> Vec<String> {
+    input.split(',')
+        .map(str::trim)
+        .filter(|ecu| ecu.is_empty().not())
+        .map(String::from)
+        .collect()
+}
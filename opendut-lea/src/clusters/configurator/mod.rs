@@ -1,8 +1,10 @@
 use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 use crate::components::UserInputValue;
-use opendut_model::cluster::ClusterId;
+use opendut_model::cluster::{ClusterId, ClusterTopology};
 use opendut_model::peer::PeerDescriptor;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::resources::Revision;
 
 use crate::app::use_app_globals;
 use crate::clusters::configurator::components::{DeviceSelection, DeviceSelector, LeaderSelection};
@@ -32,6 +34,12 @@ pub fn ClusterConfigurator() -> impl IntoView {
             name: UserInputValue::Left(String::from("Enter a valid cluster name.")),
             devices: DeviceSelection::Left(String::from("Select at least two devices.")),
             leader: LeaderSelection::Left(String::from("Select a leader.")),
+            device_selectors: Vec::new(),
+            topology: ClusterTopology::default(),
+            can_rules: Vec::new(),
+            restbus_simulations: Vec::new(),
+            namespace: NamespaceName::default(),
+            revision: Revision::INITIAL,
         }
     );
 
@@ -50,6 +58,12 @@ pub fn ClusterConfigurator() -> impl IntoView {
                             name: UserInputValue::Right(configuration.name.value().to_owned()),
                             devices: DeviceSelection::Right(configuration.devices),
                             leader: LeaderSelection::Right(configuration.leader),
+                            device_selectors: configuration.device_selectors,
+                            topology: configuration.topology,
+                            can_rules: configuration.can_rules,
+                            restbus_simulations: configuration.restbus_simulations,
+                            namespace: configuration.namespace,
+                            revision: configuration.revision,
                         }
                     )
                 } else {
@@ -136,7 +150,7 @@ fn LoadedClusterConfigurator(
             controls=move || {
                 view! {
                     <Controls
-                        cluster_descriptor=cluster_descriptor.read_only()
+                        cluster_descriptor
                         deployed_signal
                     />
                 }
@@ -2,6 +2,7 @@ use leptos::prelude::*;
 use opendut_model::peer::PeerDescriptor;
 
 use crate::clusters::configurator::DeviceSelector;
+use crate::clusters::configurator::components::{DeviceTagSelectors, RestbusSimulations};
 use crate::clusters::configurator::types::UserClusterDescriptor;
 
 #[component]
@@ -13,6 +14,8 @@ pub fn DevicesTab(
     view! {
         <div>
             <DeviceSelector cluster_descriptor peers />
+            <DeviceTagSelectors cluster_descriptor />
+            <RestbusSimulations cluster_descriptor peers />
         </div>
     }
 }
@@ -1,4 +1,6 @@
-use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterName};
+use opendut_model::cluster::{ClusterDescriptor, ClusterId, ClusterName, ClusterTopology, DeviceCanRules, DeviceRestbusSimulation, DeviceSelector};
+use opendut_model::namespace::NamespaceName;
+use opendut_model::resources::Revision;
 
 use crate::clusters::configurator::components::{DeviceSelection, LeaderSelection};
 use crate::components::UserInputValue;
@@ -22,6 +24,12 @@ pub struct UserClusterDescriptor {
     pub name: UserInputValue,
     pub devices: DeviceSelection,
     pub leader: LeaderSelection,
+    pub device_selectors: Vec<DeviceSelector>,
+    pub topology: ClusterTopology,
+    pub can_rules: Vec<DeviceCanRules>,
+    pub restbus_simulations: Vec<DeviceRestbusSimulation>,
+    pub namespace: NamespaceName,
+    pub revision: Revision,
 }
 
 impl UserClusterDescriptor {
@@ -49,6 +57,12 @@ impl TryFrom<UserClusterDescriptor> for ClusterDescriptor {
             name,
             leader,
             devices,
+            device_selectors: configuration.device_selectors,
+            topology: configuration.topology,
+            can_rules: configuration.can_rules,
+            restbus_simulations: configuration.restbus_simulations,
+            namespace: configuration.namespace,
+            revision: configuration.revision,
         })
     }
 }
@@ -71,11 +71,11 @@ pub fn ClustersOverview() -> impl IntoView {
 
                 leptos::task::spawn_local(async move {
                     match carl.cluster.store_cluster_deployment(ClusterDeployment { id: cluster_id }).await {
-                        Ok(cluster_id) => {
-                            debug!("Successfully stored cluster deployment: {}", cluster_id);
+                        Ok(stored_deployment) => {
+                            debug!("Successfully stored cluster deployment: {}", stored_deployment.cluster_id);
                             toaster.toast(
                                 Toast::builder()
-                                    .simple("Successfully stored cluster deployment!")
+                                    .simple(format!("Successfully stored cluster deployment with {} devices!", stored_deployment.devices.len()))
                                     .success()
                             );
                         }
@@ -23,6 +23,8 @@ mod downloads;
 mod util;
 mod components;
 mod nav;
+#[cfg(feature = "viper")]
+mod test_suites;
 
 fn main() {
 
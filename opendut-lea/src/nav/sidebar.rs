@@ -24,6 +24,7 @@ pub fn Sidebar(menu_visible: RwSignal<bool>, hide_buttons: Signal<bool>) -> impl
                     label="Peers"
                     route=routing::path::peers_overview
                 />
+                <TestSuitesSidebarItem />
                 <SidebarItem
                     icon= FontAwesomeIcon::Downloads
                     label="Downloads"
@@ -45,6 +46,22 @@ pub fn Sidebar(menu_visible: RwSignal<bool>, hide_buttons: Signal<bool>) -> impl
     }
 }
 
+#[cfg(feature = "viper")]
+#[component]
+fn TestSuitesSidebarItem() -> impl IntoView {
+    view! {
+        <SidebarItem
+            icon= FontAwesomeIcon::Flask
+            label="Test Suites"
+            route=routing::path::test_suites_overview
+        />
+    }
+}
+
+#[cfg(not(feature = "viper"))]
+#[component]
+fn TestSuitesSidebarItem() -> impl IntoView {}
+
 #[component]
 pub fn SidebarItem(
     #[prop(into)] icon: Signal<FontAwesomeIcon>,
@@ -5,6 +5,8 @@ use std::sync::Arc;
 use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 use tracing::{debug, error};
+use opendut_carl_api::carl::ClientError;
+use opendut_carl_api::carl::peer::StorePeerDescriptorError;
 use opendut_model::cluster::ClusterId;
 use opendut_model::peer::PeerDescriptor;
 
@@ -70,8 +72,9 @@ fn SavePeerButton(
 
     let setter = create_write_slice(
         configuration,
-        |config, input| {
-            config.is_new = input;
+        |config, stored_revision| {
+            config.is_new = false;
+            config.revision = stored_revision;
         },
     );
 
@@ -98,6 +101,7 @@ fn SavePeerButton(
             match peer_descriptor {
                 Ok(peer_descriptor) => {
                     let peer_id = peer_descriptor.id;
+                    let revision = peer_descriptor.revision;
                     let result = carl.peers.store_peer_descriptor(peer_descriptor).await;
                     match result {
                         Ok(_) => {
@@ -107,7 +111,11 @@ fn SavePeerButton(
                                     .simple("Successfully stored peer configuration.")
                                     .success(),
                             );
-                            setter.set(false);
+                            setter.set(revision.next());
+                        }
+                        Err(ClientError::UsageError(StorePeerDescriptorError::Conflict { .. })) => {
+                            error!("Failed to store peer <{peer_id}>, because it was changed in the meantime.");
+                            toaster.toast(Toast::builder().simple("Failed to store peer! It was changed in the meantime. Reload the page and apply your changes again.").error());
                         }
                         Err(cause) => {
                             error!("Failed to create peer <{peer_id}>, due to error: {cause:?}");
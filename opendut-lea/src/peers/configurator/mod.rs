@@ -10,6 +10,8 @@ use leptos::either::Either;
 use leptos::prelude::*;
 use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorKind};
 use opendut_model::peer::PeerId;
+use opendut_model::namespace::NamespaceName;
+use opendut_model::resources::Revision;
 use std::collections::HashMap;
 use leptos_router::hooks::{use_navigate, use_params_map};
 
@@ -55,8 +57,11 @@ pub fn PeerConfigurator() -> impl IntoView {
                 network_interfaces: Vec::new(),
                 bridge_name: UserInputValue::Right(String::from("")),
             },
+            namespace: NamespaceName::default(),
+            revision: Revision::INITIAL,
             is_new: true,
             executors: Vec::new(),
+            executables: Vec::new(),
         });
 
         let peer_configuration_resource = LocalResource::new(move || {
@@ -69,6 +74,8 @@ pub fn PeerConfigurator() -> impl IntoView {
                     peer_configuration.update(|user_configuration| {
                         user_configuration.name = UserInputValue::Right(configuration.name.value().to_owned());
                         user_configuration.is_new = false;
+                        user_configuration.namespace = configuration.namespace;
+                        user_configuration.revision = configuration.revision;
                         user_configuration.location = UserInputValue::Right(configuration.location.unwrap_or_default().value());
                         user_configuration.devices = configuration.topology.devices.into_iter().map(|device| {
                             let mut configured_clusters = vec![];
@@ -101,7 +108,10 @@ pub fn PeerConfigurator() -> impl IntoView {
                             let ExecutorDescriptor { id, kind, results_url } = executor;
 
                             let kind = match kind {
-                                ExecutorKind::Executable => todo!(),
+                                executable @ ExecutorKind::Executable { .. } => {
+                                    user_configuration.executables.push(ExecutorDescriptor { id, kind: executable, results_url });
+                                    continue;
+                                }
                                 ExecutorKind::Container {
                                     engine,
                                     name,
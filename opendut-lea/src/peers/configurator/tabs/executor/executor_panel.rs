@@ -1,4 +1,6 @@
 use leptos::{either::Either, prelude::*};
+use opendut_lea_components::health::{self, Health};
+use opendut_model::peer::executor::state::ExecutorStatus;
 use opendut_model::peer::executor::{container::{ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine, IllegalContainerImage}, ExecutorId, ResultsUrl};
 use strum::IntoEnumIterator;
 
@@ -9,6 +11,7 @@ use crate::components::NON_BREAKING_SPACE;
 #[component]
 pub fn ExecutorPanel<OnDeleteFn>(
     executor: RwSignal<UserPeerExecutor>,
+    status: Signal<Option<ExecutorStatus>>,
     on_delete: OnDeleteFn
 ) -> impl IntoView
 where
@@ -20,7 +23,7 @@ where
     
     view! {
         <div class="panel is-light">
-            <ExecutorPanelHeading executor on_delete/>
+            <ExecutorPanelHeading executor status on_delete/>
             <div class="panel-block" class=("is-hidden", is_collapsed)>
                 <div class="container">
                     <ExecutorEngineInput executor />
@@ -42,6 +45,7 @@ where
 #[component]
 fn ExecutorPanelHeading<OnDeleteFn>(
     executor: RwSignal<UserPeerExecutor>,
+    status: Signal<Option<ExecutorStatus>>,
     on_delete: OnDeleteFn
 ) -> impl IntoView
 where
@@ -82,6 +86,26 @@ where
        }
     );
 
+    let health_state = Signal::derive(move || {
+        match status.get() {
+            None => health::State {
+                kind: health::StateKind::Unknown,
+                text: String::from("No status reported by the peer."),
+            },
+            Some(status) => {
+                let kind = match status {
+                    ExecutorStatus::Pending | ExecutorStatus::Pulling => health::StateKind::Yellow,
+                    ExecutorStatus::Running | ExecutorStatus::ResultsUploaded | ExecutorStatus::Exited { code: Some(0) } => health::StateKind::Green,
+                    ExecutorStatus::Exited { .. } | ExecutorStatus::Failed { .. } => health::StateKind::Red,
+                };
+                health::State {
+                    kind,
+                    text: status.to_string(),
+                }
+            }
+        }
+    });
+
     view! {
         <div class="panel-heading px-2 py-3">
             <div class="is-flex is-justify-content-space-between is-align-items-center">
@@ -99,7 +123,10 @@ where
                     <span class="is-size-5 has-text-weight-bold pr-3">"Container:"</span>
                     <span class="is-size-5 has-text-weight-bold">{ executor_heading }</span>
                 </div>
-                <div>
+                <div class="is-flex is-align-items-center">
+                    <span class="pr-3">
+                        <Health state=health_state />
+                    </span>
                     <ConfirmationButton
                         icon=FontAwesomeIcon::TrashCan
                         color=ButtonColor::Light
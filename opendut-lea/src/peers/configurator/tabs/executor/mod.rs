@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use leptos::prelude::*;
 use opendut_model::peer::executor::container::Engine;
-use opendut_model::peer::executor::ExecutorId;
+use opendut_model::peer::executor::{ExecutorId, ExecutorKind};
+use crate::app::use_app_globals;
 use crate::components::UserInputValue;
 use crate::peers::configurator::tabs::executor::executor_panel::ExecutorPanel;
 use crate::peers::configurator::types::{EMPTY_CONTAINER_IMAGE_ERROR_MESSAGE, UserPeerConfiguration, UserPeerExecutor, UserPeerExecutorKind};
@@ -12,6 +15,7 @@ pub fn ExecutorTab(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl
     view! {
         <div>
             <ExecutorTable peer_configuration />
+            <ExecutableTable peer_configuration />
         </div>
     }
 }
@@ -19,6 +23,24 @@ pub fn ExecutorTab(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl
 #[component]
 fn ExecutorTable(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl IntoView {
 
+    let globals = use_app_globals();
+
+    let executor_states = LocalResource::new(move || {
+        let mut carl = globals.client.clone();
+        let (peer_id, is_new) = peer_configuration.with(|peer_configuration| (peer_configuration.id, peer_configuration.is_new));
+        async move {
+            if is_new {
+                return HashMap::new();
+            }
+            carl.peers.list_executor_states(peer_id).await
+                .map(|executor_states| executor_states.into_iter()
+                    .map(|state| (state.id, state.status))
+                    .collect::<HashMap<_, _>>()
+                )
+                .unwrap_or_default()
+        }
+    });
+
     let (executors, executors_setter) = create_slice(peer_configuration,
         |peer_configuration| {
             Clone::clone(&peer_configuration.executors)
@@ -46,8 +68,13 @@ fn ExecutorTable(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl In
             executors.iter()
                 .cloned()
                 .map(|executor| {
+                    let executor_id = executor.with_untracked(|executor| executor.id);
+                    let status = Signal::derive(move || {
+                        executor_states.get()
+                            .and_then(|executor_states| executor_states.get(&executor_id).cloned())
+                    });
                     view! {
-                        <ExecutorPanel executor on_delete=on_executor_delete />
+                        <ExecutorPanel executor status on_delete=on_executor_delete />
                     }
                 })
                 .collect::<Vec<_>>()
@@ -92,3 +119,53 @@ fn ExecutorTable(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl In
         </div>
     }
 }
+
+/// Lists the executors of kind executable. LEA cannot edit these yet, so they are only shown.
+#[component]
+fn ExecutableTable(peer_configuration: RwSignal<UserPeerConfiguration>) -> impl IntoView {
+
+    let executables = Signal::derive(move || {
+        peer_configuration.with(|peer_configuration| Clone::clone(&peer_configuration.executables))
+    });
+
+    let rows = move || {
+        executables.get().into_iter()
+            .filter_map(|executor| {
+                let ExecutorKind::Executable { path, args, .. } = executor.kind else { return None };
+                let args = args.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                Some(view! {
+                    <tr>
+                        <td>{executor.id.to_string()}</td>
+                        <td>{path.to_string()}</td>
+                        <td>{args}</td>
+                    </tr>
+                })
+            })
+            .collect_view()
+    };
+
+    view! {
+        <Show when=move || executables.with(|executables| !executables.is_empty())>
+            <h2 class="subtitle mt-5">Executables</h2>
+            <p class="help">"Executors of kind executable can only be configured via CLEO."</p>
+            <div class="table-container mt-2">
+                <table class="table is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th>Id</th>
+                            <th>Path</th>
+                            <th>Arguments</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { rows }
+                    </tbody>
+                </table>
+            </div>
+        </Show>
+    }
+}
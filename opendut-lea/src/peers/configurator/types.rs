@@ -3,6 +3,8 @@ use opendut_model::cluster::ClusterDescriptor;
 use opendut_model::peer::executor::{ExecutorDescriptor, ExecutorId};
 use opendut_model::peer::{PeerDescriptor, PeerId, PeerLocation, PeerName, PeerNetworkDescriptor};
 use opendut_model::peer::executor::{container::{ContainerCommand, ContainerCommandArgument, ContainerDevice, ContainerEnvironmentVariable, ContainerImage, ContainerName, ContainerPortSpec, ContainerVolume, Engine}, ExecutorKind, ExecutorDescriptors, ResultsUrl};
+use opendut_model::namespace::NamespaceName;
+use opendut_model::resources::Revision;
 use opendut_model::topology::{DeviceDescription, DeviceDescriptor, DeviceId, DeviceName, Topology};
 use opendut_model::util::net::{NetworkInterfaceDescriptor, NetworkInterfaceId, NetworkInterfaceName};
 
@@ -46,6 +48,10 @@ pub struct UserPeerConfiguration {
     pub devices: Vec<RwSignal<UserDeviceConfiguration>>,
     pub network: UserPeerNetwork,
     pub executors: Vec<RwSignal<UserPeerExecutor>>,
+    /// Executors of kind executable. These cannot be edited in LEA yet and are stored unchanged.
+    pub executables: Vec<ExecutorDescriptor>,
+    pub namespace: NamespaceName,
+    pub revision: Revision,
     pub is_new: bool,
 }
 
@@ -149,7 +155,7 @@ impl TryFrom<UserPeerConfiguration> for PeerDescriptor {
                     .map_err(PeerMisconfigurationError::InvalidDevice)
             })
             .collect::<Result<Vec<_>, _>>()?;
-        let executors = configuration
+        let mut executors = configuration
             .executors
             .into_iter()
             .map(|signal| signal.get_untracked())
@@ -158,6 +164,7 @@ impl TryFrom<UserPeerConfiguration> for PeerDescriptor {
                     .map_err(|_|  PeerMisconfigurationError::InvalidPeerExecutor)
             })
             .collect::<Result<Vec<_>, _>>()?;
+        executors.extend(configuration.executables);
         Ok(PeerDescriptor {
             id: configuration.id,
             name,
@@ -167,6 +174,8 @@ impl TryFrom<UserPeerConfiguration> for PeerDescriptor {
             executors: ExecutorDescriptors {
                 executors
             },
+            namespace: configuration.namespace,
+            revision: configuration.revision,
         })
     }
 }
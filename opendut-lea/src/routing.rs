@@ -5,6 +5,8 @@ use ::url::Url;
 
 use opendut_model::cluster::ClusterId;
 use opendut_model::peer::PeerId;
+#[cfg(feature = "viper")]
+use opendut_model::viper::ViperRunId;
 
 use crate::components::BasePageContainer;
 pub use routes::AppRoutes;
@@ -22,6 +24,10 @@ pub mod path {
     pub const licenses: &str = "/licenses";
     pub const peers_overview: &str = "/peers";
     pub const user: &str = "/user";
+    #[cfg(feature = "viper")]
+    pub const test_suites_overview: &str = "/test-suites";
+    #[cfg(feature = "viper")]
+    pub const test_suites_run_configurator: &str = "/test-suites/runs/new";
 }
 
 #[derive(Clone)]
@@ -31,6 +37,12 @@ pub enum WellKnownRoutes {
     PeersOverview,
     PeerConfigurator { id: PeerId },
     Downloads,
+    #[cfg(feature = "viper")]
+    TestSuitesOverview,
+    #[cfg(feature = "viper")]
+    RunConfigurator,
+    #[cfg(feature = "viper")]
+    RunOverview { id: ViperRunId },
     ErrorPage { title: String, text: String, details: Option<String> },
 }
 
@@ -58,6 +70,21 @@ impl WellKnownRoutes {
                 base.join(path::downloads)
                     .expect("Downloads route should be valid.")
             },
+            #[cfg(feature = "viper")]
+            WellKnownRoutes::TestSuitesOverview => {
+                base.join(path::test_suites_overview)
+                    .expect("TestSuitesOverview route should be valid.")
+            },
+            #[cfg(feature = "viper")]
+            WellKnownRoutes::RunConfigurator => {
+                base.join(path::test_suites_run_configurator)
+                    .expect("RunConfigurator route should be valid.")
+            },
+            #[cfg(feature = "viper")]
+            WellKnownRoutes::RunOverview { id } => {
+                base.join(&format!("/test-suites/runs/{}", id.url_encode()))
+                    .expect("RunOverview route should be valid.")
+            },
             WellKnownRoutes::ErrorPage { title, text, details } => {
                 let mut url = base.join(path::error).unwrap();
                 {
@@ -77,7 +104,7 @@ impl WellKnownRoutes {
 mod routes {
     use leptos::prelude::*;
     use leptos_router::components::{Route, ProtectedRoute, Routes};
-    use leptos_router::{path};
+    use leptos_router::{path, MatchNestedRoutes};
 
     use crate::clusters::{ClusterConfigurator, ClustersOverview};
     use crate::dashboard::Dashboard;
@@ -141,6 +168,7 @@ mod routes {
                     fallback=LoadingSpinner
                     redirect_path=|| "/login"
                 />
+                <TestSuiteRoutes app_globals opendut_user />
                 <ProtectedRoute
                     path=path!("/user")
                     view=move || view! { <Initialized app_globals><UserOverview/></Initialized> }
@@ -174,6 +202,50 @@ mod routes {
             </Routes>
         }
     }
+
+    #[cfg(feature = "viper")]
+    #[component(transparent)]
+    fn TestSuiteRoutes<C>(app_globals: AppGlobalsResource, opendut_user: C) -> impl MatchNestedRoutes + Clone
+    where C: Fn() -> bool + Clone + Send + Sync + 'static {
+        use leptos_router::components::ParentRoute;
+        use leptos_router::nested_router::Outlet;
+        use crate::test_suites::{RunConfigurator, RunOverview, TestSuitesOverview};
+
+        view! {
+            <ParentRoute path=path!("/test-suites") view=Outlet>
+                <ProtectedRoute
+                    path=path!("")
+                    view=move || view! { <Initialized app_globals><TestSuitesOverview/></Initialized> }
+                    condition=opendut_user.clone()
+                    fallback=LoadingSpinner
+                    redirect_path=|| "/login"
+                />
+                <ProtectedRoute
+                    path=path!("/runs/new")
+                    view=move || view! { <Initialized app_globals><RunConfigurator/></Initialized> }
+                    condition=opendut_user.clone()
+                    fallback=LoadingSpinner
+                    redirect_path=|| "/login"
+                />
+                <ProtectedRoute
+                    path=path!("/runs/:id")
+                    view=move || view! { <Initialized app_globals><RunOverview/></Initialized> }
+                    condition=opendut_user
+                    fallback=LoadingSpinner
+                    redirect_path=|| "/login"
+                />
+            </ParentRoute>
+        }
+        .into_inner()
+    }
+
+    /// Without the `viper` feature, no routes for test suites are registered.
+    #[cfg(not(feature = "viper"))]
+    #[component(transparent)]
+    fn TestSuiteRoutes<C>(app_globals: AppGlobalsResource, opendut_user: C) -> impl MatchNestedRoutes + Clone
+    where C: Fn() -> bool + Clone + Send + Sync + 'static {
+        let _ = (app_globals, opendut_user);
+    }
 }
 
 /// When using inside a closure in a view!-macro, you will need to call this function like this:
@@ -265,4 +337,11 @@ mod url_encode {
             PeerId::try_from(encoded)
         }
     }
+
+    #[cfg(feature = "viper")]
+    impl UrlEncodable for opendut_model::viper::ViperRunId {
+        fn url_encode(&self) -> String {
+            self.to_string()
+        }
+    }
 }
@@ -0,0 +1,304 @@
+mod parameter_input;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use tracing::{debug, error};
+
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperRunDescriptor, ViperRunId, ViperSourceDescriptor, ViperSourceId};
+use opendut_viper_rt::common::TestSuiteIdentifier;
+
+use crate::app::use_app_globals;
+use crate::components::{use_toaster, BasePageContainer, Breadcrumb, ButtonColor, ButtonSize, ButtonState, FontAwesomeIcon, IconButton, LoadingSpinner, Toast, UserInput, UserInputValue, WarningMessage};
+use crate::routing::{navigate_to, WellKnownRoutes};
+use crate::test_suites::configurator::parameter_input::{ParameterInput, UserParameter};
+
+#[component(transparent)]
+pub fn RunConfigurator() -> impl IntoView {
+
+    let globals = use_app_globals();
+
+    let sources = LocalResource::new(move || {
+        let mut carl = globals.client.clone();
+        async move {
+            let mut sources = carl.test_suite.list_viper_source_descriptors().await
+                .expect("Failed to request the list of test suite sources");
+
+            sources.sort_by(|source_a, source_b|
+                source_a.name.value().to_lowercase()
+                    .cmp(&source_b.name.value().to_lowercase())
+            );
+
+            sources
+        }
+    });
+
+    view! {
+        <Transition
+            fallback=LoadingSpinner
+        >
+        {move || Suspend::new(async move {
+            let sources = sources.await;
+
+            view! {
+                <LoadedRunConfigurator sources />
+            }
+        })}
+        </Transition>
+    }
+}
+
+#[component]
+fn LoadedRunConfigurator(sources: Vec<ViperSourceDescriptor>) -> impl IntoView {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+    let navigate = use_navigate();
+
+    let source = RwSignal::new(sources.first().map(|source| source.id));
+    let (suite_getter, suite_setter) = RwSignal::new(UserInputValue::Left(String::from("Enter the identifier of a test suite."))).split();
+    let (timeout_getter, timeout_setter) = RwSignal::new(UserInputValue::Right(String::new())).split();
+
+    // `None` as long as the parameters of the selected test suite have not been loaded.
+    let parameters = RwSignal::new(None::<Vec<UserParameter>>);
+    let inspection_error = RwSignal::new(None::<String>);
+    let loading = RwSignal::new(false);
+    let pending = RwSignal::new(false);
+
+    Effect::new(move || {
+        source.track();
+        suite_getter.track();
+        parameters.set(None);
+        inspection_error.set(None);
+    });
+
+    let suite_validator = |input: String| {
+        match TestSuiteIdentifier::try_from(input.as_str()) {
+            Ok(_) => UserInputValue::Right(input),
+            Err(cause) => UserInputValue::Both(cause.to_string(), input),
+        }
+    };
+
+    let timeout_validator = |input: String| {
+        if input.is_empty() {
+            return UserInputValue::Right(input);
+        }
+        match input.parse::<u64>() {
+            Ok(seconds) if seconds > 0 => UserInputValue::Right(input),
+            _ => UserInputValue::Both(String::from("Enter the timeout as a positive number of seconds or leave it empty."), input),
+        }
+    };
+
+    let load_button_state = Signal::derive(move || {
+        if loading.get() {
+            ButtonState::Loading
+        } else if source.get().is_some() && suite_getter.with(UserInputValue::is_right) {
+            ButtonState::Enabled
+        } else {
+            ButtonState::Disabled
+        }
+    });
+
+    let on_load = {
+        let carl = globals.client.clone();
+        move || {
+            let Some(source_id) = source.get_untracked() else { return };
+            let Some(suite) = suite_getter.get_untracked().right()
+                .and_then(|suite| TestSuiteIdentifier::try_from(suite).ok())
+            else { return };
+
+            let mut carl = carl.clone();
+
+            leptos::task::spawn_local(async move {
+                loading.set(true);
+
+                match carl.test_suite.inspect_viper_test_suite(source_id, suite).await {
+                    Ok(descriptors) => {
+                        debug!("Loaded {} parameter(s) of test suite.", descriptors.len());
+                        parameters.set(Some(descriptors.into_iter().map(UserParameter::new).collect()));
+                    }
+                    Err(cause) => {
+                        error!("Failed to inspect test suite: {cause}");
+                        inspection_error.set(Some(cause.to_string()));
+                    }
+                }
+
+                loading.set(false);
+            });
+        }
+    };
+
+    let save_button_state = Signal::derive(move || {
+        if pending.get() {
+            return ButtonState::Loading;
+        }
+        let parameters_valid = parameters.with(|parameters| match parameters {
+            Some(parameters) => parameters.iter().all(|parameter| parameter.value.with(UserInputValue::is_right)),
+            None => false,
+        });
+        if parameters_valid && timeout_getter.with(UserInputValue::is_right) {
+            ButtonState::Enabled
+        } else {
+            ButtonState::Disabled
+        }
+    });
+
+    let on_save = move || {
+        let source = source.get_untracked();
+        let suite = suite_getter.get_untracked().right()
+            .and_then(|suite| TestSuiteIdentifier::try_from(suite).ok());
+        let parameters = parameters.get_untracked()
+            .and_then(|parameters| parameters.iter()
+                .map(UserParameter::to_run_parameter)
+                .collect::<Option<HashMap<_, _>>>()
+            );
+        let timeout = timeout_getter.get_untracked().right()
+            .map(|timeout| timeout.parse::<u64>().ok().map(Duration::from_secs));
+
+        let (Some(source), Some(suite), Some(parameters), Some(timeout)) = (source, suite, parameters, timeout) else {
+            error!("Failed to store test suite run, due to invalid input!");
+            return;
+        };
+
+        let descriptor = ViperRunDescriptor {
+            id: ViperRunId::random(),
+            source,
+            suite,
+            parameters,
+            timeout,
+            namespace: NamespaceName::default(),
+        };
+
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+        let navigate = navigate.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.store_viper_run_descriptor(descriptor).await {
+                Ok(run_id) => {
+                    debug!("Successfully stored test suite run <{run_id}>.");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Successfully stored test suite run.")
+                            .success()
+                    );
+                    navigate_to(WellKnownRoutes::RunOverview { id: run_id }, navigate);
+                }
+                Err(cause) => {
+                    error!("Failed to store test suite run: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to store test suite run!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+        });
+    };
+
+    let breadcrumbs = vec![
+        Breadcrumb::new("Dashboard", "/"),
+        Breadcrumb::new("Test Suites", "/test-suites"),
+        Breadcrumb::new("New Run", "/test-suites/runs/new"),
+    ];
+
+    let has_sources = !sources.is_empty();
+
+    let source_options = sources.into_iter()
+        .map(|source| view! {
+            <option value=source.id.to_string()>{ source.name.to_string() }</option>
+        })
+        .collect_view();
+
+    view! {
+        <BasePageContainer
+            title="Create Run"
+            breadcrumbs=breadcrumbs
+            controls=view! {
+                <IconButton
+                    icon=FontAwesomeIcon::Save
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=save_button_state
+                    label="Save Run"
+                    on_action=on_save
+                />
+            }
+        >
+            <Show when=move || !has_sources>
+                <WarningMessage>"No test suite sources are registered yet. Register a source on the test suites page first."</WarningMessage>
+            </Show>
+
+            <div class="field">
+                <label class="label">Source</label>
+                <div class="control">
+                    <div class="select"
+                        on:change=move |ev| {
+                            let source_id = ViperSourceId::try_from(event_target_value(&ev).as_str()).ok();
+                            source.set(source_id);
+                        }>
+                        <select>
+                            { source_options }
+                        </select>
+                    </div>
+                </div>
+            </div>
+
+            <UserInput
+                getter=suite_getter.into()
+                setter=suite_setter.into()
+                validator=suite_validator
+                label="Test Suite"
+                placeholder="regression_suite"
+            />
+
+            <UserInput
+                getter=timeout_getter.into()
+                setter=timeout_setter.into()
+                validator=timeout_validator
+                label="Timeout per Test (seconds, optional)"
+                placeholder="300"
+            />
+
+            <IconButton
+                icon=FontAwesomeIcon::ArrowsRotate
+                color=ButtonColor::Info
+                size=ButtonSize::Normal
+                state=load_button_state
+                label="Load Parameters"
+                show_label=true
+                on_action=on_load
+            />
+
+            {move || inspection_error.get().map(|cause| view! {
+                <div class="notification is-danger mt-4">
+                    <p>"The parameters of the test suite could not be loaded:"</p>
+                    <pre class="mt-2">{ cause }</pre>
+                </div>
+            })}
+
+            {move || parameters.get().map(|parameters| {
+                let has_parameters = !parameters.is_empty();
+
+                view! {
+                    <h5 class="title is-5 mt-5">Parameters</h5>
+                    <Show when=move || !has_parameters>
+                        <p>"The test suite does not declare any parameters."</p>
+                    </Show>
+                    <For
+                        each=move || parameters.clone()
+                        key=|parameter| parameter.descriptor.name().to_string()
+                        children=move |parameter| view! { <ParameterInput parameter /> }
+                    />
+                }
+            })}
+        </BasePageContainer>
+    }
+}
@@ -0,0 +1,162 @@
+use leptos::either::EitherOf3;
+use leptos::prelude::*;
+
+use opendut_model::viper::{ViperRunParameterKey, ViperRunParameterValue};
+use opendut_viper_rt::compile::ParameterDescriptor;
+
+use crate::components::{UserInputValue, NON_BREAKING_SPACE};
+
+/// A parameter of a test suite together with the value entered by the user.
+///
+/// Values are kept as text, as entered into the input field, and only converted when storing the run.
+#[derive(Clone)]
+pub struct UserParameter {
+    pub descriptor: ParameterDescriptor,
+    pub value: RwSignal<UserInputValue>,
+}
+
+impl UserParameter {
+    pub fn new(descriptor: ParameterDescriptor) -> Self {
+        let value = match &descriptor {
+            ParameterDescriptor::BooleanParameter { default, .. } => {
+                UserInputValue::Right(default.unwrap_or_default().to_string())
+            }
+            ParameterDescriptor::NumberParameter { default, .. } => match default {
+                Some(default) => UserInputValue::Right(default.to_string()),
+                None => UserInputValue::Left(String::from("Enter a number.")),
+            }
+            ParameterDescriptor::TextParameter { default, .. } => {
+                UserInputValue::Right(default.clone().unwrap_or_default())
+            }
+        };
+
+        Self {
+            descriptor,
+            value: RwSignal::new(value),
+        }
+    }
+
+    pub fn validate(&self, input: String) -> UserInputValue {
+        match parse_value(&self.descriptor, &input) {
+            Ok(_) => UserInputValue::Right(input),
+            Err(cause) => UserInputValue::Both(cause, input),
+        }
+    }
+
+    pub fn to_run_parameter(&self) -> Option<(ViperRunParameterKey, ViperRunParameterValue)> {
+        let input = self.value.get_untracked().right()?;
+        let value = parse_value(&self.descriptor, &input).ok()?;
+        let key = ViperRunParameterKey { inner: self.descriptor.name().to_string() };
+        Some((key, value))
+    }
+}
+
+fn parse_value(descriptor: &ParameterDescriptor, input: &str) -> Result<ViperRunParameterValue, String> {
+    match descriptor {
+        ParameterDescriptor::BooleanParameter { .. } => {
+            input.parse::<bool>()
+                .map(ViperRunParameterValue::Boolean)
+                .map_err(|_| String::from("Select either true or false."))
+        }
+        ParameterDescriptor::NumberParameter { min, max, .. } => {
+            let number = input.parse::<i64>()
+                .map_err(|_| String::from("Enter a whole number."))?;
+            if number < *min || number > *max {
+                Err(format!("Enter a number between {min} and {max}."))
+            } else {
+                Ok(ViperRunParameterValue::Number(number))
+            }
+        }
+        ParameterDescriptor::TextParameter { max, .. } => {
+            if input.chars().count() > *max as usize {
+                Err(format!("Enter at most {max} characters."))
+            } else {
+                Ok(ViperRunParameterValue::Text(input.to_owned()))
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ParameterInput(parameter: UserParameter) -> impl IntoView {
+
+    let (name, info) = match &parameter.descriptor {
+        ParameterDescriptor::BooleanParameter { name, info, .. }
+        | ParameterDescriptor::NumberParameter { name, info, .. }
+        | ParameterDescriptor::TextParameter { name, info, .. } => (name.to_string(), info.clone()),
+    };
+    let label = info.display_name.unwrap_or_else(|| Clone::clone(&name));
+    let description = info.description.unwrap_or_default();
+
+    let value = parameter.value;
+
+    let value_text = move || {
+        value.with(|input| match input {
+            UserInputValue::Left(_) => String::new(),
+            UserInputValue::Right(value) => value.to_owned(),
+            UserInputValue::Both(_, value) => value.to_owned(),
+        })
+    };
+
+    let help_text = move || {
+        value.with(|input| match input {
+            UserInputValue::Right(_) => String::from(NON_BREAKING_SPACE),
+            UserInputValue::Left(error) => error.to_owned(),
+            UserInputValue::Both(error, _) => error.to_owned(),
+        })
+    };
+
+    let on_input = {
+        let parameter = Clone::clone(&parameter);
+        move |ev| value.set(parameter.validate(event_target_value(&ev)))
+    };
+
+    let input = match parameter.descriptor {
+        ParameterDescriptor::BooleanParameter { .. } => EitherOf3::A(view! {
+            <label class="checkbox">
+                <input
+                    type="checkbox"
+                    aria-label=name
+                    prop:checked=move || value_text() == "true"
+                    on:change=move |ev| value.set(UserInputValue::Right(event_target_checked(&ev).to_string()))
+                />
+                { format!(" {label}") }
+            </label>
+        }),
+        ParameterDescriptor::NumberParameter { min, max, .. } => EitherOf3::B(view! {
+            <label class="label">{ label }</label>
+            <div class="control">
+                <input
+                    class="input"
+                    type="number"
+                    aria-label=name
+                    min=min
+                    max=max
+                    prop:value=value_text
+                    on:input=on_input
+                />
+            </div>
+        }),
+        ParameterDescriptor::TextParameter { max, .. } => EitherOf3::C(view! {
+            <label class="label">{ label }</label>
+            <div class="control">
+                <input
+                    class="input"
+                    type="text"
+                    aria-label=name
+                    maxlength=max
+                    prop:value=value_text
+                    on:input=on_input
+                />
+            </div>
+        }),
+    };
+
+    view! {
+        <div class="field">
+            { input }
+            <p class="help">{ description }</p>
+            <p class="help has-text-danger">{ help_text }</p>
+        </div>
+    }
+}
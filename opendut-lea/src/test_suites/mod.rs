@@ -0,0 +1,48 @@
+use opendut_model::viper::{ViperRunDeployment, ViperRunResult};
+use opendut_viper_rt::run::Report;
+
+pub use configurator::RunConfigurator;
+pub use overview::TestSuitesOverview;
+pub use run::RunOverview;
+
+mod configurator;
+mod overview;
+mod run;
+
+/// Status of a run, as far as it can be derived from its deployment and its result.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RunStatus {
+    NotStarted,
+    Deployed,
+    Passed,
+    Failed,
+}
+
+impl RunStatus {
+    fn new(deployment: Option<&ViperRunDeployment>, result: Option<&ViperRunResult>) -> Self {
+        match (deployment, result) {
+            (_, Some(result)) if result.report.is_success() => RunStatus::Passed,
+            (_, Some(_)) => RunStatus::Failed,
+            (Some(_), None) => RunStatus::Deployed,
+            (None, None) => RunStatus::NotStarted,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            RunStatus::NotStarted => "Not started",
+            RunStatus::Deployed => "Deployed",
+            RunStatus::Passed => "Passed",
+            RunStatus::Failed => "Failed",
+        }
+    }
+
+    fn as_tag_class(&self) -> &'static str {
+        match self {
+            RunStatus::NotStarted => "tag",
+            RunStatus::Deployed => "tag is-info",
+            RunStatus::Passed => "tag is-success",
+            RunStatus::Failed => "tag is-danger",
+        }
+    }
+}
@@ -0,0 +1,49 @@
+mod runs;
+mod sources;
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use crate::components::{BasePageContainer, Breadcrumb, ButtonColor, ButtonSize, ButtonState, FontAwesomeIcon, IconButton};
+use crate::routing::{navigate_to, WellKnownRoutes};
+use crate::test_suites::overview::runs::RunsTable;
+use crate::test_suites::overview::sources::{RegisterSourceForm, SourcesTable};
+
+#[component]
+pub fn TestSuitesOverview() -> impl IntoView {
+
+    let refetch_sources = RwSignal::new(());
+
+    let navigate = use_navigate();
+
+    let breadcrumbs = vec![
+        Breadcrumb::new("Dashboard", "/"),
+        Breadcrumb::new("Test Suites", "/test-suites"),
+    ];
+
+    view! {
+        <BasePageContainer
+            title="Test Suites"
+            breadcrumbs=breadcrumbs
+            controls=view! {
+                <IconButton
+                    icon=FontAwesomeIcon::Plus
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=ButtonState::Enabled
+                    label="Create Run"
+                    on_action=move || {
+                        navigate_to(WellKnownRoutes::RunConfigurator, navigate.clone());
+                    }
+                />
+            }
+        >
+            <h5 class="title is-5">Runs</h5>
+            <RunsTable />
+
+            <h5 class="title is-5 mt-6">Sources</h5>
+            <SourcesTable refetch_sources />
+            <RegisterSourceForm on_register=move || refetch_sources.notify() />
+        </BasePageContainer>
+    }
+}
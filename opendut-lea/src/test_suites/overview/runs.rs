@@ -0,0 +1,107 @@
+use leptos::prelude::*;
+
+use opendut_model::viper::ViperRunId;
+
+use crate::app::use_app_globals;
+use crate::components::LoadingSpinner;
+use crate::test_suites::RunStatus;
+
+#[derive(Clone)]
+struct RunRow {
+    id: ViperRunId,
+    suite: String,
+    source: String,
+    status: RunStatus,
+}
+
+#[component]
+pub fn RunsTable() -> impl IntoView {
+
+    let globals = use_app_globals();
+
+    let runs = LocalResource::new(move || {
+        let mut carl = globals.client.clone();
+        async move {
+            let descriptors = carl.test_suite.list_viper_run_descriptors().await
+                .expect("Failed to request the list of test suite runs");
+            let sources = carl.test_suite.list_viper_source_descriptors().await
+                .expect("Failed to request the list of test suite sources");
+            let deployments = carl.test_suite.list_viper_run_deployments().await
+                .expect("Failed to request the list of test suite run deployments");
+            let results = carl.test_suite.list_viper_run_results().await
+                .expect("Failed to request the list of test suite run results");
+
+            let mut runs = descriptors.into_iter()
+                .map(|descriptor| {
+                    let source = sources.iter()
+                        .find(|source| source.id == descriptor.source)
+                        .map(|source| source.name.to_string())
+                        .unwrap_or_else(|| descriptor.source.to_string());
+
+                    let status = RunStatus::new(
+                        deployments.iter().find(|deployment| deployment.id == descriptor.id),
+                        results.iter().find(|result| result.id == descriptor.id),
+                    );
+
+                    RunRow {
+                        id: descriptor.id,
+                        suite: descriptor.suite.to_string(),
+                        source,
+                        status,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            runs.sort_by(|run_a, run_b|
+                run_a.suite.to_lowercase()
+                    .cmp(&run_b.suite.to_lowercase())
+            );
+
+            runs
+        }
+    });
+
+    view! {
+        <table class="table is-hoverable is-fullwidth">
+            <thead>
+                <tr>
+                    <th>"Test Suite"</th>
+                    <th>"Source"</th>
+                    <th>"Run ID"</th>
+                    <th class="is-narrow has-text-centered">"Status"</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense
+                    fallback=LoadingSpinner
+                >
+                {move || Suspend::new(async move {
+                    let runs = runs.await;
+
+                    view! {
+                        <For
+                            each = move || runs.clone()
+                            key = |run| run.id
+                            children = move |run: RunRow| {
+                                let run_href = format!("/test-suites/runs/{}", run.id);
+                                view! {
+                                    <tr>
+                                        <td class="is-vcentered">
+                                            <a href=run_href>{ run.suite }</a>
+                                        </td>
+                                        <td class="is-vcentered">{ run.source }</td>
+                                        <td class="is-vcentered">{ run.id.to_string() }</td>
+                                        <td class="is-vcentered has-text-centered">
+                                            <span class=run.status.as_tag_class()>{ run.status.as_str() }</span>
+                                        </td>
+                                    </tr>
+                                }
+                            }
+                        />
+                    }
+                })}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
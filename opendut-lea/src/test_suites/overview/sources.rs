@@ -0,0 +1,260 @@
+use leptos::prelude::*;
+use tracing::{debug, error};
+use url::Url;
+
+use opendut_model::namespace::NamespaceName;
+use opendut_model::viper::{ViperSourceDescriptor, ViperSourceId, ViperSourceName};
+
+use crate::app::use_app_globals;
+use crate::components::{use_toaster, ButtonColor, ButtonSize, ButtonState, ConfirmationButton, FontAwesomeIcon, IconButton, LoadingSpinner, Toast, UserInput, UserInputValue};
+
+#[component]
+pub fn SourcesTable(refetch_sources: RwSignal<()>) -> impl IntoView {
+
+    let globals = use_app_globals();
+
+    let sources = LocalResource::new(move || {
+        refetch_sources.track();
+        let mut carl = globals.client.clone();
+        async move {
+            let mut sources = carl.test_suite.list_viper_source_descriptors().await
+                .expect("Failed to request the list of test suite sources");
+
+            sources.sort_by(|source_a, source_b|
+                source_a.name.value().to_lowercase()
+                    .cmp(&source_b.name.value().to_lowercase())
+            );
+
+            sources
+        }
+    });
+
+    view! {
+        <table class="table is-hoverable is-fullwidth">
+            <thead>
+                <tr>
+                    <th>"Name"</th>
+                    <th>"URL"</th>
+                    <th class="is-narrow has-text-centered">"Action"</th>
+                </tr>
+            </thead>
+            <tbody>
+                <Suspense
+                    fallback=LoadingSpinner
+                >
+                {move || Suspend::new(async move {
+                    let sources = sources.await;
+
+                    view! {
+                        <For
+                            each = move || sources.clone()
+                            key = |source| source.id
+                            children = move |source: ViperSourceDescriptor| {
+                                view! {
+                                    <tr>
+                                        <td class="is-vcentered">{ source.name.to_string() }</td>
+                                        <td class="is-vcentered">{ source.url.to_string() }</td>
+                                        <td class="is-vcentered is-flex is-justify-content-center">
+                                            <DeleteSourceButton
+                                                source_id=source.id
+                                                on_delete=move || refetch_sources.notify()
+                                            />
+                                        </td>
+                                    </tr>
+                                }
+                            }
+                        />
+                    }
+                })}
+                </Suspense>
+            </tbody>
+        </table>
+    }
+}
+
+#[component]
+fn DeleteSourceButton<F>(
+    source_id: ViperSourceId,
+    on_delete: F,
+) -> impl IntoView
+where F: Fn() + Clone + Send + 'static {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+    let pending = RwSignal::new(false);
+
+    let button_state = Signal::derive(move || {
+        if pending.get() {
+            ButtonState::Loading
+        } else {
+            ButtonState::Enabled
+        }
+    });
+
+    let on_confirm = move || {
+        let on_delete = on_delete.clone();
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.delete_viper_source_descriptor(source_id).await {
+                Ok(_) => {
+                    debug!("Successfully deleted test suite source <{source_id}>.");
+                    on_delete();
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Deleted test suite source successfully.")
+                            .success()
+                    );
+                }
+                Err(cause) => {
+                    error!("Failed to delete test suite source <{source_id}>: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to delete test suite source!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+        });
+    };
+
+    view! {
+        <ConfirmationButton
+            icon=FontAwesomeIcon::TrashCan
+            color=ButtonColor::TextDanger
+            size=ButtonSize::Normal
+            state=button_state
+            label="Remove Source?"
+            on_confirm
+        />
+    }
+}
+
+#[component]
+pub fn RegisterSourceForm<F>(on_register: F) -> impl IntoView
+where F: Fn() + Clone + 'static {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+
+    let empty_name = || UserInputValue::Left(String::from("Enter a valid source name."));
+    let empty_url = || UserInputValue::Left(String::from("Enter the URL of a test suite source."));
+
+    let (name_getter, name_setter) = RwSignal::new(empty_name()).split();
+    let (url_getter, url_setter) = RwSignal::new(empty_url()).split();
+    let pending = RwSignal::new(false);
+
+    let name_validator = |input: String| {
+        match ViperSourceName::try_from(input.clone()) {
+            Ok(_) => UserInputValue::Right(input),
+            Err(cause) => UserInputValue::Both(cause.to_string(), input),
+        }
+    };
+
+    let url_validator = |input: String| {
+        match Url::parse(&input) {
+            Ok(_) => UserInputValue::Right(input),
+            Err(cause) => UserInputValue::Both(format!("The URL is invalid: {cause}"), input),
+        }
+    };
+
+    let button_state = Signal::derive(move || {
+        if pending.get() {
+            ButtonState::Loading
+        } else if name_getter.with(UserInputValue::is_right) && url_getter.with(UserInputValue::is_right) {
+            ButtonState::Enabled
+        } else {
+            ButtonState::Disabled
+        }
+    });
+
+    let on_action = move || {
+        let name = name_getter.get_untracked().right()
+            .and_then(|name| ViperSourceName::try_from(name).ok());
+        let url = url_getter.get_untracked().right()
+            .and_then(|url| Url::parse(&url).ok());
+
+        let (Some(name), Some(url)) = (name, url) else {
+            error!("Failed to register test suite source, due to invalid input!");
+            return;
+        };
+
+        let descriptor = ViperSourceDescriptor {
+            id: ViperSourceId::random(),
+            name,
+            url,
+            namespace: NamespaceName::default(),
+        };
+
+        let on_register = on_register.clone();
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.store_viper_source_descriptor(descriptor).await {
+                Ok(source_id) => {
+                    debug!("Successfully registered test suite source <{source_id}>.");
+                    name_setter.set(empty_name());
+                    url_setter.set(empty_url());
+                    on_register();
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Registered test suite source successfully.")
+                            .success()
+                    );
+                }
+                Err(cause) => {
+                    error!("Failed to register test suite source: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to register test suite source!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+        });
+    };
+
+    view! {
+        <div class="columns">
+            <div class="column is-one-third">
+                <UserInput
+                    getter=name_getter.into()
+                    setter=name_setter.into()
+                    validator=name_validator
+                    label="Source Name"
+                    placeholder="regression-tests"
+                />
+            </div>
+            <div class="column">
+                <UserInput
+                    getter=url_getter.into()
+                    setter=url_setter.into()
+                    validator=url_validator
+                    label="Source URL"
+                    placeholder="git+https://example.com/tests.git"
+                />
+            </div>
+            <div class="column is-narrow pt-5 mt-3">
+                <IconButton
+                    icon=FontAwesomeIcon::Plus
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=button_state
+                    label="Register Source"
+                    show_label=true
+                    on_action
+                />
+            </div>
+        </div>
+    }
+}
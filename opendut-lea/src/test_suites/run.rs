@@ -0,0 +1,486 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_params_map};
+use tracing::{debug, error};
+
+use opendut_model::cluster::{ClusterDescriptor, ClusterId};
+use opendut_model::viper::{ViperRunDeployment, ViperRunDescriptor, ViperRunId, ViperRunParameterValue, ViperRunResult, ViperSourceDescriptor};
+use opendut_viper_rt::run::{Outcome, Report};
+
+use crate::app::use_app_globals;
+use crate::components::{use_toaster, BasePageContainer, Breadcrumb, ButtonColor, ButtonSize, ButtonState, ConfirmationButton, FontAwesomeIcon, IconButton, LoadingSpinner, Toast};
+use crate::routing::{navigate_to, WellKnownRoutes};
+use crate::test_suites::RunStatus;
+
+#[derive(Clone)]
+struct LoadedRun {
+    descriptor: ViperRunDescriptor,
+    source: Option<ViperSourceDescriptor>,
+    deployment: Option<ViperRunDeployment>,
+    result: Option<ViperRunResult>,
+}
+
+#[component(transparent)]
+pub fn RunOverview() -> impl IntoView {
+
+    let globals = use_app_globals();
+    let params = use_params_map();
+
+    let run_id = Memo::new(move |_| params.with(|params| {
+        params.get("id")
+            .and_then(|id| ViperRunId::try_from(id.as_str()).ok())
+    }));
+
+    let refetch_run = RwSignal::new(());
+
+    let run = {
+        let carl = globals.client.clone();
+
+        LocalResource::new(move || {
+            refetch_run.track();
+            let run_id = run_id.get();
+            let mut carl = carl.clone();
+
+            async move {
+                let run_id = run_id?;
+                let descriptor = carl.test_suite.get_viper_run_descriptor(run_id).await.ok()?;
+                let source = carl.test_suite.get_viper_source_descriptor(descriptor.source).await.ok();
+                let deployment = carl.test_suite.get_viper_run_deployment(run_id).await.ok();
+                let result = carl.test_suite.get_viper_run_result(run_id).await.ok();
+
+                Some(LoadedRun { descriptor, source, deployment, result })
+            }
+        })
+    };
+
+    let clusters = LocalResource::new(move || {
+        let mut carl = globals.client.clone();
+        async move {
+            let mut clusters = carl.cluster.list_cluster_descriptors().await
+                .expect("Failed to request the list of clusters");
+
+            clusters.sort_by(|cluster_a, cluster_b|
+                cluster_a.name.value().to_lowercase()
+                    .cmp(&cluster_b.name.value().to_lowercase())
+            );
+
+            clusters
+        }
+    });
+
+    view! {
+        <Transition
+            fallback=LoadingSpinner
+        >
+        {move || Suspend::new(async move {
+            let run = run.await;
+            let clusters = clusters.await;
+
+            match run {
+                Some(run) => Either::Left(view! {
+                    <LoadedRunOverview run clusters refetch_run />
+                }),
+                None => Either::Right(view! {
+                    <BasePageContainer
+                        title="Run Not Found"
+                        breadcrumbs=vec![
+                            Breadcrumb::new("Dashboard", "/"),
+                            Breadcrumb::new("Test Suites", "/test-suites"),
+                        ]
+                        controls=|| ()
+                    >
+                        <p class="subtitle">"The test suite run you are looking for does not exist."</p>
+                    </BasePageContainer>
+                }),
+            }
+        })}
+        </Transition>
+    }
+}
+
+#[component]
+fn LoadedRunOverview(
+    run: LoadedRun,
+    clusters: Vec<ClusterDescriptor>,
+    refetch_run: RwSignal<()>,
+) -> impl IntoView {
+
+    let LoadedRun { descriptor, source, deployment, result } = run;
+    let run_id = descriptor.id;
+
+    let status = RunStatus::new(deployment.as_ref(), result.as_ref());
+
+    let breadcrumbs = vec![
+        Breadcrumb::new("Dashboard", "/"),
+        Breadcrumb::new("Test Suites", "/test-suites"),
+        Breadcrumb::new(run_id.to_string(), format!("/test-suites/runs/{run_id}")),
+    ];
+
+    let source_text = match &source {
+        Some(source) => format!("{} ({})", source.name, source.url),
+        None => descriptor.source.to_string(),
+    };
+
+    let timeout_text = descriptor.timeout
+        .map(|timeout| format!("{} seconds", timeout.as_secs()))
+        .unwrap_or_else(|| String::from("As declared by the test suite"));
+
+    let mut parameters = descriptor.parameters.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                ViperRunParameterValue::Boolean(value) => value.to_string(),
+                ViperRunParameterValue::Number(value) => value.to_string(),
+                ViperRunParameterValue::Text(value) => value.to_owned(),
+            };
+            (key.inner.clone(), value)
+        })
+        .collect::<Vec<_>>();
+    parameters.sort();
+
+    let deployed_cluster = deployment.as_ref().map(|deployment| {
+        clusters.iter()
+            .find(|cluster| cluster.id == deployment.cluster)
+            .map(|cluster| cluster.name.to_string())
+            .unwrap_or_else(|| deployment.cluster.to_string())
+    });
+
+    view! {
+        <BasePageContainer
+            title="Test Suite Run"
+            subtitle=descriptor.suite.to_string()
+            breadcrumbs=breadcrumbs
+            controls=view! {
+                <DeleteRunButton run_id is_deployed=deployment.is_some() />
+            }
+        >
+            <table class="table is-fullwidth">
+                <tbody>
+                    <tr>
+                        <th class="is-narrow">"Test Suite"</th>
+                        <td>{ descriptor.suite.to_string() }</td>
+                    </tr>
+                    <tr>
+                        <th class="is-narrow">"Source"</th>
+                        <td>{ source_text }</td>
+                    </tr>
+                    <tr>
+                        <th class="is-narrow">"Timeout per Test"</th>
+                        <td>{ timeout_text }</td>
+                    </tr>
+                    <tr>
+                        <th class="is-narrow">"Status"</th>
+                        <td><span class=status.as_tag_class()>{ status.as_str() }</span></td>
+                    </tr>
+                </tbody>
+            </table>
+
+            <h5 class="title is-5 mt-5">Parameters</h5>
+            {
+                if parameters.is_empty() {
+                    Either::Left(view! { <p>"The run does not set any parameters."</p> })
+                } else {
+                    Either::Right(view! {
+                        <table class="table is-fullwidth">
+                            <thead>
+                                <tr>
+                                    <th>"Name"</th>
+                                    <th>"Value"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {
+                                    parameters.into_iter()
+                                        .map(|(name, value)| view! {
+                                            <tr>
+                                                <td>{ name }</td>
+                                                <td>{ value }</td>
+                                            </tr>
+                                        })
+                                        .collect_view()
+                                }
+                            </tbody>
+                        </table>
+                    })
+                }
+            }
+
+            <h5 class="title is-5 mt-5">Deployment</h5>
+            {
+                match deployed_cluster {
+                    Some(cluster_name) => Either::Left(view! {
+                        <div class="is-flex is-align-items-center">
+                            <p class="mr-3">{ format!("The run is deployed on cluster '{cluster_name}'.") }</p>
+                            <UndeployRunButton run_id refetch_run />
+                        </div>
+                    }),
+                    None => Either::Right(view! {
+                        <StartRunForm run_id clusters refetch_run />
+                    }),
+                }
+            }
+
+            <h5 class="title is-5 mt-5">Result</h5>
+            {
+                match result {
+                    Some(result) => Either::Left(view! { <RunResultTable result /> }),
+                    None => Either::Right(view! { <p>"No result has been reported for this run yet."</p> }),
+                }
+            }
+        </BasePageContainer>
+    }
+}
+
+#[component]
+fn StartRunForm(
+    run_id: ViperRunId,
+    clusters: Vec<ClusterDescriptor>,
+    refetch_run: RwSignal<()>,
+) -> impl IntoView {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+
+    let cluster = RwSignal::new(clusters.first().map(|cluster| cluster.id));
+    let pending = RwSignal::new(false);
+
+    let button_state = Signal::derive(move || {
+        if pending.get() {
+            ButtonState::Loading
+        } else if cluster.get().is_some() {
+            ButtonState::Enabled
+        } else {
+            ButtonState::Disabled
+        }
+    });
+
+    let on_action = move || {
+        let Some(cluster) = cluster.get_untracked() else { return };
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.store_viper_run_deployment(ViperRunDeployment { id: run_id, cluster, peer: None }).await {
+                Ok(_) => {
+                    debug!("Successfully started test suite run <{run_id}> on cluster <{cluster}>.");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Successfully started test suite run.")
+                            .success()
+                    );
+                }
+                Err(cause) => {
+                    error!("Failed to start test suite run <{run_id}> on cluster <{cluster}>: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to start test suite run!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+            refetch_run.notify();
+        });
+    };
+
+    let cluster_options = clusters.into_iter()
+        .map(|cluster| view! {
+            <option value=cluster.id.to_string()>{ cluster.name.to_string() }</option>
+        })
+        .collect_view();
+
+    view! {
+        <div class="field has-addons">
+            <div class="control">
+                <div class="select"
+                    on:change=move |ev| {
+                        let cluster_id = ClusterId::try_from(event_target_value(&ev).as_str()).ok();
+                        cluster.set(cluster_id);
+                    }>
+                    <select aria-label="Cluster">
+                        { cluster_options }
+                    </select>
+                </div>
+            </div>
+            <div class="control">
+                <IconButton
+                    icon=FontAwesomeIcon::Play
+                    color=ButtonColor::Success
+                    size=ButtonSize::Normal
+                    state=button_state
+                    label="Start Run"
+                    show_label=true
+                    on_action
+                />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn UndeployRunButton(
+    run_id: ViperRunId,
+    refetch_run: RwSignal<()>,
+) -> impl IntoView {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+    let pending = RwSignal::new(false);
+
+    let button_state = Signal::derive(move || {
+        if pending.get() {
+            ButtonState::Loading
+        } else {
+            ButtonState::Enabled
+        }
+    });
+
+    let on_confirm = move || {
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.delete_viper_run_deployment(run_id).await {
+                Ok(_) => {
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Successfully deleted deployment of test suite run.")
+                            .success()
+                    );
+                }
+                Err(cause) => {
+                    error!("Failed to delete deployment of test suite run <{run_id}>: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to delete deployment of test suite run!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+            refetch_run.notify();
+        });
+    };
+
+    view! {
+        <ConfirmationButton
+            icon=FontAwesomeIcon::XMark
+            color=ButtonColor::Danger
+            size=ButtonSize::Normal
+            state=button_state
+            label="Remove Deployment?"
+            on_confirm
+        />
+    }
+}
+
+#[component]
+fn DeleteRunButton(
+    run_id: ViperRunId,
+    is_deployed: bool,
+) -> impl IntoView {
+
+    let globals = use_app_globals();
+    let toaster = use_toaster();
+    let navigate = use_navigate();
+    let pending = RwSignal::new(false);
+
+    let button_state = Signal::derive(move || {
+        if is_deployed {
+            ButtonState::Disabled
+        } else if pending.get() {
+            ButtonState::Loading
+        } else {
+            ButtonState::Enabled
+        }
+    });
+
+    let on_confirm = move || {
+        let mut carl = globals.client.clone();
+        let toaster = toaster.clone();
+        let navigate = navigate.clone();
+
+        leptos::task::spawn_local(async move {
+            pending.set(true);
+
+            match carl.test_suite.delete_viper_run_descriptor(run_id).await {
+                Ok(_) => {
+                    debug!("Successfully deleted test suite run <{run_id}>.");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Deleted test suite run successfully.")
+                            .success()
+                    );
+                    navigate_to(WellKnownRoutes::TestSuitesOverview, navigate);
+                }
+                Err(cause) => {
+                    error!("Failed to delete test suite run <{run_id}>: {cause}");
+                    toaster.toast(
+                        Toast::builder()
+                            .simple("Failed to delete test suite run!")
+                            .error()
+                    );
+                }
+            }
+
+            pending.set(false);
+        });
+    };
+
+    view! {
+        <ConfirmationButton
+            icon=FontAwesomeIcon::TrashCan
+            color=ButtonColor::Danger
+            size=ButtonSize::Normal
+            state=button_state
+            label="Remove Run?"
+            on_confirm
+        />
+    }
+}
+
+#[component]
+fn RunResultTable(result: ViperRunResult) -> impl IntoView {
+
+    let outcome_tag = |outcome: Outcome| {
+        let class = match outcome {
+            Outcome::Success => "tag is-success",
+            Outcome::Failure => "tag is-danger",
+            Outcome::Timeout => "tag is-warning",
+        };
+        view! { <span class=class>{ outcome.to_string() }</span> }
+    };
+
+    let rows = result.report.cases.iter()
+        .flat_map(|case| case.tests.iter())
+        .map(|test| view! {
+            <tr>
+                <td>{ test.identifier.to_string() }</td>
+                <td class="has-text-centered">{ outcome_tag(test.outcome()) }</td>
+            </tr>
+        })
+        .collect_view();
+
+    view! {
+        <p class="mb-3">
+            { format!("Reported by peer <{}>: ", result.peer) }
+            { outcome_tag(result.report.outcome()) }
+        </p>
+        <table class="table is-hoverable is-fullwidth">
+            <thead>
+                <tr>
+                    <th>"Test"</th>
+                    <th class="is-narrow has-text-centered">"Outcome"</th>
+                </tr>
+            </thead>
+            <tbody>
+                { rows }
+            </tbody>
+        </table>
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::cluster::ClusterId;
+use crate::create_id_type;
+use crate::peer::PeerId;
+
+create_id_type!(AuditEventId);
+
+impl AuditEventId {
+    /// Creates a time-ordered ID (UUIDv7), so that the IDs of later events sort after the IDs of earlier events.
+    pub fn now() -> Self {
+        Self::from(Uuid::now_v7())
+    }
+}
+
+/// Record of a mutating operation, which was requested via the API of CARL.
+///
+/// Audit events are only ever appended and never modified or removed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub id: AuditEventId,
+    pub timestamp: SystemTime,
+    /// Name of the user, who requested the operation, or `None` if authentication is disabled.
+    pub user: Option<String>,
+    /// Name of the requested gRPC method, e.g. `StoreClusterDeployment`.
+    pub operation: String,
+    /// Resources affected by the operation.
+    pub resource_ids: Vec<AuditResourceId>,
+    pub outcome: AuditOutcome,
+}
+
+/// Identifies a resource affected by an [`AuditEvent`].
+///
+/// VIPER resources are identified by their plain UUID, as the VIPER types are only available with the `viper` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditResourceId {
+    Peer(PeerId),
+    Cluster(ClusterId),
+    ViperSource(Uuid),
+    ViperRun(Uuid),
+}
+
+impl fmt::Display for AuditResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditResourceId::Peer(id) => write!(f, "peer <{id}>"),
+            AuditResourceId::Cluster(id) => write!(f, "cluster <{id}>"),
+            AuditResourceId::ViperSource(id) => write!(f, "viper-source <{id}>"),
+            AuditResourceId::ViperRun(id) => write!(f, "viper-run <{id}>"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditOutcome {
+    Success,
+    Failure { message: String },
+}
+
+impl fmt::Display for AuditOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditOutcome::Success => write!(f, "Success"),
+            AuditOutcome::Failure { message } => write!(f, "Failure: {message}"),
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::fmt::Formatter;
 use std::ops::Not;
+use std::path::PathBuf;
 
 use crate::create_id_type;
+use crate::namespace::NamespaceName;
 use crate::peer::PeerId;
-use crate::topology::DeviceId;
+use crate::topology::{DeviceDescriptor, DeviceId, DeviceTag};
+use crate::resources::Revision;
+use crate::util::can::CanFrameRules;
 
 pub mod state;
 
@@ -100,6 +104,225 @@ pub struct ClusterDescriptor {
     pub name: ClusterName,
     pub leader: PeerId,
     pub devices: HashSet<DeviceId>,
+    /// Additional devices, which are resolved by their tags when the cluster is deployed.
+    /// A device is part of the cluster, if it matches any of these selectors.
+    pub device_selectors: Vec<DeviceSelector>,
+    pub topology: ClusterTopology,
+    /// Rules for the CAN frames, which are forwarded from a device to the rest of the cluster.
+    pub can_rules: Vec<DeviceCanRules>,
+    /// Restbus simulations, which are run on the CAN interfaces of devices, while the cluster is deployed.
+    #[serde(default)]
+    pub restbus_simulations: Vec<DeviceRestbusSimulation>,
+    /// Namespace the cluster belongs to, e.g. a project or team.
+    #[serde(default)]
+    pub namespace: NamespaceName,
+    /// Revision of the stored descriptor. Must match the stored revision when updating the descriptor.
+    #[serde(default)]
+    pub revision: Revision,
+}
+
+impl ClusterDescriptor {
+    /// Determines the devices of this cluster, i.e. the explicitly listed devices
+    /// and the devices out of `available_devices` which match any of the device selectors.
+    pub fn resolve_devices<'a>(&self, available_devices: impl IntoIterator<Item=&'a DeviceDescriptor>) -> HashSet<DeviceId> {
+        let selected_devices = available_devices.into_iter()
+            .filter(|device| self.device_selectors.iter().any(|selector| selector.matches(device)))
+            .map(|device| device.id);
+
+        self.devices.iter().copied()
+            .chain(selected_devices)
+            .collect()
+    }
+
+    /// Returns the CAN rules configured for the given device. Without rules, all frames are forwarded.
+    pub fn can_rules_for(&self, device: DeviceId) -> CanFrameRules {
+        self.can_rules.iter()
+            .find(|device_rules| device_rules.device == device)
+            .map(|device_rules| device_rules.rules.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Filter and rewrite rules for the CAN frames of a device.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCanRules {
+    pub device: DeviceId,
+    pub rules: CanFrameRules,
+}
+
+impl fmt::Display for DeviceCanRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.device, self.rules)
+    }
+}
+
+/// Simulates the given ECUs on the CAN interface of a device, according to an ARXML file on the peer of the device.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DeviceRestbusSimulation {
+    pub device: DeviceId,
+    pub arxml_file: PathBuf,
+    pub ecus: Vec<String>,
+}
+
+impl fmt::Display for DeviceRestbusSimulation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.device, self.arxml_file.display(), self.ecus.join(", "))
+    }
+}
+
+/// Selects all devices which are tagged with every one of the given tags.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DeviceSelector {
+    pub tags: Vec<DeviceTag>,
+}
+
+impl DeviceSelector {
+    pub fn matches(&self, device: &DeviceDescriptor) -> bool {
+        self.tags.is_empty().not()
+            && self.tags.iter().all(|tag| device.tags.contains(tag))
+    }
+}
+
+impl fmt::Display for DeviceSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let tags = self.tags.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        write!(f, "tags: {tags}")
+    }
+}
+
+/// Determines between which peers of a cluster the network connections are established.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ClusterTopology {
+    /// Every peer connects to the leader, which forwards the traffic between the other peers.
+    #[default]
+    Star,
+    /// Every peer connects directly to every other peer.
+    FullMesh,
+    /// Only the explicitly linked peers connect to each other.
+    /// Every peer has to be reachable from the leader via these links.
+    Custom { links: Vec<ClusterLink> },
+}
+
+impl ClusterTopology {
+    /// Determines the direct links between the members of a cluster.
+    /// For CAN connections, the first peer of a link acts as the server.
+    pub fn links(&self, leader: PeerId, members: &HashSet<PeerId>) -> Result<Vec<ClusterLink>, IllegalClusterTopology> {
+        let mut members = members.iter().copied().collect::<Vec<_>>();
+        members.sort_by_key(|peer_id| peer_id.uuid);
+
+        match self {
+            ClusterTopology::Star => {
+                Ok(members.into_iter()
+                    .filter(|peer_id| *peer_id != leader)
+                    .map(|peer_id| ClusterLink { peer_a: leader, peer_b: peer_id })
+                    .collect())
+            }
+            ClusterTopology::FullMesh => {
+                Ok(members.iter().enumerate()
+                    .flat_map(|(index, peer_a)| {
+                        members[index + 1..].iter()
+                            .map(|peer_b| ClusterLink { peer_a: *peer_a, peer_b: *peer_b })
+                    })
+                    .collect())
+            }
+            ClusterTopology::Custom { links } => {
+                let mut distinct_links: Vec<ClusterLink> = Vec::new();
+                for link in links {
+                    if link.peer_a == link.peer_b {
+                        return Err(IllegalClusterTopology::SelfLink { peer_id: link.peer_a });
+                    }
+                    for peer_id in [link.peer_a, link.peer_b] {
+                        if members.contains(&peer_id).not() {
+                            return Err(IllegalClusterTopology::PeerNotInCluster { peer_id });
+                        }
+                    }
+                    if distinct_links.iter().any(|known| known.connects(link.peer_a) && known.connects(link.peer_b)).not() {
+                        distinct_links.push(*link);
+                    }
+                }
+
+                let mut neighbours = HashMap::<PeerId, Vec<PeerId>>::new();
+                for link in &distinct_links {
+                    neighbours.entry(link.peer_a).or_default().push(link.peer_b);
+                    neighbours.entry(link.peer_b).or_default().push(link.peer_a);
+                }
+
+                let mut reachable = HashSet::from([leader]);
+                let mut queue = VecDeque::from([leader]);
+                while let Some(peer_id) = queue.pop_front() {
+                    for neighbour in neighbours.get(&peer_id).into_iter().flatten() {
+                        if reachable.insert(*neighbour) {
+                            queue.push_back(*neighbour);
+                        }
+                    }
+                }
+
+                match members.into_iter().find(|peer_id| reachable.contains(peer_id).not()) {
+                    Some(peer_id) => Err(IllegalClusterTopology::UnreachablePeer { peer_id }),
+                    None => Ok(distinct_links),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ClusterTopology {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ClusterTopology::Star => write!(f, "star"),
+            ClusterTopology::FullMesh => write!(f, "full mesh"),
+            ClusterTopology::Custom { links } => {
+                let links = links.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "custom: {links}")
+            }
+        }
+    }
+}
+
+/// A direct connection between two peers of a cluster.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ClusterLink {
+    pub peer_a: PeerId,
+    pub peer_b: PeerId,
+}
+
+impl ClusterLink {
+    pub fn connects(&self, peer_id: PeerId) -> bool {
+        self.peer_a == peer_id || self.peer_b == peer_id
+    }
+
+    /// Returns the peer on the other end of this link, if the given peer is part of it.
+    pub fn other_peer(&self, peer_id: PeerId) -> Option<PeerId> {
+        if self.peer_a == peer_id {
+            Some(self.peer_b)
+        } else if self.peer_b == peer_id {
+            Some(self.peer_a)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for ClusterLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}> <-> <{}>", self.peer_a, self.peer_b)
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+pub enum IllegalClusterTopology {
+    #[error("Peer <{peer_id}> cannot be linked to itself.")]
+    SelfLink { peer_id: PeerId },
+    #[error("Peer <{peer_id}> is linked in the cluster topology, but is not a member of the cluster.")]
+    PeerNotInCluster { peer_id: PeerId },
+    #[error("Peer <{peer_id}> cannot be reached from the leader via the links of the cluster topology.")]
+    UnreachablePeer { peer_id: PeerId },
 }
 
 #[derive(thiserror::Error, Clone, Debug)]
@@ -160,4 +383,95 @@ mod tests {
         let _peer_name = ClusterName::try_from("_asd123".to_string()).is_err();
         Ok(())
     }
+
+    #[test]
+    fn A_ClusterDescriptor_should_resolve_devices_matching_any_of_its_selectors() -> anyhow::Result<()> {
+        let device = |name: &str, tags: &[&str]| -> anyhow::Result<DeviceDescriptor> {
+            Ok(DeviceDescriptor {
+                id: DeviceId::random(),
+                name: crate::topology::DeviceName::try_from(name)?,
+                description: None,
+                interface: crate::util::net::NetworkInterfaceId::random(),
+                tags: tags.iter().copied().map(DeviceTag::try_from).collect::<std::result::Result<_, _>>()?,
+            })
+        };
+        let powertrain_rig_3 = device("powertrain-rig-3", &["powertrain", "hil-rig-3"])?;
+        let powertrain_rig_4 = device("powertrain-rig-4", &["powertrain", "hil-rig-4"])?;
+        let body_rig_4 = device("body-rig-4", &["body", "hil-rig-4"])?;
+        let untagged = device("untagged", &[])?;
+        let explicit_device = DeviceId::random();
+
+        let cluster = ClusterDescriptor {
+            id: ClusterId::random(),
+            name: ClusterName::try_from("TaggedCluster")?,
+            leader: PeerId::random(),
+            devices: HashSet::from([explicit_device]),
+            device_selectors: vec![
+                DeviceSelector { tags: vec![DeviceTag::try_from("powertrain")?, DeviceTag::try_from("hil-rig-3")?] },
+                DeviceSelector { tags: vec![DeviceTag::try_from("body")?] },
+                DeviceSelector { tags: vec![] },
+            ],
+            topology: ClusterTopology::default(),
+            can_rules: vec![],
+            restbus_simulations: vec![],
+            revision: Default::default(),
+            namespace: Default::default(),
+        };
+
+        let devices = cluster.resolve_devices(&[powertrain_rig_3.clone(), powertrain_rig_4, body_rig_4.clone(), untagged]);
+
+        assert_that!(devices, unordered_elements_are![eq(&explicit_device), eq(&powertrain_rig_3.id), eq(&body_rig_4.id)]);
+        Ok(())
+    }
+
+    #[test]
+    fn A_ClusterTopology_should_determine_the_links_between_the_members() -> anyhow::Result<()> {
+        let leader = PeerId::random();
+        let peer_b = PeerId::random();
+        let peer_c = PeerId::random();
+        let members = HashSet::from([leader, peer_b, peer_c]);
+
+        let star = ClusterTopology::Star.links(leader, &members)?;
+        assert_that!(star, unordered_elements_are![
+            eq(&ClusterLink { peer_a: leader, peer_b }),
+            eq(&ClusterLink { peer_a: leader, peer_b: peer_c }),
+        ]);
+
+        let full_mesh = ClusterTopology::FullMesh.links(leader, &members)?;
+        assert_eq!(full_mesh.len(), 3);
+        for (one, other) in [(leader, peer_b), (leader, peer_c), (peer_b, peer_c)] {
+            assert!(full_mesh.iter().any(|link| link.connects(one) && link.connects(other)));
+        }
+
+        let chain = ClusterTopology::Custom { links: vec![
+            ClusterLink { peer_a: leader, peer_b },
+            ClusterLink { peer_a: peer_c, peer_b },
+            ClusterLink { peer_a: peer_b, peer_b: leader },
+        ]};
+        let chain = chain.links(leader, &members)?;
+        assert_that!(chain, elements_are![
+            eq(&ClusterLink { peer_a: leader, peer_b }),
+            eq(&ClusterLink { peer_a: peer_c, peer_b }),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn A_custom_ClusterTopology_should_reject_invalid_links() -> anyhow::Result<()> {
+        let leader = PeerId::random();
+        let peer_b = PeerId::random();
+        let peer_c = PeerId::random();
+        let outsider = PeerId::random();
+        let members = HashSet::from([leader, peer_b, peer_c]);
+
+        let unreachable = ClusterTopology::Custom { links: vec![ClusterLink { peer_a: leader, peer_b }] };
+        assert_eq!(unreachable.links(leader, &members), Err(IllegalClusterTopology::UnreachablePeer { peer_id: peer_c }));
+
+        let self_link = ClusterTopology::Custom { links: vec![ClusterLink { peer_a: peer_b, peer_b }] };
+        assert_eq!(self_link.links(leader, &members), Err(IllegalClusterTopology::SelfLink { peer_id: peer_b }));
+
+        let outside_link = ClusterTopology::Custom { links: vec![ClusterLink { peer_a: leader, peer_b: outsider }] };
+        assert_eq!(outside_link.links(leader, &members), Err(IllegalClusterTopology::PeerNotInCluster { peer_id: outsider }));
+        Ok(())
+    }
 }
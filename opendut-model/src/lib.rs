@@ -1,9 +1,11 @@
 use uuid::{Uuid, uuid};
 
+pub mod audit;
 pub mod cleo;
 pub mod cluster;
 pub mod id;
 pub mod lea;
+pub mod namespace;
 pub mod peer;
 pub mod proto;
 pub mod resources;
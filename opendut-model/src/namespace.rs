@@ -0,0 +1,131 @@
+use std::fmt;
+use std::ops::Not;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Namespace of a resource, e.g. a project or team.
+/// Resources without an explicitly assigned namespace belong to the [`NamespaceName::DEFAULT`] namespace.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct NamespaceName(pub(crate) String);
+
+impl NamespaceName {
+    pub const DEFAULT: &'static str = "default";
+    pub const MIN_LENGTH: usize = 1;
+    pub const MAX_LENGTH: usize = 64;
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for NamespaceName {
+    fn default() -> Self {
+        Self(String::from(Self::DEFAULT))
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum IllegalNamespaceName {
+    #[error(
+        "Namespace name '{value}' is too short. Expected at least {expected} characters, got {actual}."
+    )]
+    TooShort {
+        value: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error(
+        "Namespace name '{value}' is too long. Expected at most {expected} characters, got {actual}."
+    )]
+    TooLong {
+        value: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Namespace name '{value}' contains invalid characters.")]
+    InvalidCharacter { value: String },
+    #[error("Namespace name '{value}' contains invalid start or end characters.")]
+    InvalidStartEndCharacter { value: String },
+}
+
+impl From<NamespaceName> for String {
+    fn from(value: NamespaceName) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<String> for NamespaceName {
+    type Error = IllegalNamespaceName;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let length = value.len();
+        if length < Self::MIN_LENGTH {
+            Err(IllegalNamespaceName::TooShort {
+                value,
+                expected: Self::MIN_LENGTH,
+                actual: length,
+            })
+        } else if length > Self::MAX_LENGTH {
+            Err(IllegalNamespaceName::TooLong {
+                value,
+                expected: Self::MAX_LENGTH,
+                actual: length,
+            })
+        } else if crate::util::invalid_start_and_end_of_a_name(&value) {
+            Err(IllegalNamespaceName::InvalidStartEndCharacter { value })
+        } else if value
+            .chars()
+            .any(|c| crate::util::valid_characters_in_name(&c).not())
+        {
+            Err(IllegalNamespaceName::InvalidCharacter { value })
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<&str> for NamespaceName {
+    type Error = IllegalNamespaceName;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        NamespaceName::try_from(value.to_owned())
+    }
+}
+
+impl fmt::Display for NamespaceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for NamespaceName {
+    type Err = IllegalNamespaceName;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_default_to_the_default_namespace() {
+        assert_eq!(NamespaceName::default().value(), "default");
+    }
+
+    #[test]
+    fn should_accept_valid_namespace_names() {
+        assert!(NamespaceName::try_from("team-a").is_ok());
+        assert!(NamespaceName::try_from("project_42").is_ok());
+    }
+
+    #[test]
+    fn should_reject_invalid_namespace_names() {
+        assert!(matches!(NamespaceName::try_from(""), Err(IllegalNamespaceName::TooShort { .. })));
+        assert!(matches!(NamespaceName::try_from("team a"), Err(IllegalNamespaceName::InvalidCharacter { .. })));
+        assert!(matches!(NamespaceName::try_from("-team"), Err(IllegalNamespaceName::InvalidStartEndCharacter { .. })));
+    }
+}
@@ -87,20 +87,29 @@ pub enum ParameterDetectedStateKind {
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EdgePeerConfigurationState {
-    pub parameter_states: Vec<EdgePeerConfigurationParameterState>
+    pub parameter_states: Vec<EdgePeerConfigurationParameterState>,
+    /// Set, if the peer applied the configuration from its local cache, because CARL was not reachable.
+    pub cached: Option<CachedPeerConfiguration>,
+}
+
+/// Configuration, which a peer applied from its local cache, identified by the checksum of the cached configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedPeerConfiguration {
+    pub checksum: String,
+    pub stored_at: SystemTime,
 }
 
 /// State of a parameter on the edge peer side.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EdgePeerConfigurationParameterState {
     pub id: ParameterId,
     pub timestamp: SystemTime,
     pub detected_state: ParameterEdgeDetectedStateKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterEdgeDetectedStateKind {
     Present,
     Absent,
@@ -108,19 +117,19 @@ pub enum ParameterEdgeDetectedStateKind {
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParameterDetectedStateError {
     pub kind: ParameterDetectedStateErrorKind,
     pub cause: ParameterDetectedStateErrorCause,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterDetectedStateErrorCause {
     Unclassified(String),
     MissingDependencies(Vec<ParameterId>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterDetectedStateErrorKind {
     CreatingFailed,
     RemovingFailed,
@@ -160,7 +169,7 @@ mod tests {
             name: NetworkInterfaceName::try_from("eth0")?,
             configuration: NetworkInterfaceConfiguration::Ethernet,
         }};
-        let bridge = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut")? };
+        let bridge = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut")?, spanning_tree: false };
 
         let dep1 = ParameterId::from_hashable(&ethernet);
         let dep2 = ParameterId::from_hashable(&bridge);
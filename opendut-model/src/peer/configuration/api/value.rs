@@ -38,7 +38,7 @@ impl ParameterValue for parameter::DeviceInterface {
 }
 impl ParameterValue for parameter::EthernetBridge {
     fn parameter_identifier(&self) -> ParameterId {
-        let parameter::EthernetBridge { name } = self;
+        let parameter::EthernetBridge { name, spanning_tree: _ } = self;
 
         let mut hasher = DefaultHasher::new(); //ID not stable across Rust releases
         name.name().hash(&mut hasher);
@@ -62,7 +62,8 @@ impl ParameterValue for parameter::GreInterfaceConfig {
 
 impl ParameterValue for parameter::InterfaceJoinConfig {
     fn parameter_identifier(&self) -> ParameterId {
-        ParameterId::from_hashable(self)
+        let parameter::InterfaceJoinConfig { name, bridge, isolated: _ } = self;
+        ParameterId::from_hashable(&(name, bridge))
     }
 }
 
@@ -72,6 +73,12 @@ impl ParameterValue for parameter::RemotePeerConnectionCheck {
     }
 }
 
+impl ParameterValue for parameter::RestbusSimulation {
+    fn parameter_identifier(&self) -> ParameterId {
+        ParameterId::from_hashable(self)
+    }
+}
+
 impl ParameterValue for parameter::CanConnection {
     fn parameter_identifier(&self) -> ParameterId {
         ParameterId::from_hashable(self)
@@ -96,6 +103,7 @@ mod tests {
     use super::*;
     use crate::peer::configuration::{ParameterTarget, PeerConfiguration};
     use crate::peer::executor::{ExecutorDescriptor, ExecutorId, ExecutorKind};
+    use crate::peer::executor::executable::{ExecutablePath, ExecutableRestartPolicy};
 
     #[test]
     fn insert_value_in_peer_configuration() {
@@ -104,7 +112,13 @@ mod tests {
         let value = parameter::Executor {
             descriptor: ExecutorDescriptor {
                 id: ExecutorId::random(),
-                kind: ExecutorKind::Executable,
+                kind: ExecutorKind::Executable {
+                    path: ExecutablePath::try_from("/usr/bin/true").unwrap(),
+                    args: vec![],
+                    envs: vec![],
+                    working_dir: None,
+                    restart_policy: ExecutableRestartPolicy::Never,
+                },
                 results_url: None
             }
         };
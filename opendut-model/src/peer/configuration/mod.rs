@@ -18,6 +18,7 @@ pub struct PeerConfiguration {
     pub can_connections: ParameterField<parameter::CanConnection>,
     pub can_bridges: ParameterField<parameter::CanBridge>,
     pub can_local_routes: ParameterField<parameter::CanLocalRoute>,
+    pub restbus_simulations: ParameterField<parameter::RestbusSimulation>,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize)]
@@ -31,6 +32,7 @@ pub enum ParameterVariant {
     CanConnections(Box<Parameter<parameter::CanConnection>>),
     CanBridges(Box<Parameter<parameter::CanBridge>>),
     CanLocalRoutes(Box<Parameter<parameter::CanLocalRoute>>),
+    RestbusSimulations(Box<Parameter<parameter::RestbusSimulation>>),
 }
 
 impl ParameterVariant {
@@ -45,6 +47,7 @@ impl ParameterVariant {
             ParameterVariant::CanConnections(parameter) => { parameter.dependencies.iter().cloned().collect::<HashSet<_>>() }
             ParameterVariant::CanBridges(parameter) => { parameter.dependencies.iter().cloned().collect::<HashSet<_>>() }
             ParameterVariant::CanLocalRoutes(parameter) => { parameter.dependencies.iter().cloned().collect::<HashSet<_>>() }
+            ParameterVariant::RestbusSimulations(parameter) => { parameter.dependencies.iter().cloned().collect::<HashSet<_>>() }
         }
     }
     pub fn target(&self) -> ParameterTarget {
@@ -58,6 +61,7 @@ impl ParameterVariant {
             ParameterVariant::CanConnections(parameter) => parameter.target,
             ParameterVariant::CanBridges(parameter) => parameter.target,
             ParameterVariant::CanLocalRoutes(parameter) => parameter.target,
+            ParameterVariant::RestbusSimulations(parameter) => parameter.target,
         }
     }
     pub fn id(&self) -> ParameterId {
@@ -71,6 +75,7 @@ impl ParameterVariant {
             ParameterVariant::CanConnections(parameter) => parameter.id,
             ParameterVariant::CanBridges(parameter) => parameter.id,
             ParameterVariant::CanLocalRoutes(parameter) => parameter.id,
+            ParameterVariant::RestbusSimulations(parameter) => parameter.id,
         }
     }
 }
@@ -86,7 +91,8 @@ impl PeerConfiguration {
             remote_peer_connection_checks,
             can_connections,
             can_bridges,
-            can_local_routes,            
+            can_local_routes,
+            restbus_simulations,
         } = self.clone();
 
         device_interfaces.values.into_iter().map(|(id, parameter) | { (id, ParameterVariant::DeviceInterface(Box::new(parameter))) })
@@ -98,6 +104,7 @@ impl PeerConfiguration {
             .chain(can_connections.values.into_iter().map(|(id, parameter)| { (id, ParameterVariant::CanConnections(Box::new(parameter))) }))
             .chain(can_bridges.values.into_iter().map(|(id, parameter)| { (id, ParameterVariant::CanBridges(Box::new(parameter))) }))
             .chain(can_local_routes.values.into_iter().map(|(id, parameter)| { (id, ParameterVariant::CanLocalRoutes(Box::new(parameter))) }))
+            .chain(restbus_simulations.values.into_iter().map(|(id, parameter)| { (id, ParameterVariant::RestbusSimulations(Box::new(parameter))) }))
             .collect()
     }
 
@@ -114,6 +121,7 @@ impl PeerConfiguration {
         self.can_connections.retain(|id, _| !obsolete_parameter_ids.contains(id));
         self.can_bridges.retain(|id, _| !obsolete_parameter_ids.contains(id));
         self.can_local_routes.retain(|id, _| !obsolete_parameter_ids.contains(id));
+        self.restbus_simulations.retain(|id, _| !obsolete_parameter_ids.contains(id));
     }
 }
 
@@ -240,6 +248,7 @@ impl<V: ParameterValue> FromIterator<Parameter<V>> for ParameterField<V> {
 mod tests {
     use super::*;
     use crate::peer::executor::{ExecutorDescriptor, ExecutorId, ExecutorKind, ResultsUrl};
+    use crate::peer::executor::executable::{ExecutablePath, ExecutableRestartPolicy};
     use crate::util::net::NetworkInterfaceName;
     use googletest::prelude::*;
 
@@ -249,7 +258,7 @@ mod tests {
         #[test]
         fn should_replace_a_previous_parameter_when_it_is_set_another_time() -> anyhow::Result<()> {
 
-            let parameter_value = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut")? };
+            let parameter_value = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut")?, spanning_tree: false };
 
             let mut testee = PeerConfiguration::default();
             testee.ethernet_bridges.set(parameter_value.clone(), ParameterTarget::Present, vec![]);
@@ -274,7 +283,13 @@ mod tests {
             let parameter_value = parameter::Executor {
                 descriptor: ExecutorDescriptor {
                     id: ExecutorId::random(),
-                    kind: ExecutorKind::Executable,
+                    kind: ExecutorKind::Executable {
+                        path: ExecutablePath::try_from("/usr/bin/true")?,
+                        args: vec![],
+                        envs: vec![],
+                        working_dir: None,
+                        restart_policy: ExecutableRestartPolicy::Never,
+                    },
                     results_url: Some(ResultsUrl::try_from("https://example.com")?),
                 }
             };
@@ -307,7 +322,7 @@ mod tests {
         fn should_mark_obsolete_parameters_as_absent_and_retain_or_set_other_parameters_as_present() -> anyhow::Result<()> {
             // ARRANGE
             fn parameter_value(id: &str) -> parameter::EthernetBridge {
-                parameter::EthernetBridge { name: NetworkInterfaceName::try_from(id).unwrap() }
+                parameter::EthernetBridge { name: NetworkInterfaceName::try_from(id).unwrap(), spanning_tree: false }
             }
 
             let present_then_absent = parameter_value("1");
@@ -369,7 +384,7 @@ mod tests {
         fn should_mark_all_parameters_as_absent() -> anyhow::Result<()> {
 
             fn parameter_value(id: &str) -> parameter::EthernetBridge {
-                parameter::EthernetBridge { name: NetworkInterfaceName::try_from(id).unwrap() }
+                parameter::EthernetBridge { name: NetworkInterfaceName::try_from(id).unwrap(), spanning_tree: false }
             }
 
             let initially_present = parameter_value("1");
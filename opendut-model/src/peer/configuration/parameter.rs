@@ -1,10 +1,12 @@
 use std::fmt::{Display, Formatter};
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::IpAddr;
+use std::path::PathBuf;
 use std::str::FromStr;
 use base64::Engine;
 use serde::Serialize;
 use crate::peer::executor::ExecutorDescriptor;
 use crate::peer::PeerId;
+use crate::util::can::{CanFrameRules, CanRateLimitRule};
 use crate::util::net::{NetworkInterfaceDescriptor, NetworkInterfaceName, NetworkInterfaceNameError};
 use crate::util::Port;
 
@@ -16,34 +18,66 @@ pub struct DeviceInterface {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct EthernetBridge {
     pub name: NetworkInterfaceName,
+    /// enables the Spanning Tree Protocol, to protect against loops between the peers
+    pub spanning_tree: bool,
 }
 
+/// A GRE tunnel between two peers. Both addresses have to be of the same family,
+/// resulting in a `gretap` interface for IPv4 or an `ip6gretap` interface for IPv6.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct GreInterfaceConfig {
-    pub local_ip: Ipv4Addr,
-    pub remote_ip: Ipv4Addr,
+    pub local_ip: IpAddr,
+    pub remote_ip: IpAddr,
 }
 
 impl GreInterfaceConfig {
+    const IPV6_NAME_NAMESPACE: uuid::Uuid = uuid::Uuid::from_u128(0x5ac0c3a8_1a5d_4d0e_9d3c_6f1b2e7a9c41);
+    const IPV6_NAME_HASH_LENGTH: usize = 5;
+
     pub fn interface_name(&self) -> Result<NetworkInterfaceName, NetworkInterfaceNameError> {
-        let mut addr_bytes = self.local_ip.octets().to_vec();
-        addr_bytes.extend(self.remote_ip.octets());
-        
-        // https://git.kernel.org/pub/scm/network/iproute2/iproute2.git/tree/lib/utils.c?id=1f420318bda3cc62156e89e1b56d60cc744b48ad#n827
-        // documents that pretty much anything is allowed except "/", "\0" or whitespace
-        // using url safe base64 to avoid forward slash "/" 
-        let encoded_addresses = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(addr_bytes);
-        let name = format!("gre-{}", encoded_addresses.replace("=", ""));
+        let name = match (self.local_ip, self.remote_ip) {
+            (IpAddr::V4(local_ip), IpAddr::V4(remote_ip)) => {
+                let mut addr_bytes = local_ip.octets().to_vec();
+                addr_bytes.extend(remote_ip.octets());
+
+                // https://git.kernel.org/pub/scm/network/iproute2/iproute2.git/tree/lib/utils.c?id=1f420318bda3cc62156e89e1b56d60cc744b48ad#n827
+                // documents that pretty much anything is allowed except "/", "\0" or whitespace
+                // using url safe base64 to avoid forward slash "/"
+                let encoded_addresses = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(addr_bytes);
+                format!("gre-{}", encoded_addresses.replace("=", ""))
+            }
+            (local_ip, remote_ip) => {
+                // IPv6 addresses are too long to be encoded into the interface name, which is limited to 15 characters,
+                // so a stable hash of the addresses is used instead, encoded as hex to avoid special characters.
+                let mut addr_bytes = ip_octets(local_ip);
+                addr_bytes.extend(ip_octets(remote_ip));
+
+                let hash = uuid::Uuid::new_v5(&Self::IPV6_NAME_NAMESPACE, &addr_bytes);
+                let encoded_hash = hash.as_bytes()[..Self::IPV6_NAME_HASH_LENGTH].iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>();
+                format!("gre6-{encoded_hash}")
+            }
+        };
 
         NetworkInterfaceName::from_str(&name)
     }
 }
 
+fn ip_octets(ip: IpAddr) -> Vec<u8> {
+    match ip {
+        IpAddr::V4(ip) => ip.octets().to_vec(),
+        IpAddr::V6(ip) => ip.octets().to_vec(),
+    }
+}
+
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct InterfaceJoinConfig {
     pub name: NetworkInterfaceName,
     pub bridge: NetworkInterfaceName,
+    /// isolated bridge ports do not forward traffic to each other (split horizon)
+    pub isolated: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
@@ -73,6 +107,48 @@ pub struct CanConnection {
     /// starts a CAN server for other peers to connect to
     pub local_is_server: bool,
     pub buffer_timeout_microseconds: u64,
+    pub transport: CanTransport,
+    /// applied to the frames sent to the remote peer
+    pub rules: CanFrameRules,
+    /// rate limits of the local CAN devices, applied to the frames sent to the remote peer
+    pub rate_limits: Vec<CanRateLimitRule>,
+}
+
+/// Transport protocol for tunneling CAN frames between peers in the wire format of cannelloni.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub enum CanTransport {
+    /// Both peers send to each other's port. Lost frames are not retransmitted.
+    #[default]
+    Udp,
+    /// The client connects to the port of the server, for reliable transmission over lossy connections.
+    Sctp,
+}
+
+impl Display for CanTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanTransport::Udp => write!(f, "udp"),
+            CanTransport::Sctp => write!(f, "sctp"),
+        }
+    }
+}
+
+impl FromStr for CanTransport {
+    type Err = IllegalCanTransport;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "udp" => Ok(CanTransport::Udp),
+            "sctp" => Ok(CanTransport::Sctp),
+            _ => Err(IllegalCanTransport { value: value.to_owned() }),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Illegal CAN transport <{value}>. Expected 'udp' or 'sctp'.")]
+pub struct IllegalCanTransport {
+    pub value: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
@@ -86,26 +162,65 @@ pub struct CanBridge {
 pub struct CanLocalRoute {
     pub can_source_device_name: NetworkInterfaceName,
     pub can_destination_device_name: NetworkInterfaceName,
+    /// applied to the frames forwarded from the source to the destination, except for the rate limit
+    pub rules: CanFrameRules,
+}
+
+/// Simulates the rest of a CAN bus on an interface, by cyclically transmitting
+/// the frames, which the given ECUs send according to an ARXML file on the peer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct RestbusSimulation {
+    pub can_interface_name: NetworkInterfaceName,
+    pub arxml_file: PathBuf,
+    pub ecus: Vec<String>,
 }
 
 
 
 #[cfg(test)]
 mod tests {
-    use std::net::Ipv4Addr;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     use std::str::FromStr;
     use crate::peer::configuration::parameter::GreInterfaceConfig;
+    use crate::util::net::NetworkInterfaceName;
 
     #[test_log::test]
     fn test_gre_interface_name() -> anyhow::Result<()> {
         let gre_addresses = GreInterfaceConfig {
-            local_ip: Ipv4Addr::from_str("192.168.123.123")?,
-            remote_ip: Ipv4Addr::from_str("192.168.123.124")?,
+            local_ip: IpAddr::V4(Ipv4Addr::from_str("192.168.123.123")?),
+            remote_ip: IpAddr::V4(Ipv4Addr::from_str("192.168.123.124")?),
         };
         let name = gre_addresses.interface_name()?;
         assert!(name.name().starts_with("gre-"));
         assert!(name.name().len() < 16);
-        
+        assert_valid_characters(&name);
+
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_gre_interface_name_for_ipv6() -> anyhow::Result<()> {
+        let gre_addresses = GreInterfaceConfig {
+            local_ip: IpAddr::V6(Ipv6Addr::from_str("fd00:1234:5678::1")?),
+            remote_ip: IpAddr::V6(Ipv6Addr::from_str("fd00:1234:5678::2")?),
+        };
+        let name = gre_addresses.interface_name()?;
+        assert!(name.name().starts_with("gre6-"));
+        assert!(name.name().len() < 16);
+        assert_valid_characters(&name);
+
+        assert_eq!(gre_addresses.interface_name()?, name, "Interface name should be stable.");
+
+        let reversed_addresses = GreInterfaceConfig {
+            local_ip: gre_addresses.remote_ip,
+            remote_ip: gre_addresses.local_ip,
+        };
+        assert_ne!(reversed_addresses.interface_name()?, name);
+
+        Ok(())
+    }
+
+    fn assert_valid_characters(name: &NetworkInterfaceName) {
         let illegal_chars = ["=", "/", "+"];
         for illegal_char in illegal_chars.iter() {
             assert!(!name.name().contains(illegal_char));
@@ -114,7 +229,5 @@ mod tests {
         for illegal_char in illegal_end_chars.iter() {
             assert!(!name.name().ends_with(illegal_char));
         }
-        
-        Ok(())
     }
 }
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// Path to a program, which is started natively on the peer.
+/// Relative paths are looked up in the `PATH` of EDGAR.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ExecutablePath(PathBuf);
+
+impl ExecutablePath {
+    pub fn value(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum IllegalExecutablePath {
+    #[error("Executable path must not be empty.")]
+    Empty,
+}
+
+impl TryFrom<PathBuf> for ExecutablePath {
+    type Error = IllegalExecutablePath;
+
+    fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
+        if value.as_os_str().is_empty() {
+            Err(IllegalExecutablePath::Empty)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<String> for ExecutablePath {
+    type Error = IllegalExecutablePath;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        ExecutablePath::try_from(PathBuf::from(value))
+    }
+}
+
+impl TryFrom<&str> for ExecutablePath {
+    type Error = IllegalExecutablePath;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ExecutablePath::try_from(PathBuf::from(value))
+    }
+}
+
+impl FromStr for ExecutablePath {
+    type Err = IllegalExecutablePath;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ExecutablePath::try_from(value)
+    }
+}
+
+impl From<ExecutablePath> for String {
+    fn from(value: ExecutablePath) -> Self {
+        value.0.to_string_lossy().into_owned()
+    }
+}
+
+impl fmt::Display for ExecutablePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.display())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ExecutableArgument(String);
+
+impl ExecutableArgument {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum IllegalExecutableArgument {
+    #[error("Executable argument must not be empty.")]
+    Empty,
+}
+
+impl TryFrom<String> for ExecutableArgument {
+    type Error = IllegalExecutableArgument;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(IllegalExecutableArgument::Empty)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<&str> for ExecutableArgument {
+    type Error = IllegalExecutableArgument;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ExecutableArgument::try_from(value.to_owned())
+    }
+}
+
+impl FromStr for ExecutableArgument {
+    type Err = IllegalExecutableArgument;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ExecutableArgument::try_from(value)
+    }
+}
+
+impl From<ExecutableArgument> for String {
+    fn from(value: ExecutableArgument) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for ExecutableArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Directory in which the executable is started.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ExecutableWorkingDirectory(PathBuf);
+
+impl ExecutableWorkingDirectory {
+    pub fn value(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug)]
+pub enum IllegalExecutableWorkingDirectory {
+    #[error("Executable working directory must not be empty.")]
+    Empty,
+}
+
+impl TryFrom<PathBuf> for ExecutableWorkingDirectory {
+    type Error = IllegalExecutableWorkingDirectory;
+
+    fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
+        if value.as_os_str().is_empty() {
+            Err(IllegalExecutableWorkingDirectory::Empty)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<String> for ExecutableWorkingDirectory {
+    type Error = IllegalExecutableWorkingDirectory;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        ExecutableWorkingDirectory::try_from(PathBuf::from(value))
+    }
+}
+
+impl TryFrom<&str> for ExecutableWorkingDirectory {
+    type Error = IllegalExecutableWorkingDirectory;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ExecutableWorkingDirectory::try_from(PathBuf::from(value))
+    }
+}
+
+impl FromStr for ExecutableWorkingDirectory {
+    type Err = IllegalExecutableWorkingDirectory;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ExecutableWorkingDirectory::try_from(value)
+    }
+}
+
+impl From<ExecutableWorkingDirectory> for String {
+    fn from(value: ExecutableWorkingDirectory) -> Self {
+        value.0.to_string_lossy().into_owned()
+    }
+}
+
+impl fmt::Display for ExecutableWorkingDirectory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.display())
+    }
+}
+
+/// Determines whether EDGAR starts the executable again, after it terminated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExecutableRestartPolicy {
+    #[default]
+    Never,
+    OnFailure,
+    Always,
+}
+
+impl Display for ExecutableRestartPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutableRestartPolicy::Never => write!(f, "Never"),
+            ExecutableRestartPolicy::OnFailure => write!(f, "OnFailure"),
+            ExecutableRestartPolicy::Always => write!(f, "Always"),
+        }
+    }
+}
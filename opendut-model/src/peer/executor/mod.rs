@@ -5,8 +5,11 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 use crate::create_id_type;
 use crate::peer::executor::container::{Engine, ContainerName, ContainerImage, ContainerVolume, ContainerDevice, ContainerEnvironmentVariable, ContainerPortSpec, ContainerCommand, ContainerCommandArgument, serialize_container_environment_variable_vec, deserialize_container_environment_variable_vec};
+use crate::peer::executor::executable::{ExecutableArgument, ExecutablePath, ExecutableRestartPolicy, ExecutableWorkingDirectory};
 
 pub mod container;
+pub mod executable;
+pub mod state;
 
 #[derive(Clone, Debug, PartialEq,  Eq, Serialize, Deserialize)]
 pub struct ExecutorDescriptors {
@@ -29,7 +32,18 @@ create_id_type!(ExecutorId);
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub enum ExecutorKind {
-    Executable,
+    #[serde(rename_all = "kebab-case")]
+    Executable {
+        path: ExecutablePath,
+        #[serde(default)]
+        args: Vec<ExecutableArgument>,
+        #[serde(default, serialize_with = "serialize_container_environment_variable_vec", deserialize_with = "deserialize_container_environment_variable_vec")]
+        envs: Vec<ContainerEnvironmentVariable>,
+        #[serde(default)]
+        working_dir: Option<ExecutableWorkingDirectory>,
+        #[serde(default)]
+        restart_policy: ExecutableRestartPolicy,
+    },
     #[serde(rename_all = "kebab-case")]
     Container {
         engine: Engine,
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+use crate::peer::executor::ExecutorId;
+use crate::peer::PeerId;
+
+
+/// State of an [`ExecutorDescriptor`](crate::peer::executor::ExecutorDescriptor), as reported by the peer running it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutorState {
+    pub id: ExecutorId,
+    pub peer: PeerId,
+    pub status: ExecutorStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecutorStatus {
+    /// The executor was created by the peer, but not yet started.
+    Pending,
+    /// The container image of the executor is being pulled.
+    Pulling,
+    Running,
+    /// The executor terminated. The exit code is missing, if the executor was terminated by a signal.
+    Exited { code: Option<i32> },
+    /// The executor could not be started or monitored.
+    Failed { reason: String },
+    /// The executor terminated and its results were uploaded to the results URL.
+    ResultsUploaded,
+}
+
+impl ExecutorStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, ExecutorStatus::Exited { .. } | ExecutorStatus::Failed { .. } | ExecutorStatus::ResultsUploaded)
+    }
+}
+
+impl Display for ExecutorStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutorStatus::Pending => write!(f, "Pending"),
+            ExecutorStatus::Pulling => write!(f, "Pulling"),
+            ExecutorStatus::Running => write!(f, "Running"),
+            ExecutorStatus::Exited { code: Some(code) } => write!(f, "Exited ({code})"),
+            ExecutorStatus::Exited { code: None } => write!(f, "Exited"),
+            ExecutorStatus::Failed { reason } => write!(f, "Failed: {reason}"),
+            ExecutorStatus::ResultsUploaded => write!(f, "Results uploaded"),
+        }
+    }
+}
@@ -9,6 +9,8 @@ use crate::create_id_type;
 use crate::peer::executor::ExecutorDescriptors;
 use crate::topology::{DeviceDescriptor, Topology};
 use crate::util::net::{AuthConfig, Certificate, NetworkInterfaceDescriptor, NetworkInterfaceName};
+use crate::namespace::NamespaceName;
+use crate::resources::Revision;
 use crate::vpn::VpnPeerConfiguration;
 
 pub mod state;
@@ -225,6 +227,12 @@ pub struct PeerDescriptor {
     pub network: PeerNetworkDescriptor,
     pub topology: Topology,
     pub executors: ExecutorDescriptors,
+    /// Namespace the peer belongs to, e.g. a project or team.
+    #[serde(default)]
+    pub namespace: NamespaceName,
+    /// Revision of the stored descriptor. Must match the stored revision when updating the descriptor.
+    #[serde(default)]
+    pub revision: Revision,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -0,0 +1,137 @@
+use std::time::SystemTime;
+use opendut_util::conversion;
+use opendut_util::proto::ConversionResult;
+
+opendut_util::include_proto!("opendut.model.audit");
+
+
+conversion! {
+    type Model = crate::audit::AuditEventId;
+    type Proto = AuditEventId;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            uuid: Some(value.uuid.into())
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        extract!(value.uuid)
+            .map(|uuid| Model { uuid: uuid.into() })
+    }
+}
+
+conversion! {
+    type Model = crate::audit::AuditEvent;
+    type Proto = AuditEvent;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            id: Some(value.id.into()),
+            timestamp: Some(value.timestamp.into()),
+            user: value.user,
+            operation: value.operation,
+            resource_ids: value.resource_ids.into_iter()
+                .map(Into::into)
+                .collect(),
+            outcome: Some(value.outcome.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let timestamp = SystemTime::try_from(extract!(value.timestamp)?)
+            .map_err(|error| ErrorBuilder::message(error.to_string()))?;
+
+        let resource_ids = value.resource_ids.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Model {
+            id: extract!(value.id)?.try_into()?,
+            timestamp,
+            user: value.user,
+            operation: value.operation,
+            resource_ids,
+            outcome: extract!(value.outcome)?.try_into()?,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::audit::AuditResourceId;
+    type Proto = AuditResourceId;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Peer(id) => audit_resource_id::Kind::Peer(id.into()),
+            Model::Cluster(id) => audit_resource_id::Kind::Cluster(id.into()),
+            Model::ViperSource(id) => audit_resource_id::Kind::ViperSource(id.into()),
+            Model::ViperRun(id) => audit_resource_id::Kind::ViperRun(id.into()),
+        };
+        Proto {
+            kind: Some(kind)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let result = match extract!(value.kind)? {
+            audit_resource_id::Kind::Peer(id) => Model::Peer(id.try_into()?),
+            audit_resource_id::Kind::Cluster(id) => Model::Cluster(id.try_into()?),
+            audit_resource_id::Kind::ViperSource(id) => Model::ViperSource(id.into()),
+            audit_resource_id::Kind::ViperRun(id) => Model::ViperRun(id.into()),
+        };
+        Ok(result)
+    }
+}
+
+conversion! {
+    type Model = crate::audit::AuditOutcome;
+    type Proto = AuditOutcome;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Success => audit_outcome::Kind::Success(AuditOutcomeSuccess {}),
+            Model::Failure { message } => audit_outcome::Kind::Failure(AuditOutcomeFailure { message }),
+        };
+        Proto {
+            kind: Some(kind)
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let result = match extract!(value.kind)? {
+            audit_outcome::Kind::Success(AuditOutcomeSuccess {}) => Model::Success,
+            audit_outcome::Kind::Failure(AuditOutcomeFailure { message }) => Model::Failure { message },
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::AuditOutcome;
+    use crate::cluster::ClusterId;
+    use crate::peer::PeerId;
+
+    #[test]
+    fn should_convert_an_audit_event_to_proto_and_back() -> anyhow::Result<()> {
+        let event = crate::audit::AuditEvent {
+            id: crate::audit::AuditEventId::random(),
+            timestamp: SystemTime::now(),
+            user: Some(String::from("alice")),
+            operation: String::from("StoreClusterDeployment"),
+            resource_ids: vec![
+                crate::audit::AuditResourceId::Cluster(ClusterId::random()),
+                crate::audit::AuditResourceId::Peer(PeerId::random()),
+            ],
+            outcome: AuditOutcome::Failure { message: String::from("Peer is offline.") },
+        };
+
+        let proto = AuditEvent::from(event.clone());
+        let result = crate::audit::AuditEvent::try_from(proto)?;
+
+        assert_eq!(result, event);
+        Ok(())
+    }
+}
@@ -49,6 +49,18 @@ conversion! {
             devices: configuration.devices.into_iter()
                 .map(DeviceId::from)
                 .collect(),
+            device_selectors: configuration.device_selectors.into_iter()
+                .map(DeviceSelector::from)
+                .collect(),
+            topology: Some(configuration.topology.into()),
+            can_rules: configuration.can_rules.into_iter()
+                .map(DeviceCanRules::from)
+                .collect(),
+            restbus_simulations: configuration.restbus_simulations.into_iter()
+                .map(DeviceRestbusSimulation::from)
+                .collect(),
+            revision: configuration.revision.into(),
+            namespace: Some(configuration.namespace.into()),
         }
     }
 
@@ -59,6 +71,12 @@ conversion! {
 
         let leader: crate::peer::PeerId = extract!(configuration.leader)?.try_into()?;
 
+        // Descriptors stored before the introduction of namespaces belong to the default namespace
+        let namespace = configuration.namespace
+            .map(crate::namespace::NamespaceName::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
         Ok(Model {
             id: cluster_id,
             name: cluster_name,
@@ -66,6 +84,138 @@ conversion! {
             devices: configuration.devices.into_iter()
                 .map(DeviceId::try_into)
                 .collect::<Result<_, _>>()?,
+            device_selectors: configuration.device_selectors.into_iter()
+                .map(DeviceSelector::try_into)
+                .collect::<Result<_, _>>()?,
+            topology: configuration.topology
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            can_rules: configuration.can_rules.into_iter()
+                .map(DeviceCanRules::try_into)
+                .collect::<Result<_, _>>()?,
+            restbus_simulations: configuration.restbus_simulations.into_iter()
+                .map(DeviceRestbusSimulation::try_into)
+                .collect::<Result<_, _>>()?,
+            namespace,
+            revision: configuration.revision.into(),
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::cluster::DeviceSelector;
+    type Proto = DeviceSelector;
+
+    fn from(selector: Model) -> Proto {
+        Proto {
+            tags: selector.tags.into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+
+    fn try_from(selector: Proto) -> ConversionResult<Model> {
+        Ok(Model {
+            tags: selector.tags.into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::cluster::DeviceCanRules;
+    type Proto = DeviceCanRules;
+
+    fn from(device_rules: Model) -> Proto {
+        Proto {
+            device: Some(device_rules.device.into()),
+            rules: Some(device_rules.rules.into()),
+        }
+    }
+
+    fn try_from(device_rules: Proto) -> ConversionResult<Model> {
+        let device = extract!(device_rules.device)?.try_into()?;
+        let rules = extract!(device_rules.rules)?.try_into()?;
+
+        Ok(Model { device, rules })
+    }
+}
+
+conversion! {
+    type Model = crate::cluster::DeviceRestbusSimulation;
+    type Proto = DeviceRestbusSimulation;
+
+    fn from(simulation: Model) -> Proto {
+        Proto {
+            device: Some(simulation.device.into()),
+            arxml_file: simulation.arxml_file.to_string_lossy().into_owned(),
+            ecus: simulation.ecus,
+        }
+    }
+
+    fn try_from(simulation: Proto) -> ConversionResult<Model> {
+        let device = extract!(simulation.device)?.try_into()?;
+
+        if simulation.arxml_file.is_empty() {
+            return Err(ErrorBuilder::message("Path of the ARXML file must not be empty."));
+        }
+
+        Ok(Model {
+            device,
+            arxml_file: std::path::PathBuf::from(simulation.arxml_file),
+            ecus: simulation.ecus,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::cluster::ClusterTopology;
+    type Proto = ClusterTopology;
+
+    fn from(topology: Model) -> Proto {
+        let inner = match topology {
+            Model::Star => cluster_topology::Inner::Star(ClusterTopologyStar {}),
+            Model::FullMesh => cluster_topology::Inner::FullMesh(ClusterTopologyFullMesh {}),
+            Model::Custom { links } => cluster_topology::Inner::Custom(ClusterTopologyCustom {
+                links: links.into_iter()
+                    .map(ClusterLink::from)
+                    .collect(),
+            }),
+        };
+        Proto { inner: Some(inner) }
+    }
+
+    fn try_from(topology: Proto) -> ConversionResult<Model> {
+        let topology = match extract!(topology.inner)? {
+            cluster_topology::Inner::Star(_) => Model::Star,
+            cluster_topology::Inner::FullMesh(_) => Model::FullMesh,
+            cluster_topology::Inner::Custom(custom) => Model::Custom {
+                links: custom.links.into_iter()
+                    .map(ClusterLink::try_into)
+                    .collect::<Result<_, _>>()?,
+            },
+        };
+        Ok(topology)
+    }
+}
+
+conversion! {
+    type Model = crate::cluster::ClusterLink;
+    type Proto = ClusterLink;
+
+    fn from(link: Model) -> Proto {
+        Proto {
+            peer_a: Some(link.peer_a.into()),
+            peer_b: Some(link.peer_b.into()),
+        }
+    }
+
+    fn try_from(link: Proto) -> ConversionResult<Model> {
+        Ok(Model {
+            peer_a: extract!(link.peer_a)?.try_into()?,
+            peer_b: extract!(link.peer_b)?.try_into()?,
         })
     }
 }
@@ -1,5 +1,7 @@
+pub mod audit;
 pub mod cleo;
 pub mod cluster;
+pub mod namespace;
 pub mod peer;
 pub mod topology;
 pub mod util;
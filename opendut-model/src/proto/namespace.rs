@@ -0,0 +1,21 @@
+use opendut_util::conversion;
+use opendut_util::proto::ConversionResult;
+
+opendut_util::include_proto!("opendut.model.namespace");
+
+
+conversion! {
+    type Model = crate::namespace::NamespaceName;
+    type Proto = NamespaceName;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            value: value.0
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::try_from(value.value)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
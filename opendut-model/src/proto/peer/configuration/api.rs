@@ -20,6 +20,7 @@ conversion! {
             can_connections: value.can_connections.into_iter().map(From::from).collect(),
             can_bridges: value.can_bridges.into_iter().map(From::from).collect(),
             can_local_routes: value.can_local_routes.into_iter().map(From::from).collect(),
+            restbus_simulations: value.restbus_simulations.into_iter().map(From::from).collect(),
         }
     }
 
@@ -34,6 +35,7 @@ conversion! {
             can_connections: value.can_connections.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
             can_bridges: value.can_bridges.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
             can_local_routes: value.can_local_routes.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
+            restbus_simulations: value.restbus_simulations.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
         })
     }
 }
@@ -125,6 +127,11 @@ parameter_conversion! {
     type ProtoParameter = PeerConfigurationParameterCanLocalRoute;
 }
 
+parameter_conversion! {
+    type ModelParameter = crate::peer::configuration::parameter::RestbusSimulation;
+    type ProtoParameter = PeerConfigurationParameterRestbusSimulation;
+}
+
 
 impl<V: crate::peer::configuration::ParameterValue> From<crate::peer::configuration::Parameter<V>> for PeerConfigurationParameter {
     fn from(value: crate::peer::configuration::Parameter<V>) -> Self {
@@ -179,6 +186,7 @@ conversion! {
             parameters: value.parameter_states.into_iter()
                 .map(Into::into)
                 .collect(),
+            cached: value.cached.map(Into::into),
         }
     }
 
@@ -187,6 +195,28 @@ conversion! {
             parameter_states: value.parameters.into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            cached: value.cached.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::peer::configuration::CachedPeerConfiguration;
+    type Proto = CachedPeerConfiguration;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            checksum: value.checksum,
+            stored_at: Some(value.stored_at.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let stored_at = SystemTime::try_from(extract!(value.stored_at)?)
+            .map_err(|error| ErrorBuilder::message(error.to_string()))?;
+        Ok(Model {
+            checksum: value.checksum,
+            stored_at,
         })
     }
 }
@@ -362,7 +392,7 @@ mod tests {
     use crate::proto::peer::configuration::api::{PeerConfigurationParameter, PeerConfigurationParameterCanLocalRoute, PeerConfigurationParameterStateKindPresent};
     use crate::proto::peer::configuration::api::peer_configuration_parameter::TargetState;
     use crate::proto::peer::configuration::parameter::CanLocalRoute;
-    use crate::proto::util::{NetworkInterfaceName};
+    use crate::proto::util::{CanFrameRules, NetworkInterfaceName};
 
     #[test]
     fn test_convert_can_local_route_proto_to_model() {
@@ -370,6 +400,7 @@ mod tests {
         let can_local_route = CanLocalRoute {
             can_source_device_name: Some(NetworkInterfaceName { name: can_bridge_name.clone() }),
             can_destination_device_name: Some(NetworkInterfaceName { name: "can0".to_string() }),
+            rules: Some(CanFrameRules::default()),
         };
         let parameter = PeerConfigurationParameter {
             id: Some(ParameterId(Uuid::new_v4()).into()),
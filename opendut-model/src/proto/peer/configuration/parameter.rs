@@ -29,7 +29,8 @@ conversion! {
 
     fn from(value: Model) -> Proto {
         Proto {
-            name: Some(value.name.into())
+            name: Some(value.name.into()),
+            spanning_tree: value.spanning_tree,
         }
     }
 
@@ -38,6 +39,7 @@ conversion! {
 
         Ok(Model {
             name,
+            spanning_tree: value.spanning_tree,
         })
     }
 }
@@ -68,17 +70,29 @@ conversion! {
     
     fn from(value: Model) -> Proto {
         Proto {
-            local_ip: Some(value.local_ip.into()),
-            remote_ip: Some(value.remote_ip.into()),
+            local_ip: ipv4_address(value.local_ip),
+            remote_ip: ipv4_address(value.remote_ip),
+            local_ip_address: Some(value.local_ip.into()),
+            remote_ip_address: Some(value.remote_ip.into()),
         }
     }
     
     fn try_from(value: Proto) -> ConversionResult<Model> {
-        let local_ip = std::net::Ipv4Addr::try_from(extract!(value.local_ip)?)
-            .map_err(|cause| ErrorBuilder::message(cause.to_string()))?;
-
-        let remote_ip = std::net::Ipv4Addr::try_from(extract!(value.remote_ip)?)
-            .map_err(|cause| ErrorBuilder::message(cause.to_string()))?;
+        let local_ip = match value.local_ip_address {
+            Some(local_ip) => local_ip.try_into()?,
+            None => std::net::IpAddr::V4(
+                std::net::Ipv4Addr::try_from(extract!(value.local_ip)?)
+                    .map_err(|cause| ErrorBuilder::message(cause.to_string()))?
+            ),
+        };
+
+        let remote_ip = match value.remote_ip_address {
+            Some(remote_ip) => remote_ip.try_into()?,
+            None => std::net::IpAddr::V4(
+                std::net::Ipv4Addr::try_from(extract!(value.remote_ip)?)
+                    .map_err(|cause| ErrorBuilder::message(cause.to_string()))?
+            ),
+        };
 
         Ok(Model {
             local_ip,
@@ -87,6 +101,14 @@ conversion! {
     }
 }
 
+/// Peers, which only support IPv4 GRE tunnels, read the addresses from the IPv4-only fields.
+fn ipv4_address(address: std::net::IpAddr) -> Option<crate::proto::util::IpV4Address> {
+    match address {
+        std::net::IpAddr::V4(address) => Some(address.into()),
+        std::net::IpAddr::V6(_) => None,
+    }
+}
+
 conversion! {
     type Model = crate::peer::configuration::parameter::InterfaceJoinConfig;
     type Proto = InterfaceJoinConfig;
@@ -95,6 +117,7 @@ conversion! {
         Proto {
             name: Some(value.name.into()),
             bridge: Some(value.bridge.into()),
+            isolated: value.isolated,
         }
     }
     
@@ -104,6 +127,7 @@ conversion! {
         Ok(Model {
             name,
             bridge,
+            isolated: value.isolated,
         })
     }
 }
@@ -143,6 +167,9 @@ conversion! {
             can_interface_name: Some(value.can_interface_name.into()),
             local_is_server: value.local_is_server,
             buffer_timeout_microseconds: value.buffer_timeout_microseconds,
+            transport: Some(value.transport.into()),
+            rules: Some(value.rules.into()),
+            rate_limits: value.rate_limits.into_iter().map(Into::into).collect(),
         }
     }
 
@@ -152,6 +179,18 @@ conversion! {
         let remote_port = extract!(value.remote_port)?.try_into()?;
         let local_port = extract!(value.local_port)?.try_into()?;
         let can_interface_name = extract!(value.can_interface_name)?.try_into()?;
+        // Older versions of CARL do not send a transport, as their peers always tunneled CAN frames via SCTP.
+        let transport = value.transport
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or(crate::peer::configuration::parameter::CanTransport::Sctp);
+        let rules = value.rules
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        let rate_limits = value.rate_limits.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
 
         Ok(Model {
             remote_peer_id,
@@ -161,10 +200,34 @@ conversion! {
             can_interface_name,
             local_is_server: value.local_is_server,
             buffer_timeout_microseconds: value.buffer_timeout_microseconds,
+            transport,
+            rules,
+            rate_limits,
         })
     }
 }
 
+conversion! {
+    type Model = crate::peer::configuration::parameter::CanTransport;
+    type Proto = CanTransport;
+
+    fn from(transport: Model) -> Proto {
+        let inner = match transport {
+            Model::Udp => can_transport::Inner::Udp(CanTransportUdp {}),
+            Model::Sctp => can_transport::Inner::Sctp(CanTransportSctp {}),
+        };
+        Proto { inner: Some(inner) }
+    }
+
+    fn try_from(transport: Proto) -> ConversionResult<Model> {
+        let transport = match extract!(transport.inner)? {
+            can_transport::Inner::Udp(_) => Model::Udp,
+            can_transport::Inner::Sctp(_) => Model::Sctp,
+        };
+        Ok(transport)
+    }
+}
+
 conversion! {
     type Model = crate::peer::configuration::parameter::CanBridge;
     type Proto = CanBridge;
@@ -192,16 +255,122 @@ conversion! {
         Proto {
             can_source_device_name: Some(value.can_source_device_name.into()),
             can_destination_device_name: Some(value.can_destination_device_name.into()),
+            rules: Some(value.rules.into()),
+            rate_limits: value.rate_limits.into_iter().map(Into::into).collect(),
         }
     }
 
     fn try_from(value: Proto) -> ConversionResult<Model> {
         let can_source_device_name = extract!(value.can_source_device_name)?.try_into()?;
         let can_destination_device_name = extract!(value.can_destination_device_name)?.try_into()?;
+        let rules = value.rules
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Model {
             can_source_device_name,
             can_destination_device_name,
+            rules,
         })
     }
 }
+
+conversion! {
+    type Model = crate::peer::configuration::parameter::RestbusSimulation;
+    type Proto = RestbusSimulation;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            can_interface_name: Some(value.can_interface_name.into()),
+            arxml_file: value.arxml_file.to_string_lossy().into_owned(),
+            ecus: value.ecus,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let can_interface_name = extract!(value.can_interface_name)?.try_into()?;
+
+        if value.arxml_file.is_empty() {
+            return Err(ErrorBuilder::message("Path of the ARXML file must not be empty."));
+        }
+
+        Ok(Model {
+            can_interface_name,
+            arxml_file: std::path::PathBuf::from(value.arxml_file),
+            ecus: value.ecus,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn A_GreInterfaceConfig_with_IPv4_addresses_should_remain_readable_for_peers_only_knowing_the_IPv4_fields() -> Result<()> {
+        let native = crate::peer::configuration::parameter::GreInterfaceConfig {
+            local_ip: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+            remote_ip: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 2)),
+        };
+
+        let proto = GreInterfaceConfig::from(Clone::clone(&native));
+        assert_that!(proto.local_ip, some(eq(&crate::proto::util::IpV4Address::from(Ipv4Addr::new(192, 168, 0, 1)))));
+
+        let legacy_proto = GreInterfaceConfig {
+            local_ip_address: None,
+            remote_ip_address: None,
+            ..proto
+        };
+        let result = crate::peer::configuration::parameter::GreInterfaceConfig::try_from(legacy_proto)?;
+        assert_that!(result, eq(&native));
+
+        Ok(())
+    }
+
+    #[test]
+    fn A_GreInterfaceConfig_with_IPv6_addresses_should_be_convertable_to_its_proto_and_vice_versa() -> Result<()> {
+        let native = crate::peer::configuration::parameter::GreInterfaceConfig {
+            local_ip: IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)),
+            remote_ip: IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 2)),
+        };
+
+        let proto = GreInterfaceConfig::from(Clone::clone(&native));
+        assert_that!(proto.local_ip, none());
+
+        let result = crate::peer::configuration::parameter::GreInterfaceConfig::try_from(proto)?;
+        assert_that!(result, eq(&native));
+
+        Ok(())
+    }
+
+    #[test]
+    fn A_CanConnection_without_a_transport_should_use_SCTP_like_peers_of_older_versions() -> Result<()> {
+        let native = crate::peer::configuration::parameter::CanConnection {
+            remote_peer_id: crate::peer::PeerId::random(),
+            remote_ip: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 2)),
+            remote_port: crate::util::Port(10000),
+            local_port: crate::util::Port(10001),
+            can_interface_name: crate::util::net::NetworkInterfaceName::try_from("br-vcan-opendut")?,
+            local_is_server: true,
+            buffer_timeout_microseconds: 100,
+            transport: crate::peer::configuration::parameter::CanTransport::Udp,
+            rules: Default::default(),
+            rate_limits: vec![],
+        };
+
+        let legacy_proto = CanConnection {
+            transport: None,
+            ..CanConnection::from(Clone::clone(&native))
+        };
+        let result = crate::peer::configuration::parameter::CanConnection::try_from(legacy_proto)?;
+        assert_that!(result.transport, eq(crate::peer::configuration::parameter::CanTransport::Sctp));
+
+        Ok(())
+    }
+}
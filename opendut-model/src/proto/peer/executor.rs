@@ -37,11 +37,22 @@ conversion! {
         let id = Some(value.id.into());
 
         let executor_kind = match value.kind {
-            crate::peer::executor::ExecutorKind::Executable => {
+            crate::peer::executor::ExecutorKind::Executable {
+                path,
+                args,
+                envs,
+                working_dir,
+                restart_policy,
+            } => {
                 Some(executor_descriptor::Kind::Executable(
-                       Executable {}
-                    )
-                )
+                    Executable {
+                        path: Some(path.into()),
+                        args: args.into_iter().map(|arg| arg.into()).collect(),
+                        envs: envs.into_iter().map(|env| env.into()).collect(),
+                        working_dir: working_dir.map(|working_dir| working_dir.into()),
+                        restart_policy: Some(restart_policy.into()),
+                    }
+                ))
             }
             crate::peer::executor::ExecutorKind::Container {
                 engine,
@@ -84,8 +95,24 @@ conversion! {
         let kind = extract!(value.kind)?;
 
         let result_kind = match kind {
-            executor_descriptor::Kind::Executable(_) => {
-                crate::peer::executor::ExecutorKind::Executable
+            executor_descriptor::Kind::Executable(descriptor) => {
+                let Executable { path, args, envs, working_dir, restart_policy } = descriptor;
+
+                let path = extract!(path)?.try_into()?;
+
+                let args = args.into_iter()
+                    .map(TryFrom::try_from)
+                    .collect::<Result<_, _>>()?;
+
+                let envs = envs.into_iter()
+                    .map(TryFrom::try_from)
+                    .collect::<Result<_, _>>()?;
+
+                let working_dir = working_dir.map(TryFrom::try_from).transpose()?;
+
+                let restart_policy = extract!(restart_policy)?.try_into()?;
+
+                crate::peer::executor::ExecutorKind::Executable { path, args, envs, working_dir, restart_policy }
             },
             executor_descriptor::Kind::Container(descriptor) => {
                 let Container { engine, name, image, volumes, devices, envs, ports, command, args } = descriptor;
@@ -313,6 +340,81 @@ conversion! {
     }
 }
 
+conversion! {
+    type Model = crate::peer::executor::executable::ExecutablePath;
+    type Proto = ExecutablePath;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            value: value.into()
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::try_from(value.value)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
+
+conversion! {
+    type Model = crate::peer::executor::executable::ExecutableArgument;
+    type Proto = ExecutableArgument;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            value: value.into()
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::try_from(value.value)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
+
+conversion! {
+    type Model = crate::peer::executor::executable::ExecutableWorkingDirectory;
+    type Proto = ExecutableWorkingDirectory;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            value: value.into()
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::try_from(value.value)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
+
+conversion! {
+    type Model = crate::peer::executor::executable::ExecutableRestartPolicy;
+    type Proto = ExecutableRestartPolicy;
+
+    fn from(value: Model) -> Proto {
+        let inner = match value {
+            Model::Never => executable_restart_policy::Inner::Never(ExecutableRestartPolicyNever {}),
+            Model::OnFailure => executable_restart_policy::Inner::OnFailure(ExecutableRestartPolicyOnFailure {}),
+            Model::Always => executable_restart_policy::Inner::Always(ExecutableRestartPolicyAlways {}),
+        };
+        Proto { inner: Some(inner) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+
+        let inner = extract!(value.inner)?;
+
+        let result = match inner {
+            executable_restart_policy::Inner::Never(_) => Model::Never,
+            executable_restart_policy::Inner::OnFailure(_) => Model::OnFailure,
+            executable_restart_policy::Inner::Always(_) => Model::Always,
+        };
+
+        Ok(result)
+    }
+}
+
 conversion! {
     type Model = crate::peer::executor::ResultsUrl;
     type Proto = ResultsUrl;
@@ -328,3 +430,58 @@ conversion! {
             .map_err(|cause| ErrorBuilder::message(cause.to_string()))
     }
 }
+
+conversion! {
+    type Model = crate::peer::executor::state::ExecutorState;
+    type Proto = ExecutorState;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            id: Some(value.id.into()),
+            peer: Some(value.peer.into()),
+            status: Some(value.status.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?
+            .try_into()?;
+
+        let peer = extract!(value.peer)?
+            .try_into()?;
+
+        let status = extract!(value.status)?
+            .try_into()?;
+
+        Ok(Model { id, peer, status })
+    }
+}
+
+conversion! {
+    type Model = crate::peer::executor::state::ExecutorStatus;
+    type Proto = ExecutorStatus;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Pending => executor_status::Kind::Pending(ExecutorStatusPending {}),
+            Model::Pulling => executor_status::Kind::Pulling(ExecutorStatusPulling {}),
+            Model::Running => executor_status::Kind::Running(ExecutorStatusRunning {}),
+            Model::Exited { code } => executor_status::Kind::Exited(ExecutorStatusExited { code }),
+            Model::Failed { reason } => executor_status::Kind::Failed(ExecutorStatusFailed { reason }),
+            Model::ResultsUploaded => executor_status::Kind::ResultsUploaded(ExecutorStatusResultsUploaded {}),
+        };
+        Proto { kind: Some(kind) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let status = match extract!(value.kind)? {
+            executor_status::Kind::Pending(_) => Model::Pending,
+            executor_status::Kind::Pulling(_) => Model::Pulling,
+            executor_status::Kind::Running(_) => Model::Running,
+            executor_status::Kind::Exited(ExecutorStatusExited { code }) => Model::Exited { code },
+            executor_status::Kind::Failed(ExecutorStatusFailed { reason }) => Model::Failed { reason },
+            executor_status::Kind::ResultsUploaded(_) => Model::ResultsUploaded,
+        };
+        Ok(status)
+    }
+}
@@ -113,6 +113,8 @@ conversion! {
             network: Some(value.network.into()),
             topology: Some(value.topology.into()),
             executors: Some(value.executors.into()),
+            revision: value.revision.into(),
+            namespace: Some(value.namespace.into()),
         }
     }
 
@@ -148,7 +150,15 @@ conversion! {
             }
         }
 
-        Ok(Model { id, name, location, network, topology, executors })
+        let revision = crate::resources::Revision::from(value.revision);
+
+        // Descriptors stored before the introduction of namespaces belong to the default namespace
+        let namespace = value.namespace
+            .map(crate::namespace::NamespaceName::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Model { id, name, location, network, topology, executors, namespace, revision })
     }
 }
 
@@ -358,3 +358,91 @@ conversion! {
         Ok(config)
     }
 }
+
+conversion! {
+    type Model = crate::util::can::CanIdFilter;
+    type Proto = CanIdFilter;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            id: value.id,
+            mask: value.mask,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::new(value.id, value.mask)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
+
+conversion! {
+    type Model = crate::util::can::CanIdRewrite;
+    type Proto = CanIdRewrite;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            from: value.from,
+            to: value.to,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Model::new(value.from, value.to)
+            .map_err(|cause| ErrorBuilder::message(cause.to_string()))
+    }
+}
+
+conversion! {
+    type Model = crate::util::can::CanRateLimitRule;
+    type Proto = CanRateLimitRule;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            filters: value.filters.into_iter().map(Into::into).collect(),
+            rate_limit: Some(CanRateLimit { frames_per_second: value.rate_limit.frames_per_second }),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let filters = value.filters.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let rate_limit = extract!(value.rate_limit)?;
+
+        Ok(Model {
+            filters,
+            rate_limit: util::can::CanRateLimit { frames_per_second: rate_limit.frames_per_second },
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::util::can::CanFrameRules;
+    type Proto = CanFrameRules;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            accept: value.accept.into_iter().map(Into::into).collect(),
+            reject: value.reject.into_iter().map(Into::into).collect(),
+            rewrites: value.rewrites.into_iter().map(Into::into).collect(),
+            rate_limit: value.rate_limit.map(|rate_limit| CanRateLimit { frames_per_second: rate_limit.frames_per_second }),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let accept = value.accept.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let reject = value.reject.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let rewrites = value.rewrites.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let rate_limit = value.rate_limit
+            .map(|rate_limit| util::can::CanRateLimit { frames_per_second: rate_limit.frames_per_second });
+
+        Ok(Model { accept, reject, rewrites, rate_limit })
+    }
+}
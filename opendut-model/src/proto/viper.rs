@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Duration;
 use opendut_util::conversion;
 use opendut_util::proto::ConversionResult;
 use crate::viper::ViperRunParameterKey;
@@ -51,6 +52,7 @@ conversion! {
             id: Some(value.id.into()),
             name: Some(value.name.into()),
             url: Some(value.url.into()),
+            namespace: Some(value.namespace.into()),
         }
     }
 
@@ -64,7 +66,14 @@ conversion! {
         let url = extract!(value.url)?
             .try_into()?;
 
-        Ok(Model { id, name, url })
+
+        // Descriptors stored before the introduction of namespaces belong to the default namespace
+        let namespace = value.namespace
+            .map(crate::namespace::NamespaceName::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Model { id, name, url, namespace })
     }
 }
 
@@ -93,6 +102,8 @@ conversion! {
             source: Some(value.source.into()),
             suite: Some(value.suite.into()),
             parameters,
+            timeout_milliseconds: value.timeout.map(|timeout| timeout.as_millis() as u64),
+            namespace: Some(value.namespace.into()),
         }
     }
 
@@ -115,7 +126,16 @@ conversion! {
             })
             .collect::<Result<HashMap<_, _>, _>>()?;
 
-        Ok(Model { id, source, suite, parameters })
+        let timeout = value.timeout_milliseconds.map(Duration::from_millis);
+
+
+        // Descriptors stored before the introduction of namespaces belong to the default namespace
+        let namespace = value.namespace
+            .map(crate::namespace::NamespaceName::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Model { id, source, suite, parameters, timeout, namespace })
     }
 }
 
@@ -171,6 +191,7 @@ conversion! {
         Proto {
             id: Some(value.id.into()),
             cluster: Some(value.cluster.into()),
+            peer: value.peer.map(Into::into),
         }
     }
 
@@ -181,6 +202,122 @@ conversion! {
         let cluster = extract!(value.cluster)?
             .try_into()?;
 
-        Ok(Model { id, cluster })
+        let peer = value.peer
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        Ok(Model { id, cluster, peer })
+    }
+}
+
+
+//
+// ViperRunState
+//
+
+conversion! {
+    type Model = crate::viper::ViperRunState;
+    type Proto = ViperRunState;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            id: Some(value.id.into()),
+            peer: Some(value.peer.into()),
+            status: Some(value.status.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?
+            .try_into()?;
+
+        let peer = extract!(value.peer)?
+            .try_into()?;
+
+        let status = extract!(value.status)?
+            .try_into()?;
+
+        Ok(Model { id, peer, status })
+    }
+}
+
+conversion! {
+    type Model = crate::viper::ViperRunStatus;
+    type Proto = ViperRunStatus;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Scheduled => viper_run_status::Kind::Scheduled(ViperRunStatusScheduled {}),
+            Model::Compiling => viper_run_status::Kind::Compiling(ViperRunStatusCompiling {}),
+            Model::Running(progress) => viper_run_status::Kind::Running(progress.into()),
+            Model::Passed => viper_run_status::Kind::Passed(ViperRunStatusPassed {}),
+            Model::Failed => viper_run_status::Kind::Failed(ViperRunStatusFailed {}),
+            Model::Error { cause } => viper_run_status::Kind::Error(ViperRunStatusError { cause }),
+        };
+        Proto { kind: Some(kind) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let status = match extract!(value.kind)? {
+            viper_run_status::Kind::Scheduled(_) => Model::Scheduled,
+            viper_run_status::Kind::Compiling(_) => Model::Compiling,
+            viper_run_status::Kind::Running(progress) => Model::Running(progress.try_into()?),
+            viper_run_status::Kind::Passed(_) => Model::Passed,
+            viper_run_status::Kind::Failed(_) => Model::Failed,
+            viper_run_status::Kind::Error(ViperRunStatusError { cause }) => Model::Error { cause },
+        };
+        Ok(status)
+    }
+}
+
+conversion! {
+    type Model = crate::viper::ViperRunProgress;
+    type Proto = ViperRunProgress;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            total_tests: value.total_tests,
+            passed_tests: value.passed_tests,
+            failed_tests: value.failed_tests,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        Ok(Model {
+            total_tests: value.total_tests,
+            passed_tests: value.passed_tests,
+            failed_tests: value.failed_tests,
+        })
+    }
+}
+
+
+//
+// ViperRunResult
+//
+
+conversion! {
+    type Model = crate::viper::ViperRunResult;
+    type Proto = ViperRunResult;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            id: Some(value.id.into()),
+            peer: Some(value.peer.into()),
+            report: Some(value.report.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let id = extract!(value.id)?
+            .try_into()?;
+
+        let peer = extract!(value.peer)?
+            .try_into()?;
+
+        let report = extract!(value.report)?
+            .try_into()?;
+
+        Ok(Model { id, peer, report })
     }
 }
@@ -47,3 +47,37 @@ impl From<Uuid> for Id {
         }
     }
 }
+
+/// Revision of a stored resource, which is incremented with every update.
+///
+/// An update is only accepted, if it is based on the latest revision of the resource.
+/// Newly created resources start with [`Revision::INITIAL`].
+#[derive(Copy, Debug, Clone, Default, Eq, Hash, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Revision(pub u64);
+
+impl Revision {
+    pub const INITIAL: Revision = Revision(0);
+
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl fmt::Display for Revision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for Revision {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Revision> for u64 {
+    fn from(value: Revision) -> Self {
+        value.0
+    }
+}
@@ -11,5 +11,61 @@ pub enum ClusterDescriptorSpecification {
 pub struct ClusterDescriptorSpecificationV1 {
     #[serde(default)]
     pub leader_id: Uuid,
-    pub devices: Vec<Uuid>
+    #[serde(default)]
+    pub devices: Vec<Uuid>,
+    #[serde(default)]
+    pub device_selectors: Vec<DeviceSelectorSpecification>,
+    #[serde(default)]
+    pub topology: ClusterTopologySpecification,
+    #[serde(default)]
+    pub can_rules: Vec<DeviceCanRulesSpecification>,
+    #[serde(default)]
+    pub restbus_simulations: Vec<DeviceRestbusSimulationSpecification>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct DeviceSelectorSpecification {
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(tag="kind", rename_all="kebab-case")]
+pub enum ClusterTopologySpecification {
+    #[default]
+    Star,
+    FullMesh,
+    Custom { links: Vec<ClusterLinkSpecification> },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct ClusterLinkSpecification {
+    pub peer_a: Uuid,
+    pub peer_b: Uuid,
+}
+
+/// CAN identifiers and masks are specified in hexadecimal, e.g. `7DF` or `100:700`, rewrites as `<from>-><to>`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct DeviceCanRulesSpecification {
+    pub device: Uuid,
+    #[serde(default)]
+    pub accept: Vec<String>,
+    #[serde(default)]
+    pub reject: Vec<String>,
+    #[serde(default)]
+    pub rewrite: Vec<String>,
+    /// maximum number of frames per second and CAN identifier
+    #[serde(default)]
+    pub rate_limit: Option<u32>,
+}
+
+/// Path of the ARXML file on the peer of the device and the names of the ECUs to simulate.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct DeviceRestbusSimulationSpecification {
+    pub device: Uuid,
+    pub arxml_file: String,
+    pub ecus: Vec<String>,
 }
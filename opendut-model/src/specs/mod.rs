@@ -2,6 +2,7 @@
 pub mod cluster;
 pub mod parse;
 pub mod peer;
+pub mod viper;
 
 use serde::Deserialize;
 use strum::Display;
@@ -32,4 +33,7 @@ pub enum SpecificationVersion {
 pub enum Specification {
     PeerDescriptorSpecification(peer::PeerDescriptorSpecification),
     ClusterDescriptorSpecification(cluster::ClusterDescriptorSpecification),
+    ViperSourceDescriptorSpecification(viper::ViperSourceDescriptorSpecification),
+    ViperRunDescriptorSpecification(viper::ViperRunDescriptorSpecification),
+    ViperRunDeploymentSpecification(viper::ViperRunDeploymentSpecification),
 }
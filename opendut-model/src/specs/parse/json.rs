@@ -90,5 +90,29 @@ fn parse_spec(kind: ResourceKind, version: SpecificationVersion, spec: Value) ->
         (ResourceKind::PeerDescriptor, _) => {
             Err(ParseSpecificationError::UnknownVersion { kind, version })
         }
+        (ResourceKind::ViperSourceDescriptor, SpecificationVersion::V1) => {
+            let spec = serde_json::from_value::<specs::viper::ViperSourceDescriptorSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalJsonSpecification { cause } )?;
+            Ok(Specification::ViperSourceDescriptorSpecification(specs::viper::ViperSourceDescriptorSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperSourceDescriptor, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
+        (ResourceKind::ViperRunDescriptor, SpecificationVersion::V1) => {
+            let spec = serde_json::from_value::<specs::viper::ViperRunDescriptorSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalJsonSpecification { cause } )?;
+            Ok(Specification::ViperRunDescriptorSpecification(specs::viper::ViperRunDescriptorSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperRunDescriptor, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
+        (ResourceKind::ViperRunDeployment, SpecificationVersion::V1) => {
+            let spec = serde_json::from_value::<specs::viper::ViperRunDeploymentSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalJsonSpecification { cause } )?;
+            Ok(Specification::ViperRunDeploymentSpecification(specs::viper::ViperRunDeploymentSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperRunDeployment, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
     }
 }
@@ -27,4 +27,7 @@ pub enum ParseSpecificationError {
 pub enum ResourceKind {
     PeerDescriptor,
     ClusterDescriptor,
+    ViperSourceDescriptor,
+    ViperRunDescriptor,
+    ViperRunDeployment,
 }
@@ -100,6 +100,30 @@ fn parse_spec(kind: ResourceKind, version: SpecificationVersion, spec: Value) ->
         (ResourceKind::PeerDescriptor, _) => {
             Err(ParseSpecificationError::UnknownVersion { kind, version })
         }
+        (ResourceKind::ViperSourceDescriptor, SpecificationVersion::V1) => {
+            let spec = serde_yaml::from_value::<viper::ViperSourceDescriptorSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalYamlSpecification { cause } )?;
+            Ok(Specification::ViperSourceDescriptorSpecification(viper::ViperSourceDescriptorSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperSourceDescriptor, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
+        (ResourceKind::ViperRunDescriptor, SpecificationVersion::V1) => {
+            let spec = serde_yaml::from_value::<viper::ViperRunDescriptorSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalYamlSpecification { cause } )?;
+            Ok(Specification::ViperRunDescriptorSpecification(viper::ViperRunDescriptorSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperRunDescriptor, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
+        (ResourceKind::ViperRunDeployment, SpecificationVersion::V1) => {
+            let spec = serde_yaml::from_value::<viper::ViperRunDeploymentSpecificationV1>(spec)
+                .map_err(|cause| ParseSpecificationError::IllegalYamlSpecification { cause } )?;
+            Ok(Specification::ViperRunDeploymentSpecification(viper::ViperRunDeploymentSpecification::V1(spec)))
+        }
+        (ResourceKind::ViperRunDeployment, _) => {
+            Err(ParseSpecificationError::UnknownVersion { kind, version })
+        }
     }
 }
 
@@ -300,4 +324,91 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_try_from_with_viper_run_parameters() -> googletest::Result<()> {
+        let document_string = YamlSpecificationDocument::try_from_yaml_str(r#"
+            kind: ViperRunDescriptor
+            version: v1
+            metadata:
+              id: 0b52a2f6-7ad0-4d33-9f8c-0c3e9a3fa2a1
+              name: NightlyRegression
+            spec:
+              source-id: 6d727331-8b2c-4300-abfa-45141d98c554
+              suite: regression_suite
+              timeout: 300
+              parameters:
+                verbose: true
+                retries: 3
+                target: hil-rig-3
+        "#)?;
+
+        let result = SpecificationDocument::try_from(document_string)?;
+
+        let Specification::ViperRunDescriptorSpecification(viper::ViperRunDescriptorSpecification::V1(run)) = result.spec else {
+            panic!("Specification is not a test suite run.")
+        };
+        verify_that!(run.timeout, some(eq(300)))?;
+        verify_that!(run.parameters.get("verbose"), some(eq(&viper::ViperRunParameterValueSpecification::Boolean(true))))?;
+        verify_that!(run.parameters.get("retries"), some(eq(&viper::ViperRunParameterValueSpecification::Number(3))))?;
+        verify_that!(run.parameters.get("target"), some(eq(&viper::ViperRunParameterValueSpecification::Text(String::from("hil-rig-3")))))?;
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_try_from_with_executor_parameters_selected_by_kind() -> googletest::Result<()> {
+        let document_string = YamlSpecificationDocument::try_from_yaml_str(r#"
+            kind: PeerDescriptor
+            version: v1
+            metadata:
+              id: 140f29fd-336b-48f7-9936-6b1892574543
+              name: TheForgottenName
+            spec:
+              network:
+                interfaces: []
+              topology:
+                devices: []
+              executors:
+                - id: da6ad5f7-ea45-4a11-aadf-4408bdb69e8e
+                  kind: executable
+                  parameters:
+                    path: /usr/bin/sleep
+                    args:
+                    - "60"
+        "#)?;
+
+        let result = SpecificationDocument::try_from(document_string)?;
+
+        let Specification::PeerDescriptorSpecification(peer::PeerDescriptorSpecification::V1(peer)) = result.spec else {
+            panic!("Specification is not a peer descriptor.")
+        };
+        let Some(peer::ExecutorParametersSpecification::Executable(Some(parameters))) = peer.executors.first().map(|executor| &executor.parameters) else {
+            panic!("Executor is not an executable with parameters.")
+        };
+        verify_that!(parameters.path, eq("/usr/bin/sleep"))?;
+
+        let document_string = YamlSpecificationDocument::try_from_yaml_str(r#"
+            kind: PeerDescriptor
+            version: v1
+            metadata:
+              id: 140f29fd-336b-48f7-9936-6b1892574543
+              name: TheForgottenName
+            spec:
+              network:
+                interfaces: []
+              topology:
+                devices: []
+              executors:
+                - id: da6ad5f7-ea45-4a11-aadf-4408bdb69e8e
+                  kind: container
+                  parameters:
+                    path: /usr/bin/sleep
+        "#)?;
+
+        let result = SpecificationDocument::try_from(document_string);
+        verify_that!(result, err(anything()))?;
+
+        Ok(())
+    }
 }
@@ -73,15 +73,16 @@ pub struct DeviceSpecificationV1 {
 pub struct ExecutorSpecificationV1 {
     pub id: Uuid,
     pub results_url: Option<String>,
-    pub kind: SpecificationExecutorKind,
-    pub parameters: Option<ExecutorConfigurationSpecification>
+    #[serde(flatten)]
+    pub parameters: ExecutorParametersSpecification,
 }
 
+/// The `kind` of an executor selects the variant, which its `parameters` are parsed as.
 #[derive(Debug, Deserialize)]
-#[serde(rename_all="kebab-case")]
-pub enum SpecificationExecutorKind {
-    Executable,
-    Container,
+#[serde(tag="kind", content="parameters", rename_all="kebab-case")]
+pub enum ExecutorParametersSpecification {
+    Executable(Option<ExecutableConfigurationSpecification>),
+    Container(Option<ExecutorConfigurationSpecification>),
 }
 
 #[derive(Debug, Deserialize)]
@@ -98,6 +99,28 @@ pub struct ExecutorConfigurationSpecification {
     pub command_args: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct ExecutableConfigurationSpecification {
+    pub path: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub envs: Vec<SpecificationEnvVariable>,
+    pub working_dir: Option<String>,
+    #[serde(default)]
+    pub restart_policy: SpecificationRestartPolicy,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub enum SpecificationRestartPolicy {
+    #[default]
+    Never,
+    OnFailure,
+    Always,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all="kebab-case")]
 pub enum SpecificationEngineKind {
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::fmt;
+use serde::Deserialize;
+use url::Url;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum ViperSourceDescriptorSpecification {
+    V1(ViperSourceDescriptorSpecificationV1)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct ViperSourceDescriptorSpecificationV1 {
+    pub url: Url,
+}
+
+/// The name in the metadata of a run is not stored, as runs are identified by their ID only.
+#[derive(Debug)]
+pub enum ViperRunDescriptorSpecification {
+    V1(ViperRunDescriptorSpecificationV1)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct ViperRunDescriptorSpecificationV1 {
+    pub source_id: Uuid,
+    pub suite: String,
+    #[serde(default)]
+    pub parameters: HashMap<String, ViperRunParameterValueSpecification>,
+    /// time limit for each test of the run in seconds
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ViperRunParameterValueSpecification {
+    Boolean(bool),
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for ViperRunParameterValueSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViperRunParameterValueSpecification::Boolean(value) => write!(f, "{value}"),
+            ViperRunParameterValueSpecification::Number(value) => write!(f, "{value}"),
+            ViperRunParameterValueSpecification::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The ID in the metadata of a deployment is the ID of the deployed run.
+#[derive(Debug)]
+pub enum ViperRunDeploymentSpecification {
+    V1(ViperRunDeploymentSpecificationV1)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all="kebab-case")]
+pub struct ViperRunDeploymentSpecificationV1 {
+    pub cluster_id: Uuid,
+    /// peer in the cluster, which should execute the run, defaults to the cluster leader
+    #[serde(default)]
+    pub peer_id: Option<Uuid>,
+}
@@ -0,0 +1,411 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Mask of the bits, which make up a standard or extended CAN identifier.
+pub const CAN_ID_MASK: u32 = 0x1FFF_FFFF;
+/// Highest standard CAN identifier. Higher identifiers are sent as extended identifiers.
+pub const CAN_MAX_STANDARD_ID: u32 = 0x7FF;
+/// Maximum number of routes per CAN bridge, into which the rules of a device may be split, see [`CanFrameRules::validate()`].
+pub const MAX_CAN_ROUTES: usize = 64;
+
+/// Matches CAN frames, whose identifier equals `id` in all bits set in `mask`.
+/// Standard and extended identifiers are matched by their numeric value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CanIdFilter {
+    pub id: u32,
+    pub mask: u32,
+}
+
+impl CanIdFilter {
+    /// Matches all CAN frames.
+    pub const ANY: CanIdFilter = CanIdFilter { id: 0, mask: 0 };
+
+    pub fn new(id: u32, mask: u32) -> Result<Self, IllegalCanFrameRule> {
+        Ok(Self {
+            id: validate_can_id(id)?,
+            mask: mask & CAN_ID_MASK,
+        })
+    }
+
+    /// Matches exactly the given identifier.
+    pub fn exact(id: u32) -> Result<Self, IllegalCanFrameRule> {
+        Self::new(id, CAN_ID_MASK)
+    }
+
+    pub fn matches(&self, id: u32) -> bool {
+        (id ^ self.id) & self.mask == 0
+    }
+
+    fn is_disjoint_from(&self, other: &CanIdFilter) -> bool {
+        (self.id ^ other.id) & self.mask & other.mask != 0
+    }
+
+    /// Splits this filter into disjoint filters, which together match all identifiers matched by this filter, but not by `other`.
+    fn subtract(self, other: &CanIdFilter) -> Vec<CanIdFilter> {
+        if self.is_disjoint_from(other) {
+            return vec![self];
+        }
+
+        let differing_bits = other.mask & !self.mask;
+        let mut result = vec![];
+        let mut remainder = self;
+        for bit in (0..u32::BITS).map(|index| 1 << index).filter(|bit| differing_bits & bit != 0) {
+            let mask = remainder.mask | bit;
+            result.push(CanIdFilter { id: (remainder.id & !bit) | (!other.id & bit), mask });
+            remainder = CanIdFilter { id: (remainder.id & !bit) | (other.id & bit), mask };
+        }
+        result
+    }
+}
+
+impl Display for CanIdFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.mask == CAN_ID_MASK {
+            write!(f, "{:X}", self.id)
+        } else {
+            write!(f, "{:X}:{:X}", self.id, self.mask)
+        }
+    }
+}
+
+/// Parses a filter in the notation of `cangw`, i.e. `<id>:<mask>` with hexadecimal values, or only `<id>` to match exactly one identifier.
+impl FromStr for CanIdFilter {
+    type Err = IllegalCanFrameRule;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once(':') {
+            Some((id, mask)) => CanIdFilter::new(parse_hex(id)?, parse_hex(mask)?),
+            None => CanIdFilter::exact(parse_hex(value)?),
+        }
+    }
+}
+
+/// Replaces the identifier `from` with `to`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CanIdRewrite {
+    pub from: u32,
+    pub to: u32,
+}
+
+impl CanIdRewrite {
+    pub fn new(from: u32, to: u32) -> Result<Self, IllegalCanFrameRule> {
+        Ok(Self {
+            from: validate_can_id(from)?,
+            to: validate_can_id(to)?,
+        })
+    }
+}
+
+impl Display for CanIdRewrite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:X}->{:X}", self.from, self.to)
+    }
+}
+
+/// Parses a rewrite in the notation `<from>-><to>` with hexadecimal identifiers.
+impl FromStr for CanIdRewrite {
+    type Err = IllegalCanFrameRule;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (from, to) = value.split_once("->")
+            .ok_or_else(|| IllegalCanFrameRule::InvalidRewrite { value: value.to_owned() })?;
+        CanIdRewrite::new(parse_hex(from)?, parse_hex(to)?)
+    }
+}
+
+/// Limits the number of frames, which are forwarded per second for each CAN identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CanRateLimit {
+    pub frames_per_second: u32,
+}
+
+/// Limits the rate of the frames, whose identifier matches any of the `filters`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CanRateLimitRule {
+    pub filters: Vec<CanIdFilter>,
+    pub rate_limit: CanRateLimit,
+}
+
+impl CanRateLimitRule {
+    pub fn matches(&self, id: u32) -> bool {
+        self.filters.iter().any(|filter| filter.matches(id))
+    }
+}
+
+/// Rules for forwarding CAN frames.
+/// A frame is forwarded, if it matches any of the `accept` filters (or there are none) and none of the `reject` filters.
+/// Afterwards, its identifier is replaced according to the first matching rewrite.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CanFrameRules {
+    pub accept: Vec<CanIdFilter>,
+    pub reject: Vec<CanIdFilter>,
+    pub rewrites: Vec<CanIdRewrite>,
+    pub rate_limit: Option<CanRateLimit>,
+}
+
+impl CanFrameRules {
+    /// Whether all frames are forwarded unchanged.
+    pub fn forwards_all(&self) -> bool {
+        self.accept.is_empty() && self.reject.is_empty() && self.rewrites.is_empty() && self.rate_limit.is_none()
+    }
+
+    pub fn is_forwarded(&self, id: u32) -> bool {
+        let accepted = self.accept.is_empty() || self.accept.iter().any(|filter| filter.matches(id));
+        let rejected = self.reject.iter().any(|filter| filter.matches(id));
+        accepted && !rejected
+    }
+
+    /// Determines the identifier, with which a frame is forwarded, or `None` if it is not forwarded.
+    /// The rate limit is not taken into account.
+    pub fn apply(&self, id: u32) -> Option<u32> {
+        if !self.is_forwarded(id) {
+            return None;
+        }
+        let rewritten = self.rewrites.iter()
+            .find(|rewrite| rewrite.from == id)
+            .map(|rewrite| rewrite.to)
+            .unwrap_or(id);
+        Some(rewritten)
+    }
+
+    /// Rewrites which apply to forwarded frames, with at most one rewrite per identifier.
+    pub fn effective_rewrites(&self) -> Vec<CanIdRewrite> {
+        let mut rewrites: Vec<CanIdRewrite> = vec![];
+        for rewrite in &self.rewrites {
+            if self.is_forwarded(rewrite.from) && rewrites.iter().all(|existing| existing.from != rewrite.from) {
+                rewrites.push(*rewrite);
+            }
+        }
+        rewrites
+    }
+
+    /// Disjoint filters, which together match all identifiers forwarded without a rewrite.
+    /// This allows expressing the rules with tools supporting only a single filter per route, like `cangw`.
+    pub fn unmodified_filters(&self) -> Vec<CanIdFilter> {
+        self.split_unmodified_filters(usize::MAX)
+            .expect("Number of filters should not be limited.")
+    }
+
+    /// Checks that the rules can be expressed with at most [`MAX_CAN_ROUTES`] routes,
+    /// i.e. one route per filter of [`CanFrameRules::unmodified_filters()`] and one per effective rewrite.
+    pub fn validate(&self) -> Result<(), IllegalCanFrameRule> {
+        let too_many_routes = IllegalCanFrameRule::TooManyRoutes { max: MAX_CAN_ROUTES };
+
+        if self.rewrites.len() > MAX_CAN_ROUTES {
+            return Err(too_many_routes);
+        }
+        let max_filters = MAX_CAN_ROUTES - self.effective_rewrites().len();
+        self.split_unmodified_filters(max_filters)
+            .map(|_| ())
+            .ok_or(too_many_routes)
+    }
+
+    /// Rate limit of these rules, applied to the identifiers with which the frames are forwarded.
+    pub fn rate_limit_rule(&self) -> Option<CanRateLimitRule> {
+        let rate_limit = self.rate_limit?;
+        let rewritten = self.effective_rewrites().into_iter()
+            .map(|rewrite| CanIdFilter { id: rewrite.to, mask: CAN_ID_MASK });
+        let filters = self.unmodified_filters().into_iter()
+            .chain(rewritten)
+            .collect();
+        Some(CanRateLimitRule { filters, rate_limit })
+    }
+
+    /// Returns `None`, as soon as more than `max_filters` filters are required.
+    fn split_unmodified_filters(&self, max_filters: usize) -> Option<Vec<CanIdFilter>> {
+        let accept = if self.accept.is_empty() { vec![CanIdFilter::ANY] } else { self.accept.clone() };
+        let rewritten = self.effective_rewrites().into_iter()
+            .map(|rewrite| CanIdFilter { id: rewrite.from, mask: CAN_ID_MASK })
+            .collect::<Vec<_>>();
+
+        let mut filters: Vec<CanIdFilter> = vec![];
+        for filter in accept {
+            let mut pieces = vec![filter];
+            for excluded in filters.iter().chain(&self.reject).chain(&rewritten) {
+                pieces = pieces.into_iter()
+                    .flat_map(|piece| piece.subtract(excluded))
+                    .collect();
+                if filters.len() + pieces.len() > max_filters {
+                    return None;
+                }
+            }
+            if filters.len() + pieces.len() > max_filters {
+                return None;
+            }
+            filters.extend(pieces);
+        }
+        Some(filters)
+    }
+}
+
+impl Display for CanFrameRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn join<T: Display>(values: &[T]) -> String {
+            values.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+        }
+
+        let mut rules = vec![];
+        if !self.accept.is_empty() {
+            rules.push(format!("accept: {}", join(&self.accept)));
+        }
+        if !self.reject.is_empty() {
+            rules.push(format!("reject: {}", join(&self.reject)));
+        }
+        if !self.rewrites.is_empty() {
+            rules.push(format!("rewrite: {}", join(&self.rewrites)));
+        }
+        if let Some(rate_limit) = self.rate_limit {
+            rules.push(format!("rate limit: {}/s", rate_limit.frames_per_second));
+        }
+
+        if rules.is_empty() {
+            write!(f, "forward all")
+        } else {
+            write!(f, "{}", rules.join("; "))
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum IllegalCanFrameRule {
+    #[error("CAN identifier <{value:X}> exceeds the highest extended identifier <{CAN_ID_MASK:X}>.")]
+    IdOutOfRange { value: u32 },
+    #[error("<{value}> is not a hexadecimal CAN identifier.")]
+    InvalidHex { value: String },
+    #[error("Invalid CAN identifier rewrite <{value}>. Expected the format '<from>-><to>', e.g. '123->456'.")]
+    InvalidRewrite { value: String },
+    #[error("The rules require more than {max} routes per CAN bridge. Use fewer or broader filters and rewrites.")]
+    TooManyRoutes { max: usize },
+}
+
+fn validate_can_id(id: u32) -> Result<u32, IllegalCanFrameRule> {
+    if id > CAN_ID_MASK {
+        Err(IllegalCanFrameRule::IdOutOfRange { value: id })
+    } else {
+        Ok(id)
+    }
+}
+
+fn parse_hex(value: &str) -> Result<u32, IllegalCanFrameRule> {
+    let trimmed = value.trim();
+    let digits = trimmed.strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    u32::from_str_radix(digits, 16)
+        .map_err(|_| IllegalCanFrameRule::InvalidHex { value: value.to_owned() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_filters_and_rewrites() -> anyhow::Result<()> {
+        assert_eq!(CanIdFilter::from_str("7DF")?, CanIdFilter { id: 0x7df, mask: CAN_ID_MASK });
+        assert_eq!(CanIdFilter::from_str("0x100:700")?, CanIdFilter { id: 0x100, mask: 0x700 });
+        assert_eq!(CanIdRewrite::from_str("123->18FF0001")?, CanIdRewrite { from: 0x123, to: 0x18ff_0001 });
+
+        assert_eq!(CanIdFilter::from_str("xyz"), Err(IllegalCanFrameRule::InvalidHex { value: String::from("xyz") }));
+        assert_eq!(CanIdFilter::from_str("20000000"), Err(IllegalCanFrameRule::IdOutOfRange { value: 0x2000_0000 }));
+        assert!(matches!(CanIdRewrite::from_str("123"), Err(IllegalCanFrameRule::InvalidRewrite { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn should_filter_and_rewrite_identifiers() -> anyhow::Result<()> {
+        let rules = CanFrameRules {
+            accept: vec![CanIdFilter::from_str("100:700")?, CanIdFilter::from_str("7DF")?],
+            reject: vec![CanIdFilter::from_str("1F0:7F0")?, CanIdFilter::from_str("7DF")?],
+            rewrites: vec![CanIdRewrite::from_str("123->523")?, CanIdRewrite::from_str("7DF->7E0")?],
+            rate_limit: None,
+        };
+
+        assert_eq!(rules.apply(0x100), Some(0x100));
+        assert_eq!(rules.apply(0x123), Some(0x523));
+        assert_eq!(rules.apply(0x1f5), None, "rejected");
+        assert_eq!(rules.apply(0x7df), None, "rejected, even though accepted and rewritten");
+        assert_eq!(rules.apply(0x200), None, "not accepted");
+
+        assert_eq!(rules.effective_rewrites(), vec![CanIdRewrite { from: 0x123, to: 0x523 }]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_forward_everything_without_rules() {
+        let rules = CanFrameRules::default();
+
+        assert!(rules.forwards_all());
+        assert_eq!(rules.apply(0x1234_5678), Some(0x1234_5678));
+        assert_eq!(rules.unmodified_filters(), vec![CanIdFilter::ANY]);
+    }
+
+    #[test]
+    fn should_express_the_rules_as_disjoint_filters() -> anyhow::Result<()> {
+        let rules = CanFrameRules {
+            accept: vec![CanIdFilter::from_str("100:700")?, CanIdFilter::from_str("180:780")?],
+            reject: vec![CanIdFilter::from_str("1F0:7F0")?],
+            rewrites: vec![CanIdRewrite::from_str("123->523")?],
+            rate_limit: None,
+        };
+
+        let filters = rules.unmodified_filters();
+
+        for (index, filter) in filters.iter().enumerate() {
+            for other in &filters[index + 1..] {
+                assert!(filter.is_disjoint_from(other), "{filter} and {other} overlap");
+            }
+        }
+        for id in 0..=0xfff {
+            let matched = filters.iter().filter(|filter| filter.matches(id)).count();
+            let expected = usize::from(rules.apply(id) == Some(id) && id != 0x123);
+            assert_eq!(matched, expected, "identifier {id:X}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_rules_which_require_too_many_routes() -> anyhow::Result<()> {
+        let rules = CanFrameRules {
+            reject: vec![CanIdFilter::exact(0x7df)?],
+            rewrites: vec![CanIdRewrite::new(0x123, 0x523)?],
+            ..CanFrameRules::default()
+        };
+        assert_eq!(rules.validate(), Ok(()));
+
+        let rules = CanFrameRules {
+            reject: vec![CanIdFilter::exact(0x7df)?, CanIdFilter::exact(0x7e0)?, CanIdFilter::exact(0x7e8)?],
+            ..CanFrameRules::default()
+        };
+        assert_eq!(rules.validate(), Err(IllegalCanFrameRule::TooManyRoutes { max: MAX_CAN_ROUTES }));
+
+        let rules = CanFrameRules {
+            rewrites: (0..=MAX_CAN_ROUTES as u32).map(|id| CanIdRewrite::new(id, id + 0x100)).collect::<Result<_, _>>()?,
+            ..CanFrameRules::default()
+        };
+        assert_eq!(rules.validate(), Err(IllegalCanFrameRule::TooManyRoutes { max: MAX_CAN_ROUTES }));
+        Ok(())
+    }
+
+    #[test]
+    fn should_apply_the_rate_limit_to_the_forwarded_identifiers() -> anyhow::Result<()> {
+        let rules = CanFrameRules {
+            accept: vec![CanIdFilter::new(0x100, 0x700)?],
+            rewrites: vec![CanIdRewrite::new(0x123, 0x523)?],
+            rate_limit: Some(CanRateLimit { frames_per_second: 100 }),
+            ..CanFrameRules::default()
+        };
+
+        let rule = rules.rate_limit_rule().expect("Rules with a rate limit should have a rate limit rule.");
+
+        assert_eq!(rule.rate_limit, CanRateLimit { frames_per_second: 100 });
+        assert!(rule.matches(0x100));
+        assert!(rule.matches(0x523));
+        assert!(!rule.matches(0x123), "Rewritten identifier is not forwarded.");
+        assert!(!rule.matches(0x200), "Identifier is not forwarded.");
+
+        assert_eq!(CanFrameRules::default().rate_limit_rule(), None);
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 pub mod net;
 pub mod brotli;
+pub mod can;
 
 use std::fmt::Display;
 
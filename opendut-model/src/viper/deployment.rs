@@ -1,4 +1,5 @@
 use crate::cluster::ClusterId;
+use crate::peer::PeerId;
 use crate::viper::ViperRunId;
 
 
@@ -6,4 +7,6 @@ use crate::viper::ViperRunId;
 pub struct ViperRunDeployment {
     pub id: ViperRunId,
     pub cluster: ClusterId,
+    /// Peer in the cluster, which should execute the run. Defaults to the cluster leader, if not specified.
+    pub peer: Option<PeerId>,
 }
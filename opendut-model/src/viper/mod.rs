@@ -1,7 +1,11 @@
 mod deployment;
+mod result;
 mod run;
 mod source;
+mod state;
 
 pub use deployment::*;
+pub use result::*;
 pub use run::*;
 pub use source::*;
+pub use state::*;
@@ -0,0 +1,12 @@
+use opendut_viper_rt::run::TestSuiteReport;
+use crate::peer::PeerId;
+use crate::viper::ViperRunId;
+
+
+/// Result of a finished [`ViperRunDeployment`](crate::viper::ViperRunDeployment), as reported by the executing peer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViperRunResult {
+    pub id: ViperRunId,
+    pub peer: PeerId,
+    pub report: TestSuiteReport,
+}
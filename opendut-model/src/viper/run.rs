@@ -1,15 +1,21 @@
 use std::collections::HashMap;
+use std::time::Duration;
 use opendut_viper_rt::common::TestSuiteIdentifier;
 use crate::create_id_type;
+use crate::namespace::NamespaceName;
 use crate::viper::ViperSourceId;
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ViperRunDescriptor {
     pub id: ViperRunId,
     pub source: ViperSourceId,
     pub suite: TestSuiteIdentifier,
     pub parameters: HashMap<ViperRunParameterKey, ViperRunParameterValue>,
+    /// Time limit for each test of the run, overriding the time limits declared in the test suite.
+    pub timeout: Option<Duration>,
+    /// Namespace the run belongs to, e.g. a project or team.
+    pub namespace: NamespaceName,
 }
 
 
@@ -19,7 +25,7 @@ create_id_type!(ViperRunId);
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ViperRunParameterKey { pub inner: String }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ViperRunParameterValue {
     Boolean(bool),
     Number(i64),
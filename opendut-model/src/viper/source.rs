@@ -4,6 +4,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use url::Url;
 use crate::create_id_type;
+use crate::namespace::NamespaceName;
 
 
 create_id_type!(ViperSourceId);
@@ -109,4 +110,7 @@ pub struct ViperSourceDescriptor {
     pub id: ViperSourceId,
     pub name: ViperSourceName,
     pub url: Url,
+    /// Namespace the source belongs to, e.g. a project or team.
+    #[serde(default)]
+    pub namespace: NamespaceName,
 }
@@ -0,0 +1,37 @@
+use crate::peer::PeerId;
+use crate::viper::ViperRunId;
+
+
+/// State of a [`ViperRunDeployment`](crate::viper::ViperRunDeployment), as reported by the executing peer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViperRunState {
+    pub id: ViperRunId,
+    pub peer: PeerId,
+    pub status: ViperRunStatus,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViperRunStatus {
+    /// The run is to be executed by the peer, which did not yet report back.
+    /// If the peer was offline, the run is sent once the peer connects.
+    Scheduled,
+    Compiling,
+    Running(ViperRunProgress),
+    Passed,
+    Failed,
+    /// The run could not be executed, e.g. because the source could not be loaded or compiled.
+    Error { cause: String },
+}
+
+impl ViperRunStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, ViperRunStatus::Passed | ViperRunStatus::Failed | ViperRunStatus::Error { .. })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ViperRunProgress {
+    pub total_tests: u32,
+    pub passed_tests: u32,
+    pub failed_tests: u32,
+}
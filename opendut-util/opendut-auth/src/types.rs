@@ -44,6 +44,15 @@ pub struct MyAdditionalClaims {
     /// Groups of the user (custom claim) may be omitted by identity provider, so we need a default value
     #[serde(default = "MyAdditionalClaims::empty_vector")]
     pub groups: Vec<String>,
+    /// Realm roles of the user, as issued by Keycloak without a custom mapper
+    #[serde(default)]
+    pub realm_access: RealmAccess,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RealmAccess {
+    #[serde(default)]
+    pub roles: Vec<String>,
 }
 
 impl MyAdditionalClaims {
@@ -52,7 +61,9 @@ impl MyAdditionalClaims {
         self.groups.contains(&format!("/{group}"))
     }
     pub fn has_role(&self, role: &str) -> bool {
-        self.roles.contains(&role.to_string())
+        self.roles.iter()
+            .chain(self.realm_access.roles.iter())
+            .any(|claimed_role| claimed_role == role)
     }
 }
 
@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use autosar_data::{AutosarDataError, AutosarModel, CharacterData, Element, ElementName, EnumItem};
+use tracing::{debug, warn};
+
+use crate::arxml_structs::*;
+use crate::arxml_utils::*;
+
+/*
+- Arxml parser that is able to extract all values necessary for a restbus simulation
+
+- Improvements at some stage:
+    - What about TPConfig?
+    - Provide options to store parsed data for quicker restart
+    - be able to manually add stuff to restbus -> provide interface
+*/
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArxmlParserError {
+    #[error("Failed to load ARXML file <{path}>:\n  {cause}")]
+    Load { path: PathBuf, cause: AutosarDataError },
+    #[error("Element <{parent}> is missing the required sub-element <{element}>.")]
+    MissingElement { parent: String, element: ElementName },
+    #[error("Element <{parent}> contains an invalid value: {details}")]
+    InvalidValue { parent: String, details: String },
+}
+
+// Parser structure
+#[derive(Default)]
+pub struct ArxmlParser {
+}
+
+// Use autosar-data library to parse data like in this example:
+// https://github.com/DanielT/autosar-data/blob/main/autosar-data/examples/businfo/main.rs
+impl ArxmlParser {
+    fn handle_isignal_to_pdu_mappings(&self, mapping: &Element,
+        signals: &mut HashMap<String, SignalInfo>,
+        signal_groups: &mut Vec<Element>) -> Result<(), ArxmlParserError>
+    {
+        if let Some(signal) = mapping
+            .get_sub_element(ElementName::ISignalRef)
+            .and_then(|elem| elem.get_reference_target().ok())
+        {
+            let refpath = get_required_string(mapping, ElementName::ISignalRef)?;
+
+            let name = get_required_item_name(&signal)?;
+
+            let byte_order = get_required_string(mapping, ElementName::PackingByteOrder)?;
+
+            let start_pos = get_required_int_value(mapping, ElementName::StartPosition)?;
+
+            let length = get_required_int_value(&signal, ElementName::Length)?;
+
+            let init_values = match signal.get_sub_element(ElementName::InitValue) {
+                Some(init_value_elem) => process_init_value(&init_value_elem)?,
+                None => InitValues::NotExist,
+            };
+            signals.insert(refpath, (name, byte_order, start_pos, length, init_values));
+        } else if let Some(signal_group) = mapping
+            .get_sub_element(ElementName::ISignalGroupRef)
+            .and_then(|elem| elem.get_reference_target().ok())
+        {
+            // store the signal group for now
+            signal_groups.push(signal_group);
+        }
+        Ok(())
+    }
+
+    fn handle_isignals(&self, pdu: &Element, grouped_signals: &mut Vec<ISignalGroup>, ungrouped_signals: &mut Vec<ISignal>) -> Result<(), ArxmlParserError> {
+        let mut signals: HashMap<String, SignalInfo> = HashMap::new();
+        let mut signal_groups = Vec::new();
+
+        if let Some(isignal_to_pdu_mappings) = pdu.get_sub_element(ElementName::ISignalToPduMappings) {
+            // collect information about the signals and signal groups
+            for mapping in isignal_to_pdu_mappings.sub_elements() {
+                self.handle_isignal_to_pdu_mappings(&mapping, &mut signals, &mut signal_groups)?;
+            }
+        }
+
+        for signal_group in &signal_groups {
+            process_signal_group(signal_group, &mut signals, grouped_signals)?;
+        }
+
+        for (name, byte_order, start_pos, length, init_values) in signals.into_values() {
+            ungrouped_signals.push(ISignal {
+                name,
+                byte_order: get_byte_order(&byte_order),
+                start_pos,
+                length,
+                init_values,
+            });
+        }
+
+        ungrouped_signals.sort_by_key(|signal| signal.start_pos);
+
+        Ok(())
+    }
+
+    fn handle_isignal_ipdu(&self, pdu: &Element) -> Result<ISignalIPdu, ArxmlParserError> {
+        // Find out these values: ...
+        let mut cyclic_timing_period_value: f64 = 0_f64;
+        let mut cyclic_timing_period_tolerance: Option<TimeRangeTolerance> = None;
+
+        let mut cyclic_timing_offset_value: f64 = 0_f64;
+        let mut cyclic_timing_offset_tolerance: Option<TimeRangeTolerance> = None;
+
+        let mut number_of_repetitions: i64 = 0;
+        let mut repetition_period_value: f64 = 0_f64;
+        let mut repetition_period_tolerance: Option<TimeRangeTolerance> = None;
+
+        if let Some(tx_mode_true_timing) = pdu
+            .get_sub_element(ElementName::IPduTimingSpecifications)
+            .and_then(|elem| elem.get_sub_element(ElementName::IPduTiming))
+            .and_then(|elem| elem.get_sub_element(ElementName::TransmissionModeDeclaration))
+            .and_then(|elem| elem.get_sub_element(ElementName::TransmissionModeTrueTiming))
+        {
+            if let Some(cyclic_timing) = tx_mode_true_timing
+                .get_sub_element(ElementName::CyclicTiming)
+            {
+                get_sub_element_and_time_range(&cyclic_timing, ElementName::TimePeriod, &mut cyclic_timing_period_value, &mut cyclic_timing_period_tolerance);
+
+                get_sub_element_and_time_range(&cyclic_timing, ElementName::TimeOffset, &mut cyclic_timing_offset_value, &mut cyclic_timing_offset_tolerance);
+            }
+            if let Some(event_timing) = tx_mode_true_timing
+                .get_sub_element(ElementName::EventControlledTiming)
+            {
+                number_of_repetitions = get_optional_int_value(&event_timing,
+                    ElementName::NumberOfRepetitions);
+
+                get_sub_element_and_time_range(&event_timing, ElementName::RepetitionPeriod, &mut repetition_period_value, &mut repetition_period_tolerance);
+            }
+        }
+
+        let unused_bit_pattern = get_unused_bit_pattern(pdu)?;
+
+        let mut grouped_signals: Vec<ISignalGroup> = Vec::new();
+
+        let mut ungrouped_signals: Vec<ISignal> = Vec::new();
+
+        self.handle_isignals(pdu, &mut grouped_signals, &mut ungrouped_signals)?;
+
+        Ok(ISignalIPdu {
+            cyclic_timing_period_value,
+            cyclic_timing_period_tolerance,
+            cyclic_timing_offset_value,
+            cyclic_timing_offset_tolerance,
+            number_of_repetitions,
+            repetition_period_value,
+            repetition_period_tolerance,
+            unused_bit_pattern,
+            ungrouped_signals,
+            grouped_signals,
+        })
+    }
+
+    fn handle_nm_pdu(&self, pdu: &Element) -> Result<NmPdu, ArxmlParserError> {
+        let unused_bit_pattern = get_unused_bit_pattern(pdu)?;
+
+        let mut grouped_signals: Vec<ISignalGroup> = Vec::new();
+
+        let mut ungrouped_signals: Vec<ISignal> = Vec::new();
+
+        self.handle_isignals(pdu, &mut grouped_signals, &mut ungrouped_signals)?;
+
+        Ok(NmPdu {
+            unused_bit_pattern,
+            ungrouped_signals,
+            grouped_signals,
+        })
+    }
+
+    // ContainerIPdus and SecuredIPdus are not supported yet. Add support in future in case it is needed.
+
+    fn handle_pdu_mapping(&self, pdu_mapping: &Element) -> Result<PduMapping, String> {
+        let pdu = get_required_reference(pdu_mapping, ElementName::PduRef)
+            .map_err(|error| error.to_string())?;
+
+        let pdu_name = get_required_item_name(&pdu)
+            .map_err(|error| error.to_string())?;
+
+        let byte_order = get_required_string(pdu_mapping, ElementName::PackingByteOrder)
+            .map_err(|error| error.to_string())?;
+
+        let start_position = get_required_int_value(pdu_mapping, ElementName::StartPosition)
+            .map_err(|error| error.to_string())?;
+
+        let pdu_length = get_required_int_value(&pdu, ElementName::Length)
+            .map_err(|error| error.to_string())?;
+
+        let pdu_dynamic_length = get_optional_string(&pdu,
+            ElementName::HasDynamicLength);
+
+        let pdu_category = get_optional_string(&pdu,
+            ElementName::Category);
+
+        let pdu_contained_header_id_short = get_subelement_optional_string(&pdu,
+            ElementName::ContainedIPduProps, ElementName::HeaderIdShortHeader);
+
+        let pdu_contained_header_id_long = get_subelement_optional_string(&pdu,
+            ElementName::ContainedIPduProps, ElementName::HeaderIdLongHeader);
+
+        let pdu_specific = match pdu.element_name() {
+            ElementName::ISignalIPdu => {
+                Pdu::ISignalIPdu(self.handle_isignal_ipdu(&pdu).map_err(|error| error.to_string())?)
+            }
+            ElementName::NmPdu => {
+                Pdu::NmPdu(self.handle_nm_pdu(&pdu).map_err(|error| error.to_string())?)
+            }
+            // Handle more?
+            other => {
+                return Err(format!("PDU type {other} not supported. Will skip it."))
+            }
+        };
+
+        Ok(PduMapping {
+            name: pdu_name,
+            byte_order: get_byte_order(&byte_order),
+            start_position,
+            length: pdu_length,
+            dynamic_length: pdu_dynamic_length,
+            category: pdu_category,
+            contained_header_id_short: pdu_contained_header_id_short,
+            contained_header_id_long: pdu_contained_header_id_long,
+            pdu: pdu_specific,
+        })
+    }
+
+    fn handle_can_frame_triggering(&self, can_frame_triggering: &Element) -> Result<CanFrameTriggering, String> {
+        let can_frame_triggering_name = get_required_item_name(can_frame_triggering)
+            .map_err(|error| error.to_string())?;
+
+        let can_id = get_required_int_value(can_frame_triggering, ElementName::Identifier)
+            .map_err(|error| error.to_string())?;
+
+        let frame = get_required_reference(can_frame_triggering, ElementName::FrameRef)
+            .map_err(|error| error.to_string())?;
+
+        let frame_name = get_required_item_name(&frame)
+            .map_err(|error| error.to_string())?;
+
+        let addressing_mode = if let Some(CharacterData::Enum(value)) = can_frame_triggering
+            .get_sub_element(ElementName::CanAddressingMode)
+            .and_then(|elem| elem.character_data())
+        {
+            value.to_string()
+        } else {
+            EnumItem::Standard.to_string()
+        };
+
+        let frame_rx_behavior = get_optional_string(
+            can_frame_triggering,
+            ElementName::CanFrameRxBehavior);
+
+        let frame_tx_behavior = get_optional_string(
+            can_frame_triggering,
+            ElementName::CanFrameTxBehavior);
+
+        let mut rx_range_lower: i64 = 0;
+        let mut rx_range_upper: i64 = 0;
+        if let Some(range_elem) = can_frame_triggering.get_sub_element(ElementName::RxIdentifierRange) {
+            rx_range_lower = get_required_int_value(&range_elem, ElementName::LowerCanId)
+                .map_err(|error| error.to_string())?;
+            rx_range_upper = get_required_int_value(&range_elem, ElementName::UpperCanId)
+                .map_err(|error| error.to_string())?;
+        }
+
+        let mut rx_ecus: Vec<String> = Vec::new();
+        let mut tx_ecus: Vec<String> = Vec::new();
+
+        process_frame_ports(can_frame_triggering, &can_frame_triggering_name, &mut rx_ecus, &mut tx_ecus)?;
+
+        let frame_length = get_optional_int_value(
+            &frame,
+            ElementName::FrameLength);
+
+        let mut pdu_mappings_vec: Vec<PduMapping> = Vec::new();
+
+        if let Some(mappings) = frame.get_sub_element(ElementName::PduToFrameMappings) {
+            for pdu_mapping in mappings.sub_elements() {
+                pdu_mappings_vec.push(self.handle_pdu_mapping(&pdu_mapping)?);
+            }
+        }
+
+        Ok(CanFrameTriggering {
+            frame_triggering_name: can_frame_triggering_name,
+            frame_name,
+            can_id,
+            addressing_mode,
+            frame_rx_behavior,
+            frame_tx_behavior,
+            rx_range_lower,
+            rx_range_upper,
+            receiver_ecus: rx_ecus,
+            sender_ecus: tx_ecus,
+            frame_length,
+            pdu_mappings: pdu_mappings_vec,
+        })
+    }
+
+    fn handle_can_cluster(&self, can_cluster: &Element) -> Result<CanCluster, String> {
+        let can_cluster_name = get_required_item_name(can_cluster)
+            .map_err(|error| error.to_string())?;
+
+        let can_cluster_conditional = get_required_sub_subelement(
+            can_cluster,
+            ElementName::CanClusterVariants,
+            ElementName::CanClusterConditional)
+            .map_err(|error| error.to_string())?;
+
+        let can_cluster_baudrate = get_optional_int_value(
+            &can_cluster_conditional,
+            ElementName::Baudrate);
+
+        let can_cluster_fd_baudrate = get_optional_int_value(
+            &can_cluster_conditional,
+            ElementName::CanFdBaudrate);
+
+        if can_cluster_baudrate == 0 && can_cluster_fd_baudrate == 0 {
+            return Err(format!("Baudrate and FD Baudrate of CanCluster {can_cluster_name} do not exist or are 0. Skipping this CanCluster."));
+        }
+
+        // iterate over PhysicalChannels and handle the CanFrameTriggerings inside them
+        let Some(physical_channels) = can_cluster_conditional
+            .get_sub_element(ElementName::PhysicalChannels).map(|elem| {
+                elem.sub_elements().filter(|se| se.element_name() == ElementName::CanPhysicalChannel)
+            })
+        else {
+            return Err(format!("Cannot handle physical channels of CanCluster {can_cluster_name}"));
+        };
+
+        let mut can_frame_triggerings: HashMap<i64, CanFrameTriggering> = HashMap::new();
+        for physical_channel in physical_channels {
+            if let Some(frame_triggerings) = physical_channel.get_sub_element(ElementName::FrameTriggerings) {
+                for can_frame_triggering in frame_triggerings.sub_elements() {
+                    match self.handle_can_frame_triggering(&can_frame_triggering) {
+                        Ok(value) => {
+                            can_frame_triggerings.insert(value.can_id, value);
+                        }
+                        Err(error) => warn!("{error}"),
+                    }
+                }
+            }
+        }
+
+        Ok(CanCluster {
+            name: can_cluster_name,
+            baudrate: can_cluster_baudrate,
+            canfd_baudrate: can_cluster_fd_baudrate,
+            can_frame_triggerings,
+        })
+    }
+
+    // Main parsing method. Uses autosar-data libray for parsing ARXML
+    // In the future, it might be extended to support Ethernet, Flexray, ...
+    // Returns the CanClusters by their name
+    pub fn parse_file(&self, file_name: impl AsRef<Path>) -> Result<HashMap<String, CanCluster>, ArxmlParserError> {
+        let file_name = file_name.as_ref();
+        let start = Instant::now();
+
+        let model = AutosarModel::new();
+
+        model.load_file(file_name, false)
+            .map_err(|cause| ArxmlParserError::Load { path: file_name.to_owned(), cause })?;
+
+        debug!("Loading ARXML file <{}> took {:?}.", file_name.display(), start.elapsed());
+
+        let mut can_clusters: HashMap<String, CanCluster> = HashMap::new();
+
+        // Iterate over Autosar elements and handle CanCluster elements
+        for element in model
+            .identifiable_elements()
+            .filter_map(|(_path, element)| element.upgrade())
+            .filter(|element| element.element_name() == ElementName::CanCluster)
+        {
+            match self.handle_can_cluster(&element) {
+                Ok(value) => {
+                    can_clusters.insert(value.name.clone(), value);
+                }
+                Err(error) => warn!("{error}")
+            }
+        }
+
+        debug!("Parsing ARXML file <{}> took {:?}.", file_name.display(), start.elapsed());
+
+        Ok(can_clusters)
+    }
+}
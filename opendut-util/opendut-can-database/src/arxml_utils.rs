@@ -0,0 +1,372 @@
+/*
+    HELPER METHODS
+*/
+use autosar_data::{CharacterData, Element, ElementName, EnumItem};
+
+use std::collections::HashMap;
+
+use crate::arxml_parser::ArxmlParserError;
+use crate::arxml_structs::*;
+
+/// Signal information collected from an ISignalToPduMapping: name, byte order, start position, length and init values.
+pub type SignalInfo = (String, String, i64, i64, InitValues);
+
+pub fn decode_integer(cdata: &CharacterData) -> Option<i64> {
+    if let CharacterData::UnsignedInteger(value) = cdata {
+        i64::try_from(*value).ok()
+    } else if let CharacterData::String(text) = cdata {
+        if text == "0" {
+            Some(0)
+        } else if let Some(hexstr) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            i64::from_str_radix(hexstr, 16).ok()
+        } else if let Some(binstr) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+            i64::from_str_radix(binstr, 2).ok()
+        } else if let Some(octstr) = text.strip_prefix('0') {
+            i64::from_str_radix(octstr, 8).ok()
+        } else {
+            text.parse().ok()
+        }
+    } else {
+        None
+    }
+}
+
+/// Human-readable identification of an element for error messages, i.e. its path or else its name.
+pub fn describe(element: &Element) -> String {
+    element.path()
+        .unwrap_or_else(|_| element.element_name().to_string())
+}
+
+pub fn get_time_range(base: &Element) -> Option<TimeRange> {
+    let value = base
+        .get_sub_element(ElementName::Value)
+        .and_then(|elem| elem.character_data())
+        .and_then(|cdata| cdata.parse_float())?;
+
+    let tolerance = if let Some(absolute_tolerance) = base
+        .get_sub_element(ElementName::AbsoluteTolerance)
+        .and_then(|elem| elem.get_sub_element(ElementName::Absolute))
+        .and_then(|elem| elem.character_data())
+        .and_then(|cdata| cdata.parse_float())
+    {
+        Some(TimeRangeTolerance::Absolute(absolute_tolerance))
+    } else {
+        base.get_sub_element(ElementName::RelativeTolerance)
+            .and_then(|elem| elem.get_sub_element(ElementName::Relative))
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| decode_integer(&cdata))
+            .map(TimeRangeTolerance::Relative)
+    };
+
+    Some(TimeRange { tolerance, value })
+}
+
+pub fn get_sub_element_and_time_range(base: &Element, sub_elem_name: ElementName, value: &mut f64, tolerance: &mut Option<TimeRangeTolerance>) {
+    if let Some(time_range) = base
+        .get_sub_element(sub_elem_name)
+        .and_then(|elem| get_time_range(&elem))
+    {
+        *value = time_range.value;
+        *tolerance = time_range.tolerance;
+    }
+}
+
+pub fn get_required_item_name(element: &Element) -> Result<String, ArxmlParserError> {
+    element.item_name()
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(element), element: ElementName::ShortName })
+}
+
+pub fn get_required_sub_subelement(element: &Element, subelement_name: ElementName, sub_subelement_name: ElementName) -> Result<Element, ArxmlParserError> {
+    let subelement = element.get_sub_element(subelement_name)
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(element), element: subelement_name })?;
+
+    subelement.get_sub_element(sub_subelement_name)
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(&subelement), element: sub_subelement_name })
+}
+
+pub fn get_subelement_int_value(element: &Element, subelement_name: ElementName) -> Option<i64> {
+    element
+        .get_sub_element(subelement_name)
+        .and_then(|elem| elem.character_data())
+        .and_then(|cdata| decode_integer(&cdata))
+}
+
+pub fn get_required_int_value(element: &Element, subelement_name: ElementName) -> Result<i64, ArxmlParserError> {
+    get_subelement_int_value(element, subelement_name)
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(element), element: subelement_name })
+}
+
+pub fn get_optional_int_value(element: &Element, subelement_name: ElementName) -> i64 {
+    get_subelement_int_value(element, subelement_name)
+        .unwrap_or(0)
+}
+
+pub fn get_required_reference(element: &Element, subelement_name: ElementName) -> Result<Element, ArxmlParserError> {
+    element.get_sub_element(subelement_name)
+        .and_then(|subelement| subelement.get_reference_target().ok())
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(element), element: subelement_name })
+}
+
+pub fn get_subelement_string_value(element: &Element, subelement_name: ElementName) -> Option<String> {
+    element
+        .get_sub_element(subelement_name)
+        .and_then(|elem| elem.character_data())
+        .map(|cdata| cdata.to_string())
+}
+
+pub fn get_required_string(element: &Element, subelement_name: ElementName) -> Result<String, ArxmlParserError> {
+    get_subelement_string_value(element, subelement_name)
+        .ok_or_else(|| ArxmlParserError::MissingElement { parent: describe(element), element: subelement_name })
+}
+
+pub fn get_optional_string(element: &Element, subelement_name: ElementName) -> String {
+    get_subelement_string_value(element, subelement_name)
+        .unwrap_or_default()
+}
+
+pub fn get_subelement_optional_string(element: &Element, subelement_name: ElementName, sub_subelement_name: ElementName) -> String {
+    element.get_sub_element(subelement_name)
+        .and_then(|elem| elem.get_sub_element(sub_subelement_name))
+        .and_then(|elem| elem.character_data())
+        .map(|cdata| cdata.to_string())
+        .unwrap_or_default()
+}
+
+pub fn ecu_of_frame_port(frame_port: &Element) -> Option<String> {
+    let ecu_comm_port_instance = frame_port.parent().ok()??;
+    let comm_connector = ecu_comm_port_instance.parent().ok()??;
+    let connectors = comm_connector.parent().ok()??;
+    let ecu_instance = connectors.parent().ok()??;
+    ecu_instance.item_name()
+}
+
+// true: Big Endian, false: Little Endian
+pub fn get_byte_order(byte_order: &str) -> bool {
+    byte_order != "MOST-SIGNIFICANT-BYTE-LAST"
+}
+
+// See how endianess affects PDU in 6.2.2 https://www.autosar.org/fileadmin/standards/R22-11/CP/AUTOSAR_TPS_SystemTemplate.pdf
+// Currently assumes Little Endian byte ordering and has support for signals that are Little Endian or Big Endian
+// Bit positions in undefined ranges are set to the unused bit pattern
+pub fn extract_init_values(unused_bit_pattern: bool, ungrouped_signals: &[ISignal], grouped_signals: &[ISignalGroup], length: i64, byte_order: bool) -> Result<Vec<u8>, String> {
+    let isignals = ungrouped_signals.iter()
+        .chain(grouped_signals.iter().flat_map(|group| group.isignals.iter()));
+
+    let dlc = usize::try_from(length)
+        .map_err(|_| format!("Invalid PDU length {length}"))?;
+
+    let mut bits = vec![unused_bit_pattern; dlc * 8];
+
+    for isignal in isignals {
+        let mut tmp_bit_array: Vec<bool> = Vec::new();
+        let isignal_length = usize::try_from(isignal.length)
+            .map_err(|_| format!("Invalid length {} of ISignal {}", isignal.length, isignal.name))?;
+        let isignal_start = usize::try_from(isignal.start_pos)
+            .map_err(|_| format!("Invalid start position {} of ISignal {}", isignal.start_pos, isignal.name))?;
+
+        match &isignal.init_values {
+            InitValues::Single(value) => {
+                let mut n = *value;
+
+                while n != 0 && tmp_bit_array.len() < isignal_length {
+                    tmp_bit_array.push(n & 1 != 0);
+                    n >>= 1;
+                }
+
+                while tmp_bit_array.len() < isignal_length {
+                    tmp_bit_array.push(false);
+                }
+
+                if isignal.byte_order {
+                    tmp_bit_array.reverse();
+                }
+            }
+            InitValues::Array(values) => {
+                if isignal_length % 8 != 0 {
+                    return Err(format!("Length {isignal_length} of ISignal {} with an array of init values is not divisible by 8", isignal.name));
+                }
+
+                for isignal_value in values {
+                    let mut byte_bits = (0..8)
+                        .map(|bit| (isignal_value >> bit) & 1 != 0)
+                        .collect::<Vec<_>>();
+                    byte_bits.reverse();
+
+                    tmp_bit_array.extend(byte_bits);
+                }
+            }
+            InitValues::NotExist => continue
+        }
+
+        if tmp_bit_array.len() != isignal_length {
+            return Err(format!("Init values of ISignal {} do not match its length of {isignal_length} bits", isignal.name));
+        }
+        if isignal_start + isignal_length > bits.len() {
+            return Err(format!("ISignal {} exceeds the PDU length of {dlc} bytes", isignal.name));
+        }
+
+        bits[isignal_start..isignal_start + isignal_length].copy_from_slice(&tmp_bit_array);
+    }
+
+    let mut init_values = bits.chunks(8)
+        .map(|byte_bits| byte_bits.iter().fold(0u8, |byte, bit| (byte << 1) | u8::from(*bit)))
+        .collect::<Vec<u8>>();
+
+    if !byte_order {
+        for init_value in init_values.iter_mut() {
+            *init_value = init_value.reverse_bits(); // reverse bits of each byte
+        }
+    }
+
+    Ok(init_values)
+}
+
+pub fn get_unused_bit_pattern(pdu: &Element) -> Result<bool, ArxmlParserError> {
+    match get_subelement_int_value(pdu, ElementName::UnusedBitPattern) {
+        None | Some(0) => Ok(false),
+        Some(1) => Ok(true),
+        Some(other) => Err(ArxmlParserError::InvalidValue {
+            parent: describe(pdu),
+            details: format!("Unused bit pattern must be 0 or 1, but is {other}"),
+        }),
+    }
+}
+
+pub fn process_frame_ports(can_frame_triggering: &Element, can_frame_triggering_name: &str, rx_ecus: &mut Vec<String>, tx_ecus: &mut Vec<String>) -> Result<(), String> {
+    let Some(frame_ports) = can_frame_triggering.get_sub_element(ElementName::FramePortRefs) else {
+        return Err(format!("FramePortRefs in CanFrameTriggering not found. Skipping CanFrameTriggering {can_frame_triggering_name}"));
+    };
+
+    let frame_ports: Vec<Element> = frame_ports.sub_elements()
+        .filter(|se| se.element_name() == ElementName::FramePortRef)
+        .filter_map(|fpr| fpr.get_reference_target().ok())
+        .collect();
+
+    for frame_port in frame_ports {
+        let Some(ecu_name) = ecu_of_frame_port(&frame_port) else {
+            return Err(format!("Could not extract ECUName in FramePort. Skipping CanFrameTriggering {can_frame_triggering_name}"));
+        };
+        if let Some(CharacterData::Enum(direction)) = frame_port
+            .get_sub_element(ElementName::CommunicationDirection)
+            .and_then(|elem| elem.character_data())
+        {
+            match direction {
+                EnumItem::In => rx_ecus.push(ecu_name),
+                EnumItem::Out => tx_ecus.push(ecu_name),
+                _ => return Err(format!("Invalid direction ID encountered in FramePort. Skipping CanFrameTriggering {can_frame_triggering_name}"))
+            }
+        } else {
+            return Err(format!("No CommunicationDirection encountered in FramePort. Skipping CanFrameTriggering {can_frame_triggering_name}"))
+        }
+    }
+
+    Ok(())
+}
+
+pub fn process_init_value(init_value_elem: &Element) -> Result<InitValues, ArxmlParserError> {
+    if let Some(num_val) = init_value_elem.get_sub_element(ElementName::NumericalValueSpecification) {
+        let init_value = get_required_int_value(&num_val, ElementName::Value)?;
+        Ok(InitValues::Single(init_value))
+    } else if init_value_elem.get_sub_element(ElementName::ArrayValueSpecification).is_some() {
+        let num_val_elements = get_required_sub_subelement(init_value_elem,
+            ElementName::ArrayValueSpecification,
+            ElementName::Elements)?;
+
+        let init_value_array = num_val_elements.sub_elements()
+            .map(|num_val_elem| get_required_int_value(&num_val_elem, ElementName::Value))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InitValues::Array(init_value_array))
+    } else {
+        Err(ArxmlParserError::InvalidValue {
+            parent: describe(init_value_elem),
+            details: String::from("Only numerical and array value specifications are supported as init values"),
+        })
+    }
+}
+
+pub fn process_signal_group(signal_group: &Element,
+    signals: &mut HashMap<String, SignalInfo>,
+    grouped_signals: &mut Vec<ISignalGroup>) -> Result<(), ArxmlParserError>
+{
+    let group_name = get_required_item_name(signal_group)?;
+
+    let mut signal_group_signals: Vec<ISignal> = Vec::new();
+
+    if let Some(isignal_refs) = signal_group.get_sub_element(ElementName::ISignalRefs) {
+        for isignal_ref in isignal_refs.sub_elements()
+            .filter(|elem| elem.element_name() == ElementName::ISignalRef)
+        {
+            if let Some(CharacterData::String(path)) = isignal_ref.character_data()
+                && let Some((name, byte_order, start_pos, length, init_values)) = signals.remove(&path)
+            {
+                signal_group_signals.push(ISignal {
+                    name,
+                    byte_order: get_byte_order(&byte_order),
+                    start_pos,
+                    length,
+                    init_values,
+                });
+            }
+        }
+    }
+
+    signal_group_signals.sort_by_key(|signal| signal.start_pos);
+
+    let mut data_transformations: Vec<String> = Vec::new();
+
+    if let Some(com_transformations) = signal_group
+        .get_sub_element(ElementName::ComBasedSignalGroupTransformations)
+    {
+        for elem in com_transformations.sub_elements() {
+            let data_transformation = get_required_reference(&elem,
+                ElementName::DataTransformationRef)?;
+
+            data_transformations.push(get_required_item_name(&data_transformation)?);
+        }
+    }
+
+    let mut props_vector: Vec<E2EDataTransformationProps> = Vec::new();
+
+    if let Some(transformation_props) = signal_group.get_sub_element(ElementName::TransformationISignalPropss) {
+        for e2exf_props in transformation_props
+            .sub_elements()
+            .filter(|elem| elem.element_name() == ElementName::EndToEndTransformationISignalProps)
+        {
+            if let Some(e2exf_props_cond) = e2exf_props
+                .get_sub_element(ElementName::EndToEndTransformationISignalPropsVariants)
+                .and_then(|elem| elem.get_sub_element(ElementName::EndToEndTransformationISignalPropsConditional))
+            {
+                let transformer_reference = get_required_reference(&e2exf_props_cond,
+                    ElementName::TransformerRef)?;
+
+                let transformer_name = get_required_item_name(&transformer_reference)?;
+
+                let Some(data_ids) = e2exf_props_cond.get_sub_element(ElementName::DataIds) else {
+                    continue;
+                };
+
+                let data_id = get_required_int_value(&data_ids,
+                    ElementName::DataId)?;
+
+                let data_length = get_required_int_value(&e2exf_props_cond,
+                    ElementName::DataLength)?;
+
+                props_vector.push(E2EDataTransformationProps {
+                    transformer_name,
+                    data_id,
+                    data_length,
+                });
+            }
+        }
+    }
+
+    grouped_signals.push(ISignalGroup {
+        name: group_name,
+        isignals: signal_group_signals,
+        data_transformations,
+        transformation_props: props_vector,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,461 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::arxml_structs::{CanCluster, Pdu};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CanDatabaseError {
+    #[error("Unknown CAN message <{message}>.")]
+    UnknownMessage { message: String },
+    #[error("Unknown signal <{signal}> in CAN message <{message}>.")]
+    UnknownSignal { message: String, signal: String },
+    #[error("Signal <{signal}> of CAN message <{message}> is not transmitted with multiplexor value {multiplexor_value}.")]
+    InactiveSignal { message: String, signal: String, multiplexor_value: u64 },
+    #[error("CAN message <{message}> contains multiplexed signals, but no value for its multiplexor <{multiplexor}> was given.")]
+    MissingMultiplexor { message: String, multiplexor: String },
+    #[error("CAN message <{message}> requires {expected} bytes of data, but only {actual} bytes were given.")]
+    DataTooShort { message: String, expected: usize, actual: usize },
+    #[error("Value {value} cannot be represented by signal <{signal}> of CAN message <{message}>.")]
+    ValueOutOfRange { message: String, signal: String, value: f64 },
+}
+
+/// Messages and signals of a CAN bus, as described by a DBC or ARXML file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CanDatabase {
+    pub messages: Vec<CanMessage>,
+    pub value_tables: BTreeMap<String, BTreeMap<i64, String>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanMessage {
+    pub name: String,
+    pub can_id: u32,
+    pub extended_id: bool,
+    /// Length of the message in bytes.
+    pub length: usize,
+    pub senders: Vec<String>,
+    pub signals: Vec<CanSignal>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanSignal {
+    pub name: String,
+    /// Position of the least significant bit for little endian signals, of the most significant bit for big endian signals.
+    pub start_bit: u32,
+    /// Length of the signal in bits.
+    pub length: u32,
+    pub byte_order: ByteOrder,
+    pub value_type: ValueType,
+    pub factor: f64,
+    pub offset: f64,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub unit: String,
+    pub receivers: Vec<String>,
+    pub multiplexing: Multiplexing,
+    pub value_descriptions: BTreeMap<i64, String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueType {
+    Unsigned,
+    Signed,
+    Float32,
+    Float64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Multiplexing {
+    None,
+    /// The signal selects which multiplexed signals are transmitted.
+    Multiplexor,
+    /// The signal is only transmitted when the multiplexor has the given value.
+    Multiplexed { multiplexor_value: u64 },
+}
+
+/// Decoded value of a signal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignalValue {
+    pub physical: f64,
+    /// Description of the raw value, if the signal has a matching value description.
+    pub description: Option<String>,
+}
+
+impl CanDatabase {
+    /// Builds a database from CAN clusters parsed by the [`crate::arxml_parser::ArxmlParser`].
+    /// ARXML signals are represented as unsigned values without scaling.
+    pub fn from_can_clusters(can_clusters: &HashMap<String, CanCluster>) -> Self {
+        let mut messages = can_clusters.values()
+            .flat_map(|can_cluster| can_cluster.can_frame_triggerings.values())
+            .map(|triggering| {
+                let signals = triggering.pdu_mappings.iter()
+                    .flat_map(|pdu_mapping| {
+                        let (ungrouped_signals, grouped_signals) = match &pdu_mapping.pdu {
+                            Pdu::ISignalIPdu(pdu) => (&pdu.ungrouped_signals, &pdu.grouped_signals),
+                            Pdu::NmPdu(pdu) => (&pdu.ungrouped_signals, &pdu.grouped_signals),
+                        };
+                        ungrouped_signals.iter()
+                            .chain(grouped_signals.iter().flat_map(|group| group.isignals.iter()))
+                            .map(move |isignal| (pdu_mapping.start_position, isignal))
+                    })
+                    .map(|(pdu_start_position, isignal)| {
+                        let length = u32::try_from(isignal.length).unwrap_or_default();
+                        CanSignal {
+                            name: isignal.name.clone(),
+                            start_bit: u32::try_from(pdu_start_position + isignal.start_pos).unwrap_or_default(),
+                            length,
+                            byte_order: if isignal.byte_order { ByteOrder::BigEndian } else { ByteOrder::LittleEndian },
+                            value_type: ValueType::Unsigned,
+                            factor: 1.0,
+                            offset: 0.0,
+                            minimum: 0.0,
+                            maximum: max_raw_value(length) as f64,
+                            unit: String::new(),
+                            receivers: triggering.receiver_ecus.clone(),
+                            multiplexing: Multiplexing::None,
+                            value_descriptions: BTreeMap::new(),
+                        }
+                    })
+                    .collect();
+
+                CanMessage {
+                    name: triggering.frame_name.clone(),
+                    can_id: u32::try_from(triggering.can_id).unwrap_or_default(),
+                    extended_id: triggering.addressing_mode == autosar_data::EnumItem::Extended.to_string(),
+                    length: usize::try_from(triggering.frame_length).unwrap_or_default(),
+                    senders: triggering.sender_ecus.clone(),
+                    signals,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        messages.sort_by_key(|message| message.can_id);
+        Self { messages, value_tables: BTreeMap::new() }
+    }
+
+    pub fn message(&self, name: &str) -> Result<&CanMessage, CanDatabaseError> {
+        self.messages.iter()
+            .find(|message| message.name == name)
+            .ok_or_else(|| CanDatabaseError::UnknownMessage { message: name.to_owned() })
+    }
+
+    pub fn message_by_id(&self, can_id: u32, extended_id: bool) -> Option<&CanMessage> {
+        self.messages.iter()
+            .find(|message| message.can_id == can_id && message.extended_id == extended_id)
+    }
+
+    /// Decodes all signals of the named message, which are transmitted in the given data.
+    pub fn decode(&self, message: &str, data: &[u8]) -> Result<BTreeMap<String, SignalValue>, CanDatabaseError> {
+        self.message(message)?.decode(data)
+    }
+
+    pub fn decode_signal(&self, message: &str, signal: &str, data: &[u8]) -> Result<SignalValue, CanDatabaseError> {
+        self.message(message)?.decode_signal(signal, data)
+    }
+
+    /// Encodes the given physical signal values into the data of the named message.
+    pub fn encode(&self, message: &str, values: &BTreeMap<String, f64>) -> Result<Vec<u8>, CanDatabaseError> {
+        self.message(message)?.encode(values)
+    }
+}
+
+impl CanMessage {
+    pub fn signal(&self, name: &str) -> Result<&CanSignal, CanDatabaseError> {
+        self.signals.iter()
+            .find(|signal| signal.name == name)
+            .ok_or_else(|| CanDatabaseError::UnknownSignal { message: self.name.clone(), signal: name.to_owned() })
+    }
+
+    fn multiplexor(&self) -> Option<&CanSignal> {
+        self.signals.iter()
+            .find(|signal| signal.multiplexing == Multiplexing::Multiplexor)
+    }
+
+    pub fn decode(&self, data: &[u8]) -> Result<BTreeMap<String, SignalValue>, CanDatabaseError> {
+        self.check_data_length(data)?;
+
+        let multiplexor_value = self.multiplexor()
+            .map(|multiplexor| multiplexor.extract_raw(data));
+
+        let values = self.signals.iter()
+            .filter(|signal| signal.is_active(multiplexor_value))
+            .map(|signal| (signal.name.clone(), signal.decode(data)))
+            .collect();
+        Ok(values)
+    }
+
+    pub fn decode_signal(&self, signal: &str, data: &[u8]) -> Result<SignalValue, CanDatabaseError> {
+        self.check_data_length(data)?;
+        let signal = self.signal(signal)?;
+
+        if let Multiplexing::Multiplexed { .. } = signal.multiplexing
+            && let Some(multiplexor) = self.multiplexor() {
+            let multiplexor_value = multiplexor.extract_raw(data);
+            if !signal.is_active(Some(multiplexor_value)) {
+                return Err(CanDatabaseError::InactiveSignal { message: self.name.clone(), signal: signal.name.clone(), multiplexor_value });
+            }
+        }
+        Ok(signal.decode(data))
+    }
+
+    /// Encodes the given physical signal values. Signals without a given value are encoded as zero.
+    pub fn encode(&self, values: &BTreeMap<String, f64>) -> Result<Vec<u8>, CanDatabaseError> {
+        let mut data = vec![0u8; self.length];
+
+        for name in values.keys() {
+            self.signal(name)?;
+        }
+
+        let multiplexor_value = match self.multiplexor() {
+            Some(multiplexor) => match values.get(&multiplexor.name) {
+                Some(value) => Some(multiplexor.to_raw(&self.name, *value)?),
+                None => {
+                    if self.signals.iter().any(|signal| matches!(signal.multiplexing, Multiplexing::Multiplexed { .. }) && values.contains_key(&signal.name)) {
+                        return Err(CanDatabaseError::MissingMultiplexor { message: self.name.clone(), multiplexor: multiplexor.name.clone() });
+                    }
+                    None
+                }
+            },
+            None => None,
+        };
+
+        for signal in &self.signals {
+            let Some(value) = values.get(&signal.name) else { continue };
+
+            if !signal.is_active(multiplexor_value) {
+                return Err(CanDatabaseError::InactiveSignal {
+                    message: self.name.clone(),
+                    signal: signal.name.clone(),
+                    multiplexor_value: multiplexor_value.unwrap_or_default(),
+                });
+            }
+            let raw = signal.to_raw(&self.name, *value)?;
+            signal.insert_raw(&mut data, raw);
+        }
+        Ok(data)
+    }
+
+    fn check_data_length(&self, data: &[u8]) -> Result<(), CanDatabaseError> {
+        let expected = self.signals.iter()
+            .map(CanSignal::required_bytes)
+            .max()
+            .unwrap_or_default();
+
+        if data.len() < expected {
+            Err(CanDatabaseError::DataTooShort { message: self.name.clone(), expected, actual: data.len() })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl CanSignal {
+    fn is_active(&self, multiplexor_value: Option<u64>) -> bool {
+        match self.multiplexing {
+            Multiplexing::None | Multiplexing::Multiplexor => true,
+            Multiplexing::Multiplexed { multiplexor_value: expected } => multiplexor_value == Some(expected),
+        }
+    }
+
+    fn decode(&self, data: &[u8]) -> SignalValue {
+        let raw = self.extract_raw(data);
+
+        let value = match self.value_type {
+            ValueType::Unsigned => raw as f64,
+            ValueType::Signed => sign_extend(raw, self.length) as f64,
+            ValueType::Float32 => f64::from(f32::from_bits(raw as u32)),
+            ValueType::Float64 => f64::from_bits(raw),
+        };
+        let description = match self.value_type {
+            ValueType::Unsigned => i64::try_from(raw).ok(),
+            ValueType::Signed => Some(sign_extend(raw, self.length)),
+            ValueType::Float32 | ValueType::Float64 => None,
+        }
+            .and_then(|raw| self.value_descriptions.get(&raw))
+            .cloned();
+
+        SignalValue {
+            physical: value * self.factor + self.offset,
+            description,
+        }
+    }
+
+    fn to_raw(&self, message: &str, physical: f64) -> Result<u64, CanDatabaseError> {
+        let scaled = (physical - self.offset) / self.factor;
+        let out_of_range = || CanDatabaseError::ValueOutOfRange { message: message.to_owned(), signal: self.name.clone(), value: physical };
+
+        match self.value_type {
+            ValueType::Float32 => Ok(u64::from((scaled as f32).to_bits())),
+            ValueType::Float64 => Ok(scaled.to_bits()),
+            ValueType::Unsigned => {
+                let raw = scaled.round();
+                if !(0.0..=max_raw_value(self.length) as f64).contains(&raw) {
+                    return Err(out_of_range());
+                }
+                Ok(raw as u64)
+            }
+            ValueType::Signed => {
+                let raw = scaled.round();
+                let limit = 2f64.powi(i32::try_from(self.length).unwrap_or(64) - 1);
+                if raw < -limit || raw >= limit {
+                    return Err(out_of_range());
+                }
+                Ok((raw as i64 as u64) & max_raw_value(self.length))
+            }
+        }
+    }
+
+    fn required_bytes(&self) -> usize {
+        self.bit_positions()
+            .map(|(byte, _)| byte + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Positions of the signal's bits in the data as `(byte, bit)`, starting with the most significant bit.
+    fn bit_positions(&self) -> impl Iterator<Item=(usize, u32)> + '_ {
+        let start_bit = self.start_bit as usize;
+        let length = self.length as usize;
+
+        (0..length).map(move |index| match self.byte_order {
+            ByteOrder::LittleEndian => {
+                let position = start_bit + (length - 1 - index);
+                (position / 8, (position % 8) as u32)
+            }
+            ByteOrder::BigEndian => {
+                let position = (start_bit / 8) * 8 + (7 - start_bit % 8) + index;
+                (position / 8, 7 - (position % 8) as u32)
+            }
+        })
+    }
+
+    fn extract_raw(&self, data: &[u8]) -> u64 {
+        self.bit_positions()
+            .fold(0u64, |raw, (byte, bit)| {
+                let bit_value = data.get(byte).map(|byte| (byte >> bit) & 1).unwrap_or_default();
+                (raw << 1) | u64::from(bit_value)
+            })
+    }
+
+    fn insert_raw(&self, data: &mut [u8], raw: u64) {
+        let length = self.length;
+        for (index, (byte, bit)) in self.bit_positions().enumerate() {
+            let bit_value = (raw >> (length - 1 - index as u32)) & 1;
+            if let Some(byte) = data.get_mut(byte) {
+                *byte = (*byte & !(1 << bit)) | ((bit_value as u8) << bit);
+            }
+        }
+    }
+}
+
+fn max_raw_value(length: u32) -> u64 {
+    if length >= 64 { u64::MAX } else { (1u64 << length) - 1 }
+}
+
+fn sign_extend(raw: u64, length: u32) -> i64 {
+    if length == 0 || length >= 64 {
+        raw as i64
+    } else {
+        let shift = 64 - length;
+        ((raw << shift) as i64) >> shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arxml_parser::ArxmlParser;
+    use crate::dbc_parser::DbcParser;
+    use googletest::prelude::*;
+    use std::path::PathBuf;
+
+    const VEHICLE_INFO: [u8; 8] = [0x92, 0x22, 0xEC, 0x03, 0xF3, 0x80, 0x00, 0x00];
+
+    fn database() -> anyhow::Result<CanDatabase> {
+        let dbc_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/resources/vehicle.dbc");
+        Ok(DbcParser::default().parse_file(dbc_file)?)
+    }
+
+    #[test]
+    fn should_decode_signals_by_name() -> anyhow::Result<()> {
+        let database = database()?;
+
+        let values = database.decode("VehicleInfo", &VEHICLE_INFO)?;
+
+        assert_that!(values.get("VehicleSpeed").map(|value| value.physical), some(near(88.5, 1e-9)));
+        assert_that!(values.get("EngineTemperature").map(|value| value.physical), some(eq(-20.0)));
+        assert_that!(values.get("Torque").map(|value| value.physical), some(eq(-100.0)));
+        assert_that!(values.get("Gear"), some(eq(&SignalValue { physical: 3.0, description: Some(String::from("Drive")) })));
+
+        assert_that!(database.decode_signal("VehicleInfo", "VehicleSpeed", &VEHICLE_INFO)?.physical, near(88.5, 1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn should_encode_signals_by_name() -> anyhow::Result<()> {
+        let database = database()?;
+
+        let values = BTreeMap::from([
+            (String::from("VehicleSpeed"), 88.5),
+            (String::from("EngineTemperature"), -20.0),
+            (String::from("Gear"), 3.0),
+            (String::from("Torque"), -100.0),
+        ]);
+        let data = database.encode("VehicleInfo", &values)?;
+
+        assert_that!(data, eq(&VEHICLE_INFO));
+        Ok(())
+    }
+
+    #[test]
+    fn should_only_decode_signals_selected_by_the_multiplexor() -> anyhow::Result<()> {
+        let database = database()?;
+        let data = [0x01, 0x10, 0x27, 0x00, 0x00, 0x50, 0x7D, 0x44];
+
+        let values = database.decode("Diagnostics", &data)?;
+
+        assert_that!(values.keys().collect::<Vec<_>>(), unordered_elements_are![eq(&"Mode"), eq(&"BatteryVoltage"), eq(&"AmbientPressure")]);
+        assert_that!(values.get("BatteryVoltage").map(|value| value.physical), some(near(10.0, 1e-9)));
+        assert_that!(values.get("AmbientPressure").map(|value| value.physical), some(eq(1013.25)));
+
+        assert_that!(
+            database.decode_signal("Diagnostics", "ErrorCode", &data),
+            err(eq(&CanDatabaseError::InactiveSignal { message: String::from("Diagnostics"), signal: String::from("ErrorCode"), multiplexor_value: 1 }))
+        );
+
+        let encoded = database.encode("Diagnostics", &BTreeMap::from([
+            (String::from("Mode"), 1.0),
+            (String::from("BatteryVoltage"), 10.0),
+            (String::from("AmbientPressure"), 1013.25),
+        ]))?;
+        assert_that!(encoded, eq(&data));
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_values_which_do_not_fit_into_the_signal() -> anyhow::Result<()> {
+        let database = database()?;
+
+        let result = database.encode("VehicleInfo", &BTreeMap::from([(String::from("Gear"), 4.0)]));
+
+        assert_that!(result, err(eq(&CanDatabaseError::ValueOutOfRange { message: String::from("VehicleInfo"), signal: String::from("Gear"), value: 4.0 })));
+        Ok(())
+    }
+
+    #[test]
+    fn should_represent_arxml_frames_as_messages() -> anyhow::Result<()> {
+        let arxml_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/resources/restbus.arxml");
+        let database = CanDatabase::from_can_clusters(&ArxmlParser::default().parse_file(arxml_file)?);
+
+        let values = database.decode("EngineStatus", &[0x34, 0x12, 90, 0, 0, 0, 0, 0])?;
+
+        assert_that!(values.get("EngineSpeed").map(|value| value.physical), some(eq(4660.0)));
+        assert_that!(values.get("EngineTemperature").map(|value| value.physical), some(eq(90.0)));
+        Ok(())
+    }
+}
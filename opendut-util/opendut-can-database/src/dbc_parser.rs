@@ -0,0 +1,648 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::can_database::{ByteOrder, CanDatabase, CanMessage, CanSignal, Multiplexing, ValueType};
+
+const EXTENDED_ID_FLAG: u32 = 0x8000_0000;
+const INDEPENDENT_SIGNALS_MESSAGE_ID: u32 = 0xC000_0000;
+const NO_NODE: &str = "Vector__XXX";
+/// Maximum payload length of a CAN FD frame in bytes.
+const MAX_MESSAGE_LENGTH: usize = 64;
+
+/// Statements, which are skipped until their terminating semicolon, since they do not affect signal decoding.
+const IGNORED_STATEMENTS: [&str; 18] = [
+    "BA_", "BA_DEF_", "BA_DEF_DEF_", "BA_DEF_REL_", "BA_DEF_DEF_REL_", "BA_REL_", "BA_DEF_SGTYPE_", "BA_SGTYPE_",
+    "BO_TX_BU_", "BU_BO_REL_", "BU_EV_REL_", "BU_SG_REL_", "CM_", "ENVVAR_DATA_", "EV_", "SGTYPE_", "SIG_GROUP_", "SIG_TYPE_REF_",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum DbcParserError {
+    #[error("Failed to read DBC file <{path}>: {cause}")]
+    Read { path: PathBuf, cause: std::io::Error },
+    #[error("{location}: {message}")]
+    Syntax { location: Location, message: String },
+}
+
+/// Position in a DBC file, used for error messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub path: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}:{}", path.display(), self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DbcParser {}
+
+impl DbcParser {
+    pub fn parse_file(&self, file_name: impl AsRef<Path>) -> Result<CanDatabase, DbcParserError> {
+        let path = file_name.as_ref();
+        let content = std::fs::read(path)
+            .map_err(|cause| DbcParserError::Read { path: path.to_owned(), cause })?;
+        // DBC files are commonly encoded in Windows-1252, which only matters for comments and units.
+        let content = String::from_utf8_lossy(&content);
+
+        self.parse_with_path(&content, Some(path))
+    }
+
+    pub fn parse_str(&self, content: &str) -> Result<CanDatabase, DbcParserError> {
+        self.parse_with_path(content, None)
+    }
+
+    fn parse_with_path(&self, content: &str, path: Option<&Path>) -> Result<CanDatabase, DbcParserError> {
+        let tokens = tokenize(content, path)?;
+        Parser { tokens, position: 0, path, eof: end_location(content) }.parse()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    Identifier(String),
+    Number(String),
+    String(String),
+    Symbol(char),
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+fn end_location(content: &str) -> (usize, usize) {
+    let line = content.lines().count().max(1);
+    let column = content.lines().last().map(|last| last.chars().count() + 1).unwrap_or(1);
+    (line, column)
+}
+
+fn tokenize(content: &str, path: Option<&Path>) -> Result<Vec<Token>, DbcParserError> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+
+    while let Some(&c) = chars.peek() {
+        let (start_line, start_column) = (line, column);
+        let mut advance = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+            let c = chars.next();
+            if c == Some('\n') {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+            c
+        };
+
+        let kind = if c.is_whitespace() {
+            advance(&mut chars);
+            continue;
+        } else if c == '"' {
+            advance(&mut chars);
+            let mut value = String::new();
+            loop {
+                match advance(&mut chars) {
+                    Some('"') => break,
+                    Some('\\') => {
+                        if let Some(escaped) = advance(&mut chars) {
+                            value.push(escaped);
+                        }
+                    }
+                    Some(c) => value.push(c),
+                    None => return Err(DbcParserError::Syntax {
+                        location: Location { path: path.map(Path::to_path_buf), line: start_line, column: start_column },
+                        message: String::from("Unterminated string"),
+                    }),
+                }
+            }
+            TokenKind::String(value)
+        } else if c.is_ascii_digit() {
+            let mut value = String::new();
+            while let Some(&c) = chars.peek() {
+                let is_exponent_sign = (c == '-' || c == '+') && value.ends_with(['e', 'E']) && !value.starts_with("0x");
+                if c.is_ascii_alphanumeric() || c == '.' || is_exponent_sign {
+                    value.push(c);
+                    advance(&mut chars);
+                } else {
+                    break;
+                }
+            }
+            TokenKind::Number(value)
+        } else if c.is_alphabetic() || c == '_' {
+            let mut value = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_alphanumeric() || c == '_' {
+                    value.push(c);
+                    advance(&mut chars);
+                } else {
+                    break;
+                }
+            }
+            TokenKind::Identifier(value)
+        } else {
+            advance(&mut chars);
+            TokenKind::Symbol(c)
+        };
+        tokens.push(Token { kind, line: start_line, column: start_column });
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    path: Option<&'a Path>,
+    eof: (usize, usize),
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<CanDatabase, DbcParserError> {
+        let mut database = CanDatabase::default();
+        let mut message_indices: HashMap<u32, usize> = HashMap::new();
+        let mut current_message: Option<usize> = None;
+
+        while let Some(token) = self.tokens.get(self.position).cloned() {
+            let TokenKind::Identifier(keyword) = &token.kind else {
+                return Err(self.error_at(&token, format!("Expected a keyword, but found {}", describe(&token.kind))));
+            };
+            self.position += 1;
+
+            match keyword.as_str() {
+                "VERSION" => {
+                    self.expect_string()?;
+                }
+                "NS_" | "BS_" => {
+                    // Both sections end with the next statement, which starts at the beginning of a line.
+                    self.expect_symbol(':')?;
+                    while self.tokens.get(self.position).is_some_and(|next| next.column > 1) {
+                        self.position += 1;
+                    }
+                }
+                "BU_" => {
+                    self.expect_symbol(':')?;
+                    while let Some(next) = self.tokens.get(self.position)
+                        && next.line == token.line
+                        && matches!(next.kind, TokenKind::Identifier(_)) {
+                        self.position += 1;
+                    }
+                }
+                "BO_" => {
+                    let (raw_id, message) = self.parse_message()?;
+                    if raw_id == INDEPENDENT_SIGNALS_MESSAGE_ID {
+                        current_message = None;
+                        self.skip_signals()?;
+                        continue;
+                    }
+                    if message_indices.contains_key(&raw_id) {
+                        return Err(self.error_at(&token, format!("Duplicate definition of message with ID {raw_id}")));
+                    }
+                    message_indices.insert(raw_id, database.messages.len());
+                    current_message = Some(database.messages.len());
+                    database.messages.push(message);
+                }
+                "SG_" => {
+                    let Some(index) = current_message else {
+                        return Err(self.error_at(&token, String::from("Signal defined outside of a message")));
+                    };
+                    let signal = self.parse_signal()?;
+                    let message = &mut database.messages[index];
+                    if message.signals.iter().any(|existing| existing.name == signal.name) {
+                        return Err(self.error_at(&token, format!("Duplicate definition of signal <{}> in message <{}>", signal.name, message.name)));
+                    }
+                    message.signals.push(signal);
+                }
+                "VAL_TABLE_" => {
+                    let name = self.expect_identifier()?;
+                    let values = self.parse_value_descriptions()?;
+                    database.value_tables.insert(name, values);
+                }
+                "VAL_" => {
+                    let Some(next) = self.tokens.get(self.position).cloned() else {
+                        return Err(self.error_at_end(String::from("Expected a message ID")));
+                    };
+                    if let TokenKind::Identifier(_) = next.kind {
+                        // value descriptions of an environment variable
+                        self.skip_statement()?;
+                        continue;
+                    }
+                    let signal = self.resolve_signal(&mut database, &message_indices)?;
+                    signal.value_descriptions = self.parse_value_descriptions()?;
+                }
+                "SIG_VALTYPE_" => {
+                    let signal = self.resolve_signal(&mut database, &message_indices)?;
+                    self.expect_symbol(':')?;
+                    let value_type_token = self.peek_token()?;
+                    let value_type = match self.expect_integer()? {
+                        0 => None,
+                        1 => Some((ValueType::Float32, 32)),
+                        2 => Some((ValueType::Float64, 64)),
+                        other => return Err(self.error_at(&value_type_token, format!("Unknown signal value type {other}"))),
+                    };
+                    if let Some((value_type, required_length)) = value_type {
+                        if signal.length != required_length {
+                            return Err(self.error_at(&value_type_token, format!("Signal <{}> must be {required_length} bits long to hold a {value_type:?} value, but is {} bits long", signal.name, signal.length)));
+                        }
+                        signal.value_type = value_type;
+                    }
+                    self.expect_symbol(';')?;
+                }
+                "SG_MUL_VAL_" => {
+                    return Err(self.error_at(&token, String::from("Extended multiplexing is not supported")));
+                }
+                ignored if IGNORED_STATEMENTS.contains(&ignored) => {
+                    self.skip_statement()?;
+                }
+                unknown => {
+                    return Err(self.error_at(&token, format!("Unknown keyword <{unknown}>")));
+                }
+            }
+        }
+
+        for message in &database.messages {
+            let multiplexors = message.signals.iter()
+                .filter(|signal| signal.multiplexing == Multiplexing::Multiplexor)
+                .count();
+            let has_multiplexed_signals = message.signals.iter()
+                .any(|signal| matches!(signal.multiplexing, Multiplexing::Multiplexed { .. }));
+
+            if multiplexors > 1 {
+                return Err(self.error_at_end(format!("Message <{}> has more than one multiplexor", message.name)));
+            }
+            if has_multiplexed_signals && multiplexors == 0 {
+                return Err(self.error_at_end(format!("Message <{}> has multiplexed signals, but no multiplexor", message.name)));
+            }
+        }
+
+        Ok(database)
+    }
+
+    fn parse_message(&mut self) -> Result<(u32, CanMessage), DbcParserError> {
+        let id_token = self.peek_token()?;
+        let raw_id = self.expect_integer()?;
+        let raw_id = u32::try_from(raw_id)
+            .map_err(|_| self.error_at(&id_token, format!("Invalid message ID {raw_id}")))?;
+        let name = self.expect_identifier()?;
+        self.expect_symbol(':')?;
+        let length_token = self.peek_token()?;
+        let raw_length = self.expect_integer()?;
+        let length = usize::try_from(raw_length).ok()
+            .filter(|length| *length <= MAX_MESSAGE_LENGTH)
+            .ok_or_else(|| self.error_at(&length_token, format!("Invalid message length {raw_length}, expected 0 to {MAX_MESSAGE_LENGTH} bytes")))?;
+        let sender = self.expect_identifier()?;
+
+        let extended_id = raw_id & EXTENDED_ID_FLAG != 0;
+        let can_id = raw_id & !EXTENDED_ID_FLAG;
+        if !extended_id && can_id > 0x7FF && raw_id != INDEPENDENT_SIGNALS_MESSAGE_ID {
+            return Err(self.error_at(&id_token, format!("Message ID {can_id:#x} exceeds the range of standard CAN IDs")));
+        }
+
+        Ok((raw_id, CanMessage {
+            name,
+            can_id,
+            extended_id,
+            length,
+            senders: if sender == NO_NODE { vec![] } else { vec![sender] },
+            signals: vec![],
+        }))
+    }
+
+    fn skip_signals(&mut self) -> Result<(), DbcParserError> {
+        while self.tokens.get(self.position).is_some_and(|token| token.kind == TokenKind::Identifier(String::from("SG_"))) {
+            self.position += 1;
+            self.parse_signal()?;
+        }
+        Ok(())
+    }
+
+    fn parse_signal(&mut self) -> Result<CanSignal, DbcParserError> {
+        let name = self.expect_identifier()?;
+
+        let multiplexing = match self.peek_token()?.kind {
+            TokenKind::Identifier(indicator) => {
+                let indicator_token = self.peek_token()?;
+                self.position += 1;
+                if indicator == "M" {
+                    Multiplexing::Multiplexor
+                } else if let Some(value) = indicator.strip_prefix('m') {
+                    if value.ends_with('M') {
+                        return Err(self.error_at(&indicator_token, String::from("Extended multiplexing is not supported")));
+                    }
+                    let multiplexor_value = value.parse::<u64>()
+                        .map_err(|_| self.error_at(&indicator_token, format!("Invalid multiplexer indicator <{indicator}>")))?;
+                    Multiplexing::Multiplexed { multiplexor_value }
+                } else {
+                    return Err(self.error_at(&indicator_token, format!("Invalid multiplexer indicator <{indicator}>")));
+                }
+            }
+            _ => Multiplexing::None,
+        };
+
+        self.expect_symbol(':')?;
+        let start_bit_token = self.peek_token()?;
+        let start_bit = self.expect_integer()?;
+        self.expect_symbol('|')?;
+        let length_token = self.peek_token()?;
+        let length = self.expect_integer()?;
+        self.expect_symbol('@')?;
+        let byte_order_token = self.peek_token()?;
+        let byte_order = match self.expect_integer()? {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            other => return Err(self.error_at(&byte_order_token, format!("Invalid byte order {other}, expected 0 (big endian) or 1 (little endian)"))),
+        };
+        let sign_token = self.peek_token()?;
+        let value_type = match self.next_token()?.kind {
+            TokenKind::Symbol('+') => ValueType::Unsigned,
+            TokenKind::Symbol('-') => ValueType::Signed,
+            other => return Err(self.error_at(&sign_token, format!("Expected '+' or '-', but found {}", describe(&other)))),
+        };
+
+        self.expect_symbol('(')?;
+        let factor = self.expect_float()?;
+        self.expect_symbol(',')?;
+        let offset = self.expect_float()?;
+        self.expect_symbol(')')?;
+        self.expect_symbol('[')?;
+        let minimum = self.expect_float()?;
+        self.expect_symbol('|')?;
+        let maximum = self.expect_float()?;
+        self.expect_symbol(']')?;
+        let unit = self.expect_string()?;
+
+        let mut receivers = vec![self.expect_identifier()?];
+        while self.tokens.get(self.position).is_some_and(|token| token.kind == TokenKind::Symbol(',')) {
+            self.position += 1;
+            receivers.push(self.expect_identifier()?);
+        }
+        receivers.retain(|receiver| receiver != NO_NODE);
+
+        let start_bit = u32::try_from(start_bit)
+            .map_err(|_| self.error_at(&start_bit_token, format!("Invalid start bit {start_bit}")))?;
+        let length = u32::try_from(length).ok()
+            .filter(|length| (1..=64).contains(length))
+            .ok_or_else(|| self.error_at(&length_token, format!("Invalid signal length {length}, expected 1 to 64 bits")))?;
+        if factor == 0.0 {
+            return Err(self.error_at(&start_bit_token, format!("Factor of signal <{name}> must not be zero")));
+        }
+
+        Ok(CanSignal {
+            name,
+            start_bit,
+            length,
+            byte_order,
+            value_type,
+            factor,
+            offset,
+            minimum,
+            maximum,
+            unit,
+            receivers,
+            multiplexing,
+            value_descriptions: BTreeMap::new(),
+        })
+    }
+
+    fn parse_value_descriptions(&mut self) -> Result<BTreeMap<i64, String>, DbcParserError> {
+        let mut values = BTreeMap::new();
+        loop {
+            if self.peek_token()?.kind == TokenKind::Symbol(';') {
+                self.position += 1;
+                return Ok(values);
+            }
+            let value = self.expect_integer()?;
+            let description = self.expect_string()?;
+            values.insert(value, description);
+        }
+    }
+
+    fn resolve_signal<'d>(&mut self, database: &'d mut CanDatabase, message_indices: &HashMap<u32, usize>) -> Result<&'d mut CanSignal, DbcParserError> {
+        let message_token = self.peek_token()?;
+        let raw_id = self.expect_integer()?;
+        let index = u32::try_from(raw_id).ok()
+            .and_then(|raw_id| message_indices.get(&raw_id))
+            .ok_or_else(|| self.error_at(&message_token, format!("Unknown message ID {raw_id}")))?;
+
+        let signal_token = self.peek_token()?;
+        let signal_name = self.expect_identifier()?;
+        let message = &mut database.messages[*index];
+        let message_name = message.name.clone();
+        message.signals.iter_mut()
+            .find(|signal| signal.name == signal_name)
+            .ok_or_else(|| self.error_at(&signal_token, format!("Unknown signal <{signal_name}> in message <{message_name}>")))
+    }
+
+    fn skip_statement(&mut self) -> Result<(), DbcParserError> {
+        loop {
+            if self.next_token()?.kind == TokenKind::Symbol(';') {
+                return Ok(());
+            }
+        }
+    }
+
+    fn peek_token(&self) -> Result<Token, DbcParserError> {
+        self.tokens.get(self.position).cloned()
+            .ok_or_else(|| self.error_at_end(String::from("Unexpected end of file")))
+    }
+
+    fn next_token(&mut self) -> Result<Token, DbcParserError> {
+        let token = self.peek_token()?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expect_symbol(&mut self, expected: char) -> Result<(), DbcParserError> {
+        let token = self.next_token()?;
+        match token.kind {
+            TokenKind::Symbol(symbol) if symbol == expected => Ok(()),
+            ref other => Err(self.error_at(&token, format!("Expected '{expected}', but found {}", describe(other)))),
+        }
+    }
+
+    fn expect_identifier(&mut self) -> Result<String, DbcParserError> {
+        let token = self.next_token()?;
+        match token.kind {
+            TokenKind::Identifier(identifier) => Ok(identifier),
+            ref other => Err(self.error_at(&token, format!("Expected an identifier, but found {}", describe(other)))),
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String, DbcParserError> {
+        let token = self.next_token()?;
+        match token.kind {
+            TokenKind::String(value) => Ok(value),
+            ref other => Err(self.error_at(&token, format!("Expected a string, but found {}", describe(other)))),
+        }
+    }
+
+    fn expect_integer(&mut self) -> Result<i64, DbcParserError> {
+        let token = self.peek_token()?;
+        let number = self.expect_number()?;
+        parse_integer(&number)
+            .ok_or_else(|| self.error_at(&token, format!("Expected an integer, but found '{number}'")))
+    }
+
+    fn expect_float(&mut self) -> Result<f64, DbcParserError> {
+        let token = self.peek_token()?;
+        let number = self.expect_number()?;
+        number.parse::<f64>()
+            .map_err(|_| self.error_at(&token, format!("Expected a number, but found '{number}'")))
+    }
+
+    /// Reads a number, including an optional leading sign, which is tokenized as a separate symbol.
+    fn expect_number(&mut self) -> Result<String, DbcParserError> {
+        let mut token = self.next_token()?;
+        let mut sign = "";
+        if let TokenKind::Symbol(symbol @ ('-' | '+')) = token.kind {
+            sign = if symbol == '-' { "-" } else { "" };
+            token = self.next_token()?;
+        }
+        match token.kind {
+            TokenKind::Number(number) => Ok(format!("{sign}{number}")),
+            ref other => Err(self.error_at(&token, format!("Expected a number, but found {}", describe(other)))),
+        }
+    }
+
+    fn error_at(&self, token: &Token, message: String) -> DbcParserError {
+        DbcParserError::Syntax {
+            location: Location { path: self.path.map(Path::to_path_buf), line: token.line, column: token.column },
+            message,
+        }
+    }
+
+    fn error_at_end(&self, message: String) -> DbcParserError {
+        let (line, column) = self.eof;
+        DbcParserError::Syntax {
+            location: Location { path: self.path.map(Path::to_path_buf), line, column },
+            message,
+        }
+    }
+}
+
+fn parse_integer(number: &str) -> Option<i64> {
+    match number.strip_prefix("0x").or_else(|| number.strip_prefix("-0x")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok().map(|value| if number.starts_with('-') { -value } else { value }),
+        None => number.parse::<i64>().ok(),
+    }
+}
+
+fn describe(kind: &TokenKind) -> String {
+    match kind {
+        TokenKind::Identifier(identifier) => format!("<{identifier}>"),
+        TokenKind::Number(number) => format!("'{number}'"),
+        TokenKind::String(value) => format!("\"{value}\""),
+        TokenKind::Symbol(symbol) => format!("'{symbol}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use googletest::prelude::*;
+
+    fn dbc_file() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/resources/vehicle.dbc")
+    }
+
+    #[test]
+    fn should_parse_messages_and_signals() -> anyhow::Result<()> {
+        let database = DbcParser::default().parse_file(dbc_file())?;
+
+        assert_that!(database.messages.iter().map(|message| message.name.as_str()).collect::<Vec<_>>(), elements_are![eq(&"VehicleInfo"), eq(&"Diagnostics")]);
+        assert_that!(database.value_tables.get("GearTable").map(BTreeMap::len), some(eq(4)));
+
+        let vehicle_info = database.message("VehicleInfo")?;
+        assert_that!(vehicle_info, matches_pattern!(CanMessage {
+            can_id: eq(&0x100),
+            extended_id: eq(&false),
+            length: eq(&8),
+            senders: elements_are![eq("Engine")],
+            ..
+        }));
+        assert_that!(vehicle_info.signal("VehicleSpeed")?, matches_pattern!(CanSignal {
+            start_bit: eq(&0),
+            length: eq(&16),
+            byte_order: eq(&ByteOrder::LittleEndian),
+            value_type: eq(&ValueType::Unsigned),
+            factor: eq(&0.01),
+            maximum: eq(&655.35),
+            unit: eq("km/h"),
+            receivers: elements_are![eq("Dashboard"), eq("Gateway")],
+            ..
+        }));
+        assert_that!(vehicle_info.signal("Torque")?, matches_pattern!(CanSignal {
+            byte_order: eq(&ByteOrder::BigEndian),
+            value_type: eq(&ValueType::Signed),
+            ..
+        }));
+        assert_that!(vehicle_info.signal("Gear")?.value_descriptions.get(&3), some(eq("Drive")));
+
+        let diagnostics = database.message("Diagnostics")?;
+        assert_that!(diagnostics.can_id, eq(0x200));
+        assert_that!(diagnostics.extended_id, eq(true));
+        assert_that!(diagnostics.signal("Mode")?.multiplexing, eq(Multiplexing::Multiplexor));
+        assert_that!(diagnostics.signal("ErrorCode")?.multiplexing, eq(Multiplexing::Multiplexed { multiplexor_value: 2 }));
+        assert_that!(diagnostics.signal("AmbientPressure")?.value_type, eq(ValueType::Float32));
+        assert_that!(diagnostics.signal("AmbientPressure")?.receivers, is_empty());
+
+        Ok(())
+    }
+
+    #[rstest::rstest]
+    #[case::invalid_byte_order(
+        "BO_ 256 VehicleInfo: 8 Engine\n SG_ VehicleSpeed : 0|16@2+ (0.01,0) [0|655.35] \"km/h\" Dashboard\n",
+        "2:26: Invalid byte order 2, expected 0 (big endian) or 1 (little endian)",
+    )]
+    #[case::missing_separator(
+        "BO_ 256 VehicleInfo: 8 Engine\n SG_ VehicleSpeed : 0|16@1+ (0.01 0) [0|655.35] \"km/h\" Dashboard\n",
+        "2:35: Expected ',', but found '0'",
+    )]
+    #[case::signal_outside_of_message(
+        "BU_: Engine\n SG_ VehicleSpeed : 0|16@1+ (0.01,0) [0|655.35] \"km/h\" Dashboard\n",
+        "2:2: Signal defined outside of a message",
+    )]
+    #[case::unknown_signal_in_value_descriptions(
+        "BO_ 256 VehicleInfo: 8 Engine\n SG_ Gear : 24|2@1+ (1,0) [0|3] \"\" Dashboard\n\nVAL_ 256 Gears 0 \"Park\" ;\n",
+        "4:10: Unknown signal <Gears> in message <VehicleInfo>",
+    )]
+    #[case::float_of_invalid_length(
+        "BO_ 256 VehicleInfo: 8 Engine\n SG_ Gear : 24|2@1+ (1,0) [0|3] \"\" Dashboard\n\nSIG_VALTYPE_ 256 Gear : 1;\n",
+        "4:25: Signal <Gear> must be 32 bits long to hold a Float32 value, but is 2 bits long",
+    )]
+    #[case::unterminated_string(
+        "BO_ 256 VehicleInfo: 8 Engine\n SG_ Gear : 24|2@1+ (1,0) [0|3] \"Dashboard\n",
+        "2:33: Unterminated string",
+    )]
+    #[case::invalid_message_length(
+        "BO_ 256 VehicleInfo: 65 Engine\n",
+        "1:22: Invalid message length 65, expected 0 to 64 bytes",
+    )]
+    #[case::unexpected_end_of_file(
+        "BO_ 256 VehicleInfo: 8",
+        "1:23: Unexpected end of file",
+    )]
+    fn should_report_the_location_of_errors(#[case] content: &str, #[case] expected_error: &str) {
+        let result = DbcParser::default().parse_str(content);
+
+        assert_that!(result.map_err(|error| error.to_string()), err(eq(expected_error)));
+    }
+
+    #[test]
+    fn should_include_the_path_in_error_locations() -> anyhow::Result<()> {
+        let file = tempfile::NamedTempFile::new()?;
+        std::fs::write(file.path(), "BO_ 256 VehicleInfo: 8 Engine\nFOO_ 1;\n")?;
+
+        let result = DbcParser::default().parse_file(file.path());
+
+        assert_that!(result.map_err(|error| error.to_string()), err(eq(&format!("{}:2:1: Unknown keyword <FOO_>", file.path().display()))));
+        Ok(())
+    }
+}
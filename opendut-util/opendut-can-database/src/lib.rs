@@ -0,0 +1,5 @@
+pub mod arxml_parser;
+pub mod arxml_structs;
+pub mod arxml_utils;
+pub mod can_database;
+pub mod dbc_parser;
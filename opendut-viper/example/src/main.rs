@@ -138,6 +138,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     match test_suite_report.outcome() {
         Outcome::Success => println!("All tests passed."),
         Outcome::Failure => println!("At least one test failed."),
+        Outcome::Timeout => println!("At least one test timed out."),
     }
 
     for test_case_report in test_suite_report.cases {
@@ -2,6 +2,7 @@ mod templates;
 mod console;
 mod parse;
 mod param_config;
+mod report;
 
 use std::collections::HashMap;
 use clap::Parser;
@@ -25,7 +26,8 @@ use opendut_viper_rt::source::Source;
 use opendut_viper_rt::ViperRuntime;
 use crate::console::Event;
 use crate::param_config::{IncompleteBindingsError, ParameterToml};
-use crate::templates::SCRIPT_PY_TEMPLATE;
+use crate::parse::ReportTarget;
+use crate::templates::{SCRIPT_PY_TEMPLATE, UNITTEST_PY_TIMEOUT_STUB};
 
 /// Write reproducible tests for ECUs using Python syntax
 #[derive(clap::Parser)]
@@ -46,6 +48,10 @@ enum Command {
         #[arg(long="params-from-file")]
         params_from_file: Option<String>,
 
+        /// Write a report of the test results to the given file (junit=<path> or json=<path>)
+        #[arg(long="report", value_name="FORMAT=PATH")]
+        reports: Vec<ReportTarget>,
+
         /// Run given Test (suite::case::test)
         #[arg()]
         test_identifier_filter: Option<String>,
@@ -70,8 +76,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 std::process::exit(1);
             }
         }
-        Command::Run{ params_from_file, test_identifier_filter } => {
-            if let Err(e) = build_and_run(params_from_file, test_identifier_filter).await {
+        Command::Run{ params_from_file, reports, test_identifier_filter } => {
+            if let Err(e) = build_and_run(params_from_file, reports, test_identifier_filter).await {
                 eprintln!("Error running tests: {e}");
                 std::process::exit(1);
             }
@@ -102,6 +108,7 @@ fn create_project_structure() -> io::Result<()> {
 
     let mut unittest_py = File::create("src/viper/unittest.py")?;
     unittest_py.write_all(opendut_viper_py::unittest::unittest::TestCase::GENERATED_PYTHON_CODE.as_bytes())?;
+    unittest_py.write_all(UNITTEST_PY_TIMEOUT_STUB.as_bytes())?;
 
     let mut script_py = File::create("src/script.py")?;
     script_py.write_all(SCRIPT_PY_TEMPLATE.as_bytes())?;
@@ -110,7 +117,7 @@ fn create_project_structure() -> io::Result<()> {
     Ok(())
 }
 
-async fn build_and_run(params_from_file: Option<String>, test_identifier_filter: Option<String>) -> Result<(), Box<dyn Error>> {
+async fn build_and_run(params_from_file: Option<String>, report_targets: Vec<ReportTarget>, test_identifier_filter: Option<String>) -> Result<(), Box<dyn Error>> {
 
     let runtime = ViperRuntime::builder()
         .with_source_loader(SimpleFileSourceLoader)
@@ -124,7 +131,7 @@ async fn build_and_run(params_from_file: Option<String>, test_identifier_filter:
         None => IdentifierFilter::default(),
     };
 
-    let render_task = {
+    let (render_task, reports) = {
         let (sender, receiver) = tokio::sync::mpsc::channel::<Event>(64);
 
         let render_task = tokio::spawn(async move {
@@ -194,18 +201,21 @@ async fn build_and_run(params_from_file: Option<String>, test_identifier_filter:
 
         sender.send(Event::RenderEvent).await?;
 
+        let mut reports = Vec::new();
         let mut emitter = emitter::sink(new_run_event_sink(&sender));
         for suite in test_suites {
             let bindings = bindings_map.remove(suite.name());
             if let Some(complete_bindings) = bindings {
-                runtime.run(suite, complete_bindings, &mut emitter).await?;
+                reports.push(runtime.run(suite, complete_bindings, &mut emitter).await?);
             }
         }
 
-        render_task
+        (render_task, reports)
     };
 
     render_task.await?;
+
+    report::write_reports(&report_targets, &reports)?;
     Ok(())
 }
 
@@ -1,4 +1,5 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
 use std::str::FromStr;
 
 #[derive(Clone, Debug)]
@@ -29,6 +30,64 @@ impl FromStr for Parameter {
     }
 }
 
+/// Target of a report written after running the tests, specified as `<format>=<path>`.
+#[derive(Clone, Debug)]
+pub struct ReportTarget {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+impl FromStr for ReportTarget {
+    type Err = ParseError;
+
+    fn from_str(target_string: &str) -> Result<Self, Self::Err> {
+        let Some((format, path)) = target_string.split_once('=') else {
+            return Err(ParseError {
+                message: format!("Failed to parse '{target_string}' as a report target. Expected '<format>=<path>', e.g. 'junit=report.xml'."),
+            })
+        };
+
+        if path.is_empty() {
+            return Err(ParseError {
+                message: format!("Failed to parse '{target_string}' as a report target. The path must not be empty."),
+            })
+        }
+
+        Ok(Self {
+            format: ReportFormat::from_str(format)?,
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    Junit,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = ParseError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "junit" => Ok(ReportFormat::Junit),
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(ParseError {
+                message: format!("Unknown report format '{format}'. Supported formats are 'junit' and 'json'."),
+            }),
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFormat::Junit => write!(f, "JUnit"),
+            ReportFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct ParseError {
@@ -42,3 +101,26 @@ impl Display for ParseError {
         writeln!(f, "{}", self.message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_parse_report_targets() -> Result<()> {
+        let junit = ReportTarget::from_str("junit=target/report.xml")?;
+        assert_that!(junit.format, eq(ReportFormat::Junit));
+        assert_that!(junit.path, eq(&PathBuf::from("target/report.xml")));
+
+        let json = ReportTarget::from_str("json=report.json")?;
+        assert_that!(json.format, eq(ReportFormat::Json));
+
+        assert_that!(ReportTarget::from_str("report.xml"), err(anything()));
+        assert_that!(ReportTarget::from_str("junit="), err(anything()));
+        assert_that!(ReportTarget::from_str("html=report.html"), err(anything()));
+
+        Ok(())
+    }
+}
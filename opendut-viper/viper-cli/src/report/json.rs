@@ -0,0 +1,180 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use opendut_viper_rt::common::Identifier;
+use opendut_viper_rt::run::{Outcome, Report, ReportProperty, ReportPropertyValue, TestCaseReport, TestReport, TestSuiteReport};
+
+/// Version of the JSON report schema. Increment on incompatible changes to the structure below.
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub fn render(reports: &[TestSuiteReport]) -> Result<String, serde_json::Error> {
+    let document = JsonReport {
+        schema_version: SCHEMA_VERSION,
+        suites: reports.iter().map(JsonTestSuiteReport::from).collect(),
+    };
+    serde_json::to_string_pretty(&document)
+}
+
+#[derive(Serialize)]
+struct JsonReport {
+    schema_version: u32,
+    suites: Vec<JsonTestSuiteReport>,
+}
+
+#[derive(Serialize)]
+struct JsonTestSuiteReport {
+    name: String,
+    outcome: JsonOutcome,
+    cases: Vec<JsonTestCaseReport>,
+}
+
+#[derive(Serialize)]
+struct JsonTestCaseReport {
+    identifier: String,
+    name: String,
+    outcome: JsonOutcome,
+    tests: Vec<JsonTestReport>,
+}
+
+#[derive(Serialize)]
+struct JsonTestReport {
+    identifier: String,
+    name: String,
+    outcome: JsonOutcome,
+    properties: Vec<JsonReportProperty>,
+    output: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JsonOutcome {
+    Success,
+    Failure,
+    Timeout,
+}
+
+#[derive(Serialize)]
+struct JsonReportProperty {
+    name: String,
+    #[serde(flatten)]
+    value: JsonReportPropertyValue,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum JsonReportPropertyValue {
+    Number(i64),
+    String(String),
+    FilePath(PathBuf),
+}
+
+impl From<&TestSuiteReport> for JsonTestSuiteReport {
+    fn from(report: &TestSuiteReport) -> Self {
+        Self {
+            name: report.name.to_string(),
+            outcome: report.outcome().into(),
+            cases: report.cases.iter().map(JsonTestCaseReport::from).collect(),
+        }
+    }
+}
+
+impl From<&TestCaseReport> for JsonTestCaseReport {
+    fn from(report: &TestCaseReport) -> Self {
+        Self {
+            identifier: report.name.to_string(),
+            name: report.name.name().to_owned(),
+            outcome: report.outcome().into(),
+            tests: report.tests.iter().map(JsonTestReport::from).collect(),
+        }
+    }
+}
+
+impl From<&TestReport> for JsonTestReport {
+    fn from(report: &TestReport) -> Self {
+        Self {
+            identifier: report.identifier.to_string(),
+            name: report.identifier.name().to_owned(),
+            outcome: report.outcome.into(),
+            properties: report.properties.iter().map(JsonReportProperty::from).collect(),
+            output: Clone::clone(&report.output),
+        }
+    }
+}
+
+impl From<Outcome> for JsonOutcome {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Success => JsonOutcome::Success,
+            Outcome::Failure => JsonOutcome::Failure,
+            Outcome::Timeout => JsonOutcome::Timeout,
+        }
+    }
+}
+
+impl From<&ReportProperty> for JsonReportProperty {
+    fn from(property: &ReportProperty) -> Self {
+        let value = match &property.value {
+            ReportPropertyValue::Number(value) => JsonReportPropertyValue::Number(*value),
+            ReportPropertyValue::String(value) => JsonReportPropertyValue::String(Clone::clone(value)),
+            ReportPropertyValue::FilePath(path) => JsonReportPropertyValue::FilePath(Clone::clone(path)),
+        };
+        Self { name: Clone::clone(&property.name), value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_render_reports_with_versioned_schema() -> Result<()> {
+        let reports = vec![TestSuiteReport {
+            name: "my_suite".try_into()?,
+            cases: vec![TestCaseReport {
+                name: "my_suite::MyCase".try_into()?,
+                tests: vec![TestReport {
+                    identifier: "my_suite::MyCase::test_failure".try_into()?,
+                    outcome: Outcome::Failure,
+                    properties: vec![
+                        ReportProperty { name: String::from("speed"), value: ReportPropertyValue::Number(42) },
+                        ReportProperty { name: String::from("unit"), value: ReportPropertyValue::String(String::from("km/h")) },
+                        ReportProperty { name: String::from("trace"), value: ReportPropertyValue::FilePath(PathBuf::from("/tmp/trace.log")) },
+                    ],
+                    output: vec![String::from("Too slow.")],
+                }],
+            }],
+        }];
+
+        let result = serde_json::from_str::<serde_json::Value>(&render(&reports)?)?;
+
+        assert_that!(result, eq(&json!({
+            "schema_version": 1,
+            "suites": [{
+                "name": "my_suite",
+                "outcome": "failure",
+                "cases": [{
+                    "identifier": "my_suite::MyCase",
+                    "name": "MyCase",
+                    "outcome": "failure",
+                    "tests": [{
+                        "identifier": "my_suite::MyCase::test_failure",
+                        "name": "test_failure",
+                        "outcome": "failure",
+                        "properties": [
+                            { "name": "speed", "type": "number", "value": 42 },
+                            { "name": "unit", "type": "string", "value": "km/h" },
+                            { "name": "trace", "type": "file_path", "value": "/tmp/trace.log" },
+                        ],
+                        "output": ["Too slow."],
+                    }],
+                }],
+            }],
+        })));
+
+        Ok(())
+    }
+}
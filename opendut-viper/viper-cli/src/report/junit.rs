@@ -0,0 +1,170 @@
+use std::fmt::Write;
+use std::ops::Not;
+
+use opendut_viper_rt::common::Identifier;
+use opendut_viper_rt::run::{Outcome, Report, ReportProperty, ReportPropertyValue, TestReport, TestSuiteReport};
+
+/// Renders the given reports as [JUnit XML](https://github.com/testmoapp/junitxml).
+///
+/// Files attached via report properties are referenced in the `system-out` of the respective test,
+/// using the `[[ATTACHMENT|<path>]]` convention understood by common CI servers.
+pub fn render(reports: &[TestSuiteReport]) -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+
+    let (tests, failures) = count(reports.iter().flat_map(|suite| suite.cases.iter()).flat_map(|case| case.tests.iter()));
+    let _ = writeln!(xml, r#"<testsuites name="viper" tests="{tests}" failures="{failures}">"#);
+
+    for suite in reports {
+        let (tests, failures) = count(suite.cases.iter().flat_map(|case| case.tests.iter()));
+        let _ = writeln!(xml, r#"  <testsuite name="{}" tests="{tests}" failures="{failures}">"#, escape(suite.name.as_str()));
+
+        for case in &suite.cases {
+            for test in &case.tests {
+                render_test(&mut xml, case.name.as_str(), test);
+            }
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn render_test(xml: &mut String, classname: &str, test: &TestReport) {
+    let _ = writeln!(xml, r#"    <testcase classname="{}" name="{}">"#, escape(classname), escape(test.identifier.name()));
+
+    if test.properties.is_empty().not() {
+        xml.push_str("      <properties>\n");
+        for ReportProperty { name, value } in &test.properties {
+            let value = match value {
+                ReportPropertyValue::Number(value) => value.to_string(),
+                ReportPropertyValue::String(value) => value.to_owned(),
+                ReportPropertyValue::FilePath(path) => path.display().to_string(),
+            };
+            let _ = writeln!(xml, r#"        <property name="{}" value="{}"/>"#, escape(name), escape(&value));
+        }
+        xml.push_str("      </properties>\n");
+    }
+
+    match test.outcome {
+        Outcome::Success => {}
+        Outcome::Failure => {
+            xml.push_str(r#"      <failure message="Test failed."/>"#);
+            xml.push('\n');
+        }
+        Outcome::Timeout => {
+            xml.push_str(r#"      <failure message="Test timed out." type="timeout"/>"#);
+            xml.push('\n');
+        }
+    }
+
+    let attachments = test.properties.iter()
+        .filter_map(|property| match &property.value {
+            ReportPropertyValue::FilePath(path) => Some(format!("[[ATTACHMENT|{}]]", path.display())),
+            _ => None,
+        });
+    let system_out = test.output.iter().cloned()
+        .chain(attachments)
+        .collect::<Vec<_>>();
+
+    if system_out.is_empty().not() {
+        let _ = writeln!(xml, "      <system-out>{}</system-out>", escape(&system_out.join("\n")));
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+fn count<'a>(tests: impl Iterator<Item=&'a TestReport>) -> (usize, usize) {
+    tests.fold((0, 0), |(tests, failures), test| {
+        (tests + 1, failures + usize::from(test.is_failure()))
+    })
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(char),
+            char if char.is_control() => {}
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use googletest::prelude::*;
+    use indoc::indoc;
+    use opendut_viper_rt::run::TestCaseReport;
+
+    use super::*;
+
+    #[test]
+    fn should_render_reports_as_junit_xml() -> Result<()> {
+        let reports = vec![TestSuiteReport {
+            name: "my_suite".try_into()?,
+            cases: vec![TestCaseReport {
+                name: "my_suite::MyCase".try_into()?,
+                tests: vec![
+                    TestReport {
+                        identifier: "my_suite::MyCase::test_success".try_into()?,
+                        outcome: Outcome::Success,
+                        properties: vec![
+                            ReportProperty { name: String::from("speed"), value: ReportPropertyValue::Number(42) },
+                            ReportProperty { name: String::from("trace"), value: ReportPropertyValue::FilePath(PathBuf::from("/tmp/trace.log")) },
+                        ],
+                        output: vec![String::from("Speed is <42>.")],
+                    },
+                    TestReport {
+                        identifier: "my_suite::MyCase::test_failure".try_into()?,
+                        outcome: Outcome::Failure,
+                        properties: vec![],
+                        output: vec![],
+                    },
+                    TestReport {
+                        identifier: "my_suite::MyCase::test_timeout".try_into()?,
+                        outcome: Outcome::Timeout,
+                        properties: vec![],
+                        output: vec![],
+                    },
+                ],
+            }],
+        }];
+
+        let result = render(&reports);
+
+        assert_that!(result, eq(indoc!(r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="viper" tests="3" failures="2">
+              <testsuite name="my_suite" tests="3" failures="2">
+                <testcase classname="my_suite::MyCase" name="test_success">
+                  <properties>
+                    <property name="speed" value="42"/>
+                    <property name="trace" value="/tmp/trace.log"/>
+                  </properties>
+                  <system-out>Speed is &lt;42&gt;.
+            [[ATTACHMENT|/tmp/trace.log]]</system-out>
+                </testcase>
+                <testcase classname="my_suite::MyCase" name="test_failure">
+                  <failure message="Test failed."/>
+                </testcase>
+                <testcase classname="my_suite::MyCase" name="test_timeout">
+                  <failure message="Test timed out." type="timeout"/>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#)));
+
+        Ok(())
+    }
+}
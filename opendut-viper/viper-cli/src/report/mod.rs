@@ -0,0 +1,28 @@
+mod json;
+mod junit;
+
+use std::error::Error;
+use std::fs;
+use std::ops::Not;
+
+use opendut_viper_rt::run::TestSuiteReport;
+
+use crate::parse::{ReportFormat, ReportTarget};
+
+pub fn write_reports(targets: &[ReportTarget], reports: &[TestSuiteReport]) -> Result<(), Box<dyn Error>> {
+    for target in targets {
+        let content = match target.format {
+            ReportFormat::Junit => junit::render(reports),
+            ReportFormat::Json => json::render(reports)?,
+        };
+
+        if let Some(parent) = target.path.parent()
+            && parent.as_os_str().is_empty().not() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&target.path, content)
+            .map_err(|cause| format!("Failed to write {} report to '{}': {cause}", target.format, target.path.display()))?;
+    }
+    Ok(())
+}
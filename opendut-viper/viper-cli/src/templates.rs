@@ -8,3 +8,14 @@ pub const SCRIPT_PY_TEMPLATE: &str = indoc! {"
         def test_hello(self):
             self.assertEquals(True, True)
 "};
+
+/// Stub for the `unittest.timeout` decorator, which is a plain function and therefore not covered by the generated stubs.
+pub const UNITTEST_PY_TIMEOUT_STUB: &str = indoc! {"
+
+    def timeout(seconds: float):
+        \"\"\"
+        Decorator limiting the execution time of a test to the given number of `seconds`.
+        A test exceeding its time limit is aborted and reported with the outcome `Timeout`.
+        \"\"\"
+        pass
+"};
@@ -0,0 +1,162 @@
+#![allow(clippy::module_inception)]
+use rustpython_vm::pymodule;
+
+#[pymodule]
+pub mod can {
+    use std::collections::BTreeMap;
+    use std::fmt::Formatter;
+    use std::path::Path;
+    use opendut_can_database::arxml_parser::ArxmlParser;
+    use opendut_can_database::can_database::{CanDatabase, CanMessage};
+    use opendut_can_database::dbc_parser::DbcParser;
+    use rustpython_vm::builtins::{PyDictRef, PyInt, PyStr};
+    use rustpython_vm::function::ArgBytesLike;
+    use rustpython_vm::{pyclass, PyObjectRef, PyPayload, PyResult, VirtualMachine};
+
+    /// Loads the CAN messages and signals described by a DBC or ARXML file.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// database = can.load_database("vehicle.dbc")
+    /// ```
+    #[pyfunction]
+    fn load_database(path: String, vm: &VirtualMachine) -> PyResult<PyCanDatabase> {
+        let is_arxml = Path::new(&path).extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("arxml"));
+
+        let database = if is_arxml {
+            ArxmlParser::default().parse_file(&path)
+                .map(|can_clusters| CanDatabase::from_can_clusters(&can_clusters))
+                .map_err(|cause| vm.new_value_error(cause.to_string()))?
+        } else {
+            DbcParser::default().parse_file(&path)
+                .map_err(|cause| vm.new_value_error(cause.to_string()))?
+        };
+        Ok(PyCanDatabase { database })
+    }
+
+    #[pyattr]
+    #[pyclass(name = "CanDatabase")]
+    #[derive(PyPayload)]
+    pub struct PyCanDatabase {
+        database: CanDatabase,
+    }
+
+    #[pyclass]
+    #[opendut_viper_pygen::pygen]
+    impl PyCanDatabase {
+
+        /// Decodes the signals of a `message`, given by its name or CAN ID, from the `data` of a frame.
+        /// Returns a dictionary of the signal names and their physical values.
+        ///
+        /// # Example
+        ///
+        /// ```python
+        /// values = database.decode("VehicleInfo", frame_data)
+        /// self.assertEquals(values["VehicleSpeed"], 88.5)
+        /// ```
+        #[pymethod]
+        fn decode(&self, message: PyObjectRef, data: ArgBytesLike, #[viper(skip)] vm: &VirtualMachine) -> PyResult<PyDictRef> {
+            let message = self.message(message, vm)?;
+            let values = message.decode(&data.borrow_buf())
+                .map_err(|cause| vm.new_value_error(cause.to_string()))?;
+
+            let dict = vm.ctx.new_dict();
+            for (name, value) in values {
+                dict.set_item(name.as_str(), vm.ctx.new_float(value.physical).into(), vm)?;
+            }
+            Ok(dict)
+        }
+
+        /// Decodes the signals of a `message` from the `data` of a frame and returns the descriptions of their values,
+        /// e.g. `"Drive"` for a gear signal. Signals without a description for their value are omitted.
+        ///
+        /// # Example
+        ///
+        /// ```python
+        /// descriptions = database.describe("VehicleInfo", frame_data)
+        /// self.assertEquals(descriptions["Gear"], "Drive")
+        /// ```
+        #[pymethod]
+        fn describe(&self, message: PyObjectRef, data: ArgBytesLike, #[viper(skip)] vm: &VirtualMachine) -> PyResult<PyDictRef> {
+            let message = self.message(message, vm)?;
+            let values = message.decode(&data.borrow_buf())
+                .map_err(|cause| vm.new_value_error(cause.to_string()))?;
+
+            let dict = vm.ctx.new_dict();
+            for (name, value) in values {
+                if let Some(description) = value.description {
+                    dict.set_item(name.as_str(), vm.ctx.new_str(description).into(), vm)?;
+                }
+            }
+            Ok(dict)
+        }
+
+        /// Decodes the physical value of a single `signal` of a `message` from the `data` of a frame.
+        ///
+        /// # Example
+        ///
+        /// ```python
+        /// speed = database.decode_signal("VehicleInfo", "VehicleSpeed", frame_data)
+        /// ```
+        #[pymethod]
+        fn decode_signal(&self, message: PyObjectRef, signal: String, data: ArgBytesLike, #[viper(skip)] vm: &VirtualMachine) -> PyResult<f64> {
+            let message = self.message(message, vm)?;
+            message.decode_signal(&signal, &data.borrow_buf())
+                .map(|value| value.physical)
+                .map_err(|cause| vm.new_value_error(cause.to_string()))
+        }
+
+        /// Encodes the physical values of the signals of a `message` into the data of a frame.
+        /// Signals without a given value are encoded as zero.
+        ///
+        /// # Example
+        ///
+        /// ```python
+        /// frame_data = database.encode("VehicleInfo", {"VehicleSpeed": 88.5, "Gear": 3})
+        /// ```
+        #[pymethod]
+        fn encode(&self, message: PyObjectRef, values: PyDictRef, #[viper(skip)] vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+            let message = self.message(message, vm)?;
+
+            let mut signal_values = BTreeMap::new();
+            for (name, value) in values {
+                let name = name.downcast_ref::<PyStr>()
+                    .ok_or_else(|| vm.new_type_error(String::from("Signal names must be strings")))?
+                    .to_string();
+                let value = value.try_float(vm)?.to_f64();
+                signal_values.insert(name, value);
+            }
+
+            let data = message.encode(&signal_values)
+                .map_err(|cause| vm.new_value_error(cause.to_string()))?;
+            Ok(vm.ctx.new_bytes(data).into())
+        }
+
+        #[viper(skip)]
+        fn message(&self, message: PyObjectRef, vm: &VirtualMachine) -> PyResult<&CanMessage> {
+            if let Some(name) = message.downcast_ref::<PyStr>() {
+                self.database.message(name.as_str())
+                    .map_err(|cause| vm.new_value_error(cause.to_string()))
+            }
+            else if let Some(can_id) = message.downcast_ref::<PyInt>() {
+                let can_id = u32::try_from(can_id.as_bigint())
+                    .map_err(|_| vm.new_value_error(format!("Invalid CAN ID {}", can_id.as_bigint())))?;
+                self.database.message_by_id(can_id, false)
+                    .or_else(|| self.database.message_by_id(can_id, true))
+                    .ok_or_else(|| vm.new_value_error(format!("Unknown CAN message with ID {can_id:#x}.")))
+            }
+            else {
+                Err(vm.new_type_error(String::from("The message must be given by its name or CAN ID")))
+            }
+        }
+    }
+
+    impl core::fmt::Debug for PyCanDatabase {
+        fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+            formatter.debug_struct("PyCanDatabase")
+                .finish()
+        }
+    }
+}
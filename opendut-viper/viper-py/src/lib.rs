@@ -1,3 +1,4 @@
+pub mod can;
 pub mod container;
 pub mod file;
 pub mod metadata;
@@ -13,6 +14,7 @@ mod viper {}
 
 pub fn create_module(vm: &rustpython_vm::vm::VirtualMachine) -> PyRef<PyModule> {
     let module = viper::make_module(vm);
+    module.set_attr("can", can::can::make_module(vm), vm).unwrap();
     module.set_attr("container", container::container::make_module(vm), vm).unwrap();
     module.set_attr("file", file::file::make_module(vm), vm).unwrap();
     module.set_attr("metadata", metadata::metadata::make_module(vm), vm).unwrap();
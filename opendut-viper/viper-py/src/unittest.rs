@@ -1,12 +1,30 @@
 #![allow(clippy::module_inception)]
 use rustpython_vm::pymodule;
 
+/// Name of the attribute holding the time limit in seconds, which is set on a test function by the `timeout` decorator.
+pub const TIMEOUT_ATTRIBUTE_NAME: &str = "__viper_timeout__";
+
 #[pymodule]
 pub mod unittest {
-    use rustpython_vm::function::OptionalArg;
+    use rustpython_vm::function::{ArgIntoFloat, OptionalArg};
     use rustpython_vm::{pyclass, AsObject, PyObjectRef, PyPayload, PyResult, VirtualMachine};
     use std::ops::Not;
 
+    /// Decorator limiting the execution time of a test to the given number of `seconds`.
+    /// A test exceeding its time limit is aborted and reported with the outcome `Timeout`.
+    #[pyfunction]
+    fn timeout(seconds: ArgIntoFloat, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        let seconds = f64::from(seconds);
+        if (seconds.is_finite() && seconds > 0.0).not() {
+            return Err(vm.new_value_error(format!("The timeout must be a positive number of seconds, but was {seconds}.")));
+        }
+        let decorator = move |function: PyObjectRef, vm: &VirtualMachine| -> PyResult<PyObjectRef> {
+            function.set_attr(super::TIMEOUT_ATTRIBUTE_NAME, vm.ctx.new_float(seconds), vm)?;
+            Ok(function)
+        };
+        Ok(vm.new_function("timeout", decorator).into())
+    }
+
     #[pyattr]
     #[pyclass(name)]
     #[derive(Debug, PyPayload)]
@@ -119,6 +119,8 @@
 //! 3. `setUpClass` is called before the first test method in the class - good for one-time setup across all tests like starting a server.
 //! 4. `tearDownClass` is called after the last test method in the class - good for cleaning up class-level resources like shutting down servers.
 //!
+//! When `setUp` fails, the test method and `tearDown` are skipped and the test fails. When `tearDown` fails, the test fails, too.
+//!
 //! ## Assertions
 //!
 //! Viper's Python API provides a set of assertions that you can use to write your test logic.
@@ -189,6 +191,7 @@
 //! #         Metadata {
 //! #             display_name: &Some(String::from("My Awesome Test Suite")),
 //! #             description: &Some(String::from("Verifies the awesomeness of my software.")),
+//! #             timeout: &None,
 //! #         }
 //! #     ));
 //! #     assert!(report.is_success());
@@ -196,7 +199,60 @@
 //! #     Ok(())
 //! # }
 //! ```
-//! 
+//!
+//! ## Timeouts
+//!
+//! A test, which does not finish within its time limit, is aborted and reported with the outcome
+//! [`Timeout`](crate::run::Outcome::Timeout). The remaining tests of the test suite are run nonetheless.
+//! The time limit covers `setUp`, the test and `tearDown` together. Once it is exhausted, the remaining steps are not run.
+//! A test case, including `setUpClass` and `tearDownClass`, has to finish within the time limits of all its tests added up.
+//!
+//! Python code is aborted between two instructions. Functions blocking within native code, like `time.sleep()`
+//! or waiting for a CAN frame, cannot be interrupted and are only aborted after they returned.
+//! Such calls should therefore be given a timeout of their own, which fits into the time limit of the test.
+//!
+//! A time limit in seconds for all tests of a test suite can be declared via the `timeout` field of the metadata.
+//! The `unittest.timeout` decorator sets the time limit of a single test, taking precedence over the metadata.
+//! When a test suite is run by openDuT, the time limit given in the run takes precedence over both.
+//!
+//! ```
+//! # use opendut_viper_rt::events::emitter;
+//! # use opendut_viper_rt::run::{Report, ParameterBindings};
+//! # use opendut_viper_rt::compile::IdentifierFilter;
+//! # use opendut_viper_rt::source::Source;
+//! # use opendut_viper_rt::ViperRuntime;
+//! # use indoc::indoc;
+//! # use std::time::Duration;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
+//! #     let viper = ViperRuntime::default();
+//! #     let source = Source::embedded(indoc!(r#"
+//! ## VIPER_VERSION = 1.0
+//! from viper import *
+//!
+//! METADATA = metadata.Metadata(
+//!     timeout=60,
+//! )
+//!
+//! class MyTestCase(unittest.TestCase):
+//!
+//!     def test_limited_by_metadata(self):
+//!         pass
+//!
+//!     @unittest.timeout(0.5)
+//!     def test_limited_by_decorator(self):
+//!         pass
+//! #     "#));
+//! #     let (metadata, _, suite) = viper.compile(&source, &mut emitter::drain(), &IdentifierFilter::default()).await?.split();
+//! #     assert_eq!(metadata.timeout, Some(Duration::from_secs(60)));
+//! #     let report = viper.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+//! #     assert!(report.is_success());
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
 //! ## Parameters
 //!
 //! Parameters allow you to define test inputs that can be specified outside the test, making it
@@ -460,6 +516,46 @@
 //! }
 //! ```
 //!
+//! ## CAN Signals
+//!
+//! The `can` module decodes and encodes the signals of CAN frames by their name. The messages and signals are loaded
+//! from a DBC or ARXML file via `can.load_database()`. A message can be referenced by its name or its CAN ID.
+//! Decoded values are physical values, i.e. with the factor and offset of the signal applied.
+//! The descriptions of the decoded values, like `"Drive"` for a gear signal, are returned by `describe()`.
+//!
+//! ```
+//! # use opendut_viper_rt::events::emitter;
+//! # use opendut_viper_rt::run::{Report, ParameterBindings};
+//! # use opendut_viper_rt::source::Source;
+//! # use opendut_viper_rt::ViperRuntime;
+//! # use opendut_viper_rt::compile::IdentifierFilter;
+//! # use indoc::indoc;
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let viper = ViperRuntime::default();
+//! #     let source = Source::embedded(indoc!(r#"
+//! ## VIPER_VERSION = 1.0
+//! from viper import *
+//!
+//! class MyTestCase(unittest.TestCase):
+//!     def test_vehicle_speed(self):
+//!         database = can.load_database("tests/vehicle.dbc")
+//!         frame_data = database.encode("VehicleInfo", {"VehicleSpeed": 50})
+//!
+//!         speed = database.decode_signal(0x100, "VehicleSpeed", frame_data)
+//!         self.assertEquals(speed, 50.0)
+//!
+//!         gear = database.describe("VehicleInfo", database.encode("VehicleInfo", {"Gear": 3}))["Gear"]
+//!         self.assertEquals(gear, "Drive")
+//! #     "#));
+//! #     let (_, _, suite) = viper.compile(&source, &mut emitter::drain(), &IdentifierFilter::default()).await?.split();
+//! #     let report = viper.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+//! #     assert!(report.is_success());
+//! #     Ok(())
+//! # }
+//! ```
+//!
 //! ## Containers
 //!
 //! <div class="warning">
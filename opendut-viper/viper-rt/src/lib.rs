@@ -33,6 +33,7 @@
 //!     match report.outcome() {
 //!         Outcome::Success => println!("All tests passed."),
 //!         Outcome::Failure => println!("Some tests failed."),
+//!         Outcome::Timeout => println!("Some tests timed out."),
 //!     }
 //!
 //!     Ok(())
@@ -57,7 +58,7 @@
 //! | ----------- |:--------:| --------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------- |
 //! | file-source | &#x2717; | When enabled, this crate provides a [`SimpleFileSourceLoader`](crate::source::loaders::SimpleFileSourceLoader) to use local files as a source.                                                |
 //! | http-source | &#x2717; | When enabled, this crate provides a [`HttpSourceLoader`](crate::source::loaders::HttpSourceLoader) to use HTTP resources as a source.                                                         |
-// | git-source  | &#x2717; | When enabled, this crate provides a [SourceLoader](`crate::source::loaders::SourceLoader`) implementation to use [Git](https://git-scm.com/) repositories as a source.                         |
+//! | git-source  | &#x2717; | When enabled, this crate provides a [`GitSourceLoader`](crate::source::loaders::GitSourceLoader) to use [Git](https://git-scm.com/) repositories as a source. Requires a [tokio](https://tokio.rs/) runtime. |
 //!
 //! <sup>&#x2714; enabled, &#x2717; disabled</sup>
 //!
@@ -226,7 +227,10 @@ pub mod source {
             HttpSourceLoaderOptions
         };
         #[cfg(feature = "git-source")]
-        pub use crate::runtime::source::git::GitSourceLoader;
+        pub use crate::runtime::source::git::{
+            GitSourceLoader,
+            GitSourceLoaderOptions
+        };
     }
 }
 
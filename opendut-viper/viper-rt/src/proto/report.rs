@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+use opendut_util::conversion;
+use opendut_util::proto::ConversionResult;
+use crate::runtime::types::naming::Identifier;
+
+opendut_util::include_proto!("opendut.viper.rt.report");
+
+
+conversion! {
+    type Model = crate::runtime::types::run::report::TestSuiteReport;
+    type Proto = ViperTestSuiteReport;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            name: Some(value.name.into()),
+            cases: value.cases.into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let name = extract!(value.name)?
+            .try_into()?;
+
+        let cases = value.cases.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Model { name, cases })
+    }
+}
+
+conversion! {
+    type Model = crate::runtime::types::run::report::TestCaseReport;
+    type Proto = ViperTestCaseReport;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            name: value.name.as_str().to_owned(),
+            tests: value.tests.into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let name = value.name.try_into()
+            .map_err(|cause| ErrorBuilder::message(format!("Error while parsing TestCaseIdentifier from Protobuf message: {cause}")))?;
+
+        let tests = value.tests.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Model { name, tests })
+    }
+}
+
+conversion! {
+    type Model = crate::runtime::types::run::report::TestReport;
+    type Proto = ViperTestReport;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            identifier: value.identifier.as_str().to_owned(),
+            outcome: Some(value.outcome.into()),
+            properties: value.properties.into_iter()
+                .map(Into::into)
+                .collect(),
+            output: value.output,
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let identifier = value.identifier.try_into()
+            .map_err(|cause| ErrorBuilder::message(format!("Error while parsing TestIdentifier from Protobuf message: {cause}")))?;
+
+        let outcome = extract!(value.outcome)?
+            .try_into()?;
+
+        let properties = value.properties.into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Model {
+            identifier,
+            outcome,
+            properties,
+            output: value.output,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::runtime::types::run::report::Outcome;
+    type Proto = ViperOutcome;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Success => viper_outcome::Kind::Success(ViperOutcomeSuccess {}),
+            Model::Failure => viper_outcome::Kind::Failure(ViperOutcomeFailure {}),
+            Model::Timeout => viper_outcome::Kind::Timeout(ViperOutcomeTimeout {}),
+        };
+        Proto { kind: Some(kind) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let outcome = match extract!(value.kind)? {
+            viper_outcome::Kind::Success(_) => Model::Success,
+            viper_outcome::Kind::Failure(_) => Model::Failure,
+            viper_outcome::Kind::Timeout(_) => Model::Timeout,
+        };
+        Ok(outcome)
+    }
+}
+
+conversion! {
+    type Model = crate::runtime::types::run::report::ReportProperty;
+    type Proto = ViperReportProperty;
+
+    fn from(value: Model) -> Proto {
+        Proto {
+            name: value.name,
+            value: Some(value.value.into()),
+        }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let property_value = extract!(value.value)?
+            .try_into()?;
+
+        Ok(Model {
+            name: value.name,
+            value: property_value,
+        })
+    }
+}
+
+conversion! {
+    type Model = crate::runtime::types::run::report::ReportPropertyValue;
+    type Proto = ViperReportPropertyValue;
+
+    fn from(value: Model) -> Proto {
+        let kind = match value {
+            Model::Number(value) => viper_report_property_value::Kind::Number(value),
+            Model::String(value) => viper_report_property_value::Kind::String(value),
+            Model::FilePath(path) => viper_report_property_value::Kind::FilePath(path.to_string_lossy().into_owned()),
+        };
+        Proto { kind: Some(kind) }
+    }
+
+    fn try_from(value: Proto) -> ConversionResult<Model> {
+        let property_value = match extract!(value.kind)? {
+            viper_report_property_value::Kind::Number(value) => Model::Number(value),
+            viper_report_property_value::Kind::String(value) => Model::String(value),
+            viper_report_property_value::Kind::FilePath(path) => Model::FilePath(PathBuf::from(path)),
+        };
+        Ok(property_value)
+    }
+}
@@ -1,4 +1,5 @@
 use std::ops::Not;
+use std::time::Duration;
 use crate::compile::{ParameterInfo, Test, TestSuite};
 use crate::runtime::types::compile::code::SourceCode;
 use crate::runtime::types::compile::inspect::InspectionError;
@@ -6,22 +7,26 @@ use crate::runtime::types::compile::metadata::{Metadata, MetadataError};
 use crate::runtime::types::compile::parameters::{ParameterDescriptor, ParameterDescriptors, ParameterError};
 use crate::runtime::types::compile::suite::TestCase;
 use crate::runtime::types::naming::{TestCaseIdentifier, TestIdentifier, TestSuiteIdentifier};
+use crate::runtime::timeout::Watchdog;
 use crate::runtime::types::py::error::PythonReflectionError;
 use rustpython_vm::builtins::{PyClassMethod, PyModule, PyStr, PyType};
 use rustpython_vm::class::StaticType;
-use rustpython_vm::{AsObject, Interpreter};
+use rustpython_vm::function::ArgIntoFloat;
+use rustpython_vm::{AsObject, Interpreter, TryFromObject, VirtualMachine};
 use rustpython_vm::{PyObjectRef, PyRef};
 use opendut_viper_py::metadata::metadata::PyMetadata;
 use opendut_viper_py::parameters::parameters::{PyBooleanParameterDescriptor, PyNumberParameterDescriptor, PyPeerInterfaceParameterDescriptor, PyTextParameterDescriptor};
 use opendut_viper_py::unittest::unittest::TestCase as PyTestCase;
+use opendut_viper_py::unittest::TIMEOUT_ATTRIBUTE_NAME;
 use crate::runtime::types::compile::filter::{FilterError, IdentifierFilter};
 
-pub fn inspect(source_code: SourceCode, module: PyRef<PyModule>, interpreter: Interpreter, identifier_filter: &IdentifierFilter)
+pub fn inspect(source_code: SourceCode, module: PyRef<PyModule>, interpreter: Interpreter, watchdog: Watchdog, identifier_filter: &IdentifierFilter)
     -> Result<(Metadata, ParameterDescriptors, TestSuite), InspectionError>
 {
     let SourceCode { identifier, code: _code, version } = source_code;
     let (cases, metadata, parameters) = traverse_code(&identifier, &module, &interpreter, identifier_filter)?;
-    Ok((metadata, parameters, TestSuite { identifier, version, interpreter, module, cases }))
+    let timeout = metadata.timeout;
+    Ok((metadata, parameters, TestSuite { identifier, version, interpreter, module, cases, timeout, watchdog }))
 }
 
 fn traverse_code(test_suite_name: &TestSuiteIdentifier, py_module: &PyRef<PyModule>, interpreter: &Interpreter, identifier_filter: &IdentifierFilter)
@@ -49,7 +54,7 @@ fn traverse_code(test_suite_name: &TestSuiteIdentifier, py_module: &PyRef<PyModu
             }
         }
         else if let Some(data) = value.payload::<PyMetadata>() {
-            metadata.replace(make_metadata(&key, data) // TODO: If returned (old) value is `Option::Some`, there are multiple metadata variables of which the user should be warned.
+            metadata.replace(interpreter.enter(|vm| make_metadata(&key, data, vm)) // TODO: If returned (old) value is `Option::Some`, there are multiple metadata variables of which the user should be warned.
                 .map_err(InspectionError::new_invalid_metadata_error)?);
         }
         else if let Some(parameter) = value.payload::<PyBooleanParameterDescriptor>() {
@@ -97,7 +102,9 @@ fn make_test_case(test_type: &PyType, test_case_name: TestCaseIdentifier, interp
                     if identifier_filter.matches_test(&identifier).not() {
                         continue;
                     }
-                    tests.push(Test { identifier, function: value.clone() })
+                    let timeout = value.get_attr(TIMEOUT_ATTRIBUTE_NAME, vm).ok()
+                        .and_then(|timeout| to_duration(&timeout, vm));
+                    tests.push(Test { identifier, timeout, function: value.clone() })
                 } else if name == "setUp" {
                     setup_fn = Some(value.clone());
                 } else if name == "tearDown" {
@@ -131,7 +138,7 @@ fn make_test_case(test_type: &PyType, test_case_name: TestCaseIdentifier, interp
     })
 }
 
-fn make_metadata(key: &PyObjectRef, _metadata: &PyMetadata) -> Result<Metadata, MetadataError> {
+fn make_metadata(key: &PyObjectRef, _metadata: &PyMetadata, vm: &VirtualMachine) -> Result<Metadata, MetadataError> {
     let _key = key.downcast_ref::<PyStr>()
         .ok_or_else(|| PythonReflectionError::new_downcast_error(key, "PyStr"))
         .expect("downcast to `PyStr` for metadata key")
@@ -155,6 +162,11 @@ fn make_metadata(key: &PyObjectRef, _metadata: &PyMetadata) -> Result<Metadata,
                     return Err(MetadataError::new_wrong_attribute_type_error("description", "String"))
                 }
             }
+            "timeout" => {
+                let timeout = to_duration(attr_value, vm)
+                    .ok_or_else(|| MetadataError::new_wrong_attribute_type_error("timeout", "positive Number"))?;
+                metadata.timeout = Some(timeout);
+            }
             "selector" => {
                 // TODO: Metadata attribute 'selector' is not supported yet
             }
@@ -166,6 +178,13 @@ fn make_metadata(key: &PyObjectRef, _metadata: &PyMetadata) -> Result<Metadata,
     Ok(metadata)
 }
 
+/// Converts a number of seconds into a [`Duration`], if it is positive.
+fn to_duration(value: &PyObjectRef, vm: &VirtualMachine) -> Option<Duration> {
+    let seconds = f64::from(ArgIntoFloat::try_from_object(vm, Clone::clone(value)).ok()?);
+    Duration::try_from_secs_f64(seconds).ok()
+        .filter(|duration| duration.is_zero().not())
+}
+
 fn make_boolean_parameter(parameter: &PyBooleanParameterDescriptor) -> Result<ParameterDescriptor, ParameterError> {
     Ok(ParameterDescriptor::BooleanParameter {
         name: Clone::clone(&parameter.name).try_into()?,
@@ -1,11 +1,11 @@
 mod emit;
 mod inspect;
 mod prepare;
-mod py;
+pub(crate) mod py;
 
 use crate::compile::{Compilation, CompileEvent, IdentifierFilter};
 use crate::runtime::compile::inspect::inspect;
-use crate::runtime::compile::prepare::prepare_source_code;
+use crate::runtime::compile::prepare::{load_source, prepare_source_code};
 use crate::runtime::compile::py::{compile_source_code, create_interpreter};
 use crate::runtime::ctx::Context;
 use crate::runtime::emitter::EventEmitter;
@@ -72,10 +72,15 @@ pub async fn compile_source(
 ) -> CompileResult<Compilation> {
 
     let source_code = prepare_source_code(source, context).await?;
-    let interpreter = create_interpreter();
-    let py_module = compile_source_code(&source_code, &interpreter)?;
-    let (metadata, parameters, suite) = inspect(source_code, py_module, interpreter, identifier_filter)
+    let (interpreter, watchdog) = create_interpreter();
+    let py_module = watchdog.timeout(context.compile_timeout, || compile_source_code(&source_code, &interpreter))
+        .map_err(|_| CompilationError::new_timeout_error(source, context.compile_timeout.unwrap_or_default()))??;
+    let (metadata, parameters, suite) = inspect(source_code, py_module, interpreter, watchdog, identifier_filter)
         .map_err(|error| CompilationError::new_inspection_failure_error(source, error))?;
 
     Ok(Compilation::new(metadata, parameters, suite))
 }
+
+pub async fn load(source: &Source, context: &Context) -> CompileResult<String> {
+    load_source(source, context).await
+}
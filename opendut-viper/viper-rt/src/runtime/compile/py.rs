@@ -4,12 +4,16 @@ use crate::runtime::types::compile::error::CompilationError;
 use crate::runtime::types::py::error::{PythonReflectionError, PythonRuntimeError};
 use rustpython_vm::builtins::{PyCode, PyModule, PyStr};
 use rustpython_vm::scope::Scope;
-use rustpython_vm::{compiler, Interpreter, PyRef, Settings, VirtualMachine};
-
-pub fn create_interpreter() -> Interpreter {
-    Interpreter::with_init(Settings::default(), |_vm| {
-        // currently nothing to do.
-    })
+use crate::runtime::timeout::Watchdog;
+use rustpython_vm::{compiler, signal, Interpreter, PyRef, Settings, VirtualMachine};
+
+/// Creates a new interpreter and a [`Watchdog`] to interrupt code running in it.
+pub fn create_interpreter() -> (Interpreter, Watchdog) {
+    let (signal_sender, signal_receiver) = signal::user_signal_channel();
+    let interpreter = Interpreter::with_init(Settings::default(), |vm| {
+        vm.set_user_signal_channel(signal_receiver);
+    });
+    (interpreter, Watchdog::new(signal_sender))
 }
 
 pub fn compile_source_code(source_code: &SourceCode, py_interpreter: &Interpreter) -> CompileResult<PyRef<PyModule>> {
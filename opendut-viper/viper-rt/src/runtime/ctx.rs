@@ -4,4 +4,5 @@ pub struct Context {
     pub source_loaders: Vec<Box<dyn SourceLoader>>,
     #[cfg(feature = "containers")]
     pub container_runtime: Option<opendut_viper_containers::ContainerRuntime>,
+    pub compile_timeout: Option<std::time::Duration>,
 }
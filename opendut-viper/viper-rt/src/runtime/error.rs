@@ -77,6 +77,9 @@ impl Display for CompilationError {
             CompilationErrorKind::PythonRuntimeError { cause } => {
                 write!(f, "Compilation failed, due to a Python runtime error in source '{source_name}': {cause}")
             }
+            CompilationErrorKind::Timeout { timeout } => {
+                write!(f, "Compilation failed, because the code of source '{source_name}' did not finish within {} seconds.", timeout.as_secs_f64())
+            }
         }
     }
 }
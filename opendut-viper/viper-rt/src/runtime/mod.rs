@@ -16,9 +16,11 @@ pub mod source;
 pub mod types;
 
 mod ctx;
-mod timeout;
 mod error;
 
+#[cfg(feature = "py")]
+pub(crate) mod timeout;
+
 pub struct ViperRuntime {
     context: Context,
 }
@@ -29,6 +31,7 @@ impl Default for ViperRuntime {
     /// **Note:** The runtime contains an [`EmbeddedSourceLoader`] to load embedded sources.
     /// 
     fn default() -> Self {
+        #[cfg_attr(not(feature = "containers"), allow(clippy::needless_update))]
         let options = ViperOptions {
             source_loaders: vec![Box::new(EmbeddedSourceLoader)],
             ..Default::default()
@@ -46,6 +49,7 @@ impl ViperRuntime {
                 source_loaders: options.source_loaders,
                 #[cfg(feature = "containers")]
                 container_runtime: options.container_runtime,
+                compile_timeout: options.compile_timeout,
             },
         })
     }
@@ -71,6 +75,12 @@ impl ViperRuntime {
         compile::compile_tree(sources, &self.context, identifier_filter).await
     }
 
+    /// Loads the code of the given source with the registered source loaders, without compiling it.
+    #[cfg(feature = "compile")]
+    pub async fn load(&self, source: &types::source::Source) -> types::compile::error::CompileResult<String> {
+        compile::load(source, &self.context).await
+    }
+
     #[cfg(feature = "compile")]
     pub async fn compile(
         &self,
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::runtime::RuntimeInstantiationError;
 use crate::runtime::source::SourceLoader;
 use crate::ViperRuntime;
@@ -7,6 +9,8 @@ pub struct ViperOptions {
     pub source_loaders: Vec<Box<dyn SourceLoader>>,
     #[cfg(feature = "containers")]
     pub container_runtime: Option<crate::containers::ContainerRuntime>,
+    /// Maximum time the top-level code of a test suite may run while it is compiled. Unlimited, if not specified.
+    pub compile_timeout: Option<Duration>,
 }
 
 #[derive(Default)]
@@ -34,6 +38,13 @@ impl ViperBuilder {
         self
     }
 
+    /// Limits the time the top-level code of a test suite may run while it is compiled,
+    /// e.g. when compiling test suites from untrusted sources.
+    pub fn with_compile_timeout(mut self, timeout: Duration) -> Self {
+        self.options.compile_timeout = Some(timeout);
+        self
+    }
+
     pub fn build(self) -> Result<ViperRuntime, RuntimeInstantiationError> {
         ViperRuntime::new(self.options)
     }
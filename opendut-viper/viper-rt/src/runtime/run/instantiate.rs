@@ -11,6 +11,7 @@ use crate::runtime::types::run::suite::{TestCaseRun, TestRun, TestSuiteRun};
 use rustpython_vm::builtins::{PyModule, PyStr, PyType, PyTypeRef};
 use rustpython_vm::{PyObjectRef, PyRef, VirtualMachine};
 use std::rc::Rc;
+use std::time::Duration;
 use tracing::debug;
 
 pub fn instantiate(
@@ -19,6 +20,7 @@ pub fn instantiate(
     context: &Context,
     bindings: Rc<ParameterBindings<Complete>>,
     test_cases: &[TestCase],
+    timeout: Option<Duration>,
     vm: &VirtualMachine,
 ) -> RunResult<TestSuiteRun> {
 
@@ -54,6 +56,7 @@ pub fn instantiate(
             .map(|test| {
                 TestRun {
                     identifier: Clone::clone(&test.identifier),
+                    timeout: test.timeout.or(timeout),
                     test_fn: Clone::clone(&test.function),
                     setup_fn: Clone::clone(setup_fn),
                     teardown_fn: Clone::clone(teardown_fn),
@@ -78,6 +81,7 @@ pub fn instantiate(
 
 fn make_test_case_instance(
     class: &PyTypeRef,
+    #[cfg_attr(not(feature = "containers"), allow(unused_variables))]
     context: &Context,
     bindings: Rc<ParameterBindings<Complete>>,
     vm: &VirtualMachine,
@@ -8,6 +8,7 @@ use crate::runtime::ctx::Context;
 use crate::runtime::emitter::EventEmitter;
 use crate::runtime::run::instantiate::instantiate;
 use crate::runtime::run::py::{make_report_properties, make_stdout_object};
+use crate::runtime::timeout::{Elapsed, Watchdog};
 use crate::runtime::types::py::error::PythonReflectionError;
 use crate::runtime::types::run::error::RunError;
 use crate::runtime::types::run::error::RunResult;
@@ -16,9 +17,10 @@ use crate::runtime::types::run::report::TestCaseReport;
 use crate::runtime::types::run::suite::{TestCaseRun, TestRun, TestSuiteRun};
 use rustpython_vm::builtins::PyBaseExceptionRef;
 use rustpython_vm::function::OptionalArg;
-use rustpython_vm::{Interpreter, PyObjectRef, VirtualMachine};
+use rustpython_vm::{Interpreter, PyObjectRef, PyResult, VirtualMachine};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info};
 use opendut_viper_py::file::file::FileHandler;
 
@@ -29,24 +31,25 @@ pub async fn run(
     emitter: &mut dyn EventEmitter<RunEvent>
 ) -> RunResult<TestSuiteReport> {
 
-    let TestSuite { identifier, interpreter, module, cases, .. } = suite;
+    let TestSuite { identifier, interpreter, module, cases, timeout, watchdog, .. } = suite;
     let bindings = Rc::new(bindings);
 
     let test_suite_run = interpreter.enter(|vm| {
-        instantiate(&identifier, module, context, bindings, &cases, vm)
+        instantiate(&identifier, module, context, bindings, &cases, timeout, vm)
     })?;
 
     let state = TestSuiteRunState::from_run(&test_suite_run);
 
     emit::initialized(emitter, Clone::clone(&state)).await?;
 
-    run_test_suite(test_suite_run, emitter, &interpreter).await
+    run_test_suite(test_suite_run, emitter, &interpreter, &watchdog).await
 }
 
 async fn run_test_suite(
     suite: TestSuiteRun,
     emitter: &mut dyn EventEmitter<RunEvent>,
     interpreter: &Interpreter,
+    watchdog: &Watchdog,
 ) -> RunResult<TestSuiteReport> {
 
     let TestSuiteRun { identifier, cases } = suite;
@@ -61,7 +64,7 @@ async fn run_test_suite(
     emit::test_suite_started(emitter, Clone::clone(&identifier)).await?;
 
     for case in cases {
-        let test_case_report = run_test_case(case, emitter, interpreter).await?;
+        let test_case_report = run_test_case(case, emitter, interpreter, watchdog).await?;
         test_suite_report.cases.push(test_case_report);
     }
 
@@ -81,6 +84,7 @@ async fn run_test_case(
     test_case: TestCaseRun,
     emitter: &mut dyn EventEmitter<RunEvent>,
     interpreter: &Interpreter,
+    watchdog: &Watchdog,
 ) -> RunResult<TestCaseReport> {
 
     let TestCaseRun { identifier, ty, instance, setup_class_fn, teardown_class_fn, tests } = test_case;
@@ -94,22 +98,32 @@ async fn run_test_case(
 
     emit::test_case_started(emitter, Clone::clone(&identifier)).await?;
 
+    // The test case, including `setUpClass` and `tearDownClass`, has to finish within the time limits of all its tests together.
+    let deadline = tests.iter()
+        .map(|test| test.timeout)
+        .sum::<Option<Duration>>()
+        .filter(|timeout| !timeout.is_zero())
+        .map(Deadline::after)
+        .unwrap_or(Deadline::NONE);
+
     interpreter.enter(|vm| {
-        if let Some(setup_class_fn) = &setup_class_fn
-        && let Err(err) = setup_class_fn.call((ty.clone(), ), vm) {
-            print_error(err);
+        if let Some(setup_class_fn) = &setup_class_fn {
+            call_within_deadline(watchdog, deadline, format!("Class setup of test case '{identifier}'"), || {
+                setup_class_fn.call((ty.clone(), ), vm)
+            });
         }
     });
 
     for test in tests {
-        let test_report = run_test(test, Clone::clone(&instance), emitter, interpreter).await?;
+        let test_report = run_test(test, Clone::clone(&instance), deadline, emitter, interpreter, watchdog).await?;
         test_case_report.tests.push(test_report);
     }
 
     interpreter.enter(|vm| {
-        if let Some(teardown_class_fn) = teardown_class_fn
-        && let Err(error) = teardown_class_fn.call((ty, ), vm) {
-            print_error(error);
+        if let Some(teardown_class_fn) = teardown_class_fn {
+            call_within_deadline(watchdog, deadline, format!("Class teardown of test case '{identifier}'"), || {
+                teardown_class_fn.call((ty, ), vm)
+            });
         }
     });
 
@@ -128,16 +142,23 @@ async fn run_test_case(
 async fn run_test(
     test: TestRun,
     instance: PyObjectRef,
+    test_case_deadline: Deadline,
     emitter: &mut dyn EventEmitter<RunEvent>,
     interpreter: &Interpreter,
+    watchdog: &Watchdog,
 ) -> RunResult<TestReport> {
 
-    let TestRun { identifier, test_fn, setup_fn, teardown_fn } = test;
+    let TestRun { identifier, timeout, test_fn, setup_fn, teardown_fn } = test;
 
     debug!("Running test: {}", identifier);
 
     emit::test_started(emitter, Clone::clone(&identifier)).await?;
 
+    // `setUp`, the test and `tearDown` share the time limit of the test.
+    let deadline = timeout.map(Deadline::after)
+        .unwrap_or(Deadline::NONE)
+        .earliest(test_case_deadline);
+
     let test_report = interpreter.enter(|vm| {
         let buffer = Rc::new(RefCell::new(Some(Vec::<String>::new())));
         let stdout = make_stdout_object(vm, Rc::clone(&buffer));
@@ -154,11 +175,6 @@ async fn run_test(
             })
             .map_err(|cause| RunError::new_python_reflection_error(Clone::clone(&identifier), cause))?;
 
-        if let Some(setup_fn) = setup_fn
-        && let Err(err) = setup_fn.call((Clone::clone(&instance), ), vm) { // TODO: Decide, what should happen when a setup function fails.
-            print_error(err);
-        }
-
         vm.sys_module.set_attr("stdout", stdout, vm)
             .map_err(|_| PythonReflectionError::new_attribute_not_writable_error("sys", "stdout"))
             .map_err(|cause| RunError::new_python_reflection_error(Clone::clone(&identifier), cause))?;
@@ -169,19 +185,30 @@ async fn run_test(
             .map_err(|_| PythonReflectionError::new_attribute_not_writable_error("builtins", "open"))
             .map_err(|cause| RunError::new_python_reflection_error(Clone::clone(&identifier), cause))?;
 
-        let py_result = test_fn.call((Clone::clone(&instance), ), vm);
-
-        let outcome = match py_result {
-            Ok(_) => Outcome::Success,
-            Err(error) => {
-                print_error(error);
-                Outcome::Failure
-            },
+        // Like in Python's unittest, the test and `tearDown` are skipped, if `setUp` did not succeed.
+        let setup_outcome = match setup_fn {
+            Some(setup_fn) => call_within_deadline(watchdog, deadline, format!("Setup of test '{identifier}'"), || {
+                setup_fn.call((Clone::clone(&instance), ), vm)
+            }),
+            None => Outcome::Success,
         };
 
-        if let Some(teardown_fn) = teardown_fn {
-            teardown_fn.call((Clone::clone(&instance), ), vm).unwrap(); // TODO: Decide what should happen when a teardown function fails.
-        }
+        let outcome = if setup_outcome == Outcome::Success {
+            let test_outcome = call_within_deadline(watchdog, deadline, format!("Test '{identifier}'"), || {
+                test_fn.call((Clone::clone(&instance), ), vm)
+            });
+
+            let teardown_outcome = match teardown_fn {
+                Some(teardown_fn) => call_within_deadline(watchdog, deadline, format!("Teardown of test '{identifier}'"), || {
+                    teardown_fn.call((Clone::clone(&instance), ), vm)
+                }),
+                None => Outcome::Success,
+            };
+
+            if test_outcome == Outcome::Success { teardown_outcome } else { test_outcome }
+        } else {
+            setup_outcome
+        };
 
         Ok(TestReport {
             identifier: Clone::clone(&identifier),
@@ -205,6 +232,59 @@ async fn run_test(
     test_report
 }
 
+/// Point in time, by which a test or test case has to be finished.
+#[derive(Clone, Copy, Debug)]
+struct Deadline(Option<Instant>);
+
+impl Deadline {
+    const NONE: Deadline = Deadline(None);
+
+    fn after(timeout: Duration) -> Self {
+        Self(Instant::now().checked_add(timeout))
+    }
+
+    fn earliest(self, other: Deadline) -> Self {
+        match (self.0, other.0) {
+            (Some(this), Some(other)) => Self(Some(this.min(other))),
+            (this, other) => Self(this.or(other)),
+        }
+    }
+
+    fn remaining(self) -> Option<Duration> {
+        self.0.map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// Calls a function of a test before the given deadline and determines the outcome of the call.
+/// The function is not called at all, if the deadline already passed.
+///
+/// The function can only be interrupted between two Python instructions, see [`Watchdog`].
+/// Native calls blocking the interpreter, like `time.sleep()` or waiting for a CAN frame, are only aborted after they returned.
+fn call_within_deadline(
+    watchdog: &Watchdog,
+    deadline: Deadline,
+    description: String,
+    function: impl FnOnce() -> PyResult,
+) -> Outcome {
+    let remaining = deadline.remaining();
+    if remaining == Some(Duration::ZERO) {
+        error!("{description} was not started, as its time limit is exhausted.");
+        return Outcome::Timeout;
+    }
+
+    match watchdog.timeout(remaining, function) {
+        Ok(Ok(_)) => Outcome::Success,
+        Ok(Err(error)) => {
+            print_error(error);
+            Outcome::Failure
+        },
+        Err(Elapsed) => {
+            error!("{description} did not finish within its time limit of {:?}.", remaining.unwrap_or_default());
+            Outcome::Timeout
+        },
+    }
+}
+
 fn print_error(error: PyBaseExceptionRef) {
     let args = error.args().iter().map(|arg| format!("{arg:?}")).collect::<Vec<String>>().join(", ");
     error!("error: {error:?}: {:?}", args);
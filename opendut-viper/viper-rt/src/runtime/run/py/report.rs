@@ -33,7 +33,7 @@ impl ReportPropertiesCollector for Collector {
     fn set_file_property(&self, value: String) -> PyResult<()> {
         self.0.borrow_mut().push(ReportProperty {
             name: Clone::clone(&value),
-            value: ReportPropertyValue::File(PathBuf::from(value)),
+            value: ReportPropertyValue::FilePath(PathBuf::from(value)),
         });
         Ok(())
     }
@@ -1,8 +1,80 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use git2::{Commit, ObjectType, Oid, Repository};
+use url::Url;
+
 use crate::runtime::source::loader::SourceLoaderResult;
-use crate::runtime::source::SourceLoader;
-use crate::source::Source;
+use crate::runtime::source::{SourceLoader, SourceLoaderError};
+use crate::runtime::types::naming::Identifier;
+use crate::source::{Source, SourceLocation};
+
+const URL_SCHEME_PREFIX: &str = "git+";
+const FRAGMENT_KEY_REF: &str = "ref";
+const FRAGMENT_KEY_PATH: &str = "path";
+const REMOTE_HEAD: &str = "refs/remotes/origin/HEAD";
+
+/// An implementation of a [SourceLoader] that loads a [Source] from a [Git](https://git-scm.com/) repository.
+///
+/// Sources are addressed with URLs of the form `git+<remote-url>#ref=<ref>&path=<path>`, e.g.:
+/// ```text
+/// git+https://example.com/tests.git#ref=main&path=suites/can_tests.py
+/// ```
+///
+/// * `ref` is the name of a branch or tag, or a commit hash. Defaults to the `HEAD` of the remote repository.
+/// * `path` is the path of the test suite file within the repository. If the path denotes a directory,
+///   the test suite is looked up by the [`TestSuiteIdentifier`](crate::common::TestSuiteIdentifier) of the [Source],
+///   which allows loading multiple test suites from the same repository. Defaults to the root of the repository.
+///
+/// Repositories are fetched into a bare repository within the cache directory configured via [`GitSourceLoaderOptions`].
+/// The cached repository is named after a hash of the remote URL without credentials, so credentials do not end up in file names.
+/// Subsequent loads of the same repository only fetch the changes.
+/// Concurrent loads of the same repository, also from other processes, wait for each other via a lock file next to the cached repository.
+///
+/// Git operations are blocking, so they are run on the blocking thread pool of [tokio](https://tokio.rs/),
+/// which requires the loader to be used within a tokio runtime.
+///
+/// # Example
+/// ```no_run
+/// use opendut_viper_rt::events::emitter;
+/// use opendut_viper_rt::source::loaders::GitSourceLoader;
+/// use opendut_viper_rt::source::Source;
+/// use opendut_viper_rt::ViperRuntime;
+/// use opendut_viper_rt::compile::IdentifierFilter;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///
+///     let runtime = ViperRuntime::builder()
+///         .with_source_loader(GitSourceLoader::default())
+///         .build()?;
+///
+///     let url = "git+https://example.com/tests.git#ref=v1.0.0&path=suites";
+///     let source = Source::try_from_url_str("can_tests".try_into()?, url)?;
+///
+///     runtime.compile(&source, &mut emitter::drain(), &IdentifierFilter::default()).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+pub struct GitSourceLoader {
+    options: GitSourceLoaderOptions,
+}
+
+impl GitSourceLoader {
 
-pub struct GitSourceLoader;
+    pub fn new(options: GitSourceLoaderOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Default for GitSourceLoader {
+
+    fn default() -> Self {
+        GitSourceLoader::new(GitSourceLoaderOptions::default())
+    }
+}
 
 #[async_trait::async_trait]
 impl SourceLoader for GitSourceLoader {
@@ -11,11 +83,203 @@ impl SourceLoader for GitSourceLoader {
         "GitSourceLoader"
     }
 
-    fn supports(&self, _source: &Source) -> bool {
-        todo!()
+    fn supports(&self, source: &Source) -> bool {
+        let SourceLocation::Url(url) = &source.location else {
+            return false
+        };
+        url.scheme().starts_with(URL_SCHEME_PREFIX)
+    }
+
+    async fn load(&self, source: &Source) -> SourceLoaderResult {
+
+        let SourceLocation::Url(url) = &source.location else {
+            return Err(SourceLoaderError::new("Invalid source location"))
+        };
+
+        let location = GitLocation::try_from(url)?;
+        let cache_dir = Clone::clone(&self.options.cache_dir);
+        let identifier = source.identifier.as_str().to_owned();
+
+        tokio::task::spawn_blocking(move || load_suite(&cache_dir, &location, &identifier))
+            .await
+            .map_err(|cause| SourceLoaderError::new(format!("Loading the test suite from a git repository did not complete: {cause}")))?
+    }
+}
+
+fn load_suite(cache_dir: &Path, location: &GitLocation, identifier: &str) -> SourceLoaderResult {
+
+    let cache_path = cache_dir.join(cache_directory_name(&location.remote));
+    let _lock = lock_cache(&cache_path)?;
+
+    let repository = fetch(&cache_path, &location.remote)?;
+
+    let commit = resolve_commit(&repository, location.reference.as_deref())
+        .ok_or_else(|| SourceLoaderError::new(match &location.reference {
+            Some(reference) => format!("Reference '{reference}' does not exist in repository '{}'.", location.remote),
+            None => format!("Repository '{}' has no HEAD.", location.remote),
+        }))?;
+
+    read_suite(&repository, &commit, &location.path, identifier)
+        .map_err(|message| SourceLoaderError::new(format!(
+            "{message} (repository '{}', commit {})", location.remote, commit.id()
+        )))
+}
+
+/// Locks the cached repository exclusively. The lock is released, when the returned file is dropped.
+fn lock_cache(cache_path: &Path) -> Result<File, SourceLoaderError> {
+    let mut lock_path = cache_path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    let lock_path = PathBuf::from(lock_path);
+
+    let lock_file = create_cache_dir(cache_path.parent().unwrap_or(cache_path))
+        .and_then(|()| File::options().create(true).truncate(false).write(true).open(&lock_path))
+        .map_err(|cause| SourceLoaderError::new(format!("Could not open lock file '{}': {cause}", lock_path.display())))?;
+
+    lock_file.lock()
+        .map_err(|cause| SourceLoaderError::new(format!("Could not lock repository cache at '{}': {cause}", cache_path.display())))?;
+
+    Ok(lock_file)
+}
+
+/// Creates the cache directory, which is only accessible by the current user, as the cached repositories are loaded as code.
+fn create_cache_dir(cache_dir: &Path) -> std::io::Result<()> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(cache_dir)
+}
+
+fn fetch(cache_path: &Path, remote: &Url) -> Result<Repository, SourceLoaderError> {
+    let repository = if cache_path.exists() {
+        Repository::open_bare(cache_path)
+    } else {
+        Repository::init_bare(cache_path)
+    }
+    .map_err(|cause| SourceLoaderError::new(format!("Could not open repository cache at '{}': {}", cache_path.display(), cause.message())))?;
+
+    repository.remote_anonymous(remote.as_str())
+        .and_then(|mut origin| origin.fetch(&[
+            "+HEAD:refs/remotes/origin/HEAD",
+            "+refs/heads/*:refs/remotes/origin/*",
+            "+refs/tags/*:refs/tags/*",
+        ], None, None))
+        .map_err(|cause| SourceLoaderError::new(format!("Could not fetch repository '{remote}': {}", cause.message())))?;
+
+    Ok(repository)
+}
+
+pub struct GitSourceLoaderOptions {
+    /// Directory, in which the fetched repositories are cached.
+    /// It should only be writable by the user running the loader, since the cached test suites are executed.
+    pub cache_dir: PathBuf,
+}
+
+impl Default for GitSourceLoaderOptions {
+    /// Caches the repositories in the cache directory of the current user, i.e. `$XDG_CACHE_HOME` or `$HOME/.cache`,
+    /// falling back to the working directory if neither is set.
+    fn default() -> Self {
+        let user_cache_dir = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").filter(|dir| !dir.is_empty()).map(|home| PathBuf::from(home).join(".cache")))
+            .unwrap_or_else(|| PathBuf::from(".cache"));
+
+        Self {
+            cache_dir: user_cache_dir.join("opendut-viper").join("git-sources"),
+        }
     }
+}
+
+#[derive(Debug)]
+struct GitLocation {
+    remote: Url,
+    reference: Option<String>,
+    path: PathBuf,
+}
+
+impl TryFrom<&Url> for GitLocation {
+    type Error = SourceLoaderError;
 
-    async fn load(&self, _source: &Source) -> SourceLoaderResult {
-        todo!()
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        let Some(remote) = url.as_str().strip_prefix(URL_SCHEME_PREFIX) else {
+            return Err(SourceLoaderError::new(format!("Cannot load sources with non-git scheme: {url}")));
+        };
+
+        let mut remote = Url::parse(remote)
+            .map_err(|cause| SourceLoaderError::new(format!("Invalid repository URL '{remote}': {cause}")))?;
+        remote.set_fragment(None);
+
+        let mut reference = None;
+        let mut path = PathBuf::new();
+
+        for (key, value) in url::form_urlencoded::parse(url.fragment().unwrap_or_default().as_bytes()) {
+            match key.as_ref() {
+                FRAGMENT_KEY_REF => reference = Some(value.into_owned()).filter(|reference| !reference.is_empty()),
+                FRAGMENT_KEY_PATH => path = PathBuf::from(value.trim_matches('/')),
+                _ => return Err(SourceLoaderError::new(format!(
+                    "Unknown parameter '{key}' in git source URL '{url}'. Expected '{FRAGMENT_KEY_REF}' or '{FRAGMENT_KEY_PATH}'."
+                ))),
+            }
+        }
+
+        Ok(Self { remote, reference, path })
     }
 }
+
+fn resolve_commit<'repo>(repository: &'repo Repository, reference: Option<&str>) -> Option<Commit<'repo>> {
+    let candidates = match reference {
+        None => vec![String::from(REMOTE_HEAD)],
+        Some(reference) => vec![
+            format!("refs/remotes/origin/{reference}"),
+            format!("refs/tags/{reference}"),
+            String::from(reference),
+        ],
+    };
+
+    candidates.into_iter()
+        .filter_map(|candidate| repository.revparse_single(&candidate).ok())
+        .find_map(|object| object.peel_to_commit().ok())
+}
+
+fn read_suite(repository: &Repository, commit: &Commit, path: &Path, identifier: &str) -> Result<String, String> {
+    let tree = commit.tree()
+        .map_err(|cause| format!("Could not read tree of commit: {}", cause.message()))?;
+
+    let object = if path.as_os_str().is_empty() {
+        tree.into_object()
+    } else {
+        tree.get_path(path)
+            .and_then(|entry| entry.to_object(repository))
+            .map_err(|_| format!("Path '{}' does not exist.", path.display()))?
+    };
+
+    let blob = match object.kind() {
+        Some(ObjectType::Blob) => object.peel_to_blob()
+            .map_err(|cause| format!("Could not read test suite '{}': {}", path.display(), cause.message()))?,
+        Some(ObjectType::Tree) => {
+            let file_name = if identifier.ends_with(".py") { identifier.to_owned() } else { format!("{identifier}.py") };
+            let suite_path = path.join(&file_name);
+            let entry = object.as_tree()
+                .and_then(|tree| tree.get_name(&file_name))
+                .ok_or_else(|| format!("Test suite '{}' does not exist.", suite_path.display()))?;
+            entry.to_object(repository)
+                .and_then(|object| object.peel_to_blob())
+                .map_err(|cause| format!("Could not read test suite '{}': {}", suite_path.display(), cause.message()))?
+        }
+        _ => return Err(format!("Path '{}' is neither a file nor a directory.", path.display())),
+    };
+
+    String::from_utf8(blob.content().to_vec())
+        .map_err(|_| format!("Test suite at '{}' is not valid UTF-8.", path.display()))
+}
+
+fn cache_directory_name(remote: &Url) -> String {
+    let mut remote = Clone::clone(remote);
+    let _ = remote.set_username("");
+    let _ = remote.set_password(None);
+
+    Oid::hash_object(ObjectType::Blob, remote.as_str().as_bytes())
+        .map(|hash| hash.to_string())
+        .expect("Hashing in memory should not fail.")
+}
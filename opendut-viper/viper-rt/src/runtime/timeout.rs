@@ -1,102 +1,162 @@
-use pin_project_lite::pin_project;
-use std::task::Poll;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use rustpython_vm::signal::UserSignalSender;
+
+/// Interval in which the interruption is repeated, until the guarded code returns.
+const RETRIGGER_INTERVAL: Duration = Duration::from_millis(50);
 
 #[derive(Clone, Copy, Debug)]
 pub struct Elapsed;
 
-#[allow(dead_code)]
-pub fn timeout(duration: Duration, future: impl Future) -> Timeout<impl Future> {
-    Timeout {
-        future,
-        time_limit: Instant::now() + duration,
-    }
+/// A `Watchdog` limits the execution time of Python code running in an interpreter.
+///
+/// The interpreter checks for pending signals between the execution of two instructions.
+/// When the time limit is exceeded, the `Watchdog` signals the interpreter to raise a `TimeoutError`
+/// and repeats this until the guarded code returns, even if the code catches the error.
+/// Calls blocking outside the interpreter, like `time.sleep()`, are only interrupted after they returned.
+#[derive(Clone)]
+pub struct Watchdog {
+    signals: UserSignalSender,
 }
 
-pin_project! {
-    #[must_use = "futures do nothing unless you `.await` or poll them"]
-    pub struct Timeout<F> {
-        #[pin]
-        future: F,
-        time_limit: Instant,
+impl Watchdog {
+
+    pub fn new(signals: UserSignalSender) -> Self {
+        Self { signals }
     }
-}
 
-impl<F> Future for Timeout<F>
-where
-    F: Future,
-{
-    type Output = Result<F::Output, Elapsed>;
-
-    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
-        let time_limit = self.time_limit;
-        let this = self.project();
-        if let Poll::Ready(value) = this.future.poll(cx) {
-            return Poll::Ready(Ok(value));
-        }
-        if Instant::now() >= time_limit {
-            Poll::Ready(Err(Elapsed))
+    /// Runs the given function, interrupting it after the given duration.
+    /// Returns [`Elapsed`], if the function was interrupted.
+    pub fn timeout<T>(&self, duration: Option<Duration>, function: impl FnOnce() -> T) -> Result<T, Elapsed> {
+        let Some(duration) = duration else {
+            return Ok(function());
+        };
+
+        let armed = Arc::new(AtomicBool::new(true));
+        let elapsed = Arc::new(AtomicBool::new(false));
+        let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+
+        let watcher = thread::spawn({
+            let signals = Clone::clone(&self.signals);
+            let armed = Arc::clone(&armed);
+            let elapsed = Arc::clone(&elapsed);
+            move || {
+                let mut wait = duration;
+                while let Err(RecvTimeoutError::Timeout) = stop_receiver.recv_timeout(wait) {
+                    let armed = Arc::clone(&armed);
+                    let elapsed = Arc::clone(&elapsed);
+                    let interruption = signals.send(Box::new(move |vm| {
+                        if armed.load(Ordering::Acquire) {
+                            elapsed.store(true, Ordering::Release);
+                            Err(vm.new_exception_msg(
+                                vm.ctx.exceptions.timeout_error.to_owned(),
+                                format!("Execution did not finish within {} seconds.", duration.as_secs_f64()),
+                            ))
+                        } else {
+                            Ok(())
+                        }
+                    }));
+                    if interruption.is_err() {
+                        break; // The interpreter does not exist anymore.
+                    }
+                    wait = RETRIGGER_INTERVAL;
+                }
+            }
+        });
+
+        let result = function();
+
+        armed.store(false, Ordering::Release);
+        drop(stop_sender);
+        let _ = watcher.join();
+
+        if elapsed.load(Ordering::Acquire) {
+            Err(Elapsed)
         }
         else {
-            Poll::Pending
+            Ok(result)
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "compile"))]
 mod tests {
     use super::*;
-    use futures::Future;
-    use std::pin::{pin, Pin};
-    use std::task::Context;
-    use std::thread::sleep;
+    use crate::runtime::compile::py::create_interpreter;
+    use std::time::Instant;
 
     #[test]
     fn test_timeout_elapsed() {
 
-        let waker = futures::task::noop_waker();
-        let mut cx = Context::from_waker(&waker);
-
-        let timeout = pin!(timeout(Duration::from_millis(10), NeverReady));
-
-        sleep(Duration::from_millis(100));
+        let (interpreter, watchdog) = create_interpreter();
 
-        let result = timeout.poll(&mut cx);
+        let started = Instant::now();
+        let result = interpreter.enter(|vm| {
+            watchdog.timeout(Some(Duration::from_millis(100)), || {
+                vm.run_code_string(vm.new_scope_with_builtins(), "while True: pass", String::from("<test>"))
+            })
+        });
 
-        assert!(matches!(result, Poll::Ready(Err(Elapsed))));
+        assert!(matches!(result, Err(Elapsed)));
+        assert!(started.elapsed() < Duration::from_secs(10));
     }
 
     #[test]
-    fn test_timeout_pending() {
+    fn test_timeout_elapsed_even_if_caught() {
 
-        let waker = futures::task::noop_waker();
-        let mut cx = Context::from_waker(&waker);
+        let (interpreter, watchdog) = create_interpreter();
 
-        let timeout = pin!(timeout(Duration::from_secs(60), NeverReady));
+        let result = interpreter.enter(|vm| {
+            watchdog.timeout(Some(Duration::from_millis(100)), || {
+                vm.run_code_string(vm.new_scope_with_builtins(), "try:\n    while True: pass\nexcept TimeoutError:\n    pass\nwhile True: pass\n", String::from("<test>"))
+            })
+        });
 
-        let result = timeout.poll(&mut cx);
-
-        assert!(matches!(result, Poll::Pending));
+        assert!(matches!(result, Err(Elapsed)));
     }
 
     #[test]
     fn test_timeout_ready() {
 
-        let waker = futures::task::noop_waker();
-        let mut cx = Context::from_waker(&waker);
+        let (interpreter, watchdog) = create_interpreter();
 
-        let timeout = pin!(timeout(Duration::from_secs(60), async { 42 }));
+        let result = interpreter.enter(|vm| {
+            watchdog.timeout(Some(Duration::from_secs(60)), || {
+                vm.run_block_expr(vm.new_scope_with_builtins(), "6 * 7")
+                    .map(|value| value.try_to_value::<i32>(vm).ok())
+            })
+        });
 
-        let result = timeout.poll(&mut cx);
+        assert!(matches!(result, Ok(Ok(Some(42)))));
+    }
 
-        assert!(matches!(result, Poll::Ready(Ok(_))));
+    #[test]
+    fn test_timeout_does_not_interrupt_subsequent_code() {
+
+        let (interpreter, watchdog) = create_interpreter();
+
+        interpreter.enter(|vm| {
+            let result = watchdog.timeout(Some(Duration::from_millis(10)), || {
+                thread::sleep(Duration::from_millis(100));
+            });
+            assert!(result.is_ok());
+
+            let result = vm.run_code_string(vm.new_scope_with_builtins(), "for x in range(100000): pass", String::from("<test>"));
+            assert!(result.is_ok());
+        });
     }
 
-    struct NeverReady;
-    impl Future for NeverReady {
-        type Output = i32;
-        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
-            Poll::Pending
-        }
+    #[test]
+    fn test_no_timeout() {
+
+        let (_interpreter, watchdog) = create_interpreter();
+
+        let result = watchdog.timeout(None, || 42);
+
+        assert!(matches!(result, Ok(42)));
     }
 }
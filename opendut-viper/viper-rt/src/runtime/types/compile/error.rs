@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::common::TestSuiteIdentifier;
 use crate::runtime::source::SourceLoaderError;
 use crate::runtime::types::compile::inspect::InspectionError;
@@ -56,7 +58,11 @@ pub enum CompilationErrorKind {
     },
     PythonRuntimeError {
         cause: PythonRuntimeError,
-    }
+    },
+    /// The top-level code of the source did not finish within the compile timeout.
+    Timeout {
+        timeout: Duration,
+    },
 }
 
 impl CompilationError {
@@ -149,6 +155,18 @@ impl CompilationError {
         )
     }
 
+    pub(crate) fn new_timeout_error(
+        source: &Source,
+        timeout: Duration,
+    ) -> Self {
+        Self::new(
+            Clone::clone(&source.identifier),
+            CompilationErrorKind::Timeout {
+                timeout,
+            }
+        )
+    }
+
     pub(crate) fn new_python_reflection_error(
         identifier: TestSuiteIdentifier,
         cause: PythonReflectionError
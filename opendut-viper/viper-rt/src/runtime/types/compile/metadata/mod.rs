@@ -1,5 +1,7 @@
 mod error;
 
+use std::time::Duration;
+
 pub use error::MetadataError;
 
 /// A `Metadata` is a part of a [`Compilation`].
@@ -10,4 +12,6 @@ pub use error::MetadataError;
 pub struct Metadata {
     pub display_name: Option<String>,
     pub description: Option<String>,
+    /// Time limit for each test of the test suite, unless specified otherwise for a particular test.
+    pub timeout: Option<Duration>,
 }
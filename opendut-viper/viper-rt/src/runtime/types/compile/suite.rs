@@ -3,6 +3,7 @@ use crate::runtime::types::naming::{TestCaseIdentifier, TestIdentifier, TestSuit
 
 use crate::common::Identifier;
 use std::fmt::{Debug, Formatter};
+use std::time::Duration;
 
 #[cfg(feature = "py")]
 use rustpython_vm as vm;
@@ -34,8 +35,10 @@ pub struct TestSuite {
     pub(crate) identifier: TestSuiteIdentifier,
     pub(crate) version: ApiVersion,
     pub(crate) cases: Vec<TestCase>,
+    pub(crate) timeout: Option<Duration>,
     #[cfg(feature = "py")] pub(crate) interpreter: vm::Interpreter,
     #[cfg(feature = "py")] pub(crate) module: vm::PyRef<vm::builtins::PyModule>,
+    #[cfg(feature = "py")] pub(crate) watchdog: crate::runtime::timeout::Watchdog,
 }
 
 impl TestSuite {
@@ -51,6 +54,21 @@ impl TestSuite {
     pub fn test_cases(&self) -> &[TestCase] {
         self.cases.as_slice()
     }
+
+    /// Returns the time limit for each test, as declared in the [`Metadata`] of the test suite.
+    ///
+    /// [`Metadata`]: crate::compile::Metadata
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Overrides the time limit of all tests of this suite, including the time limits declared for particular tests.
+    pub fn override_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+        for test in self.cases.iter_mut().flat_map(|case| case.tests.iter_mut()) {
+            test.timeout = None;
+        }
+    }
 }
 
 impl Debug for TestSuite {
@@ -60,6 +78,7 @@ impl Debug for TestSuite {
             .field("identifier", &self.identifier.to_string())
             .field("api_version", &self.version.to_string())
             .field("cases", &self.cases)
+            .field("timeout", &self.timeout)
             .finish()
     }
 }
@@ -143,6 +162,7 @@ impl Debug for TestCase {
 #[cfg_attr(not(feature = "py"), derive(Clone))]
 pub struct Test {
     pub(crate) identifier: TestIdentifier,
+    pub(crate) timeout: Option<Duration>,
     #[cfg(feature = "py")] pub(crate) function: vm::PyObjectRef
 }
 
@@ -155,6 +175,11 @@ impl Test {
     pub fn name(&self) -> &str {
         self.identifier.name()
     }
+
+    /// Returns the time limit declared for this particular test, e.g. via the `@unittest.timeout()` decorator.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
 
 impl Debug for Test {
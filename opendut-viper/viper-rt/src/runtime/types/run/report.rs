@@ -2,19 +2,19 @@ use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use crate::runtime::types::naming::{TestCaseIdentifier, TestIdentifier, TestSuiteIdentifier};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TestSuiteReport {
     pub name: TestSuiteIdentifier,
     pub cases: Vec<TestCaseReport>
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TestCaseReport {
     pub name: TestCaseIdentifier,
     pub tests: Vec<TestReport>
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TestReport {
     pub identifier: TestIdentifier,
     pub outcome: Outcome,
@@ -26,11 +26,14 @@ pub struct TestReport {
 pub enum Outcome {
     Success,
     Failure,
+    /// The test did not finish within its time limit and was aborted.
+    Timeout,
 }
 
 impl Outcome {
+    /// Returns `true` for every outcome other than [`Outcome::Success`], including [`Outcome::Timeout`].
     pub fn is_failure(&self) -> bool {
-        matches!(self, Outcome::Failure)
+        matches!(self, Outcome::Failure | Outcome::Timeout)
     }
 }
 
@@ -39,6 +42,7 @@ impl Display for Outcome {
         match self {
             Outcome::Success => write!(f, "Success"),
             Outcome::Failure => write!(f, "Failure"),
+            Outcome::Timeout => write!(f, "Timeout"),
         }
     }
 }
@@ -53,7 +57,9 @@ pub struct ReportProperty {
 pub enum ReportPropertyValue {
     Number(i64),
     String(String),
-    File(PathBuf),
+    /// Path of a file written by the test. The path is local to the host, which executed the test,
+    /// so when the run was executed by a peer, the file is only available on that peer.
+    FilePath(PathBuf),
 }
 
 pub trait Report {
@@ -65,7 +71,7 @@ pub trait Report {
     }
 
     fn is_failure(&self) -> bool {
-        self.outcome() == Outcome::Failure
+        self.outcome().is_failure()
     }
 }
 
@@ -1,5 +1,6 @@
 #[cfg(feature = "py")]
 use rustpython_vm as vm;
+use std::time::Duration;
 
 use crate::runtime::types::naming::{TestCaseIdentifier, TestIdentifier, TestSuiteIdentifier};
 
@@ -20,6 +21,7 @@ pub struct TestCaseRun {
 
 pub struct TestRun {
     pub identifier: TestIdentifier,
+    pub timeout: Option<Duration>,
     #[cfg(feature = "py")] pub test_fn: vm::PyObjectRef,
     #[cfg(feature = "py")] pub setup_fn: Option<vm::PyObjectRef>,
     #[cfg(feature = "py")] pub teardown_fn: Option<vm::PyObjectRef>,
@@ -28,6 +28,11 @@ impl Source {
         Self::new(identifier, SourceLocation::Embedded(code.into()))
     }
 
+    /// Creates an embedded source with the given identifier, e.g. for code loaded beforehand via [`ViperRuntime::load()`](crate::ViperRuntime::load).
+    pub fn embedded_with_identifier(identifier: TestSuiteIdentifier, code: impl Into<String>) -> Self {
+        Self::new(identifier, SourceLocation::Embedded(code.into()))
+    }
+
     pub fn try_from_url(identifier: TestSuiteIdentifier, url: Url) -> Result<Self, InvalidSourceLocationError> {
         Ok(Self::new(identifier, SourceLocation::Url(url)))
     }
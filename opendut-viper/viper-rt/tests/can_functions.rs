@@ -0,0 +1,76 @@
+use googletest::prelude::*;
+use indoc::indoc;
+use opendut_viper_rt::compile::{Compilation, CompileResult, IdentifierFilter};
+use opendut_viper_rt::events::emitter;
+use opendut_viper_rt::run::{Outcome, ParameterBindings, Report};
+use opendut_viper_rt::source::Source;
+use opendut_viper_rt::ViperRuntime;
+
+async fn compile_test(runtime: &ViperRuntime, source: &Source) -> CompileResult<Compilation> {
+    runtime.compile(source, &mut emitter::drain(), &IdentifierFilter::default()).await
+}
+
+#[tokio::test]
+async fn test_decode_and_encode_signals() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import can, unittest
+
+            class MyTestCase(unittest.TestCase):
+
+                def test_decode(self):
+                    database = can.load_database("tests/vehicle.dbc")
+                    data = bytes([0x92, 0x22, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00])
+
+                    values = database.decode("VehicleInfo", data)
+                    self.assertEquals(round(values["VehicleSpeed"], 2), 88.5)
+                    self.assertEquals(values["Gear"], 3.0)
+                    self.assertEquals(round(database.decode_signal(0x100, "VehicleSpeed", data), 2), 88.5)
+
+                    descriptions = database.describe("VehicleInfo", data)
+                    self.assertEquals(descriptions["Gear"], "Drive")
+                    self.assertFalse("VehicleSpeed" in descriptions)
+
+                def test_encode(self):
+                    database = can.load_database("tests/vehicle.dbc")
+
+                    data = database.encode("VehicleInfo", {"VehicleSpeed": 88.5, "Gear": 3})
+                    self.assertEquals(data, bytes([0x92, 0x22, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00]))
+        "#)
+    )).await?.into_suite();
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(report.outcome(), eq(Outcome::Success));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_unknown_signal() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import can, unittest
+
+            class MyTestCase(unittest.TestCase):
+
+                def test_decode_unknown_signal(self):
+                    database = can.load_database("tests/vehicle.dbc")
+                    database.decode_signal("VehicleInfo", "EngineSpeed", bytes(8))
+        "#)
+    )).await?.into_suite();
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(report.outcome(), eq(Outcome::Failure));
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+#![cfg(feature = "git-source")]
+#![allow(non_snake_case)]
+
+use std::path::Path;
+
+use git2::{Oid, Repository, RepositoryInitOptions, Signature};
+use googletest::prelude::*;
+use indoc::indoc;
+use opendut_viper_rt::common::TestSuiteIdentifier;
+use opendut_viper_rt::compile::IdentifierFilter;
+use opendut_viper_rt::events::emitter;
+use opendut_viper_rt::source::loaders::{GitSourceLoader, GitSourceLoaderOptions, SourceLoader};
+use opendut_viper_rt::source::Source;
+use opendut_viper_rt::ViperRuntime;
+use tempfile::TempDir;
+
+const SUITE: &str = indoc!(r#"
+    # VIPER_VERSION = 1.0
+    from viper import unittest
+
+    class SomeClass(unittest.TestCase):
+        def test_awesomeness(self):
+            print("Awesome!")
+"#);
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_compiles_a_testsuite_from_a_git_repository() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+
+    let runtime = ViperRuntime::builder()
+        .with_source_loader(fixture.loader())
+        .build()?;
+
+    let source = fixture.source("my_testsuite", "ref=main&path=suites/my_testsuite.py")?;
+
+    let suite = runtime.compile(&source, &mut emitter::drain(), &IdentifierFilter::default())
+        .await?.into_suite();
+
+    assert_that!(suite.test_cases(), len(eq(1)));
+    assert_that!(suite.test_cases()[0].tests(), len(eq(1)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_checks_out_branches_tags_and_commits() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let loader = fixture.loader();
+
+    let from_head = loader.load(&fixture.source("my_testsuite", "path=suites/my_testsuite.py")?).await?;
+    assert_that!(from_head, contains_substring("# main"));
+
+    let from_branch = loader.load(&fixture.source("my_testsuite", "ref=feature&path=suites/my_testsuite.py")?).await?;
+    assert_that!(from_branch, contains_substring("# feature"));
+
+    let from_tag = loader.load(&fixture.source("my_testsuite", "ref=v1.0.0&path=suites/my_testsuite.py")?).await?;
+    assert_that!(from_tag, contains_substring("# v1.0.0"));
+
+    let commit = fixture.tagged_commit.to_string();
+    let from_commit = loader.load(&fixture.source("my_testsuite", &format!("ref={commit}&path=suites/my_testsuite.py"))?).await?;
+    assert_that!(from_commit, contains_substring("# v1.0.0"));
+
+    let from_short_commit = loader.load(&fixture.source("my_testsuite", &format!("ref={}&path=suites/my_testsuite.py", &commit[..8]))?).await?;
+    assert_that!(from_short_commit, contains_substring("# v1.0.0"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_looks_up_testsuites_in_directories_by_identifier() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let loader = fixture.loader();
+
+    let first = loader.load(&fixture.source("my_testsuite", "ref=main&path=suites")?).await?;
+    assert_that!(first, contains_substring("# main"));
+
+    let second = loader.load(&fixture.source("other_testsuite", "ref=main&path=suites/")?).await?;
+    assert_that!(second, contains_substring("# other"));
+
+    let from_root = loader.load(&fixture.source("root_testsuite", "ref=main")?).await?;
+    assert_that!(from_root, contains_substring("# root"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_fetches_updates_into_its_cache() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let loader = fixture.loader();
+
+    let before = loader.load(&fixture.source("my_testsuite", "ref=main&path=suites/my_testsuite.py")?).await?;
+    assert_that!(before, contains_substring("# main"));
+
+    fixture.commit("main", &[("suites/my_testsuite.py", &format!("{SUITE}# updated"))])?;
+    fixture.push()?;
+
+    let after = loader.load(&fixture.source("my_testsuite", "ref=main&path=suites/my_testsuite.py")?).await?;
+    assert_that!(after, contains_substring("# updated"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_names_cached_repositories_by_a_hash_of_the_url() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+
+    fixture.loader().load(&fixture.source("my_testsuite", "ref=main&path=suites/my_testsuite.py")?).await?;
+
+    let cache_entries = std::fs::read_dir(fixture.cache_dir.path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let repository_name = cache_entries.iter()
+        .find(|name| !name.ends_with(".lock"))
+        .expect("cache should contain the fetched repository");
+
+    assert_that!(repository_name.len(), eq(40));
+    assert_that!(repository_name.chars().all(|char| char.is_ascii_hexdigit()), eq(true));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_that_GitSourceLoader_loads_concurrently_into_the_same_cache() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let first_loader = fixture.loader();
+    let second_loader = fixture.loader();
+
+    let main = fixture.source("my_testsuite", "ref=main&path=suites/my_testsuite.py")?;
+    let feature = fixture.source("my_testsuite", "ref=feature&path=suites/my_testsuite.py")?;
+
+    let (from_main, from_feature, from_main_again) = tokio::join!(
+        first_loader.load(&main),
+        second_loader.load(&feature),
+        first_loader.load(&main),
+    );
+
+    assert_that!(from_main?, contains_substring("# main"));
+    assert_that!(from_feature?, contains_substring("# feature"));
+    assert_that!(from_main_again?, contains_substring("# main"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_reports_unknown_references_and_paths() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let loader = fixture.loader();
+
+    let unknown_reference = loader.load(&fixture.source("my_testsuite", "ref=unknown&path=suites/my_testsuite.py")?).await;
+    assert_that!(unknown_reference.map_err(|error| error.to_string()), err(contains_substring("Reference 'unknown' does not exist")));
+
+    let unknown_path = loader.load(&fixture.source("my_testsuite", "ref=main&path=suites/missing.py")?).await;
+    assert_that!(unknown_path.map_err(|error| error.to_string()), err(contains_substring("Path 'suites/missing.py' does not exist")));
+
+    let unknown_suite = loader.load(&fixture.source("missing", "ref=main&path=suites")?).await;
+    assert_that!(unknown_suite.map_err(|error| error.to_string()), err(contains_substring("Test suite 'suites/missing.py' does not exist")));
+
+    let unknown_parameter = loader.load(&fixture.source("my_testsuite", "branch=main")?).await;
+    assert_that!(unknown_parameter.map_err(|error| error.to_string()), err(contains_substring("Unknown parameter 'branch'")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_GitSourceLoader_supports_only_git_urls() -> Result<()> {
+
+    let fixture = Fixture::create()?;
+    let loader = fixture.loader();
+
+    assert_that!(loader.supports(&fixture.source("my_testsuite", "ref=main")?), eq(true));
+    assert_that!(loader.supports(&Source::try_from_url_str(TestSuiteIdentifier::try_from("my_testsuite")?, "https://example.com/tests.git")?), eq(false));
+    assert_that!(loader.supports(&Source::embedded(SUITE)), eq(false));
+
+    Ok(())
+}
+
+
+struct Fixture {
+    work: Repository,
+    remote_dir: TempDir,
+    cache_dir: TempDir,
+    tagged_commit: Oid,
+    _work_dir: TempDir,
+}
+
+impl Fixture {
+
+    fn create() -> Result<Self> {
+        let work_dir = TempDir::new()?;
+        let remote_dir = TempDir::new()?;
+        let cache_dir = TempDir::new()?;
+
+        let work = Repository::init_opts(work_dir.path(), RepositoryInitOptions::new().initial_head("main"))?;
+        let remote = Repository::init_bare(remote_dir.path())?;
+        remote.set_head("refs/heads/main")?;
+
+        let mut fixture = Self { work, remote_dir, cache_dir, tagged_commit: Oid::zero(), _work_dir: work_dir };
+
+        fixture.tagged_commit = fixture.commit("main", &[("suites/my_testsuite.py", &format!("{SUITE}# v1.0.0"))])?;
+        fixture.work.tag_lightweight("v1.0.0", &fixture.work.find_object(fixture.tagged_commit, None)?, false)?;
+
+        fixture.commit("main", &[
+            ("suites/my_testsuite.py", &format!("{SUITE}# main")),
+            ("suites/other_testsuite.py", &format!("{SUITE}# other")),
+            ("root_testsuite.py", &format!("{SUITE}# root")),
+        ])?;
+
+        fixture.work.branch("feature", &fixture.work.find_commit(fixture.work.refname_to_id("refs/heads/main")?)?, false)?;
+        fixture.commit("feature", &[("suites/my_testsuite.py", &format!("{SUITE}# feature"))])?;
+
+        fixture.push()?;
+
+        Ok(fixture)
+    }
+
+    fn loader(&self) -> GitSourceLoader {
+        GitSourceLoader::new(GitSourceLoaderOptions {
+            cache_dir: self.cache_dir.path().to_owned(),
+        })
+    }
+
+    fn source(&self, identifier: &str, fragment: &str) -> Result<Source> {
+        let url = format!("git+file://{}#{fragment}", self.remote_dir.path().display());
+        Ok(Source::try_from_url_str(TestSuiteIdentifier::try_from(identifier)?, &url)?)
+    }
+
+    fn commit(&self, branch: &str, files: &[(&str, &str)]) -> Result<Oid> {
+        let workdir = self.work.workdir().expect("work repository should have a working directory");
+        let mut index = self.work.index()?;
+
+        for (path, content) in files {
+            let file = workdir.join(path);
+            std::fs::create_dir_all(file.parent().expect("file should have a parent directory"))?;
+            std::fs::write(&file, content)?;
+            index.add_path(Path::new(path))?;
+        }
+        index.write()?;
+
+        let tree = self.work.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("VIPER", "viper@example.com")?;
+        let reference = format!("refs/heads/{branch}");
+        let parent = self.work.refname_to_id(&reference).ok()
+            .map(|parent| self.work.find_commit(parent))
+            .transpose()?;
+
+        let commit = self.work.commit(Some(&reference), &signature, &signature, branch, &tree, parent.as_slice().iter().collect::<Vec<_>>().as_slice())?;
+        Ok(commit)
+    }
+
+    fn push(&self) -> Result<()> {
+        let mut remote = self.work.remote_anonymous(&format!("file://{}", self.remote_dir.path().display()))?;
+        remote.push(&[
+            "+refs/heads/main:refs/heads/main",
+            "+refs/heads/feature:refs/heads/feature",
+            "+refs/tags/v1.0.0:refs/tags/v1.0.0",
+        ], None)?;
+        Ok(())
+    }
+}
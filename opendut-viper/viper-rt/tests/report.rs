@@ -90,12 +90,12 @@ async fn test_report_file_properties() -> Result<()> {
     assert_that!(report.cases[0].tests[0].properties, len(eq(3)));
     assert_that!(report.cases[0].tests[1].properties, len(eq(2)));
 
-    assert_that!(report.cases[0].tests[0].properties[0], eq(&ReportProperty { name: String::from("/a/b"), value: ReportPropertyValue::File(PathBuf::from("/a/b")) }));
-    assert_that!(report.cases[0].tests[0].properties[1], eq(&ReportProperty { name: String::from("/c/d/e/f"), value: ReportPropertyValue::File(PathBuf::from("/c/d/e/f")) }));
-    assert_that!(report.cases[0].tests[0].properties[2], eq(&ReportProperty { name: String::from("/g/h/i/j"), value: ReportPropertyValue::File(PathBuf::from("/g/h/i/j")) }));
+    assert_that!(report.cases[0].tests[0].properties[0], eq(&ReportProperty { name: String::from("/a/b"), value: ReportPropertyValue::FilePath(PathBuf::from("/a/b")) }));
+    assert_that!(report.cases[0].tests[0].properties[1], eq(&ReportProperty { name: String::from("/c/d/e/f"), value: ReportPropertyValue::FilePath(PathBuf::from("/c/d/e/f")) }));
+    assert_that!(report.cases[0].tests[0].properties[2], eq(&ReportProperty { name: String::from("/g/h/i/j"), value: ReportPropertyValue::FilePath(PathBuf::from("/g/h/i/j")) }));
 
-    assert_that!(report.cases[0].tests[1].properties[0], eq(&ReportProperty { name: String::from("/a/b"), value: ReportPropertyValue::File(PathBuf::from("/a/b")) }));
-    assert_that!(report.cases[0].tests[1].properties[1], eq(&ReportProperty { name: String::from("/c/d/e/f"), value: ReportPropertyValue::File(PathBuf::from("/c/d/e/f")) }));
+    assert_that!(report.cases[0].tests[1].properties[0], eq(&ReportProperty { name: String::from("/a/b"), value: ReportPropertyValue::FilePath(PathBuf::from("/a/b")) }));
+    assert_that!(report.cases[0].tests[1].properties[1], eq(&ReportProperty { name: String::from("/c/d/e/f"), value: ReportPropertyValue::FilePath(PathBuf::from("/c/d/e/f")) }));
 
     Ok(())
 }
@@ -231,3 +231,50 @@ async fn test_calls_order() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_failing_setup_and_teardown() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            calls = []
+
+            class FailingSetUp(unittest.TestCase):
+                def setUp(self):
+                    raise RuntimeError("setUp failed")
+
+                def tearDown(self):
+                    calls.append("tearDown FailingSetUp")
+
+                def test_skipped(self):
+                    calls.append("test_skipped")
+
+            class FailingTearDown(unittest.TestCase):
+                def tearDown(self):
+                    raise RuntimeError("tearDown failed")
+
+                def test_passing(self):
+                    calls.append("test_passing")
+
+            class TestCalls(unittest.TestCase):
+                def test_result(self):
+                    print(calls)
+        "#)
+    )).await?.into_suite();
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(report.cases[0].tests[0].outcome, eq(Outcome::Failure));
+    assert_that!(report.cases[1].tests[0].outcome, eq(Outcome::Failure));
+    assert_that!(report.cases[2].tests[0].output, container_eq([
+        String::from("['test_passing']"),
+        String::from("\n"),
+    ]));
+
+    Ok(())
+}
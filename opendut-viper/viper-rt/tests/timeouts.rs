@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use googletest::prelude::*;
+use indoc::indoc;
+use opendut_viper_rt::common::Identifier;
+use opendut_viper_rt::compile::{Compilation, CompilationError, CompilationErrorKind, CompileResult, IdentifierFilter};
+use opendut_viper_rt::events::emitter;
+use opendut_viper_rt::run::{Outcome, ParameterBindings, Report, TestSuiteReport};
+use opendut_viper_rt::source::loaders::EmbeddedSourceLoader;
+use opendut_viper_rt::source::Source;
+use opendut_viper_rt::ViperRuntime;
+
+async fn compile_test(runtime: &ViperRuntime, source: &Source) -> CompileResult<Compilation> {
+    runtime.compile(source, &mut emitter::drain(), &IdentifierFilter::default()).await
+}
+
+fn outcome_of(report: &TestSuiteReport, test: &str) -> Option<Outcome> {
+    report.cases.iter()
+        .flat_map(|case| case.tests.iter())
+        .find(|report| report.identifier.name() == test)
+        .map(|report| report.outcome)
+}
+
+#[tokio::test]
+async fn test_that_a_test_exceeding_its_decorated_timeout_is_aborted() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            class MyTestCase(unittest.TestCase):
+                @unittest.timeout(0.1)
+                def test_hanging(self):
+                    while True:
+                        pass
+
+                def test_afterwards(self):
+                    print("Still running!")
+        "#)
+    )).await?.into_suite();
+
+    let hanging = suite.test_cases()[0].tests().iter()
+        .find(|test| test.name() == "test_hanging")
+        .expect("test_hanging should have been compiled");
+    assert_that!(hanging.timeout(), some(eq(Duration::from_millis(100))));
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(outcome_of(&report, "test_hanging"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_afterwards"), some(eq(Outcome::Success)));
+    assert_that!(report.outcome(), eq(Outcome::Failure));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_the_timeout_of_the_metadata_applies_to_all_tests() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let (metadata, _, suite) = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import metadata
+            from viper import unittest
+
+            METADATA = metadata.Metadata(
+                timeout = 0.1,
+            )
+
+            class MyTestCase(unittest.TestCase):
+                def test_hanging(self):
+                    while True:
+                        pass
+
+                @unittest.timeout(60)
+                def test_with_own_timeout(self):
+                    pass
+        "#)
+    )).await?.split();
+
+    assert_that!(metadata.timeout, some(eq(Duration::from_millis(100))));
+    assert_that!(suite.timeout(), some(eq(Duration::from_millis(100))));
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(outcome_of(&report, "test_hanging"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_with_own_timeout"), some(eq(Outcome::Success)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_an_overridden_timeout_takes_precedence() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let mut suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            class MyTestCase(unittest.TestCase):
+                @unittest.timeout(3600)
+                def test_hanging(self):
+                    while True:
+                        pass
+        "#)
+    )).await?.into_suite();
+
+    suite.override_timeout(Duration::from_millis(100));
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(outcome_of(&report, "test_hanging"), some(eq(Outcome::Timeout)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_setup_and_teardown_are_aborted_after_the_timeout_of_the_test() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            class HangingSetUp(unittest.TestCase):
+                def setUp(self):
+                    while True:
+                        pass
+
+                @unittest.timeout(0.1)
+                def test_after_hanging_setup(self):
+                    pass
+
+            class HangingTearDown(unittest.TestCase):
+                def tearDown(self):
+                    while True:
+                        pass
+
+                @unittest.timeout(0.1)
+                def test_before_hanging_teardown(self):
+                    pass
+
+            class Afterwards(unittest.TestCase):
+                def test_afterwards(self):
+                    pass
+        "#)
+    )).await?.into_suite();
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(outcome_of(&report, "test_after_hanging_setup"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_before_hanging_teardown"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_afterwards"), some(eq(Outcome::Success)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_class_fixtures_are_aborted_after_the_timeouts_of_the_test_case() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let suite = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            class HangingSetUpClass(unittest.TestCase):
+                @classmethod
+                def setUpClass(cls):
+                    while True:
+                        pass
+
+                @unittest.timeout(0.1)
+                def test_first(self):
+                    pass
+
+                @unittest.timeout(0.1)
+                def test_second(self):
+                    pass
+
+            class HangingTearDownClass(unittest.TestCase):
+                @classmethod
+                def tearDownClass(cls):
+                    while True:
+                        pass
+
+                @unittest.timeout(0.1)
+                def test_before_hanging_teardown_class(self):
+                    pass
+
+            class Afterwards(unittest.TestCase):
+                def test_afterwards(self):
+                    pass
+        "#)
+    )).await?.into_suite();
+
+    let report = runtime.run(suite, ParameterBindings::new(), &mut emitter::drain()).await?;
+
+    assert_that!(outcome_of(&report, "test_first"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_second"), some(eq(Outcome::Timeout)));
+    assert_that!(outcome_of(&report, "test_before_hanging_teardown_class"), some(eq(Outcome::Success)));
+    assert_that!(outcome_of(&report, "test_afterwards"), some(eq(Outcome::Success)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_invalid_timeouts_are_rejected() -> Result<()> {
+
+    let runtime = ViperRuntime::default();
+
+    let result = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import metadata
+
+            METADATA = metadata.Metadata(
+                timeout = "soon",
+            )
+        "#)
+    )).await;
+    assert_that!(result, err(anything()));
+
+    let result = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            class MyTestCase(unittest.TestCase):
+                @unittest.timeout(-1)
+                def test_something(self):
+                    pass
+        "#)
+    )).await;
+    assert_that!(result, err(anything()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_that_top_level_code_exceeding_the_compile_timeout_is_aborted() -> Result<()> {
+
+    let runtime = ViperRuntime::builder()
+        .with_source_loader(EmbeddedSourceLoader)
+        .with_compile_timeout(Duration::from_millis(100))
+        .build()?;
+
+    let result = compile_test(&runtime, &Source::embedded(
+        indoc!(r#"
+            # VIPER_VERSION = 1.0
+            from viper import unittest
+
+            while True:
+                pass
+        "#)
+    )).await;
+
+    assert_that!(result.map_err(|e| *e), err(matches_pattern!(
+        CompilationError {
+            kind: matches_pattern!(CompilationErrorKind::Timeout { .. }),
+            ..
+        }
+    )));
+
+    Ok(())
+}
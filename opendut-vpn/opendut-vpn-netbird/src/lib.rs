@@ -12,7 +12,7 @@ pub use netbird::NetbirdToken;
 use opendut_model::cluster::ClusterId;
 use opendut_model::peer::PeerId;
 use opendut_model::vpn::VpnPeerConfiguration;
-use opendut_vpn::{CreateClusterError, CreatePeerError, CreateVpnPeerConfigurationError, DeleteClusterError, DeletePeerError, VpnManagementClient};
+use opendut_vpn::{CheckReachableError, CreateClusterError, CreatePeerError, CreateVpnPeerConfigurationError, DeleteClusterError, DeletePeerError, VpnManagementClient};
 
 pub use crate::client::auth::NetbirdAuthenticationMethod;
 
@@ -223,6 +223,13 @@ impl VpnManagementClient for NetbirdManagementClient {
         Ok(())
     }
 
+    #[tracing::instrument(skip(self), level="trace")]
+    async fn check_reachable(&self) -> Result<(), CheckReachableError> {
+        self.inner.list_setup_keys().await
+            .map_err(|error| CheckReachableError::Unreachable { error: error.into() })?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self), level="trace")]
     async fn generate_vpn_peer_configuration(&self, peer_id: PeerId) -> Result<VpnPeerConfiguration, CreateVpnPeerConfigurationError> {
 
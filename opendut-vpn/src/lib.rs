@@ -16,6 +16,9 @@ pub trait VpnManagementClient {
     async fn delete_peer(&self, peer_id: PeerId) -> Result<(), DeletePeerError>;
 
     async fn generate_vpn_peer_configuration(&self, peer_id: PeerId) -> Result<VpnPeerConfiguration, CreateVpnPeerConfigurationError>;
+
+    /// Checks whether the VPN management service can be reached with the configured credentials.
+    async fn check_reachable(&self) -> Result<(), CheckReachableError>;
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -83,3 +86,11 @@ pub enum CreateVpnPeerConfigurationError {
         #[source] error: Box<dyn std::error::Error + Send + Sync>
     },
 }
+
+#[derive(thiserror::Error, Debug)]
+pub enum CheckReachableError {
+    #[error("The VPN management service could not be reached:\n  {error}")]
+    Unreachable {
+        #[source] error: Box<dyn std::error::Error + Send + Sync>
+    },
+}
@@ -85,20 +85,22 @@ async fn carl_should_send_peer_configurations_in_happy_flow() -> anyhow::Result<
 }
 
 fn validate_peer_configuration(peer_descriptor: PeerDescriptor, check_remote_peer_id: Option<PeerId>, peer_configuration: PeerConfiguration) -> anyhow::Result<()> {
-    let bridge = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut").expect("Could not construct interface name.") };
+    let bridge = parameter::EthernetBridge { name: NetworkInterfaceName::try_from("br-opendut").expect("Could not construct interface name."), spanning_tree: false };
     let ethernet_descriptor = peer_descriptor.network.interfaces.first().cloned().expect("Peer has no network interfaces.");
     let ethernet = parameter::DeviceInterface { descriptor: ethernet_descriptor.clone() };
     let gre_interface = parameter::GreInterfaceConfig {
-        local_ip: Ipv4Addr::from_str("127.0.0.1")?,
-        remote_ip: Ipv4Addr::from_str("127.0.0.1")?,
+        local_ip: IpAddr::V4(Ipv4Addr::from_str("127.0.0.1")?),
+        remote_ip: IpAddr::V4(Ipv4Addr::from_str("127.0.0.1")?),
     };
     let interface_join_gre = parameter::InterfaceJoinConfig {
         name: gre_interface.interface_name()?,
         bridge: bridge.name.clone(),
+        isolated: false,
     };
     let interface_join_ethernet = parameter::InterfaceJoinConfig {
         name: ethernet_descriptor.name,
         bridge: bridge.name.clone(),
+        isolated: false,
     };
     match check_remote_peer_id {
         Some(remote_peer_id) => {
@@ -289,6 +291,12 @@ async fn store_cluster_descriptor(leader: PeerId, devices: impl Iterator<Item=&D
         name: ClusterName::try_from(format!("cluster-{cluster_id}"))?,
         leader,
         devices,
+        device_selectors: vec![],
+        topology: Default::default(),
+        can_rules: vec![],
+        restbus_simulations: vec![],
+        revision: Default::default(),
+        namespace: Default::default(),
     };
 
     carl_client.inner().await.cluster.store_cluster_descriptor(cluster_descriptor.clone()).await?;
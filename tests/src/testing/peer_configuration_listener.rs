@@ -1,4 +1,5 @@
 use opendut_edgar::testing::service::peer_configuration::ApplyPeerConfigurationParams;
+use opendut_edgar::testing::service::status::ServiceStatusWriter;
 use opendut_model::peer::configuration::{PeerConfiguration, EdgePeerConfigurationState};
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -8,6 +9,7 @@ use tokio::time::timeout;
 pub struct PeerConfigurationReceiver {
     pub inner: mpsc::Receiver<ApplyPeerConfigurationParams>,
     pub tx_peer_configuration_state: mpsc::Sender<EdgePeerConfigurationState>,
+    pub status_writer: ServiceStatusWriter,
 }
 impl PeerConfigurationReceiver {
     pub async fn receive_peer_configuration(&mut self) -> anyhow::Result<PeerConfiguration> {
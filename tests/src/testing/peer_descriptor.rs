@@ -37,6 +37,8 @@ pub async fn store_peer_descriptor(carl_client: &TestCarlClient) -> anyhow::Resu
         executors: ExecutorDescriptors {
             executors: vec![],
         },
+        revision: Default::default(),
+        namespace: Default::default(),
     };
 
     carl_client.inner().await.peers
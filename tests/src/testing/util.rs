@@ -5,6 +5,8 @@ use opendut_util::settings::LoadedConfig;
 use tokio::sync::mpsc;
 use tracing::info;
 use opendut_model::peer::configuration::EdgePeerConfigurationState;
+use opendut_edgar::testing::service::peer_messaging_client::{HandleStreamInfo, PeerMessagingClient};
+use opendut_edgar::testing::service::status::ServiceStatusWriter;
 
 pub fn spawn_carl() -> anyhow::Result<Port> {
     let carl_port = select_free_port();
@@ -41,7 +43,7 @@ pub fn spawn_carl() -> anyhow::Result<Port> {
 pub async fn spawn_edgar_with_default_behavior(peer_id: PeerId, carl_port: Port) -> anyhow::Result<()> {
     let receiver = spawn_edgar_with_peer_configuration_receiver(peer_id, carl_port).await?;
 
-    opendut_edgar::testing::service::peer_configuration::spawn_peer_configurations_handler(receiver.inner, receiver.tx_peer_configuration_state).await.unwrap();
+    opendut_edgar::testing::service::peer_configuration::spawn_peer_configurations_handler(receiver.inner, receiver.tx_peer_configuration_state, None, receiver.status_writer).await.unwrap();
     Ok(())
 }
 
@@ -49,19 +51,21 @@ pub async fn spawn_edgar_with_peer_configuration_receiver(peer_id: PeerId, carl_
     let (tx_peer_configuration_state, rx_peer_configuration_state) = mpsc::channel::<EdgePeerConfigurationState>(100);
 
     let edgar_config = load_edgar_config(carl_port, peer_id)?;
+    let status_writer = ServiceStatusWriter::load(&edgar_config.config, peer_id)?;
 
     let (tx_peer_configuration, rx_peer_configuration) = mpsc::channel(100);
+    let (handle_stream_info, rx_executor_status) = HandleStreamInfo::create(peer_id, &edgar_config, status_writer.clone())?;
     tokio::spawn(async move {
         let carl = opendut_edgar::testing::carl::connect(&edgar_config.config).await
             .expect("Could not connect to CARL for spawning EDGAR");
 
-        let mut peer_messaging_client = opendut_edgar::testing::service::peer_messaging_client::PeerMessagingClient::create(peer_id, carl, edgar_config, tx_peer_configuration)
+        let mut peer_messaging_client = PeerMessagingClient::create(carl, handle_stream_info, rx_executor_status, edgar_config, tx_peer_configuration)
             .await
             .expect("Could not create EDGAR peer messaging client");
         peer_messaging_client.process_messages_loop(rx_peer_configuration_state).await
             .expect("Could not communicate with CARL. EDGAR test instance.");
     });
-    Ok(PeerConfigurationReceiver { inner: rx_peer_configuration, tx_peer_configuration_state })
+    Ok(PeerConfigurationReceiver { inner: rx_peer_configuration, tx_peer_configuration_state, status_writer })
 }
 
 pub(super) fn load_edgar_config(carl_port: Port, peer_id: PeerId) -> anyhow::Result<LoadedConfig> {
@@ -72,6 +76,8 @@ pub(super) fn load_edgar_config(carl_port: Port, peer_id: PeerId) -> anyhow::Res
         .set_override("network.carl.port", carl_port.0)?
         .set_override("network.connect.retries", 20)?
         .set_override("network.oidc.enabled", false)?
+        .set_override("peer.configuration.cache.enabled", false)?
+        .set_override("service.status.file", std::env::temp_dir().join(format!("opendut-edgar-{peer_id}-status.json")).display().to_string())?
         .build()?;
 
     opendut_edgar::testing::settings::load_with_overrides(settings_overrides)